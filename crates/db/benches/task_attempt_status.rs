@@ -0,0 +1,160 @@
+//! Benchmark for `Task::find_by_project_id_with_attempt_status`.
+//!
+//! Seeds an in-memory database with a single large project and times the board
+//! query. Run with `cargo bench -p db --bench task_attempt_status`.
+//!
+//! Environment:
+//! - `VK_BENCH_TASKS`: number of tasks to seed (default 5000)
+//! - `VK_BENCH_ITERATIONS`: number of timed runs (default 20)
+//! - `VK_BENCH_BUDGET_MS`: fail if the p95 exceeds this many milliseconds
+
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use db::models::task::Task;
+use sqlx::{
+    SqlitePool,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+use uuid::Uuid;
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+async fn setup_pool() -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::new().in_memory(true);
+    // A single connection keeps every query on the same in-memory database.
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await?;
+    sqlx::migrate!("./migrations").run(&pool).await?;
+    Ok(pool)
+}
+
+/// Seed one project with `task_count` tasks. Every other task gets two attempts,
+/// each with a session and a setup + coding agent process, so the query has to
+/// pick the latest row out of several candidates.
+async fn seed(pool: &SqlitePool, task_count: usize) -> Result<Uuid, sqlx::Error> {
+    let project_id = Uuid::new_v4();
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("INSERT INTO projects (id, name) VALUES ($1, $2)")
+        .bind(project_id)
+        .bind("bench")
+        .execute(&mut *tx)
+        .await?;
+
+    for i in 0..task_count {
+        let task_id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO tasks (id, project_id, title, created_at)
+             VALUES ($1, $2, $3, datetime('now', $4))",
+        )
+        .bind(task_id)
+        .bind(project_id)
+        .bind(format!("Task {i}"))
+        .bind(format!("-{i} seconds"))
+        .execute(&mut *tx)
+        .await?;
+
+        if i % 2 != 0 {
+            continue;
+        }
+
+        for attempt in 0..2 {
+            let workspace_id = Uuid::new_v4();
+            let session_id = Uuid::new_v4();
+            let offset = format!("+{attempt} minutes");
+
+            sqlx::query(
+                "INSERT INTO workspaces (id, task_id, container_ref, branch, created_at)
+                 VALUES ($1, $2, $3, $4, datetime('now', $5))",
+            )
+            .bind(workspace_id)
+            .bind(task_id)
+            .bind(format!("/tmp/bench/{workspace_id}"))
+            .bind(format!("vk/bench-{i}-{attempt}"))
+            .bind(&offset)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                "INSERT INTO sessions (id, workspace_id, executor, created_at)
+                 VALUES ($1, $2, 'CLAUDE_CODE', datetime('now', $3))",
+            )
+            .bind(session_id)
+            .bind(workspace_id)
+            .bind(&offset)
+            .execute(&mut *tx)
+            .await?;
+
+            for (run_reason, status) in [("setupscript", "completed"), ("codingagent", "failed")] {
+                sqlx::query(
+                    "INSERT INTO execution_processes (id, session_id, run_reason, status, created_at)
+                     VALUES ($1, $2, $3, $4, datetime('now', $5))",
+                )
+                .bind(Uuid::new_v4())
+                .bind(session_id)
+                .bind(run_reason)
+                .bind(status)
+                .bind(&offset)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+    }
+
+    tx.commit().await?;
+    sqlx::query("ANALYZE").execute(pool).await?;
+    Ok(project_id)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let task_count: usize = env_or("VK_BENCH_TASKS", 5000);
+    let iterations: usize = env_or("VK_BENCH_ITERATIONS", 20).max(1);
+    let budget_ms: Option<u64> = env::var("VK_BENCH_BUDGET_MS")
+        .ok()
+        .and_then(|v| v.parse().ok());
+
+    let pool = setup_pool().await?;
+    let project_id = seed(&pool, task_count).await?;
+
+    // Warm up the page cache and statement cache before timing.
+    let tasks = Task::find_by_project_id_with_attempt_status(&pool, project_id).await?;
+    assert_eq!(tasks.len(), task_count);
+
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        Task::find_by_project_id_with_attempt_status(&pool, project_id).await?;
+        samples.push(start.elapsed());
+    }
+    samples.sort();
+
+    let total: Duration = samples.iter().sum();
+    let mean = total / iterations as u32;
+    let p95 = samples[(iterations * 95 / 100).min(iterations - 1)];
+
+    println!(
+        "find_by_project_id_with_attempt_status: tasks={task_count} iterations={iterations} \
+         mean={mean:?} min={:?} p95={p95:?} max={:?}",
+        samples[0],
+        samples[iterations - 1],
+    );
+
+    if let Some(budget_ms) = budget_ms
+        && p95 > Duration::from_millis(budget_ms)
+    {
+        return Err(format!("p95 {p95:?} exceeded budget of {budget_ms}ms").into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+-- Covering indexes for Task::find_by_project_id_with_attempt_status.
+-- The query ranks workspaces, sessions and execution processes per task with
+-- window functions; these indexes let SQLite satisfy each ranking straight
+-- from the index without touching the base tables.
+
+-- Latest workspace per task (id + container_ref are read directly)
+CREATE INDEX IF NOT EXISTS idx_workspaces_task_created_covering
+ON workspaces (task_id, created_at DESC, id, container_ref);
+
+-- Latest session per workspace (executor and id are read directly)
+CREATE INDEX IF NOT EXISTS idx_sessions_workspace_created_covering
+ON sessions (workspace_id, created_at DESC, executor, id);
+
+-- Latest attempt process per session (status is read directly)
+CREATE INDEX IF NOT EXISTS idx_execution_processes_session_reason_created_covering
+ON execution_processes (session_id, run_reason, created_at DESC, status);
+
+-- Superseded by the covering variants above
+DROP INDEX IF EXISTS idx_sessions_workspace_id_created_at;
+DROP INDEX IF EXISTS idx_execution_processes_session_run_reason_created;
+
+PRAGMA optimize;
@@ -0,0 +1,21 @@
+-- Review requests on tasks awaiting review; one row per reviewer per task
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_reviews (
+    id           BLOB PRIMARY KEY,
+    task_id      BLOB NOT NULL,
+    reviewer_id  BLOB NOT NULL,
+    requested_by BLOB,
+    status       TEXT NOT NULL DEFAULT 'pending'
+                    CHECK (status IN ('pending', 'approved', 'changes_requested')),
+    comment      TEXT,
+    responded_at TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (reviewer_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (requested_by) REFERENCES users(id) ON DELETE SET NULL,
+    UNIQUE (task_id, reviewer_id)
+);
+
+CREATE INDEX idx_task_reviews_reviewer_status ON task_reviews(reviewer_id, status);
@@ -0,0 +1,35 @@
+-- Versioned per-project agent instruction documents, selectable per task
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_instructions (
+    id              BLOB PRIMARY KEY,
+    project_id      BLOB NOT NULL,
+    name            TEXT NOT NULL,
+    is_default      INTEGER NOT NULL DEFAULT 0,
+    current_version INTEGER NOT NULL DEFAULT 1,
+    created_at      TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at      TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    UNIQUE (project_id, name)
+);
+
+CREATE TABLE project_instruction_versions (
+    instruction_id BLOB NOT NULL,
+    version        INTEGER NOT NULL,
+    content        TEXT NOT NULL,
+    created_at     TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (instruction_id, version),
+    FOREIGN KEY (instruction_id) REFERENCES project_instructions(id) ON DELETE CASCADE
+);
+
+-- NULL version follows the instruction's current version
+CREATE TABLE task_instructions (
+    task_id        BLOB PRIMARY KEY,
+    instruction_id BLOB NOT NULL,
+    version        INTEGER,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (instruction_id) REFERENCES project_instructions(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_project_instructions_project_id ON project_instructions(project_id);
+CREATE INDEX idx_task_instructions_instruction_id ON task_instructions(instruction_id);
@@ -0,0 +1,20 @@
+-- Credentials detected in attempt diffs when sharing a task or opening a PR
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE secret_scan_findings (
+    id           BLOB PRIMARY KEY,
+    workspace_id BLOB NOT NULL,
+    repo_id      BLOB NOT NULL,
+    operation    TEXT NOT NULL CHECK (operation IN ('share', 'create_pr')),
+    file_path    TEXT NOT NULL,
+    line_number  INTEGER NOT NULL,
+    kind         TEXT NOT NULL,
+    redacted     TEXT NOT NULL,
+    -- Set when the user chose to proceed despite the finding
+    overridden   INTEGER NOT NULL DEFAULT 0,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_secret_scan_findings_workspace_id ON secret_scan_findings(workspace_id, created_at);
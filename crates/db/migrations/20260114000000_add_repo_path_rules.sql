@@ -0,0 +1,16 @@
+-- Per-repo rules restricting which paths agent attempts may modify
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE repo_path_rules (
+    id         BLOB PRIMARY KEY,
+    repo_id    BLOB NOT NULL,
+    -- gitignore-style pattern, e.g. `infra/` or `*.lock`
+    pattern    TEXT NOT NULL,
+    -- 'deny' protects matching paths; 'allow' rules together form an allowlist
+    kind       TEXT NOT NULL DEFAULT 'deny' CHECK (kind IN ('allow', 'deny')),
+    action     TEXT NOT NULL DEFAULT 'reject' CHECK (action IN ('reject', 'flag')),
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_repo_path_rules_repo_id ON repo_path_rules(repo_id);
@@ -0,0 +1,30 @@
+-- Project folders, manual ordering and per-user favorites
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_groups (
+    id         BLOB PRIMARY KEY,
+    name       TEXT NOT NULL UNIQUE,
+    position   INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec'))
+);
+
+-- Where a project sits in the list; projects without a row are ungrouped at position 0
+CREATE TABLE project_placements (
+    project_id BLOB PRIMARY KEY,
+    group_id   BLOB,
+    position   INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (group_id) REFERENCES project_groups(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_project_placements_group_id ON project_placements(group_id);
+
+CREATE TABLE project_favorites (
+    user_id    BLOB NOT NULL,
+    project_id BLOB NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (user_id, project_id),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,15 @@
+-- Who created a task and who it is assigned to
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_owners (
+    task_id            BLOB PRIMARY KEY,
+    created_by_user_id BLOB,
+    assignee_user_id   BLOB,
+    updated_at         TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by_user_id) REFERENCES users(id) ON DELETE SET NULL,
+    FOREIGN KEY (assignee_user_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_task_owners_created_by_user_id ON task_owners(created_by_user_id);
+CREATE INDEX idx_task_owners_assignee_user_id ON task_owners(assignee_user_id);
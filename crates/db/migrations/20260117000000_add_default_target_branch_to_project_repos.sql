@@ -0,0 +1,2 @@
+-- Branch new attempts target when the client doesn't pick one
+ALTER TABLE project_repos ADD COLUMN default_target_branch TEXT;
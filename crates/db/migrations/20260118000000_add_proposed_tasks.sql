@@ -0,0 +1,20 @@
+-- Follow-up tasks proposed by a coding agent, waiting for a human to accept or reject them
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE proposed_tasks (
+    id                   BLOB PRIMARY KEY,
+    workspace_id         BLOB NOT NULL,
+    execution_process_id BLOB,
+    title                TEXT NOT NULL,
+    description          TEXT,
+    status               TEXT NOT NULL DEFAULT 'pending'
+                            CHECK (status IN ('pending', 'accepted', 'rejected')),
+    task_id              BLOB,
+    created_at           TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at           TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (execution_process_id) REFERENCES execution_processes(id) ON DELETE SET NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_proposed_tasks_workspace_id ON proposed_tasks(workspace_id);
@@ -0,0 +1,20 @@
+-- Review comments anchored to a file, or a line of a file, in a workspace diff
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE diff_comments (
+    id             BLOB PRIMARY KEY,
+    workspace_id   BLOB NOT NULL,
+    repo_id        BLOB NOT NULL,
+    file_path      TEXT NOT NULL,
+    line_number    INTEGER,
+    body           TEXT NOT NULL,
+    author_user_id BLOB,
+    resolved       INTEGER NOT NULL DEFAULT 0,
+    created_at     TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at     TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE,
+    FOREIGN KEY (author_user_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_diff_comments_workspace_id ON diff_comments(workspace_id);
@@ -0,0 +1,10 @@
+-- Allowed task status moves per project; a project without rows allows any move
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_status_transitions (
+    project_id  BLOB NOT NULL,
+    from_status TEXT NOT NULL,
+    to_status   TEXT NOT NULL,
+    PRIMARY KEY (project_id, from_status, to_status),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
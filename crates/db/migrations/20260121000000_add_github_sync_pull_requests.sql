@@ -0,0 +1,2 @@
+-- Opt-in import of open GitHub pull requests as review tasks
+ALTER TABLE projects ADD COLUMN github_sync_pull_requests INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,19 @@
+-- GitLab merge requests opened from a workspace branch, one per repo
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE gitlab_merge_requests (
+    id            BLOB PRIMARY KEY,
+    workspace_id  BLOB NOT NULL,
+    repo_id       BLOB NOT NULL,
+    mr_iid        INTEGER NOT NULL,
+    web_url       TEXT NOT NULL,
+    state         TEXT NOT NULL,
+    target_branch TEXT NOT NULL,
+    created_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE,
+    UNIQUE (workspace_id, repo_id)
+);
+
+CREATE INDEX idx_gitlab_merge_requests_workspace_id ON gitlab_merge_requests(workspace_id);
@@ -0,0 +1,14 @@
+-- Shared secrets for inbound issue-tracker webhooks, one per project and provider
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_webhooks (
+    project_id BLOB NOT NULL,
+    provider   TEXT NOT NULL CHECK (provider IN ('github', 'gitlab')),
+    secret     TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (project_id, provider),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_project_webhooks_provider ON project_webhooks(provider);
@@ -0,0 +1,28 @@
+-- Allow issue_comment notifications; SQLite can't alter a CHECK, so rebuild the table
+CREATE TABLE notifications_new (
+    id           BLOB PRIMARY KEY,
+    user_id      BLOB NOT NULL,
+    kind         TEXT NOT NULL
+                    CHECK (kind IN ('attempt_completed', 'attempt_failed', 'mention', 'review_requested', 'sync_error', 'issue_comment')),
+    title        TEXT NOT NULL,
+    message      TEXT NOT NULL,
+    project_id   BLOB,
+    task_id      BLOB,
+    workspace_id BLOB,
+    read_at      TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE
+);
+
+INSERT INTO notifications_new (id, user_id, kind, title, message, project_id, task_id, workspace_id, read_at, created_at)
+SELECT id, user_id, kind, title, message, project_id, task_id, workspace_id, read_at, created_at
+FROM notifications;
+
+DROP TABLE notifications;
+ALTER TABLE notifications_new RENAME TO notifications;
+
+CREATE INDEX idx_notifications_user_created ON notifications(user_id, created_at DESC);
+CREATE INDEX idx_notifications_user_unread ON notifications(user_id) WHERE read_at IS NULL;
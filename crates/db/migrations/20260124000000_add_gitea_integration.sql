@@ -0,0 +1,29 @@
+-- Per-project Gitea/Forgejo issue sync, plus webhook secrets for it
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN gitea_base_url TEXT;
+ALTER TABLE projects ADD COLUMN gitea_repo TEXT;
+ALTER TABLE projects ADD COLUMN gitea_token TEXT;
+ALTER TABLE projects ADD COLUMN gitea_sync_enabled INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE projects ADD COLUMN gitea_sync_labels TEXT;
+ALTER TABLE projects ADD COLUMN gitea_last_sync_at TEXT;
+
+-- SQLite can't alter a CHECK, so rebuild project_webhooks to accept 'gitea'
+CREATE TABLE project_webhooks_new (
+    project_id BLOB NOT NULL,
+    provider   TEXT NOT NULL CHECK (provider IN ('github', 'gitlab', 'gitea')),
+    secret     TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (project_id, provider),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+INSERT INTO project_webhooks_new (project_id, provider, secret, created_at, updated_at)
+SELECT project_id, provider, secret, created_at, updated_at
+FROM project_webhooks;
+
+DROP TABLE project_webhooks;
+ALTER TABLE project_webhooks_new RENAME TO project_webhooks;
+
+CREATE INDEX idx_project_webhooks_provider ON project_webhooks(provider);
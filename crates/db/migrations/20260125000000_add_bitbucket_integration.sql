@@ -0,0 +1,24 @@
+-- Bitbucket Cloud issue import and pull requests opened from workspace branches
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN bitbucket_workspace TEXT;
+ALTER TABLE projects ADD COLUMN bitbucket_repo_slug TEXT;
+ALTER TABLE projects ADD COLUMN bitbucket_username TEXT;
+ALTER TABLE projects ADD COLUMN bitbucket_app_password TEXT;
+
+CREATE TABLE bitbucket_pull_requests (
+    id            BLOB PRIMARY KEY,
+    workspace_id  BLOB NOT NULL,
+    repo_id       BLOB NOT NULL,
+    pr_id         INTEGER NOT NULL,
+    web_url       TEXT NOT NULL,
+    state         TEXT NOT NULL,
+    target_branch TEXT NOT NULL,
+    created_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE,
+    UNIQUE (workspace_id, repo_id)
+);
+
+CREATE INDEX idx_bitbucket_pull_requests_workspace_id ON bitbucket_pull_requests(workspace_id);
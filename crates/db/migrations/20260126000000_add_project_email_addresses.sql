@@ -0,0 +1,10 @@
+-- Inbound email address per project; mail delivered to it becomes a task
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_email_addresses (
+    project_id BLOB PRIMARY KEY,
+    address    TEXT NOT NULL COLLATE NOCASE UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
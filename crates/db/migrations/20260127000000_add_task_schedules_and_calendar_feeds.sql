@@ -0,0 +1,17 @@
+-- Due dates and scheduled starts for tasks, and tokenized ICS feeds per project
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_schedules (
+    task_id    BLOB PRIMARY KEY,
+    starts_at  TEXT,
+    due_at     TEXT,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE TABLE project_calendar_feeds (
+    project_id BLOB PRIMARY KEY,
+    token      TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,17 @@
+-- REST hook subscriptions: target URLs that receive an outbound POST per event
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE rest_hook_subscriptions (
+    id                 BLOB PRIMARY KEY,
+    event              TEXT NOT NULL
+                          CHECK (event IN ('task_created', 'task_status_changed', 'attempt_finished')),
+    target_url         TEXT NOT NULL,
+    -- NULL subscribes to events from every project
+    project_id         BLOB,
+    created_by_user_id BLOB,
+    created_at         TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by_user_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_rest_hook_subscriptions_event ON rest_hook_subscriptions(event);
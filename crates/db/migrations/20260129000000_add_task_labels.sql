@@ -0,0 +1,13 @@
+-- Labels on tasks, and which way they are synced with linked GitHub issues
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_labels (
+    task_id    BLOB NOT NULL,
+    name       TEXT NOT NULL COLLATE NOCASE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (task_id, name),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+ALTER TABLE projects ADD COLUMN github_label_sync TEXT NOT NULL DEFAULT 'import'
+    CHECK (github_label_sync IN ('off', 'import', 'export', 'both'));
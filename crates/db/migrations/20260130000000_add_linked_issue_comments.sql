@@ -0,0 +1,2 @@
+-- Opt-in comment on the source issue when an attempt for a linked task finishes
+ALTER TABLE projects ADD COLUMN comment_on_linked_issues INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,12 @@
+-- Vortex OAuth connections per project. Tokens are encrypted before they are stored.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE vortex_oauth_tokens (
+    project_id    BLOB PRIMARY KEY,
+    access_token  TEXT NOT NULL,
+    refresh_token TEXT,
+    expires_at    TEXT,
+    created_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
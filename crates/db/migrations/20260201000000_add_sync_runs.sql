@@ -0,0 +1,18 @@
+-- Outcome of each provider issue sync, so failures are visible per project
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE sync_runs (
+    id             BLOB PRIMARY KEY,
+    project_id     BLOB NOT NULL,
+    provider       TEXT NOT NULL,
+    status         TEXT NOT NULL DEFAULT 'running'
+                   CHECK (status IN ('running', 'succeeded', 'failed')),
+    imported_count INTEGER NOT NULL DEFAULT 0,
+    updated_count  INTEGER NOT NULL DEFAULT 0,
+    error          TEXT,
+    started_at     TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    finished_at    TEXT,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_sync_runs_project_started ON sync_runs(project_id, started_at DESC);
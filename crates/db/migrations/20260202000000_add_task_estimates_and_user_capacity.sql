@@ -0,0 +1,18 @@
+-- Task estimates and each user's weekly capacity, for capacity planning and queue ETAs
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_estimates (
+    task_id    BLOB PRIMARY KEY,
+    value      REAL NOT NULL CHECK (value >= 0),
+    unit       TEXT NOT NULL CHECK (unit IN ('points', 'hours')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE TABLE user_capacities (
+    user_id       BLOB PRIMARY KEY,
+    weekly_hours  REAL CHECK (weekly_hours >= 0),
+    weekly_points REAL CHECK (weekly_points >= 0),
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,106 @@
+-- Per-project SLA rules and the breaches found by the SLA monitor, plus when each
+-- task entered its current status so time-in-status can be measured
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_sla_rules (
+    id              BLOB PRIMARY KEY,
+    project_id      BLOB NOT NULL,
+    status          TEXT NOT NULL
+                       CHECK (status IN ('todo', 'inprogress', 'inreview', 'done', 'cancelled')),
+    -- time_in_status: too long in the status; idle: no attempt activity for too long
+    kind            TEXT NOT NULL CHECK (kind IN ('time_in_status', 'idle')),
+    threshold_hours REAL NOT NULL CHECK (threshold_hours > 0),
+    created_at      TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_project_sla_rules_project_id ON project_sla_rules(project_id);
+
+CREATE TABLE task_sla_breaches (
+    id          BLOB PRIMARY KEY,
+    task_id     BLOB NOT NULL,
+    rule_id     BLOB NOT NULL,
+    breached_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    resolved_at TEXT,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (rule_id) REFERENCES project_sla_rules(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX idx_task_sla_breaches_open
+    ON task_sla_breaches(task_id, rule_id) WHERE resolved_at IS NULL;
+
+CREATE TABLE task_status_entered (
+    task_id    BLOB PRIMARY KEY,
+    entered_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+-- Existing tasks have no status history; their last update is the best guess
+INSERT INTO task_status_entered (task_id, entered_at)
+SELECT id, updated_at FROM tasks;
+
+CREATE TRIGGER IF NOT EXISTS trg_tasks_status_entered_insert
+AFTER INSERT ON tasks
+FOR EACH ROW
+BEGIN
+    INSERT OR REPLACE INTO task_status_entered (task_id) VALUES (NEW.id);
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_tasks_status_entered_update
+AFTER UPDATE OF status ON tasks
+FOR EACH ROW WHEN OLD.status IS NOT NEW.status
+BEGIN
+    INSERT OR REPLACE INTO task_status_entered (task_id) VALUES (NEW.id);
+END;
+
+-- Allow sla_breach notifications; SQLite can't alter a CHECK, so rebuild the table
+CREATE TABLE notifications_new (
+    id           BLOB PRIMARY KEY,
+    user_id      BLOB NOT NULL,
+    kind         TEXT NOT NULL
+                    CHECK (kind IN ('attempt_completed', 'attempt_failed', 'mention', 'review_requested', 'sync_error', 'issue_comment', 'sla_breach')),
+    title        TEXT NOT NULL,
+    message      TEXT NOT NULL,
+    project_id   BLOB,
+    task_id      BLOB,
+    workspace_id BLOB,
+    read_at      TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE
+);
+
+INSERT INTO notifications_new (id, user_id, kind, title, message, project_id, task_id, workspace_id, read_at, created_at)
+SELECT id, user_id, kind, title, message, project_id, task_id, workspace_id, read_at, created_at
+FROM notifications;
+
+DROP TABLE notifications;
+ALTER TABLE notifications_new RENAME TO notifications;
+
+CREATE INDEX idx_notifications_user_created ON notifications(user_id, created_at DESC);
+CREATE INDEX idx_notifications_user_unread ON notifications(user_id) WHERE read_at IS NULL;
+
+-- Same for the sla_breached REST hook event
+CREATE TABLE rest_hook_subscriptions_new (
+    id                 BLOB PRIMARY KEY,
+    event              TEXT NOT NULL
+                          CHECK (event IN ('task_created', 'task_status_changed', 'attempt_finished', 'sla_breached')),
+    target_url         TEXT NOT NULL,
+    -- NULL subscribes to events from every project
+    project_id         BLOB,
+    created_by_user_id BLOB,
+    created_at         TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (created_by_user_id) REFERENCES users(id) ON DELETE SET NULL
+);
+
+INSERT INTO rest_hook_subscriptions_new (id, event, target_url, project_id, created_by_user_id, created_at)
+SELECT id, event, target_url, project_id, created_by_user_id, created_at
+FROM rest_hook_subscriptions;
+
+DROP TABLE rest_hook_subscriptions;
+ALTER TABLE rest_hook_subscriptions_new RENAME TO rest_hook_subscriptions;
+
+CREATE INDEX idx_rest_hook_subscriptions_event ON rest_hook_subscriptions(event);
@@ -0,0 +1,12 @@
+-- Long-lived read-only tokens for wallboard displays; only a hash of each is kept
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE kiosk_tokens (
+    id                 BLOB PRIMARY KEY,
+    name               TEXT NOT NULL,
+    token_hash         TEXT NOT NULL UNIQUE,
+    created_by_user_id BLOB,
+    last_used_at       TEXT,
+    created_at         TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (created_by_user_id) REFERENCES users(id) ON DELETE SET NULL
+);
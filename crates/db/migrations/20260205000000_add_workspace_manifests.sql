@@ -0,0 +1,9 @@
+-- What an attempt was started with, so its results can be reproduced or audited
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE workspace_manifests (
+    workspace_id BLOB PRIMARY KEY,
+    manifest     TEXT NOT NULL,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE
+);
@@ -0,0 +1,13 @@
+-- Agent-written commit message and PR description for an attempt's changes
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE workspace_summaries (
+    workspace_id         BLOB PRIMARY KEY,
+    execution_process_id BLOB,
+    commit_message       TEXT,
+    pr_title             TEXT,
+    pr_description       TEXT,
+    updated_at           TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE,
+    FOREIGN KEY (execution_process_id) REFERENCES execution_processes(id) ON DELETE SET NULL
+);
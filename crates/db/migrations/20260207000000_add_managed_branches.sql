@@ -0,0 +1,18 @@
+-- Local branches vibe-kanban created for attempts, so cleanup only ever deletes
+-- branches it owns
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE managed_branches (
+    id           BLOB PRIMARY KEY,
+    repo_id      BLOB NOT NULL,
+    workspace_id BLOB,
+    branch_name  TEXT NOT NULL,
+    base_branch  TEXT NOT NULL,
+    deleted_at   TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE,
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE SET NULL,
+    UNIQUE (repo_id, branch_name)
+);
+
+CREATE INDEX idx_managed_branches_workspace_id ON managed_branches(workspace_id);
@@ -0,0 +1,22 @@
+-- Recurring agent jobs per project (dependency bumps, lint fixes). The scheduler
+-- runs a due job when no other agent is running and creates a task for the run.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_maintenance_jobs (
+    id                  BLOB PRIMARY KEY,
+    project_id          BLOB NOT NULL,
+    name                TEXT NOT NULL,
+    prompt              TEXT NOT NULL,
+    -- Five-field cron expression, evaluated in UTC
+    schedule            TEXT NOT NULL,
+    executor_profile_id TEXT NOT NULL,
+    enabled             INTEGER NOT NULL DEFAULT 1,
+    last_run_at         TEXT,
+    last_task_id        BLOB,
+    created_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (last_task_id) REFERENCES tasks(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_project_maintenance_jobs_project_id ON project_maintenance_jobs(project_id);
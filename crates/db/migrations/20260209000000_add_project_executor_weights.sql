@@ -0,0 +1,10 @@
+-- Each project's share of the executor slots for automatically started attempts.
+-- Projects without a row have weight 1.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_executor_weights (
+    project_id BLOB PRIMARY KEY,
+    weight     INTEGER NOT NULL CHECK (weight BETWEEN 1 AND 100),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,13 @@
+-- Whether each repo's path still holds the repository, plus what identifies the
+-- repository so a moved checkout can be verified before the path is updated.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE repo_health (
+    repo_id       BLOB PRIMARY KEY,
+    root_commit   TEXT,
+    remote_url    TEXT,
+    -- Set while the repo is broken
+    broken_reason TEXT,
+    checked_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (repo_id) REFERENCES repos(id) ON DELETE CASCADE
+);
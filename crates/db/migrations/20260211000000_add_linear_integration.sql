@@ -0,0 +1,17 @@
+-- A project's connection to a Linear team, for importing issues and writing
+-- task status changes back to them
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_linear_connections (
+    project_id          BLOB PRIMARY KEY,
+    -- Personal API key, encrypted
+    api_key             TEXT NOT NULL,
+    team_id             TEXT NOT NULL,
+    team_key            TEXT NOT NULL,
+    sync_labels         TEXT,
+    status_sync_enabled INTEGER NOT NULL DEFAULT 1,
+    last_sync_at        TEXT,
+    created_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
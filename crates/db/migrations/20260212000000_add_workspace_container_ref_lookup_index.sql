@@ -0,0 +1,8 @@
+-- Orphan cleanup, storage stats and the MCP context lookup all match
+-- workspaces by container_ref and read the id and task_id. Cover those
+-- columns so the lookup doesn't touch the table.
+DROP INDEX IF EXISTS idx_workspaces_container_ref;
+
+CREATE INDEX IF NOT EXISTS idx_workspaces_container_ref_lookup
+ON workspaces (container_ref, id, task_id)
+WHERE container_ref IS NOT NULL;
@@ -0,0 +1,18 @@
+-- A project's connection to an Azure DevOps project, for importing Boards
+-- work items as tasks
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_azure_connections (
+    project_id       BLOB PRIMARY KEY,
+    -- https://dev.azure.com/{organization} or a server collection URL
+    organization_url TEXT NOT NULL,
+    azure_project    TEXT NOT NULL,
+    -- Personal access token, encrypted
+    pat              TEXT NOT NULL,
+    -- Extra WIQL condition narrowing which work items are imported
+    wiql_filter      TEXT,
+    last_sync_at     TEXT,
+    created_at       TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at       TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
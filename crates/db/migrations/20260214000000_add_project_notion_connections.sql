@@ -0,0 +1,17 @@
+-- A project's connection to a Notion database whose rows are imported as
+-- tasks
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_notion_connections (
+    project_id           BLOB PRIMARY KEY,
+    database_id          TEXT NOT NULL,
+    -- Internal integration secret, encrypted
+    token                TEXT NOT NULL,
+    -- Names of the properties holding each row's status and description
+    status_property      TEXT NOT NULL DEFAULT 'Status',
+    description_property TEXT NOT NULL DEFAULT 'Description',
+    last_sync_at         TEXT,
+    created_at           TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at           TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
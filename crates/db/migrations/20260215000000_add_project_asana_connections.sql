@@ -0,0 +1,14 @@
+-- A project's connection to an Asana project whose tasks are imported as
+-- vibe-kanban tasks
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_asana_connections (
+    project_id   BLOB PRIMARY KEY,
+    project_gid  TEXT NOT NULL,
+    -- Personal access token, encrypted
+    token        TEXT NOT NULL,
+    last_sync_at TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
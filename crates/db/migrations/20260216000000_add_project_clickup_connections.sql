@@ -0,0 +1,16 @@
+-- A project's connection to a ClickUp list whose tasks are imported as
+-- vibe-kanban tasks
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_clickup_connections (
+    project_id   BLOB PRIMARY KEY,
+    list_id      TEXT NOT NULL,
+    -- Personal API token, encrypted
+    token        TEXT NOT NULL,
+    -- Comma-separated tags; only tasks with one of them are listed and synced
+    tag_filter   TEXT,
+    last_sync_at TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,18 @@
+-- A project's connection to a Shortcut workspace whose labelled stories are
+-- imported as vibe-kanban tasks
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_shortcut_connections (
+    project_id      BLOB PRIMARY KEY,
+    -- API token, encrypted
+    token           TEXT NOT NULL,
+    -- Comma-separated labels; stories with any of them are imported
+    labels          TEXT,
+    -- Workflow state stories move to when their task goes to review; when
+    -- unset, a state named like "review" in the story's workflow is used
+    review_state_id INTEGER,
+    last_sync_at    TEXT,
+    created_at      TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at      TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
@@ -0,0 +1,15 @@
+-- Explicit "task_id depends on depends_on_task_id" edges; the sequential
+-- queue holds a task until all of its dependencies are done
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_dependencies (
+    task_id            BLOB NOT NULL,
+    depends_on_task_id BLOB NOT NULL,
+    created_at         TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (task_id, depends_on_task_id),
+    CHECK (task_id != depends_on_task_id),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (depends_on_task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_task_dependencies_depends_on ON task_dependencies(depends_on_task_id);
@@ -0,0 +1,48 @@
+-- Every creation, status or title change and deletion of a task, so the board
+-- can be reconstructed as it was at any point in time
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_activity (
+    id         BLOB PRIMARY KEY,
+    project_id BLOB NOT NULL,
+    -- Not a foreign key: the history of deleted tasks is kept
+    task_id    BLOB NOT NULL,
+    kind       TEXT NOT NULL CHECK (kind IN ('created', 'updated', 'deleted')),
+    -- Title and status of the task after the change
+    title      TEXT NOT NULL,
+    status     TEXT NOT NULL
+                  CHECK (status IN ('todo', 'inprogress', 'inreview', 'done', 'cancelled')),
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_task_activity_project_id ON task_activity(project_id, created_at);
+
+-- Existing tasks have no history; they appear as created in their current state
+INSERT INTO task_activity (id, project_id, task_id, kind, title, status, created_at)
+SELECT randomblob(16), project_id, id, 'created', title, status, created_at FROM tasks;
+
+CREATE TRIGGER IF NOT EXISTS trg_task_activity_insert
+AFTER INSERT ON tasks
+FOR EACH ROW
+BEGIN
+    INSERT INTO task_activity (id, project_id, task_id, kind, title, status)
+    VALUES (randomblob(16), NEW.project_id, NEW.id, 'created', NEW.title, NEW.status);
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_task_activity_update
+AFTER UPDATE OF status, title ON tasks
+FOR EACH ROW WHEN OLD.status IS NOT NEW.status OR OLD.title IS NOT NEW.title
+BEGIN
+    INSERT INTO task_activity (id, project_id, task_id, kind, title, status)
+    VALUES (randomblob(16), NEW.project_id, NEW.id, 'updated', NEW.title, NEW.status);
+END;
+
+-- Tasks deleted along with their project leave no history behind
+CREATE TRIGGER IF NOT EXISTS trg_task_activity_delete
+AFTER DELETE ON tasks
+FOR EACH ROW WHEN EXISTS (SELECT 1 FROM projects WHERE id = OLD.project_id)
+BEGIN
+    INSERT INTO task_activity (id, project_id, task_id, kind, title, status)
+    VALUES (randomblob(16), OLD.project_id, OLD.id, 'deleted', OLD.title, OLD.status);
+END;
@@ -0,0 +1,55 @@
+-- Token budgets for coding agent attempts, set per project or per task, and
+-- the spend recorded on each attempt's summary
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_token_budgets (
+    project_id BLOB PRIMARY KEY,
+    max_tokens INTEGER NOT NULL CHECK (max_tokens > 0),
+    -- pause: stop the agent so the attempt can be resumed; abort: fail it
+    action     TEXT NOT NULL CHECK (action IN ('pause', 'abort')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- Overrides the project budget for one task
+CREATE TABLE task_token_budgets (
+    task_id    BLOB PRIMARY KEY,
+    max_tokens INTEGER NOT NULL CHECK (max_tokens > 0),
+    action     TEXT NOT NULL CHECK (action IN ('pause', 'abort')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+ALTER TABLE workspace_summaries ADD COLUMN tokens_used INTEGER NOT NULL DEFAULT 0;
+-- Set when the attempt went over its budget
+ALTER TABLE workspace_summaries ADD COLUMN token_budget INTEGER;
+ALTER TABLE workspace_summaries ADD COLUMN budget_exceeded_at TEXT;
+
+-- Allow budget_exceeded notifications; SQLite can't alter a CHECK, so rebuild the table
+CREATE TABLE notifications_new (
+    id           BLOB PRIMARY KEY,
+    user_id      BLOB NOT NULL,
+    kind         TEXT NOT NULL
+                    CHECK (kind IN ('attempt_completed', 'attempt_failed', 'mention', 'review_requested', 'sync_error', 'issue_comment', 'sla_breach', 'budget_exceeded')),
+    title        TEXT NOT NULL,
+    message      TEXT NOT NULL,
+    project_id   BLOB,
+    task_id      BLOB,
+    workspace_id BLOB,
+    read_at      TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE
+);
+
+INSERT INTO notifications_new (id, user_id, kind, title, message, project_id, task_id, workspace_id, read_at, created_at)
+SELECT id, user_id, kind, title, message, project_id, task_id, workspace_id, read_at, created_at
+FROM notifications;
+
+DROP TABLE notifications;
+ALTER TABLE notifications_new RENAME TO notifications;
+
+CREATE INDEX idx_notifications_user_created ON notifications(user_id, created_at DESC);
+CREATE INDEX idx_notifications_user_unread ON notifications(user_id) WHERE read_at IS NULL;
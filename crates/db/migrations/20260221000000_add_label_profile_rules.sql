@@ -0,0 +1,17 @@
+-- Executor profile to start a project's tasks with when they carry a label,
+-- e.g. a fast model for `bug` and a strong one for `refactor`
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_label_profile_rules (
+    id                  BLOB PRIMARY KEY,
+    project_id          BLOB NOT NULL,
+    label               TEXT NOT NULL COLLATE NOCASE,
+    -- JSON ExecutorProfileId
+    executor_profile_id TEXT NOT NULL,
+    created_at          TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    UNIQUE (project_id, label)
+);
+
+CREATE INDEX idx_project_label_profile_rules_project_id
+    ON project_label_profile_rules(project_id);
@@ -0,0 +1,18 @@
+-- Projects that want a person to confirm each automatic attempt start. With
+-- it on, moving a task to In Progress records a pending start instead.
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN confirm_auto_start INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE pending_starts (
+    id           BLOB PRIMARY KEY,
+    task_id      BLOB NOT NULL UNIQUE,
+    project_id   BLOB NOT NULL,
+    requested_by BLOB,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    FOREIGN KEY (requested_by) REFERENCES users(id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_pending_starts_project_id ON pending_starts(project_id);
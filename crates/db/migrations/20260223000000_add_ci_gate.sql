@@ -0,0 +1,12 @@
+-- Projects that hold tasks out of Done until CI passes on their open pull
+-- and merge requests, and the latest CI result polled for each workspace
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN wait_for_ci INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE workspace_ci_statuses (
+    workspace_id BLOB PRIMARY KEY,
+    status       TEXT NOT NULL CHECK (status IN ('none', 'pending', 'success', 'failure')),
+    checked_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE
+);
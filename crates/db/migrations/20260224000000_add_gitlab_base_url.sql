@@ -0,0 +1,2 @@
+-- Instance URL for projects on a self-hosted GitLab; NULL means gitlab.com
+ALTER TABLE projects ADD COLUMN gitlab_base_url TEXT;
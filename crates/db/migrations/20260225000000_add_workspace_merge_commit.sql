@@ -0,0 +1,2 @@
+-- Commit that landed the workspace branch, from a local merge or a merged PR
+ALTER TABLE workspaces ADD COLUMN merge_commit_sha TEXT;
@@ -0,0 +1,13 @@
+-- Per-project paths left out of diffs, linked-issue reports and PR descriptions
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_diff_exclusions (
+    id         BLOB PRIMARY KEY,
+    project_id BLOB NOT NULL,
+    -- gitignore-style pattern relative to each repo root, e.g. `vendor/` or `*.snap`
+    pattern    TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_project_diff_exclusions_project_id ON project_diff_exclusions(project_id);
@@ -0,0 +1,17 @@
+-- Projects can hold unlabeled issues picked up by sync as drafts, which stay off
+-- the board until someone triages them
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN triage_synced_issues INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE task_drafts (
+    task_id    BLOB PRIMARY KEY,
+    project_id BLOB NOT NULL,
+    -- Provider the issue was synced from, e.g. `GitHub`
+    source     TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_task_drafts_project_id ON task_drafts(project_id);
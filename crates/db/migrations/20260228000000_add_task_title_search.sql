@@ -0,0 +1,31 @@
+-- Trigram index of task titles, used to find tasks that may duplicate a new one.
+-- It keeps its own copy of each title, kept current by the triggers below.
+CREATE VIRTUAL TABLE task_titles_fts USING fts5(
+    task_id UNINDEXED,
+    title,
+    tokenize = 'trigram'
+);
+
+INSERT INTO task_titles_fts (task_id, title) SELECT id, title FROM tasks;
+
+CREATE TRIGGER IF NOT EXISTS trg_task_titles_fts_insert
+AFTER INSERT ON tasks
+FOR EACH ROW
+BEGIN
+    INSERT INTO task_titles_fts (task_id, title) VALUES (NEW.id, NEW.title);
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_task_titles_fts_update
+AFTER UPDATE OF title ON tasks
+FOR EACH ROW WHEN OLD.title IS NOT NEW.title
+BEGIN
+    DELETE FROM task_titles_fts WHERE task_id = OLD.id;
+    INSERT INTO task_titles_fts (task_id, title) VALUES (NEW.id, NEW.title);
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_task_titles_fts_delete
+AFTER DELETE ON tasks
+FOR EACH ROW
+BEGIN
+    DELETE FROM task_titles_fts WHERE task_id = OLD.id;
+END;
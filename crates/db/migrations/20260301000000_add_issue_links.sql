@@ -0,0 +1,69 @@
+-- Tasks imported from an issue tracker were recognised by the
+-- `Imported from <Provider> Issue #<key>` header of their description, which
+-- stops working once someone edits the description. Links are now stored.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE issue_links (
+    task_id        BLOB PRIMARY KEY,
+    -- Provider name as shown to users, e.g. `GitHub`
+    provider       TEXT NOT NULL,
+    -- What the provider's API addresses the issue by
+    external_id    TEXT NOT NULL,
+    url            TEXT NOT NULL,
+    last_synced_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_issue_links_provider_external_id ON issue_links(provider, external_id);
+
+-- Link the tasks imported so far from their description headers. The issue's
+-- key follows the `#` of the header, and its URL is on the second line.
+INSERT INTO issue_links (task_id, provider, external_id, url)
+SELECT id,
+       provider,
+       CASE
+           -- Vortex issues are addressed by the last segment of their URL
+           WHEN provider = 'Vortex' THEN
+               CASE
+                   WHEN instr(url_tail, '?') > 0 THEN substr(url_tail, 1, instr(url_tail, '?') - 1)
+                   ELSE url_tail
+               END
+           ELSE issue_key
+       END,
+       url
+FROM (
+    SELECT id,
+           provider,
+           issue_key,
+           url,
+           trim(substr(url, length(rtrim(url, replace(url, '/', ''))) + 1)) AS url_tail
+    FROM (
+        SELECT id,
+               provider,
+               trim(substr(header, instr(header, '#') + 1)) AS issue_key,
+               trim(substr(rest, 1, instr(rest || char(10), char(10)) - 1)) AS url
+        FROM (
+            SELECT id,
+                   CASE
+                       WHEN header LIKE 'Imported from GitHub Issue #%' THEN 'GitHub'
+                       WHEN header LIKE 'Imported from GitLab Issue #%' THEN 'GitLab'
+                       WHEN header LIKE 'Imported from Gitea Issue #%' THEN 'Gitea'
+                       WHEN header LIKE 'Imported from Vortex Issue #%' THEN 'Vortex'
+                   END AS provider,
+                   header,
+                   substr(description, length(header) + 2) AS rest
+            FROM (
+                SELECT id,
+                       description,
+                       substr(description, 1, instr(description || char(10), char(10)) - 1) AS header
+                FROM tasks
+                WHERE description LIKE 'Imported from % Issue #%'
+            )
+        )
+        WHERE provider IS NOT NULL
+    )
+)
+WHERE CASE
+          WHEN provider = 'Vortex' THEN url_tail <> '' AND url_tail NOT LIKE '?%'
+          ELSE issue_key <> '' AND issue_key NOT GLOB '*[^0-9]*'
+      END;
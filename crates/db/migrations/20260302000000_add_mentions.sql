@@ -0,0 +1,22 @@
+-- `@username` mentions of local users in task descriptions and diff comments.
+-- A source keeps the mentions it currently contains, so editing it only
+-- notifies the users it newly mentions.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE mentions (
+    id                   BLOB PRIMARY KEY,
+    user_id              BLOB NOT NULL,
+    source               TEXT NOT NULL
+                            CHECK (source IN ('task_description', 'diff_comment')),
+    -- The task or diff comment the mention was written in
+    source_id            BLOB NOT NULL,
+    task_id              BLOB NOT NULL,
+    mentioned_by_user_id BLOB,
+    created_at           TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (mentioned_by_user_id) REFERENCES users(id) ON DELETE SET NULL,
+    UNIQUE (source, source_id, user_id)
+);
+
+CREATE INDEX idx_mentions_user_id ON mentions(user_id);
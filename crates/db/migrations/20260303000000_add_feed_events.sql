@@ -0,0 +1,20 @@
+-- Per-user activity feed, written by the feed consumer on the event bus. Rows
+-- are numbered so the feed can be paged with a cursor.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE feed_events (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id      BLOB NOT NULL,
+    kind         TEXT NOT NULL
+                    CHECK (kind IN ('attempt_started', 'attempt_completed', 'attempt_failed',
+                                    'mentioned', 'review_requested', 'queue_started')),
+    title        TEXT NOT NULL,
+    project_id   BLOB,
+    task_id      BLOB,
+    workspace_id BLOB,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_feed_events_user_id ON feed_events(user_id, id);
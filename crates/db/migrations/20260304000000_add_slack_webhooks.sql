@@ -0,0 +1,23 @@
+-- Slack slash commands and interactive messages are verified with the Slack
+-- app's signing secret, stored with the other webhook secrets
+PRAGMA foreign_keys = ON;
+
+-- SQLite can't alter a CHECK, so rebuild project_webhooks to accept 'slack'
+CREATE TABLE project_webhooks_new (
+    project_id BLOB NOT NULL,
+    provider   TEXT NOT NULL CHECK (provider IN ('github', 'gitlab', 'gitea', 'slack')),
+    secret     TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (project_id, provider),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+INSERT INTO project_webhooks_new (project_id, provider, secret, created_at, updated_at)
+SELECT project_id, provider, secret, created_at, updated_at
+FROM project_webhooks;
+
+DROP TABLE project_webhooks;
+ALTER TABLE project_webhooks_new RENAME TO project_webhooks;
+
+CREATE INDEX idx_project_webhooks_provider ON project_webhooks(provider);
@@ -0,0 +1,21 @@
+-- Whether a merged pull or merge request moves its task to Done, which it
+-- always did before, plus the review state and individual CI checks polled for
+-- each open pull or merge request of a workspace
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN done_on_merge INTEGER NOT NULL DEFAULT 1;
+
+CREATE TABLE pull_request_statuses (
+    workspace_id BLOB NOT NULL,
+    -- Web URL of the pull or merge request
+    url          TEXT NOT NULL,
+    review_state TEXT NOT NULL DEFAULT 'none'
+                 CHECK (review_state IN ('none', 'review_required', 'approved', 'changes_requested')),
+    ci_status    TEXT NOT NULL DEFAULT 'none'
+                 CHECK (ci_status IN ('none', 'pending', 'success', 'failure')),
+    -- JSON array of {name, status, url}
+    checks       TEXT NOT NULL DEFAULT '[]',
+    checked_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (workspace_id, url),
+    FOREIGN KEY (workspace_id) REFERENCES workspaces(id) ON DELETE CASCADE
+);
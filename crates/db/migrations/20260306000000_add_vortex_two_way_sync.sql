@@ -0,0 +1,30 @@
+-- Two-way sync of titles and descriptions between tasks and their Vortex
+-- issues. Links remember the content both sides had after the last sync, which
+-- tells which side changed since; when both did, the last edit wins and the
+-- overwritten content is kept in `issue_sync_conflicts`.
+PRAGMA foreign_keys = ON;
+
+ALTER TABLE projects ADD COLUMN vortex_two_way_sync INTEGER NOT NULL DEFAULT 0;
+
+-- NULL until the link's first two-way sync
+ALTER TABLE issue_links ADD COLUMN synced_title TEXT;
+ALTER TABLE issue_links ADD COLUMN synced_description TEXT;
+
+CREATE TABLE issue_sync_conflicts (
+    id                BLOB PRIMARY KEY,
+    task_id           BLOB NOT NULL,
+    provider          TEXT NOT NULL,
+    external_id       TEXT NOT NULL,
+    -- Side whose edit was kept
+    winner            TEXT NOT NULL CHECK (winner IN ('task', 'issue')),
+    -- Both sides as they were before the conflict was resolved. Descriptions
+    -- are the issue body, without the task's import header.
+    task_title        TEXT NOT NULL,
+    task_description  TEXT,
+    issue_title       TEXT NOT NULL,
+    issue_description TEXT,
+    created_at        TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_issue_sync_conflicts_task_id ON issue_sync_conflicts(task_id);
@@ -0,0 +1,10 @@
+-- Secret tokens that publish a read-only summary of a project's board, for
+-- embedding in wikis without signing in
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_status_pages (
+    project_id BLOB PRIMARY KEY,
+    token      TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
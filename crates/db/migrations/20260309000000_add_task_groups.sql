@@ -0,0 +1,34 @@
+-- Groups of tasks tracked together, such as the tasks of a GitHub milestone,
+-- so epics show up on the board with their progress
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE task_groups (
+    id            BLOB PRIMARY KEY,
+    project_id    BLOB NOT NULL,
+    -- Provider name as shown to users, e.g. `GitHub`
+    provider      TEXT NOT NULL,
+    -- What the provider's API addresses the group by, e.g. the milestone number
+    external_id   TEXT NOT NULL,
+    title         TEXT NOT NULL,
+    description   TEXT,
+    url           TEXT NOT NULL,
+    closed        INTEGER NOT NULL DEFAULT 0,
+    due_at        TEXT,
+    -- Issue counts as the provider reports them, including issues without a task
+    open_issues   INTEGER NOT NULL DEFAULT 0,
+    closed_issues INTEGER NOT NULL DEFAULT 0,
+    created_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    UNIQUE (project_id, provider, external_id),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+-- A task belongs to at most one group
+CREATE TABLE task_group_tasks (
+    task_id       BLOB PRIMARY KEY,
+    task_group_id BLOB NOT NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (task_group_id) REFERENCES task_groups(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_task_group_tasks_task_group_id ON task_group_tasks(task_group_id);
@@ -0,0 +1,12 @@
+-- Slack accounts an admin has linked to local users. Slack actions are
+-- attributed by Slack's user id, which its owner can't change, unlike the
+-- username.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE slack_user_links (
+    slack_user_id TEXT PRIMARY KEY,
+    user_id       BLOB NOT NULL UNIQUE,
+    created_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at    TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,22 @@
+-- Keep only a hash of calendar feed and status page tokens, as for kiosk
+-- tokens. The plaintext tokens can't be hashed in SQL, so existing feeds and
+-- pages are dropped and have to be enabled again.
+PRAGMA foreign_keys = ON;
+
+DROP TABLE project_calendar_feeds;
+
+CREATE TABLE project_calendar_feeds (
+    project_id BLOB PRIMARY KEY,
+    token_hash TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+DROP TABLE project_status_pages;
+
+CREATE TABLE project_status_pages (
+    project_id BLOB PRIMARY KEY,
+    token_hash TEXT NOT NULL UNIQUE,
+    created_at TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
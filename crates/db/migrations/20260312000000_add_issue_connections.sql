@@ -0,0 +1,148 @@
+-- Linear, Azure DevOps, Notion, Asana, ClickUp and Shortcut each had a
+-- connection table of their own. They now share one, keyed by provider, with
+-- each provider's settings stored as JSON.
+PRAGMA foreign_keys = ON;
+
+CREATE TABLE project_issue_connections (
+    project_id   BLOB NOT NULL,
+    -- Provider name as shown to users, e.g. `Linear`
+    provider     TEXT NOT NULL,
+    -- API token or integration secret, encrypted
+    token        TEXT NOT NULL,
+    -- Provider's settings, tagged with the provider name
+    settings     TEXT NOT NULL,
+    last_sync_at TEXT,
+    created_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    updated_at   TEXT NOT NULL DEFAULT (datetime('now', 'subsec')),
+    PRIMARY KEY (project_id, provider),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+INSERT INTO project_issue_connections
+    (project_id, provider, token, settings, last_sync_at, created_at, updated_at)
+SELECT project_id,
+       'Linear',
+       api_key,
+       json_object(
+           'provider', 'Linear',
+           'team_id', team_id,
+           'team_key', team_key,
+           'sync_labels', sync_labels,
+           'status_sync_enabled', json(CASE WHEN status_sync_enabled THEN 'true' ELSE 'false' END)
+       ),
+       last_sync_at,
+       created_at,
+       updated_at
+FROM project_linear_connections;
+
+INSERT INTO project_issue_connections
+    (project_id, provider, token, settings, last_sync_at, created_at, updated_at)
+SELECT project_id,
+       'Azure DevOps',
+       pat,
+       json_object(
+           'provider', 'Azure DevOps',
+           'organization_url', organization_url,
+           'azure_project', azure_project,
+           'wiql_filter', wiql_filter
+       ),
+       last_sync_at,
+       created_at,
+       updated_at
+FROM project_azure_connections;
+
+INSERT INTO project_issue_connections
+    (project_id, provider, token, settings, last_sync_at, created_at, updated_at)
+SELECT project_id,
+       'Notion',
+       token,
+       json_object(
+           'provider', 'Notion',
+           'database_id', database_id,
+           'status_property', status_property,
+           'description_property', description_property
+       ),
+       last_sync_at,
+       created_at,
+       updated_at
+FROM project_notion_connections;
+
+INSERT INTO project_issue_connections
+    (project_id, provider, token, settings, last_sync_at, created_at, updated_at)
+SELECT project_id,
+       'Asana',
+       token,
+       json_object('provider', 'Asana', 'project_gid', project_gid),
+       last_sync_at,
+       created_at,
+       updated_at
+FROM project_asana_connections;
+
+INSERT INTO project_issue_connections
+    (project_id, provider, token, settings, last_sync_at, created_at, updated_at)
+SELECT project_id,
+       'ClickUp',
+       token,
+       json_object('provider', 'ClickUp', 'list_id', list_id, 'tag_filter', tag_filter),
+       last_sync_at,
+       created_at,
+       updated_at
+FROM project_clickup_connections;
+
+INSERT INTO project_issue_connections
+    (project_id, provider, token, settings, last_sync_at, created_at, updated_at)
+SELECT project_id,
+       'Shortcut',
+       token,
+       json_object('provider', 'Shortcut', 'labels', labels, 'review_state_id', review_state_id),
+       last_sync_at,
+       created_at,
+       updated_at
+FROM project_shortcut_connections;
+
+DROP TABLE project_linear_connections;
+DROP TABLE project_azure_connections;
+DROP TABLE project_notion_connections;
+DROP TABLE project_asana_connections;
+DROP TABLE project_clickup_connections;
+DROP TABLE project_shortcut_connections;
+
+-- Tasks imported from these providers were recognised by the header of their
+-- description. Link the ones imported so far: the id follows the header's
+-- prefix, and the URL is on the second line.
+WITH prefixes (provider, prefix, numeric) AS (
+    VALUES ('Linear', 'Imported from Linear Issue ', 0),
+           ('Azure DevOps', 'Imported from Azure DevOps Work Item #', 1),
+           ('Notion', 'Imported from Notion page ', 0),
+           ('Asana', 'Imported from Asana task ', 0),
+           ('ClickUp', 'Imported from ClickUp task ', 0),
+           ('Shortcut', 'Imported from Shortcut story ', 1)
+)
+INSERT INTO issue_links (task_id, provider, external_id, url)
+SELECT id, provider, external_id, url
+FROM (
+    SELECT id,
+           provider,
+           numeric,
+           trim(substr(header, length(prefix) + 1)) AS external_id,
+           trim(substr(rest, 1, instr(rest || char(10), char(10)) - 1)) AS url
+    FROM (
+        SELECT t.id,
+               p.provider,
+               p.prefix,
+               p.numeric,
+               t.header,
+               substr(t.description, length(t.header) + 2) AS rest
+        FROM (
+            SELECT id,
+                   description,
+                   substr(description, 1, instr(description || char(10), char(10)) - 1) AS header
+            FROM tasks
+            WHERE description LIKE 'Imported from %'
+              AND id NOT IN (SELECT task_id FROM issue_links)
+        ) t
+        JOIN prefixes p ON substr(t.header, 1, length(p.prefix)) = p.prefix
+    )
+)
+WHERE external_id <> ''
+  AND (numeric = 0 OR external_id NOT GLOB '*[^0-9]*');
@@ -1,28 +1,38 @@
-use std::{str::FromStr, sync::Arc};
+use std::{future::Future, str::FromStr, sync::Arc, time::Duration};
 
 use sqlx::{
     Error, Pool, Sqlite, SqlitePool,
-    sqlite::{SqliteConnectOptions, SqliteConnection, SqlitePoolOptions},
+    sqlite::{
+        SqliteConnectOptions, SqliteConnection, SqliteJournalMode, SqlitePoolOptions,
+        SqliteSynchronous,
+    },
 };
+use tokio::sync::Mutex;
 use utils::assets::asset_dir;
 
 pub mod models;
+pub mod stats;
+
+/// How long a connection waits on a locked database before SQLite returns SQLITE_BUSY
+const BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Attempts made by `DBService::write` before a busy error is returned to the caller
+const BUSY_RETRY_ATTEMPTS: u32 = 5;
+const BUSY_RETRY_BASE_DELAY: Duration = Duration::from_millis(50);
 
 #[derive(Clone)]
 pub struct DBService {
     pub pool: Pool<Sqlite>,
+    /// Serialises writes issued through `write` so hot paths queue up in-process
+    /// instead of contending for SQLite's write lock
+    writer: Arc<Mutex<()>>,
 }
 
 impl DBService {
     pub async fn new() -> Result<DBService, Error> {
-        let database_url = format!(
-            "sqlite://{}",
-            asset_dir().join("db.sqlite").to_string_lossy()
-        );
-        let options = SqliteConnectOptions::from_str(&database_url)?.create_if_missing(true);
-        let pool = SqlitePool::connect_with(options).await?;
+        let pool = SqlitePool::connect_with(Self::connect_options()?).await?;
         sqlx::migrate!("./migrations").run(&pool).await?;
-        Ok(DBService { pool })
+        Self::ensure_wal(&pool).await?;
+        Ok(DBService::from_pool(pool))
     }
 
     pub async fn new_with_after_connect<F>(after_connect: F) -> Result<DBService, Error>
@@ -36,7 +46,97 @@ impl DBService {
             + 'static,
     {
         let pool = Self::create_pool(Some(Arc::new(after_connect))).await?;
-        Ok(DBService { pool })
+        Ok(DBService::from_pool(pool))
+    }
+
+    fn from_pool(pool: Pool<Sqlite>) -> Self {
+        DBService {
+            pool,
+            writer: Arc::new(Mutex::new(())),
+        }
+    }
+
+    fn connect_options() -> Result<SqliteConnectOptions, Error> {
+        let database_url = format!(
+            "sqlite://{}",
+            asset_dir().join("db.sqlite").to_string_lossy()
+        );
+        Ok(SqliteConnectOptions::from_str(&database_url)?
+            .create_if_missing(true)
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal)
+            .busy_timeout(BUSY_TIMEOUT))
+    }
+
+    /// WAL is persistent per database file, but it can silently fail to apply (e.g. on
+    /// network filesystems), in which case readers and writers block each other.
+    async fn ensure_wal(pool: &Pool<Sqlite>) -> Result<(), Error> {
+        let mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+            .fetch_one(pool)
+            .await?;
+        if !mode.eq_ignore_ascii_case("wal") {
+            tracing::warn!(
+                "SQLite journal_mode is '{}' instead of WAL; expect lock contention",
+                mode
+            );
+        }
+        Ok(())
+    }
+
+    /// Run a write on the single-writer queue, retrying with backoff while SQLite
+    /// reports the database as busy or locked. Calls must not be nested.
+    pub async fn write<T, F, Fut>(&self, op: F) -> Result<T, Error>
+    where
+        F: FnMut(SqlitePool) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let _guard = self.writer.lock().await;
+        self.retry_busy(op).await
+    }
+
+    /// Retry `op` while it fails with SQLITE_BUSY / SQLITE_LOCKED, without queueing
+    pub async fn retry_busy<T, F, Fut>(&self, mut op: F) -> Result<T, Error>
+    where
+        F: FnMut(SqlitePool) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match op(self.pool.clone()).await {
+                Err(e) if is_busy_error(&e) && attempt < BUSY_RETRY_ATTEMPTS => {
+                    let delay = BUSY_RETRY_BASE_DELAY * 2u32.pow(attempt - 1);
+                    tracing::debug!(
+                        "Database busy (attempt {}/{}), retrying in {:?}",
+                        attempt,
+                        BUSY_RETRY_ATTEMPTS,
+                        delay
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Rebuild the database file to reclaim free pages, then truncate the WAL.
+    /// Returns the number of bytes given back to the filesystem.
+    pub async fn vacuum(&self) -> Result<i64, Error> {
+        let before = stats::DatabaseStats::collect(&self.pool)
+            .await?
+            .file_size_bytes;
+        self.write(|pool| async move {
+            sqlx::query("VACUUM").execute(&pool).await?;
+            sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+                .execute(&pool)
+                .await?;
+            sqlx::query("PRAGMA optimize").execute(&pool).await
+        })
+        .await?;
+        let after = stats::DatabaseStats::collect(&self.pool)
+            .await?
+            .file_size_bytes;
+        Ok((before - after).max(0))
     }
 
     async fn create_pool<F>(after_connect: Option<Arc<F>>) -> Result<Pool<Sqlite>, Error>
@@ -49,11 +149,7 @@ impl DBService {
             + Sync
             + 'static,
     {
-        let database_url = format!(
-            "sqlite://{}",
-            asset_dir().join("db.sqlite").to_string_lossy()
-        );
-        let options = SqliteConnectOptions::from_str(&database_url)?.create_if_missing(true);
+        let options = Self::connect_options()?;
 
         let pool = if let Some(hook) = after_connect {
             SqlitePoolOptions::new()
@@ -71,6 +167,21 @@ impl DBService {
         };
 
         sqlx::migrate!("./migrations").run(&pool).await?;
+        Self::ensure_wal(&pool).await?;
         Ok(pool)
     }
 }
+
+/// Whether `err` is SQLITE_BUSY or SQLITE_LOCKED, including their extended codes
+pub fn is_busy_error(err: &Error) -> bool {
+    const SQLITE_BUSY: i32 = 5;
+    const SQLITE_LOCKED: i32 = 6;
+
+    let Error::Database(db_err) = err else {
+        return false;
+    };
+    db_err
+        .code()
+        .and_then(|code| code.parse::<i32>().ok())
+        .is_some_and(|code| matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED))
+}
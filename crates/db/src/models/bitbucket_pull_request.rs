@@ -0,0 +1,88 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A Bitbucket Cloud pull request opened for a workspace branch. `state` is
+/// Bitbucket's own value (`OPEN`, `MERGED`, `DECLINED` or `SUPERSEDED`).
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct BitbucketPullRequest {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub repo_id: Uuid,
+    pub pr_id: i64,
+    pub web_url: String,
+    pub state: String,
+    pub target_branch: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BitbucketPullRequest {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            BitbucketPullRequest,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      pr_id,
+                      web_url,
+                      state,
+                      target_branch,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM bitbucket_pull_requests
+               WHERE workspace_id = $1
+               ORDER BY created_at ASC"#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Record a pull request, replacing any earlier one for the same repo
+    pub async fn upsert(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        repo_id: Uuid,
+        pr_id: i64,
+        web_url: &str,
+        state: &str,
+        target_branch: &str,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            BitbucketPullRequest,
+            r#"INSERT INTO bitbucket_pull_requests (id, workspace_id, repo_id, pr_id, web_url, state, target_branch)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)
+               ON CONFLICT (workspace_id, repo_id) DO UPDATE
+               SET pr_id = excluded.pr_id,
+                   web_url = excluded.web_url,
+                   state = excluded.state,
+                   target_branch = excluded.target_branch,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING id as "id!: Uuid",
+                         workspace_id as "workspace_id!: Uuid",
+                         repo_id as "repo_id!: Uuid",
+                         pr_id,
+                         web_url,
+                         state,
+                         target_branch,
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            workspace_id,
+            repo_id,
+            pr_id,
+            web_url,
+            state,
+            target_branch
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
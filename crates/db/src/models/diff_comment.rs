@@ -0,0 +1,170 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A review comment on a workspace diff. Comments without a line number apply to the whole file.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct DiffComment {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub repo_id: Uuid,
+    pub file_path: String,
+    pub line_number: Option<i64>,
+    pub body: String,
+    pub author_user_id: Option<Uuid>,
+    pub resolved: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateDiffComment {
+    pub repo_id: Uuid,
+    pub file_path: String,
+    pub line_number: Option<i64>,
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct UpdateDiffComment {
+    pub body: Option<String>,
+    pub resolved: Option<bool>,
+}
+
+impl DiffComment {
+    pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            DiffComment,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      file_path,
+                      line_number,
+                      body,
+                      author_user_id as "author_user_id: Uuid",
+                      resolved as "resolved!: bool",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM diff_comments
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Comments on a workspace in file and line order
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            DiffComment,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      file_path,
+                      line_number,
+                      body,
+                      author_user_id as "author_user_id: Uuid",
+                      resolved as "resolved!: bool",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM diff_comments
+               WHERE workspace_id = $1
+               ORDER BY repo_id, file_path, line_number ASC NULLS FIRST, created_at ASC"#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        author_user_id: Option<Uuid>,
+        data: &CreateDiffComment,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            DiffComment,
+            r#"INSERT INTO diff_comments (id, workspace_id, repo_id, file_path, line_number, body, author_user_id)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)
+               RETURNING id as "id!: Uuid",
+                         workspace_id as "workspace_id!: Uuid",
+                         repo_id as "repo_id!: Uuid",
+                         file_path,
+                         line_number,
+                         body,
+                         author_user_id as "author_user_id: Uuid",
+                         resolved as "resolved!: bool",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            workspace_id,
+            data.repo_id,
+            data.file_path,
+            data.line_number,
+            data.body,
+            author_user_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update(
+        pool: &SqlitePool,
+        existing: &Self,
+        data: &UpdateDiffComment,
+    ) -> Result<Self, sqlx::Error> {
+        let body = data.body.as_deref().unwrap_or(&existing.body);
+        let resolved = data.resolved.unwrap_or(existing.resolved);
+        sqlx::query_as!(
+            DiffComment,
+            r#"UPDATE diff_comments
+               SET body = $2,
+                   resolved = $3,
+                   updated_at = datetime('now', 'subsec')
+               WHERE id = $1
+               RETURNING id as "id!: Uuid",
+                         workspace_id as "workspace_id!: Uuid",
+                         repo_id as "repo_id!: Uuid",
+                         file_path,
+                         line_number,
+                         body,
+                         author_user_id as "author_user_id: Uuid",
+                         resolved as "resolved!: bool",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            existing.id,
+            body,
+            resolved
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Mark comments as resolved, e.g. once they were sent to the agent
+    pub async fn resolve_many(pool: &SqlitePool, ids: &[Uuid]) -> Result<(), sqlx::Error> {
+        for id in ids {
+            sqlx::query!(
+                r#"UPDATE diff_comments
+                   SET resolved = 1, updated_at = datetime('now', 'subsec')
+                   WHERE id = $1"#,
+                id
+            )
+            .execute(pool)
+            .await?;
+        }
+        Ok(())
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM diff_comments WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -91,7 +91,7 @@ pub struct UpdateExecutionProcess {
     pub completed_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ExecutionContext {
     pub execution_process: ExecutionProcess,
     pub session: Session,
@@ -108,6 +108,33 @@ pub enum ExecutorActionField {
     Other(Value),
 }
 
+/// A coding agent run on one of a project's tasks
+#[derive(Debug, Clone, FromRow)]
+pub struct ProjectAgentRun {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub status: ExecutionProcessStatus,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// How one of a project's attempts went, keyed by the profile its first
+/// coding agent run used
+#[derive(Debug, Clone)]
+pub struct AgentAttemptOutcome {
+    pub workspace_id: Uuid,
+    pub executor_profile_id: ExecutorProfileId,
+    /// Initial run plus follow-ups
+    pub agent_runs: i64,
+    pub failed_runs: i64,
+    /// Minutes the agent spent running, across all its runs
+    pub agent_minutes: f64,
+    /// Merged directly or through a merged PR
+    pub merged: bool,
+    pub task_done: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct MissingBeforeContext {
     pub id: Uuid,
@@ -292,6 +319,87 @@ impl ExecutionProcess {
         .await
     }
 
+    /// Coding agent runs on a project's tasks that started after `since`, oldest first
+    pub async fn find_agent_runs_by_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<ProjectAgentRun>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectAgentRun,
+            r#"SELECT ep.id as "id!: Uuid",
+                      t.id as "task_id!: Uuid",
+                      t.title as "task_title!",
+                      ep.status as "status!: ExecutionProcessStatus",
+                      ep.started_at as "started_at!: DateTime<Utc>",
+                      ep.completed_at as "completed_at?: DateTime<Utc>"
+               FROM execution_processes ep
+               JOIN sessions s ON ep.session_id = s.id
+               JOIN workspaces w ON s.workspace_id = w.id
+               JOIN tasks t ON w.task_id = t.id
+               WHERE t.project_id = $1
+                 AND ep.run_reason = 'codingagent'
+                 AND ep.started_at >= $2
+               ORDER BY ep.started_at ASC"#,
+            project_id,
+            since
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Outcomes of the project's attempts that ran a coding agent. Attempts
+    /// whose profile can't be read are left out.
+    pub async fn find_attempt_outcomes_by_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<AgentAttemptOutcome>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"SELECT w.id as "workspace_id!: Uuid",
+                      (SELECT json_extract(f.executor_action, '$.typ.executor_profile_id')
+                       FROM execution_processes f
+                       JOIN sessions fs ON f.session_id = fs.id
+                       WHERE fs.workspace_id = w.id AND f.run_reason = 'codingagent'
+                       ORDER BY f.created_at ASC
+                       LIMIT 1) as "profile: String",
+                      COUNT(ep.id) as "agent_runs!: i64",
+                      COALESCE(SUM(ep.status = 'failed'), 0) as "failed_runs!: i64",
+                      COALESCE(SUM((julianday(ep.completed_at) - julianday(ep.started_at)) * 1440), 0.0)
+                          as "agent_minutes!: f64",
+                      EXISTS (SELECT 1 FROM merges m
+                              WHERE m.workspace_id = w.id
+                                AND (m.merge_type = 'direct' OR m.pr_status = 'merged'))
+                          as "merged!: bool",
+                      t.status = 'done' as "task_done!: bool"
+               FROM workspaces w
+               JOIN tasks t ON w.task_id = t.id
+               JOIN sessions s ON s.workspace_id = w.id
+               JOIN execution_processes ep
+                    ON ep.session_id = s.id AND ep.run_reason = 'codingagent'
+               WHERE t.project_id = $1
+               GROUP BY w.id"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let executor_profile_id = serde_json::from_str(row.profile.as_deref()?).ok()?;
+                Some(AgentAttemptOutcome {
+                    workspace_id: row.workspace_id,
+                    executor_profile_id,
+                    agent_runs: row.agent_runs,
+                    failed_runs: row.failed_runs,
+                    agent_minutes: row.agent_minutes,
+                    merged: row.merged,
+                    task_done: row.task_done,
+                })
+            })
+            .collect())
+    }
+
     /// Check if there are running processes (excluding dev servers) for a workspace (across all sessions)
     pub async fn has_running_non_dev_server_processes_for_workspace(
         pool: &SqlitePool,
@@ -311,6 +419,26 @@ impl ExecutionProcess {
         Ok(count > 0)
     }
 
+    /// Check if any attempt for a task has running processes (excluding dev servers)
+    pub async fn has_running_non_dev_server_processes_for_task(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let count: i64 = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64"
+               FROM execution_processes ep
+               JOIN sessions s ON ep.session_id = s.id
+               JOIN workspaces w ON s.workspace_id = w.id
+               WHERE w.task_id = $1
+                 AND ep.status = 'running'
+                 AND ep.run_reason != 'devserver'"#,
+            task_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(count > 0)
+    }
+
     /// Check if there are any running processes for a workspace (including dev servers)
     pub async fn has_running_processes_for_workspace(
         pool: &SqlitePool,
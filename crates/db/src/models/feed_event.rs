@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "feed_event_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FeedEventKind {
+    AttemptStarted,
+    AttemptCompleted,
+    AttemptFailed,
+    Mentioned,
+    ReviewRequested,
+    /// A sequential task was started from the project's queue
+    QueueStarted,
+}
+
+/// Something relevant to a user: activity on tasks they created or are
+/// assigned to, and mentions and review requests addressed to them
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct FeedEvent {
+    /// Increases with every event, so it doubles as the pagination cursor
+    #[ts(type = "number")]
+    pub id: i64,
+    pub user_id: Uuid,
+    pub kind: FeedEventKind,
+    pub title: String,
+    pub project_id: Option<Uuid>,
+    pub task_id: Option<Uuid>,
+    pub workspace_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateFeedEvent {
+    pub kind: FeedEventKind,
+    pub title: String,
+    pub project_id: Option<Uuid>,
+    pub task_id: Option<Uuid>,
+    pub workspace_id: Option<Uuid>,
+}
+
+impl FeedEvent {
+    /// A user's events older than the `before` cursor, newest first
+    pub async fn find_by_user_id(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        before: Option<i64>,
+        limit: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            FeedEvent,
+            r#"SELECT id as "id!: i64",
+                      user_id as "user_id!: Uuid",
+                      kind as "kind!: FeedEventKind",
+                      title,
+                      project_id as "project_id: Uuid",
+                      task_id as "task_id: Uuid",
+                      workspace_id as "workspace_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM feed_events
+               WHERE user_id = $1 AND ($2 IS NULL OR id < $2)
+               ORDER BY id DESC
+               LIMIT $3"#,
+            user_id,
+            before,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        data: &CreateFeedEvent,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO feed_events (user_id, kind, title, project_id, task_id, workspace_id)
+               VALUES ($1, $2, $3, $4, $5, $6)"#,
+            user_id,
+            data.kind,
+            data.title,
+            data.project_id,
+            data.task_id,
+            data.workspace_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
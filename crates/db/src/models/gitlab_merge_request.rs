@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A GitLab merge request opened for a workspace branch. `state` is GitLab's own
+/// value (`opened`, `merged`, `closed` or `locked`).
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct GitLabMergeRequest {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub repo_id: Uuid,
+    pub mr_iid: i64,
+    pub web_url: String,
+    pub state: String,
+    pub target_branch: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl GitLabMergeRequest {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            GitLabMergeRequest,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      mr_iid,
+                      web_url,
+                      state,
+                      target_branch,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM gitlab_merge_requests
+               WHERE workspace_id = $1
+               ORDER BY created_at ASC"#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Merge requests last seen open, across all workspaces
+    pub async fn find_open(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            GitLabMergeRequest,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      mr_iid,
+                      web_url,
+                      state,
+                      target_branch,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM gitlab_merge_requests
+               WHERE state = 'opened'
+               ORDER BY created_at ASC"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Record a merge request, replacing any earlier one for the same repo
+    pub async fn upsert(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        repo_id: Uuid,
+        mr_iid: i64,
+        web_url: &str,
+        state: &str,
+        target_branch: &str,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            GitLabMergeRequest,
+            r#"INSERT INTO gitlab_merge_requests (id, workspace_id, repo_id, mr_iid, web_url, state, target_branch)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)
+               ON CONFLICT (workspace_id, repo_id) DO UPDATE
+               SET mr_iid = excluded.mr_iid,
+                   web_url = excluded.web_url,
+                   state = excluded.state,
+                   target_branch = excluded.target_branch,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING id as "id!: Uuid",
+                         workspace_id as "workspace_id!: Uuid",
+                         repo_id as "repo_id!: Uuid",
+                         mr_iid,
+                         web_url,
+                         state,
+                         target_branch,
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            workspace_id,
+            repo_id,
+            mr_iid,
+            web_url,
+            state,
+            target_branch
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update_state(pool: &SqlitePool, id: Uuid, state: &str) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE gitlab_merge_requests
+               SET state = $2, updated_at = datetime('now', 'subsec')
+               WHERE id = $1"#,
+            id,
+            state
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
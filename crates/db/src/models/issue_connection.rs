@@ -0,0 +1,246 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, types::Json};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A project's connection to an issue tracker configured through an API token
+/// rather than project settings, one per provider. `token` holds ciphertext;
+/// callers encrypt and decrypt it.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct IssueConnection {
+    pub project_id: Uuid,
+    /// Provider name as shown to users, e.g. `Linear`
+    pub provider: String,
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    pub token: String,
+    #[ts(type = "IssueConnectionSettings")]
+    pub settings: Json<IssueConnectionSettings>,
+    #[ts(type = "string | null")]
+    pub last_sync_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// What a connection points at, and how it syncs, by provider
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(tag = "provider")]
+pub enum IssueConnectionSettings {
+    Linear(LinearSettings),
+    #[serde(rename = "Azure DevOps")]
+    AzureDevOps(AzureDevOpsSettings),
+    Notion(NotionSettings),
+    Asana(AsanaSettings),
+    ClickUp(ClickUpSettings),
+    Shortcut(ShortcutSettings),
+}
+
+impl IssueConnectionSettings {
+    /// Name of the provider, as the connection is keyed by
+    pub fn provider(&self) -> &'static str {
+        match self {
+            Self::Linear(_) => "Linear",
+            Self::AzureDevOps(_) => "Azure DevOps",
+            Self::Notion(_) => "Notion",
+            Self::Asana(_) => "Asana",
+            Self::ClickUp(_) => "ClickUp",
+            Self::Shortcut(_) => "Shortcut",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct LinearSettings {
+    pub team_id: String,
+    /// Prefix of the team's issue identifiers, e.g. `ENG`; filled in on save
+    #[serde(default)]
+    pub team_key: String,
+    /// Comma-separated labels; only issues with one of them are imported
+    #[serde(default)]
+    pub sync_labels: Option<String>,
+    /// Move the linked Linear issue when its task moves to In Progress, In
+    /// Review or Done
+    #[serde(default = "default_true")]
+    pub status_sync_enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AzureDevOpsSettings {
+    /// `https://dev.azure.com/{organization}` or a server collection URL
+    pub organization_url: String,
+    pub azure_project: String,
+    /// Extra WIQL condition narrowing which work items are imported
+    #[serde(default)]
+    pub wiql_filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct NotionSettings {
+    pub database_id: String,
+    /// Name of the property holding each row's status
+    #[serde(default = "default_status_property")]
+    pub status_property: String,
+    /// Name of the property holding each row's description
+    #[serde(default = "default_description_property")]
+    pub description_property: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AsanaSettings {
+    pub project_gid: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ClickUpSettings {
+    pub list_id: String,
+    /// Comma-separated tags; only tasks with one of them are listed and synced
+    #[serde(default)]
+    pub tag_filter: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ShortcutSettings {
+    /// Comma-separated labels; stories with any of them are imported
+    #[serde(default)]
+    pub labels: Option<String>,
+    /// Workflow state stories move to when their task goes to review; when
+    /// unset, a state named like "review" in the story's workflow is used
+    #[serde(default)]
+    #[ts(type = "number | null")]
+    pub review_state_id: Option<i64>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_status_property() -> String {
+    "Status".to_string()
+}
+
+fn default_description_property() -> String {
+    "Description".to_string()
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct UpsertIssueConnection {
+    /// Required when connecting; omit to keep the stored token
+    #[serde(default)]
+    #[ts(optional)]
+    pub token: Option<String>,
+    pub settings: IssueConnectionSettings,
+}
+
+impl IssueConnection {
+    pub async fn find(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            IssueConnection,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      provider,
+                      token,
+                      settings as "settings!: Json<IssueConnectionSettings>",
+                      last_sync_at as "last_sync_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_issue_connections
+               WHERE project_id = $1 AND provider = $2"#,
+            project_id,
+            provider
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            IssueConnection,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      provider,
+                      token,
+                      settings as "settings!: Json<IssueConnectionSettings>",
+                      last_sync_at as "last_sync_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_issue_connections
+               WHERE project_id = $1
+               ORDER BY provider"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Connect the project to the settings' provider, replacing its previous
+    /// connection there. `encrypted_token` replaces the stored token.
+    pub async fn upsert(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        encrypted_token: &str,
+        settings: &IssueConnectionSettings,
+    ) -> Result<Self, sqlx::Error> {
+        let provider = settings.provider();
+        let settings = Json(settings);
+        sqlx::query_as!(
+            IssueConnection,
+            r#"INSERT INTO project_issue_connections (project_id, provider, token, settings)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(project_id, provider) DO UPDATE SET
+                   token = excluded.token,
+                   settings = excluded.settings,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid",
+                         provider,
+                         token,
+                         settings as "settings!: Json<IssueConnectionSettings>",
+                         last_sync_at as "last_sync_at: DateTime<Utc>",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            project_id,
+            provider,
+            encrypted_token,
+            settings
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update_last_sync(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE project_issue_connections
+               SET last_sync_at = datetime('now', 'subsec')
+               WHERE project_id = $1 AND provider = $2"#,
+            project_id,
+            provider
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: &str,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_issue_connections WHERE project_id = $1 AND provider = $2",
+            project_id,
+            provider
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+/// The tracker issue a task was imported from
+#[derive(Debug, Clone, FromRow)]
+pub struct IssueLink {
+    pub task_id: Uuid,
+    /// Provider name as shown to users, e.g. `GitHub`
+    pub provider: String,
+    /// What the provider's API addresses the issue by
+    pub external_id: String,
+    pub url: String,
+    /// Last time a sync saw the issue
+    pub last_synced_at: DateTime<Utc>,
+    /// Title both sides had after the last two-way sync
+    pub synced_title: Option<String>,
+    /// Issue body both sides had after the last two-way sync
+    pub synced_description: Option<String>,
+}
+
+impl IssueLink {
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            IssueLink,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      provider,
+                      external_id,
+                      url,
+                      last_synced_at as "last_synced_at!: DateTime<Utc>",
+                      synced_title,
+                      synced_description
+               FROM issue_links
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Links of the project's tasks to issues of `provider`
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: &str,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            IssueLink,
+            r#"SELECT l.task_id as "task_id!: Uuid",
+                      l.provider,
+                      l.external_id,
+                      l.url,
+                      l.last_synced_at as "last_synced_at!: DateTime<Utc>",
+                      l.synced_title,
+                      l.synced_description
+               FROM issue_links l
+               JOIN tasks t ON t.id = l.task_id
+               WHERE t.project_id = $1 AND l.provider = $2"#,
+            project_id,
+            provider
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Record the issue a task was imported from, replacing any earlier link
+    pub async fn create(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        provider: &str,
+        external_id: &str,
+        url: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO issue_links (task_id, provider, external_id, url)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   provider = excluded.provider,
+                   external_id = excluded.external_id,
+                   url = excluded.url,
+                   synced_title = NULL,
+                   synced_description = NULL,
+                   last_synced_at = datetime('now', 'subsec')"#,
+            task_id,
+            provider,
+            external_id,
+            url
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn mark_synced(pool: &SqlitePool, task_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE issue_links SET last_synced_at = datetime('now', 'subsec') WHERE task_id = $1",
+            task_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record the content a two-way sync left on both sides
+    pub async fn record_content_synced(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        title: &str,
+        description: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE issue_links
+               SET synced_title = $2,
+                   synced_description = $3,
+                   last_synced_at = datetime('now', 'subsec')
+               WHERE task_id = $1"#,
+            task_id,
+            title,
+            description
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Side of a two-way sync whose edit was kept
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "issue_sync_side", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum IssueSyncSide {
+    Task,
+    Issue,
+}
+
+/// A task and its issue that were both edited between two syncs. The side
+/// edited last was kept; both versions are recorded so the other can be
+/// recovered.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct IssueSyncConflict {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    /// Provider name as shown to users, e.g. `Vortex`
+    pub provider: String,
+    pub external_id: String,
+    pub winner: IssueSyncSide,
+    pub task_title: String,
+    /// The task's description without its import header
+    pub task_description: Option<String>,
+    pub issue_title: String,
+    pub issue_description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateIssueSyncConflict<'a> {
+    pub task_id: Uuid,
+    pub provider: &'a str,
+    pub external_id: &'a str,
+    pub winner: IssueSyncSide,
+    pub task_title: &'a str,
+    pub task_description: Option<&'a str>,
+    pub issue_title: &'a str,
+    pub issue_description: Option<&'a str>,
+}
+
+impl IssueSyncConflict {
+    pub async fn create(
+        pool: &SqlitePool,
+        data: &CreateIssueSyncConflict<'_>,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            IssueSyncConflict,
+            r#"INSERT INTO issue_sync_conflicts
+                   (id, task_id, provider, external_id, winner, task_title, task_description,
+                    issue_title, issue_description)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+               RETURNING id as "id!: Uuid",
+                         task_id as "task_id!: Uuid",
+                         provider,
+                         external_id,
+                         winner as "winner!: IssueSyncSide",
+                         task_title,
+                         task_description,
+                         issue_title,
+                         issue_description,
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            data.task_id,
+            data.provider,
+            data.external_id,
+            data.winner,
+            data.task_title,
+            data.task_description,
+            data.issue_title,
+            data.issue_description
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Conflicts resolved on the project's tasks, newest first
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            IssueSyncConflict,
+            r#"SELECT c.id as "id!: Uuid",
+                      c.task_id as "task_id!: Uuid",
+                      c.provider,
+                      c.external_id,
+                      c.winner as "winner!: IssueSyncSide",
+                      c.task_title,
+                      c.task_description,
+                      c.issue_title,
+                      c.issue_description,
+                      c.created_at as "created_at!: DateTime<Utc>"
+               FROM issue_sync_conflicts c
+               JOIN tasks t ON t.id = c.task_id
+               WHERE t.project_id = $1
+               ORDER BY c.created_at DESC
+               LIMIT $2"#,
+            project_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,104 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A read-only token for a wallboard display. The token itself is only shown
+/// once, when created; the database keeps its hash.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct KioskToken {
+    pub id: Uuid,
+    pub name: String,
+    pub created_by_user_id: Option<Uuid>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateKioskToken {
+    pub name: String,
+}
+
+impl KioskToken {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            KioskToken,
+            r#"SELECT id as "id!: Uuid",
+                      name,
+                      created_by_user_id as "created_by_user_id: Uuid",
+                      last_used_at as "last_used_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM kiosk_tokens
+               ORDER BY created_at"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_token_hash(
+        pool: &SqlitePool,
+        token_hash: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            KioskToken,
+            r#"SELECT id as "id!: Uuid",
+                      name,
+                      created_by_user_id as "created_by_user_id: Uuid",
+                      last_used_at as "last_used_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM kiosk_tokens
+               WHERE token_hash = $1"#,
+            token_hash
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        name: &str,
+        token_hash: &str,
+        created_by_user_id: Option<Uuid>,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            KioskToken,
+            r#"INSERT INTO kiosk_tokens (id, name, token_hash, created_by_user_id)
+               VALUES ($1, $2, $3, $4)
+               RETURNING id as "id!: Uuid",
+                         name,
+                         created_by_user_id as "created_by_user_id: Uuid",
+                         last_used_at as "last_used_at: DateTime<Utc>",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            name,
+            token_hash,
+            created_by_user_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Record that the token was used. Wallboards poll constantly, so this
+    /// writes at most once a minute per token.
+    pub async fn touch(pool: &SqlitePool, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE kiosk_tokens
+               SET last_used_at = datetime('now', 'subsec')
+               WHERE id = $1
+                 AND (last_used_at IS NULL OR last_used_at < datetime('now', '-1 minute'))"#,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM kiosk_tokens WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
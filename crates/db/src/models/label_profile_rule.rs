@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use executors::profile::ExecutorProfileId;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, types::Json};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Start a project's tasks that carry `label` with a given executor profile.
+/// When a task matches several rules, the oldest one wins.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct LabelProfileRule {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub label: String,
+    #[ts(type = "ExecutorProfileId")]
+    pub executor_profile_id: Json<ExecutorProfileId>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct UpsertLabelProfileRule {
+    pub label: String,
+    pub executor_profile_id: ExecutorProfileId,
+}
+
+impl LabelProfileRule {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            LabelProfileRule,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      label,
+                      executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_label_profile_rules
+               WHERE project_id = $1
+               ORDER BY created_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Add a rule, or point an existing rule for the same label at a new profile
+    pub async fn upsert(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        label: &str,
+        executor_profile_id: &ExecutorProfileId,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        let profile = Json(executor_profile_id);
+        sqlx::query_as!(
+            LabelProfileRule,
+            r#"INSERT INTO project_label_profile_rules (id, project_id, label, executor_profile_id)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(project_id, label) DO UPDATE SET
+                   executor_profile_id = excluded.executor_profile_id
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         label,
+                         executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            project_id,
+            label,
+            profile
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_label_profile_rules WHERE id = $1 AND project_id = $2",
+            id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// The profile the project's rules pick for the task from its labels
+    pub async fn profile_for_task(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<Option<ExecutorProfileId>, sqlx::Error> {
+        let profile = sqlx::query_scalar!(
+            r#"SELECT r.executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>"
+               FROM project_label_profile_rules r
+               JOIN task_labels tl ON tl.name = r.label
+               WHERE r.project_id = $1 AND tl.task_id = $2
+               ORDER BY r.created_at
+               LIMIT 1"#,
+            project_id,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(profile.map(|profile| profile.0))
+    }
+}
@@ -0,0 +1,221 @@
+use chrono::{DateTime, Utc};
+use executors::profile::ExecutorProfileId;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, types::Json};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A recurring agent job for a project. When its schedule comes due and no
+/// other agent is running, the scheduler creates a task from it and starts an
+/// attempt with its profile.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct MaintenanceJob {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Title of the tasks created for each run
+    pub name: String,
+    pub prompt: String,
+    /// Five-field cron expression, evaluated in UTC
+    pub schedule: String,
+    #[ts(type = "ExecutorProfileId")]
+    pub executor_profile_id: Json<ExecutorProfileId>,
+    pub enabled: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// Task created by the most recent run
+    pub last_task_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct UpsertMaintenanceJob {
+    pub name: String,
+    pub prompt: String,
+    pub schedule: String,
+    pub executor_profile_id: ExecutorProfileId,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl MaintenanceJob {
+    pub async fn find_enabled(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            MaintenanceJob,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      name,
+                      prompt,
+                      schedule,
+                      executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                      enabled as "enabled!: bool",
+                      last_run_at as "last_run_at: DateTime<Utc>",
+                      last_task_id as "last_task_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_maintenance_jobs
+               WHERE enabled = 1
+               ORDER BY COALESCE(last_run_at, created_at)"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            MaintenanceJob,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      name,
+                      prompt,
+                      schedule,
+                      executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                      enabled as "enabled!: bool",
+                      last_run_at as "last_run_at: DateTime<Utc>",
+                      last_task_id as "last_task_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_maintenance_jobs
+               WHERE project_id = $1
+               ORDER BY created_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            MaintenanceJob,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      name,
+                      prompt,
+                      schedule,
+                      executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                      enabled as "enabled!: bool",
+                      last_run_at as "last_run_at: DateTime<Utc>",
+                      last_task_id as "last_task_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_maintenance_jobs
+               WHERE id = $1 AND project_id = $2"#,
+            id,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        data: &UpsertMaintenanceJob,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        let executor_profile_id = Json(&data.executor_profile_id);
+        sqlx::query_as!(
+            MaintenanceJob,
+            r#"INSERT INTO project_maintenance_jobs
+                   (id, project_id, name, prompt, schedule, executor_profile_id, enabled)
+               VALUES ($1, $2, $3, $4, $5, $6, $7)
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         name,
+                         prompt,
+                         schedule,
+                         executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                         enabled as "enabled!: bool",
+                         last_run_at as "last_run_at: DateTime<Utc>",
+                         last_task_id as "last_task_id: Uuid",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            project_id,
+            data.name,
+            data.prompt,
+            data.schedule,
+            executor_profile_id,
+            data.enabled
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        id: Uuid,
+        data: &UpsertMaintenanceJob,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let executor_profile_id = Json(&data.executor_profile_id);
+        sqlx::query_as!(
+            MaintenanceJob,
+            r#"UPDATE project_maintenance_jobs
+               SET name = $3, prompt = $4, schedule = $5, executor_profile_id = $6,
+                   enabled = $7, updated_at = datetime('now', 'subsec')
+               WHERE id = $1 AND project_id = $2
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         name,
+                         prompt,
+                         schedule,
+                         executor_profile_id as "executor_profile_id!: Json<ExecutorProfileId>",
+                         enabled as "enabled!: bool",
+                         last_run_at as "last_run_at: DateTime<Utc>",
+                         last_task_id as "last_task_id: Uuid",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            project_id,
+            data.name,
+            data.prompt,
+            data.schedule,
+            executor_profile_id,
+            data.enabled
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Mark the job as run now, keeping the previous task when `task_id` is
+    /// `None`
+    pub async fn record_run(
+        pool: &SqlitePool,
+        id: Uuid,
+        task_id: Option<Uuid>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE project_maintenance_jobs
+               SET last_run_at = datetime('now', 'subsec'),
+                   last_task_id = COALESCE($2, last_task_id)
+               WHERE id = $1"#,
+            id,
+            task_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_maintenance_jobs WHERE id = $1 AND project_id = $2",
+            id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
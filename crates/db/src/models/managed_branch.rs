@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A local branch vibe-kanban created for an attempt. Cleanup only deletes
+/// branches recorded here.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ManagedBranch {
+    pub id: Uuid,
+    pub repo_id: Uuid,
+    /// Cleared when the attempt is deleted; the branch may outlive it
+    pub workspace_id: Option<Uuid>,
+    pub branch_name: String,
+    /// Target branch the attempt branched from
+    pub base_branch: String,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ManagedBranch {
+    pub async fn find_by_repo_id(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ManagedBranch,
+            r#"SELECT id as "id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      workspace_id as "workspace_id: Uuid",
+                      branch_name,
+                      base_branch,
+                      deleted_at as "deleted_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM managed_branches
+               WHERE repo_id = $1
+               ORDER BY created_at DESC"#,
+            repo_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_repo_and_name(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+        branch_name: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ManagedBranch,
+            r#"SELECT id as "id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      workspace_id as "workspace_id: Uuid",
+                      branch_name,
+                      base_branch,
+                      deleted_at as "deleted_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM managed_branches
+               WHERE repo_id = $1 AND branch_name = $2"#,
+            repo_id,
+            branch_name
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Record a branch created for `workspace_id`. Re-creating a branch that
+    /// was deleted earlier makes it live again.
+    pub async fn record(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+        workspace_id: Uuid,
+        branch_name: &str,
+        base_branch: &str,
+    ) -> Result<(), sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query!(
+            r#"INSERT INTO managed_branches (id, repo_id, workspace_id, branch_name, base_branch)
+               VALUES ($1, $2, $3, $4, $5)
+               ON CONFLICT(repo_id, branch_name) DO UPDATE SET
+                   workspace_id = excluded.workspace_id,
+                   base_branch = excluded.base_branch,
+                   deleted_at = NULL"#,
+            id,
+            repo_id,
+            workspace_id,
+            branch_name,
+            base_branch
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Follow a branch rename across all of a workspace's repos
+    pub async fn rename(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        old_name: &str,
+        new_name: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE managed_branches
+               SET branch_name = $3
+               WHERE workspace_id = $1 AND branch_name = $2"#,
+            workspace_id,
+            old_name,
+            new_name
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn mark_deleted(pool: &SqlitePool, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE managed_branches
+               SET deleted_at = datetime('now', 'subsec')
+               WHERE id = $1"#,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use uuid::Uuid;
+
+/// Where a mention was written
+#[derive(Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq)]
+#[sqlx(type_name = "mention_source", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MentionSource {
+    TaskDescription,
+    DiffComment,
+}
+
+/// A local user mentioned by `@username`
+#[derive(Debug, Clone, FromRow)]
+pub struct Mention {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub source: MentionSource,
+    /// The task or diff comment the mention was written in
+    pub source_id: Uuid,
+    pub task_id: Uuid,
+    pub mentioned_by_user_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Mention {
+    pub async fn find_by_source(
+        pool: &SqlitePool,
+        source: MentionSource,
+        source_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Mention,
+            r#"SELECT id as "id!: Uuid",
+                      user_id as "user_id!: Uuid",
+                      source as "source!: MentionSource",
+                      source_id as "source_id!: Uuid",
+                      task_id as "task_id!: Uuid",
+                      mentioned_by_user_id as "mentioned_by_user_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM mentions
+               WHERE source = $1 AND source_id = $2"#,
+            source,
+            source_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Make `user_ids` the users a source mentions, returning the ones it didn't
+    /// mention before
+    pub async fn replace_for_source(
+        pool: &SqlitePool,
+        source: MentionSource,
+        source_id: Uuid,
+        task_id: Uuid,
+        mentioned_by_user_id: Option<Uuid>,
+        user_ids: &[Uuid],
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        let existing: HashSet<Uuid> = Self::find_by_source(pool, source, source_id)
+            .await?
+            .into_iter()
+            .map(|mention| mention.user_id)
+            .collect();
+        let wanted: HashSet<Uuid> = user_ids.iter().copied().collect();
+
+        let mut tx = pool.begin().await?;
+        for user_id in existing.difference(&wanted) {
+            sqlx::query!(
+                "DELETE FROM mentions WHERE source = $1 AND source_id = $2 AND user_id = $3",
+                source,
+                source_id,
+                user_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        let mut added = Vec::new();
+        for &user_id in user_ids {
+            if existing.contains(&user_id) || added.contains(&user_id) {
+                continue;
+            }
+            let id = Uuid::new_v4();
+            sqlx::query!(
+                r#"INSERT INTO mentions (id, user_id, source, source_id, task_id, mentioned_by_user_id)
+                   VALUES ($1, $2, $3, $4, $5, $6)"#,
+                id,
+                user_id,
+                source,
+                source_id,
+                task_id,
+                mentioned_by_user_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            added.push(user_id);
+        }
+        tx.commit().await?;
+        Ok(added)
+    }
+
+    pub async fn delete_by_source(
+        pool: &SqlitePool,
+        source: MentionSource,
+        source_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM mentions WHERE source = $1 AND source_id = $2",
+            source,
+            source_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -1,16 +1,64 @@
+pub mod bitbucket_pull_request;
 pub mod coding_agent_turn;
+pub mod diff_comment;
 pub mod execution_process;
 pub mod execution_process_logs;
 pub mod execution_process_repo_state;
+pub mod feed_event;
+pub mod gitlab_merge_request;
 pub mod image;
+pub mod issue_connection;
+pub mod issue_link;
+pub mod issue_sync_conflict;
+pub mod kiosk_token;
+pub mod label_profile_rule;
+pub mod maintenance_job;
+pub mod managed_branch;
+pub mod mention;
 pub mod merge;
+pub mod notification;
+pub mod pending_start;
 pub mod project;
+pub mod project_calendar_feed;
+pub mod project_diff_exclusion;
+pub mod project_email_address;
+pub mod project_executor_weight;
+pub mod project_group;
+pub mod project_instruction;
 pub mod project_repo;
+pub mod project_status_page;
+pub mod project_status_transition;
+pub mod project_webhook;
+pub mod proposed_task;
+pub mod pull_request_status;
 pub mod repo;
+pub mod repo_health;
+pub mod repo_path_rule;
+pub mod rest_hook_subscription;
 pub mod scratch;
+pub mod secret_scan_finding;
 pub mod session;
+pub mod sla_rule;
+pub mod slack_user_link;
+pub mod sync_run;
 pub mod tag;
 pub mod task;
+pub mod task_activity;
+pub mod task_dependency;
+pub mod task_draft;
+pub mod task_estimate;
+pub mod task_group;
+pub mod task_owner;
+pub mod task_label;
+pub mod task_review;
+pub mod task_schedule;
+pub mod task_sla_breach;
+pub mod token_budget;
 pub mod user;
+pub mod user_capacity;
+pub mod vortex_oauth_token;
 pub mod workspace;
+pub mod workspace_ci_status;
+pub mod workspace_manifest;
 pub mod workspace_repo;
+pub mod workspace_summary;
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "notification_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationKind {
+    AttemptCompleted,
+    AttemptFailed,
+    Mention,
+    ReviewRequested,
+    SyncError,
+    IssueComment,
+    SlaBreach,
+    BudgetExceeded,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub kind: NotificationKind,
+    pub title: String,
+    pub message: String,
+    pub project_id: Option<Uuid>,
+    pub task_id: Option<Uuid>,
+    pub workspace_id: Option<Uuid>,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateNotification {
+    pub kind: NotificationKind,
+    pub title: String,
+    pub message: String,
+    pub project_id: Option<Uuid>,
+    pub task_id: Option<Uuid>,
+    pub workspace_id: Option<Uuid>,
+}
+
+impl Notification {
+    pub async fn create(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        data: &CreateNotification,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            Notification,
+            r#"INSERT INTO notifications (id, user_id, kind, title, message, project_id, task_id, workspace_id)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+               RETURNING id as "id!: Uuid",
+                         user_id as "user_id!: Uuid",
+                         kind as "kind!: NotificationKind",
+                         title,
+                         message,
+                         project_id as "project_id: Uuid",
+                         task_id as "task_id: Uuid",
+                         workspace_id as "workspace_id: Uuid",
+                         read_at as "read_at: DateTime<Utc>",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            user_id,
+            data.kind,
+            data.title,
+            data.message,
+            data.project_id,
+            data.task_id,
+            data.workspace_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Deliver a notification to every user's inbox. Tasks have no owner, so
+    /// project-wide events are fanned out to all accounts.
+    pub async fn create_for_all_users(
+        pool: &SqlitePool,
+        data: &CreateNotification,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        let user_ids = sqlx::query_scalar!(r#"SELECT id as "id!: Uuid" FROM users"#)
+            .fetch_all(pool)
+            .await?;
+
+        let mut notifications = Vec::with_capacity(user_ids.len());
+        for user_id in user_ids {
+            notifications.push(Self::create(pool, user_id, data).await?);
+        }
+        Ok(notifications)
+    }
+
+    pub async fn find_by_user_id(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        unread_only: bool,
+        limit: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Notification,
+            r#"SELECT id as "id!: Uuid",
+                      user_id as "user_id!: Uuid",
+                      kind as "kind!: NotificationKind",
+                      title,
+                      message,
+                      project_id as "project_id: Uuid",
+                      task_id as "task_id: Uuid",
+                      workspace_id as "workspace_id: Uuid",
+                      read_at as "read_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM notifications
+               WHERE user_id = $1
+                 AND ($2 = 0 OR read_at IS NULL)
+               ORDER BY created_at DESC
+               LIMIT $3"#,
+            user_id,
+            unread_only,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn count_unread(pool: &SqlitePool, user_id: Uuid) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64"
+               FROM notifications
+               WHERE user_id = $1 AND read_at IS NULL"#,
+            user_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Returns the number of rows changed, 0 if the notification doesn't belong to
+    /// the user or was already read
+    pub async fn mark_read(pool: &SqlitePool, user_id: Uuid, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE notifications
+               SET read_at = datetime('now', 'subsec')
+               WHERE id = $1 AND user_id = $2 AND read_at IS NULL"#,
+            id,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn mark_all_read(pool: &SqlitePool, user_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"UPDATE notifications
+               SET read_at = datetime('now', 'subsec')
+               WHERE user_id = $1 AND read_at IS NULL"#,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// An automatic attempt start waiting for a person to approve it, for projects
+/// with `confirm_auto_start` on. A task has at most one.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct PendingStart {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub task_title: String,
+    /// Who moved the task to In Progress, when it was a signed-in user
+    pub requested_by: Option<Uuid>,
+    pub requested_by_username: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PendingStart {
+    pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            PendingStart,
+            r#"SELECT ps.id as "id!: Uuid",
+                      ps.task_id as "task_id!: Uuid",
+                      ps.project_id as "project_id!: Uuid",
+                      t.title as "task_title!",
+                      ps.requested_by as "requested_by: Uuid",
+                      u.username as "requested_by_username?",
+                      ps.created_at as "created_at!: DateTime<Utc>"
+               FROM pending_starts ps
+               JOIN tasks t ON t.id = ps.task_id
+               LEFT JOIN users u ON u.id = ps.requested_by
+               WHERE ps.id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Oldest first
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            PendingStart,
+            r#"SELECT ps.id as "id!: Uuid",
+                      ps.task_id as "task_id!: Uuid",
+                      ps.project_id as "project_id!: Uuid",
+                      t.title as "task_title!",
+                      ps.requested_by as "requested_by: Uuid",
+                      u.username as "requested_by_username?",
+                      ps.created_at as "created_at!: DateTime<Utc>"
+               FROM pending_starts ps
+               JOIN tasks t ON t.id = ps.task_id
+               LEFT JOIN users u ON u.id = ps.requested_by
+               WHERE ps.project_id = $1
+               ORDER BY ps.created_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Record a pending start for the task. A task that already has one keeps it.
+    pub async fn create(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        project_id: Uuid,
+        requested_by: Option<Uuid>,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let id = Uuid::new_v4();
+        let inserted = sqlx::query!(
+            r#"INSERT INTO pending_starts (id, task_id, project_id, requested_by)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(task_id) DO NOTHING"#,
+            id,
+            task_id,
+            project_id,
+            requested_by
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+        if inserted == 0 {
+            return Ok(None);
+        }
+        Self::find_by_id(pool, id).await
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM pending_starts WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -1,11 +1,14 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool};
 use thiserror::Error;
 use ts_rs::TS;
+use utils::validation::{MAX_NAME_LEN, Validate, ValidationErrors};
 use uuid::Uuid;
 
-use super::project_repo::CreateProjectRepo;
+use super::{project_repo::CreateProjectRepo, task_label::LabelSyncDirection};
 
 #[derive(Debug, Error)]
 pub enum ProjectError {
@@ -31,8 +34,12 @@ pub struct Project {
     pub github_token: Option<String>,
     pub github_sync_enabled: bool,
     pub github_sync_labels: Option<String>,
+    /// Also import open pull requests as review tasks when syncing
+    pub github_sync_pull_requests: bool,
     #[ts(type = "string | null")]
     pub github_last_sync_at: Option<DateTime<Utc>>,
+    /// Instance URL for a self-hosted GitLab; gitlab.com when unset
+    pub gitlab_base_url: Option<String>,
     pub gitlab_project_url: Option<String>,
     #[serde(skip_serializing)]
     #[ts(skip)]
@@ -48,8 +55,42 @@ pub struct Project {
     pub vortex_token: Option<String>,
     pub vortex_sync_enabled: bool,
     pub vortex_sync_labels: Option<String>,
+    /// Keep the titles and descriptions of tasks and their Vortex issues in
+    /// step both ways, instead of only pushing status changes to Vortex
+    pub vortex_two_way_sync: bool,
     #[ts(type = "string | null")]
     pub vortex_last_sync_at: Option<DateTime<Utc>>,
+    pub gitea_base_url: Option<String>,
+    pub gitea_repo: Option<String>,
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    pub gitea_token: Option<String>,
+    pub gitea_sync_enabled: bool,
+    pub gitea_sync_labels: Option<String>,
+    #[ts(type = "string | null")]
+    pub gitea_last_sync_at: Option<DateTime<Utc>>,
+    pub bitbucket_workspace: Option<String>,
+    pub bitbucket_repo_slug: Option<String>,
+    pub bitbucket_username: Option<String>,
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    pub bitbucket_app_password: Option<String>,
+    /// Which way labels are synced between tasks and linked GitHub issues
+    pub github_label_sync: LabelSyncDirection,
+    /// Comment on the GitHub, GitLab or Vortex issue a task was imported from
+    /// when one of its attempts finishes
+    pub comment_on_linked_issues: bool,
+    /// Record a pending start for a person to approve instead of starting an
+    /// attempt automatically when a task moves to In Progress
+    pub confirm_auto_start: bool,
+    /// Keep tasks out of Done while CI is pending or failing on their open
+    /// pull and merge requests
+    pub wait_for_ci: bool,
+    /// Move tasks to Done when their pull or merge request is merged
+    pub done_on_merge: bool,
+    /// Hold unlabeled issues picked up by sync as drafts, off the board until
+    /// someone triages them
+    pub triage_synced_issues: bool,
     #[ts(type = "Date")]
     pub created_at: DateTime<Utc>,
     #[ts(type = "Date")]
@@ -62,25 +103,247 @@ pub struct CreateProject {
     pub repositories: Vec<CreateProjectRepo>,
 }
 
-#[derive(Debug, Deserialize, TS)]
+#[derive(Debug, Clone, Deserialize, TS)]
+pub struct CreateProjectFromTemplate {
+    pub template_project_id: Uuid,
+    pub name: String,
+    /// Repositories for the new project; defaults to the template's repositories
+    #[serde(default)]
+    #[ts(optional)]
+    pub repositories: Option<Vec<CreateProjectRepo>>,
+}
+
+#[derive(Debug, Clone, Deserialize, TS)]
+pub struct DuplicateProject {
+    pub name: String,
+    /// Skip tasks that are already done or cancelled
+    #[serde(default)]
+    pub open_tasks_only: bool,
+    /// Attach the source tasks' images to their copies
+    #[serde(default)]
+    pub include_images: bool,
+    /// Replacement repository for a source repo id; unlisted repos are reused as-is
+    #[serde(default)]
+    pub repo_remap: HashMap<Uuid, CreateProjectRepo>,
+}
+
+/// Partial update for a project. Omitted fields are left untouched; for nullable
+/// fields an explicit `null` (or an empty string) clears the stored value.
+#[derive(Debug, Default, Deserialize, TS)]
 pub struct UpdateProject {
+    #[serde(default)]
+    #[ts(optional)]
     pub name: Option<String>,
-    pub dev_script: Option<String>,
-    pub dev_script_working_dir: Option<String>,
-    pub default_agent_working_dir: Option<String>,
-    pub github_repo_url: Option<String>,
-    pub github_token: Option<String>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub dev_script: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub dev_script_working_dir: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub default_agent_working_dir: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub github_repo_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub github_token: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
     pub github_sync_enabled: Option<bool>,
-    pub github_sync_labels: Option<String>,
-    pub gitlab_project_url: Option<String>,
-    pub gitlab_token: Option<String>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub github_sync_labels: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub github_sync_pull_requests: Option<bool>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitlab_base_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitlab_project_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitlab_token: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
     pub gitlab_sync_enabled: Option<bool>,
-    pub gitlab_sync_labels: Option<String>,
-    pub vortex_api_url: Option<String>,
-    pub vortex_project_id: Option<String>,
-    pub vortex_token: Option<String>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitlab_sync_labels: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub vortex_api_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub vortex_project_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub vortex_token: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
     pub vortex_sync_enabled: Option<bool>,
-    pub vortex_sync_labels: Option<String>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub vortex_sync_labels: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub vortex_two_way_sync: Option<bool>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitea_base_url: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitea_repo: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitea_token: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub gitea_sync_enabled: Option<bool>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub gitea_sync_labels: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub bitbucket_workspace: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub bitbucket_repo_slug: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub bitbucket_username: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[ts(optional)]
+    pub bitbucket_app_password: Option<Option<String>>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub github_label_sync: Option<LabelSyncDirection>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub comment_on_linked_issues: Option<bool>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub confirm_auto_start: Option<bool>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub wait_for_ci: Option<bool>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub done_on_merge: Option<bool>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub triage_synced_issues: Option<bool>,
+}
+
+impl Validate for CreateProject {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("name", &self.name, MAX_NAME_LEN);
+        validate_repositories(&mut errors, &self.repositories);
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateProjectFromTemplate {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("name", &self.name, MAX_NAME_LEN);
+        if let Some(repositories) = &self.repositories {
+            validate_repositories(&mut errors, repositories);
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for DuplicateProject {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("name", &self.name, MAX_NAME_LEN);
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateProject {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(name) = &self.name {
+            errors.required_text("name", name, MAX_NAME_LEN);
+        }
+        if let Some(url) = set_value(&self.github_repo_url) {
+            errors.repo_url("github_repo_url", url, Some("github.com"));
+        }
+        if let Some(url) = set_value(&self.gitlab_base_url) {
+            errors.http_url("gitlab_base_url", url);
+        }
+        if let Some(url) = set_value(&self.gitlab_project_url) {
+            // Self-hosted instances live on their own hosts
+            errors.repo_url("gitlab_project_url", url, None);
+        }
+        if let Some(repo) = set_value(&self.gitea_repo) {
+            errors.repo_url("gitea_repo", repo, None);
+        }
+        if let Some(url) = set_value(&self.vortex_api_url) {
+            errors.http_url("vortex_api_url", url);
+        }
+        if let Some(url) = set_value(&self.gitea_base_url) {
+            errors.http_url("gitea_base_url", url);
+        }
+        for (field, labels) in [
+            ("github_sync_labels", &self.github_sync_labels),
+            ("gitlab_sync_labels", &self.gitlab_sync_labels),
+            ("vortex_sync_labels", &self.vortex_sync_labels),
+            ("gitea_sync_labels", &self.gitea_sync_labels),
+        ] {
+            if let Some(labels) = set_value(labels) {
+                errors.label_list(field, labels);
+            }
+        }
+        errors.into_result()
+    }
+}
+
+/// The new value of a nullable field, unless it is absent or being cleared
+fn set_value(field: &Option<Option<String>>) -> Option<&str> {
+    field
+        .as_ref()?
+        .as_deref()
+        .filter(|value| !value.trim().is_empty())
+}
+
+fn validate_repositories(errors: &mut ValidationErrors, repositories: &[CreateProjectRepo]) {
+    for (i, repo) in repositories.iter().enumerate() {
+        errors.required_text(
+            &format!("repositories[{i}].display_name"),
+            &repo.display_name,
+            MAX_NAME_LEN,
+        );
+        if repo.git_repo_path.trim().is_empty() {
+            errors.add(
+                &format!("repositories[{i}].git_repo_path"),
+                "must not be empty",
+            );
+        }
+    }
+}
+
+/// Maps a present field to `Some(..)` so `null` is kept apart from an absent field,
+/// which `#[serde(default)]` leaves as `None`.
+fn explicit_null<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(Some)
+}
+
+/// Result of `Project::update`: the stored project and the `UpdateProject` fields
+/// whose values actually changed
+#[derive(Debug, Clone)]
+pub struct ProjectUpdate {
+    pub project: Project,
+    pub changed_fields: Vec<&'static str>,
 }
 
 #[derive(Debug, Serialize, TS)]
@@ -117,7 +380,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -129,6 +394,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -149,7 +431,9 @@ impl Project {
                    p.github_token,
                    p.github_sync_enabled as "github_sync_enabled!: bool",
                    p.github_sync_labels,
+                   p.github_sync_pull_requests as "github_sync_pull_requests!: bool",
                    p.github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                   p.gitlab_base_url,
                    p.gitlab_project_url,
                    p.gitlab_token,
                    p.gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -161,6 +445,23 @@ impl Project {
                    p.vortex_sync_enabled as "vortex_sync_enabled!: bool",
                    p.vortex_sync_labels,
                    p.vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                   p.gitea_base_url,
+                   p.gitea_repo,
+                   p.gitea_token,
+                   p.gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                   p.gitea_sync_labels,
+                   p.gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                   p.bitbucket_workspace,
+                   p.bitbucket_repo_slug,
+                   p.bitbucket_username,
+                   p.bitbucket_app_password,
+                   p.github_label_sync as "github_label_sync!: LabelSyncDirection",
+                   p.comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                   p.confirm_auto_start as "confirm_auto_start!: bool",
+                   p.wait_for_ci as "wait_for_ci!: bool",
+                   p.done_on_merge as "done_on_merge!: bool",
+                   p.vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                   p.triage_synced_issues as "triage_synced_issues!: bool",
                    p.created_at as "created_at!: DateTime<Utc>", p.updated_at as "updated_at!: DateTime<Utc>"
             FROM projects p
             WHERE p.id IN (
@@ -190,7 +491,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -202,6 +505,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -225,7 +545,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -237,6 +559,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -263,7 +602,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -275,6 +616,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -309,7 +667,9 @@ impl Project {
                           github_token,
                           github_sync_enabled as "github_sync_enabled!: bool",
                           github_sync_labels,
+                          github_sync_pull_requests as "github_sync_pull_requests!: bool",
                           github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                          gitlab_base_url,
                           gitlab_project_url,
                           gitlab_token,
                           gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -321,6 +681,23 @@ impl Project {
                           vortex_sync_enabled as "vortex_sync_enabled!: bool",
                           vortex_sync_labels,
                           vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                          gitea_base_url,
+                          gitea_repo,
+                          gitea_token,
+                          gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                          gitea_sync_labels,
+                          gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                          bitbucket_workspace,
+                          bitbucket_repo_slug,
+                          bitbucket_username,
+                          bitbucket_app_password,
+                          github_label_sync as "github_label_sync!: LabelSyncDirection",
+                          comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                          confirm_auto_start as "confirm_auto_start!: bool",
+                          wait_for_ci as "wait_for_ci!: bool",
+                          done_on_merge as "done_on_merge!: bool",
+                          vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                          triage_synced_issues as "triage_synced_issues!: bool",
                           created_at as "created_at!: DateTime<Utc>",
                           updated_at as "updated_at!: DateTime<Utc>""#,
             project_id,
@@ -334,82 +711,301 @@ impl Project {
         pool: &SqlitePool,
         id: Uuid,
         payload: &UpdateProject,
-    ) -> Result<Self, sqlx::Error> {
+    ) -> Result<ProjectUpdate, sqlx::Error> {
         let existing = Self::find_by_id(pool, id)
             .await?
             .ok_or(sqlx::Error::RowNotFound)?;
 
-        let name = payload.name.clone().unwrap_or(existing.name);
-        let dev_script = payload.dev_script.clone();
-        let dev_script_working_dir = payload.dev_script_working_dir.clone();
-        let default_agent_working_dir = payload.default_agent_working_dir.clone();
-        let github_repo_url = payload
-            .github_repo_url
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.github_repo_url);
-        let github_token = payload
-            .github_token
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.github_token);
-        let github_sync_enabled = payload
-            .github_sync_enabled
-            .unwrap_or(existing.github_sync_enabled);
-        let github_sync_labels = payload
-            .github_sync_labels
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.github_sync_labels);
-        let gitlab_project_url = payload
-            .gitlab_project_url
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.gitlab_project_url);
-        let gitlab_token = payload
-            .gitlab_token
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.gitlab_token);
-        let gitlab_sync_enabled = payload
-            .gitlab_sync_enabled
-            .unwrap_or(existing.gitlab_sync_enabled);
-        let gitlab_sync_labels = payload
-            .gitlab_sync_labels
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.gitlab_sync_labels);
-        let vortex_api_url = payload
-            .vortex_api_url
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.vortex_api_url);
-        let vortex_project_id = payload
-            .vortex_project_id
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.vortex_project_id);
-        let vortex_token = payload
-            .vortex_token
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.vortex_token);
-        let vortex_sync_enabled = payload
-            .vortex_sync_enabled
-            .unwrap_or(existing.vortex_sync_enabled);
-        let vortex_sync_labels = payload
-            .vortex_sync_labels
-            .clone()
-            .filter(|s| !s.is_empty())
-            .or(existing.vortex_sync_labels);
+        fn apply<T: PartialEq>(
+            field: &'static str,
+            patch: Option<T>,
+            current: &mut T,
+            changed_fields: &mut Vec<&'static str>,
+        ) {
+            if let Some(value) = patch
+                && value != *current
+            {
+                *current = value;
+                changed_fields.push(field);
+            }
+        }
 
-        sqlx::query_as!(
+        // Blank strings clear nullable fields rather than storing ""
+        let text = |patch: &Option<Option<String>>| {
+            patch
+                .as_ref()
+                .map(|value| value.clone().filter(|s| !s.trim().is_empty()))
+        };
+
+        let mut next = existing.clone();
+        let mut changed_fields = Vec::new();
+        let c = &mut changed_fields;
+        apply("name", payload.name.clone(), &mut next.name, c);
+        apply(
+            "dev_script",
+            text(&payload.dev_script),
+            &mut next.dev_script,
+            c,
+        );
+        apply(
+            "dev_script_working_dir",
+            text(&payload.dev_script_working_dir),
+            &mut next.dev_script_working_dir,
+            c,
+        );
+        apply(
+            "default_agent_working_dir",
+            text(&payload.default_agent_working_dir),
+            &mut next.default_agent_working_dir,
+            c,
+        );
+        apply(
+            "github_repo_url",
+            text(&payload.github_repo_url),
+            &mut next.github_repo_url,
+            c,
+        );
+        apply(
+            "github_token",
+            text(&payload.github_token),
+            &mut next.github_token,
+            c,
+        );
+        apply(
+            "github_sync_enabled",
+            payload.github_sync_enabled,
+            &mut next.github_sync_enabled,
+            c,
+        );
+        apply(
+            "github_sync_labels",
+            text(&payload.github_sync_labels),
+            &mut next.github_sync_labels,
+            c,
+        );
+        apply(
+            "github_sync_pull_requests",
+            payload.github_sync_pull_requests,
+            &mut next.github_sync_pull_requests,
+            c,
+        );
+        apply(
+            "gitlab_base_url",
+            text(&payload.gitlab_base_url),
+            &mut next.gitlab_base_url,
+            c,
+        );
+        apply(
+            "gitlab_project_url",
+            text(&payload.gitlab_project_url),
+            &mut next.gitlab_project_url,
+            c,
+        );
+        apply(
+            "gitlab_token",
+            text(&payload.gitlab_token),
+            &mut next.gitlab_token,
+            c,
+        );
+        apply(
+            "gitlab_sync_enabled",
+            payload.gitlab_sync_enabled,
+            &mut next.gitlab_sync_enabled,
+            c,
+        );
+        apply(
+            "gitlab_sync_labels",
+            text(&payload.gitlab_sync_labels),
+            &mut next.gitlab_sync_labels,
+            c,
+        );
+        apply(
+            "vortex_api_url",
+            text(&payload.vortex_api_url),
+            &mut next.vortex_api_url,
+            c,
+        );
+        apply(
+            "vortex_project_id",
+            text(&payload.vortex_project_id),
+            &mut next.vortex_project_id,
+            c,
+        );
+        apply(
+            "vortex_token",
+            text(&payload.vortex_token),
+            &mut next.vortex_token,
+            c,
+        );
+        apply(
+            "vortex_sync_enabled",
+            payload.vortex_sync_enabled,
+            &mut next.vortex_sync_enabled,
+            c,
+        );
+        apply(
+            "vortex_sync_labels",
+            text(&payload.vortex_sync_labels),
+            &mut next.vortex_sync_labels,
+            c,
+        );
+        apply(
+            "gitea_base_url",
+            text(&payload.gitea_base_url),
+            &mut next.gitea_base_url,
+            c,
+        );
+        apply(
+            "gitea_repo",
+            text(&payload.gitea_repo),
+            &mut next.gitea_repo,
+            c,
+        );
+        apply(
+            "gitea_token",
+            text(&payload.gitea_token),
+            &mut next.gitea_token,
+            c,
+        );
+        apply(
+            "gitea_sync_enabled",
+            payload.gitea_sync_enabled,
+            &mut next.gitea_sync_enabled,
+            c,
+        );
+        apply(
+            "gitea_sync_labels",
+            text(&payload.gitea_sync_labels),
+            &mut next.gitea_sync_labels,
+            c,
+        );
+        apply(
+            "bitbucket_workspace",
+            text(&payload.bitbucket_workspace),
+            &mut next.bitbucket_workspace,
+            c,
+        );
+        apply(
+            "bitbucket_repo_slug",
+            text(&payload.bitbucket_repo_slug),
+            &mut next.bitbucket_repo_slug,
+            c,
+        );
+        apply(
+            "bitbucket_username",
+            text(&payload.bitbucket_username),
+            &mut next.bitbucket_username,
+            c,
+        );
+        apply(
+            "bitbucket_app_password",
+            text(&payload.bitbucket_app_password),
+            &mut next.bitbucket_app_password,
+            c,
+        );
+        apply(
+            "github_label_sync",
+            payload.github_label_sync,
+            &mut next.github_label_sync,
+            c,
+        );
+        apply(
+            "comment_on_linked_issues",
+            payload.comment_on_linked_issues,
+            &mut next.comment_on_linked_issues,
+            c,
+        );
+        apply(
+            "confirm_auto_start",
+            payload.confirm_auto_start,
+            &mut next.confirm_auto_start,
+            c,
+        );
+        apply("wait_for_ci", payload.wait_for_ci, &mut next.wait_for_ci, c);
+        apply(
+            "done_on_merge",
+            payload.done_on_merge,
+            &mut next.done_on_merge,
+            c,
+        );
+        apply(
+            "vortex_two_way_sync",
+            payload.vortex_two_way_sync,
+            &mut next.vortex_two_way_sync,
+            c,
+        );
+        apply(
+            "triage_synced_issues",
+            payload.triage_synced_issues,
+            &mut next.triage_synced_issues,
+            c,
+        );
+
+        // Nothing to write; skip the UPDATE so no change events are emitted
+        if changed_fields.is_empty() {
+            return Ok(ProjectUpdate {
+                project: existing,
+                changed_fields,
+            });
+        }
+
+        let Project {
+            name,
+            dev_script,
+            dev_script_working_dir,
+            default_agent_working_dir,
+            github_repo_url,
+            github_token,
+            github_sync_enabled,
+            github_sync_labels,
+            github_sync_pull_requests,
+            gitlab_base_url,
+            gitlab_project_url,
+            gitlab_token,
+            gitlab_sync_enabled,
+            gitlab_sync_labels,
+            vortex_api_url,
+            vortex_project_id,
+            vortex_token,
+            vortex_sync_enabled,
+            vortex_sync_labels,
+            gitea_base_url,
+            gitea_repo,
+            gitea_token,
+            gitea_sync_enabled,
+            gitea_sync_labels,
+            bitbucket_workspace,
+            bitbucket_repo_slug,
+            bitbucket_username,
+            bitbucket_app_password,
+            github_label_sync,
+            comment_on_linked_issues,
+            confirm_auto_start,
+            wait_for_ci,
+            done_on_merge,
+            vortex_two_way_sync,
+            triage_synced_issues,
+            ..
+        } = next;
+
+        let project = sqlx::query_as!(
             Project,
             r#"UPDATE projects
                SET name = $2, dev_script = $3, dev_script_working_dir = $4, default_agent_working_dir = $5,
                    github_repo_url = $6, github_token = $7, github_sync_enabled = $8, github_sync_labels = $9,
                    gitlab_project_url = $10, gitlab_token = $11, gitlab_sync_enabled = $12, gitlab_sync_labels = $13,
-                   vortex_api_url = $14, vortex_project_id = $15, vortex_token = $16, vortex_sync_enabled = $17, vortex_sync_labels = $18
+                   vortex_api_url = $14, vortex_project_id = $15, vortex_token = $16, vortex_sync_enabled = $17, vortex_sync_labels = $18,
+                   github_sync_pull_requests = $19,
+                   gitea_base_url = $20, gitea_repo = $21, gitea_token = $22, gitea_sync_enabled = $23, gitea_sync_labels = $24,
+                   bitbucket_workspace = $25, bitbucket_repo_slug = $26, bitbucket_username = $27, bitbucket_app_password = $28,
+                   github_label_sync = $29,
+                   comment_on_linked_issues = $30,
+                   confirm_auto_start = $31,
+                   wait_for_ci = $32,
+                   gitlab_base_url = $33,
+                   triage_synced_issues = $34,
+                   done_on_merge = $35,
+                   vortex_two_way_sync = $36
                WHERE id = $1
                RETURNING id as "id!: Uuid",
                          name,
@@ -421,7 +1017,9 @@ impl Project {
                          github_token,
                          github_sync_enabled as "github_sync_enabled!: bool",
                          github_sync_labels,
+                         github_sync_pull_requests as "github_sync_pull_requests!: bool",
                          github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                         gitlab_base_url,
                          gitlab_project_url,
                          gitlab_token,
                          gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -433,6 +1031,23 @@ impl Project {
                          vortex_sync_enabled as "vortex_sync_enabled!: bool",
                          vortex_sync_labels,
                          vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                         gitea_base_url,
+                         gitea_repo,
+                         gitea_token,
+                         gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                         gitea_sync_labels,
+                         gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                         bitbucket_workspace,
+                         bitbucket_repo_slug,
+                         bitbucket_username,
+                         bitbucket_app_password,
+                         github_label_sync as "github_label_sync!: LabelSyncDirection",
+                         comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                         confirm_auto_start as "confirm_auto_start!: bool",
+                         wait_for_ci as "wait_for_ci!: bool",
+                         done_on_merge as "done_on_merge!: bool",
+                         vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                         triage_synced_issues as "triage_synced_issues!: bool",
                          created_at as "created_at!: DateTime<Utc>",
                          updated_at as "updated_at!: DateTime<Utc>""#,
             id,
@@ -453,9 +1068,32 @@ impl Project {
             vortex_token,
             vortex_sync_enabled,
             vortex_sync_labels,
+            github_sync_pull_requests,
+            gitea_base_url,
+            gitea_repo,
+            gitea_token,
+            gitea_sync_enabled,
+            gitea_sync_labels,
+            bitbucket_workspace,
+            bitbucket_repo_slug,
+            bitbucket_username,
+            bitbucket_app_password,
+            github_label_sync,
+            comment_on_linked_issues,
+            confirm_auto_start,
+            wait_for_ci,
+            gitlab_base_url,
+            triage_synced_issues,
+            done_on_merge,
+            vortex_two_way_sync,
         )
         .fetch_one(pool)
-        .await
+        .await?;
+
+        Ok(ProjectUpdate {
+            project,
+            changed_fields,
+        })
     }
 
     pub async fn clear_default_agent_working_dir(
@@ -546,7 +1184,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -558,6 +1198,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -596,7 +1253,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -608,6 +1267,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -646,7 +1322,9 @@ impl Project {
                       github_token,
                       github_sync_enabled as "github_sync_enabled!: bool",
                       github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
                       github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
                       gitlab_project_url,
                       gitlab_token,
                       gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
@@ -658,6 +1336,23 @@ impl Project {
                       vortex_sync_enabled as "vortex_sync_enabled!: bool",
                       vortex_sync_labels,
                       vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
                       created_at as "created_at!: DateTime<Utc>",
                       updated_at as "updated_at!: DateTime<Utc>"
                FROM projects
@@ -668,4 +1363,137 @@ impl Project {
         .fetch_all(pool)
         .await
     }
+
+    pub async fn update_gitea_last_sync(pool: &SqlitePool, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE projects
+               SET gitea_last_sync_at = datetime('now')
+               WHERE id = $1"#,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn find_with_gitea_sync_enabled(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Project,
+            r#"SELECT id as "id!: Uuid",
+                      name,
+                      dev_script,
+                      dev_script_working_dir,
+                      default_agent_working_dir,
+                      remote_project_id as "remote_project_id: Uuid",
+                      github_repo_url,
+                      github_token,
+                      github_sync_enabled as "github_sync_enabled!: bool",
+                      github_sync_labels,
+                      github_sync_pull_requests as "github_sync_pull_requests!: bool",
+                      github_last_sync_at as "github_last_sync_at: DateTime<Utc>",
+                      gitlab_base_url,
+                      gitlab_project_url,
+                      gitlab_token,
+                      gitlab_sync_enabled as "gitlab_sync_enabled!: bool",
+                      gitlab_sync_labels,
+                      gitlab_last_sync_at as "gitlab_last_sync_at: DateTime<Utc>",
+                      vortex_api_url,
+                      vortex_project_id,
+                      vortex_token,
+                      vortex_sync_enabled as "vortex_sync_enabled!: bool",
+                      vortex_sync_labels,
+                      vortex_last_sync_at as "vortex_last_sync_at: DateTime<Utc>",
+                      gitea_base_url,
+                      gitea_repo,
+                      gitea_token,
+                      gitea_sync_enabled as "gitea_sync_enabled!: bool",
+                      gitea_sync_labels,
+                      gitea_last_sync_at as "gitea_last_sync_at: DateTime<Utc>",
+                      bitbucket_workspace,
+                      bitbucket_repo_slug,
+                      bitbucket_username,
+                      bitbucket_app_password,
+                      github_label_sync as "github_label_sync!: LabelSyncDirection",
+                      comment_on_linked_issues as "comment_on_linked_issues!: bool",
+                      confirm_auto_start as "confirm_auto_start!: bool",
+                      wait_for_ci as "wait_for_ci!: bool",
+                      done_on_merge as "done_on_merge!: bool",
+                      vortex_two_way_sync as "vortex_two_way_sync!: bool",
+                      triage_synced_issues as "triage_synced_issues!: bool",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM projects
+               WHERE gitea_sync_enabled = 1
+                 AND gitea_base_url IS NOT NULL
+                 AND gitea_repo IS NOT NULL
+                 AND gitea_token IS NOT NULL"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    use super::*;
+
+    async fn test_pool() -> SqlitePool {
+        // One connection, since each in-memory connection is its own database
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn update_leaves_omitted_fields_unchanged() {
+        let pool = test_pool().await;
+        let id = Uuid::new_v4();
+        let create = CreateProject {
+            name: "Board".to_string(),
+            repositories: Vec::new(),
+        };
+        Project::create(&pool, &create, id).await.unwrap();
+        let integrations: UpdateProject = serde_json::from_value(serde_json::json!({
+            "github_repo_url": "https://github.com/acme/board",
+            "github_token": "ghp_secret",
+            "github_sync_labels": "bug",
+            "gitlab_project_url": "https://gitlab.com/acme/board",
+            "vortex_token": "vortex_secret",
+        }))
+        .unwrap();
+        Project::update(&pool, id, &integrations).await.unwrap();
+
+        let dev_script: UpdateProject =
+            serde_json::from_value(serde_json::json!({ "dev_script": "npm run dev" })).unwrap();
+        let update = Project::update(&pool, id, &dev_script).await.unwrap();
+        assert_eq!(update.changed_fields, vec!["dev_script"]);
+        let project = update.project;
+        assert_eq!(project.dev_script.as_deref(), Some("npm run dev"));
+        assert_eq!(
+            project.github_repo_url.as_deref(),
+            Some("https://github.com/acme/board")
+        );
+        assert_eq!(project.github_token.as_deref(), Some("ghp_secret"));
+        assert_eq!(project.github_sync_labels.as_deref(), Some("bug"));
+        assert_eq!(
+            project.gitlab_project_url.as_deref(),
+            Some("https://gitlab.com/acme/board")
+        );
+        assert_eq!(project.vortex_token.as_deref(), Some("vortex_secret"));
+
+        let clear: UpdateProject =
+            serde_json::from_value(serde_json::json!({ "github_token": null })).unwrap();
+        let project = Project::update(&pool, id, &clear).await.unwrap().project;
+        assert_eq!(project.github_token, None);
+        assert_eq!(
+            project.github_repo_url.as_deref(),
+            Some("https://github.com/acme/board")
+        );
+    }
 }
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Secret token that grants read-only access to a project's ICS feed. The
+/// token itself is only shown when issued; the database keeps its hash.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectCalendarFeed {
+    pub project_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProjectCalendarFeed {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectCalendarFeed,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_calendar_feeds
+               WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_token_hash(
+        pool: &SqlitePool,
+        token_hash: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectCalendarFeed,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_calendar_feeds
+               WHERE token_hash = $1"#,
+            token_hash
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Create the feed, or replace its token so previously shared URLs stop working
+    pub async fn rotate(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        token_hash: &str,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectCalendarFeed,
+            r#"INSERT INTO project_calendar_feeds (project_id, token_hash)
+               VALUES ($1, $2)
+               ON CONFLICT(project_id) DO UPDATE SET
+                   token_hash = excluded.token_hash,
+                   created_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            project_id,
+            token_hash
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_calendar_feeds WHERE project_id = $1",
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Files matching the pattern are left out of a project's diffs, linked-issue
+/// reports and PR descriptions, e.g. fixtures, generated or vendored code
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct ProjectDiffExclusion {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// gitignore-style pattern relative to each repo root
+    pub pattern: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, TS)]
+pub struct CreateProjectDiffExclusion {
+    pub pattern: String,
+}
+
+impl ProjectDiffExclusion {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectDiffExclusion,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      pattern,
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_diff_exclusions
+               WHERE project_id = $1
+               ORDER BY created_at ASC"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        data: &CreateProjectDiffExclusion,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        let pattern = data.pattern.trim();
+        sqlx::query_as!(
+            ProjectDiffExclusion,
+            r#"INSERT INTO project_diff_exclusions (id, project_id, pattern)
+               VALUES ($1, $2, $3)
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         pattern,
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            project_id,
+            pattern
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Delete an exclusion belonging to `project_id`. Returns the number of rows removed.
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_diff_exclusions WHERE id = $1 AND project_id = $2",
+            id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
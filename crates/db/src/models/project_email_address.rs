@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Address whose inbound mail is turned into tasks on a project. Matching is
+/// case-insensitive.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectEmailAddress {
+    pub project_id: Uuid,
+    pub address: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetProjectEmailAddress {
+    pub address: String,
+}
+
+impl ProjectEmailAddress {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectEmailAddress,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      address,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_email_addresses
+               WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_address(
+        pool: &SqlitePool,
+        address: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectEmailAddress,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      address,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_email_addresses
+               WHERE address = $1"#,
+            address
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        address: &str,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectEmailAddress,
+            r#"INSERT INTO project_email_addresses (project_id, address)
+               VALUES ($1, $2)
+               ON CONFLICT (project_id) DO UPDATE
+               SET address = excluded.address,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid",
+                         address,
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            project_id,
+            address
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_email_addresses WHERE project_id = $1",
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A project's share of the executor slots for automatically started attempts,
+/// relative to other projects. Projects without a row have weight 1.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectExecutorWeight {
+    pub project_id: Uuid,
+    pub weight: i64,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetProjectExecutorWeight {
+    pub weight: i64,
+}
+
+impl ProjectExecutorWeight {
+    pub const DEFAULT: i64 = 1;
+
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        let weight = sqlx::query_scalar!(
+            r#"SELECT weight FROM project_executor_weights WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(Self {
+            project_id,
+            weight: weight.unwrap_or(Self::DEFAULT),
+        })
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        weight: i64,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectExecutorWeight,
+            r#"INSERT INTO project_executor_weights (project_id, weight)
+               VALUES ($1, $2)
+               ON CONFLICT(project_id) DO UPDATE SET
+                   weight = excluded.weight,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid", weight"#,
+            project_id,
+            weight
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// The weight as the slot scheduler takes it
+    pub fn slot_weight(&self) -> u32 {
+        self.weight.clamp(1, u32::MAX as i64) as u32
+    }
+}
@@ -0,0 +1,235 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use utils::validation::{MAX_NAME_LEN, Validate, ValidationErrors};
+use uuid::Uuid;
+
+/// A folder projects can be filed under
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct ProjectGroup {
+    pub id: Uuid,
+    pub name: String,
+    pub position: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateProjectGroup {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct UpdateProjectGroup {
+    pub name: Option<String>,
+    pub position: Option<i64>,
+}
+
+impl Validate for CreateProjectGroup {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("name", &self.name, MAX_NAME_LEN);
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateProjectGroup {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(name) = &self.name {
+            errors.required_text("name", name, MAX_NAME_LEN);
+        }
+        errors.into_result()
+    }
+}
+
+/// A project's group and position in the project list
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct ProjectPlacement {
+    pub project_id: Uuid,
+    pub group_id: Option<Uuid>,
+    pub position: i64,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetProjectPlacement {
+    pub group_id: Option<Uuid>,
+    pub position: i64,
+}
+
+impl ProjectGroup {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectGroup,
+            r#"SELECT id as "id!: Uuid",
+                      name,
+                      position,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_groups
+               ORDER BY position ASC, name ASC"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectGroup,
+            r#"SELECT id as "id!: Uuid",
+                      name,
+                      position,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_groups
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Create a group at the end of the list
+    pub async fn create(pool: &SqlitePool, name: &str) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            ProjectGroup,
+            r#"INSERT INTO project_groups (id, name, position)
+               VALUES ($1, $2, (SELECT COALESCE(MAX(position) + 1, 0) FROM project_groups))
+               RETURNING id as "id!: Uuid",
+                         name,
+                         position,
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            name
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn update(
+        pool: &SqlitePool,
+        existing: &Self,
+        data: &UpdateProjectGroup,
+    ) -> Result<Self, sqlx::Error> {
+        let name = data
+            .name
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or(&existing.name);
+        let position = data.position.unwrap_or(existing.position);
+        sqlx::query_as!(
+            ProjectGroup,
+            r#"UPDATE project_groups
+               SET name = $2, position = $3, updated_at = datetime('now', 'subsec')
+               WHERE id = $1
+               RETURNING id as "id!: Uuid",
+                         name,
+                         position,
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            existing.id,
+            name,
+            position
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Delete a group; its projects become ungrouped
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM project_groups WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+impl ProjectPlacement {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectPlacement,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      group_id as "group_id: Uuid",
+                      position
+               FROM project_placements"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        data: &SetProjectPlacement,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectPlacement,
+            r#"INSERT INTO project_placements (project_id, group_id, position)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(project_id) DO UPDATE SET
+                   group_id = excluded.group_id,
+                   position = excluded.position
+               RETURNING project_id as "project_id!: Uuid",
+                         group_id as "group_id: Uuid",
+                         position"#,
+            project_id,
+            data.group_id,
+            data.position
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
+
+/// Per-user starred projects
+pub struct ProjectFavorite;
+
+impl ProjectFavorite {
+    pub async fn find_project_ids(
+        pool: &SqlitePool,
+        user_id: Uuid,
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT project_id as "project_id!: Uuid"
+               FROM project_favorites
+               WHERE user_id = $1
+               ORDER BY created_at ASC"#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn add(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT OR IGNORE INTO project_favorites (user_id, project_id) VALUES ($1, $2)",
+            user_id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn remove(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM project_favorites WHERE user_id = $1 AND project_id = $2",
+            user_id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
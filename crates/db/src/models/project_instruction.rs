@@ -0,0 +1,431 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use thiserror::Error;
+use ts_rs::TS;
+use utils::validation::{MAX_DESCRIPTION_LEN, MAX_NAME_LEN, Validate, ValidationErrors};
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ProjectInstructionError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error("Instruction not found")]
+    NotFound,
+    #[error("Instruction version {0} not found")]
+    VersionNotFound(i64),
+    #[error("An instruction named '{0}' already exists in this project")]
+    NameExists(String),
+}
+
+/// A named instruction document with the content of its current version
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectInstruction {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    /// Applied to tasks that don't select an instruction explicitly
+    pub is_default: bool,
+    pub current_version: i64,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectInstructionVersion {
+    pub instruction_id: Uuid,
+    pub version: i64,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateProjectInstruction {
+    pub name: String,
+    pub content: String,
+    pub is_default: Option<bool>,
+}
+
+/// Changing `content` records a new version; other fields are edited in place
+#[derive(Debug, Deserialize, TS)]
+pub struct UpdateProjectInstruction {
+    pub name: Option<String>,
+    pub content: Option<String>,
+    pub is_default: Option<bool>,
+}
+
+impl Validate for CreateProjectInstruction {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("name", &self.name, MAX_NAME_LEN);
+        errors.max_length("content", &self.content, MAX_DESCRIPTION_LEN);
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateProjectInstruction {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(name) = &self.name {
+            errors.required_text("name", name, MAX_NAME_LEN);
+        }
+        if let Some(content) = &self.content {
+            errors.max_length("content", content, MAX_DESCRIPTION_LEN);
+        }
+        errors.into_result()
+    }
+}
+
+/// Instruction selected for a task. `version: None` follows the current version.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct TaskInstruction {
+    pub task_id: Uuid,
+    pub instruction_id: Uuid,
+    pub version: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskInstruction {
+    pub instruction_id: Uuid,
+    pub version: Option<i64>,
+}
+
+/// Instruction content resolved for an agent run
+#[derive(Debug, Clone)]
+pub struct ResolvedInstruction {
+    pub instruction_id: Uuid,
+    pub name: String,
+    pub version: i64,
+    pub content: String,
+}
+
+impl ResolvedInstruction {
+    /// Prepend the instruction document to a task prompt
+    pub fn apply_to_prompt(&self, prompt: &str) -> String {
+        format!(
+            "<project_instructions name=\"{}\" version=\"{}\">\n{}\n</project_instructions>\n\n{}",
+            self.name,
+            self.version,
+            self.content.trim(),
+            prompt
+        )
+    }
+}
+
+impl ProjectInstruction {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectInstruction,
+            r#"SELECT pi.id as "id!: Uuid",
+                      pi.project_id as "project_id!: Uuid",
+                      pi.name,
+                      pi.is_default as "is_default!: bool",
+                      pi.current_version as "current_version!: i64",
+                      v.content,
+                      pi.created_at as "created_at!: DateTime<Utc>",
+                      pi.updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_instructions pi
+               JOIN project_instruction_versions v
+                 ON v.instruction_id = pi.id AND v.version = pi.current_version
+               WHERE pi.project_id = $1
+               ORDER BY pi.name ASC"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectInstruction,
+            r#"SELECT pi.id as "id!: Uuid",
+                      pi.project_id as "project_id!: Uuid",
+                      pi.name,
+                      pi.is_default as "is_default!: bool",
+                      pi.current_version as "current_version!: i64",
+                      v.content,
+                      pi.created_at as "created_at!: DateTime<Utc>",
+                      pi.updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_instructions pi
+               JOIN project_instruction_versions v
+                 ON v.instruction_id = pi.id AND v.version = pi.current_version
+               WHERE pi.id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        data: &CreateProjectInstruction,
+    ) -> Result<Self, ProjectInstructionError> {
+        let name = data.name.trim();
+        if Self::name_exists(pool, project_id, name, None).await? {
+            return Err(ProjectInstructionError::NameExists(name.to_string()));
+        }
+
+        let id = Uuid::new_v4();
+        let is_default = data.is_default.unwrap_or(false);
+        let mut tx = pool.begin().await?;
+
+        if is_default {
+            sqlx::query!(
+                "UPDATE project_instructions SET is_default = 0 WHERE project_id = $1",
+                project_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query!(
+            r#"INSERT INTO project_instructions (id, project_id, name, is_default, current_version)
+               VALUES ($1, $2, $3, $4, 1)"#,
+            id,
+            project_id,
+            name,
+            is_default
+        )
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query!(
+            r#"INSERT INTO project_instruction_versions (instruction_id, version, content)
+               VALUES ($1, 1, $2)"#,
+            id,
+            data.content
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Self::find_by_id(pool, id)
+            .await?
+            .ok_or(ProjectInstructionError::NotFound)
+    }
+
+    pub async fn update(
+        pool: &SqlitePool,
+        id: Uuid,
+        data: &UpdateProjectInstruction,
+    ) -> Result<Self, ProjectInstructionError> {
+        let existing = Self::find_by_id(pool, id)
+            .await?
+            .ok_or(ProjectInstructionError::NotFound)?;
+
+        let name = data
+            .name
+            .as_deref()
+            .map(str::trim)
+            .unwrap_or(&existing.name);
+        if name != existing.name
+            && Self::name_exists(pool, existing.project_id, name, Some(id)).await?
+        {
+            return Err(ProjectInstructionError::NameExists(name.to_string()));
+        }
+        let is_default = data.is_default.unwrap_or(existing.is_default);
+        let new_version = data
+            .content
+            .as_ref()
+            .filter(|content| **content != existing.content)
+            .map(|content| (existing.current_version + 1, content));
+        let current_version = new_version
+            .map(|(version, _)| version)
+            .unwrap_or(existing.current_version);
+
+        let mut tx = pool.begin().await?;
+        if is_default && !existing.is_default {
+            sqlx::query!(
+                "UPDATE project_instructions SET is_default = 0 WHERE project_id = $1",
+                existing.project_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        if let Some((version, content)) = new_version {
+            sqlx::query!(
+                r#"INSERT INTO project_instruction_versions (instruction_id, version, content)
+                   VALUES ($1, $2, $3)"#,
+                id,
+                version,
+                content
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        sqlx::query!(
+            r#"UPDATE project_instructions
+               SET name = $2,
+                   is_default = $3,
+                   current_version = $4,
+                   updated_at = datetime('now', 'subsec')
+               WHERE id = $1"#,
+            id,
+            name,
+            is_default,
+            current_version
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Self::find_by_id(pool, id)
+            .await?
+            .ok_or(ProjectInstructionError::NotFound)
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM project_instructions WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn find_versions(
+        pool: &SqlitePool,
+        id: Uuid,
+    ) -> Result<Vec<ProjectInstructionVersion>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectInstructionVersion,
+            r#"SELECT instruction_id as "instruction_id!: Uuid",
+                      version as "version!: i64",
+                      content,
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_instruction_versions
+               WHERE instruction_id = $1
+               ORDER BY version DESC"#,
+            id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_version(
+        pool: &SqlitePool,
+        id: Uuid,
+        version: i64,
+    ) -> Result<Option<ProjectInstructionVersion>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectInstructionVersion,
+            r#"SELECT instruction_id as "instruction_id!: Uuid",
+                      version as "version!: i64",
+                      content,
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_instruction_versions
+               WHERE instruction_id = $1 AND version = $2"#,
+            id,
+            version
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn name_exists(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        name: &str,
+        exclude_id: Option<Uuid>,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"SELECT EXISTS(
+                   SELECT 1 FROM project_instructions
+                   WHERE project_id = $1 AND name = $2 AND ($3 IS NULL OR id != $3)
+               ) as "exists!: bool""#,
+            project_id,
+            name,
+            exclude_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(result.exists)
+    }
+}
+
+impl TaskInstruction {
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskInstruction,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      instruction_id as "instruction_id!: Uuid",
+                      version as "version: i64"
+               FROM task_instructions
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        data: &SetTaskInstruction,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TaskInstruction,
+            r#"INSERT INTO task_instructions (task_id, instruction_id, version)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   instruction_id = excluded.instruction_id,
+                   version = excluded.version
+               RETURNING task_id as "task_id!: Uuid",
+                         instruction_id as "instruction_id!: Uuid",
+                         version as "version: i64""#,
+            task_id,
+            data.instruction_id,
+            data.version
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn clear(pool: &SqlitePool, task_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM task_instructions WHERE task_id = $1", task_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// The instruction an agent run for this task should receive: the task's own
+    /// selection (pinned or latest version), else the project's default instruction
+    pub async fn resolve(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<Option<ResolvedInstruction>, sqlx::Error> {
+        let row = sqlx::query!(
+            r#"SELECT pi.id as "id!: Uuid",
+                      pi.name,
+                      v.version as "version!: i64",
+                      v.content
+               FROM project_instructions pi
+               LEFT JOIN task_instructions ti ON ti.instruction_id = pi.id AND ti.task_id = $1
+               JOIN project_instruction_versions v
+                 ON v.instruction_id = pi.id
+                AND v.version = COALESCE(ti.version, pi.current_version)
+               WHERE pi.project_id = $2
+                 AND (ti.task_id IS NOT NULL
+                      OR (pi.is_default = 1
+                          AND NOT EXISTS (SELECT 1 FROM task_instructions WHERE task_id = $1)))
+               ORDER BY ti.task_id IS NULL
+               LIMIT 1"#,
+            task_id,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|row| ResolvedInstruction {
+            instruction_id: row.id,
+            name: row.name,
+            version: row.version,
+            content: row.content,
+        }))
+    }
+}
@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, SqlitePool};
 use thiserror::Error;
 use ts_rs::TS;
+use utils::validation::{MAX_NAME_LEN, Validate, ValidationErrors};
 use uuid::Uuid;
 
 use super::repo::Repo;
@@ -28,6 +29,8 @@ pub struct ProjectRepo {
     pub cleanup_script: Option<String>,
     pub copy_files: Option<String>,
     pub parallel_setup_script: bool,
+    /// Target branch for new attempts when the client doesn't specify one
+    pub default_target_branch: Option<String>,
 }
 
 /// ProjectRepo with the associated repo name (for script execution in worktrees)
@@ -41,6 +44,7 @@ pub struct ProjectRepoWithName {
     pub cleanup_script: Option<String>,
     pub copy_files: Option<String>,
     pub parallel_setup_script: bool,
+    pub default_target_branch: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, TS)]
@@ -49,6 +53,17 @@ pub struct CreateProjectRepo {
     pub git_repo_path: String,
 }
 
+impl Validate for CreateProjectRepo {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("display_name", &self.display_name, MAX_NAME_LEN);
+        if self.git_repo_path.trim().is_empty() {
+            errors.add("git_repo_path", "must not be empty");
+        }
+        errors.into_result()
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, TS)]
 #[ts(export)]
 pub struct UpdateProjectRepo {
@@ -56,6 +71,7 @@ pub struct UpdateProjectRepo {
     pub cleanup_script: Option<String>,
     pub copy_files: Option<String>,
     pub parallel_setup_script: Option<bool>,
+    pub default_target_branch: Option<String>,
 }
 
 impl ProjectRepo {
@@ -71,7 +87,8 @@ impl ProjectRepo {
                       setup_script,
                       cleanup_script,
                       copy_files,
-                      parallel_setup_script as "parallel_setup_script!: bool"
+                      parallel_setup_script as "parallel_setup_script!: bool",
+                      default_target_branch
                FROM project_repos
                WHERE project_id = $1"#,
             project_id
@@ -92,7 +109,8 @@ impl ProjectRepo {
                       setup_script,
                       cleanup_script,
                       copy_files,
-                      parallel_setup_script as "parallel_setup_script!: bool"
+                      parallel_setup_script as "parallel_setup_script!: bool",
+                      default_target_branch
                FROM project_repos
                WHERE repo_id = $1"#,
             repo_id
@@ -114,7 +132,8 @@ impl ProjectRepo {
                       pr.setup_script,
                       pr.cleanup_script,
                       pr.copy_files,
-                      pr.parallel_setup_script as "parallel_setup_script!: bool"
+                      pr.parallel_setup_script as "parallel_setup_script!: bool",
+                      pr.default_target_branch
                FROM project_repos pr
                JOIN repos r ON r.id = pr.repo_id
                WHERE pr.project_id = $1
@@ -160,7 +179,8 @@ impl ProjectRepo {
                       setup_script,
                       cleanup_script,
                       copy_files,
-                      parallel_setup_script as "parallel_setup_script!: bool"
+                      parallel_setup_script as "parallel_setup_script!: bool",
+                      default_target_branch
                FROM project_repos
                WHERE project_id = $1 AND repo_id = $2"#,
             project_id,
@@ -235,7 +255,8 @@ impl ProjectRepo {
                          setup_script,
                          cleanup_script,
                          copy_files,
-                         parallel_setup_script as "parallel_setup_script!: bool""#,
+                         parallel_setup_script as "parallel_setup_script!: bool",
+                         default_target_branch"#,
             id,
             project_id,
             repo_id
@@ -259,6 +280,11 @@ impl ProjectRepo {
         let parallel_setup_script = payload
             .parallel_setup_script
             .unwrap_or(existing.parallel_setup_script);
+        let default_target_branch = payload
+            .default_target_branch
+            .as_deref()
+            .map(str::trim)
+            .filter(|branch| !branch.is_empty());
 
         sqlx::query_as!(
             ProjectRepo,
@@ -266,19 +292,22 @@ impl ProjectRepo {
                SET setup_script = $1,
                    cleanup_script = $2,
                    copy_files = $3,
-                   parallel_setup_script = $4
-               WHERE project_id = $5 AND repo_id = $6
+                   parallel_setup_script = $4,
+                   default_target_branch = $5
+               WHERE project_id = $6 AND repo_id = $7
                RETURNING id as "id!: Uuid",
                          project_id as "project_id!: Uuid",
                          repo_id as "repo_id!: Uuid",
                          setup_script,
                          cleanup_script,
                          copy_files,
-                         parallel_setup_script as "parallel_setup_script!: bool""#,
+                         parallel_setup_script as "parallel_setup_script!: bool",
+                         default_target_branch"#,
             setup_script,
             cleanup_script,
             copy_files,
             parallel_setup_script,
+            default_target_branch,
             project_id,
             repo_id
         )
@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+/// Secret token that grants read-only access to a project's public status page. The
+/// token itself is only shown when issued; the database keeps its hash.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectStatusPage {
+    pub project_id: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Number of tasks in one board column
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct StatusCount {
+    pub status: TaskStatus,
+    #[ts(type = "number")]
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct CompletedTask {
+    pub title: String,
+    /// When the task last changed while in Done
+    pub completed_at: DateTime<Utc>,
+}
+
+impl ProjectStatusPage {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectStatusPage,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_status_pages
+               WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_token_hash(
+        pool: &SqlitePool,
+        token_hash: &str,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectStatusPage,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_status_pages
+               WHERE token_hash = $1"#,
+            token_hash
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Enable the page, or replace its token so previously shared URLs stop working
+    pub async fn rotate(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        token_hash: &str,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectStatusPage,
+            r#"INSERT INTO project_status_pages (project_id, token_hash)
+               VALUES ($1, $2)
+               ON CONFLICT(project_id) DO UPDATE SET
+                   token_hash = excluded.token_hash,
+                   created_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            project_id,
+            token_hash
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_status_pages WHERE project_id = $1",
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Tasks per column, leaving out drafts since they aren't on the board.
+    /// Columns without tasks are missing.
+    pub async fn status_counts(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<StatusCount>, sqlx::Error> {
+        sqlx::query_as!(
+            StatusCount,
+            r#"SELECT t.status as "status!: TaskStatus",
+                      COUNT(*) as "count!: i64"
+               FROM tasks t
+               WHERE t.project_id = $1
+                 AND NOT EXISTS (SELECT 1 FROM task_drafts d WHERE d.task_id = t.id)
+               GROUP BY t.status"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn recently_completed(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<CompletedTask>, sqlx::Error> {
+        sqlx::query_as!(
+            CompletedTask,
+            r#"SELECT title,
+                      updated_at as "completed_at!: DateTime<Utc>"
+               FROM tasks
+               WHERE project_id = $1 AND status = 'done'
+               ORDER BY updated_at DESC
+               LIMIT $2"#,
+            project_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Sequential tasks still waiting in To Do for the queue to reach them
+    pub async fn queue_length(pool: &SqlitePool, project_id: Uuid) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64"
+               FROM tasks t
+               WHERE t.project_id = $1
+                 AND t.execution_mode = 'sequential'
+                 AND t.status = 'todo'
+                 AND NOT EXISTS (SELECT 1 FROM task_drafts d WHERE d.task_id = t.id)"#,
+            project_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+/// A status move tasks in a project are allowed to make
+#[derive(Debug, Clone, PartialEq, FromRow, Serialize, Deserialize, TS)]
+pub struct StatusTransition {
+    pub from_status: TaskStatus,
+    pub to_status: TaskStatus,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetStatusTransitions {
+    /// An empty list removes all restrictions
+    pub transitions: Vec<StatusTransition>,
+}
+
+pub struct ProjectStatusTransition;
+
+impl ProjectStatusTransition {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<StatusTransition>, sqlx::Error> {
+        sqlx::query_as!(
+            StatusTransition,
+            r#"SELECT from_status as "from_status!: TaskStatus",
+                      to_status as "to_status!: TaskStatus"
+               FROM project_status_transitions
+               WHERE project_id = $1
+               ORDER BY from_status, to_status"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replace the project's rules with `transitions`
+    pub async fn replace(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        transitions: &[StatusTransition],
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        sqlx::query!(
+            "DELETE FROM project_status_transitions WHERE project_id = $1",
+            project_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        for transition in transitions {
+            sqlx::query!(
+                r#"INSERT OR IGNORE INTO project_status_transitions (project_id, from_status, to_status)
+                   VALUES ($1, $2, $3)"#,
+                project_id,
+                transition.from_status,
+                transition.to_status
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await
+    }
+}
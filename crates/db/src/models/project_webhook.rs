@@ -0,0 +1,119 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "webhook_provider", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookProvider {
+    Github,
+    Gitlab,
+    Gitea,
+    Slack,
+    Vortex,
+}
+
+/// Secret a provider must present when delivering webhooks for a project
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProjectWebhook {
+    pub project_id: Uuid,
+    pub provider: WebhookProvider,
+    #[serde(skip_serializing)]
+    #[ts(skip)]
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetProjectWebhook {
+    pub secret: String,
+}
+
+impl ProjectWebhook {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectWebhook,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      provider as "provider!: WebhookProvider",
+                      secret,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_webhooks
+               WHERE project_id = $1
+               ORDER BY provider"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_provider(
+        pool: &SqlitePool,
+        provider: WebhookProvider,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectWebhook,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      provider as "provider!: WebhookProvider",
+                      secret,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_webhooks
+               WHERE provider = $1"#,
+            provider
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: WebhookProvider,
+        secret: &str,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProjectWebhook,
+            r#"INSERT INTO project_webhooks (project_id, provider, secret)
+               VALUES ($1, $2, $3)
+               ON CONFLICT (project_id, provider) DO UPDATE
+               SET secret = excluded.secret,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid",
+                         provider as "provider!: WebhookProvider",
+                         secret,
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            project_id,
+            provider,
+            secret
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: WebhookProvider,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_webhooks WHERE project_id = $1 AND provider = $2",
+            project_id,
+            provider
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "proposed_task_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProposedTaskStatus {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+/// A follow-up task a coding agent suggested at the end of a run
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct ProposedTask {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub execution_process_id: Option<Uuid>,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: ProposedTaskStatus,
+    /// The child task created when the proposal was accepted
+    pub task_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProposedTask {
+    pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProposedTask,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      execution_process_id as "execution_process_id: Uuid",
+                      title,
+                      description,
+                      status as "status!: ProposedTaskStatus",
+                      task_id as "task_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM proposed_tasks
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            ProposedTask,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      execution_process_id as "execution_process_id: Uuid",
+                      title,
+                      description,
+                      status as "status!: ProposedTaskStatus",
+                      task_id as "task_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM proposed_tasks
+               WHERE workspace_id = $1
+               ORDER BY created_at ASC"#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        execution_process_id: Option<Uuid>,
+        title: &str,
+        description: Option<&str>,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            ProposedTask,
+            r#"INSERT INTO proposed_tasks (id, workspace_id, execution_process_id, title, description)
+               VALUES ($1, $2, $3, $4, $5)
+               RETURNING id as "id!: Uuid",
+                         workspace_id as "workspace_id!: Uuid",
+                         execution_process_id as "execution_process_id: Uuid",
+                         title,
+                         description,
+                         status as "status!: ProposedTaskStatus",
+                         task_id as "task_id: Uuid",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            workspace_id,
+            execution_process_id,
+            title,
+            description
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Record the reviewer's decision; `task_id` is the child task created on acceptance
+    pub async fn resolve(
+        pool: &SqlitePool,
+        id: Uuid,
+        status: ProposedTaskStatus,
+        task_id: Option<Uuid>,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            ProposedTask,
+            r#"UPDATE proposed_tasks
+               SET status = $2,
+                   task_id = $3,
+                   updated_at = datetime('now', 'subsec')
+               WHERE id = $1
+               RETURNING id as "id!: Uuid",
+                         workspace_id as "workspace_id!: Uuid",
+                         execution_process_id as "execution_process_id: Uuid",
+                         title,
+                         description,
+                         status as "status!: ProposedTaskStatus",
+                         task_id as "task_id: Uuid",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            status,
+            task_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
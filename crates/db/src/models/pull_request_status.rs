@@ -0,0 +1,93 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type, types::Json};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::workspace_ci_status::CiStatus;
+
+/// Where review of a pull or merge request stands
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "review_state", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReviewState {
+    /// No review is required, or the provider doesn't say
+    None,
+    ReviewRequired,
+    Approved,
+    ChangesRequested,
+}
+
+/// One CI check or commit status reported on a pull or merge request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS)]
+pub struct CiCheck {
+    pub name: String,
+    pub status: CiStatus,
+    /// Where the check's details are shown
+    pub url: Option<String>,
+}
+
+/// The review state and checks last polled for a pull or merge request
+#[derive(Debug, Clone, FromRow)]
+pub struct PullRequestStatus {
+    pub workspace_id: Uuid,
+    pub url: String,
+    pub review_state: ReviewState,
+    pub ci_status: CiStatus,
+    pub checks: Json<Vec<CiCheck>>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl PullRequestStatus {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            PullRequestStatus,
+            r#"SELECT workspace_id as "workspace_id!: Uuid",
+                      url,
+                      review_state as "review_state!: ReviewState",
+                      ci_status as "ci_status!: CiStatus",
+                      checks as "checks!: Json<Vec<CiCheck>>",
+                      checked_at as "checked_at!: DateTime<Utc>"
+               FROM pull_request_statuses
+               WHERE workspace_id = $1"#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn upsert(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        url: &str,
+        review_state: ReviewState,
+        checks: &[CiCheck],
+        ci_status: CiStatus,
+    ) -> Result<(), sqlx::Error> {
+        let checks = Json(checks);
+        sqlx::query!(
+            r#"INSERT INTO pull_request_statuses (workspace_id, url, review_state, ci_status, checks)
+               VALUES ($1, $2, $3, $4, $5)
+               ON CONFLICT(workspace_id, url) DO UPDATE SET
+                   review_state = excluded.review_state,
+                   ci_status = excluded.ci_status,
+                   checks = excluded.checks,
+                   checked_at = datetime('now', 'subsec')"#,
+            workspace_id,
+            url,
+            review_state,
+            ci_status,
+            checks
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -80,6 +80,36 @@ impl Repo {
         .await
     }
 
+    /// Repos used by at least one project
+    pub async fn find_in_projects(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Repo,
+            r#"SELECT id as "id!: Uuid",
+                      path,
+                      name,
+                      display_name,
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM repos
+               WHERE id IN (SELECT repo_id FROM project_repos)
+               ORDER BY created_at"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn update_path(pool: &SqlitePool, id: Uuid, path: &Path) -> Result<(), sqlx::Error> {
+        let path_str = path.to_string_lossy().to_string();
+        sqlx::query!(
+            "UPDATE repos SET path = $1, updated_at = datetime('now', 'subsec') WHERE id = $2",
+            path_str,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn find_or_create<'e, E>(
         executor: E,
         path: &Path,
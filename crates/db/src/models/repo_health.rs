@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Last check of a repo's path. A repo is broken while `broken_reason` is set,
+/// e.g. after its checkout was moved or deleted.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct RepoHealth {
+    pub repo_id: Uuid,
+    /// First commit of the repository's history, used to recognise it at a new
+    /// path
+    pub root_commit: Option<String>,
+    pub remote_url: Option<String>,
+    pub broken_reason: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl RepoHealth {
+    pub async fn find_by_repo_id(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            RepoHealth,
+            r#"SELECT repo_id as "repo_id!: Uuid",
+                      root_commit,
+                      remote_url,
+                      broken_reason,
+                      checked_at as "checked_at!: DateTime<Utc>"
+               FROM repo_health
+               WHERE repo_id = $1"#,
+            repo_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Broken repos among the project's repos
+    pub async fn find_broken_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            RepoHealth,
+            r#"SELECT rh.repo_id as "repo_id!: Uuid",
+                      rh.root_commit,
+                      rh.remote_url,
+                      rh.broken_reason,
+                      rh.checked_at as "checked_at!: DateTime<Utc>"
+               FROM repo_health rh
+               JOIN project_repos pr ON pr.repo_id = rh.repo_id
+               WHERE pr.project_id = $1 AND rh.broken_reason IS NOT NULL
+               ORDER BY rh.checked_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Record the repo as healthy, keeping any identity already known when
+    /// `root_commit` or `remote_url` is `None`
+    pub async fn record_healthy(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+        root_commit: Option<&str>,
+        remote_url: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO repo_health (repo_id, root_commit, remote_url)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(repo_id) DO UPDATE SET
+                   root_commit = COALESCE(excluded.root_commit, root_commit),
+                   remote_url = COALESCE(excluded.remote_url, remote_url),
+                   broken_reason = NULL,
+                   checked_at = datetime('now', 'subsec')"#,
+            repo_id,
+            root_commit,
+            remote_url
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn mark_broken(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+        reason: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO repo_health (repo_id, broken_reason)
+               VALUES ($1, $2)
+               ON CONFLICT(repo_id) DO UPDATE SET
+                   broken_reason = excluded.broken_reason,
+                   checked_at = datetime('now', 'subsec')"#,
+            repo_id,
+            reason
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "repo_path_rule_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RepoPathRuleKind {
+    /// Changes to matching paths are violations
+    Deny,
+    /// Changes outside every allow rule are violations
+    Allow,
+}
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "repo_path_rule_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RepoPathRuleAction {
+    /// Block merging until the violation is reverted
+    Reject,
+    /// Only surface the violation
+    Flag,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct RepoPathRule {
+    pub id: Uuid,
+    pub repo_id: Uuid,
+    /// gitignore-style pattern relative to the repo root
+    pub pattern: String,
+    pub kind: RepoPathRuleKind,
+    pub action: RepoPathRuleAction,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, TS)]
+pub struct CreateRepoPathRule {
+    pub pattern: String,
+    pub kind: RepoPathRuleKind,
+    pub action: RepoPathRuleAction,
+}
+
+impl RepoPathRule {
+    pub async fn find_by_repo_id(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            RepoPathRule,
+            r#"SELECT id as "id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      pattern,
+                      kind as "kind!: RepoPathRuleKind",
+                      action as "action!: RepoPathRuleAction",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM repo_path_rules
+               WHERE repo_id = $1
+               ORDER BY created_at ASC"#,
+            repo_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        repo_id: Uuid,
+        data: &CreateRepoPathRule,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        let pattern = data.pattern.trim();
+        sqlx::query_as!(
+            RepoPathRule,
+            r#"INSERT INTO repo_path_rules (id, repo_id, pattern, kind, action)
+               VALUES ($1, $2, $3, $4, $5)
+               RETURNING id as "id!: Uuid",
+                         repo_id as "repo_id!: Uuid",
+                         pattern,
+                         kind as "kind!: RepoPathRuleKind",
+                         action as "action!: RepoPathRuleAction",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            repo_id,
+            pattern,
+            data.kind,
+            data.action
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Delete a rule belonging to `repo_id`. Returns the number of rows removed.
+    pub async fn delete(pool: &SqlitePool, repo_id: Uuid, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM repo_path_rules WHERE id = $1 AND repo_id = $2",
+            id,
+            repo_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
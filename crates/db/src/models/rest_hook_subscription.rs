@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use utils::validation::{Validate, ValidationErrors};
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, Hash, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "rest_hook_event", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RestHookEvent {
+    TaskCreated,
+    TaskStatusChanged,
+    AttemptFinished,
+    SlaBreached,
+}
+
+/// Target URL that receives a POST for every matching event
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct RestHookSubscription {
+    pub id: Uuid,
+    pub event: RestHookEvent,
+    pub target_url: String,
+    /// None subscribes to events from every project
+    pub project_id: Option<Uuid>,
+    pub created_by_user_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateRestHookSubscription {
+    pub event: RestHookEvent,
+    pub target_url: String,
+    pub project_id: Option<Uuid>,
+}
+
+impl Validate for CreateRestHookSubscription {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.http_url("target_url", &self.target_url);
+        errors.into_result()
+    }
+}
+
+impl RestHookSubscription {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            RestHookSubscription,
+            r#"SELECT id as "id!: Uuid",
+                      event as "event!: RestHookEvent",
+                      target_url,
+                      project_id as "project_id: Uuid",
+                      created_by_user_id as "created_by_user_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM rest_hook_subscriptions
+               ORDER BY created_at"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Subscriptions for an event that cover the given project
+    pub async fn find_for_event(
+        pool: &SqlitePool,
+        event: RestHookEvent,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            RestHookSubscription,
+            r#"SELECT id as "id!: Uuid",
+                      event as "event!: RestHookEvent",
+                      target_url,
+                      project_id as "project_id: Uuid",
+                      created_by_user_id as "created_by_user_id: Uuid",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM rest_hook_subscriptions
+               WHERE event = $1 AND (project_id IS NULL OR project_id = $2)"#,
+            event,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        data: &CreateRestHookSubscription,
+        created_by_user_id: Option<Uuid>,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            RestHookSubscription,
+            r#"INSERT INTO rest_hook_subscriptions (id, event, target_url, project_id, created_by_user_id)
+               VALUES ($1, $2, $3, $4, $5)
+               RETURNING id as "id!: Uuid",
+                         event as "event!: RestHookEvent",
+                         target_url,
+                         project_id as "project_id: Uuid",
+                         created_by_user_id as "created_by_user_id: Uuid",
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            data.event,
+            data.target_url,
+            data.project_id,
+            created_by_user_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM rest_hook_subscriptions WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,113 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "secret_scan_operation", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SecretScanOperation {
+    Share,
+    CreatePr,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct SecretScanFinding {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub repo_id: Uuid,
+    pub operation: SecretScanOperation,
+    pub file_path: String,
+    pub line_number: i64,
+    pub kind: String,
+    /// Prefix of the matched value; the secret itself is never stored
+    pub redacted: String,
+    pub overridden: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateSecretScanFinding {
+    pub repo_id: Uuid,
+    pub file_path: String,
+    pub line_number: i64,
+    pub kind: String,
+    pub redacted: String,
+}
+
+impl SecretScanFinding {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            SecretScanFinding,
+            r#"SELECT id as "id!: Uuid",
+                      workspace_id as "workspace_id!: Uuid",
+                      repo_id as "repo_id!: Uuid",
+                      operation as "operation!: SecretScanOperation",
+                      file_path,
+                      line_number,
+                      kind,
+                      redacted,
+                      overridden as "overridden!: bool",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM secret_scan_findings
+               WHERE workspace_id = $1
+               ORDER BY created_at DESC"#,
+            workspace_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Record the findings of one scan
+    pub async fn record(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        operation: SecretScanOperation,
+        overridden: bool,
+        findings: &[CreateSecretScanFinding],
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let mut recorded = Vec::with_capacity(findings.len());
+        for finding in findings {
+            let id = Uuid::new_v4();
+            let row = sqlx::query_as!(
+                SecretScanFinding,
+                r#"INSERT INTO secret_scan_findings
+                       (id, workspace_id, repo_id, operation, file_path, line_number, kind, redacted, overridden)
+                   VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                   RETURNING id as "id!: Uuid",
+                             workspace_id as "workspace_id!: Uuid",
+                             repo_id as "repo_id!: Uuid",
+                             operation as "operation!: SecretScanOperation",
+                             file_path,
+                             line_number,
+                             kind,
+                             redacted,
+                             overridden as "overridden!: bool",
+                             created_at as "created_at!: DateTime<Utc>""#,
+                id,
+                workspace_id,
+                finding.repo_id,
+                operation,
+                finding.file_path,
+                finding.line_number,
+                finding.kind,
+                finding.redacted,
+                overridden
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            recorded.push(row);
+        }
+        tx.commit().await?;
+        Ok(recorded)
+    }
+}
@@ -24,6 +24,18 @@ pub struct Session {
     pub updated_at: DateTime<Utc>,
 }
 
+/// A session that never recorded an execution process, typically left
+/// behind when starting an attempt failed partway through
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct StaleSession {
+    pub session_id: Uuid,
+    pub workspace_id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub executor: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Deserialize, TS)]
 pub struct CreateSession {
     pub executor: Option<String>,
@@ -110,4 +122,42 @@ impl Session {
         .fetch_one(pool)
         .await?)
     }
+
+    /// Sessions older than an hour that have no execution processes
+    pub async fn find_stale(pool: &SqlitePool) -> Result<Vec<StaleSession>, sqlx::Error> {
+        sqlx::query_as!(
+            StaleSession,
+            r#"SELECT s.id AS "session_id!: Uuid",
+                      s.workspace_id AS "workspace_id!: Uuid",
+                      t.id AS "task_id!: Uuid",
+                      t.title AS "task_title!",
+                      s.executor,
+                      s.created_at AS "created_at!: DateTime<Utc>"
+               FROM sessions s
+               JOIN workspaces w ON w.id = s.workspace_id
+               JOIN tasks t ON t.id = w.task_id
+               WHERE s.created_at < datetime('now', '-1 hour')
+                 AND NOT EXISTS (
+                     SELECT 1 FROM execution_processes ep WHERE ep.session_id = s.id
+                 )
+               ORDER BY s.created_at ASC"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Delete a session, only if it still has no execution processes
+    pub async fn delete_if_empty(pool: &SqlitePool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"DELETE FROM sessions
+               WHERE id = $1
+                 AND NOT EXISTS (
+                     SELECT 1 FROM execution_processes ep WHERE ep.session_id = sessions.id
+                 )"#,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
 }
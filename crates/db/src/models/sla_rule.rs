@@ -0,0 +1,152 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "sla_rule_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SlaRuleKind {
+    /// The task has been in the status for longer than the threshold
+    TimeInStatus,
+    /// No attempt on the task has run for longer than the threshold
+    Idle,
+}
+
+/// A limit on how long tasks in a project may sit in a status
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct SlaRule {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub status: TaskStatus,
+    pub kind: SlaRuleKind,
+    pub threshold_hours: f64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateSlaRule {
+    pub status: TaskStatus,
+    pub kind: SlaRuleKind,
+    pub threshold_hours: f64,
+}
+
+/// What the SLA monitor needs to know about a task to check it against a rule
+#[derive(Debug, Clone, FromRow)]
+pub struct SlaTaskState {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status_entered_at: DateTime<Utc>,
+    /// When an attempt last started or finished; now if one is running
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+impl SlaRule {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            SlaRule,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      status as "status!: TaskStatus",
+                      kind as "kind!: SlaRuleKind",
+                      threshold_hours,
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_sla_rules
+               ORDER BY created_at"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            SlaRule,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      status as "status!: TaskStatus",
+                      kind as "kind!: SlaRuleKind",
+                      threshold_hours,
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM project_sla_rules
+               WHERE project_id = $1
+               ORDER BY created_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        data: &CreateSlaRule,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            SlaRule,
+            r#"INSERT INTO project_sla_rules (id, project_id, status, kind, threshold_hours)
+               VALUES ($1, $2, $3, $4, $5)
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         status as "status!: TaskStatus",
+                         kind as "kind!: SlaRuleKind",
+                         threshold_hours,
+                         created_at as "created_at!: DateTime<Utc>""#,
+            id,
+            project_id,
+            data.status,
+            data.kind,
+            data.threshold_hours
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM project_sla_rules WHERE id = $1 AND project_id = $2",
+            id,
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Tasks currently in the rule's status, with when they got there and when
+    /// an attempt last ran
+    pub async fn find_task_states(
+        &self,
+        pool: &SqlitePool,
+    ) -> Result<Vec<SlaTaskState>, sqlx::Error> {
+        sqlx::query_as!(
+            SlaTaskState,
+            r#"SELECT t.id as "task_id!: Uuid",
+                      t.title,
+                      COALESCE(se.entered_at, t.updated_at) as "status_entered_at!: DateTime<Utc>",
+                      (SELECT MAX(CASE WHEN ep.status = 'running' THEN datetime('now', 'subsec')
+                                       ELSE COALESCE(ep.completed_at, ep.started_at) END)
+                       FROM execution_processes ep
+                       JOIN sessions s ON s.id = ep.session_id
+                       JOIN workspaces w ON w.id = s.workspace_id
+                       WHERE w.task_id = t.id) as "last_activity_at: DateTime<Utc>"
+               FROM tasks t
+               LEFT JOIN task_status_entered se ON se.task_id = t.id
+               WHERE t.project_id = $1 AND t.status = $2"#,
+            self.project_id,
+            self.status
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A Slack account an admin linked to a local user, so approvals and tasks from
+/// Slack are attributed to that user. Slack users without a link act as
+/// themselves only.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct SlackUserLink {
+    /// Slack's id for the user, e.g. `U2147483697`
+    pub slack_user_id: String,
+    pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetSlackUserLink {
+    pub slack_user_id: String,
+    pub user_id: Uuid,
+}
+
+impl SlackUserLink {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            SlackUserLink,
+            r#"SELECT slack_user_id,
+                      user_id as "user_id!: Uuid",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM slack_user_links
+               ORDER BY slack_user_id"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// The local user linked to a Slack user id
+    pub async fn find_user_id(
+        pool: &SqlitePool,
+        slack_user_id: &str,
+    ) -> Result<Option<Uuid>, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT user_id as "user_id!: Uuid"
+               FROM slack_user_links
+               WHERE slack_user_id = $1"#,
+            slack_user_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Link a Slack user to a local user, replacing either side's earlier link
+    pub async fn set(
+        pool: &SqlitePool,
+        slack_user_id: &str,
+        user_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        sqlx::query!(
+            "DELETE FROM slack_user_links WHERE user_id = $1 AND slack_user_id <> $2",
+            user_id,
+            slack_user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        let link = sqlx::query_as!(
+            SlackUserLink,
+            r#"INSERT INTO slack_user_links (slack_user_id, user_id)
+               VALUES ($1, $2)
+               ON CONFLICT (slack_user_id) DO UPDATE
+               SET user_id = excluded.user_id,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING slack_user_id,
+                         user_id as "user_id!: Uuid",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            slack_user_id,
+            user_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(link)
+    }
+
+    pub async fn delete(pool: &SqlitePool, slack_user_id: &str) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM slack_user_links WHERE slack_user_id = $1",
+            slack_user_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "sync_run_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum SyncRunStatus {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// One run of a provider's issue sync for a project
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct SyncRun {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Provider name as shown to users, e.g. `GitHub`
+    pub provider: String,
+    pub status: SyncRunStatus,
+    pub imported_count: i64,
+    pub updated_count: i64,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl SyncRun {
+    /// Most recent runs first
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            SyncRun,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      provider,
+                      status as "status!: SyncRunStatus",
+                      imported_count,
+                      updated_count,
+                      error,
+                      started_at as "started_at!: DateTime<Utc>",
+                      finished_at as "finished_at: DateTime<Utc>"
+               FROM sync_runs
+               WHERE project_id = $1
+               ORDER BY started_at DESC
+               LIMIT $2"#,
+            project_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn start(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        provider: &str,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            SyncRun,
+            r#"INSERT INTO sync_runs (id, project_id, provider)
+               VALUES ($1, $2, $3)
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         provider,
+                         status as "status!: SyncRunStatus",
+                         imported_count,
+                         updated_count,
+                         error,
+                         started_at as "started_at!: DateTime<Utc>",
+                         finished_at as "finished_at: DateTime<Utc>""#,
+            id,
+            project_id,
+            provider
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Mark a run finished; it failed if `error` is set
+    pub async fn finish(
+        pool: &SqlitePool,
+        id: Uuid,
+        imported_count: i64,
+        updated_count: i64,
+        error: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let status = if error.is_some() {
+            SyncRunStatus::Failed
+        } else {
+            SyncRunStatus::Succeeded
+        };
+        sqlx::query!(
+            r#"UPDATE sync_runs
+               SET status = $2,
+                   imported_count = $3,
+                   updated_count = $4,
+                   error = $5,
+                   finished_at = datetime('now', 'subsec')
+               WHERE id = $1"#,
+            id,
+            status,
+            imported_count,
+            updated_count,
+            error
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, SqlitePool};
 use ts_rs::TS;
+use utils::validation::{MAX_DESCRIPTION_LEN, MAX_NAME_LEN, Validate, ValidationErrors};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
@@ -25,6 +26,28 @@ pub struct UpdateTag {
     pub content: Option<String>,
 }
 
+impl Validate for CreateTag {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("tag_name", &self.tag_name, MAX_NAME_LEN);
+        errors.max_length("content", &self.content, MAX_DESCRIPTION_LEN);
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateTag {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(tag_name) = &self.tag_name {
+            errors.required_text("tag_name", tag_name, MAX_NAME_LEN);
+        }
+        if let Some(content) = &self.content {
+            errors.max_length("content", content, MAX_DESCRIPTION_LEN);
+        }
+        errors.into_result()
+    }
+}
+
 impl Tag {
     pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
         sqlx::query_as!(
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::{Executor, FromRow, Sqlite, SqlitePool, Type};
 use strum_macros::{Display, EnumString};
 use ts_rs::TS;
+use utils::validation::{MAX_DESCRIPTION_LEN, MAX_TITLE_LEN, Validate, ValidationErrors};
 use uuid::Uuid;
 
 use super::{project::Project, workspace::Workspace};
@@ -81,6 +82,23 @@ pub struct TaskRelationships {
     pub children: Vec<Task>,       // Tasks created from this workspace
 }
 
+/// A task's place on the project timeline. `started_at` is when its first
+/// attempt was created; `finished_at` is the task's last update once it is Done,
+/// since status changes aren't recorded separately.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TimelineTask {
+    pub id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    /// Task whose attempt spawned this one
+    pub parent_task_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub planned_start_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct CreateTask {
     pub project_id: Uuid,
@@ -141,6 +159,30 @@ pub struct UpdateTask {
     pub image_ids: Option<Vec<Uuid>>,
 }
 
+impl Validate for CreateTask {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("title", &self.title, MAX_TITLE_LEN);
+        if let Some(description) = &self.description {
+            errors.max_length("description", description, MAX_DESCRIPTION_LEN);
+        }
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdateTask {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(title) = &self.title {
+            errors.required_text("title", title, MAX_TITLE_LEN);
+        }
+        if let Some(description) = &self.description {
+            errors.max_length("description", description, MAX_DESCRIPTION_LEN);
+        }
+        errors.into_result()
+    }
+}
+
 impl Task {
     pub fn to_prompt(&self) -> String {
         if let Some(description) = self.description.as_ref().filter(|d| !d.trim().is_empty()) {
@@ -154,12 +196,100 @@ impl Task {
         Project::find_by_id(pool, self.project_id).await
     }
 
+    pub async fn find_timeline_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<TimelineTask>, sqlx::Error> {
+        sqlx::query_as!(
+            TimelineTask,
+            r#"SELECT t.id as "id!: Uuid",
+                      t.title,
+                      t.status as "status!: TaskStatus",
+                      pw.task_id as "parent_task_id: Uuid",
+                      t.created_at as "created_at!: DateTime<Utc>",
+                      (SELECT MIN(w.created_at) FROM workspaces w WHERE w.task_id = t.id)
+                          as "started_at: DateTime<Utc>",
+                      CASE WHEN t.status = 'done' THEN t.updated_at END
+                          as "finished_at: DateTime<Utc>",
+                      ts.starts_at as "planned_start_at: DateTime<Utc>",
+                      ts.due_at as "due_at: DateTime<Utc>"
+               FROM tasks t
+               LEFT JOIN workspaces pw ON pw.id = t.parent_workspace_id
+               LEFT JOIN task_schedules ts ON ts.task_id = t.id
+               WHERE t.project_id = $1
+               ORDER BY t.created_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn find_by_project_id_with_attempt_status(
         pool: &SqlitePool,
         project_id: Uuid,
+    ) -> Result<Vec<TaskWithAttemptStatus>, sqlx::Error> {
+        Self::fetch_with_attempt_status(pool, project_id, None).await
+    }
+
+    /// Fetch a single task with its attempt status, without materializing the
+    /// rest of the project's tasks.
+    pub async fn find_by_id_with_attempt_status(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        task_id: Uuid,
+    ) -> Result<Option<TaskWithAttemptStatus>, sqlx::Error> {
+        Ok(
+            Self::fetch_with_attempt_status(pool, project_id, Some(task_id))
+                .await?
+                .into_iter()
+                .next(),
+        )
+    }
+
+    /// Latest workspace, session and attempt process per task are ranked once with
+    /// window functions instead of running correlated subqueries for every task row.
+    /// The CROSS JOINs pin SQLite's join order so attempt processes are reached through
+    /// the project's tasks rather than a scan of every process with a matching run_reason.
+    async fn fetch_with_attempt_status(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        task_id: Option<Uuid>,
     ) -> Result<Vec<TaskWithAttemptStatus>, sqlx::Error> {
         let records = sqlx::query!(
-            r#"SELECT
+            r#"WITH project_tasks AS (
+  SELECT t.id
+    FROM tasks t
+   WHERE t.project_id = $1
+     AND ($2 IS NULL OR t.id = $2)
+),
+ranked_workspaces AS (
+  SELECT w.task_id,
+         w.id,
+         w.container_ref,
+         ROW_NUMBER() OVER (PARTITION BY w.task_id ORDER BY w.created_at DESC) AS rn
+    FROM workspaces w
+    JOIN project_tasks pt ON pt.id = w.task_id
+),
+ranked_sessions AS (
+  SELECT w.task_id,
+         s.executor,
+         ROW_NUMBER() OVER (PARTITION BY w.task_id ORDER BY s.created_at DESC) AS rn
+    FROM sessions s
+    JOIN workspaces w ON w.id = s.workspace_id
+    JOIN project_tasks pt ON pt.id = w.task_id
+),
+ranked_processes AS (
+  SELECT w.task_id,
+         ep.status,
+         ROW_NUMBER() OVER (PARTITION BY w.task_id ORDER BY ep.created_at DESC) AS rn,
+         MAX(ep.status = 'running') OVER (PARTITION BY w.task_id) AS has_running
+    FROM project_tasks pt
+    CROSS JOIN workspaces w ON w.task_id = pt.id
+    CROSS JOIN sessions s ON s.workspace_id = w.id
+    CROSS JOIN execution_processes ep ON ep.session_id = s.id
+   WHERE ep.run_reason IN ('setupscript','cleanupscript','codingagent')
+)
+SELECT
   t.id                            AS "id!: Uuid",
   t.project_id                    AS "project_id!: Uuid",
   t.title,
@@ -171,56 +301,20 @@ impl Task {
   t.shared_task_id                AS "shared_task_id: Uuid",
   t.created_at                    AS "created_at!: DateTime<Utc>",
   t.updated_at                    AS "updated_at!: DateTime<Utc>",
-
-  CASE WHEN EXISTS (
-    SELECT 1
-      FROM workspaces w
-      JOIN sessions s ON s.workspace_id = w.id
-      JOIN execution_processes ep ON ep.session_id = s.id
-     WHERE w.task_id       = t.id
-       AND ep.status        = 'running'
-       AND ep.run_reason IN ('setupscript','cleanupscript','codingagent')
-     LIMIT 1
-  ) THEN 1 ELSE 0 END            AS "has_in_progress_attempt!: i64",
-
-  CASE WHEN (
-    SELECT ep.status
-      FROM workspaces w
-      JOIN sessions s ON s.workspace_id = w.id
-      JOIN execution_processes ep ON ep.session_id = s.id
-     WHERE w.task_id       = t.id
-     AND ep.run_reason IN ('setupscript','cleanupscript','codingagent')
-     ORDER BY ep.created_at DESC
-     LIMIT 1
-  ) IN ('failed','killed') THEN 1 ELSE 0 END
-                                 AS "last_attempt_failed!: i64",
-
-  ( SELECT s.executor
-      FROM workspaces w
-      JOIN sessions s ON s.workspace_id = w.id
-      WHERE w.task_id = t.id
-     ORDER BY s.created_at DESC
-      LIMIT 1
-    )                               AS "executor!: String",
-
-  ( SELECT w.id
-      FROM workspaces w
-      WHERE w.task_id = t.id
-     ORDER BY w.created_at DESC
-      LIMIT 1
-    )                               AS "latest_workspace_id: Uuid",
-
-  ( SELECT w.container_ref
-      FROM workspaces w
-      WHERE w.task_id = t.id
-     ORDER BY w.created_at DESC
-      LIMIT 1
-    )                               AS "latest_workspace_container_ref: String"
-
+  COALESCE(rp.has_running, 0)     AS "has_in_progress_attempt!: i64",
+  CASE WHEN rp.status IN ('failed','killed') THEN 1 ELSE 0 END
+                                  AS "last_attempt_failed!: i64",
+  COALESCE(rs.executor, '')       AS "executor!: String",
+  rw.id                           AS "latest_workspace_id: Uuid",
+  rw.container_ref                AS "latest_workspace_container_ref: String"
 FROM tasks t
-WHERE t.project_id = $1
+JOIN project_tasks pt ON pt.id = t.id
+LEFT JOIN ranked_workspaces rw ON rw.task_id = t.id AND rw.rn = 1
+LEFT JOIN ranked_sessions rs ON rs.task_id = t.id AND rs.rn = 1
+LEFT JOIN ranked_processes rp ON rp.task_id = t.id AND rp.rn = 1
 ORDER BY t.created_at DESC"#,
-            project_id
+            project_id,
+            task_id
         )
         .fetch_all(pool)
         .await?;
@@ -276,6 +370,32 @@ ORDER BY t.created_at DESC"#,
         .await
     }
 
+    /// Open tasks of the project whose titles match an FTS5 query over the
+    /// title trigram index, best matches first
+    pub async fn search_open_by_title(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        fts_query: &str,
+        limit: i64,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            Task,
+            r#"SELECT t.id as "id!: Uuid", t.project_id as "project_id!: Uuid", t.title, t.description, t.status as "status!: TaskStatus", t.execution_mode as "execution_mode!: ExecutionMode", t.queue_position as "queue_position: i32", t.parent_workspace_id as "parent_workspace_id: Uuid", t.shared_task_id as "shared_task_id: Uuid", t.created_at as "created_at!: DateTime<Utc>", t.updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_titles_fts f
+               JOIN tasks t ON t.id = f.task_id
+               WHERE task_titles_fts MATCH $1
+                 AND t.project_id = $2
+                 AND t.status NOT IN ('done', 'cancelled')
+               ORDER BY f.rank
+               LIMIT $3"#,
+            fts_query,
+            project_id,
+            limit
+        )
+        .fetch_all(pool)
+        .await
+    }
+
     pub async fn find_by_shared_task_id<'e, E>(
         executor: E,
         shared_task_id: Uuid,
@@ -306,6 +426,17 @@ ORDER BY t.created_at DESC"#,
         .await
     }
 
+    /// Current status of every task
+    pub async fn find_all_statuses(
+        pool: &SqlitePool,
+    ) -> Result<Vec<(Uuid, TaskStatus)>, sqlx::Error> {
+        let records =
+            sqlx::query!(r#"SELECT id as "id!: Uuid", status as "status!: TaskStatus" FROM tasks"#)
+                .fetch_all(pool)
+                .await?;
+        Ok(records.into_iter().map(|r| (r.id, r.status)).collect())
+    }
+
     pub async fn create(
         pool: &SqlitePool,
         data: &CreateTask,
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "task_activity_kind", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum TaskActivityKind {
+    Created,
+    /// Status or title changed
+    Updated,
+    Deleted,
+}
+
+/// A change to a task, recorded by triggers on `tasks`
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskActivity {
+    pub task_id: Uuid,
+    pub kind: TaskActivityKind,
+    /// Title after the change
+    pub title: String,
+    /// Status after the change
+    pub status: TaskStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TaskActivity {
+    /// A project's task activity up to and including `until`, oldest first
+    pub async fn find_by_project_id_until(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskActivity,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      kind as "kind!: TaskActivityKind",
+                      title as "title!",
+                      status as "status!: TaskStatus",
+                      created_at as "created_at!: DateTime<Utc>"
+               FROM task_activity
+               WHERE project_id = $1
+                 AND julianday(created_at) <= julianday($2)
+               ORDER BY created_at ASC, rowid ASC"#,
+            project_id,
+            until
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
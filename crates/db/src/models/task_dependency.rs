@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+/// `task_id` can't start before `depends_on_task_id` is done
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskDependency {
+    pub task_id: Uuid,
+    pub depends_on_task_id: Uuid,
+    /// Current status of the task depended on
+    pub depends_on_status: TaskStatus,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateTaskDependency {
+    pub depends_on_task_id: Uuid,
+}
+
+impl TaskDependency {
+    pub fn is_satisfied(&self) -> bool {
+        self.depends_on_status == TaskStatus::Done
+    }
+
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskDependency,
+            r#"SELECT d.task_id as "task_id!: Uuid",
+                      d.depends_on_task_id as "depends_on_task_id!: Uuid",
+                      t.status as "depends_on_status!: TaskStatus",
+                      d.created_at as "created_at!: DateTime<Utc>"
+               FROM task_dependencies d
+               JOIN tasks t ON t.id = d.depends_on_task_id
+               WHERE d.task_id = $1
+               ORDER BY d.created_at ASC"#,
+            task_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Dependencies of all of a project's tasks
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskDependency,
+            r#"SELECT d.task_id as "task_id!: Uuid",
+                      d.depends_on_task_id as "depends_on_task_id!: Uuid",
+                      dep.status as "depends_on_status!: TaskStatus",
+                      d.created_at as "created_at!: DateTime<Utc>"
+               FROM task_dependencies d
+               JOIN tasks t ON t.id = d.task_id
+               JOIN tasks dep ON dep.id = d.depends_on_task_id
+               WHERE t.project_id = $1
+               ORDER BY d.created_at ASC"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Whether any task depends on `task_id`
+    pub async fn has_dependents(pool: &SqlitePool, task_id: Uuid) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT EXISTS(
+                SELECT 1 FROM task_dependencies WHERE depends_on_task_id = $1
+               ) as "exists!: bool""#,
+            task_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Adding an existing dependency again leaves it unchanged
+    pub async fn create(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query!(
+            "INSERT OR IGNORE INTO task_dependencies (task_id, depends_on_task_id) VALUES ($1, $2)",
+            task_id,
+            depends_on_task_id
+        )
+        .execute(pool)
+        .await?;
+        sqlx::query_as!(
+            TaskDependency,
+            r#"SELECT d.task_id as "task_id!: Uuid",
+                      d.depends_on_task_id as "depends_on_task_id!: Uuid",
+                      t.status as "depends_on_status!: TaskStatus",
+                      d.created_at as "created_at!: DateTime<Utc>"
+               FROM task_dependencies d
+               JOIN tasks t ON t.id = d.depends_on_task_id
+               WHERE d.task_id = $1 AND d.depends_on_task_id = $2"#,
+            task_id,
+            depends_on_task_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        depends_on_task_id: Uuid,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM task_dependencies WHERE task_id = $1 AND depends_on_task_id = $2",
+            task_id,
+            depends_on_task_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
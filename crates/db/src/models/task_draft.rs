@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A synced issue's task held off the board until someone triages it, for
+/// projects with `triage_synced_issues` on
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskDraft {
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub task_title: String,
+    /// Provider the issue was synced from, e.g. `GitHub`
+    pub source: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct TriageTaskDrafts {
+    pub task_ids: Vec<Uuid>,
+    /// Added to each task's labels, which also pick its executor through the
+    /// project's label rules
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Move the tasks to Cancelled instead of To Do, for issues that won't be
+    /// worked on
+    #[serde(default)]
+    pub dismiss: bool,
+}
+
+impl TaskDraft {
+    /// Oldest first
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskDraft,
+            r#"SELECT d.task_id as "task_id!: Uuid",
+                      d.project_id as "project_id!: Uuid",
+                      t.title as "task_title!",
+                      d.source,
+                      d.created_at as "created_at!: DateTime<Utc>"
+               FROM task_drafts d
+               JOIN tasks t ON t.id = d.task_id
+               WHERE d.project_id = $1
+               ORDER BY d.created_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn task_ids_for_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT task_id as "task_id!: Uuid" FROM task_drafts WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn exists(pool: &SqlitePool, task_id: Uuid) -> Result<bool, sqlx::Error> {
+        let found = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM task_drafts WHERE task_id = $1) as "found!: bool""#,
+            task_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(found)
+    }
+
+    pub async fn create(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        project_id: Uuid,
+        source: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO task_drafts (task_id, project_id, source)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(task_id) DO NOTHING"#,
+            task_id,
+            project_id,
+            source
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete(pool: &SqlitePool, task_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM task_drafts WHERE task_id = $1", task_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
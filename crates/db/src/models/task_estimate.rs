@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, Hash, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "estimate_unit", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum EstimateUnit {
+    Points,
+    Hours,
+}
+
+/// How much work a task is expected to take. Unestimated tasks have no row.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskEstimate {
+    pub task_id: Uuid,
+    pub value: f64,
+    pub unit: EstimateUnit,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A null `value` clears the estimate
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskEstimate {
+    pub value: Option<f64>,
+    pub unit: EstimateUnit,
+}
+
+/// Estimate of a task that is not Done or Cancelled, with who it is assigned to
+#[derive(Debug, Clone, FromRow)]
+pub struct OpenTaskEstimate {
+    pub task_id: Uuid,
+    pub assignee_user_id: Option<Uuid>,
+    pub value: f64,
+    pub unit: EstimateUnit,
+}
+
+impl TaskEstimate {
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskEstimate,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      value,
+                      unit as "unit!: EstimateUnit",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_estimates
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_open_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<OpenTaskEstimate>, sqlx::Error> {
+        sqlx::query_as!(
+            OpenTaskEstimate,
+            r#"SELECT e.task_id as "task_id!: Uuid",
+                      o.assignee_user_id as "assignee_user_id: Uuid",
+                      e.value,
+                      e.unit as "unit!: EstimateUnit"
+               FROM task_estimates e
+               JOIN tasks t ON t.id = e.task_id
+               LEFT JOIN task_owners o ON o.task_id = e.task_id
+               WHERE t.project_id = $1 AND t.status NOT IN ('done', 'cancelled')"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        value: f64,
+        unit: EstimateUnit,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TaskEstimate,
+            r#"INSERT INTO task_estimates (task_id, value, unit)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   value = excluded.value,
+                   unit = excluded.unit,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING task_id as "task_id!: Uuid",
+                         value,
+                         unit as "unit!: EstimateUnit",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            task_id,
+            value,
+            unit
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, task_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM task_estimates WHERE task_id = $1", task_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::task::TaskStatus;
+
+/// Tasks tracked together, such as the tasks of a GitHub milestone
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskGroup {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    /// Provider name as shown to users, e.g. `GitHub`
+    pub provider: String,
+    /// What the provider's API addresses the group by, e.g. the milestone number
+    pub external_id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub closed: bool,
+    pub due_at: Option<DateTime<Utc>>,
+    /// Open issues as the provider counts them, including issues without a task
+    #[ts(type = "number")]
+    pub open_issues: i64,
+    #[ts(type = "number")]
+    pub closed_issues: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpsertTaskGroup<'a> {
+    pub provider: &'a str,
+    pub external_id: &'a str,
+    pub title: &'a str,
+    pub description: Option<&'a str>,
+    pub url: &'a str,
+    pub closed: bool,
+    pub due_at: Option<DateTime<Utc>>,
+    pub open_issues: i64,
+    pub closed_issues: i64,
+}
+
+/// A task's group, with the task's status for counting progress
+#[derive(Debug, Clone, FromRow)]
+pub struct TaskGroupMember {
+    pub task_id: Uuid,
+    pub task_group_id: Uuid,
+    pub status: TaskStatus,
+}
+
+/// A group with the tasks on the board that belong to it
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct TaskGroupWithProgress {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub group: TaskGroup,
+    pub task_ids: Vec<Uuid>,
+    /// How many of the tasks are Done
+    pub tasks_done: usize,
+}
+
+impl TaskGroup {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskGroup,
+            r#"SELECT id as "id!: Uuid",
+                      project_id as "project_id!: Uuid",
+                      provider,
+                      external_id,
+                      title,
+                      description,
+                      url,
+                      closed as "closed!: bool",
+                      due_at as "due_at: DateTime<Utc>",
+                      open_issues as "open_issues!: i64",
+                      closed_issues as "closed_issues!: i64",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_groups
+               WHERE project_id = $1
+               ORDER BY closed ASC, due_at IS NULL, due_at ASC, title ASC"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Create the group, or refresh it from the provider if the project has it
+    /// already
+    pub async fn upsert(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        data: &UpsertTaskGroup<'_>,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            TaskGroup,
+            r#"INSERT INTO task_groups
+                   (id, project_id, provider, external_id, title, description, url, closed,
+                    due_at, open_issues, closed_issues)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+               ON CONFLICT(project_id, provider, external_id) DO UPDATE SET
+                   title = excluded.title,
+                   description = excluded.description,
+                   url = excluded.url,
+                   closed = excluded.closed,
+                   due_at = excluded.due_at,
+                   open_issues = excluded.open_issues,
+                   closed_issues = excluded.closed_issues,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING id as "id!: Uuid",
+                         project_id as "project_id!: Uuid",
+                         provider,
+                         external_id,
+                         title,
+                         description,
+                         url,
+                         closed as "closed!: bool",
+                         due_at as "due_at: DateTime<Utc>",
+                         open_issues as "open_issues!: i64",
+                         closed_issues as "closed_issues!: i64",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            project_id,
+            data.provider,
+            data.external_id,
+            data.title,
+            data.description,
+            data.url,
+            data.closed,
+            data.due_at,
+            data.open_issues,
+            data.closed_issues
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Delete a group. Its tasks stay, ungrouped.
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM task_groups WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    pub async fn find_members(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<TaskGroupMember>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskGroupMember,
+            r#"SELECT m.task_id as "task_id!: Uuid",
+                      m.task_group_id as "task_group_id!: Uuid",
+                      t.status as "status!: TaskStatus"
+               FROM task_group_tasks m
+               JOIN tasks t ON t.id = m.task_id
+               WHERE t.project_id = $1"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Put a task in a group, moving it out of any other, or take it out of its
+    /// group with `None`
+    pub async fn set_task_group(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        task_group_id: Option<Uuid>,
+    ) -> Result<(), sqlx::Error> {
+        match task_group_id {
+            Some(task_group_id) => {
+                sqlx::query!(
+                    r#"INSERT INTO task_group_tasks (task_id, task_group_id)
+                       VALUES ($1, $2)
+                       ON CONFLICT(task_id) DO UPDATE SET task_group_id = excluded.task_group_id"#,
+                    task_id,
+                    task_group_id
+                )
+                .execute(pool)
+                .await?;
+            }
+            None => {
+                sqlx::query!("DELETE FROM task_group_tasks WHERE task_id = $1", task_id)
+                    .execute(pool)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The project's groups, open ones first by due date, each with its tasks
+    pub async fn find_with_progress(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<TaskGroupWithProgress>, sqlx::Error> {
+        let mut members: HashMap<Uuid, Vec<TaskGroupMember>> = HashMap::new();
+        for member in Self::find_members(pool, project_id).await? {
+            members
+                .entry(member.task_group_id)
+                .or_default()
+                .push(member);
+        }
+        Ok(Self::find_by_project_id(pool, project_id)
+            .await?
+            .into_iter()
+            .map(|group| {
+                let members = members.remove(&group.id).unwrap_or_default();
+                TaskGroupWithProgress {
+                    tasks_done: members
+                        .iter()
+                        .filter(|member| member.status == TaskStatus::Done)
+                        .count(),
+                    task_ids: members.iter().map(|member| member.task_id).collect(),
+                    group,
+                }
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Which way labels flow between tasks and the provider issues they were
+/// imported from
+#[derive(
+    Debug, Clone, Copy, Default, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "label_sync_direction", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum LabelSyncDirection {
+    Off,
+    /// Issue labels are copied onto the task when it is imported
+    #[default]
+    Import,
+    /// Label changes on the task are pushed to the issue
+    Export,
+    Both,
+}
+
+impl LabelSyncDirection {
+    pub fn imports(self) -> bool {
+        matches!(self, Self::Import | Self::Both)
+    }
+
+    pub fn exports(self) -> bool {
+        matches!(self, Self::Export | Self::Both)
+    }
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskLabels {
+    pub labels: Vec<String>,
+}
+
+pub struct TaskLabel;
+
+impl TaskLabel {
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"SELECT name as "name!" FROM task_labels WHERE task_id = $1 ORDER BY name"#,
+            task_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Replace a task's labels. Blank names are dropped and duplicates that differ
+    /// only in case are kept once.
+    pub async fn set(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        labels: &[String],
+    ) -> Result<Vec<String>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        sqlx::query!("DELETE FROM task_labels WHERE task_id = $1", task_id)
+            .execute(&mut *tx)
+            .await?;
+        for label in labels {
+            let name = label.trim();
+            if name.is_empty() {
+                continue;
+            }
+            sqlx::query!(
+                "INSERT OR IGNORE INTO task_labels (task_id, name) VALUES ($1, $2)",
+                task_id,
+                name
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Self::find_by_task_id(pool, task_id).await
+    }
+}
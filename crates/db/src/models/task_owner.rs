@@ -0,0 +1,97 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Creator and assignee of a task. Tasks created before sign-in existed have no row.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct TaskOwner {
+    pub task_id: Uuid,
+    pub created_by_user_id: Option<Uuid>,
+    pub assignee_user_id: Option<Uuid>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskAssignee {
+    pub assignee_user_id: Option<Uuid>,
+}
+
+impl TaskOwner {
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskOwner,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      created_by_user_id as "created_by_user_id: Uuid",
+                      assignee_user_id as "assignee_user_id: Uuid",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_owners
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Tasks the user created or is assigned to
+    pub async fn find_for_user(pool: &SqlitePool, user_id: Uuid) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskOwner,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      created_by_user_id as "created_by_user_id: Uuid",
+                      assignee_user_id as "assignee_user_id: Uuid",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_owners
+               WHERE created_by_user_id = $1 OR assignee_user_id = $1"#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set_creator(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO task_owners (task_id, created_by_user_id)
+               VALUES ($1, $2)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   created_by_user_id = excluded.created_by_user_id,
+                   updated_at = datetime('now', 'subsec')"#,
+            task_id,
+            user_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn set_assignee(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        assignee_user_id: Option<Uuid>,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TaskOwner,
+            r#"INSERT INTO task_owners (task_id, assignee_user_id)
+               VALUES ($1, $2)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   assignee_user_id = excluded.assignee_user_id,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING task_id as "task_id!: Uuid",
+                         created_by_user_id as "created_by_user_id: Uuid",
+                         assignee_user_id as "assignee_user_id: Uuid",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            task_id,
+            assignee_user_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
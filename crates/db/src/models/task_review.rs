@@ -0,0 +1,158 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "task_review_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TaskReviewStatus {
+    Pending,
+    Approved,
+    ChangesRequested,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskReview {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub reviewer_id: Uuid,
+    pub requested_by: Option<Uuid>,
+    pub status: TaskReviewStatus,
+    pub comment: Option<String>,
+    pub responded_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskReview {
+    pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskReview,
+            r#"SELECT id as "id!: Uuid",
+                      task_id as "task_id!: Uuid",
+                      reviewer_id as "reviewer_id!: Uuid",
+                      requested_by as "requested_by: Uuid",
+                      status as "status!: TaskReviewStatus",
+                      comment,
+                      responded_at as "responded_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_reviews
+               WHERE id = $1"#,
+            id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskReview,
+            r#"SELECT id as "id!: Uuid",
+                      task_id as "task_id!: Uuid",
+                      reviewer_id as "reviewer_id!: Uuid",
+                      requested_by as "requested_by: Uuid",
+                      status as "status!: TaskReviewStatus",
+                      comment,
+                      responded_at as "responded_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_reviews
+               WHERE task_id = $1
+               ORDER BY created_at ASC"#,
+            task_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Request a review from `reviewer_id`. Re-requesting from the same reviewer
+    /// resets their previous verdict back to pending.
+    pub async fn request(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        reviewer_id: Uuid,
+        requested_by: Option<Uuid>,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            TaskReview,
+            r#"INSERT INTO task_reviews (id, task_id, reviewer_id, requested_by)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(task_id, reviewer_id) DO UPDATE SET
+                   requested_by = excluded.requested_by,
+                   status = 'pending',
+                   comment = NULL,
+                   responded_at = NULL,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING id as "id!: Uuid",
+                         task_id as "task_id!: Uuid",
+                         reviewer_id as "reviewer_id!: Uuid",
+                         requested_by as "requested_by: Uuid",
+                         status as "status!: TaskReviewStatus",
+                         comment,
+                         responded_at as "responded_at: DateTime<Utc>",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            task_id,
+            reviewer_id,
+            requested_by
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn respond(
+        pool: &SqlitePool,
+        id: Uuid,
+        status: TaskReviewStatus,
+        comment: Option<&str>,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TaskReview,
+            r#"UPDATE task_reviews
+               SET status = $2,
+                   comment = $3,
+                   responded_at = datetime('now', 'subsec'),
+                   updated_at = datetime('now', 'subsec')
+               WHERE id = $1
+               RETURNING id as "id!: Uuid",
+                         task_id as "task_id!: Uuid",
+                         reviewer_id as "reviewer_id!: Uuid",
+                         requested_by as "requested_by: Uuid",
+                         status as "status!: TaskReviewStatus",
+                         comment,
+                         responded_at as "responded_at: DateTime<Utc>",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            id,
+            status,
+            comment
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Whether every reviewer on the task has approved
+    pub async fn all_approved(pool: &SqlitePool, task_id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query!(
+            r#"SELECT COUNT(*) > 0 AND SUM(status != 'approved') = 0 as "all_approved!: bool"
+               FROM task_reviews
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_one(pool)
+        .await?;
+        Ok(result.all_approved)
+    }
+}
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// When a task is planned to start and when it is due. Unscheduled tasks have no row.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, TS)]
+pub struct TaskSchedule {
+    pub task_id: Uuid,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTaskSchedule {
+    pub starts_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+/// A scheduled task with the fields a calendar entry needs
+#[derive(Debug, Clone, FromRow)]
+pub struct ScheduledTask {
+    pub task_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskSchedule {
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskSchedule,
+            r#"SELECT task_id as "task_id!: Uuid",
+                      starts_at as "starts_at: DateTime<Utc>",
+                      due_at as "due_at: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_schedules
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_scheduled_tasks_by_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<ScheduledTask>, sqlx::Error> {
+        sqlx::query_as!(
+            ScheduledTask,
+            r#"SELECT t.id as "task_id!: Uuid",
+                      t.title,
+                      t.description,
+                      s.starts_at as "starts_at: DateTime<Utc>",
+                      s.due_at as "due_at: DateTime<Utc>",
+                      s.updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_schedules s
+               JOIN tasks t ON t.id = s.task_id
+               WHERE t.project_id = $1
+                 AND (s.starts_at IS NOT NULL OR s.due_at IS NOT NULL)
+               ORDER BY COALESCE(s.starts_at, s.due_at)"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        schedule: &SetTaskSchedule,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TaskSchedule,
+            r#"INSERT INTO task_schedules (task_id, starts_at, due_at)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   starts_at = excluded.starts_at,
+                   due_at = excluded.due_at,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING task_id as "task_id!: Uuid",
+                         starts_at as "starts_at: DateTime<Utc>",
+                         due_at as "due_at: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            task_id,
+            schedule.starts_at,
+            schedule.due_at
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// A task found breaching an SLA rule. Open until the task stops breaching.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TaskSlaBreach {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub rule_id: Uuid,
+    pub breached_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl TaskSlaBreach {
+    pub async fn find_open_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskSlaBreach,
+            r#"SELECT b.id as "id!: Uuid",
+                      b.task_id as "task_id!: Uuid",
+                      b.rule_id as "rule_id!: Uuid",
+                      b.breached_at as "breached_at!: DateTime<Utc>",
+                      b.resolved_at as "resolved_at: DateTime<Utc>"
+               FROM task_sla_breaches b
+               JOIN tasks t ON t.id = b.task_id
+               WHERE t.project_id = $1 AND b.resolved_at IS NULL
+               ORDER BY b.breached_at"#,
+            project_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_open_by_rule_id(
+        pool: &SqlitePool,
+        rule_id: Uuid,
+    ) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TaskSlaBreach,
+            r#"SELECT id as "id!: Uuid",
+                      task_id as "task_id!: Uuid",
+                      rule_id as "rule_id!: Uuid",
+                      breached_at as "breached_at!: DateTime<Utc>",
+                      resolved_at as "resolved_at: DateTime<Utc>"
+               FROM task_sla_breaches
+               WHERE rule_id = $1 AND resolved_at IS NULL"#,
+            rule_id
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn open(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        let id = Uuid::new_v4();
+        sqlx::query_as!(
+            TaskSlaBreach,
+            r#"INSERT INTO task_sla_breaches (id, task_id, rule_id)
+               VALUES ($1, $2, $3)
+               RETURNING id as "id!: Uuid",
+                         task_id as "task_id!: Uuid",
+                         rule_id as "rule_id!: Uuid",
+                         breached_at as "breached_at!: DateTime<Utc>",
+                         resolved_at as "resolved_at: DateTime<Utc>""#,
+            id,
+            task_id,
+            rule_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn resolve(pool: &SqlitePool, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE task_sla_breaches SET resolved_at = datetime('now', 'subsec') WHERE id = $1",
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// What happens to an attempt once it spends more tokens than its budget
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "budget_action", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum BudgetAction {
+    /// Stop the agent as if the user had; a follow-up resumes the attempt
+    Pause,
+    /// Stop the agent and mark the run failed
+    Abort,
+}
+
+/// Most tokens a coding agent attempt may spend, set for a whole project or
+/// for one task. A task budget takes precedence over its project's.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct TokenBudget {
+    #[ts(type = "number")]
+    pub max_tokens: i64,
+    pub action: BudgetAction,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A null `max_tokens` removes the budget
+#[derive(Debug, Deserialize, TS)]
+pub struct SetTokenBudget {
+    #[ts(type = "number | null")]
+    pub max_tokens: Option<i64>,
+    pub action: BudgetAction,
+}
+
+impl TokenBudget {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TokenBudget,
+            r#"SELECT max_tokens,
+                      action as "action!: BudgetAction",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM project_token_budgets
+               WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_task_id(
+        pool: &SqlitePool,
+        task_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            TokenBudget,
+            r#"SELECT max_tokens,
+                      action as "action!: BudgetAction",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM task_token_budgets
+               WHERE task_id = $1"#,
+            task_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// The budget that applies to the task's attempts: its own, else its project's
+    pub async fn find_for_task(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        project_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        match Self::find_by_task_id(pool, task_id).await? {
+            Some(budget) => Ok(Some(budget)),
+            None => Self::find_by_project_id(pool, project_id).await,
+        }
+    }
+
+    pub async fn set_for_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        max_tokens: i64,
+        action: BudgetAction,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TokenBudget,
+            r#"INSERT INTO project_token_budgets (project_id, max_tokens, action)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(project_id) DO UPDATE SET
+                   max_tokens = excluded.max_tokens,
+                   action = excluded.action,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING max_tokens,
+                         action as "action!: BudgetAction",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            project_id,
+            max_tokens,
+            action
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn set_for_task(
+        pool: &SqlitePool,
+        task_id: Uuid,
+        max_tokens: i64,
+        action: BudgetAction,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            TokenBudget,
+            r#"INSERT INTO task_token_budgets (task_id, max_tokens, action)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(task_id) DO UPDATE SET
+                   max_tokens = excluded.max_tokens,
+                   action = excluded.action,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING max_tokens,
+                         action as "action!: BudgetAction",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            task_id,
+            max_tokens,
+            action
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete_for_project(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM project_token_budgets WHERE project_id = $1",
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_for_task(pool: &SqlitePool, task_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM task_token_budgets WHERE task_id = $1", task_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -24,6 +24,8 @@ pub enum UserError {
 pub enum UserRole {
     Admin,
     User,
+    /// Read-only access, held by kiosk tokens rather than user accounts
+    Viewer,
 }
 
 impl std::fmt::Display for UserRole {
@@ -31,6 +33,7 @@ impl std::fmt::Display for UserRole {
         match self {
             UserRole::Admin => write!(f, "admin"),
             UserRole::User => write!(f, "user"),
+            UserRole::Viewer => write!(f, "viewer"),
         }
     }
 }
@@ -42,6 +45,7 @@ impl std::str::FromStr for UserRole {
         match s.to_lowercase().as_str() {
             "admin" => Ok(UserRole::Admin),
             "user" => Ok(UserRole::User),
+            "viewer" => Ok(UserRole::Viewer),
             _ => Err(format!("Invalid role: {}", s)),
         }
     }
@@ -79,7 +83,7 @@ pub struct UserPublic {
     pub id: Uuid,
     pub username: String,
     pub email: Option<String>,
-    #[ts(type = "\"admin\" | \"user\"")]
+    #[ts(type = "\"admin\" | \"user\" | \"viewer\"")]
     pub role: String,
     #[ts(type = "string")]
     pub created_at: DateTime<Utc>,
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// How much work a user can take on per week, in either estimate unit. Users
+/// without a row have no capacity set.
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct UserCapacity {
+    pub user_id: Uuid,
+    pub weekly_hours: Option<f64>,
+    pub weekly_points: Option<f64>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct SetUserCapacity {
+    pub weekly_hours: Option<f64>,
+    pub weekly_points: Option<f64>,
+}
+
+impl UserCapacity {
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            UserCapacity,
+            r#"SELECT user_id as "user_id!: Uuid",
+                      weekly_hours,
+                      weekly_points,
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM user_capacities"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn find_by_user_id(
+        pool: &SqlitePool,
+        user_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            UserCapacity,
+            r#"SELECT user_id as "user_id!: Uuid",
+                      weekly_hours,
+                      weekly_points,
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM user_capacities
+               WHERE user_id = $1"#,
+            user_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn set(
+        pool: &SqlitePool,
+        user_id: Uuid,
+        data: &SetUserCapacity,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            UserCapacity,
+            r#"INSERT INTO user_capacities (user_id, weekly_hours, weekly_points)
+               VALUES ($1, $2, $3)
+               ON CONFLICT(user_id) DO UPDATE SET
+                   weekly_hours = excluded.weekly_hours,
+                   weekly_points = excluded.weekly_points,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING user_id as "user_id!: Uuid",
+                         weekly_hours,
+                         weekly_points,
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            user_id,
+            data.weekly_hours,
+            data.weekly_points
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
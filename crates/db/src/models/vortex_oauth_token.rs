@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, SqlitePool};
+use uuid::Uuid;
+
+/// Vortex OAuth tokens a project was connected with. `access_token` and
+/// `refresh_token` hold ciphertext; callers encrypt and decrypt them.
+#[derive(Debug, Clone, FromRow)]
+pub struct VortexOAuthToken {
+    pub project_id: Uuid,
+    pub access_token: String,
+    pub refresh_token: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl VortexOAuthToken {
+    pub async fn find_by_project_id(
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            VortexOAuthToken,
+            r#"SELECT project_id as "project_id!: Uuid",
+                      access_token,
+                      refresh_token,
+                      expires_at as "expires_at: DateTime<Utc>",
+                      created_at as "created_at!: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM vortex_oauth_tokens
+               WHERE project_id = $1"#,
+            project_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn upsert(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        access_token: &str,
+        refresh_token: Option<&str>,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            VortexOAuthToken,
+            r#"INSERT INTO vortex_oauth_tokens (project_id, access_token, refresh_token, expires_at)
+               VALUES ($1, $2, $3, $4)
+               ON CONFLICT(project_id) DO UPDATE SET
+                   access_token = excluded.access_token,
+                   refresh_token = COALESCE(excluded.refresh_token, vortex_oauth_tokens.refresh_token),
+                   expires_at = excluded.expires_at,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING project_id as "project_id!: Uuid",
+                         access_token,
+                         refresh_token,
+                         expires_at as "expires_at: DateTime<Utc>",
+                         created_at as "created_at!: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            project_id,
+            access_token,
+            refresh_token,
+            expires_at
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, project_id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!(
+            "DELETE FROM vortex_oauth_tokens WHERE project_id = $1",
+            project_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
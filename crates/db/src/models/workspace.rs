@@ -32,6 +32,18 @@ pub struct ContainerInfo {
     pub project_id: Uuid,
 }
 
+/// A workspace that points at a directory on disk, with its task for display
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct WorkspaceDirectory {
+    pub workspace_id: Uuid,
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub task_title: String,
+    pub container_ref: String,
+    pub branch: String,
+    pub updated_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Type, Serialize, Deserialize, PartialEq, TS)]
 #[sqlx(type_name = "workspace_status", rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -52,6 +64,8 @@ pub struct Workspace {
     pub branch: String,
     pub agent_working_dir: Option<String>,
     pub setup_completed_at: Option<DateTime<Utc>>,
+    /// Commit that landed the branch, once a merge or merged PR is recorded
+    pub merge_commit_sha: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -112,6 +126,7 @@ impl Workspace {
                               branch,
                               agent_working_dir,
                               setup_completed_at AS "setup_completed_at: DateTime<Utc>",
+                              merge_commit_sha,
                               created_at AS "created_at!: DateTime<Utc>",
                               updated_at AS "updated_at!: DateTime<Utc>"
                        FROM workspaces
@@ -130,6 +145,7 @@ impl Workspace {
                               branch,
                               agent_working_dir,
                               setup_completed_at AS "setup_completed_at: DateTime<Utc>",
+                              merge_commit_sha,
                               created_at AS "created_at!: DateTime<Utc>",
                               updated_at AS "updated_at!: DateTime<Utc>"
                        FROM workspaces
@@ -158,6 +174,7 @@ impl Workspace {
                        w.branch,
                        w.agent_working_dir,
                        w.setup_completed_at AS "setup_completed_at: DateTime<Utc>",
+                       w.merge_commit_sha,
                        w.created_at        AS "created_at!: DateTime<Utc>",
                        w.updated_at        AS "updated_at!: DateTime<Utc>"
                FROM    workspaces w
@@ -223,6 +240,21 @@ impl Workspace {
         Ok(())
     }
 
+    pub async fn record_merge_commit(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        merge_commit_sha: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE workspaces SET merge_commit_sha = $1, updated_at = datetime('now') WHERE id = $2",
+            merge_commit_sha,
+            workspace_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn find_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Self>, sqlx::Error> {
         sqlx::query_as!(
             Workspace,
@@ -232,6 +264,7 @@ impl Workspace {
                        branch,
                        agent_working_dir,
                        setup_completed_at AS "setup_completed_at: DateTime<Utc>",
+                       merge_commit_sha,
                        created_at        AS "created_at!: DateTime<Utc>",
                        updated_at        AS "updated_at!: DateTime<Utc>"
                FROM    workspaces
@@ -251,6 +284,7 @@ impl Workspace {
                        branch,
                        agent_working_dir,
                        setup_completed_at AS "setup_completed_at: DateTime<Utc>",
+                       merge_commit_sha,
                        created_at        AS "created_at!: DateTime<Utc>",
                        updated_at        AS "updated_at!: DateTime<Utc>"
                FROM    workspaces
@@ -275,6 +309,35 @@ impl Workspace {
         Ok(result.exists)
     }
 
+    /// Every workspace with a directory recorded, oldest activity first
+    pub async fn find_all_directories(
+        pool: &SqlitePool,
+    ) -> Result<Vec<WorkspaceDirectory>, sqlx::Error> {
+        sqlx::query_as!(
+            WorkspaceDirectory,
+            r#"SELECT w.id as "workspace_id!: Uuid",
+                      w.task_id as "task_id!: Uuid",
+                      t.project_id as "project_id!: Uuid",
+                      t.title as "task_title!",
+                      w.container_ref as "container_ref!",
+                      w.branch as "branch!",
+                      w.updated_at as "updated_at!: DateTime<Utc>"
+               FROM workspaces w
+               JOIN tasks t ON w.task_id = t.id
+               WHERE w.container_ref IS NOT NULL
+               ORDER BY w.updated_at ASC"#
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn delete(pool: &SqlitePool, id: Uuid) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query!("DELETE FROM workspaces WHERE id = $1", id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
     /// Find workspaces that are expired (72+ hours since last activity) and eligible for cleanup
     pub async fn find_expired_for_cleanup(
         pool: &SqlitePool,
@@ -289,6 +352,7 @@ impl Workspace {
                 w.branch as "branch!",
                 w.agent_working_dir,
                 w.setup_completed_at as "setup_completed_at: DateTime<Utc>",
+                w.merge_commit_sha,
                 w.created_at as "created_at!: DateTime<Utc>",
                 w.updated_at as "updated_at!: DateTime<Utc>"
             FROM workspaces w
@@ -332,7 +396,7 @@ impl Workspace {
             Workspace,
             r#"INSERT INTO workspaces (id, task_id, container_ref, branch, agent_working_dir, setup_completed_at)
                VALUES ($1, $2, $3, $4, $5, $6)
-               RETURNING id as "id!: Uuid", task_id as "task_id!: Uuid", container_ref, branch, agent_working_dir, setup_completed_at as "setup_completed_at: DateTime<Utc>", created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
+               RETURNING id as "id!: Uuid", task_id as "task_id!: Uuid", container_ref, branch, agent_working_dir, setup_completed_at as "setup_completed_at: DateTime<Utc>", merge_commit_sha, created_at as "created_at!: DateTime<Utc>", updated_at as "updated_at!: DateTime<Utc>""#,
             id,
             task_id,
             Option::<String>::None,
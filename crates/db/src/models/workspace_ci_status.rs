@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, SqlitePool, Type};
+use strum_macros::{Display, EnumString};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Combined CI result of the open pull and merge requests of a workspace
+#[derive(
+    Debug, Clone, Copy, Type, Serialize, Deserialize, PartialEq, Eq, TS, EnumString, Display,
+)]
+#[sqlx(type_name = "ci_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum CiStatus {
+    /// No open pull request, or no CI reported on it
+    None,
+    Pending,
+    Success,
+    Failure,
+}
+
+impl CiStatus {
+    /// Whether a task with this result has to wait before it can be Done
+    pub fn blocks_done(self) -> bool {
+        matches!(self, Self::Pending | Self::Failure)
+    }
+}
+
+/// The last CI result polled for a workspace
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct WorkspaceCiStatus {
+    pub workspace_id: Uuid,
+    pub status: CiStatus,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl WorkspaceCiStatus {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            WorkspaceCiStatus,
+            r#"SELECT workspace_id as "workspace_id!: Uuid",
+                      status as "status!: CiStatus",
+                      checked_at as "checked_at!: DateTime<Utc>"
+               FROM workspace_ci_statuses
+               WHERE workspace_id = $1"#,
+            workspace_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn upsert(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        status: CiStatus,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            WorkspaceCiStatus,
+            r#"INSERT INTO workspace_ci_statuses (workspace_id, status)
+               VALUES ($1, $2)
+               ON CONFLICT(workspace_id) DO UPDATE SET
+                   status = excluded.status,
+                   checked_at = datetime('now', 'subsec')
+               RETURNING workspace_id as "workspace_id!: Uuid",
+                         status as "status!: CiStatus",
+                         checked_at as "checked_at!: DateTime<Utc>""#,
+            workspace_id,
+            status
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
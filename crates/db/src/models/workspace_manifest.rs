@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use executors::profile::ExecutorProfileId;
+use serde::{Deserialize, Serialize};
+use sqlx::{SqlitePool, types::Json};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Everything needed to reproduce or audit an attempt: the commits it started
+/// from, the agent it ran and the machine it ran on. Captured once, when the
+/// attempt starts.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct WorkspaceManifest {
+    pub workspace_id: Uuid,
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub branch: String,
+    pub repos: Vec<ManifestRepo>,
+    pub executor_profile_id: ExecutorProfileId,
+    /// The resolved profile configuration, including any command overrides.
+    /// Default agent commands are pinned per release, so together with
+    /// `app_version` this identifies the agent version.
+    pub executor_config: Option<serde_json::Value>,
+    pub app_version: String,
+    /// Names of the variables set on the agent's process; values are left out
+    pub env_var_names: Vec<String>,
+    pub os: ManifestOs,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ManifestRepo {
+    pub repo_id: Uuid,
+    pub name: String,
+    pub target_branch: String,
+    /// Commit the attempt branch pointed at when the attempt started
+    pub base_commit: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ManifestOs {
+    pub os_type: String,
+    pub version: String,
+    pub architecture: Option<String>,
+}
+
+impl WorkspaceManifest {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        let row = sqlx::query!(
+            r#"SELECT manifest as "manifest!: Json<WorkspaceManifest>"
+               FROM workspace_manifests
+               WHERE workspace_id = $1"#,
+            workspace_id
+        )
+        .fetch_optional(pool)
+        .await?;
+        Ok(row.map(|row| row.manifest.0))
+    }
+
+    pub async fn save(&self, pool: &SqlitePool) -> Result<(), sqlx::Error> {
+        let manifest = Json(self);
+        sqlx::query!(
+            r#"INSERT INTO workspace_manifests (workspace_id, manifest)
+               VALUES ($1, $2)
+               ON CONFLICT(workspace_id) DO UPDATE SET manifest = excluded.manifest"#,
+            self.workspace_id,
+            manifest
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
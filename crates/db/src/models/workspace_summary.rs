@@ -0,0 +1,185 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Commit message and PR description an agent wrote from an attempt's diff,
+/// used as the defaults when merging or opening a PR
+#[derive(Debug, Clone, FromRow, Serialize, TS)]
+pub struct WorkspaceSummary {
+    pub workspace_id: Uuid,
+    /// Set while an agent run is generating a new summary
+    pub execution_process_id: Option<Uuid>,
+    pub commit_message: Option<String>,
+    pub pr_title: Option<String>,
+    pub pr_description: Option<String>,
+    /// Tokens the attempt's coding agent runs have spent, as far as they were metered
+    #[ts(type = "number")]
+    pub tokens_used: i64,
+    /// The budget in force when the attempt went over it
+    #[ts(type = "number | null")]
+    pub token_budget: Option<i64>,
+    pub budget_exceeded_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorkspaceSummary {
+    pub async fn find_by_workspace_id(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            WorkspaceSummary,
+            r#"SELECT workspace_id as "workspace_id!: Uuid",
+                      execution_process_id as "execution_process_id: Uuid",
+                      commit_message,
+                      pr_title,
+                      pr_description,
+                      tokens_used,
+                      token_budget,
+                      budget_exceeded_at as "budget_exceeded_at: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM workspace_summaries
+               WHERE workspace_id = $1"#,
+            workspace_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    pub async fn find_by_execution_process_id(
+        pool: &SqlitePool,
+        execution_process_id: Uuid,
+    ) -> Result<Option<Self>, sqlx::Error> {
+        sqlx::query_as!(
+            WorkspaceSummary,
+            r#"SELECT workspace_id as "workspace_id!: Uuid",
+                      execution_process_id as "execution_process_id: Uuid",
+                      commit_message,
+                      pr_title,
+                      pr_description,
+                      tokens_used,
+                      token_budget,
+                      budget_exceeded_at as "budget_exceeded_at: DateTime<Utc>",
+                      updated_at as "updated_at!: DateTime<Utc>"
+               FROM workspace_summaries
+               WHERE execution_process_id = $1"#,
+            execution_process_id
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Mark a summary as being generated by `execution_process_id`, keeping any
+    /// previous text until the new one arrives
+    pub async fn start_generating(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        execution_process_id: Uuid,
+    ) -> Result<Self, sqlx::Error> {
+        sqlx::query_as!(
+            WorkspaceSummary,
+            r#"INSERT INTO workspace_summaries (workspace_id, execution_process_id)
+               VALUES ($1, $2)
+               ON CONFLICT(workspace_id) DO UPDATE SET
+                   execution_process_id = excluded.execution_process_id,
+                   updated_at = datetime('now', 'subsec')
+               RETURNING workspace_id as "workspace_id!: Uuid",
+                         execution_process_id as "execution_process_id: Uuid",
+                         commit_message,
+                         pr_title,
+                         pr_description,
+                         tokens_used,
+                         token_budget,
+                         budget_exceeded_at as "budget_exceeded_at: DateTime<Utc>",
+                         updated_at as "updated_at!: DateTime<Utc>""#,
+            workspace_id,
+            execution_process_id
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Store the generated text and clear the pending run
+    pub async fn complete(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        commit_message: &str,
+        pr_title: &str,
+        pr_description: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE workspace_summaries
+               SET commit_message = $2,
+                   pr_title = $3,
+                   pr_description = $4,
+                   execution_process_id = NULL,
+                   updated_at = datetime('now', 'subsec')
+               WHERE workspace_id = $1"#,
+            workspace_id,
+            commit_message,
+            pr_title,
+            pr_description
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Clear the pending run without touching the stored text, e.g. when the
+    /// agent's reply couldn't be parsed
+    pub async fn clear_pending(pool: &SqlitePool, workspace_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"UPDATE workspace_summaries
+               SET execution_process_id = NULL
+               WHERE workspace_id = $1"#,
+            workspace_id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Add tokens spent by a finished coding agent run to the attempt's total
+    pub async fn add_tokens_used(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        tokens: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO workspace_summaries (workspace_id, tokens_used)
+               VALUES ($1, $2)
+               ON CONFLICT(workspace_id) DO UPDATE SET
+                   tokens_used = tokens_used + excluded.tokens_used"#,
+            workspace_id,
+            tokens
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record that the attempt has spent `tokens_used` in total, over `token_budget`
+    pub async fn record_budget_exceeded(
+        pool: &SqlitePool,
+        workspace_id: Uuid,
+        tokens_used: i64,
+        token_budget: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"INSERT INTO workspace_summaries (workspace_id, tokens_used, token_budget, budget_exceeded_at)
+               VALUES ($1, $2, $3, datetime('now', 'subsec'))
+               ON CONFLICT(workspace_id) DO UPDATE SET
+                   tokens_used = excluded.tokens_used,
+                   token_budget = excluded.token_budget,
+                   budget_exceeded_at = excluded.budget_exceeded_at"#,
+            workspace_id,
+            tokens_used,
+            token_budget
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
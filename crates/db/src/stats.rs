@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use ts_rs::TS;
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct TableStats {
+    pub name: String,
+    pub row_count: i64,
+    /// Bytes used by the table and its indexes; `None` when the SQLite build lacks `dbstat`
+    pub size_bytes: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct DatabaseStats {
+    /// Size of the main database file (page_count * page_size)
+    pub file_size_bytes: i64,
+    /// Bytes held by free pages that a VACUUM would give back
+    pub free_bytes: i64,
+    pub tables: Vec<TableStats>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct OrphanedRecords {
+    pub kind: String,
+    pub count: i64,
+    pub oldest_created_at: Option<DateTime<Utc>>,
+}
+
+impl DatabaseStats {
+    pub async fn collect(pool: &SqlitePool) -> Result<Self, sqlx::Error> {
+        let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+            .fetch_one(pool)
+            .await?;
+        let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+            .fetch_one(pool)
+            .await?;
+        let freelist_count: i64 = sqlx::query_scalar("PRAGMA freelist_count")
+            .fetch_one(pool)
+            .await?;
+
+        let names: Vec<String> = sqlx::query_scalar(
+            "SELECT name FROM sqlite_master
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%'
+             ORDER BY name",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        // dbstat is an optional compile-time extension, so sizes are best-effort
+        let sizes: Option<Vec<(String, i64)>> = sqlx::query_as(
+            "SELECT m.tbl_name, SUM(s.pgsize)
+             FROM dbstat s
+             JOIN sqlite_master m ON m.name = s.name
+             GROUP BY m.tbl_name",
+        )
+        .fetch_all(pool)
+        .await
+        .inspect_err(|e| tracing::debug!("dbstat unavailable, skipping table sizes: {}", e))
+        .ok();
+
+        let mut tables = Vec::with_capacity(names.len());
+        for name in names {
+            let row_count: i64 = sqlx::query_scalar(&format!(
+                "SELECT COUNT(*) FROM \"{}\"",
+                name.replace('"', "\"\"")
+            ))
+            .fetch_one(pool)
+            .await?;
+            let size_bytes = sizes.as_ref().map(|sizes| {
+                sizes
+                    .iter()
+                    .find(|(table, _)| *table == name)
+                    .map_or(0, |(_, size)| *size)
+            });
+            tables.push(TableStats {
+                name,
+                row_count,
+                size_bytes,
+            });
+        }
+        tables.sort_by(|a, b| {
+            b.size_bytes
+                .cmp(&a.size_bytes)
+                .then(b.row_count.cmp(&a.row_count))
+        });
+
+        Ok(Self {
+            file_size_bytes: page_count * page_size,
+            free_bytes: freelist_count * page_size,
+            tables,
+        })
+    }
+}
+
+impl OrphanedRecords {
+    /// Rows that nothing references any more and that cleanup jobs should eventually remove
+    pub async fn find_all(pool: &SqlitePool) -> Result<Vec<Self>, sqlx::Error> {
+        let images = sqlx::query!(
+            r#"SELECT COUNT(*) as "count!: i64",
+                      MIN(i.created_at) as "oldest: DateTime<Utc>"
+               FROM images i
+               LEFT JOIN task_images ti ON i.id = ti.image_id
+               WHERE ti.task_id IS NULL"#
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let repos = sqlx::query!(
+            r#"SELECT COUNT(*) as "count!: i64",
+                      MIN(created_at) as "oldest: DateTime<Utc>"
+               FROM repos
+               WHERE id NOT IN (SELECT repo_id FROM project_repos)
+                 AND id NOT IN (SELECT repo_id FROM workspace_repos)"#
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(vec![
+            Self {
+                kind: "images".to_string(),
+                count: images.count,
+                oldest_created_at: images.oldest,
+            },
+            Self {
+                kind: "repos".to_string(),
+                count: repos.count,
+                oldest_created_at: repos.oldest,
+            },
+        ])
+    }
+}
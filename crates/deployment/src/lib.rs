@@ -12,32 +12,38 @@ use db::{
     },
 };
 use executors::executors::ExecutorError;
-use futures::{StreamExt, TryStreamExt};
+use futures::{StreamExt, future};
 use git2::Error as Git2Error;
 use serde_json::Value;
 use services::services::{
     analytics::{AnalyticsContext, AnalyticsService},
     approvals::Approvals,
     auth::AuthContext,
+    ci_status::CiMonitorService,
     config::{Config, ConfigError},
     container::{ContainerError, ContainerService},
-    events::{EventError, EventService},
+    email_intake::MailgunTokens,
+    events::{EventError, EventService, EventTopic},
     file_search_cache::FileSearchCache,
     filesystem::{FilesystemError, FilesystemService},
     filesystem_watcher::FilesystemWatcherError,
     git::{GitService, GitServiceError},
     image::{ImageError, ImageService},
+    issue_sync_scheduler::{IssueSyncRunner, IssueSyncScheduler},
     pr_monitor::PrMonitorService,
     project::ProjectService,
     queued_message::QueuedMessageService,
     repo::RepoService,
+    rest_hooks::RestHookService,
     share::SharePublisher,
+    sla_monitor::SlaMonitorService,
+    user_feed::UserFeedService,
     worktree_manager::WorktreeError,
 };
 use sqlx::Error as SqlxError;
 use thiserror::Error;
 use tokio::sync::RwLock;
-use utils::sentry as sentry_utils;
+use utils::{log_msg::LogMsg, sentry as sentry_utils};
 
 #[derive(Debug, Clone, Copy, Error)]
 #[error("Remote client not configured")]
@@ -113,6 +119,10 @@ pub trait Deployment: Clone + Send + Sync + 'static {
 
     fn share_publisher(&self) -> Result<SharePublisher, RemoteClientNotConfigured>;
 
+    fn issue_sync_scheduler(&self) -> &Arc<IssueSyncScheduler>;
+
+    fn mailgun_tokens(&self) -> &Arc<MailgunTokens>;
+
     async fn update_sentry_scope(&self) -> Result<(), DeploymentError> {
         let user_id = self.user_id();
         let config = self.config().read().await;
@@ -133,7 +143,41 @@ pub trait Deployment: Clone + Send + Sync + 'static {
                 analytics_service: analytics_service.clone(),
             });
         let publisher = self.share_publisher().ok();
-        PrMonitorService::spawn(db, analytics, publisher).await
+        PrMonitorService::spawn(
+            db,
+            self.git().clone(),
+            self.config().clone(),
+            analytics,
+            publisher,
+        )
+        .await
+    }
+
+    async fn spawn_rest_hook_service(&self) -> tokio::task::JoinHandle<()> {
+        RestHookService::spawn(self.db().clone(), self.events().bus().clone()).await
+    }
+
+    async fn spawn_user_feed_service(&self) -> tokio::task::JoinHandle<()> {
+        UserFeedService::spawn(self.db().clone(), self.events().bus().clone()).await
+    }
+
+    async fn spawn_sla_monitor_service(&self) -> tokio::task::JoinHandle<()> {
+        SlaMonitorService::spawn(self.db().clone()).await
+    }
+
+    async fn spawn_ci_monitor_service(&self) -> tokio::task::JoinHandle<()> {
+        CiMonitorService::spawn(self.db().clone()).await
+    }
+
+    /// `runner` does the syncing, since the providers' sync routes live in the server
+    async fn spawn_issue_sync_service<R: IssueSyncRunner>(
+        &self,
+        runner: R,
+    ) -> tokio::task::JoinHandle<()> {
+        self.issue_sync_scheduler()
+            .clone()
+            .spawn(self.db().clone(), self.config().clone(), runner)
+            .await
     }
 
     async fn track_if_analytics_allowed(&self, event_name: &str, properties: Value) {
@@ -210,13 +254,22 @@ pub trait Deployment: Clone + Send + Sync + 'static {
         }
     }
 
+    /// Event bus events after offset `after`, limited to `topics` unless empty. Each
+    /// SSE event's id is its bus offset, so clients can resume with `Last-Event-ID`.
     async fn stream_events(
         &self,
+        after: u64,
+        topics: Vec<EventTopic>,
     ) -> futures::stream::BoxStream<'static, Result<Event, std::io::Error>> {
         self.events()
-            .msg_store()
-            .history_plus_stream()
-            .map_ok(|m| m.to_sse_event())
+            .bus()
+            .subscribe(after)
+            .filter(move |event| future::ready(topics.is_empty() || topics.contains(&event.topic)))
+            .map(|event| {
+                Ok(LogMsg::JsonPatch(event.patch)
+                    .to_sse_event()
+                    .id(event.offset.to_string()))
+            })
             .boxed()
     }
 }
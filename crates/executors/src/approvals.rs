@@ -33,6 +33,23 @@ pub trait ExecutorApprovalService: Send + Sync {
         tool_input: Value,
         tool_call_id: &str,
     ) -> Result<ApprovalStatus, ExecutorApprovalError>;
+
+    /// Whether an approval policy wants shell commands screened even when the executor
+    /// runs without approvals
+    fn has_tool_policy(&self) -> bool {
+        false
+    }
+
+    /// Screens a tool invocation against the approval policy. Calls that match no rule are
+    /// approved immediately; matching calls wait for a human decision.
+    async fn check_tool_policy(
+        &self,
+        _tool_name: &str,
+        _tool_input: Value,
+        _tool_call_id: &str,
+    ) -> Result<ApprovalStatus, ExecutorApprovalError> {
+        Ok(ApprovalStatus::Approved)
+    }
 }
 
 #[derive(Debug, Default)]
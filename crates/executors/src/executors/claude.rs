@@ -18,7 +18,7 @@ use workspace_utils::{
 };
 
 use self::{
-    client::{AUTO_APPROVE_CALLBACK_ID, ClaudeAgentClient},
+    client::{AUTO_APPROVE_CALLBACK_ID, ClaudeAgentClient, POLICY_CHECK_CALLBACK_ID},
     protocol::ProtocolPeer,
     types::PermissionMode,
 };
@@ -151,6 +151,19 @@ impl ClaudeCode {
                     }
                 ]
             }))
+        } else if self
+            .approvals_service
+            .as_ref()
+            .is_some_and(|approvals| approvals.has_tool_policy())
+        {
+            Some(serde_json::json!({
+                "PreToolUse": [
+                    {
+                        "matcher": "^Bash$",
+                        "hookCallbackIds": [POLICY_CHECK_CALLBACK_ID],
+                    }
+                ]
+            }))
         } else {
             None
         }
@@ -20,6 +20,7 @@ use crate::{
 
 const EXIT_PLAN_MODE_NAME: &str = "ExitPlanMode";
 pub const AUTO_APPROVE_CALLBACK_ID: &str = "AUTO_APPROVE_CALLBACK_ID";
+pub const POLICY_CHECK_CALLBACK_ID: &str = "POLICY_CHECK_CALLBACK_ID";
 
 /// Claude Agent client with control protocol support
 pub struct ClaudeAgentClient {
@@ -140,12 +141,52 @@ impl ClaudeAgentClient {
         }
     }
 
+    /// Screen a tool call against the approval policy and answer the hook directly, so
+    /// gated tools pause without switching the session out of its permission mode
+    async fn handle_policy_check(
+        &self,
+        input: serde_json::Value,
+        tool_use_id: Option<String>,
+    ) -> Result<serde_json::Value, ExecutorError> {
+        let (Some(approvals), Some(tool_use_id)) = (self.approvals.as_ref(), tool_use_id) else {
+            return Ok(hook_decision("allow", "No approval policy to check"));
+        };
+        let tool_name = input
+            .get("tool_name")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+        let tool_input = input.get("tool_input").cloned().unwrap_or_default();
+
+        let status = approvals
+            .check_tool_policy(&tool_name, tool_input, &tool_use_id)
+            .await;
+        Ok(match status {
+            Ok(ApprovalStatus::Approved) => hook_decision("allow", "Allowed by approval policy"),
+            Ok(ApprovalStatus::Denied { reason }) => hook_decision(
+                "deny",
+                &reason.unwrap_or_else(|| "Denied by user".to_string()),
+            ),
+            Ok(ApprovalStatus::TimedOut) => hook_decision("deny", "Approval request timed out"),
+            Ok(ApprovalStatus::Pending) => {
+                hook_decision("deny", "Approval still pending (unexpected)")
+            }
+            Err(e) => {
+                tracing::error!("Approval policy check failed: {e}");
+                hook_decision("deny", "Approval policy check failed")
+            }
+        })
+    }
+
     pub async fn on_hook_callback(
         &self,
         callback_id: String,
-        _input: serde_json::Value,
-        _tool_use_id: Option<String>,
+        input: serde_json::Value,
+        tool_use_id: Option<String>,
     ) -> Result<serde_json::Value, ExecutorError> {
+        if callback_id == POLICY_CHECK_CALLBACK_ID {
+            return self.handle_policy_check(input, tool_use_id).await;
+        }
         if self.auto_approve {
             Ok(serde_json::json!({
                 "hookSpecificOutput": {
@@ -184,3 +225,13 @@ impl ClaudeAgentClient {
         self.log_writer.log_raw(line).await
     }
 }
+
+fn hook_decision(decision: &str, reason: &str) -> serde_json::Value {
+    serde_json::json!({
+        "hookSpecificOutput": {
+            "hookEventName": "PreToolUse",
+            "permissionDecision": decision,
+            "permissionDecisionReason": reason
+        }
+    })
+}
@@ -15,7 +15,7 @@ use workspace_utils::msg_store::MsgStore;
 use crate::{
     actions::ExecutorAction,
     approvals::ExecutorApprovalService,
-    command::CommandBuildError,
+    command::{CmdOverrides, CommandBuildError},
     env::ExecutionEnv,
     executors::{
         amp::Amp, claude::ClaudeCode, codex::Codex, copilot::Copilot, cursor::CursorAgent,
@@ -42,6 +42,8 @@ pub enum BaseAgentCapability {
     SessionFork,
     /// Agent requires a setup script before it can run (e.g., login, installation)
     SetupHelper,
+    /// Agent checks its shell commands against the command approval policy
+    CommandApprovalPolicy,
 }
 
 #[derive(Debug, Error)]
@@ -153,10 +155,28 @@ impl CodingAgent {
         self.default_mcp_config_path().is_some()
     }
 
+    /// The profile's command overrides (base command, extra params and env)
+    pub fn cmd_overrides(&self) -> &CmdOverrides {
+        match self {
+            Self::ClaudeCode(agent) => &agent.cmd,
+            Self::Amp(agent) => &agent.cmd,
+            Self::Gemini(agent) => &agent.cmd,
+            Self::Codex(agent) => &agent.cmd,
+            Self::Opencode(agent) => &agent.cmd,
+            Self::CursorAgent(agent) => &agent.cmd,
+            Self::QwenCode(agent) => &agent.cmd,
+            Self::Copilot(agent) => &agent.cmd,
+            Self::Droid(agent) => &agent.cmd,
+        }
+    }
+
     pub fn capabilities(&self) -> Vec<BaseAgentCapability> {
         match self {
-            Self::ClaudeCode(_)
-            | Self::Amp(_)
+            Self::ClaudeCode(_) => vec![
+                BaseAgentCapability::SessionFork,
+                BaseAgentCapability::CommandApprovalPolicy,
+            ],
+            Self::Amp(_)
             | Self::Gemini(_)
             | Self::QwenCode(_)
             | Self::Droid(_)
@@ -3,6 +3,7 @@ use std::{
     fs,
     str::FromStr,
     sync::{LazyLock, RwLock},
+    time::{Duration, Instant},
 };
 
 use convert_case::{Case, Casing};
@@ -54,6 +55,12 @@ pub enum ProfileError {
 static EXECUTOR_PROFILES_CACHE: LazyLock<RwLock<ExecutorConfigs>> =
     LazyLock::new(|| RwLock::new(ExecutorConfigs::load()));
 
+/// How long a recommended executor profile is reused before availability is probed again.
+const RECOMMENDED_PROFILE_TTL: Duration = Duration::from_secs(300);
+
+static RECOMMENDED_PROFILE_CACHE: LazyLock<RwLock<Option<(Instant, ExecutorProfileId)>>> =
+    LazyLock::new(|| RwLock::new(None));
+
 // New format default profiles (v3 - flattened)
 const DEFAULT_PROFILES_JSON: &str = include_str!("../default_profiles.json");
 
@@ -204,6 +211,12 @@ impl ExecutorConfigs {
     pub fn reload() {
         let mut cache = EXECUTOR_PROFILES_CACHE.write().unwrap();
         *cache = Self::load();
+        Self::invalidate_recommended_executor_profile();
+    }
+
+    /// Drop the cached recommendation so the next lookup probes executors again
+    pub fn invalidate_recommended_executor_profile() {
+        *RECOMMENDED_PROFILE_CACHE.write().unwrap() = None;
     }
 
     /// Load executor profiles from file or defaults
@@ -413,9 +426,24 @@ impl ExecutorConfigs {
                     .expect("No default variant found")
             })
     }
+    /// Recommended executor profile, reusing the last probe result for up to
+    /// `RECOMMENDED_PROFILE_TTL`. Failed probes are not cached.
     pub async fn get_recommended_executor_profile(
         &self,
     ) -> Result<ExecutorProfileId, ProfileError> {
+        if let Some((probed_at, profile_id)) = RECOMMENDED_PROFILE_CACHE.read().unwrap().as_ref()
+            && probed_at.elapsed() < RECOMMENDED_PROFILE_TTL
+            && self.get_coding_agent(profile_id).is_some()
+        {
+            return Ok(profile_id.clone());
+        }
+
+        let profile_id = self.probe_recommended_executor_profile()?;
+        *RECOMMENDED_PROFILE_CACHE.write().unwrap() = Some((Instant::now(), profile_id.clone()));
+        Ok(profile_id)
+    }
+
+    fn probe_recommended_executor_profile(&self) -> Result<ExecutorProfileId, ProfileError> {
         let mut agents_with_info: Vec<(BaseCodingAgent, AvailabilityInfo)> = Vec::new();
 
         for &base_agent in self.executors.keys() {
@@ -17,12 +17,20 @@ use db::{
             ExecutionContext, ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus,
         },
         execution_process_repo_state::ExecutionProcessRepoState,
+        maintenance_job::MaintenanceJob,
+        managed_branch::ManagedBranch,
+        notification::{CreateNotification, Notification, NotificationKind},
+        project_executor_weight::ProjectExecutorWeight,
         project_repo::ProjectRepo,
+        proposed_task::ProposedTask,
         repo::Repo,
         scratch::{DraftFollowUpData, Scratch, ScratchType},
         task::{ExecutionMode, Task, TaskStatus},
+        task_owner::TaskOwner,
+        token_budget::{BudgetAction, TokenBudget},
         workspace::Workspace,
         workspace_repo::WorkspaceRepo,
+        workspace_summary::WorkspaceSummary,
     },
 };
 use deployment::{DeploymentError, RemoteClientNotConfigured};
@@ -34,31 +42,48 @@ use executors::{
     },
     approvals::{ExecutorApprovalService, NoopExecutorApprovalService},
     env::ExecutionEnv,
-    executors::{BaseCodingAgent, ExecutorExitResult, ExecutorExitSignal, InterruptSender},
+    executors::{
+        BaseAgentCapability, BaseCodingAgent, ExecutorExitResult, ExecutorExitSignal,
+        InterruptSender,
+    },
     logs::{NormalizedEntryType, utils::patch::extract_normalized_entry_from_patch},
-    profile::ExecutorProfileId,
+    profile::{ExecutorConfigs, ExecutorProfileId},
 };
-use futures::{FutureExt, TryStreamExt, stream::select};
+use futures::{FutureExt, StreamExt, TryStreamExt, stream::select};
 use serde_json::json;
 use services::services::{
     analytics::AnalyticsContext,
-    approvals::{Approvals, executor_approvals::ExecutorApprovalBridge},
+    approvals::{Approvals, executor_approvals::ExecutorApprovalBridge, policy::ApprovalPolicy},
+    change_summary,
     config::Config,
     container::{ContainerError, ContainerRef, ContainerService},
+    diff_exclusions::DiffExclusions,
+    diff_size_guard::DiffSizeGuard,
     diff_stream::{self, DiffStreamHandle},
+    executor_slots::ExecutorSlots,
+    follow_up_tasks,
     git::{Commit, GitCli, GitService},
     image::ImageService,
+    log_redaction::LogRedactor,
+    maintenance, managed_branches,
     notification::NotificationService,
+    process_tree::ProcessTracker,
     queued_message::QueuedMessageService,
+    recovery::RecoveryLog,
+    repo::RepoService,
     sequential_queue::SequentialQueueService,
     share::SharePublisher,
+    task_locks::TaskLocks,
+    token_budget::{TokenMeter, overage},
+    vortex_oauth::VortexOAuthService,
     workspace_manager::{RepoWorkspaceInput, WorkspaceManager},
 };
 use tokio::{sync::RwLock, task::JoinHandle};
-use tokio_util::io::ReaderStream;
 use utils::{
     log_msg::LogMsg,
     msg_store::MsgStore,
+    path::{MAX_DIR_NAME_LEN, normalize_container_ref, sanitize_path_component},
+    stream_lines::raw_lines,
     text::{git_branch_id, short_uuid, truncate_to_char_boundary},
 };
 use uuid::Uuid;
@@ -78,6 +103,10 @@ pub struct LocalContainerService {
     approvals: Approvals,
     queued_message_service: QueuedMessageService,
     sequential_queue_service: SequentialQueueService,
+    executor_slots: ExecutorSlots,
+    task_locks: TaskLocks,
+    recovery_log: RecoveryLog,
+    process_tracker: ProcessTracker,
     publisher: Result<SharePublisher, RemoteClientNotConfigured>,
     notification_service: NotificationService,
 }
@@ -99,6 +128,8 @@ impl LocalContainerService {
         let interrupt_senders = Arc::new(RwLock::new(HashMap::new()));
         let notification_service = NotificationService::new(config.clone());
         let sequential_queue_service = SequentialQueueService::new(db.clone());
+        let executor_slots = ExecutorSlots::new(config.read().await.auto_start_slots as usize);
+        let task_locks = TaskLocks::new(config.read().await.one_attempt_per_task);
 
         let container = LocalContainerService {
             db,
@@ -112,11 +143,18 @@ impl LocalContainerService {
             approvals,
             queued_message_service,
             sequential_queue_service,
+            executor_slots,
+            task_locks,
+            recovery_log: RecoveryLog::default(),
+            process_tracker: ProcessTracker::default(),
             publisher,
             notification_service,
         };
 
         container.spawn_workspace_cleanup().await;
+        container.spawn_maintenance_scheduler();
+        container.spawn_repo_health_check();
+        container.process_tracker.spawn_refresh();
 
         container
     }
@@ -146,7 +184,7 @@ impl LocalContainerService {
         map.remove(id)
     }
 
-    pub async fn cleanup_workspace(db: &DBService, workspace: &Workspace) {
+    pub async fn cleanup_workspace(db: &DBService, workspace: &Workspace, keep_branches: bool) {
         let Some(container_ref) = &workspace.container_ref else {
             return;
         };
@@ -176,13 +214,25 @@ impl LocalContainerService {
                         e
                     );
                 });
+            if !keep_branches {
+                managed_branches::cleanup_branches(
+                    &db.pool,
+                    &GitService::new(),
+                    &repositories,
+                    &workspace.branch,
+                )
+                .await;
+            }
         }
 
         // Clear container_ref so this workspace won't be picked up again
         let _ = Workspace::clear_container_ref(&db.pool, workspace.id).await;
     }
 
-    pub async fn cleanup_expired_workspaces(db: &DBService) -> Result<(), DeploymentError> {
+    pub async fn cleanup_expired_workspaces(
+        db: &DBService,
+        keep_branches: bool,
+    ) -> Result<(), DeploymentError> {
         let expired_workspaces = Workspace::find_expired_for_cleanup(&db.pool).await?;
         if expired_workspaces.is_empty() {
             tracing::debug!("No expired workspaces found");
@@ -193,20 +243,22 @@ impl LocalContainerService {
             expired_workspaces.len()
         );
         for workspace in &expired_workspaces {
-            Self::cleanup_workspace(db, workspace).await;
+            Self::cleanup_workspace(db, workspace, keep_branches).await;
         }
         Ok(())
     }
 
     pub async fn spawn_workspace_cleanup(&self) {
         let db = self.db.clone();
+        let config = self.config.clone();
         let mut cleanup_interval = tokio::time::interval(tokio::time::Duration::from_secs(1800)); // 30 minutes
-        WorkspaceManager::cleanup_orphan_workspaces(&self.db.pool).await;
+        WorkspaceManager::normalize_container_refs(&self.db.pool).await;
         tokio::spawn(async move {
             loop {
                 cleanup_interval.tick().await;
                 tracing::info!("Starting periodic workspace cleanup...");
-                Self::cleanup_expired_workspaces(&db)
+                let keep_branches = config.read().await.keep_branches_after_cleanup;
+                Self::cleanup_expired_workspaces(&db, keep_branches)
                     .await
                     .unwrap_or_else(|e| {
                         tracing::error!("Failed to clean up expired workspaces: {}", e)
@@ -215,6 +267,88 @@ impl LocalContainerService {
         });
     }
 
+    /// Every 15 minutes, check that each project repo's checkout is still where
+    /// it was registered, marking repos that were moved or deleted as broken
+    pub fn spawn_repo_health_check(&self) {
+        let db = self.db.clone();
+        let git = self.git.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(15 * 60));
+            loop {
+                interval.tick().await;
+                if let Err(e) = RepoService::new().check_all(&db.pool, &git).await {
+                    tracing::error!("Failed to check repository paths: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Every minute, start the longest-waiting due maintenance job if no agent
+    /// or script is running. Only one job starts per check, so maintenance runs
+    /// fill idle time instead of competing with other work.
+    pub fn spawn_maintenance_scheduler(&self) {
+        let container = self.clone();
+        let period = Duration::from_secs(60);
+        tokio::spawn(async move {
+            // The first check waits a full period so startup can reconcile
+            // processes left running by a previous session
+            let mut interval =
+                tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+            loop {
+                interval.tick().await;
+                if let Err(e) = container.start_next_due_maintenance_job().await {
+                    tracing::error!("Failed to check maintenance jobs: {}", e);
+                }
+            }
+        });
+    }
+
+    async fn start_next_due_maintenance_job(&self) -> Result<(), ContainerError> {
+        let pool = &self.db.pool;
+        let busy = ExecutionProcess::find_running(pool)
+            .await?
+            .iter()
+            .any(|process| process.run_reason != ExecutionProcessRunReason::DevServer);
+        if busy {
+            return Ok(());
+        }
+
+        let jobs = MaintenanceJob::find_enabled(pool).await?;
+        let Some(job) = maintenance::next_due_job(&jobs) else {
+            return Ok(());
+        };
+        let weight = ProjectExecutorWeight::find_by_project_id(pool, job.project_id).await?;
+        let Some(permit) = self
+            .executor_slots
+            .try_acquire(job.project_id, weight.slot_weight())
+        else {
+            return Ok(());
+        };
+
+        // Recorded up front so a job that fails to start waits for its next slot
+        MaintenanceJob::record_run(pool, job.id, None).await?;
+        match self.start_maintenance_job(job, Some(permit)).await {
+            Ok(task) => {
+                if self.config.read().await.analytics_enabled
+                    && let Some(analytics) = &self.analytics
+                {
+                    analytics.analytics_service.track_event(
+                        &analytics.user_id,
+                        "maintenance_job_started",
+                        Some(json!({
+                            "job_id": job.id.to_string(),
+                            "project_id": job.project_id.to_string(),
+                            "task_id": task.id.to_string(),
+                            "scheduled": true,
+                        })),
+                    );
+                }
+            }
+            Err(e) => tracing::error!("Failed to start maintenance job {}: {}", job.id, e),
+        }
+        Ok(())
+    }
+
     /// Record the current HEAD commit for each repository as the "after" state.
     /// Errors are silently ignored since this runs after the main execution completes
     /// and failure should not block process finalization.
@@ -394,6 +528,10 @@ impl LocalContainerService {
                 }
             }
 
+            // Kill anything the executor left running in the background
+            let process_tracker = container.process_tracker.clone();
+            tokio::spawn(async move { process_tracker.reap(exec_id).await });
+
             let (exit_code, status) = match status_result {
                 Ok(exit_status) => {
                     let code = exit_status.code().unwrap_or(-1) as i64;
@@ -408,8 +546,15 @@ impl LocalContainerService {
             };
 
             if !ExecutionProcess::was_stopped(&db.pool, exec_id).await
-                && let Err(e) =
-                    ExecutionProcess::update_completion(&db.pool, exec_id, status, exit_code).await
+                && let Err(e) = db
+                    .write(|pool| {
+                        let status = status.clone();
+                        async move {
+                            ExecutionProcess::update_completion(&pool, exec_id, status, exit_code)
+                                .await
+                        }
+                    })
+                    .await
             {
                 tracing::error!("Failed to update execution process completion: {}", e);
             }
@@ -420,6 +565,19 @@ impl LocalContainerService {
                     tracing::warn!("Failed to update executor session summary: {}", e);
                 }
 
+                if matches!(
+                    ctx.execution_process.run_reason,
+                    ExecutionProcessRunReason::CodingAgent
+                ) && container.follow_up_task_proposals_enabled().await
+                    && let Err(e) = container.record_follow_up_proposals(&ctx).await
+                {
+                    tracing::warn!("Failed to record follow-up task proposals: {}", e);
+                }
+
+                if let Err(e) = container.record_change_summary(&ctx).await {
+                    tracing::warn!("Failed to record change summary: {}", e);
+                }
+
                 let success = matches!(
                     ctx.execution_process.status,
                     ExecutionProcessStatus::Completed
@@ -616,9 +774,136 @@ impl LocalContainerService {
         rx
     }
 
+    /// Meter a coding agent run's token spend against the budget that applies
+    /// to its task, and enforce the budget once the attempt as a whole goes over
+    async fn spawn_token_budget_monitor(&self, exec_id: Uuid, workspace_id: Uuid, task: &Task) {
+        let budget = match TokenBudget::find_for_task(&self.db.pool, task.id, task.project_id).await
+        {
+            Ok(Some(budget)) => budget,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!("Failed to load token budget for task {}: {}", task.id, e);
+                return;
+            }
+        };
+        let Some(store) = self.msg_stores.read().await.get(&exec_id).cloned() else {
+            return;
+        };
+
+        let container = self.clone();
+        let task = task.clone();
+        tokio::spawn(async move {
+            let pool = &container.db.pool;
+            // Earlier runs of the attempt count towards the same budget
+            let spent_before =
+                match WorkspaceSummary::find_by_workspace_id(pool, workspace_id).await {
+                    Ok(summary) => summary.map_or(0, |summary| summary.tokens_used.max(0) as u64),
+                    Err(e) => {
+                        tracing::warn!("Failed to load token spend for {}: {}", workspace_id, e);
+                        0
+                    }
+                };
+
+            let mut meter = TokenMeter::new();
+            let mut lines = store.stdout_lines_stream();
+            while let Some(Ok(line)) = lines.next().await {
+                meter.observe(&line);
+                let tokens_used = spent_before + meter.total();
+                if overage(tokens_used, budget.max_tokens.max(0) as u64).is_some() {
+                    container
+                        .enforce_token_budget(exec_id, workspace_id, &task, &budget, tokens_used)
+                        .await;
+                    return;
+                }
+            }
+
+            if let Err(e) =
+                WorkspaceSummary::add_tokens_used(pool, workspace_id, meter.total() as i64).await
+            {
+                tracing::error!("Failed to record token spend for {}: {}", workspace_id, e);
+            }
+        });
+    }
+
+    /// Record the overage on the attempt summary, pause or abort the run and
+    /// let the task owner know
+    async fn enforce_token_budget(
+        &self,
+        exec_id: Uuid,
+        workspace_id: Uuid,
+        task: &Task,
+        budget: &TokenBudget,
+        tokens_used: u64,
+    ) {
+        let pool = &self.db.pool;
+        if let Err(e) = WorkspaceSummary::record_budget_exceeded(
+            pool,
+            workspace_id,
+            tokens_used as i64,
+            budget.max_tokens,
+        )
+        .await
+        {
+            tracing::error!("Failed to record token budget overage: {}", e);
+        }
+
+        let (status, outcome) = match budget.action {
+            BudgetAction::Pause => (ExecutionProcessStatus::Killed, "paused"),
+            BudgetAction::Abort => (ExecutionProcessStatus::Failed, "aborted"),
+        };
+        match ExecutionProcess::find_by_id(pool, exec_id).await {
+            Ok(Some(process)) if process.status == ExecutionProcessStatus::Running => {
+                if let Err(e) = self.stop_execution(&process, status).await {
+                    tracing::error!("Failed to stop {} over its token budget: {}", exec_id, e);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Failed to load execution process {}: {}", exec_id, e),
+        }
+
+        let title = format!("Token budget exceeded: {}", task.title);
+        let message = format!(
+            "The attempt spent {tokens_used} tokens, over its budget of {}, and was {outcome}",
+            budget.max_tokens
+        );
+        self.notification_service.notify(&title, &message).await;
+
+        let inbox_entry = CreateNotification {
+            kind: NotificationKind::BudgetExceeded,
+            title,
+            message,
+            project_id: Some(task.project_id),
+            task_id: Some(task.id),
+            workspace_id: Some(workspace_id),
+        };
+        let owner = match TaskOwner::find_by_task_id(pool, task.id).await {
+            Ok(owner) => {
+                owner.and_then(|owner| owner.assignee_user_id.or(owner.created_by_user_id))
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load owner of task {}: {}", task.id, e);
+                None
+            }
+        };
+        let recorded = match owner {
+            Some(user_id) => Notification::create(pool, user_id, &inbox_entry)
+                .await
+                .map(|_| ()),
+            None => Notification::create_for_all_users(pool, &inbox_entry)
+                .await
+                .map(|_| ()),
+        };
+        if let Err(e) = recorded {
+            tracing::error!("Failed to record token budget notification: {}", e);
+        }
+    }
+
     pub fn dir_name_from_workspace(workspace_id: &Uuid, task_title: &str) -> String {
         let task_title_id = git_branch_id(task_title);
-        format!("{}-{}", short_uuid(workspace_id), task_title_id)
+        sanitize_path_component(
+            &format!("{}-{}", short_uuid(workspace_id), task_title_id),
+            MAX_DIR_NAME_LEN,
+        )
     }
 
     /// Try to start the next task in the sequential queue after a task completes.
@@ -833,12 +1118,8 @@ impl LocalContainerService {
         }
 
         // Update container_ref in database
-        Workspace::update_container_ref(
-            &self.db.pool,
-            workspace.id,
-            &workspace_dir.to_string_lossy(),
-        )
-        .await?;
+        let container_ref = normalize_container_ref(&workspace_dir);
+        Workspace::update_container_ref(&self.db.pool, workspace.id, &container_ref).await?;
 
         // Copy project files and images (same as regular workspace)
         self.copy_files_and_images(&workspace_dir, workspace)
@@ -847,7 +1128,7 @@ impl LocalContainerService {
         // Create workspace config files
         Self::create_workspace_config_files(&workspace_dir, repositories).await?;
 
-        Ok(workspace_dir.to_string_lossy().to_string())
+        Ok(container_ref)
     }
 
     async fn track_child_msgs_in_store(&self, id: Uuid, child: &mut AsyncGroupChild) {
@@ -856,13 +1137,20 @@ impl LocalContainerService {
         let out = child.inner().stdout.take().expect("no stdout");
         let err = child.inner().stderr.take().expect("no stderr");
 
-        // Map stdout bytes -> LogMsg::Stdout
-        let out = ReaderStream::new(out)
-            .map_ok(|chunk| LogMsg::Stdout(String::from_utf8_lossy(&chunk).into_owned()));
+        // Redact whole lines so nothing downstream (live view, DB, sharing) sees
+        // the masked values, and a token can't slip through split across chunks
+        let redactor = Arc::new(LogRedactor::from_config(
+            &self.config.read().await.log_redaction,
+        ));
+
+        // Map stdout lines -> LogMsg::Stdout
+        let out_redactor = redactor.clone();
+        let out = raw_lines(out)
+            .map_ok(move |line| LogMsg::Stdout(out_redactor.redact(&line).into_owned()));
 
-        // Map stderr bytes -> LogMsg::Stderr
-        let err = ReaderStream::new(err)
-            .map_ok(|chunk| LogMsg::Stderr(String::from_utf8_lossy(&chunk).into_owned()));
+        // Map stderr lines -> LogMsg::Stderr
+        let err =
+            raw_lines(err).map_ok(move |line| LogMsg::Stderr(redactor.redact(&line).into_owned()));
 
         // If you have a JSON Patch source, map it to LogMsg::JsonPatch too, then select all three.
 
@@ -882,6 +1170,8 @@ impl LocalContainerService {
         base_commit: &Commit,
         stats_only: bool,
         path_prefix: Option<String>,
+        exclusions: DiffExclusions,
+        size_guard: Option<DiffSizeGuard>,
     ) -> Result<DiffStreamHandle, ContainerError> {
         diff_stream::create(
             self.git().clone(),
@@ -889,6 +1179,8 @@ impl LocalContainerService {
             base_commit.clone(),
             stats_only,
             path_prefix,
+            exclusions,
+            size_guard,
         )
         .await
         .map_err(|e| ContainerError::Other(anyhow!("{e}")))
@@ -944,6 +1236,97 @@ impl LocalContainerService {
         Ok(())
     }
 
+    /// The agent's last message in a run that contains `needle`
+    async fn last_assistant_message_containing(
+        &self,
+        exec_id: Uuid,
+        needle: &str,
+    ) -> Option<String> {
+        let msg_stores = self.msg_stores.read().await;
+        let msg_store = msg_stores.get(&exec_id)?;
+        msg_store.get_history().iter().rev().find_map(|msg| {
+            let LogMsg::JsonPatch(patch) = msg else {
+                return None;
+            };
+            let (_, entry) = extract_normalized_entry_from_patch(patch)?;
+            (matches!(entry.entry_type, NormalizedEntryType::AssistantMessage)
+                && entry.content.contains(needle))
+            .then_some(entry.content)
+        })
+    }
+
+    /// Store the commit message and PR description from a run started by
+    /// `generate-summary`. Runs that weren't asked for a summary are ignored.
+    async fn record_change_summary(&self, ctx: &ExecutionContext) -> Result<(), anyhow::Error> {
+        let exec_id = ctx.execution_process.id;
+        let Some(pending) =
+            WorkspaceSummary::find_by_execution_process_id(&self.db.pool, exec_id).await?
+        else {
+            return Ok(());
+        };
+
+        let summary = self
+            .last_assistant_message_containing(exec_id, change_summary::CHANGE_SUMMARY_FENCE)
+            .await
+            .and_then(|message| change_summary::parse_change_summary(&message));
+        match summary {
+            Some(summary) => {
+                WorkspaceSummary::complete(
+                    &self.db.pool,
+                    pending.workspace_id,
+                    &summary.commit_message,
+                    &summary.pr_title,
+                    &summary.pr_description,
+                )
+                .await?
+            }
+            None => {
+                tracing::warn!(
+                    "Agent run {} did not produce a change summary for workspace {}",
+                    exec_id,
+                    pending.workspace_id
+                );
+                WorkspaceSummary::clear_pending(&self.db.pool, pending.workspace_id).await?
+            }
+        }
+        Ok(())
+    }
+
+    /// Store the follow-up tasks the agent proposed in its last message that carries a
+    /// `follow-up-tasks` block, for a human to accept or reject
+    async fn record_follow_up_proposals(
+        &self,
+        ctx: &ExecutionContext,
+    ) -> Result<(), anyhow::Error> {
+        let exec_id = ctx.execution_process.id;
+        let Some(message) = self
+            .last_assistant_message_containing(exec_id, follow_up_tasks::FOLLOW_UP_TASKS_FENCE)
+            .await
+        else {
+            return Ok(());
+        };
+
+        let proposals = follow_up_tasks::parse_follow_up_tasks(&message);
+        for proposal in &proposals {
+            ProposedTask::create(
+                &self.db.pool,
+                ctx.workspace.id,
+                Some(exec_id),
+                &proposal.title,
+                proposal.description.as_deref(),
+            )
+            .await?;
+        }
+        if !proposals.is_empty() {
+            tracing::info!(
+                "Recorded {} follow-up task proposal(s) for workspace {}",
+                proposals.len(),
+                ctx.workspace.id
+            );
+        }
+        Ok(())
+    }
+
     /// Copy project files and images to the workspace.
     /// Skips files/images that already exist (fast no-op if all exist).
     async fn copy_files_and_images(
@@ -1136,10 +1519,34 @@ impl ContainerService for LocalContainerService {
         &self.notification_service
     }
 
+    fn executor_slots(&self) -> &ExecutorSlots {
+        &self.executor_slots
+    }
+
+    fn task_locks(&self) -> &TaskLocks {
+        &self.task_locks
+    }
+
+    fn recovery_log(&self) -> &RecoveryLog {
+        &self.recovery_log
+    }
+
+    fn process_tracker(&self) -> &ProcessTracker {
+        &self.process_tracker
+    }
+
     async fn git_branch_prefix(&self) -> String {
         self.config.read().await.git_branch_prefix.clone()
     }
 
+    async fn follow_up_task_proposals_enabled(&self) -> bool {
+        self.config.read().await.follow_up_task_proposals
+    }
+
+    async fn vortex_oauth(&self) -> VortexOAuthService {
+        VortexOAuthService::from_config(&*self.config.read().await)
+    }
+
     fn workspace_to_current_dir(&self, workspace: &Workspace) -> PathBuf {
         PathBuf::from(workspace.container_ref.clone().unwrap_or_default())
     }
@@ -1192,6 +1599,16 @@ impl ContainerService for LocalContainerService {
             &workspace.branch,
         )
         .await?;
+        for input in &workspace_inputs {
+            ManagedBranch::record(
+                &self.db.pool,
+                input.repo.id,
+                workspace.id,
+                &workspace.branch,
+                &input.target_branch,
+            )
+            .await?;
+        }
 
         // Copy project files and images to workspace
         self.copy_files_and_images(&created_workspace.workspace_dir, workspace)
@@ -1200,22 +1617,16 @@ impl ContainerService for LocalContainerService {
         Self::create_workspace_config_files(&created_workspace.workspace_dir, &repositories)
             .await?;
 
-        Workspace::update_container_ref(
-            &self.db.pool,
-            workspace.id,
-            &created_workspace.workspace_dir.to_string_lossy(),
-        )
-        .await?;
+        let container_ref = normalize_container_ref(&created_workspace.workspace_dir);
+        Workspace::update_container_ref(&self.db.pool, workspace.id, &container_ref).await?;
 
-        Ok(created_workspace
-            .workspace_dir
-            .to_string_lossy()
-            .to_string())
+        Ok(container_ref)
     }
 
     async fn delete(&self, workspace: &Workspace) -> Result<(), ContainerError> {
         self.try_stop(workspace, true).await;
-        Self::cleanup_workspace(&self.db, workspace).await;
+        let keep_branches = self.config.read().await.keep_branches_after_cleanup;
+        Self::cleanup_workspace(&self.db, workspace, keep_branches).await;
         Ok(())
     }
 
@@ -1251,7 +1662,7 @@ impl ContainerService for LocalContainerService {
             Workspace::update_container_ref(
                 &self.db.pool,
                 workspace.id,
-                &workspace_dir.to_string_lossy(),
+                &normalize_container_ref(&workspace_dir),
             )
             .await?;
         }
@@ -1303,6 +1714,32 @@ impl ContainerService for LocalContainerService {
             )))?;
         let current_dir = PathBuf::from(container_ref);
 
+        // Only agents that route their shell commands through the approval
+        // service honour the policy; refuse the rest rather than run unchecked
+        let approval_policy = self.config.read().await.approval_policy.clone();
+        if approval_policy.enabled
+            && let Some(executor_profile_id) = match executor_action.typ() {
+                ExecutorActionType::CodingAgentInitialRequest(request) => {
+                    Some(&request.executor_profile_id)
+                }
+                ExecutorActionType::CodingAgentFollowUpRequest(request) => {
+                    Some(&request.executor_profile_id)
+                }
+                ExecutorActionType::ScriptRequest(_) => None,
+            }
+            && !ExecutorConfigs::get_cached()
+                .get_coding_agent(executor_profile_id)
+                .is_some_and(|agent| {
+                    agent
+                        .capabilities()
+                        .contains(&BaseAgentCapability::CommandApprovalPolicy)
+                })
+        {
+            return Err(ContainerError::ApprovalPolicyUnsupported(
+                executor_profile_id.executor,
+            ));
+        }
+
         let approvals_service: Arc<dyn ExecutorApprovalService> =
             match executor_action.base_executor() {
                 Some(
@@ -1311,12 +1748,16 @@ impl ContainerService for LocalContainerService {
                     | BaseCodingAgent::Gemini
                     | BaseCodingAgent::QwenCode
                     | BaseCodingAgent::Opencode,
-                ) => ExecutorApprovalBridge::new(
-                    self.approvals.clone(),
-                    self.db.clone(),
-                    self.notification_service.clone(),
-                    execution_process.id,
-                ),
+                ) => {
+                    let policy = ApprovalPolicy::from_config(&approval_policy, &current_dir);
+                    ExecutorApprovalBridge::new(
+                        self.approvals.clone(),
+                        self.db.clone(),
+                        self.notification_service.clone(),
+                        execution_process.id,
+                        policy,
+                    )
+                }
                 _ => Arc::new(NoopExecutorApprovalService {}),
             };
 
@@ -1356,9 +1797,18 @@ impl ContainerService for LocalContainerService {
         self.track_child_msgs_in_store(execution_process.id, &mut spawned.child)
             .await;
 
+        if let Some(pid) = spawned.child.inner().id() {
+            self.process_tracker
+                .register(execution_process.id, workspace.id, pid);
+        }
         self.add_child_to_store(execution_process.id, spawned.child)
             .await;
 
+        if executor_action.base_executor().is_some() {
+            self.spawn_token_budget_monitor(execution_process.id, workspace.id, &task)
+                .await;
+        }
+
         // Store interrupt sender for graceful shutdown
         if let Some(interrupt_sender) = spawned.interrupt_sender {
             self.add_interrupt_sender(execution_process.id, interrupt_sender)
@@ -1388,7 +1838,19 @@ impl ContainerService for LocalContainerService {
             None
         };
 
-        ExecutionProcess::update_completion(&self.db.pool, execution_process.id, status, exit_code)
+        self.db
+            .write(|pool| {
+                let status = status.clone();
+                async move {
+                    ExecutionProcess::update_completion(
+                        &pool,
+                        execution_process.id,
+                        status,
+                        exit_code,
+                    )
+                    .await
+                }
+            })
             .await?;
 
         // Try graceful interrupt first, then force kill
@@ -1447,7 +1909,13 @@ impl ContainerService for LocalContainerService {
                 ExecutionProcessRunReason::DevServer
             )
         {
-            match Task::update_status(&self.db.pool, ctx.task.id, TaskStatus::InReview).await {
+            match self
+                .db
+                .write(|pool| async move {
+                    Task::update_status(&pool, ctx.task.id, TaskStatus::InReview).await
+                })
+                .await
+            {
                 Ok(_) => {
                     if let Some(publisher) = self.share_publisher()
                         && let Err(err) = publisher.update_shared_task_by_id(ctx.task.id).await
@@ -1491,6 +1959,8 @@ impl ContainerService for LocalContainerService {
 
         let repositories =
             WorkspaceRepo::find_repos_for_workspace(&self.db.pool, workspace.id).await?;
+        let exclusions = DiffExclusions::for_workspace(&self.db.pool, workspace).await?;
+        let size_guard = DiffSizeGuard::from_config(&self.config.read().await.diff_size_guard);
 
         let mut streams = Vec::new();
 
@@ -1530,6 +2000,8 @@ impl ContainerService for LocalContainerService {
                     &base_commit,
                     stats_only,
                     Some(repo.name.clone()),
+                    exclusions.clone(),
+                    size_guard,
                 )
                 .await?;
 
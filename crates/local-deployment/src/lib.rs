@@ -10,11 +10,14 @@ use services::services::{
     auth::AuthContext,
     config::{Config, load_config_from_file, save_config_to_file},
     container::ContainerService,
-    events::EventService,
+    email_intake::MailgunTokens,
+    events::{EventService, TaskUpdateBatcher},
     file_search_cache::FileSearchCache,
     filesystem::FilesystemService,
     git::GitService,
+    http_client::{HttpClient, HttpClientConfig},
     image::ImageService,
+    issue_sync_scheduler::IssueSyncScheduler,
     oauth_credentials::OAuthCredentials,
     project::ProjectService,
     queued_message::QueuedMessageService,
@@ -56,6 +59,8 @@ pub struct LocalDeployment {
     remote_client: Result<RemoteClient, RemoteClientNotConfigured>,
     auth_context: AuthContext,
     oauth_handoffs: Arc<RwLock<HashMap<Uuid, PendingHandoff>>>,
+    issue_sync_scheduler: Arc<IssueSyncScheduler>,
+    mailgun_tokens: Arc<MailgunTokens>,
 }
 
 #[derive(Debug, Clone)]
@@ -91,6 +96,8 @@ impl Deployment for LocalDeployment {
         // Always save config (may have been migrated or version updated)
         save_config_to_file(&raw_config, &config_path()).await?;
 
+        // Before any integration sends a request through it
+        HttpClient::configure(HttpClientConfig::from_env().with_network(&raw_config.network));
         let config = Arc::new(RwLock::new(raw_config));
         let user_id = generate_user_id();
         let analytics = AnalyticsConfig::new().map(AnalyticsService::new);
@@ -103,12 +110,14 @@ impl Deployment for LocalDeployment {
         // Create shared components for EventService
         let events_msg_store = Arc::new(MsgStore::new());
         let events_entry_count = Arc::new(RwLock::new(0));
+        let task_batcher = TaskUpdateBatcher::new(events_msg_store.clone());
 
         // Create DB with event hooks
         let db = {
             let hook = EventService::create_hook(
                 events_msg_store.clone(),
                 events_entry_count.clone(),
+                task_batcher.clone(),
                 DBService::new().await?, // Temporary DB service for the hook
             );
             DBService::new_with_after_connect(hook).await?
@@ -185,7 +194,12 @@ impl Deployment for LocalDeployment {
         )
         .await;
 
-        let events = EventService::new(db.clone(), events_msg_store, events_entry_count);
+        let events = EventService::new(
+            db.clone(),
+            events_msg_store,
+            events_entry_count,
+            task_batcher,
+        );
 
         let file_search_cache = Arc::new(FileSearchCache::new());
 
@@ -209,6 +223,8 @@ impl Deployment for LocalDeployment {
             remote_client,
             auth_context,
             oauth_handoffs,
+            issue_sync_scheduler: Arc::new(IssueSyncScheduler::default()),
+            mailgun_tokens: Arc::new(MailgunTokens::default()),
         };
 
         Ok(deployment)
@@ -277,6 +293,14 @@ impl Deployment for LocalDeployment {
     fn auth_context(&self) -> &AuthContext {
         &self.auth_context
     }
+
+    fn issue_sync_scheduler(&self) -> &Arc<IssueSyncScheduler> {
+        &self.issue_sync_scheduler
+    }
+
+    fn mailgun_tokens(&self) -> &Arc<MailgunTokens> {
+        &self.mailgun_tokens
+    }
 }
 
 impl LocalDeployment {
@@ -17,6 +17,8 @@ fn generate_types_content() -> String {
         remote::db::users::UserData::decl(),
         db::models::project::Project::decl(),
         db::models::project::CreateProject::decl(),
+        db::models::project::CreateProjectFromTemplate::decl(),
+        db::models::project::DuplicateProject::decl(),
         db::models::project::UpdateProject::decl(),
         db::models::project::SearchResult::decl(),
         db::models::project::SearchMatchType::decl(),
@@ -37,6 +39,19 @@ fn generate_types_content() -> String {
         db::models::task::TaskRelationships::decl(),
         db::models::task::CreateTask::decl(),
         db::models::task::UpdateTask::decl(),
+        db::models::project_status_transition::StatusTransition::decl(),
+        db::models::project_status_transition::SetStatusTransitions::decl(),
+        db::models::task_owner::TaskOwner::decl(),
+        db::models::task_owner::SetTaskAssignee::decl(),
+        server::routes::my_tasks::MyTask::decl(),
+        server::routes::my_tasks::MyTaskGroup::decl(),
+        db::models::feed_event::FeedEventKind::decl(),
+        db::models::feed_event::FeedEvent::decl(),
+        server::routes::my_feed::FeedPage::decl(),
+        services::services::quick_add::QuickAddPriority::decl(),
+        services::services::quick_add::QuickAdd::decl(),
+        server::routes::quick_add::QuickAddRequest::decl(),
+        server::routes::quick_add::QuickAddResponse::decl(),
         db::models::scratch::DraftFollowUpData::decl(),
         db::models::scratch::ScratchPayload::decl(),
         db::models::scratch::ScratchType::decl(),
@@ -59,9 +74,18 @@ fn generate_types_content() -> String {
         utils::approvals::ApprovalStatus::decl(),
         utils::approvals::CreateApprovalRequest::decl(),
         utils::approvals::ApprovalResponse::decl(),
+        services::services::approvals::PendingApprovalInfo::decl(),
+        server::routes::task_attempts::approvals::DenyApprovalRequest::decl(),
+        db::models::proposed_task::ProposedTaskStatus::decl(),
+        db::models::proposed_task::ProposedTask::decl(),
+        server::routes::task_attempts::proposed_tasks::AcceptProposedTaskRequest::decl(),
+        db::models::diff_comment::DiffComment::decl(),
+        db::models::diff_comment::CreateDiffComment::decl(),
+        db::models::diff_comment::UpdateDiffComment::decl(),
         utils::diff::Diff::decl(),
         utils::diff::DiffChangeKind::decl(),
         utils::response::ApiResponse::<()>::decl(),
+        utils::response::ErrorCode::decl(),
         utils::api::oauth::LoginStatus::decl(),
         utils::api::oauth::ProfileResponse::decl(),
         utils::api::oauth::ProviderProfile::decl(),
@@ -131,9 +155,84 @@ fn generate_types_content() -> String {
         server::routes::shared_tasks::AssignSharedTaskRequest::decl(),
         server::routes::tasks::ShareTaskResponse::decl(),
         server::routes::tasks::CreateAndStartTaskRequest::decl(),
+        server::routes::tasks::ValidateTaskRequest::decl(),
+        services::services::task_validation::TaskValidationRule::decl(),
+        services::services::task_validation::TaskValidationIssue::decl(),
+        services::services::task_validation::TaskValidationReport::decl(),
+        server::routes::tasks::CheckDuplicatesRequest::decl(),
+        services::services::task_duplicates::PotentialDuplicate::decl(),
         server::routes::task_attempts::pr::CreateGitHubPrRequest::decl(),
+        server::routes::task_attempts::gitlab_mr::CreateGitLabMrRequest::decl(),
+        db::models::gitlab_merge_request::GitLabMergeRequest::decl(),
+        server::routes::task_attempts::bitbucket_pr::CreateBitbucketPrRequest::decl(),
+        db::models::bitbucket_pull_request::BitbucketPullRequest::decl(),
+        server::routes::task_attempts::pr_set::CreatePrSetRequest::decl(),
+        db::models::workspace_ci_status::CiStatus::decl(),
+        services::services::pr_set::PrSetMember::decl(),
+        services::services::pr_set::PrSetState::decl(),
+        services::services::pr_set::PrSetStatus::decl(),
+        db::models::pull_request_status::ReviewState::decl(),
+        db::models::pull_request_status::CiCheck::decl(),
+        services::services::ci_status::PullRequestProvider::decl(),
+        services::services::ci_status::WorkspacePullRequest::decl(),
         server::routes::images::ImageResponse::decl(),
         server::routes::images::ImageMetadata::decl(),
+        db::stats::TableStats::decl(),
+        db::stats::DatabaseStats::decl(),
+        db::stats::OrphanedRecords::decl(),
+        services::services::storage_stats::DirectoryUsage::decl(),
+        services::services::storage_stats::WorkspaceDiskUsage::decl(),
+        services::services::storage_stats::StorageStats::decl(),
+        server::routes::admin::VacuumResponse::decl(),
+        db::models::workspace::WorkspaceDirectory::decl(),
+        server::routes::admin::ReconcileAction::decl(),
+        server::routes::admin::ReconcileWorkspacesRequest::decl(),
+        server::routes::admin::SkippedWorkspace::decl(),
+        server::routes::admin::ReconcileWorkspacesResponse::decl(),
+        db::models::session::StaleSession::decl(),
+        services::services::recovery::InterruptedProcess::decl(),
+        services::services::recovery::RecoveryReport::decl(),
+        server::routes::admin::RecoveryFix::decl(),
+        server::routes::admin::RecoveryFixResponse::decl(),
+        services::services::process_tree::TrackedProcess::decl(),
+        services::services::process_tree::ExecutionProcessTree::decl(),
+        services::services::events::EventTopic::decl(),
+        services::services::events::bus::ConsumerOffset::decl(),
+        services::services::events::EventBusStatus::decl(),
+        services::services::executor_slots::ProjectSlotUsage::decl(),
+        services::services::executor_slots::ExecutorSlotsStatus::decl(),
+        db::models::notification::NotificationKind::decl(),
+        db::models::notification::Notification::decl(),
+        server::routes::notifications::NotificationListQuery::decl(),
+        server::routes::notifications::NotificationListResponse::decl(),
+        server::routes::notifications::UnreadCountResponse::decl(),
+        db::models::task_review::TaskReviewStatus::decl(),
+        db::models::task_review::TaskReview::decl(),
+        server::routes::task_reviews::RequestReviewRequest::decl(),
+        server::routes::task_reviews::ReviewResponseRequest::decl(),
+        server::routes::task_reviews::ReviewActionResponse::decl(),
+        db::models::project_instruction::ProjectInstruction::decl(),
+        db::models::project_instruction::ProjectInstructionVersion::decl(),
+        db::models::project_instruction::CreateProjectInstruction::decl(),
+        db::models::project_instruction::UpdateProjectInstruction::decl(),
+        db::models::project_instruction::TaskInstruction::decl(),
+        db::models::project_instruction::SetTaskInstruction::decl(),
+        db::models::secret_scan_finding::SecretScanOperation::decl(),
+        db::models::secret_scan_finding::SecretScanFinding::decl(),
+        db::models::project_group::ProjectGroup::decl(),
+        db::models::project_group::CreateProjectGroup::decl(),
+        db::models::project_group::UpdateProjectGroup::decl(),
+        db::models::project_group::ProjectPlacement::decl(),
+        db::models::project_group::SetProjectPlacement::decl(),
+        server::routes::project_groups::ProjectLayout::decl(),
+        server::routes::projects::ProjectListQuery::decl(),
+        db::models::repo_path_rule::RepoPathRule::decl(),
+        db::models::repo_path_rule::RepoPathRuleKind::decl(),
+        db::models::repo_path_rule::RepoPathRuleAction::decl(),
+        db::models::repo_path_rule::CreateRepoPathRule::decl(),
+        services::services::path_rules::PathRuleViolation::decl(),
+        db::models::project_diff_exclusion::ProjectDiffExclusion::decl(),
+        db::models::project_diff_exclusion::CreateProjectDiffExclusion::decl(),
         server::routes::task_attempts::CreateTaskAttemptBody::decl(),
         server::routes::task_attempts::WorkspaceRepoInput::decl(),
         server::routes::task_attempts::RunAgentSetupRequest::decl(),
@@ -144,6 +243,7 @@ fn generate_types_content() -> String {
         server::routes::task_attempts::GitOperationError::decl(),
         server::routes::task_attempts::PushError::decl(),
         server::routes::task_attempts::pr::CreatePrError::decl(),
+        server::routes::task_attempts::pr_set::CreatePrSetError::decl(),
         server::routes::task_attempts::BranchStatus::decl(),
         server::routes::task_attempts::RunScriptError::decl(),
         server::routes::task_attempts::DeleteWorktreeError::decl(),
@@ -152,33 +252,154 @@ fn generate_types_content() -> String {
         server::routes::task_attempts::pr::PrCommentsResponse::decl(),
         server::routes::task_attempts::pr::GetPrCommentsError::decl(),
         server::routes::task_attempts::pr::GetPrCommentsQuery::decl(),
+        server::routes::task_attempts::diff_summary::DiffFileQuery::decl(),
+        services::services::diff_size_guard::DiffSizeSummary::decl(),
+        services::services::diff_size_guard::DirectoryRollup::decl(),
         services::services::github::UnifiedPrComment::decl(),
         services::services::github_issues::GitHubIssue::decl(),
         services::services::github_issues::GitHubUser::decl(),
         services::services::github_issues::GitHubLabel::decl(),
         services::services::github_issues::GitHubMilestone::decl(),
         services::services::github_issues::ListIssuesParams::decl(),
+        services::services::github_issues::GitHubPullRequest::decl(),
+        services::services::github_issues::GitHubPullRequestRef::decl(),
+        services::services::github_issues::GitHubCiStatus::decl(),
+        services::services::github_issues::GitHubIssueFilter::decl(),
+        services::services::events::ImportProgress::decl(),
         server::routes::github_issues::GitHubIssuesResponse::decl(),
         server::routes::github_issues::ImportIssueRequest::decl(),
-        server::routes::github_issues::ImportIssueResponse::decl(),
+        server::routes::github_issues::BulkImportIssuesRequest::decl(),
+        server::routes::github_issues::GitHubPullRequestsResponse::decl(),
+        server::routes::github_issues::ImportPullRequestRequest::decl(),
+        server::routes::github_issues::ImportPullRequestResponse::decl(),
         server::routes::github_issues::GitHubConfigStatus::decl(),
+        db::models::project_webhook::WebhookProvider::decl(),
+        db::models::project_webhook::ProjectWebhook::decl(),
+        db::models::project_webhook::SetProjectWebhook::decl(),
+        server::routes::webhooks::WebhookDeliveryResult::decl(),
+        db::models::project_email_address::ProjectEmailAddress::decl(),
+        db::models::project_email_address::SetProjectEmailAddress::decl(),
+        db::models::slack_user_link::SlackUserLink::decl(),
+        db::models::slack_user_link::SetSlackUserLink::decl(),
+        server::routes::email_intake::EmailIntakeResult::decl(),
+        db::models::task_schedule::TaskSchedule::decl(),
+        db::models::task_schedule::SetTaskSchedule::decl(),
+        db::models::task_label::LabelSyncDirection::decl(),
+        db::models::task_label::SetTaskLabels::decl(),
+        db::models::task_dependency::TaskDependency::decl(),
+        db::models::task_dependency::CreateTaskDependency::decl(),
+        db::models::project_calendar_feed::ProjectCalendarFeed::decl(),
+        server::routes::calendar::RotatedCalendarFeed::decl(),
+        db::models::project_status_page::ProjectStatusPage::decl(),
+        server::routes::status_page::RotatedStatusPage::decl(),
+        db::models::project_status_page::StatusCount::decl(),
+        db::models::project_status_page::CompletedTask::decl(),
+        services::services::status_page::PublicProjectStatus::decl(),
+        db::models::rest_hook_subscription::RestHookEvent::decl(),
+        db::models::rest_hook_subscription::RestHookSubscription::decl(),
+        db::models::rest_hook_subscription::CreateRestHookSubscription::decl(),
+        server::routes::issue_sync::IssueSyncPreview::decl(),
+        server::routes::issue_sync::ImportedIssue::decl(),
+        services::services::issue_provider::ProviderIssue::decl(),
+        server::routes::issue_sync::IssueSyncPreviewItem::decl(),
+        db::models::sync_run::SyncRunStatus::decl(),
+        db::models::sync_run::SyncRun::decl(),
+        services::services::issue_sync_scheduler::SyncProvider::decl(),
+        services::services::issue_sync_scheduler::ScheduledSyncStatus::decl(),
+        services::services::issue_sync_scheduler::IssueSyncSchedule::decl(),
+        db::models::task_estimate::EstimateUnit::decl(),
+        db::models::task_estimate::TaskEstimate::decl(),
+        db::models::task_estimate::SetTaskEstimate::decl(),
+        db::models::user_capacity::UserCapacity::decl(),
+        db::models::user_capacity::SetUserCapacity::decl(),
+        services::services::capacity::EstimatedWork::decl(),
+        services::services::capacity::UserCapacityUsage::decl(),
+        services::services::capacity::ProjectCapacity::decl(),
+        db::models::sla_rule::SlaRuleKind::decl(),
+        db::models::sla_rule::SlaRule::decl(),
+        db::models::sla_rule::CreateSlaRule::decl(),
+        db::models::label_profile_rule::LabelProfileRule::decl(),
+        db::models::label_profile_rule::UpsertLabelProfileRule::decl(),
+        db::models::pending_start::PendingStart::decl(),
+        db::models::task_draft::TaskDraft::decl(),
+        db::models::task_draft::TriageTaskDrafts::decl(),
+        db::models::token_budget::BudgetAction::decl(),
+        db::models::token_budget::TokenBudget::decl(),
+        db::models::token_budget::SetTokenBudget::decl(),
+        db::models::maintenance_job::MaintenanceJob::decl(),
+        db::models::maintenance_job::UpsertMaintenanceJob::decl(),
+        db::models::project_executor_weight::ProjectExecutorWeight::decl(),
+        db::models::project_executor_weight::SetProjectExecutorWeight::decl(),
+        services::services::executor_recommendation::ProfileStats::decl(),
+        services::services::executor_recommendation::ExecutorRecommendation::decl(),
+        db::models::repo_health::RepoHealth::decl(),
+        server::routes::repo::RelocateRepoRequest::decl(),
+        db::models::task_sla_breach::TaskSlaBreach::decl(),
+        db::models::kiosk_token::KioskToken::decl(),
+        db::models::kiosk_token::CreateKioskToken::decl(),
+        server::routes::kiosk_tokens::CreatedKioskToken::decl(),
+        db::models::workspace_manifest::WorkspaceManifest::decl(),
+        db::models::workspace_manifest::ManifestRepo::decl(),
+        db::models::workspace_manifest::ManifestOs::decl(),
+        db::models::workspace_summary::WorkspaceSummary::decl(),
+        db::models::managed_branch::ManagedBranch::decl(),
+        server::routes::repo::ManagedBranchStatus::decl(),
+        db::models::task::TimelineTask::decl(),
+        server::routes::timeline::ProjectTimeline::decl(),
+        server::routes::timeline::TimelineDependency::decl(),
+        server::routes::timeline::BoardSnapshot::decl(),
+        services::services::board_history::BoardSnapshotTask::decl(),
         services::services::gitlab_issues::GitLabIssue::decl(),
         services::services::gitlab_issues::GitLabUser::decl(),
         services::services::gitlab_issues::GitLabMilestone::decl(),
         services::services::gitlab_issues::ListGitLabIssuesParams::decl(),
         server::routes::gitlab_issues::GitLabIssuesResponse::decl(),
         server::routes::gitlab_issues::ImportGitLabIssueRequest::decl(),
-        server::routes::gitlab_issues::ImportGitLabIssueResponse::decl(),
         server::routes::gitlab_issues::GitLabConfigStatus::decl(),
+        services::services::gitea_issues::GiteaIssue::decl(),
+        services::services::gitea_issues::GiteaUser::decl(),
+        services::services::gitea_issues::GiteaLabel::decl(),
+        services::services::gitea_issues::GiteaMilestone::decl(),
+        services::services::gitea_issues::ListGiteaIssuesParams::decl(),
+        server::routes::gitea_issues::GiteaIssuesResponse::decl(),
+        server::routes::gitea_issues::ImportGiteaIssueRequest::decl(),
+        server::routes::gitea_issues::GiteaConfigStatus::decl(),
+        db::models::issue_connection::IssueConnection::decl(),
+        db::models::issue_connection::IssueConnectionSettings::decl(),
+        db::models::issue_connection::LinearSettings::decl(),
+        db::models::issue_connection::AzureDevOpsSettings::decl(),
+        db::models::issue_connection::NotionSettings::decl(),
+        db::models::issue_connection::AsanaSettings::decl(),
+        db::models::issue_connection::ClickUpSettings::decl(),
+        db::models::issue_connection::ShortcutSettings::decl(),
+        db::models::issue_connection::UpsertIssueConnection::decl(),
+        server::routes::issue_connections::ImportConnectedIssueRequest::decl(),
+        server::routes::issue_connections::ListLinearTeamsRequest::decl(),
+        services::services::linear_issues::LinearTeam::decl(),
+        services::services::shortcut_stories::ShortcutWorkflowState::decl(),
+        services::services::shortcut_stories::ShortcutWorkflow::decl(),
+        services::services::bitbucket_issues::BitbucketIssue::decl(),
+        services::services::bitbucket_issues::ListBitbucketIssuesParams::decl(),
+        server::routes::bitbucket_issues::BitbucketIssuesResponse::decl(),
+        server::routes::bitbucket_issues::ImportBitbucketIssueRequest::decl(),
+        server::routes::bitbucket_issues::ImportBitbucketIssueResponse::decl(),
+        server::routes::bitbucket_issues::BitbucketConfigStatus::decl(),
         services::services::vortex_issues::VortexIssue::decl(),
         services::services::vortex_issues::VortexUser::decl(),
         services::services::vortex_issues::VortexAttachment::decl(),
         services::services::vortex_issues::VortexComment::decl(),
+        services::services::vortex_issues::VortexProject::decl(),
         services::services::vortex_issues::ListVortexIssuesParams::decl(),
         server::routes::vortex_issues::VortexIssuesResponse::decl(),
         server::routes::vortex_issues::ImportVortexIssueRequest::decl(),
-        server::routes::vortex_issues::ImportVortexIssueResponse::decl(),
         server::routes::vortex_issues::VortexConfigStatus::decl(),
+        db::models::issue_sync_conflict::IssueSyncSide::decl(),
+        db::models::issue_sync_conflict::IssueSyncConflict::decl(),
+        db::models::task_group::TaskGroup::decl(),
+        db::models::task_group::TaskGroupWithProgress::decl(),
+        server::routes::vortex_oauth::StartVortexOAuthRequest::decl(),
+        server::routes::vortex_oauth::StartVortexOAuthResponse::decl(),
+        server::routes::vortex_oauth::VortexOAuthStatus::decl(),
         server::routes::task_attempts::RepoBranchStatus::decl(),
         services::services::filesystem::DirectoryEntry::decl(),
         services::services::filesystem::DirectoryListResponse::decl(),
@@ -192,6 +413,14 @@ fn generate_types_content() -> String {
         services::services::config::SoundFile::decl(),
         services::services::config::UiLanguage::decl(),
         services::services::config::ShowcaseState::decl(),
+        services::services::config::TaskValidationConfig::decl(),
+        services::services::config::ValidationLevel::decl(),
+        services::services::config::ApprovalPolicyConfig::decl(),
+        services::services::config::ApprovalRule::decl(),
+        services::services::config::LogRedactionConfig::decl(),
+        services::services::config::NetworkConfig::decl(),
+        services::services::config::DiffSizeGuardConfig::decl(),
+        services::services::config::IssueSyncScheduleConfig::decl(),
         services::services::git::GitBranch::decl(),
         services::services::share::SharedTaskDetails::decl(),
         services::services::queued_message::QueuedMessage::decl(),
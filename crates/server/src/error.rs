@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use axum::{
     Json,
     extract::multipart::MultipartError,
@@ -6,26 +8,45 @@ use axum::{
 };
 use db::models::{
     execution_process::ExecutionProcessError, project::ProjectError,
-    project_repo::ProjectRepoError, repo::RepoError, scratch::ScratchError, session::SessionError,
-    user::UserError, workspace::WorkspaceError,
+    project_instruction::ProjectInstructionError, project_repo::ProjectRepoError, repo::RepoError,
+    scratch::ScratchError, session::SessionError, user::UserError, workspace::WorkspaceError,
 };
 use deployment::{DeploymentError, RemoteClientNotConfigured};
 use executors::executors::ExecutorError;
 use git2::Error as Git2Error;
 use services::services::{
+    approvals::ApprovalError,
     config::{ConfigError, EditorOpenError},
     container::ContainerError,
     git::GitServiceError,
     github::GitHubServiceError,
+    http_client::HttpClientError,
     image::ImageError,
+    issue_provider::IssueProviderError,
+    path_rules::PathRuleError,
     project::ProjectServiceError,
     remote_client::RemoteClientError,
     repo::RepoError as RepoServiceError,
+    secret_scan::SecretScanError,
     share::ShareError,
+    vortex_issues::VortexIssuesError,
+    vortex_oauth::VortexOAuthError,
     worktree_manager::WorktreeError,
 };
 use thiserror::Error;
-use utils::response::ApiResponse;
+use utils::{
+    response::{ApiResponse, ErrorCode},
+    validation::ValidationErrors,
+};
+
+/// An error with a specific [`ErrorCode`] and the values its message was
+/// built from, for clients that show their own localized text
+#[derive(Debug)]
+pub struct CodedError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub params: BTreeMap<String, String>,
+}
 
 #[derive(Debug, Error, ts_rs::TS)]
 #[ts(type = "string")]
@@ -33,6 +54,8 @@ pub enum ApiError {
     #[error(transparent)]
     Project(#[from] ProjectError),
     #[error(transparent)]
+    ProjectInstruction(#[from] ProjectInstructionError),
+    #[error(transparent)]
     Repo(#[from] RepoError),
     #[error(transparent)]
     Workspace(#[from] WorkspaceError),
@@ -70,6 +93,10 @@ pub enum ApiError {
     EditorOpen(#[from] EditorOpenError),
     #[error(transparent)]
     RemoteClient(#[from] RemoteClientError),
+    #[error(transparent)]
+    Approval(#[from] ApprovalError),
+    #[error(transparent)]
+    VortexIssues(#[from] VortexIssuesError),
     #[error("Unauthorized")]
     Unauthorized,
     #[error("Bad request: {0}")]
@@ -78,6 +105,83 @@ pub enum ApiError {
     Conflict(String),
     #[error("Forbidden: {0}")]
     Forbidden(String),
+    #[error("{}", .0.message)]
+    Coded(CodedError),
+}
+
+impl ApiError {
+    pub fn coded(code: ErrorCode, message: impl Into<String>) -> Self {
+        ApiError::Coded(CodedError {
+            code,
+            message: message.into(),
+            params: BTreeMap::new(),
+        })
+    }
+
+    /// Record a value the message was built from; no-op unless coded
+    pub fn with_param(mut self, name: &str, value: impl ToString) -> Self {
+        if let ApiError::Coded(coded) = &mut self {
+            coded.params.insert(name.to_string(), value.to_string());
+        }
+        self
+    }
+
+    pub fn integration_not_configured(provider: &str) -> Self {
+        ApiError::coded(
+            ErrorCode::IntegrationNotConfigured,
+            format!("{provider} configuration not set for this project"),
+        )
+        .with_param("provider", provider)
+    }
+
+    /// The specific code for this error, if it has one; other errors get a
+    /// generic code from their status
+    fn specific_code(&self) -> Option<ErrorCode> {
+        match self {
+            ApiError::Coded(coded) => Some(coded.code),
+            ApiError::GitService(GitServiceError::MergeConflicts(_)) => {
+                Some(ErrorCode::MergeConflicts)
+            }
+            ApiError::GitService(GitServiceError::RebaseInProgress) => {
+                Some(ErrorCode::RebaseInProgress)
+            }
+            ApiError::Container(ContainerError::AttemptAlreadyRunning) => {
+                Some(ErrorCode::AttemptAlreadyRunning)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn coded_status(code: ErrorCode) -> StatusCode {
+    match code {
+        ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+        ErrorCode::Forbidden => StatusCode::FORBIDDEN,
+        ErrorCode::NotFound => StatusCode::NOT_FOUND,
+        ErrorCode::Conflict
+        | ErrorCode::TaskHasRunningProcesses
+        | ErrorCode::MergeConflicts
+        | ErrorCode::RebaseInProgress
+        | ErrorCode::AttemptAlreadyRunning
+        | ErrorCode::DependencyCycle => StatusCode::CONFLICT,
+        ErrorCode::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        ErrorCode::BadGateway => StatusCode::BAD_GATEWAY,
+        ErrorCode::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+fn generic_code(status: StatusCode) -> ErrorCode {
+    match status {
+        StatusCode::UNAUTHORIZED => ErrorCode::Unauthorized,
+        StatusCode::FORBIDDEN => ErrorCode::Forbidden,
+        StatusCode::NOT_FOUND => ErrorCode::NotFound,
+        StatusCode::CONFLICT => ErrorCode::Conflict,
+        StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT => ErrorCode::BadGateway,
+        StatusCode::SERVICE_UNAVAILABLE => ErrorCode::ServiceUnavailable,
+        status if status.is_server_error() => ErrorCode::Internal,
+        _ => ErrorCode::BadRequest,
+    }
 }
 
 impl From<&'static str> for ApiError {
@@ -92,6 +196,30 @@ impl From<Git2Error> for ApiError {
     }
 }
 
+impl From<ValidationErrors> for ApiError {
+    fn from(errors: ValidationErrors) -> Self {
+        ApiError::Coded(CodedError {
+            code: ErrorCode::ValidationFailed,
+            message: errors.to_string(),
+            params: errors.into_fields(),
+        })
+    }
+}
+
+impl From<IssueProviderError> for ApiError {
+    fn from(err: IssueProviderError) -> Self {
+        match err {
+            IssueProviderError::Vortex(e) => ApiError::VortexIssues(e),
+            IssueProviderError::VortexOAuth(VortexOAuthError::Database(e)) => ApiError::Database(e),
+            IssueProviderError::Database(e) => ApiError::Database(e),
+            IssueProviderError::NotConfigured(provider) => {
+                ApiError::integration_not_configured(provider)
+            }
+            e => ApiError::BadRequest(e.to_string()),
+        }
+    }
+}
+
 impl From<RemoteClientNotConfigured> for ApiError {
     fn from(_: RemoteClientNotConfigured) -> Self {
         ApiError::BadRequest("Remote client not configured".to_string())
@@ -102,6 +230,17 @@ impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         let (status_code, error_type) = match &self {
             ApiError::Project(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ProjectError"),
+            ApiError::ProjectInstruction(err) => match err {
+                ProjectInstructionError::NotFound | ProjectInstructionError::VersionNotFound(_) => {
+                    (StatusCode::NOT_FOUND, "ProjectInstructionError")
+                }
+                ProjectInstructionError::NameExists(_) => {
+                    (StatusCode::CONFLICT, "ProjectInstructionError")
+                }
+                ProjectInstructionError::Database(_) => {
+                    (StatusCode::INTERNAL_SERVER_ERROR, "ProjectInstructionError")
+                }
+            },
             ApiError::Repo(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ProjectRepoError"),
             ApiError::Workspace(_) => (StatusCode::INTERNAL_SERVER_ERROR, "WorkspaceError"),
             ApiError::Session(_) => (StatusCode::INTERNAL_SERVER_ERROR, "SessionError"),
@@ -131,7 +270,14 @@ impl IntoResponse for ApiError {
             },
             ApiError::GitHubService(_) => (StatusCode::INTERNAL_SERVER_ERROR, "GitHubServiceError"),
             ApiError::Deployment(_) => (StatusCode::INTERNAL_SERVER_ERROR, "DeploymentError"),
-            ApiError::Container(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ContainerError"),
+            ApiError::Container(err) => match err {
+                ContainerError::AttemptAlreadyRunning
+                | ContainerError::RepoUnavailable { .. }
+                | ContainerError::ApprovalPolicyUnsupported(_) => {
+                    (StatusCode::CONFLICT, "ContainerError")
+                }
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "ContainerError"),
+            },
             ApiError::Executor(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ExecutorError"),
             ApiError::Database(_) => (StatusCode::INTERNAL_SERVER_ERROR, "DatabaseError"),
             ApiError::Worktree(_) => (StatusCode::INTERNAL_SERVER_ERROR, "WorktreeError"),
@@ -182,10 +328,38 @@ impl IntoResponse for ApiError {
                     (StatusCode::BAD_REQUEST, "RemoteClientError")
                 }
             },
+            ApiError::Approval(err) => match err {
+                ApprovalError::NotFound => (StatusCode::NOT_FOUND, "ApprovalError"),
+                ApprovalError::AlreadyCompleted => (StatusCode::CONFLICT, "ApprovalError"),
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "ApprovalError"),
+            },
+            // Vortex auth failures must not be 401, which would sign the local user out
+            ApiError::VortexIssues(err) => match err {
+                VortexIssuesError::Unauthorized(_) | VortexIssuesError::Forbidden(_) => {
+                    (StatusCode::FORBIDDEN, "VortexIssuesError")
+                }
+                VortexIssuesError::NotFound(_) => (StatusCode::NOT_FOUND, "VortexIssuesError"),
+                VortexIssuesError::RateLimited { .. } => {
+                    (StatusCode::TOO_MANY_REQUESTS, "VortexIssuesError")
+                }
+                VortexIssuesError::AuthRequired | VortexIssuesError::InvalidConfig(_) => {
+                    (StatusCode::BAD_REQUEST, "VortexIssuesError")
+                }
+                VortexIssuesError::Http(HttpClientError::CircuitOpen { .. }) => {
+                    (StatusCode::SERVICE_UNAVAILABLE, "VortexIssuesError")
+                }
+                VortexIssuesError::Request(_)
+                | VortexIssuesError::Http(_)
+                | VortexIssuesError::Api { .. }
+                | VortexIssuesError::ParseError(_) => {
+                    (StatusCode::BAD_GATEWAY, "VortexIssuesError")
+                }
+            },
             ApiError::Unauthorized => (StatusCode::UNAUTHORIZED, "Unauthorized"),
             ApiError::BadRequest(_) => (StatusCode::BAD_REQUEST, "BadRequest"),
             ApiError::Conflict(_) => (StatusCode::CONFLICT, "ConflictError"),
             ApiError::Forbidden(_) => (StatusCode::FORBIDDEN, "ForbiddenError"),
+            ApiError::Coded(coded) => (coded_status(coded.code), "CodedError"),
         };
 
         let error_message = match &self {
@@ -265,17 +439,50 @@ impl IntoResponse for ApiError {
                 UserError::InvalidCredentials => "Invalid username or password.".to_string(),
                 UserError::Database(_) => "Failed to access user data.".to_string(),
             },
+            ApiError::VortexIssues(err) => err.to_string(),
+            ApiError::Container(
+                err @ (ContainerError::AttemptAlreadyRunning
+                | ContainerError::RepoUnavailable { .. }
+                | ContainerError::ApprovalPolicyUnsupported(_)),
+            ) => err.to_string(),
             ApiError::Unauthorized => "Unauthorized. Please sign in again.".to_string(),
             ApiError::BadRequest(msg) => msg.clone(),
             ApiError::Conflict(msg) => msg.clone(),
             ApiError::Forbidden(msg) => msg.clone(),
+            ApiError::Coded(coded) => coded.message.clone(),
             _ => format!("{}: {}", error_type, self),
         };
-        let response = ApiResponse::<()>::error(&error_message);
+        let code = self
+            .specific_code()
+            .unwrap_or_else(|| generic_code(status_code));
+        let params = match self {
+            ApiError::Coded(coded) => coded.params,
+            _ => BTreeMap::new(),
+        };
+        let response = ApiResponse::<()>::error(&error_message).with_error_code(code, params);
         (status_code, Json(response)).into_response()
     }
 }
 
+impl From<SecretScanError> for ApiError {
+    fn from(err: SecretScanError) -> Self {
+        match err {
+            SecretScanError::Database(db_err) => ApiError::Database(db_err),
+            SecretScanError::GitService(git_err) => ApiError::GitService(git_err),
+        }
+    }
+}
+
+impl From<PathRuleError> for ApiError {
+    fn from(err: PathRuleError) -> Self {
+        match err {
+            PathRuleError::Database(db_err) => ApiError::Database(db_err),
+            PathRuleError::GitService(git_err) => ApiError::GitService(git_err),
+            PathRuleError::InvalidPattern(..) => ApiError::BadRequest(err.to_string()),
+        }
+    }
+}
+
 impl From<ShareError> for ApiError {
     fn from(err: ShareError) -> Self {
         match err {
@@ -336,15 +543,21 @@ impl From<ProjectServiceError> for ApiError {
             ProjectServiceError::Io(io_err) => ApiError::Io(io_err),
             ProjectServiceError::Project(proj_err) => ApiError::Project(proj_err),
             ProjectServiceError::Share(share_err) => ApiError::from(share_err),
-            ProjectServiceError::PathNotFound(path) => {
-                ApiError::BadRequest(format!("Path does not exist: {}", path.display()))
-            }
-            ProjectServiceError::PathNotDirectory(path) => {
-                ApiError::BadRequest(format!("Path is not a directory: {}", path.display()))
-            }
-            ProjectServiceError::NotGitRepository(path) => {
-                ApiError::BadRequest(format!("Path is not a git repository: {}", path.display()))
-            }
+            ProjectServiceError::PathNotFound(path) => ApiError::coded(
+                ErrorCode::PathNotFound,
+                format!("Path does not exist: {}", path.display()),
+            )
+            .with_param("path", path.display()),
+            ProjectServiceError::PathNotDirectory(path) => ApiError::coded(
+                ErrorCode::PathNotDirectory,
+                format!("Path is not a directory: {}", path.display()),
+            )
+            .with_param("path", path.display()),
+            ProjectServiceError::NotGitRepository(path) => ApiError::coded(
+                ErrorCode::NotAGitRepository,
+                format!("Path is not a git repository: {}", path.display()),
+            )
+            .with_param("path", path.display()),
             ProjectServiceError::DuplicateGitRepoPath => ApiError::Conflict(
                 "A project with this git repository path already exists".to_string(),
             ),
@@ -352,7 +565,7 @@ impl From<ProjectServiceError> for ApiError {
                 "A repository with this name already exists in the project".to_string(),
             ),
             ProjectServiceError::RepositoryNotFound => {
-                ApiError::BadRequest("Repository not found".to_string())
+                ApiError::coded(ErrorCode::RepoNotFound, "Repository not found")
             }
             ProjectServiceError::GitError(msg) => {
                 ApiError::BadRequest(format!("Git operation failed: {}", msg))
@@ -369,16 +582,24 @@ impl From<RepoServiceError> for ApiError {
         match err {
             RepoServiceError::Database(db_err) => ApiError::Database(db_err),
             RepoServiceError::Io(io_err) => ApiError::Io(io_err),
-            RepoServiceError::PathNotFound(path) => {
-                ApiError::BadRequest(format!("Path does not exist: {}", path.display()))
-            }
-            RepoServiceError::PathNotDirectory(path) => {
-                ApiError::BadRequest(format!("Path is not a directory: {}", path.display()))
-            }
-            RepoServiceError::NotGitRepository(path) => {
-                ApiError::BadRequest(format!("Path is not a git repository: {}", path.display()))
+            RepoServiceError::PathNotFound(path) => ApiError::coded(
+                ErrorCode::PathNotFound,
+                format!("Path does not exist: {}", path.display()),
+            )
+            .with_param("path", path.display()),
+            RepoServiceError::PathNotDirectory(path) => ApiError::coded(
+                ErrorCode::PathNotDirectory,
+                format!("Path is not a directory: {}", path.display()),
+            )
+            .with_param("path", path.display()),
+            RepoServiceError::NotGitRepository(path) => ApiError::coded(
+                ErrorCode::NotAGitRepository,
+                format!("Path is not a git repository: {}", path.display()),
+            )
+            .with_param("path", path.display()),
+            RepoServiceError::NotFound => {
+                ApiError::coded(ErrorCode::RepoNotFound, "Repository not found")
             }
-            RepoServiceError::NotFound => ApiError::BadRequest("Repository not found".to_string()),
             RepoServiceError::DirectoryAlreadyExists(path) => {
                 ApiError::BadRequest(format!("Directory already exists: {}", path.display()))
             }
@@ -388,6 +609,14 @@ impl From<RepoServiceError> for ApiError {
             RepoServiceError::InvalidFolderName(name) => {
                 ApiError::BadRequest(format!("Invalid folder name: {}", name))
             }
+            RepoServiceError::IdentityMismatch(path) => ApiError::BadRequest(format!(
+                "Path holds a different repository: {}",
+                path.display()
+            )),
+            RepoServiceError::PathInUse(path) => ApiError::Conflict(format!(
+                "Path is already registered as another repository: {}",
+                path.display()
+            )),
         }
     }
 }
@@ -397,7 +626,7 @@ impl From<ProjectRepoError> for ApiError {
         match err {
             ProjectRepoError::Database(db_err) => ApiError::Database(db_err),
             ProjectRepoError::NotFound => {
-                ApiError::BadRequest("Repository not found in project".to_string())
+                ApiError::coded(ErrorCode::RepoNotFound, "Repository not found in project")
             }
             ProjectRepoError::AlreadyExists => {
                 ApiError::Conflict("Repository already exists in project".to_string())
@@ -405,3 +634,35 @@ impl From<ProjectRepoError> for ApiError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+
+    use super::*;
+
+    async fn response_json(error: ApiError) -> (StatusCode, serde_json::Value) {
+        let response = error.into_response();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn coded_errors_carry_their_code_and_params() {
+        let (status, json) = response_json(ApiError::integration_not_configured("Linear")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["error_code"], "INTEGRATION_NOT_CONFIGURED");
+        assert_eq!(json["error_params"]["provider"], "Linear");
+
+        let (status, json) =
+            response_json(ApiError::coded(ErrorCode::TaskNotFound, "Task not found")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["error_code"], "TASK_NOT_FOUND");
+        assert!(json["error_params"].is_null());
+
+        let (status, json) = response_json(ApiError::BadRequest("Nope".to_string())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(json["error_code"], "BAD_REQUEST");
+    }
+}
@@ -62,7 +62,18 @@ async fn main() -> Result<(), VibeKanbanError> {
         .backfill_repo_names()
         .await
         .map_err(DeploymentError::from)?;
+    match deployment.container().recovery_report().await {
+        Ok(report) => report.log_summary(),
+        Err(e) => tracing::warn!("Failed to build startup recovery report: {}", e),
+    }
     deployment.spawn_pr_monitor_service().await;
+    deployment.spawn_rest_hook_service().await;
+    deployment.spawn_user_feed_service().await;
+    deployment.spawn_sla_monitor_service().await;
+    deployment.spawn_ci_monitor_service().await;
+    deployment
+        .spawn_issue_sync_service(routes::issue_sync::ScheduledIssueSync(deployment.clone()))
+        .await;
     deployment
         .track_if_analytics_allowed("session_start", serde_json::json!({}))
         .await;
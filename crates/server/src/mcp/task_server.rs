@@ -693,7 +693,7 @@ impl TaskServer {
             .into_iter()
             .map(|r| WorkspaceRepoInput {
                 repo_id: r.repo_id,
-                target_branch: r.base_branch,
+                target_branch: Some(r.base_branch),
             })
             .collect();
 
@@ -3,11 +3,9 @@ use axum::{
     http::{StatusCode, request::Parts},
     response::{IntoResponse, Response},
 };
-use db::models::user::UserRole;
+use db::models::{kiosk_token::KioskToken, user::UserRole};
 use uuid::Uuid;
 
-use crate::DeploymentImpl;
-
 /// Authenticated user extracted from the request
 #[derive(Debug, Clone)]
 pub struct AuthUser {
@@ -58,11 +56,19 @@ fn get_jwt_secret() -> String {
 impl<S> FromRequestParts<S> for AuthUser
 where
     S: Send + Sync,
-    DeploymentImpl: FromRequestParts<S>,
 {
     type Rejection = AuthError;
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // Kiosk tokens are resolved by the read-only middleware
+        if let Some(kiosk) = parts.extensions.get::<KioskToken>() {
+            return Ok(AuthUser {
+                id: kiosk.id,
+                username: kiosk.name.clone(),
+                role: UserRole::Viewer,
+            });
+        }
+
         // Extract Authorization header
         let auth_header = parts
             .headers
@@ -101,7 +107,6 @@ pub struct RequireAdmin(pub AuthUser);
 impl<S> FromRequestParts<S> for RequireAdmin
 where
     S: Send + Sync,
-    DeploymentImpl: FromRequestParts<S>,
 {
     type Rejection = Response;
 
@@ -130,7 +135,6 @@ pub struct OptionalAuth(pub Option<AuthUser>);
 impl<S> FromRequestParts<S> for OptionalAuth
 where
     S: Send + Sync,
-    DeploymentImpl: FromRequestParts<S>,
 {
     type Rejection = std::convert::Infallible;
 
@@ -1,5 +1,7 @@
 pub mod auth;
 pub mod model_loaders;
+pub mod read_only;
 
 pub use auth::*;
 pub use model_loaders::*;
+pub use read_only::*;
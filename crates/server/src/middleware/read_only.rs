@@ -0,0 +1,76 @@
+use axum::{
+    extract::{Request, State},
+    http::{Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use db::models::kiosk_token::KioskToken;
+use deployment::Deployment;
+
+use crate::{DeploymentImpl, middleware::AuthError, secret_token};
+
+/// Kiosk tokens are opaque rather than JWTs, and this prefix tells them apart
+pub const KIOSK_TOKEN_PREFIX: &str = "vkk_";
+
+pub fn generate_kiosk_token() -> String {
+    format!("{KIOSK_TOKEN_PREFIX}{}", secret_token::generate_token())
+}
+
+/// The kiosk token on a request, from the `Authorization` header or, for
+/// WebSockets and wallboard URLs that can't set headers, a `kiosk_token` query
+/// parameter
+fn kiosk_token(request: &Request) -> Option<String> {
+    let from_header = request
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .filter(|token| token.starts_with(KIOSK_TOKEN_PREFIX))
+        .map(str::to_string);
+    from_header.or_else(|| {
+        url::form_urlencoded::parse(request.uri().query()?.as_bytes())
+            .find(|(key, _)| key == "kiosk_token")
+            .map(|(_, value)| value.into_owned())
+    })
+}
+
+/// Requests made with a kiosk token may only read. The token is resolved here
+/// and left in the request extensions, where [`AuthUser`](super::AuthUser)
+/// picks it up as a viewer.
+pub async fn read_only_middleware(
+    State(deployment): State<DeploymentImpl>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(token) = kiosk_token(&request) else {
+        return next.run(request).await;
+    };
+
+    let pool = &deployment.db().pool;
+    let token_hash = secret_token::hash_token(&token);
+    let kiosk = match KioskToken::find_by_token_hash(pool, &token_hash).await {
+        Ok(Some(kiosk)) => kiosk,
+        Ok(None) => return AuthError::InvalidToken.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to look up kiosk token: {}", e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    if !matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) {
+        let body = serde_json::json!({
+            "success": false,
+            "error": "Kiosk tokens are read-only"
+        });
+        return (StatusCode::FORBIDDEN, axum::Json(body)).into_response();
+    }
+
+    if let Err(e) = KioskToken::touch(pool, kiosk.id).await {
+        tracing::warn!("Failed to record kiosk token use: {}", e);
+    }
+    request.extensions_mut().insert(kiosk);
+    next.run(request).await
+}
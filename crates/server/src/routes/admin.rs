@@ -0,0 +1,280 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    execution_process::ExecutionProcess,
+    session::Session,
+    workspace::{Workspace, WorkspaceDirectory},
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::{
+    container::ContainerService, events::EventBusStatus, executor_slots::ExecutorSlotsStatus,
+    recovery::RecoveryReport, storage_stats::StorageStats, workspace_manager::WorkspaceManager,
+};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+#[derive(Debug, Serialize, TS)]
+pub struct VacuumResponse {
+    pub reclaimed_bytes: i64,
+    pub file_size_bytes: i64,
+}
+
+pub async fn get_stats(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<StorageStats>>, ApiError> {
+    let stats =
+        StorageStats::collect(&deployment.db().pool, deployment.image().cache_dir()).await?;
+    Ok(ResponseJson(ApiResponse::success(stats)))
+}
+
+pub async fn vacuum(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<VacuumResponse>>, ApiError> {
+    let reclaimed_bytes = deployment.db().vacuum().await?;
+    let file_size_bytes = db::stats::DatabaseStats::collect(&deployment.db().pool)
+        .await?
+        .file_size_bytes;
+
+    tracing::info!("Database vacuum reclaimed {} bytes", reclaimed_bytes);
+    deployment
+        .track_if_analytics_allowed(
+            "database_vacuumed",
+            serde_json::json!({
+                "reclaimed_bytes": reclaimed_bytes,
+                "file_size_bytes": file_size_bytes,
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(VacuumResponse {
+        reclaimed_bytes,
+        file_size_bytes,
+    })))
+}
+
+/// Event bus buffer bounds and how far each consumer has committed
+pub async fn get_event_bus_status(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<EventBusStatus>>, ApiError> {
+    Ok(ResponseJson(ApiResponse::success(
+        deployment.events().bus().status(),
+    )))
+}
+
+/// Executor slots in use and the projects running or waiting for them
+pub async fn get_executor_slots(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<ExecutorSlotsStatus>>, ApiError> {
+    Ok(ResponseJson(ApiResponse::success(
+        deployment.container().executor_slots().status(),
+    )))
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum ReconcileAction {
+    /// Recreate the directory and its worktrees from the workspace branch
+    Repair,
+    /// Delete the workspace record along with its sessions and logs
+    Purge,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ReconcileWorkspacesRequest {
+    pub workspace_ids: Vec<Uuid>,
+    pub action: ReconcileAction,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct SkippedWorkspace {
+    pub workspace_id: Uuid,
+    pub reason: String,
+}
+
+#[derive(Debug, Default, Serialize, TS)]
+pub struct ReconcileWorkspacesResponse {
+    pub repaired: Vec<Uuid>,
+    pub purged: Vec<Uuid>,
+    pub skipped: Vec<SkippedWorkspace>,
+}
+
+/// Workspaces whose directory no longer exists on disk
+pub async fn get_missing_workspaces(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<WorkspaceDirectory>>>, ApiError> {
+    let missing = WorkspaceManager::find_missing_workspaces(&deployment.db().pool).await?;
+    Ok(ResponseJson(ApiResponse::success(missing)))
+}
+
+/// Repair or purge workspaces with missing directories. Workspaces whose
+/// directory has reappeared or that have running processes are skipped.
+pub async fn reconcile_workspaces(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ReconcileWorkspacesRequest>,
+) -> Result<ResponseJson<ApiResponse<ReconcileWorkspacesResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let missing: Vec<Uuid> = WorkspaceManager::find_missing_workspaces(pool)
+        .await?
+        .into_iter()
+        .map(|directory| directory.workspace_id)
+        .collect();
+
+    let mut response = ReconcileWorkspacesResponse::default();
+    for workspace_id in payload.workspace_ids {
+        let skip = |reason: &str| SkippedWorkspace {
+            workspace_id,
+            reason: reason.to_string(),
+        };
+        if !missing.contains(&workspace_id) {
+            response.skipped.push(skip("directory is not missing"));
+            continue;
+        }
+        if ExecutionProcess::has_running_processes_for_workspace(pool, workspace_id).await? {
+            response
+                .skipped
+                .push(skip("workspace has running processes"));
+            continue;
+        }
+        let Some(workspace) = Workspace::find_by_id(pool, workspace_id).await? else {
+            response.skipped.push(skip("workspace not found"));
+            continue;
+        };
+
+        match payload.action {
+            ReconcileAction::Repair => {
+                match deployment
+                    .container()
+                    .ensure_container_exists(&workspace)
+                    .await
+                {
+                    Ok(_) => response.repaired.push(workspace_id),
+                    Err(e) => response.skipped.push(skip(&e.to_string())),
+                }
+            }
+            ReconcileAction::Purge => {
+                Workspace::delete(pool, workspace_id).await?;
+                response.purged.push(workspace_id);
+            }
+        }
+    }
+
+    tracing::info!(
+        "Workspace reconciliation repaired {}, purged {}, skipped {}",
+        response.repaired.len(),
+        response.purged.len(),
+        response.skipped.len()
+    );
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+/// A fix for one section of the recovery report. Workspaces with missing
+/// directories are handled by `/admin/workspaces/reconcile`.
+#[derive(Debug, Deserialize, TS)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum RecoveryFix {
+    RemoveOrphanedDirectories {
+        paths: Vec<String>,
+    },
+    DeleteStaleSessions {
+        session_ids: Vec<Uuid>,
+    },
+    /// Clears every interrupted process when `execution_process_ids` is empty
+    DismissInterrupted {
+        execution_process_ids: Vec<Uuid>,
+    },
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct RecoveryFixResponse {
+    pub fixed: usize,
+    pub report: RecoveryReport,
+}
+
+/// What the startup reconciliation found, rescanned on each request
+pub async fn get_recovery_report(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<RecoveryReport>>, ApiError> {
+    let report = deployment
+        .container()
+        .recovery_report()
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    Ok(ResponseJson(ApiResponse::success(report)))
+}
+
+/// Apply a fix and return the refreshed report. Items that are no longer in
+/// the report are ignored.
+pub async fn fix_recovery_report(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<RecoveryFix>,
+) -> Result<ResponseJson<ApiResponse<RecoveryFixResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let (action, fixed) = match payload {
+        RecoveryFix::RemoveOrphanedDirectories { paths } => {
+            let removed = WorkspaceManager::remove_orphan_directories(pool, &paths)
+                .await
+                .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+            ("remove_orphaned_directories", removed.len())
+        }
+        RecoveryFix::DeleteStaleSessions { session_ids } => {
+            let stale = Session::find_stale(pool).await?;
+            let mut deleted = 0;
+            for session_id in session_ids {
+                if stale.iter().any(|s| s.session_id == session_id)
+                    && Session::delete_if_empty(pool, session_id).await?
+                {
+                    deleted += 1;
+                }
+            }
+            ("delete_stale_sessions", deleted)
+        }
+        RecoveryFix::DismissInterrupted {
+            execution_process_ids,
+        } => (
+            "dismiss_interrupted",
+            deployment
+                .container()
+                .recovery_log()
+                .dismiss_interrupted(&execution_process_ids),
+        ),
+    };
+    tracing::info!("Recovery fix {} applied to {} items", action, fixed);
+
+    let report = deployment
+        .container()
+        .recovery_report()
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    Ok(ResponseJson(ApiResponse::success(RecoveryFixResponse {
+        fixed,
+        report,
+    })))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/admin/stats", get(get_stats))
+        .route("/admin/vacuum", post(vacuum))
+        .route("/admin/event-bus", get(get_event_bus_status))
+        .route("/admin/executor-slots", get(get_executor_slots))
+        .route("/admin/workspaces/missing", get(get_missing_workspaces))
+        .route("/admin/workspaces/reconcile", post(reconcile_workspaces))
+        .route("/admin/recovery-report", get(get_recovery_report))
+        .route("/admin/recovery-report/fix", post(fix_recovery_report))
+}
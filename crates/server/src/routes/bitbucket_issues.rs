@@ -0,0 +1,318 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    response::{IntoResponse, Json as ResponseJson, Response},
+    routing::{get, post},
+};
+use db::models::{
+    project::Project,
+    task::{CreateTask, Task, TaskStatus},
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::bitbucket_issues::{
+    BitbucketCredentials, BitbucketIssue, BitbucketService, ListBitbucketIssuesParams,
+};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::{
+        issue_sync::{IssueSyncPreview, SyncCounts, SyncIssuesQuery, record_sync_run},
+        notifications,
+    },
+};
+
+const BITBUCKET_IMPORT_PREFIX: &str = "Imported from Bitbucket Issue #";
+
+#[derive(Debug, Deserialize)]
+pub struct ListBitbucketIssuesQuery {
+    /// Comma-separated issue states
+    pub states: Option<String>,
+    pub page: Option<i32>,
+    pub pagelen: Option<i32>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct BitbucketIssuesResponse {
+    pub issues: Vec<BitbucketIssue>,
+    pub has_bitbucket_config: bool,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ImportBitbucketIssueRequest {
+    pub issue_id: i64,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct ImportBitbucketIssueResponse {
+    pub task: Task,
+    pub issue: BitbucketIssue,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct BitbucketConfigStatus {
+    pub has_repo: bool,
+    pub has_credentials: bool,
+    pub workspace: Option<String>,
+    pub repo_slug: Option<String>,
+    pub username: Option<String>,
+}
+
+/// Workspace, repo slug and credentials, when the project has all of them set
+pub(crate) fn bitbucket_connection(
+    project: &Project,
+) -> Option<(String, String, BitbucketCredentials)> {
+    match (
+        &project.bitbucket_workspace,
+        &project.bitbucket_repo_slug,
+        &project.bitbucket_username,
+        &project.bitbucket_app_password,
+    ) {
+        (Some(workspace), Some(repo_slug), Some(username), Some(app_password)) => Some((
+            workspace.clone(),
+            repo_slug.clone(),
+            BitbucketCredentials {
+                username: username.clone(),
+                app_password: app_password.clone(),
+            },
+        )),
+        _ => None,
+    }
+}
+
+pub async fn get_bitbucket_config_status(
+    Extension(project): Extension<Project>,
+) -> Result<ResponseJson<ApiResponse<BitbucketConfigStatus>>, ApiError> {
+    let status = BitbucketConfigStatus {
+        has_repo: project.bitbucket_workspace.is_some() && project.bitbucket_repo_slug.is_some(),
+        has_credentials: project.bitbucket_username.is_some()
+            && project.bitbucket_app_password.is_some(),
+        workspace: project.bitbucket_workspace.clone(),
+        repo_slug: project.bitbucket_repo_slug.clone(),
+        username: project.bitbucket_username.clone(),
+    };
+    Ok(ResponseJson(ApiResponse::success(status)))
+}
+
+pub async fn list_bitbucket_issues(
+    Extension(project): Extension<Project>,
+    Query(query): Query<ListBitbucketIssuesQuery>,
+) -> Result<ResponseJson<ApiResponse<BitbucketIssuesResponse>>, ApiError> {
+    let Some((workspace, repo_slug, credentials)) = bitbucket_connection(&project) else {
+        return Ok(ResponseJson(ApiResponse::success(
+            BitbucketIssuesResponse {
+                issues: vec![],
+                has_bitbucket_config: false,
+            },
+        )));
+    };
+
+    let defaults = ListBitbucketIssuesParams::default();
+    let params = ListBitbucketIssuesParams {
+        states: query
+            .states
+            .map(|states| {
+                states
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or(defaults.states),
+        pagelen: query.pagelen.or(defaults.pagelen),
+        page: query.page.or(defaults.page),
+    };
+
+    let issues = BitbucketService::new()
+        .list_issues(&credentials, &workspace, &repo_slug, &params)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        BitbucketIssuesResponse {
+            issues,
+            has_bitbucket_config: true,
+        },
+    )))
+}
+
+fn issue_task(project_id: Uuid, issue: &BitbucketIssue) -> CreateTask {
+    let description = format!(
+        "{}{}\n{}\n\n{}",
+        BITBUCKET_IMPORT_PREFIX,
+        issue.id,
+        issue.html_url,
+        issue.description.clone().unwrap_or_default()
+    );
+    CreateTask {
+        project_id,
+        title: issue.title.clone(),
+        description: Some(description),
+        status: Some(TaskStatus::Todo),
+        execution_mode: None,
+        parent_workspace_id: None,
+        image_ids: None,
+        shared_task_id: None,
+    }
+}
+
+pub async fn import_bitbucket_issue(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ImportBitbucketIssueRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportBitbucketIssueResponse>>, ApiError> {
+    let (workspace, repo_slug, credentials) = bitbucket_connection(&project)
+        .ok_or_else(|| ApiError::integration_not_configured("Bitbucket"))?;
+
+    let issue = BitbucketService::new()
+        .get_issue(&credentials, &workspace, &repo_slug, payload.issue_id)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let task = Task::create(
+        &deployment.db().pool,
+        &issue_task(project.id, &issue),
+        Uuid::new_v4(),
+    )
+    .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "bitbucket_issue_imported",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "issue_id": issue.id,
+                "task_id": task.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(
+        ImportBitbucketIssueResponse { task, issue },
+    )))
+}
+
+/// Import unresolved issues that have no task yet. With `dry_run` set,
+/// returns an [`IssueSyncPreview`] instead and writes nothing.
+pub async fn sync_bitbucket_issues(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<SyncIssuesQuery>,
+) -> Result<Response, ApiError> {
+    let (project_id, dry_run) = (project.id, query.dry_run);
+    record_sync_run(
+        &deployment,
+        project_id,
+        "Bitbucket",
+        dry_run,
+        run_bitbucket_sync(project, deployment.clone(), query),
+    )
+    .await
+}
+
+async fn run_bitbucket_sync(
+    project: Project,
+    deployment: DeploymentImpl,
+    query: SyncIssuesQuery,
+) -> Result<(Response, SyncCounts), ApiError> {
+    let (workspace, repo_slug, credentials) = bitbucket_connection(&project)
+        .ok_or_else(|| ApiError::integration_not_configured("Bitbucket"))?;
+
+    let params = ListBitbucketIssuesParams {
+        pagelen: Some(50),
+        ..Default::default()
+    };
+    let issues = match BitbucketService::new()
+        .list_issues(&credentials, &workspace, &repo_slug, &params)
+        .await
+    {
+        Ok(issues) => issues,
+        Err(e) => {
+            let error = e.to_string();
+            if !query.dry_run {
+                notifications::record_sync_error(&deployment, &project, "Bitbucket", &error).await;
+            }
+            return Err(ApiError::BadRequest(error));
+        }
+    };
+
+    let existing_tasks =
+        Task::find_by_project_id_with_attempt_status(&deployment.db().pool, project.id).await?;
+    let existing_issue_ids: Vec<i64> = existing_tasks
+        .iter()
+        .filter_map(|t| {
+            t.description
+                .as_deref()?
+                .lines()
+                .next()?
+                .strip_prefix(BITBUCKET_IMPORT_PREFIX)?
+                .parse::<i64>()
+                .ok()
+        })
+        .collect();
+
+    if query.dry_run {
+        let mut preview = IssueSyncPreview::default();
+        for issue in issues {
+            preview.push(
+                existing_issue_ids.contains(&issue.id),
+                format!("#{}", issue.id),
+                issue.title,
+                issue.html_url,
+            );
+        }
+        preview
+            .flag_duplicates(&deployment.db().pool, project.id)
+            .await?;
+        return Ok((
+            ResponseJson(ApiResponse::<IssueSyncPreview>::success(preview)).into_response(),
+            SyncCounts::default(),
+        ));
+    }
+
+    let mut imported = Vec::new();
+    for issue in issues {
+        if existing_issue_ids.contains(&issue.id) {
+            continue;
+        }
+
+        let create_task = &issue_task(project.id, &issue);
+        let task_id = Uuid::new_v4();
+        let task = deployment
+            .db()
+            .write(|pool| async move { Task::create(&pool, create_task, task_id).await })
+            .await?;
+        imported.push(ImportBitbucketIssueResponse { task, issue });
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "bitbucket_issues_synced",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "imported_count": imported.len(),
+            }),
+        )
+        .await;
+
+    let counts = SyncCounts {
+        imported: imported.len(),
+        updated: 0,
+    };
+    Ok((
+        ResponseJson(ApiResponse::<Vec<_>>::success(imported)).into_response(),
+        counts,
+    ))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/bitbucket/config", get(get_bitbucket_config_status))
+        .route("/bitbucket/issues", get(list_bitbucket_issues))
+        .route("/bitbucket/issues/import", post(import_bitbucket_issue))
+        .route("/bitbucket/issues/sync", post(sync_bitbucket_issues))
+}
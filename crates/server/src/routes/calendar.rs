@@ -0,0 +1,178 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Json as ResponseJson},
+    routing::get,
+};
+use chrono::{Duration, Utc};
+use db::models::{
+    execution_process::{ExecutionProcess, ExecutionProcessStatus},
+    project::Project,
+    project_calendar_feed::ProjectCalendarFeed,
+    task::Task,
+    task_schedule::{SetTaskSchedule, TaskSchedule},
+};
+use deployment::Deployment;
+use serde::Serialize;
+use services::services::calendar::{CalendarEvent, render_ics};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin, secret_token};
+
+/// How far back agent runs are included in the feed
+const AGENT_RUN_WINDOW_DAYS: i64 = 90;
+
+#[derive(Debug, Serialize, TS)]
+pub struct RotatedCalendarFeed {
+    pub calendar_feed: ProjectCalendarFeed,
+    /// Only returned here; it can't be looked up again
+    pub token: String,
+}
+
+/// Public, token-authenticated ICS feed of a project's scheduled tasks and agent runs
+pub async fn get_calendar_feed(
+    State(deployment): State<DeploymentImpl>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let pool = &deployment.db().pool;
+    let token = token.strip_suffix(".ics").unwrap_or(&token);
+    let feed = ProjectCalendarFeed::find_by_token_hash(pool, &secret_token::hash_token(token))
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+    let project = Project::find_by_id(pool, feed.project_id)
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+
+    let mut events = Vec::new();
+    for task in TaskSchedule::find_scheduled_tasks_by_project(pool, project.id).await? {
+        if let Some(starts_at) = task.starts_at {
+            events.push(CalendarEvent {
+                uid: format!("task-{}-start@vibe-kanban", task.task_id),
+                summary: format!("Start: {}", task.title),
+                description: task.description.clone(),
+                start: starts_at,
+                end: None,
+                updated: task.updated_at,
+            });
+        }
+        if let Some(due_at) = task.due_at {
+            events.push(CalendarEvent {
+                uid: format!("task-{}-due@vibe-kanban", task.task_id),
+                summary: format!("Due: {}", task.title),
+                description: task.description,
+                start: due_at,
+                end: None,
+                updated: task.updated_at,
+            });
+        }
+    }
+
+    let since = Utc::now() - Duration::days(AGENT_RUN_WINDOW_DAYS);
+    for run in ExecutionProcess::find_agent_runs_by_project(pool, project.id, since).await? {
+        let outcome = match run.status {
+            ExecutionProcessStatus::Running => "running",
+            ExecutionProcessStatus::Completed => "completed",
+            ExecutionProcessStatus::Failed => "failed",
+            ExecutionProcessStatus::Killed => "stopped",
+        };
+        events.push(CalendarEvent {
+            uid: format!("run-{}@vibe-kanban", run.id),
+            summary: format!("Agent run ({}): {}", outcome, run.task_title),
+            description: None,
+            start: run.started_at,
+            end: run.completed_at,
+            updated: run.completed_at.unwrap_or(run.started_at),
+        });
+    }
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        render_ics(&project.name, &events),
+    ))
+}
+
+pub async fn get_project_calendar_feed(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<ProjectCalendarFeed>>>, ApiError> {
+    let feed = ProjectCalendarFeed::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(feed)))
+}
+
+/// Enable the feed, or issue a new token so the old URL stops working
+pub async fn rotate_project_calendar_feed(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<RotatedCalendarFeed>>, ApiError> {
+    let token = secret_token::generate_token();
+    let calendar_feed = ProjectCalendarFeed::rotate(
+        &deployment.db().pool,
+        project.id,
+        &secret_token::hash_token(&token),
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(RotatedCalendarFeed {
+        calendar_feed,
+        token,
+    })))
+}
+
+pub async fn delete_project_calendar_feed(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = ProjectCalendarFeed::delete(&deployment.db().pool, project.id).await?;
+    if deleted == 0 {
+        return Err(ApiError::BadRequest(
+            "Calendar feed not enabled".to_string(),
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub async fn get_task_schedule(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TaskSchedule>>>, ApiError> {
+    let schedule = TaskSchedule::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(schedule)))
+}
+
+pub async fn set_task_schedule(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskSchedule>,
+) -> Result<ResponseJson<ApiResponse<TaskSchedule>>, ApiError> {
+    if let (Some(starts_at), Some(due_at)) = (payload.starts_at, payload.due_at)
+        && starts_at > due_at
+    {
+        return Err(ApiError::BadRequest(
+            "Scheduled start must not be after the due date".to_string(),
+        ));
+    }
+    let schedule = TaskSchedule::set(&deployment.db().pool, task.id, &payload).await?;
+    Ok(ResponseJson(ApiResponse::success(schedule)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/calendar/{token}", get(get_calendar_feed))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/calendar-feed",
+        get(get_project_calendar_feed)
+            .post(rotate_project_calendar_feed)
+            .delete(delete_project_calendar_feed),
+    )
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new().route("/schedule", get(get_task_schedule).put(set_task_schedule))
+}
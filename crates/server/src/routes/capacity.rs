@@ -0,0 +1,120 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use db::models::{
+    project::Project,
+    task::Task,
+    task_estimate::{SetTaskEstimate, TaskEstimate},
+    user::User,
+    user_capacity::{SetUserCapacity, UserCapacity},
+};
+use deployment::Deployment;
+use services::services::capacity::{ProjectCapacity, summarize_capacity};
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::AuthUser};
+
+fn validate_amount(name: &str, value: Option<f64>) -> Result<(), ApiError> {
+    match value {
+        Some(v) if !v.is_finite() || v < 0.0 => Err(ApiError::BadRequest(format!(
+            "{name} must be a non-negative number"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+pub async fn get_task_estimate(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TaskEstimate>>>, ApiError> {
+    let estimate = TaskEstimate::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(estimate)))
+}
+
+pub async fn set_task_estimate(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskEstimate>,
+) -> Result<ResponseJson<ApiResponse<Option<TaskEstimate>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    validate_amount("Estimate", payload.value)?;
+    let estimate = match payload.value {
+        Some(value) => Some(TaskEstimate::set(pool, task.id, value, payload.unit).await?),
+        None => {
+            TaskEstimate::delete(pool, task.id).await?;
+            None
+        }
+    };
+    Ok(ResponseJson(ApiResponse::success(estimate)))
+}
+
+pub async fn get_user_capacity(
+    State(deployment): State<DeploymentImpl>,
+    Path(user_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<Option<UserCapacity>>>, ApiError> {
+    let capacity = UserCapacity::find_by_user_id(&deployment.db().pool, user_id).await?;
+    Ok(ResponseJson(ApiResponse::success(capacity)))
+}
+
+/// Users set their own capacity; admins can set anyone's
+pub async fn set_user_capacity(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<SetUserCapacity>,
+) -> Result<ResponseJson<ApiResponse<UserCapacity>>, ApiError> {
+    if auth.id != user_id && !auth.is_admin() {
+        return Err(ApiError::Forbidden(
+            "Only admins can change another user's capacity".to_string(),
+        ));
+    }
+    validate_amount("Weekly hours", payload.weekly_hours)?;
+    validate_amount("Weekly points", payload.weekly_points)?;
+    let pool = &deployment.db().pool;
+    if User::find_by_id(pool, user_id).await?.is_none() {
+        return Err(ApiError::coded(ErrorCode::NotFound, "User not found"));
+    }
+    let capacity = UserCapacity::set(pool, user_id, &payload).await?;
+    Ok(ResponseJson(ApiResponse::success(capacity)))
+}
+
+/// Open estimated work in the project per assignee, against weekly capacity
+pub async fn get_project_capacity(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<ProjectCapacity>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let users: Vec<(Uuid, String)> = User::find_all(pool)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, user.username))
+        .collect();
+    let capacities = UserCapacity::find_all(pool).await?;
+    let open = TaskEstimate::find_open_by_project_id(pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(summarize_capacity(
+        &users,
+        &capacities,
+        &open,
+    ))))
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new().route("/estimate", get(get_task_estimate).put(set_task_estimate))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route("/capacity", get(get_project_capacity))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/users/{id}/capacity",
+        get(get_user_capacity).put(set_user_capacity),
+    )
+}
@@ -18,10 +18,14 @@ use executors::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use services::services::config::{
-    Config, ConfigError, SoundFile,
-    editor::{EditorConfig, EditorType},
-    save_config_to_file,
+use services::services::{
+    config::{
+        Config, ConfigError, SoundFile,
+        editor::{EditorConfig, EditorType},
+        save_config_to_file,
+    },
+    container::ContainerService,
+    http_client::{HttpClient, HttpClientConfig},
 };
 use tokio::fs;
 use ts_rs::TS;
@@ -126,12 +130,36 @@ async fn update_config(
     // Get old config state before updating
     let old_config = deployment.config().read().await.clone();
 
+    let http_config = (old_config.network != new_config.network)
+        .then(|| HttpClientConfig::from_env().with_network(&new_config.network));
+    if let Some(http_config) = &http_config
+        && let Err(e) = http_config.validate()
+    {
+        return ResponseJson(ApiResponse::error(&e));
+    }
+
     match save_config_to_file(&new_config, &config_path).await {
         Ok(_) => {
             let mut config = deployment.config().write().await;
             *config = new_config.clone();
             drop(config);
 
+            if let Some(http_config) = http_config {
+                HttpClient::configure(http_config);
+            }
+            if old_config.auto_start_slots != new_config.auto_start_slots {
+                deployment
+                    .container()
+                    .executor_slots()
+                    .set_capacity(new_config.auto_start_slots as usize);
+            }
+            if old_config.one_attempt_per_task != new_config.one_attempt_per_task {
+                deployment
+                    .container()
+                    .task_locks()
+                    .set_enabled(new_config.one_attempt_per_task);
+            }
+
             // Track config events when fields transition from false → true and run side effects
             handle_config_events(&deployment, &old_config, &new_config).await;
 
@@ -416,7 +444,8 @@ async fn update_profiles(
             match executor_profiles.save_overrides() {
                 Ok(_) => {
                     tracing::info!("Executor profiles saved successfully");
-                    // Reload the cached profiles
+                    // Reload the cached profiles; this also drops the cached
+                    // executor recommendation so it is probed against the new set
                     ExecutorConfigs::reload();
                     ResponseJson(ApiResponse::success(
                         "Executor profiles updated successfully".to_string(),
@@ -7,7 +7,7 @@ use axum::{
 use db::models::workspace::{Workspace, WorkspaceContext};
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
-use utils::response::ApiResponse;
+use utils::{path::normalize_container_ref, response::ApiResponse};
 
 use crate::{DeploymentImpl, error::ApiError};
 
@@ -21,8 +21,8 @@ pub async fn get_context(
     State(deployment): State<DeploymentImpl>,
     Query(payload): Query<ContainerQuery>,
 ) -> Result<ResponseJson<ApiResponse<WorkspaceContext>>, ApiError> {
-    let result =
-        Workspace::resolve_container_ref(&deployment.db().pool, &payload.container_ref).await;
+    let container_ref = normalize_container_ref(&payload.container_ref);
+    let result = Workspace::resolve_container_ref(&deployment.db().pool, &container_ref).await;
 
     match result {
         Ok(info) => {
@@ -0,0 +1,80 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use db::models::{
+    project::Project,
+    project_diff_exclusion::{CreateProjectDiffExclusion, ProjectDiffExclusion},
+};
+use deployment::Deployment;
+use services::services::path_rules;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+pub async fn list_diff_exclusions(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProjectDiffExclusion>>>, ApiError> {
+    let exclusions =
+        ProjectDiffExclusion::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(exclusions)))
+}
+
+pub async fn create_diff_exclusion(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateProjectDiffExclusion>,
+) -> Result<ResponseJson<ApiResponse<ProjectDiffExclusion>>, ApiError> {
+    let pattern = payload.pattern.trim();
+    if pattern.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Path pattern cannot be empty".to_string(),
+        ));
+    }
+    path_rules::compile_pattern(pattern)?;
+
+    let exclusion =
+        ProjectDiffExclusion::create(&deployment.db().pool, project.id, &payload).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "project_diff_exclusion_created",
+            serde_json::json!({ "project_id": project.id.to_string() }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(exclusion)))
+}
+
+pub async fn delete_diff_exclusion(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, exclusion_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted =
+        ProjectDiffExclusion::delete(&deployment.db().pool, project.id, exclusion_id).await?;
+    if deleted == 0 {
+        return Err(ApiError::coded(
+            ErrorCode::NotFound,
+            "Diff exclusion not found",
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/diff-exclusions",
+            get(list_diff_exclusions).post(create_diff_exclusion),
+        )
+        .route(
+            "/diff-exclusions/{exclusion_id}",
+            delete(delete_diff_exclusion),
+        )
+}
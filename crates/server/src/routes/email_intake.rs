@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{DefaultBodyLimit, Multipart, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use chrono::Utc;
+use db::models::{
+    image::TaskImage,
+    project::Project,
+    project_email_address::{ProjectEmailAddress, SetProjectEmailAddress},
+    task::{CreateTask, Task, TaskStatus},
+};
+use deployment::Deployment;
+use serde::Serialize;
+use services::services::email_intake::{
+    mailgun_signature_matches, recipient_addresses, task_description, task_title,
+};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+/// Outcome of an inbound email delivery
+#[derive(Debug, Serialize, TS)]
+pub struct EmailIntakeResult {
+    /// None when no project uses any of the recipient addresses
+    pub task: Option<Task>,
+    pub attachments_stored: usize,
+    /// Attachments that could not be stored, such as non-image files
+    pub attachments_skipped: Vec<String>,
+}
+
+struct EmailAttachment {
+    filename: String,
+    data: Vec<u8>,
+}
+
+/// Mailgun posts routed messages as multipart form fields plus `attachment-N` files
+pub async fn receive_inbound_email(
+    State(deployment): State<DeploymentImpl>,
+    mut multipart: Multipart,
+) -> Result<ResponseJson<ApiResponse<EmailIntakeResult>>, ApiError> {
+    let signing_key = deployment
+        .config()
+        .read()
+        .await
+        .email_intake_signing_key
+        .clone()
+        .filter(|key| !key.trim().is_empty())
+        .ok_or(ApiError::Unauthorized)?;
+
+    let mut fields = HashMap::new();
+    let mut attachments = Vec::new();
+    let mut verified = false;
+    while let Some(field) = multipart.next_field().await? {
+        let Some(name) = field.name().map(str::to_string) else {
+            continue;
+        };
+        if name.starts_with("attachment-") {
+            // Attachments are only read once the form fields have proved the
+            // delivery came from Mailgun
+            if !verified {
+                verify_delivery(&deployment, &signing_key, &fields)?;
+                verified = true;
+            }
+            let filename = field
+                .file_name()
+                .map(str::to_string)
+                .unwrap_or_else(|| name.clone());
+            let data = field.bytes().await?.to_vec();
+            attachments.push(EmailAttachment { filename, data });
+        } else {
+            fields.insert(name, field.text().await?);
+        }
+    }
+    if !verified {
+        verify_delivery(&deployment, &signing_key, &fields)?;
+    }
+    let field = |name: &str| fields.get(name).map(String::as_str);
+
+    let pool = &deployment.db().pool;
+    let mut matched = None;
+    for address in recipient_addresses(field("recipient").or(field("To")).unwrap_or_default()) {
+        if let Some(email_address) = ProjectEmailAddress::find_by_address(pool, &address).await? {
+            matched = Project::find_by_id(pool, email_address.project_id).await?;
+            break;
+        }
+    }
+    let Some(project) = matched else {
+        return Ok(ResponseJson(ApiResponse::success(EmailIntakeResult {
+            task: None,
+            attachments_stored: 0,
+            attachments_skipped: vec![],
+        })));
+    };
+
+    let image_service = deployment.image();
+    let mut image_ids = Vec::new();
+    let mut image_lines = Vec::new();
+    let mut attachments_skipped = Vec::new();
+    for attachment in &attachments {
+        match image_service
+            .store_image(&attachment.data, &attachment.filename)
+            .await
+        {
+            Ok(image) => {
+                image_lines.push(format!(
+                    "![{}]({}/{})",
+                    attachment.filename,
+                    utils::path::VIBE_IMAGES_DIR,
+                    image.file_path
+                ));
+                image_ids.push(image.id);
+            }
+            Err(e) => {
+                tracing::warn!("Skipping email attachment {}: {}", attachment.filename, e);
+                attachments_skipped.push(attachment.filename.clone());
+            }
+        }
+    }
+
+    let sender = field("from")
+        .or(field("sender"))
+        .unwrap_or("unknown sender");
+    let mut description = task_description(sender, field("stripped-text").or(field("body-plain")));
+    if !image_lines.is_empty() {
+        description.push_str(&format!(
+            "\n\n## Attachments\n\n{}",
+            image_lines.join("\n\n")
+        ));
+    }
+    if !attachments_skipped.is_empty() {
+        description.push_str(&format!(
+            "\n\nAttachments not imported: {}",
+            attachments_skipped.join(", ")
+        ));
+    }
+
+    let create_task = CreateTask {
+        project_id: project.id,
+        title: task_title(field("subject")),
+        description: Some(description),
+        status: Some(TaskStatus::Todo),
+        execution_mode: None,
+        parent_workspace_id: None,
+        image_ids: if image_ids.is_empty() {
+            None
+        } else {
+            Some(image_ids.clone())
+        },
+        shared_task_id: None,
+    };
+    let task_id = Uuid::new_v4();
+    let task = Task::create(pool, &create_task, task_id).await?;
+
+    if !image_ids.is_empty() {
+        TaskImage::associate_many_dedup(pool, task.id, &image_ids).await?;
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "email_task_created",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "task_id": task.id.to_string(),
+                "attachment_count": attachments.len(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(EmailIntakeResult {
+        task: Some(task),
+        attachments_stored: image_ids.len(),
+        attachments_skipped,
+    })))
+}
+
+/// Check the delivery's signature and that its token hasn't been used before
+fn verify_delivery(
+    deployment: &DeploymentImpl,
+    signing_key: &str,
+    fields: &HashMap<String, String>,
+) -> Result<(), ApiError> {
+    let field = |name: &str| fields.get(name).map(String::as_str);
+    let (Some(timestamp), Some(token), Some(signature)) =
+        (field("timestamp"), field("token"), field("signature"))
+    else {
+        return Err(ApiError::Unauthorized);
+    };
+    let now = Utc::now().timestamp();
+    if !mailgun_signature_matches(signing_key, timestamp, token, signature, now)
+        || !deployment.mailgun_tokens().first_use(timestamp, token, now)
+    {
+        return Err(ApiError::Unauthorized);
+    }
+    Ok(())
+}
+
+pub async fn get_project_email_address(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<ProjectEmailAddress>>>, ApiError> {
+    let address =
+        ProjectEmailAddress::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(address)))
+}
+
+pub async fn set_project_email_address(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetProjectEmailAddress>,
+) -> Result<ResponseJson<ApiResponse<ProjectEmailAddress>>, ApiError> {
+    let address = match recipient_addresses(&payload.address).as_slice() {
+        [address] => address.clone(),
+        _ => {
+            return Err(ApiError::BadRequest(
+                "Enter a single email address".to_string(),
+            ));
+        }
+    };
+    let pool = &deployment.db().pool;
+    if let Some(existing) = ProjectEmailAddress::find_by_address(pool, &address).await?
+        && existing.project_id != project.id
+    {
+        return Err(ApiError::Conflict(
+            "This address is already used by another project".to_string(),
+        ));
+    }
+    let email_address = ProjectEmailAddress::set(pool, project.id, &address).await?;
+    Ok(ResponseJson(ApiResponse::success(email_address)))
+}
+
+pub async fn delete_project_email_address(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = ProjectEmailAddress::delete(&deployment.db().pool, project.id).await?;
+    if deleted == 0 {
+        return Err(ApiError::BadRequest(
+            "Email intake not configured".to_string(),
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/email/inbound",
+        post(receive_inbound_email).layer(DefaultBodyLimit::max(20 * 1024 * 1024)), // 20MB limit
+    )
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/email-intake",
+        get(get_project_email_address)
+            .put(set_project_email_address)
+            .delete(delete_project_email_address),
+    )
+}
@@ -1,6 +1,9 @@
+use std::str::FromStr;
+
 use axum::{
     BoxError, Router,
-    extract::State,
+    extract::{Query, State},
+    http::HeaderMap,
     response::{
         Sse,
         sse::{Event, KeepAlive},
@@ -9,15 +12,45 @@ use axum::{
 };
 use deployment::Deployment;
 use futures_util::TryStreamExt;
+use serde::Deserialize;
+use services::services::events::EventTopic;
+
+use crate::{DeploymentImpl, error::ApiError};
 
-use crate::DeploymentImpl;
+#[derive(Debug, Deserialize)]
+pub struct EventsQuery {
+    /// Resume after this bus offset; `Last-Event-ID` takes precedence
+    pub after: Option<u64>,
+    /// Comma-separated topics, e.g. `tasks,execution_processes`. All when omitted.
+    pub topics: Option<String>,
+}
 
 pub async fn events(
     State(deployment): State<DeploymentImpl>,
-) -> Result<Sse<impl futures_util::Stream<Item = Result<Event, BoxError>>>, axum::http::StatusCode>
-{
-    // Ask the container service for a combined "history + live" stream
-    let stream = deployment.stream_events().await;
+    headers: HeaderMap,
+    Query(query): Query<EventsQuery>,
+) -> Result<Sse<impl futures_util::Stream<Item = Result<Event, BoxError>>>, ApiError> {
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+    let topics = query
+        .topics
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|topic| !topic.is_empty())
+        .map(|topic| {
+            EventTopic::from_str(topic)
+                .map_err(|_| ApiError::BadRequest(format!("Unknown event topic: {topic}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Everything still buffered on the bus, then live events
+    let stream = deployment
+        .stream_events(last_event_id.or(query.after).unwrap_or(0), topics)
+        .await;
     Ok(Sse::new(stream.map_err(|e| -> BoxError { e.into() })).keep_alive(KeepAlive::default()))
 }
 
@@ -0,0 +1,105 @@
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    execution_process::ExecutionProcess,
+    label_profile_rule::LabelProfileRule,
+    project::Project,
+    project_executor_weight::{ProjectExecutorWeight, SetProjectExecutorWeight},
+    task::Task,
+};
+use deployment::Deployment;
+use executors::{
+    executors::StandardCodingAgentExecutor,
+    profile::{ExecutorConfigs, ExecutorProfileId, ProfileError},
+};
+use services::services::executor_recommendation::{ExecutorRecommendation, recommend};
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+const MAX_WEIGHT: i64 = 100;
+
+pub async fn get_executor_weight(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<ProjectExecutorWeight>>, ApiError> {
+    let weight =
+        ProjectExecutorWeight::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(weight)))
+}
+
+/// Set the project's share of the auto-start slots. Attempts already waiting
+/// pick up the new weight the next time the project queues one.
+pub async fn set_executor_weight(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetProjectExecutorWeight>,
+) -> Result<ResponseJson<ApiResponse<ProjectExecutorWeight>>, ApiError> {
+    if !(1..=MAX_WEIGHT).contains(&payload.weight) {
+        return Err(ApiError::BadRequest(format!(
+            "Weight must be between 1 and {MAX_WEIGHT}"
+        )));
+    }
+    let weight =
+        ProjectExecutorWeight::set(&deployment.db().pool, project.id, payload.weight).await?;
+    Ok(ResponseJson(ApiResponse::success(weight)))
+}
+
+/// The executor profile that has worked best on the project's past attempts,
+/// with the per-profile numbers behind the pick
+pub async fn get_executor_recommendation(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<ExecutorRecommendation>>, ApiError> {
+    let outcomes =
+        ExecutionProcess::find_attempt_outcomes_by_project(&deployment.db().pool, project.id)
+            .await?;
+    Ok(ResponseJson(ApiResponse::success(recommend(&outcomes))))
+}
+
+/// Profile to start a task with when none was picked: the one a label rule of
+/// its project names, else the one the project's history recommends while that
+/// agent is available, else the globally recommended one
+pub(crate) async fn auto_start_profile(
+    deployment: &DeploymentImpl,
+    task: &Task,
+) -> Result<ExecutorProfileId, ProfileError> {
+    let pool = &deployment.db().pool;
+    let executor_configs = ExecutorConfigs::get_cached();
+    match LabelProfileRule::profile_for_task(pool, task.project_id, task.id).await {
+        Ok(Some(profile)) if executor_configs.get_coding_agent(&profile).is_some() => {
+            return Ok(profile);
+        }
+        Ok(_) => {}
+        Err(e) => {
+            tracing::warn!("Failed to load label profile rules for {}: {}", task.id, e);
+        }
+    }
+
+    let project_id = task.project_id;
+    match ExecutionProcess::find_attempt_outcomes_by_project(pool, project_id).await {
+        Ok(outcomes) => {
+            if let Some(profile) = recommend(&outcomes).recommended
+                && executor_configs
+                    .get_coding_agent(&profile)
+                    .is_some_and(|agent| agent.get_availability_info().is_available())
+            {
+                return Ok(profile);
+            }
+        }
+        Err(e) => {
+            tracing::warn!("Failed to load attempt history for {}: {}", project_id, e);
+        }
+    }
+    executor_configs.get_recommended_executor_profile().await
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/executor-weight",
+            get(get_executor_weight).put(set_executor_weight),
+        )
+        .route("/executor-recommendation", get(get_executor_recommendation))
+}
@@ -0,0 +1,156 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    response::{Json as ResponseJson, Response},
+    routing::{get, post},
+};
+use db::models::project::Project;
+use serde::{Deserialize, Serialize};
+use services::services::{
+    gitea_issues::{GiteaIssue, GiteaIssuesService, ListGiteaIssuesParams},
+    issue_provider::IssueProviderKind,
+};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::issue_sync::{ImportedIssue, SyncIssuesQuery, import_issue_by_id, sync_issues},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListGiteaIssuesQuery {
+    pub state: Option<String>,
+    pub labels: Option<String>,
+    pub page: Option<i32>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct GiteaIssuesResponse {
+    pub issues: Vec<GiteaIssue>,
+    pub has_gitea_config: bool,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ImportGiteaIssueRequest {
+    pub issue_number: i64,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct GiteaConfigStatus {
+    pub has_base_url: bool,
+    pub has_repo: bool,
+    pub has_token: bool,
+    pub base_url: Option<String>,
+    pub repo: Option<String>,
+    pub sync_enabled: bool,
+    pub sync_labels: Option<String>,
+}
+
+/// Instance URL, token, owner and repo name, when the project has all of them set
+struct GiteaConnection {
+    base_url: String,
+    token: String,
+    owner: String,
+    repo: String,
+}
+
+impl GiteaConnection {
+    fn from_project(project: &Project) -> Result<Option<Self>, ApiError> {
+        let (Some(base_url), Some(repo), Some(token)) = (
+            &project.gitea_base_url,
+            &project.gitea_repo,
+            &project.gitea_token,
+        ) else {
+            return Ok(None);
+        };
+        let (owner, repo) = GiteaIssuesService::parse_repo(repo)
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        Ok(Some(Self {
+            base_url: base_url.clone(),
+            token: token.clone(),
+            owner,
+            repo,
+        }))
+    }
+}
+
+pub async fn get_gitea_config_status(
+    Extension(project): Extension<Project>,
+) -> Result<ResponseJson<ApiResponse<GiteaConfigStatus>>, ApiError> {
+    let status = GiteaConfigStatus {
+        has_base_url: project.gitea_base_url.is_some(),
+        has_repo: project.gitea_repo.is_some(),
+        has_token: project.gitea_token.is_some(),
+        base_url: project.gitea_base_url.clone(),
+        repo: project.gitea_repo.clone(),
+        sync_enabled: project.gitea_sync_enabled,
+        sync_labels: project.gitea_sync_labels.clone(),
+    };
+    Ok(ResponseJson(ApiResponse::success(status)))
+}
+
+pub async fn list_gitea_issues(
+    Extension(project): Extension<Project>,
+    Query(query): Query<ListGiteaIssuesQuery>,
+) -> Result<ResponseJson<ApiResponse<GiteaIssuesResponse>>, ApiError> {
+    let Some(connection) = GiteaConnection::from_project(&project)? else {
+        return Ok(ResponseJson(ApiResponse::success(GiteaIssuesResponse {
+            issues: vec![],
+            has_gitea_config: false,
+        })));
+    };
+
+    let params = ListGiteaIssuesParams {
+        state: query.state.or(Some("open".to_string())),
+        labels: query.labels.or(project.gitea_sync_labels.clone()),
+        limit: query.limit.or(Some(30)),
+        page: query.page.or(Some(1)),
+    };
+
+    let issues = GiteaIssuesService::new()
+        .list_issues(
+            &connection.base_url,
+            &connection.token,
+            &connection.owner,
+            &connection.repo,
+            &params,
+        )
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    Ok(ResponseJson(ApiResponse::success(GiteaIssuesResponse {
+        issues,
+        has_gitea_config: true,
+    })))
+}
+
+pub async fn import_gitea_issue(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ImportGiteaIssueRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    let issue_id = payload.issue_number.to_string();
+    import_issue_by_id(&deployment, &project, IssueProviderKind::Gitea, &issue_id).await
+}
+
+/// Import open issues that have no task yet. With `dry_run` set, returns an
+/// [`IssueSyncPreview`](super::issue_sync::IssueSyncPreview) instead and
+/// writes nothing.
+pub async fn sync_gitea_issues(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<SyncIssuesQuery>,
+) -> Result<Response, ApiError> {
+    sync_issues(project, deployment, query, IssueProviderKind::Gitea).await
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/gitea/config", get(get_gitea_config_status))
+        .route("/gitea/issues", get(list_gitea_issues))
+        .route("/gitea/issues/import", post(import_gitea_issue))
+        .route("/gitea/issues/sync", post(sync_gitea_issues))
+}
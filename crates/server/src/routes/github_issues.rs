@@ -1,21 +1,43 @@
+use std::collections::HashMap;
+
 use axum::{
     Extension, Json, Router,
     extract::{Path, Query, State},
-    response::Json as ResponseJson,
+    response::{IntoResponse, Json as ResponseJson, Response},
     routing::{get, post},
 };
 use db::models::{
+    issue_link::IssueLink,
     project::Project,
-    task::{CreateTask, Task, TaskStatus, TaskWithAttemptStatus},
+    task::{CreateTask, Task, TaskStatus},
+    task_group::{TaskGroup, UpsertTaskGroup},
+    task_label::LabelSyncDirection,
 };
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
-use services::services::github_issues::{GitHubIssue, GitHubIssuesService, ListIssuesParams};
+use services::services::{
+    events::{ImportProgress, import_patch},
+    github_issues::{
+        GitHubCiStatus, GitHubIssue, GitHubIssueFilter, GitHubIssuesService, GitHubPullRequest,
+        ListIssuesParams, extract_github_pr_number_from_description, pull_request_task_description,
+    },
+    issue_provider::{IssueProvider, IssueProviderKind},
+};
 use ts_rs::TS;
 use utils::response::ApiResponse;
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError};
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::{
+        issue_sync::{
+            ImportedIssue, IssueSyncOutcome, IssueSyncPreview, SyncCounts, SyncIssuesQuery,
+            import_issue, import_issue_by_id, issue_provider, record_sync_run, sync_open_issues,
+        },
+        notifications,
+    },
+};
 
 #[derive(Debug, Deserialize)]
 pub struct ListIssuesQuery {
@@ -37,10 +59,35 @@ pub struct ImportIssueRequest {
     pub auto_start: Option<bool>,
 }
 
+/// Pages of open issues scanned when a bulk import names no issue numbers
+const BULK_IMPORT_MAX_PAGES: i32 = 10;
+
+#[derive(Debug, Deserialize, TS)]
+pub struct BulkImportIssuesRequest {
+    /// Issues to import. Empty imports every open issue that matches the filter.
+    #[serde(default)]
+    pub issue_numbers: Vec<i64>,
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub filter: GitHubIssueFilter,
+}
+
 #[derive(Debug, Serialize, TS)]
-pub struct ImportIssueResponse {
+pub struct GitHubPullRequestsResponse {
+    pub pull_requests: Vec<GitHubPullRequest>,
+    pub has_github_config: bool,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ImportPullRequestRequest {
+    pub pull_number: i64,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct ImportPullRequestResponse {
     pub task: Task,
-    pub issue: GitHubIssue,
+    pub pull_request: GitHubPullRequest,
+    pub ci_status: GitHubCiStatus,
 }
 
 #[derive(Debug, Serialize, TS)]
@@ -50,6 +97,8 @@ pub struct GitHubConfigStatus {
     pub repo_url: Option<String>,
     pub sync_enabled: bool,
     pub sync_labels: Option<String>,
+    pub sync_pull_requests: bool,
+    pub label_sync: LabelSyncDirection,
 }
 
 pub async fn get_github_config_status(
@@ -61,6 +110,8 @@ pub async fn get_github_config_status(
         repo_url: project.github_repo_url.clone(),
         sync_enabled: project.github_sync_enabled,
         sync_labels: project.github_sync_labels.clone(),
+        sync_pull_requests: project.github_sync_pull_requests,
+        label_sync: project.github_label_sync,
     };
     Ok(ResponseJson(ApiResponse::success(status)))
 }
@@ -107,73 +158,285 @@ pub async fn import_github_issue(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<ImportIssueRequest>,
-) -> Result<ResponseJson<ApiResponse<ImportIssueResponse>>, ApiError> {
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    let issue_id = payload.issue_number.to_string();
+    import_issue_by_id(&deployment, &project, IssueProviderKind::GitHub, &issue_id).await
+}
+
+/// Recreate the task for an issue whose task was deleted locally, e.g. by
+/// accident or by restoring an older database. The task is linked to the issue
+/// like an import, so sync keeps treating the issue as linked, and a closed
+/// issue comes back as Done.
+pub async fn restore_github_issue(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ImportIssueRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    let provider = issue_provider(&deployment, &project, IssueProviderKind::GitHub).await?;
+
+    let issue_id = payload.issue_number.to_string();
+    let links =
+        IssueLink::find_by_project_id(&deployment.db().pool, project.id, provider.kind().name())
+            .await?;
+    if links.iter().any(|link| link.external_id == issue_id) {
+        return Err(ApiError::Conflict(format!(
+            "Issue #{} is already linked to a task",
+            payload.issue_number
+        )));
+    }
+
+    let issue = provider.get_issue(&issue_id).await?;
+    if issue.url.contains("/pull/") {
+        return Err(ApiError::BadRequest(format!(
+            "#{} is a pull request, not an issue",
+            issue.key
+        )));
+    }
+
+    let status = if issue.closed {
+        TaskStatus::Done
+    } else {
+        TaskStatus::Todo
+    };
+    let restored = import_issue(
+        &deployment,
+        project.id,
+        provider.as_ref(),
+        issue,
+        status,
+        false,
+    )
+    .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "github_issue_restored",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "issue_number": payload.issue_number,
+                "task_id": restored.task.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(restored)))
+}
+
+/// Import a hand-picked set of issues in the background. Progress is published
+/// on the events stream at `/imports/{import_id}`; the initial progress is
+/// returned right away.
+pub async fn bulk_import_github_issues(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<BulkImportIssuesRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportProgress>>, ApiError> {
     let (repo_url, token) = match (&project.github_repo_url, &project.github_token) {
         (Some(url), Some(tok)) => (url.clone(), tok.clone()),
         _ => {
-            return Err(ApiError::BadRequest(
-                "GitHub configuration not set for this project".to_string(),
-            ));
+            return Err(ApiError::integration_not_configured("GitHub"));
         }
     };
 
     let (owner, repo) = GitHubIssuesService::parse_repo_url(&repo_url)
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let provider = issue_provider(&deployment, &project, IssueProviderKind::GitHub).await?;
+
+    if let (Some(after), Some(before)) =
+        (payload.filter.created_after, payload.filter.created_before)
+        && after >= before
+    {
+        return Err(ApiError::BadRequest(
+            "created_after must be before created_before".to_string(),
+        ));
+    }
 
-    let service = GitHubIssuesService::new();
-    let issue = service
-        .get_issue(&token, &owner, &repo, payload.issue_number)
-        .await
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let progress = ImportProgress {
+        import_id: Uuid::new_v4(),
+        project_id: project.id,
+        total: 0,
+        imported: 0,
+        skipped: 0,
+        failed: 0,
+        errors: Vec::new(),
+        done: false,
+    };
+    deployment
+        .events()
+        .msg_store()
+        .push_patch(import_patch::add(&progress));
+
+    tokio::spawn(run_bulk_import(
+        deployment.clone(),
+        provider,
+        token,
+        owner,
+        repo,
+        payload,
+        progress.clone(),
+    ));
+
+    Ok(ResponseJson(ApiResponse::success(progress)))
+}
 
-    let description = format!(
-        "Imported from GitHub Issue #{}\n{}\n\n{}",
-        issue.number,
-        issue.html_url,
-        issue.body.clone().unwrap_or_default()
-    );
+/// Issues named in the request, or every open issue when none are named.
+/// Pull requests are left out; they have their own import.
+async fn bulk_import_candidates(
+    service: &GitHubIssuesService,
+    token: &str,
+    owner: &str,
+    repo: &str,
+    payload: &BulkImportIssuesRequest,
+    progress: &mut ImportProgress,
+) -> Result<Vec<GitHubIssue>, String> {
+    if payload.issue_numbers.is_empty() {
+        let mut issues = Vec::new();
+        for page in 1..=BULK_IMPORT_MAX_PAGES {
+            let params = ListIssuesParams {
+                state: Some("open".to_string()),
+                labels: (!payload.filter.labels.is_empty())
+                    .then(|| payload.filter.labels.join(",")),
+                sort: Some("created".to_string()),
+                direction: Some("desc".to_string()),
+                per_page: Some(100),
+                page: Some(page),
+            };
+            let batch = service
+                .list_issues(token, owner, repo, &params)
+                .await
+                .map_err(|e| e.to_string())?;
+            if batch.is_empty() {
+                break;
+            }
+            issues.extend(batch);
+        }
+        return Ok(issues);
+    }
 
-    let create_task = CreateTask {
-        project_id: project.id,
-        title: issue.title.clone(),
-        description: Some(description),
-        status: Some(TaskStatus::Todo),
-        execution_mode: None,
-        parent_workspace_id: None,
-        image_ids: None,
-        shared_task_id: None,
+    let mut issues = Vec::new();
+    for &number in &payload.issue_numbers {
+        match service.get_issue(token, owner, repo, number).await {
+            Ok(issue) if issue.html_url.contains("/pull/") => progress.skipped += 1,
+            Ok(issue) => issues.push(issue),
+            Err(e) => {
+                progress.failed += 1;
+                progress.errors.push(format!("#{}: {}", number, e));
+            }
+        }
+    }
+    Ok(issues)
+}
+
+async fn run_bulk_import(
+    deployment: DeploymentImpl,
+    provider: Box<dyn IssueProvider>,
+    token: String,
+    owner: String,
+    repo: String,
+    payload: BulkImportIssuesRequest,
+    mut progress: ImportProgress,
+) {
+    let msg_store = deployment.events().msg_store().clone();
+    let service = GitHubIssuesService::new();
+    let project_id = progress.project_id;
+
+    let candidates =
+        bulk_import_candidates(&service, &token, &owner, &repo, &payload, &mut progress).await;
+    let links = IssueLink::find_by_project_id(
+        &deployment.db().pool,
+        project_id,
+        IssueProviderKind::GitHub.name(),
+    )
+    .await;
+    let (candidates, links) = match (candidates, links) {
+        (Ok(candidates), Ok(links)) => (candidates, links),
+        (Err(e), _) => {
+            progress.errors.push(e);
+            progress.done = true;
+            msg_store.push_patch(import_patch::replace(&progress));
+            return;
+        }
+        (_, Err(e)) => {
+            progress.errors.push(e.to_string());
+            progress.done = true;
+            msg_store.push_patch(import_patch::replace(&progress));
+            return;
+        }
     };
 
-    let task_id = Uuid::new_v4();
-    let task = Task::create(&deployment.db().pool, &create_task, task_id).await?;
+    let existing_issue_numbers: Vec<i64> = links
+        .iter()
+        .filter_map(|link| link.external_id.parse().ok())
+        .collect();
+    let (selected, rest): (Vec<_>, Vec<_>) = candidates.into_iter().partition(|issue| {
+        payload.filter.matches(issue) && !existing_issue_numbers.contains(&issue.number)
+    });
+    progress.skipped += rest.len();
+    progress.total = selected.len();
+    msg_store.push_patch(import_patch::replace(&progress));
+
+    for issue in selected {
+        let number = issue.number;
+        match import_issue(
+            &deployment,
+            project_id,
+            provider.as_ref(),
+            issue.into(),
+            TaskStatus::Todo,
+            false,
+        )
+        .await
+        {
+            Ok(_) => progress.imported += 1,
+            Err(e) => {
+                progress.failed += 1;
+                progress.errors.push(format!("#{}: {}", number, e));
+            }
+        }
+        msg_store.push_patch(import_patch::replace(&progress));
+    }
+
+    progress.done = true;
+    msg_store.push_patch(import_patch::replace(&progress));
 
     deployment
         .track_if_analytics_allowed(
-            "github_issue_imported",
+            "github_issues_bulk_imported",
             serde_json::json!({
-                "project_id": project.id.to_string(),
-                "issue_number": issue.number,
-                "task_id": task.id.to_string(),
+                "project_id": project_id.to_string(),
+                "imported": progress.imported,
+                "failed": progress.failed,
             }),
         )
         .await;
-
-    Ok(ResponseJson(ApiResponse::success(ImportIssueResponse {
-        task,
-        issue,
-    })))
 }
 
+/// Import open issues that have no task yet. With `dry_run` set, returns an
+/// [`IssueSyncPreview`] instead and writes nothing.
 pub async fn sync_github_issues(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<Vec<ImportIssueResponse>>>, ApiError> {
+    Query(query): Query<SyncIssuesQuery>,
+) -> Result<Response, ApiError> {
+    let (project_id, dry_run) = (project.id, query.dry_run);
+    record_sync_run(
+        &deployment,
+        project_id,
+        "GitHub",
+        dry_run,
+        run_github_sync(project, deployment.clone(), query),
+    )
+    .await
+}
+
+pub(crate) async fn run_github_sync(
+    project: Project,
+    deployment: DeploymentImpl,
+    query: SyncIssuesQuery,
+) -> Result<(Response, SyncCounts), ApiError> {
     let (repo_url, token) = match (&project.github_repo_url, &project.github_token) {
         (Some(url), Some(tok)) => (url.clone(), tok.clone()),
         _ => {
-            return Err(ApiError::BadRequest(
-                "GitHub configuration not set for this project".to_string(),
-            ));
+            return Err(ApiError::integration_not_configured("GitHub"));
         }
     };
 
@@ -181,6 +444,138 @@ pub async fn sync_github_issues(
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
     let service = GitHubIssuesService::new();
+    let provider = issue_provider(&deployment, &project, IssueProviderKind::GitHub).await?;
+
+    let imported =
+        match sync_open_issues(&deployment, &project, provider.as_ref(), query.dry_run).await? {
+            IssueSyncOutcome::Preview(mut preview) => {
+                if project.github_sync_pull_requests {
+                    for (pull_request, linked) in
+                        open_pull_requests(&deployment, &project, &service, &token, &owner, &repo)
+                            .await?
+                    {
+                        preview.push(
+                            linked,
+                            format!("PR #{}", pull_request.number),
+                            format!("Review: {}", pull_request.title),
+                            pull_request.html_url,
+                        );
+                    }
+                }
+                preview
+                    .flag_duplicates(&deployment.db().pool, project.id)
+                    .await?;
+                return Ok((
+                    ResponseJson(ApiResponse::<IssueSyncPreview>::success(preview)).into_response(),
+                    SyncCounts::default(),
+                ));
+            }
+            IssueSyncOutcome::Imported(imported) => imported,
+        };
+
+    let imported_pull_requests = if project.github_sync_pull_requests {
+        match import_open_pull_requests(&deployment, &project, &service, &token, &owner, &repo)
+            .await
+        {
+            Ok(imported) => imported.len(),
+            Err(e) => {
+                let error = e.to_string();
+                notifications::record_sync_error(&deployment, &project, "GitHub", &error).await;
+                return Err(e);
+            }
+        }
+    } else {
+        0
+    };
+
+    let regrouped = match sync_github_milestones(
+        &deployment,
+        &project,
+        &service,
+        &token,
+        &owner,
+        &repo,
+    )
+    .await
+    {
+        Ok(regrouped) => regrouped,
+        Err(e) => {
+            let error = e.to_string();
+            notifications::record_sync_error(&deployment, &project, "GitHub", &error).await;
+            return Err(e);
+        }
+    };
+
+    Project::update_github_last_sync(&deployment.db().pool, project.id).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "github_issues_synced",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "imported_count": imported.len(),
+                "imported_pull_requests": imported_pull_requests,
+            }),
+        )
+        .await;
+
+    let counts = SyncCounts {
+        imported: imported.len() + imported_pull_requests,
+        updated: regrouped,
+    };
+    Ok((
+        ResponseJson(ApiResponse::<Vec<_>>::success(imported)).into_response(),
+        counts,
+    ))
+}
+
+/// Mirror the repository's milestones as task groups and move each task linked
+/// to an open issue into its issue's milestone. Tasks of closed issues keep
+/// their group. Returns how many tasks changed group.
+async fn sync_github_milestones(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    service: &GitHubIssuesService,
+    token: &str,
+    owner: &str,
+    repo: &str,
+) -> Result<usize, ApiError> {
+    let pool = &deployment.db().pool;
+    let provider = IssueProviderKind::GitHub.name();
+    let milestones = service
+        .list_milestones(token, owner, repo)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let mut groups: HashMap<i64, Uuid> = HashMap::new();
+    for milestone in &milestones {
+        let external_id = milestone.number.to_string();
+        let group = TaskGroup::upsert(
+            pool,
+            project.id,
+            &UpsertTaskGroup {
+                provider,
+                external_id: &external_id,
+                title: &milestone.title,
+                description: milestone.description.as_deref(),
+                url: &milestone.html_url,
+                closed: milestone.state == "closed",
+                due_at: milestone.due_on,
+                open_issues: milestone.open_issues,
+                closed_issues: milestone.closed_issues,
+            },
+        )
+        .await?;
+        groups.insert(milestone.number, group.id);
+    }
+    // Milestones deleted on GitHub
+    for group in TaskGroup::find_by_project_id(pool, project.id).await? {
+        if group.provider == provider && !groups.values().any(|id| *id == group.id) {
+            TaskGroup::delete(pool, group.id).await?;
+        }
+    }
+
+    // The page the issue sync just read, so its ETag saves the quota
     let params = ListIssuesParams {
         state: Some("open".to_string()),
         labels: project.github_sync_labels.clone(),
@@ -189,68 +584,214 @@ pub async fn sync_github_issues(
         per_page: Some(100),
         page: Some(1),
     };
-
     let issues = service
-        .list_issues(&token, &owner, &repo, &params)
+        .list_issues(token, owner, repo, &params)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let links: HashMap<String, Uuid> = IssueLink::find_by_project_id(pool, project.id, provider)
+        .await?
+        .into_iter()
+        .map(|link| (link.external_id, link.task_id))
+        .collect();
+    let current: HashMap<Uuid, Uuid> = TaskGroup::find_members(pool, project.id)
+        .await?
+        .into_iter()
+        .map(|member| (member.task_id, member.task_group_id))
+        .collect();
+
+    let mut regrouped = 0;
+    for issue in issues {
+        let Some(&task_id) = links.get(&issue.number.to_string()) else {
+            continue;
+        };
+        let group_id = issue
+            .milestone
+            .and_then(|milestone| groups.get(&milestone.number).copied());
+        if current.get(&task_id).copied() != group_id {
+            TaskGroup::set_task_group(pool, task_id, group_id).await?;
+            regrouped += 1;
+        }
+    }
+    Ok(regrouped)
+}
+
+/// Create an InReview task for a pull request, with its CI status and reviewers in the description
+async fn create_pull_request_task(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    service: &GitHubIssuesService,
+    token: &str,
+    owner: &str,
+    repo: &str,
+    pull_request: GitHubPullRequest,
+) -> Result<ImportPullRequestResponse, ApiError> {
+    let ci_status = service
+        .get_ci_status(token, owner, repo, &pull_request.head.sha)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let create_task = CreateTask {
+        project_id: project.id,
+        title: format!("Review: {}", pull_request.title),
+        description: Some(pull_request_task_description(&pull_request, ci_status)),
+        status: Some(TaskStatus::InReview),
+        execution_mode: None,
+        parent_workspace_id: None,
+        image_ids: None,
+        shared_task_id: None,
+    };
+
+    let task_id = Uuid::new_v4();
+    let create_task = &create_task;
+    let task = deployment
+        .db()
+        .write(|pool| async move { Task::create(&pool, create_task, task_id).await })
+        .await?;
+
+    Ok(ImportPullRequestResponse {
+        task,
+        pull_request,
+        ci_status,
+    })
+}
+
+/// Import every open pull request that doesn't have a task yet
+/// Open pull requests, each paired with whether a task already tracks it
+async fn open_pull_requests(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    service: &GitHubIssuesService,
+    token: &str,
+    owner: &str,
+    repo: &str,
+) -> Result<Vec<(GitHubPullRequest, bool)>, ApiError> {
+    let pull_requests = service
+        .list_pull_requests(token, owner, repo, 100)
         .await
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
     let existing_tasks =
         Task::find_by_project_id_with_attempt_status(&deployment.db().pool, project.id).await?;
-    let existing_issue_numbers: Vec<i64> = existing_tasks
+    let existing_pr_numbers: Vec<i64> = existing_tasks
         .iter()
         .filter_map(|t| {
-            t.description.as_ref().and_then(|d| {
-                if d.starts_with("Imported from GitHub Issue #") {
-                    d.lines().next().and_then(|line| {
-                        line.strip_prefix("Imported from GitHub Issue #")
-                            .and_then(|s| s.parse::<i64>().ok())
-                    })
-                } else {
-                    None
-                }
-            })
+            t.description
+                .as_deref()
+                .and_then(extract_github_pr_number_from_description)
         })
         .collect();
 
-    let mut imported = Vec::new();
+    Ok(pull_requests
+        .into_iter()
+        .map(|pull_request| {
+            let linked = existing_pr_numbers.contains(&pull_request.number);
+            (pull_request, linked)
+        })
+        .collect())
+}
 
-    for issue in issues {
-        if existing_issue_numbers.contains(&issue.number) {
+async fn import_open_pull_requests(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    service: &GitHubIssuesService,
+    token: &str,
+    owner: &str,
+    repo: &str,
+) -> Result<Vec<ImportPullRequestResponse>, ApiError> {
+    let mut imported = Vec::new();
+    for (pull_request, linked) in
+        open_pull_requests(deployment, project, service, token, owner, repo).await?
+    {
+        if linked {
             continue;
         }
-
-        let description = format!(
-            "Imported from GitHub Issue #{}\n{}\n\n{}",
-            issue.number,
-            issue.html_url,
-            issue.body.clone().unwrap_or_default()
+        imported.push(
+            create_pull_request_task(
+                deployment,
+                project,
+                service,
+                token,
+                owner,
+                repo,
+                pull_request,
+            )
+            .await?,
         );
+    }
+    Ok(imported)
+}
 
-        let create_task = CreateTask {
-            project_id: project.id,
-            title: issue.title.clone(),
-            description: Some(description),
-            status: Some(TaskStatus::Todo),
-            execution_mode: None,
-            parent_workspace_id: None,
-            image_ids: None,
-            shared_task_id: None,
-        };
+pub async fn list_github_pull_requests(
+    Extension(project): Extension<Project>,
+) -> Result<ResponseJson<ApiResponse<GitHubPullRequestsResponse>>, ApiError> {
+    let (repo_url, token) = match (&project.github_repo_url, &project.github_token) {
+        (Some(url), Some(tok)) => (url.clone(), tok.clone()),
+        _ => {
+            return Ok(ResponseJson(ApiResponse::success(
+                GitHubPullRequestsResponse {
+                    pull_requests: vec![],
+                    has_github_config: false,
+                },
+            )));
+        }
+    };
 
-        let task_id = Uuid::new_v4();
-        let task = Task::create(&deployment.db().pool, &create_task, task_id).await?;
-        imported.push(ImportIssueResponse { task, issue });
-    }
+    let (owner, repo) = GitHubIssuesService::parse_repo_url(&repo_url)
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
-    Project::update_github_last_sync(&deployment.db().pool, project.id).await?;
+    let pull_requests = GitHubIssuesService::new()
+        .list_pull_requests(&token, &owner, &repo, 30)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        GitHubPullRequestsResponse {
+            pull_requests,
+            has_github_config: true,
+        },
+    )))
+}
+
+pub async fn import_github_pull_request(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ImportPullRequestRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportPullRequestResponse>>, ApiError> {
+    let (repo_url, token) = match (&project.github_repo_url, &project.github_token) {
+        (Some(url), Some(tok)) => (url.clone(), tok.clone()),
+        _ => {
+            return Err(ApiError::integration_not_configured("GitHub"));
+        }
+    };
+
+    let (owner, repo) = GitHubIssuesService::parse_repo_url(&repo_url)
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let service = GitHubIssuesService::new();
+    let pull_request = service
+        .get_pull_request(&token, &owner, &repo, payload.pull_number)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let imported = create_pull_request_task(
+        &deployment,
+        &project,
+        &service,
+        &token,
+        &owner,
+        &repo,
+        pull_request,
+    )
+    .await?;
 
     deployment
         .track_if_analytics_allowed(
-            "github_issues_synced",
+            "github_pull_request_imported",
             serde_json::json!({
                 "project_id": project.id.to_string(),
-                "imported_count": imported.len(),
+                "pull_number": imported.pull_request.number,
+                "task_id": imported.task.id.to_string(),
             }),
         )
         .await;
@@ -263,5 +804,12 @@ pub fn router() -> Router<DeploymentImpl> {
         .route("/github/config", get(get_github_config_status))
         .route("/github/issues", get(list_github_issues))
         .route("/github/issues/import", post(import_github_issue))
+        .route("/github/issues/restore", post(restore_github_issue))
+        .route(
+            "/github/issues/bulk-import",
+            post(bulk_import_github_issues),
+        )
         .route("/github/issues/sync", post(sync_github_issues))
+        .route("/github/pulls", get(list_github_pull_requests))
+        .route("/github/pulls/import", post(import_github_pull_request))
 }
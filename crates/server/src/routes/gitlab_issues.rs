@@ -1,21 +1,29 @@
 use axum::{
     Extension, Json, Router,
     extract::{Query, State},
-    response::Json as ResponseJson,
+    response::{Json as ResponseJson, Response},
     routing::{get, post},
 };
 use db::models::{
-    project::Project,
-    task::{CreateTask, Task, TaskStatus},
+    project::{Project, ProjectError},
+    task::Task,
 };
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
-use services::services::gitlab_issues::{GitLabIssue, GitLabIssuesService, ListGitLabIssuesParams};
+use services::services::{
+    gitlab_issues::{GitLabIssue, GitLabIssuesService, ListGitLabIssuesParams},
+    issue_provider::IssueProviderKind,
+};
 use ts_rs::TS;
 use utils::response::ApiResponse;
-use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError};
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::issue_sync::{
+        ImportedIssue, SyncIssuesQuery, import_issue_by_id, linked_issue, sync_issues,
+    },
+};
 
 #[derive(Debug, Deserialize)]
 pub struct ListGitLabIssuesQuery {
@@ -37,16 +45,11 @@ pub struct ImportGitLabIssueRequest {
     pub auto_start: Option<bool>,
 }
 
-#[derive(Debug, Serialize, TS)]
-pub struct ImportGitLabIssueResponse {
-    pub task: Task,
-    pub issue: GitLabIssue,
-}
-
 #[derive(Debug, Serialize, TS)]
 pub struct GitLabConfigStatus {
     pub has_project_url: bool,
     pub has_token: bool,
+    pub base_url: Option<String>,
     pub project_url: Option<String>,
     pub sync_enabled: bool,
     pub sync_labels: Option<String>,
@@ -58,6 +61,7 @@ pub async fn get_gitlab_config_status(
     let status = GitLabConfigStatus {
         has_project_url: project.gitlab_project_url.is_some(),
         has_token: project.gitlab_token.is_some(),
+        base_url: project.gitlab_base_url.clone(),
         project_url: project.gitlab_project_url.clone(),
         sync_enabled: project.gitlab_sync_enabled,
         sync_labels: project.gitlab_sync_labels.clone(),
@@ -82,7 +86,8 @@ pub async fn list_gitlab_issues(
     let project_path = GitLabIssuesService::parse_project_url(&project_url)
         .map_err(|e| ApiError::BadRequest(e.to_string()))?;
 
-    let service = GitLabIssuesService::new();
+    let service = GitLabIssuesService::for_instance(project.gitlab_base_url.as_deref())
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
     let params = ListGitLabIssuesParams {
         state: query.state.or(Some("opened".to_string())),
         labels: query.labels.or(project.gitlab_sync_labels.clone()),
@@ -107,154 +112,54 @@ pub async fn import_gitlab_issue(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<ImportGitLabIssueRequest>,
-) -> Result<ResponseJson<ApiResponse<ImportGitLabIssueResponse>>, ApiError> {
-    let (project_url, token) = match (&project.gitlab_project_url, &project.gitlab_token) {
-        (Some(url), Some(tok)) => (url.clone(), tok.clone()),
-        _ => {
-            return Err(ApiError::BadRequest(
-                "GitLab configuration not set for this project".to_string(),
-            ));
-        }
-    };
-
-    let project_path = GitLabIssuesService::parse_project_url(&project_url)
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-
-    let service = GitLabIssuesService::new();
-    let issue = service
-        .get_issue(&token, &project_path, payload.issue_iid)
-        .await
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-
-    let description = format!(
-        "Imported from GitLab Issue #{}\n{}\n\n{}",
-        issue.iid,
-        issue.web_url,
-        issue.description.clone().unwrap_or_default()
-    );
-
-    let create_task = CreateTask {
-        project_id: project.id,
-        title: issue.title.clone(),
-        description: Some(description),
-        status: Some(TaskStatus::Todo),
-        execution_mode: None,
-        parent_workspace_id: None,
-        image_ids: None,
-        shared_task_id: None,
-    };
-
-    let task_id = Uuid::new_v4();
-    let task = Task::create(&deployment.db().pool, &create_task, task_id).await?;
-
-    deployment
-        .track_if_analytics_allowed(
-            "gitlab_issue_imported",
-            serde_json::json!({
-                "project_id": project.id.to_string(),
-                "issue_iid": issue.iid,
-                "task_id": task.id.to_string(),
-            }),
-        )
-        .await;
-
-    Ok(ResponseJson(ApiResponse::success(
-        ImportGitLabIssueResponse { task, issue },
-    )))
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    let issue_id = payload.issue_iid.to_string();
+    import_issue_by_id(&deployment, &project, IssueProviderKind::GitLab, &issue_id).await
 }
 
+/// Import open issues that have no task yet. With `dry_run` set, returns an
+/// [`IssueSyncPreview`](super::issue_sync::IssueSyncPreview) instead and
+/// writes nothing.
 pub async fn sync_gitlab_issues(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<Vec<ImportGitLabIssueResponse>>>, ApiError> {
-    let (project_url, token) = match (&project.gitlab_project_url, &project.gitlab_token) {
-        (Some(url), Some(tok)) => (url.clone(), tok.clone()),
-        _ => {
-            return Err(ApiError::BadRequest(
-                "GitLab configuration not set for this project".to_string(),
-            ));
-        }
-    };
-
-    let project_path = GitLabIssuesService::parse_project_url(&project_url)
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    Query(query): Query<SyncIssuesQuery>,
+) -> Result<Response, ApiError> {
+    sync_issues(project, deployment, query, IssueProviderKind::GitLab).await
+}
 
-    let service = GitLabIssuesService::new();
-    let params = ListGitLabIssuesParams {
-        state: Some("opened".to_string()),
-        labels: project.gitlab_sync_labels.clone(),
-        sort: Some("desc".to_string()),
-        order_by: Some("updated_at".to_string()),
-        per_page: Some(100),
-        page: Some(1),
+/// Mirror a task's status onto the GitLab issue it was imported from: closed
+/// on Done, labelled `in-review` while in review and reopened when moved back.
+/// Does nothing for tasks not imported from GitLab.
+pub(crate) async fn sync_gitlab_issue_status(
+    deployment: &DeploymentImpl,
+    task: &Task,
+) -> Result<(), ApiError> {
+    let project = Project::find_by_id(&deployment.db().pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    let Some((provider, issue_iid)) =
+        linked_issue(deployment, &project, task, IssueProviderKind::GitLab).await?
+    else {
+        return Ok(());
     };
 
-    let issues = service
-        .list_issues(&token, &project_path, &params)
-        .await
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-
-    let existing_tasks =
-        Task::find_by_project_id_with_attempt_status(&deployment.db().pool, project.id).await?;
-    let existing_issue_iids: Vec<i64> = existing_tasks
-        .iter()
-        .filter_map(|t| {
-            t.description.as_ref().and_then(|d| {
-                if d.starts_with("Imported from GitLab Issue #") {
-                    d.lines().next().and_then(|line| {
-                        line.strip_prefix("Imported from GitLab Issue #")
-                            .and_then(|s| s.parse::<i64>().ok())
-                    })
-                } else {
-                    None
-                }
-            })
-        })
-        .collect();
-
-    let mut imported = Vec::new();
-
-    for issue in issues {
-        if existing_issue_iids.contains(&issue.iid) {
-            continue;
-        }
-
-        let description = format!(
-            "Imported from GitLab Issue #{}\n{}\n\n{}",
-            issue.iid,
-            issue.web_url,
-            issue.description.clone().unwrap_or_default()
-        );
-
-        let create_task = CreateTask {
-            project_id: project.id,
-            title: issue.title.clone(),
-            description: Some(description),
-            status: Some(TaskStatus::Todo),
-            execution_mode: None,
-            parent_workspace_id: None,
-            image_ids: None,
-            shared_task_id: None,
-        };
-
-        let task_id = Uuid::new_v4();
-        let task = Task::create(&deployment.db().pool, &create_task, task_id).await?;
-        imported.push(ImportGitLabIssueResponse { task, issue });
-    }
-
-    Project::update_gitlab_last_sync(&deployment.db().pool, project.id).await?;
+    provider
+        .update_status(&issue_iid, task.status.clone())
+        .await?;
 
     deployment
         .track_if_analytics_allowed(
-            "gitlab_issues_synced",
+            "gitlab_issue_status_synced",
             serde_json::json!({
-                "project_id": project.id.to_string(),
-                "imported_count": imported.len(),
+                "task_id": task.id.to_string(),
+                "issue_iid": issue_iid,
+                "new_status": task.status.to_string(),
             }),
         )
         .await;
 
-    Ok(ResponseJson(ApiResponse::success(imported)))
+    Ok(())
 }
 
 pub fn router() -> Router<DeploymentImpl> {
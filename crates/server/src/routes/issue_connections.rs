@@ -0,0 +1,280 @@
+//! Trackers a project connects to with an API token: Linear, Azure DevOps,
+//! Notion, Asana, ClickUp and Shortcut. Their issues are listed, imported and
+//! synced through each tracker's issue provider like every other tracker's.
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, Query, State},
+    response::{Json as ResponseJson, Response},
+    routing::{delete, get, post},
+};
+use db::models::{
+    issue_connection::{IssueConnection, UpsertIssueConnection},
+    issue_link::IssueLink,
+    project::{Project, ProjectError},
+    task::Task,
+};
+use deployment::Deployment;
+use serde::Deserialize;
+use services::services::{
+    issue_provider::{IssueProviderError, IssueProviderKind, ProviderIssue, verify_connection},
+    linear_issues::{LinearIssuesService, LinearTeam},
+    shortcut_stories::{ShortcutService, ShortcutWorkflow},
+    token_cipher::TokenCipher,
+};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::RequireAdmin,
+    routes::issue_sync::{
+        ImportedIssue, SyncIssuesQuery, import_issue_by_id, issue_provider, linked_issue,
+        sync_issues,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ListConnectedIssuesQuery {
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ImportConnectedIssueRequest {
+    /// The tracker's id for the issue, e.g. `ENG-123` for Linear
+    pub issue_id: String,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ListLinearTeamsRequest {
+    /// Key to list teams with; the project's stored key is used when omitted
+    #[serde(default)]
+    #[ts(optional)]
+    pub api_key: Option<String>,
+}
+
+/// The connected provider a path's `{provider}` names, e.g. `azure-devops`
+fn connected_kind(slug: &str) -> Result<IssueProviderKind, ApiError> {
+    IssueProviderKind::from_slug(slug)
+        .filter(|kind| kind.is_connected())
+        .ok_or_else(|| ApiError::BadRequest(format!("Unknown issue provider: {slug}")))
+}
+
+/// The project's decrypted token for `provider`, or `None` when it isn't
+/// connected
+async fn stored_token(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    provider: &'static str,
+) -> Result<Option<String>, ApiError> {
+    let Some(connection) =
+        IssueConnection::find(&deployment.db().pool, project_id, provider).await?
+    else {
+        return Ok(None);
+    };
+    let token = TokenCipher::shared()
+        .and_then(|cipher| cipher.decrypt(&connection.token))
+        .map_err(IssueProviderError::from)?;
+    Ok(Some(token))
+}
+
+/// `token` when given, otherwise the one stored for `provider`
+async fn given_or_stored_token(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    provider: &'static str,
+    token: Option<&str>,
+) -> Result<String, ApiError> {
+    match token.map(str::trim) {
+        Some(token) if !token.is_empty() => Ok(token.to_string()),
+        _ => stored_token(deployment, project_id, provider)
+            .await?
+            .ok_or_else(|| ApiError::integration_not_configured(provider)),
+    }
+}
+
+pub async fn list_issue_connections(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<IssueConnection>>>, ApiError> {
+    let connections =
+        IssueConnection::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(connections)))
+}
+
+/// Connect the project to a tracker, or change its connection, after checking
+/// the token can reach what the settings point at
+pub async fn upsert_issue_connection(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<UpsertIssueConnection>,
+) -> Result<ResponseJson<ApiResponse<IssueConnection>>, ApiError> {
+    let mut settings = payload.settings;
+    let token = given_or_stored_token(
+        &deployment,
+        project.id,
+        settings.provider(),
+        payload.token.as_deref(),
+    )
+    .await?;
+
+    verify_connection(&token, &mut settings).await?;
+
+    let encrypted = TokenCipher::shared()
+        .and_then(|cipher| cipher.encrypt(&token))
+        .map_err(IssueProviderError::from)?;
+    let connection =
+        IssueConnection::upsert(&deployment.db().pool, project.id, &encrypted, &settings).await?;
+
+    Ok(ResponseJson(ApiResponse::success(connection)))
+}
+
+/// Disconnect the project from a tracker. Tasks imported from it keep their
+/// links, and are written back to again if the project reconnects.
+pub async fn delete_issue_connection(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, provider)): Path<(Uuid, String)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let kind = connected_kind(&provider)?;
+    IssueConnection::delete(&deployment.db().pool, project.id, kind.name()).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Open issues of the connected tracker, as the sync would find them
+pub async fn list_connected_issues(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, provider)): Path<(Uuid, String)>,
+    Query(query): Query<ListConnectedIssuesQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProviderIssue>>>, ApiError> {
+    let kind = connected_kind(&provider)?;
+    let provider = issue_provider(&deployment, &project, kind).await?;
+    let issues = provider
+        .list_open_issues(query.limit.unwrap_or(50).clamp(1, 100))
+        .await?;
+    Ok(ResponseJson(ApiResponse::success(issues)))
+}
+
+pub async fn import_connected_issue(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, provider)): Path<(Uuid, String)>,
+    Json(payload): Json<ImportConnectedIssueRequest>,
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    let kind = connected_kind(&provider)?;
+    import_issue_by_id(&deployment, &project, kind, payload.issue_id.trim()).await
+}
+
+/// Import open issues that have no task yet. With `dry_run` set, returns an
+/// [`IssueSyncPreview`](super::issue_sync::IssueSyncPreview) instead and
+/// writes nothing.
+pub async fn sync_connected_issues(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, provider)): Path<(Uuid, String)>,
+    Query(query): Query<SyncIssuesQuery>,
+) -> Result<Response, ApiError> {
+    let kind = connected_kind(&provider)?;
+    sync_issues(project, deployment, query, kind).await
+}
+
+/// Teams a Linear key can see, for picking the team before connecting
+pub async fn list_linear_teams(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ListLinearTeamsRequest>,
+) -> Result<ResponseJson<ApiResponse<Vec<LinearTeam>>>, ApiError> {
+    let name = IssueProviderKind::Linear.name();
+    let api_key =
+        given_or_stored_token(&deployment, project.id, name, payload.api_key.as_deref()).await?;
+    let teams = LinearIssuesService::new()
+        .list_teams(&api_key)
+        .await
+        .map_err(IssueProviderError::from)?;
+    Ok(ResponseJson(ApiResponse::success(teams)))
+}
+
+/// Shortcut workflows and their states, for picking the review state
+pub async fn list_shortcut_workflows(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ShortcutWorkflow>>>, ApiError> {
+    let name = IssueProviderKind::Shortcut.name();
+    let token = given_or_stored_token(&deployment, project.id, name, None).await?;
+    let workflows = ShortcutService::new()
+        .list_workflows(&token)
+        .await
+        .map_err(IssueProviderError::from)?;
+    Ok(ResponseJson(ApiResponse::success(workflows)))
+}
+
+/// Write a task's status back to the issue it was imported from, when that
+/// came from a connected tracker. Each tracker's provider decides which
+/// statuses it writes back.
+pub(crate) async fn sync_connected_issue_status(
+    deployment: &DeploymentImpl,
+    task: &Task,
+) -> Result<(), ApiError> {
+    let pool = &deployment.db().pool;
+    let Some(kind) = IssueLink::find_by_task_id(pool, task.id)
+        .await?
+        .and_then(|link| IssueProviderKind::from_name(&link.provider))
+        .filter(|kind| kind.is_connected())
+    else {
+        return Ok(());
+    };
+    let project = Project::find_by_id(pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    let Some((provider, issue_id)) = linked_issue(deployment, &project, task, kind).await? else {
+        return Ok(());
+    };
+
+    provider
+        .update_status(&issue_id, task.status.clone())
+        .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            &format!("{}_issue_status_synced", kind.slug()),
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "issue_id": issue_id,
+                "new_status": task.status.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(())
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/issue-connections",
+            get(list_issue_connections).put(upsert_issue_connection),
+        )
+        .route(
+            "/issue-connections/{provider}",
+            delete(delete_issue_connection),
+        )
+        .route(
+            "/issue-connections/{provider}/issues",
+            get(list_connected_issues),
+        )
+        .route(
+            "/issue-connections/{provider}/issues/import",
+            post(import_connected_issue),
+        )
+        .route(
+            "/issue-connections/{provider}/issues/sync",
+            post(sync_connected_issues),
+        )
+        .route("/linear/teams", post(list_linear_teams))
+        .route("/shortcut/workflows", get(list_shortcut_workflows))
+}
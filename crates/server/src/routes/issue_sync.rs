@@ -0,0 +1,580 @@
+use std::{collections::HashMap, future::Future};
+
+use axum::{
+    Extension, Router,
+    extract::{Query, State},
+    response::{IntoResponse, Json as ResponseJson, Response},
+    routing::get,
+};
+use db::models::{
+    image::TaskImage,
+    issue_connection::IssueConnection,
+    issue_link::IssueLink,
+    project::Project,
+    sync_run::SyncRun,
+    task::{CreateTask, Task, TaskStatus},
+    task_draft::TaskDraft,
+    task_label::TaskLabel,
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::{
+    container::ContainerService,
+    image::ImageService,
+    issue_images,
+    issue_provider::{
+        IssueProvider, IssueProviderError, IssueProviderKind, IssueProviderRegistry, ProviderIssue,
+    },
+    issue_sync_scheduler::{IssueSyncRunner, IssueSyncSchedule, SyncProvider},
+    task_duplicates::{PotentialDuplicate, find_potential_duplicates},
+};
+use sqlx::SqlitePool;
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::{github_issues, notifications, vortex_issues},
+};
+
+const DEFAULT_SYNC_RUNS_LIMIT: i64 = 50;
+
+/// Query accepted by every provider's `issues/sync` endpoint
+#[derive(Debug, Default, Deserialize)]
+pub struct SyncIssuesQuery {
+    /// Report what the sync would do without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Result of a dry-run sync. Sync only imports new issues; it never updates or
+/// closes tasks that are already linked, so those are listed separately.
+#[derive(Debug, Default, Serialize, TS)]
+pub struct IssueSyncPreview {
+    pub to_import: Vec<IssueSyncPreviewItem>,
+    pub already_linked: Vec<IssueSyncPreviewItem>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct IssueSyncPreviewItem {
+    /// Provider's issue number or key, e.g. `#42` or `PR #7`
+    pub external_id: String,
+    /// Title the imported task would get
+    pub title: String,
+    pub url: String,
+    /// Open tasks with similar titles, filled in for issues that would be imported
+    pub possible_duplicates: Vec<PotentialDuplicate>,
+}
+
+impl IssueSyncPreview {
+    pub fn push(&mut self, linked: bool, external_id: String, title: String, url: String) {
+        let item = IssueSyncPreviewItem {
+            external_id,
+            title,
+            url,
+            possible_duplicates: Vec::new(),
+        };
+        if linked {
+            self.already_linked.push(item);
+        } else {
+            self.to_import.push(item);
+        }
+    }
+
+    /// Look up open tasks that the issues to import may duplicate, since issues
+    /// often overlap tasks that were created by hand
+    pub async fn flag_duplicates(
+        &mut self,
+        pool: &SqlitePool,
+        project_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        for item in &mut self.to_import {
+            item.possible_duplicates =
+                find_potential_duplicates(pool, project_id, &item.title, None).await?;
+        }
+        Ok(())
+    }
+}
+
+/// What a sync run changed. `updated` counts linked tasks that took their
+/// issue's title or description, which only Vortex projects syncing both ways
+/// do.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SyncCounts {
+    pub imported: usize,
+    pub updated: usize,
+}
+
+/// Run a provider sync and record its outcome in `sync_runs`. Dry runs write
+/// nothing, so they aren't recorded. A project runs one sync at a time, so a
+/// sync started while another, or a scheduled one, is running is refused.
+pub(crate) async fn record_sync_run(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    provider: &str,
+    dry_run: bool,
+    sync: impl Future<Output = Result<(Response, SyncCounts), ApiError>>,
+) -> Result<Response, ApiError> {
+    if dry_run {
+        return sync.await.map(|(response, _)| response);
+    }
+
+    let _guard = deployment
+        .issue_sync_scheduler()
+        .try_lock(project_id)
+        .ok_or_else(|| {
+            ApiError::Conflict("A sync of this project is already running".to_string())
+        })?;
+    record_locked_sync_run(deployment, project_id, provider, sync).await
+}
+
+/// [`record_sync_run`] for a caller that already holds the project's sync lock.
+/// Failing to record is logged and never changes the sync's own result.
+async fn record_locked_sync_run(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    provider: &str,
+    sync: impl Future<Output = Result<(Response, SyncCounts), ApiError>>,
+) -> Result<Response, ApiError> {
+    let pool = &deployment.db().pool;
+    let run = SyncRun::start(pool, project_id, provider)
+        .await
+        .inspect_err(|e| tracing::error!("Failed to record {} sync run: {}", provider, e))
+        .ok();
+
+    let result = sync.await;
+
+    if let Some(run) = run {
+        let (counts, error) = match &result {
+            Ok((_, counts)) => (*counts, None),
+            Err(e) => (SyncCounts::default(), Some(e.to_string())),
+        };
+        if let Err(e) = SyncRun::finish(
+            pool,
+            run.id,
+            counts.imported as i64,
+            counts.updated as i64,
+            error.as_deref(),
+        )
+        .await
+        {
+            tracing::error!("Failed to finish {} sync run {}: {}", provider, run.id, e);
+        }
+    }
+
+    result.map(|(response, _)| response)
+}
+
+/// A task imported from an issue, with the issue as its provider reported it
+#[derive(Debug, Serialize, TS)]
+pub struct ImportedIssue {
+    pub task: Task,
+    pub issue: ProviderIssue,
+}
+
+/// The project's provider of `kind`, or `None` when it isn't configured
+pub(crate) async fn find_issue_provider(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    kind: IssueProviderKind,
+) -> Result<Option<Box<dyn IssueProvider>>, ApiError> {
+    let vortex_oauth = deployment.container().vortex_oauth().await;
+    Ok(
+        IssueProviderRegistry::new(deployment.db().pool.clone(), vortex_oauth)
+            .provider(project, kind)
+            .await?,
+    )
+}
+
+pub(crate) async fn issue_provider(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    kind: IssueProviderKind,
+) -> Result<Box<dyn IssueProvider>, ApiError> {
+    find_issue_provider(deployment, project, kind)
+        .await?
+        .ok_or_else(|| ApiError::integration_not_configured(kind.name()))
+}
+
+/// The provider and issue id of the issue `task` was imported from, if it came
+/// from `kind` and the project is still configured for it
+pub(crate) async fn linked_issue(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    task: &Task,
+    kind: IssueProviderKind,
+) -> Result<Option<(Box<dyn IssueProvider>, String)>, ApiError> {
+    let Some(link) = IssueLink::find_by_task_id(&deployment.db().pool, task.id)
+        .await?
+        .filter(|link| link.provider == kind.name())
+    else {
+        return Ok(None);
+    };
+    Ok(find_issue_provider(deployment, project, kind)
+        .await?
+        .map(|provider| (provider, link.external_id)))
+}
+
+/// Images of an issue stored for its imported task
+#[derive(Default)]
+struct ImportedImages {
+    image_ids: Vec<Uuid>,
+    /// Local paths for the images the issue body embeds, by their original URL
+    replacements: HashMap<String, String>,
+    /// Markdown embedding the attachments the body doesn't reference
+    attachments_markdown: String,
+}
+
+/// Store the issue's image attachments and embedded images, returning their
+/// ids and how the task description should reference them
+async fn import_attachments(
+    deployment: &DeploymentImpl,
+    provider: &dyn IssueProvider,
+    issue: &ProviderIssue,
+) -> Result<ImportedImages, ApiError> {
+    let attachments = provider.fetch_attachments(issue).await;
+    if attachments.is_empty() {
+        return Ok(ImportedImages::default());
+    }
+
+    let image_service = ImageService::new(deployment.db().pool.clone())?;
+    let mut imported = ImportedImages::default();
+    let mut image_lines = Vec::new();
+    for attachment in attachments {
+        match image_service
+            .store_image(&attachment.data, &attachment.filename)
+            .await
+        {
+            Ok(image) => {
+                imported.image_ids.push(image.id);
+                let local_path = format!("{}/{}", utils::path::VIBE_IMAGES_DIR, image.file_path);
+                match attachment.source_url {
+                    Some(url) => {
+                        imported.replacements.insert(url, local_path);
+                    }
+                    None => image_lines.push(format!("![{}]({})", attachment.filename, local_path)),
+                }
+            }
+            Err(e) => tracing::warn!(
+                "Failed to store {} attachment {}: {}",
+                provider.kind().name(),
+                attachment.filename,
+                e
+            ),
+        }
+    }
+
+    if !image_lines.is_empty() {
+        imported.attachments_markdown =
+            format!("\n\n## Attachments\n\n{}", image_lines.join("\n\n"));
+    }
+    Ok(imported)
+}
+
+/// Create the task for an issue, with its attachments and, if the provider
+/// imports them, its labels. The task's link to the issue, and whether it is a
+/// draft, are recorded in the same write so a task never exists without them.
+pub(crate) async fn import_issue(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    provider: &dyn IssueProvider,
+    issue: ProviderIssue,
+    status: TaskStatus,
+    draft: bool,
+) -> Result<ImportedIssue, ApiError> {
+    let images = import_attachments(deployment, provider, &issue).await?;
+    let image_ids = images.image_ids;
+    let description = issue_images::rewrite_image_urls(
+        &issue.task_description(provider.kind(), &images.attachments_markdown),
+        &images.replacements,
+    );
+
+    let create_task = CreateTask {
+        project_id,
+        title: issue.title.clone(),
+        description: Some(description),
+        status: Some(status),
+        execution_mode: None,
+        parent_workspace_id: None,
+        image_ids: (!image_ids.is_empty()).then(|| image_ids.clone()),
+        shared_task_id: None,
+    };
+
+    let task_id = Uuid::new_v4();
+    let create_task = &create_task;
+    let source = provider.kind().name();
+    let (external_id, url) = (issue.id.as_str(), issue.url.as_str());
+    let task = deployment
+        .db()
+        .write(|pool| async move {
+            let task = Task::create(&pool, create_task, task_id).await?;
+            IssueLink::create(&pool, task.id, source, external_id, url).await?;
+            if draft {
+                TaskDraft::create(&pool, task.id, project_id, source).await?;
+            }
+            Ok::<_, sqlx::Error>(task)
+        })
+        .await?;
+
+    let pool = &deployment.db().pool;
+    if !image_ids.is_empty() {
+        TaskImage::associate_many_dedup(pool, task.id, &image_ids).await?;
+    }
+    if provider.imports_labels() && !issue.labels.is_empty() {
+        TaskLabel::set(pool, task.id, &issue.labels).await?;
+    }
+
+    Ok(ImportedIssue { task, issue })
+}
+
+/// Import an issue found by a sync or a webhook, as a draft when the project
+/// triages synced issues and nobody has labeled it yet
+pub(crate) async fn import_synced_issue(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    provider: &dyn IssueProvider,
+    issue: ProviderIssue,
+) -> Result<ImportedIssue, ApiError> {
+    // Unlabeled issues haven't been looked at on the tracker either
+    let draft = project.triage_synced_issues && issue.labels.is_empty();
+    let status = issue.status.clone().unwrap_or(TaskStatus::Todo);
+    import_issue(deployment, project.id, provider, issue, status, draft).await
+}
+
+/// Import one issue by its provider id, for the `issues/import` endpoints
+pub(crate) async fn import_issue_by_id(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    kind: IssueProviderKind,
+    issue_id: &str,
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    let provider = issue_provider(deployment, project, kind).await?;
+    let issue = provider.get_issue(issue_id).await?;
+    let status = issue.status.clone().unwrap_or(TaskStatus::Todo);
+    let imported = import_issue(
+        deployment,
+        project.id,
+        provider.as_ref(),
+        issue,
+        status,
+        false,
+    )
+    .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            &format!("{}_issue_imported", kind.slug()),
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "issue_key": imported.issue.key,
+                "task_id": imported.task.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(imported)))
+}
+
+pub(crate) enum IssueSyncOutcome {
+    Preview(IssueSyncPreview),
+    Imported(Vec<ImportedIssue>),
+}
+
+/// Import the provider's open issues that have no task yet, or with `dry_run`
+/// only preview them. A failure to list issues is recorded as a notification.
+pub(crate) async fn sync_open_issues(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    provider: &dyn IssueProvider,
+    dry_run: bool,
+) -> Result<IssueSyncOutcome, ApiError> {
+    let kind = provider.kind();
+    let issues = match provider.list_open_issues(100).await {
+        Ok(issues) => issues,
+        Err(IssueProviderError::NotConfigured(provider)) => {
+            return Err(ApiError::integration_not_configured(provider));
+        }
+        Err(e) => {
+            let error = e.to_string();
+            if !dry_run {
+                notifications::record_sync_error(deployment, project, kind.name(), &error).await;
+            }
+            return Err(ApiError::BadRequest(error));
+        }
+    };
+
+    let pool = &deployment.db().pool;
+    let links: HashMap<String, Uuid> = IssueLink::find_by_project_id(pool, project.id, kind.name())
+        .await?
+        .into_iter()
+        .map(|link| (link.external_id, link.task_id))
+        .collect();
+
+    if dry_run {
+        let mut preview = IssueSyncPreview::default();
+        for issue in issues {
+            preview.push(
+                links.contains_key(&issue.id),
+                format!("#{}", issue.key),
+                issue.title,
+                issue.url,
+            );
+        }
+        return Ok(IssueSyncOutcome::Preview(preview));
+    }
+
+    let mut imported = Vec::new();
+    for issue in issues {
+        if let Some(&task_id) = links.get(&issue.id) {
+            IssueLink::mark_synced(pool, task_id).await?;
+            continue;
+        }
+        imported.push(import_synced_issue(deployment, project, provider, issue).await?);
+    }
+    Ok(IssueSyncOutcome::Imported(imported))
+}
+
+/// Sync a provider with nothing to import besides issues, recording the run
+pub(crate) async fn sync_issues(
+    project: Project,
+    deployment: DeploymentImpl,
+    query: SyncIssuesQuery,
+    kind: IssueProviderKind,
+) -> Result<Response, ApiError> {
+    let (project_id, dry_run) = (project.id, query.dry_run);
+    record_sync_run(
+        &deployment,
+        project_id,
+        kind.name(),
+        dry_run,
+        run_issue_sync(project, deployment.clone(), query, kind),
+    )
+    .await
+}
+
+pub(crate) async fn run_issue_sync(
+    project: Project,
+    deployment: DeploymentImpl,
+    query: SyncIssuesQuery,
+    kind: IssueProviderKind,
+) -> Result<(Response, SyncCounts), ApiError> {
+    let provider = issue_provider(&deployment, &project, kind).await?;
+    let imported =
+        match sync_open_issues(&deployment, &project, provider.as_ref(), query.dry_run).await? {
+            IssueSyncOutcome::Preview(mut preview) => {
+                preview
+                    .flag_duplicates(&deployment.db().pool, project.id)
+                    .await?;
+                return Ok((
+                    ResponseJson(ApiResponse::<IssueSyncPreview>::success(preview)).into_response(),
+                    SyncCounts::default(),
+                ));
+            }
+            IssueSyncOutcome::Imported(imported) => imported,
+        };
+
+    let pool = &deployment.db().pool;
+    match kind {
+        IssueProviderKind::GitHub => Project::update_github_last_sync(pool, project.id).await?,
+        IssueProviderKind::GitLab => Project::update_gitlab_last_sync(pool, project.id).await?,
+        IssueProviderKind::Gitea => Project::update_gitea_last_sync(pool, project.id).await?,
+        IssueProviderKind::Vortex => Project::update_vortex_last_sync(pool, project.id).await?,
+        IssueProviderKind::Linear
+        | IssueProviderKind::AzureDevOps
+        | IssueProviderKind::Notion
+        | IssueProviderKind::Asana
+        | IssueProviderKind::ClickUp
+        | IssueProviderKind::Shortcut => {
+            IssueConnection::update_last_sync(pool, project.id, kind.name()).await?
+        }
+    }
+    let updated = match kind {
+        IssueProviderKind::Vortex => {
+            vortex_issues::sync_project_vortex_content(&deployment, &project).await?
+        }
+        _ => 0,
+    };
+
+    deployment
+        .track_if_analytics_allowed(
+            &format!("{}_issues_synced", kind.slug()),
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "imported_count": imported.len(),
+            }),
+        )
+        .await;
+
+    let counts = SyncCounts {
+        imported: imported.len(),
+        updated,
+    };
+    Ok((
+        ResponseJson(ApiResponse::<Vec<_>>::success(imported)).into_response(),
+        counts,
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SyncRunsQuery {
+    pub limit: Option<i64>,
+}
+
+/// Recent sync runs across all providers, newest first
+pub async fn list_sync_runs(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<SyncRunsQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<SyncRun>>>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_SYNC_RUNS_LIMIT).clamp(1, 500);
+    let runs = SyncRun::find_by_project_id(&deployment.db().pool, project.id, limit).await?;
+    Ok(ResponseJson(ApiResponse::success(runs)))
+}
+
+/// The background sync schedule and how the project's last scheduled syncs went
+pub async fn get_sync_schedule(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<IssueSyncSchedule>>, ApiError> {
+    let config = deployment.config().read().await.issue_sync_schedule.clone();
+    let schedule = deployment
+        .issue_sync_scheduler()
+        .schedule(project.id, &config);
+    Ok(ResponseJson(ApiResponse::success(schedule)))
+}
+
+/// Runs scheduled syncs the way each provider's `issues/sync` endpoint does
+pub struct ScheduledIssueSync(pub DeploymentImpl);
+
+impl IssueSyncRunner for ScheduledIssueSync {
+    async fn sync(&self, project: Project, provider: SyncProvider) -> Result<(), String> {
+        let deployment = &self.0;
+        let (project_id, name) = (project.id, provider.name());
+        let query = SyncIssuesQuery::default();
+        let result = match provider {
+            SyncProvider::GitHub => {
+                let sync = github_issues::run_github_sync(project, deployment.clone(), query);
+                record_locked_sync_run(deployment, project_id, name, sync).await
+            }
+            SyncProvider::GitLab | SyncProvider::Gitea | SyncProvider::Vortex => {
+                let kind = match provider {
+                    SyncProvider::GitLab => IssueProviderKind::GitLab,
+                    SyncProvider::Gitea => IssueProviderKind::Gitea,
+                    _ => IssueProviderKind::Vortex,
+                };
+                let sync = run_issue_sync(project, deployment.clone(), query, kind);
+                record_locked_sync_run(deployment, project_id, name, sync).await
+            }
+        };
+        result.map(|_| ()).map_err(|e| e.to_string())
+    }
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/sync-runs", get(list_sync_runs))
+        .route("/sync-schedule", get(get_sync_schedule))
+}
@@ -0,0 +1,86 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use db::models::kiosk_token::{CreateKioskToken, KioskToken};
+use deployment::Deployment;
+use serde::Serialize;
+use ts_rs::TS;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::{RequireAdmin, generate_kiosk_token},
+    secret_token,
+};
+
+#[derive(Debug, Serialize, TS)]
+pub struct CreatedKioskToken {
+    pub kiosk_token: KioskToken,
+    /// Only returned here; it can't be looked up again
+    pub token: String,
+}
+
+pub async fn list_kiosk_tokens(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<KioskToken>>>, ApiError> {
+    let tokens = KioskToken::find_all(&deployment.db().pool).await?;
+    Ok(ResponseJson(ApiResponse::success(tokens)))
+}
+
+pub async fn create_kiosk_token(
+    RequireAdmin(admin): RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateKioskToken>,
+) -> Result<ResponseJson<ApiResponse<CreatedKioskToken>>, ApiError> {
+    let name = payload.name.trim();
+    if name.is_empty() {
+        return Err(ApiError::BadRequest("Name is required".to_string()));
+    }
+
+    let token = generate_kiosk_token();
+    let kiosk_token = KioskToken::create(
+        &deployment.db().pool,
+        name,
+        &secret_token::hash_token(&token),
+        Some(admin.id),
+    )
+    .await?;
+
+    deployment
+        .track_if_analytics_allowed("kiosk_token_created", serde_json::json!({}))
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(CreatedKioskToken {
+        kiosk_token,
+        token,
+    })))
+}
+
+pub async fn revoke_kiosk_token(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Path(id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    if KioskToken::delete(&deployment.db().pool, id).await? == 0 {
+        return Err(ApiError::coded(
+            ErrorCode::NotFound,
+            "Kiosk token not found",
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/admin/kiosk-tokens",
+            get(list_kiosk_tokens).post(create_kiosk_token),
+        )
+        .route("/admin/kiosk-tokens/{id}", delete(revoke_kiosk_token))
+}
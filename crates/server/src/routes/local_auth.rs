@@ -1,12 +1,15 @@
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::State,
     http::StatusCode,
     response::Json as ResponseJson,
     routing::{get, post},
 };
 use chrono::{Duration, Utc};
-use db::models::user::{User, UserError, UserPublic, UserRole, UserSession};
+use db::models::{
+    kiosk_token::KioskToken,
+    user::{User, UserError, UserPublic, UserRole, UserSession},
+};
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
@@ -16,7 +19,7 @@ use utils::{
         create_refresh_token, validate_refresh_token,
     },
     password::{hash_password, verify_password},
-    response::ApiResponse,
+    response::{ApiResponse, ErrorCode},
 };
 
 use crate::{DeploymentImpl, error::ApiError};
@@ -91,16 +94,20 @@ async fn register(
 
     // Validate username
     if payload.username.is_empty() || payload.username.len() < 3 {
-        return Err(ApiError::BadRequest(
-            "Username must be at least 3 characters".to_string(),
-        ));
+        return Err(ApiError::coded(
+            ErrorCode::UsernameTooShort,
+            "Username must be at least 3 characters",
+        )
+        .with_param("min_length", 3));
     }
 
     // Validate password
     if payload.password.len() < 8 {
-        return Err(ApiError::BadRequest(
-            "Password must be at least 8 characters".to_string(),
-        ));
+        return Err(ApiError::coded(
+            ErrorCode::PasswordTooShort,
+            "Password must be at least 8 characters",
+        )
+        .with_param("min_length", 8));
     }
 
     // Check if this is the first user (will be admin)
@@ -290,10 +297,22 @@ async fn refresh(
 /// GET /api/local-auth/me
 async fn get_current_user(
     State(deployment): State<DeploymentImpl>,
+    kiosk: Option<Extension<KioskToken>>,
     headers: axum::http::HeaderMap,
 ) -> Result<ResponseJson<ApiResponse<UserPublic>>, ApiError> {
     let pool = &deployment.db().pool;
 
+    // Kiosk tokens stand in for a viewer that has no account
+    if let Some(Extension(kiosk)) = kiosk {
+        return Ok(ResponseJson(ApiResponse::success(UserPublic {
+            id: kiosk.id,
+            username: kiosk.name,
+            email: None,
+            role: UserRole::Viewer.to_string(),
+            created_at: kiosk.created_at,
+        })));
+    }
+
     // Extract token from Authorization header
     let auth_header = headers
         .get(axum::http::header::AUTHORIZATION)
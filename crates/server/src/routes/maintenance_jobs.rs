@@ -0,0 +1,127 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, post, put},
+};
+use db::models::{
+    maintenance_job::{MaintenanceJob, UpsertMaintenanceJob},
+    project::Project,
+    task::Task,
+};
+use deployment::Deployment;
+use services::services::{container::ContainerService, maintenance::CronSchedule};
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+fn validate_job(payload: &UpsertMaintenanceJob) -> Result<(), ApiError> {
+    if payload.name.trim().is_empty() || payload.prompt.trim().is_empty() {
+        return Err(ApiError::BadRequest(
+            "Maintenance jobs need a name and a prompt".to_string(),
+        ));
+    }
+    CronSchedule::parse(&payload.schedule)
+        .map(|_| ())
+        .map_err(|e| ApiError::BadRequest(format!("Invalid schedule: {e}")))
+}
+
+pub async fn list_maintenance_jobs(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<MaintenanceJob>>>, ApiError> {
+    let jobs = MaintenanceJob::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(jobs)))
+}
+
+pub async fn create_maintenance_job(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<UpsertMaintenanceJob>,
+) -> Result<ResponseJson<ApiResponse<MaintenanceJob>>, ApiError> {
+    validate_job(&payload)?;
+    let job = MaintenanceJob::create(&deployment.db().pool, project.id, &payload).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "maintenance_job_created",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "executor": job.executor_profile_id.executor.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(job)))
+}
+
+pub async fn update_maintenance_job(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, job_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpsertMaintenanceJob>,
+) -> Result<ResponseJson<ApiResponse<MaintenanceJob>>, ApiError> {
+    validate_job(&payload)?;
+    let job = MaintenanceJob::update(&deployment.db().pool, project.id, job_id, &payload)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Maintenance job not found"))?;
+    Ok(ResponseJson(ApiResponse::success(job)))
+}
+
+pub async fn delete_maintenance_job(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, job_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    if MaintenanceJob::delete(&deployment.db().pool, project.id, job_id).await? == 0 {
+        return Err(ApiError::BadRequest(
+            "Maintenance job not found".to_string(),
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Run a job now, regardless of its schedule or whether other agents are running
+pub async fn run_maintenance_job(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, job_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let job = MaintenanceJob::find_by_id(&deployment.db().pool, project.id, job_id)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Maintenance job not found"))?;
+    let task = deployment.container().start_maintenance_job(&job, None).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "maintenance_job_started",
+            serde_json::json!({
+                "job_id": job.id.to_string(),
+                "project_id": project.id.to_string(),
+                "task_id": task.id.to_string(),
+                "scheduled": false,
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/maintenance-jobs",
+            get(list_maintenance_jobs).post(create_maintenance_job),
+        )
+        .route(
+            "/maintenance-jobs/{job_id}",
+            put(update_maintenance_job).delete(delete_maintenance_job),
+        )
+        .route("/maintenance-jobs/{job_id}/run", post(run_maintenance_job))
+}
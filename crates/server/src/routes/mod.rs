@@ -1,35 +1,69 @@
 use axum::{
     Router,
+    middleware::from_fn_with_state,
     routing::{IntoMakeService, get},
 };
 
-use crate::DeploymentImpl;
+use crate::{DeploymentImpl, middleware};
 
+pub mod admin;
 pub mod approvals;
+pub mod bitbucket_issues;
+pub mod calendar;
+pub mod capacity;
 pub mod config;
 pub mod containers;
+pub mod diff_exclusions;
+pub mod email_intake;
 pub mod filesystem;
 // pub mod github;
 pub mod events;
 pub mod execution_processes;
+pub mod executor_slots;
 pub mod frontend;
+pub mod gitea_issues;
 pub mod github_issues;
 pub mod gitlab_issues;
 pub mod health;
 pub mod images;
+pub mod issue_connections;
+pub mod issue_sync;
+pub mod kiosk_tokens;
 pub mod local_auth;
+pub mod maintenance_jobs;
+pub mod my_feed;
+pub mod my_tasks;
+pub mod notifications;
 pub mod oauth;
 pub mod organizations;
+pub mod pending_starts;
+pub mod project_groups;
+pub mod project_instructions;
 pub mod projects;
+pub mod quick_add;
 pub mod repo;
+pub mod rest_hooks;
 pub mod scratch;
 pub mod sessions;
 pub mod shared_tasks;
+pub mod sla;
+pub mod slack;
+pub mod status_page;
+pub mod status_transitions;
 pub mod tags;
 pub mod task_attempts;
+pub mod task_dependencies;
+pub mod task_drafts;
+pub mod task_groups;
+pub mod task_labels;
+pub mod task_reviews;
 pub mod tasks;
+pub mod timeline;
+pub mod token_budgets;
 pub mod users;
 pub mod vortex_issues;
+pub mod vortex_oauth;
+pub mod webhooks;
 
 pub fn router(deployment: DeploymentImpl) -> IntoMakeService<Router> {
     // Create routers with different middleware layers
@@ -51,9 +85,27 @@ pub fn router(deployment: DeploymentImpl) -> IntoMakeService<Router> {
         .merge(repo::router())
         .merge(events::router(&deployment))
         .merge(approvals::router())
+        .merge(admin::router())
+        .merge(kiosk_tokens::router())
+        .merge(notifications::router())
+        .merge(project_groups::router())
+        .merge(my_tasks::router())
+        .merge(my_feed::router())
+        .merge(webhooks::router())
+        .merge(slack::router())
+        .merge(email_intake::router())
+        .merge(calendar::router())
+        .merge(status_page::router())
+        .merge(capacity::router())
+        .merge(rest_hooks::router())
+        .merge(vortex_oauth::router())
         .merge(scratch::router(&deployment))
         .merge(sessions::router(&deployment))
         .nest("/images", images::routes())
+        .layer(from_fn_with_state(
+            deployment.clone(),
+            middleware::read_only_middleware,
+        ))
         .with_state(deployment);
 
     Router::new()
@@ -0,0 +1,57 @@
+use axum::{
+    Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use db::models::feed_event::FeedEvent;
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::AuthUser};
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct FeedQuery {
+    /// Cursor from the previous page; the newest events when omitted
+    pub before: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct FeedPage {
+    pub events: Vec<FeedEvent>,
+    /// Pass as `before` to get the next page; null on the last one
+    #[ts(type = "number | null")]
+    pub next_cursor: Option<i64>,
+}
+
+/// Recent activity relevant to the current user, newest first: attempts and
+/// queue starts on tasks they created or are assigned to, mentions, and review
+/// requests
+pub async fn get_my_feed(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<FeedQuery>,
+) -> Result<ResponseJson<ApiResponse<FeedPage>>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let events =
+        FeedEvent::find_by_user_id(&deployment.db().pool, auth.id, query.before, limit).await?;
+    let next_cursor = if events.len() as i64 == limit {
+        events.last().map(|event| event.id)
+    } else {
+        None
+    };
+    Ok(ResponseJson(ApiResponse::success(FeedPage {
+        events,
+        next_cursor,
+    })))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/me/feed", get(get_my_feed))
+}
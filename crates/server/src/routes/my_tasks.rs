@@ -0,0 +1,141 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    project::Project,
+    task::{Task, TaskStatus, TaskWithAttemptStatus},
+    task_owner::{SetTaskAssignee, TaskOwner},
+    user::User,
+};
+use deployment::Deployment;
+use serde::Serialize;
+use ts_rs::TS;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::AuthUser};
+
+/// Order of the status groups on the personal dashboard
+const STATUS_ORDER: [TaskStatus; 5] = [
+    TaskStatus::InProgress,
+    TaskStatus::InReview,
+    TaskStatus::Todo,
+    TaskStatus::Done,
+    TaskStatus::Cancelled,
+];
+
+#[derive(Debug, Serialize, TS)]
+pub struct MyTask {
+    #[serde(flatten)]
+    #[ts(flatten)]
+    pub task: TaskWithAttemptStatus,
+    pub project_name: String,
+    pub is_creator: bool,
+    pub is_assignee: bool,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct MyTaskGroup {
+    pub status: TaskStatus,
+    pub tasks: Vec<MyTask>,
+}
+
+/// Tasks the current user created or is assigned to, across every project, grouped by status
+pub async fn get_my_tasks(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<MyTaskGroup>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let owners: HashMap<Uuid, TaskOwner> = TaskOwner::find_for_user(pool, auth.id)
+        .await?
+        .into_iter()
+        .map(|owner| (owner.task_id, owner))
+        .collect();
+
+    let mut project_ids = HashSet::new();
+    for task_id in owners.keys() {
+        if let Some(task) = Task::find_by_id(pool, *task_id).await? {
+            project_ids.insert(task.project_id);
+        }
+    }
+
+    let mut tasks = Vec::new();
+    for project in Project::find_all(pool).await? {
+        if !project_ids.contains(&project.id) {
+            continue;
+        }
+        for task in Task::find_by_project_id_with_attempt_status(pool, project.id).await? {
+            let Some(owner) = owners.get(&task.id) else {
+                continue;
+            };
+            tasks.push(MyTask {
+                is_creator: owner.created_by_user_id == Some(auth.id),
+                is_assignee: owner.assignee_user_id == Some(auth.id),
+                project_name: project.name.clone(),
+                task,
+            });
+        }
+    }
+    tasks.sort_by_key(|t| Reverse(t.task.updated_at));
+
+    let groups = STATUS_ORDER
+        .into_iter()
+        .filter_map(|status| {
+            let (matching, rest): (Vec<MyTask>, Vec<MyTask>) = std::mem::take(&mut tasks)
+                .into_iter()
+                .partition(|t| t.task.status == status);
+            tasks = rest;
+            (!matching.is_empty()).then_some(MyTaskGroup {
+                status,
+                tasks: matching,
+            })
+        })
+        .collect();
+    Ok(ResponseJson(ApiResponse::success(groups)))
+}
+
+pub async fn get_task_owner(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TaskOwner>>>, ApiError> {
+    let owner = TaskOwner::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(owner)))
+}
+
+pub async fn set_task_assignee(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskAssignee>,
+) -> Result<ResponseJson<ApiResponse<TaskOwner>>, ApiError> {
+    let pool = &deployment.db().pool;
+    if let Some(user_id) = payload.assignee_user_id
+        && User::find_by_id(pool, user_id).await?.is_none()
+    {
+        return Err(ApiError::coded(ErrorCode::NotFound, "User not found"));
+    }
+    let owner = TaskOwner::set_assignee(pool, task.id, payload.assignee_user_id).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "task_assignee_set",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "assigned": payload.assignee_user_id.is_some(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(owner)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/me/tasks", get(get_my_tasks))
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new().route("/owner", get(get_task_owner).put(set_task_assignee))
+}
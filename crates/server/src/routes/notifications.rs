@@ -0,0 +1,154 @@
+use axum::{
+    Router,
+    extract::{Path, Query, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    mention::MentionSource,
+    notification::{CreateNotification, Notification, NotificationKind},
+    project::Project,
+    task::Task,
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::mentions::{self, MentionContext};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::AuthUser};
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize, TS)]
+pub struct NotificationListQuery {
+    #[serde(default)]
+    pub unread_only: bool,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct NotificationListResponse {
+    pub notifications: Vec<Notification>,
+    pub unread_count: i64,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct UnreadCountResponse {
+    pub unread_count: i64,
+}
+
+pub async fn list_notifications(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<NotificationListQuery>,
+) -> Result<ResponseJson<ApiResponse<NotificationListResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let notifications =
+        Notification::find_by_user_id(pool, auth.id, query.unread_only, limit).await?;
+    let unread_count = Notification::count_unread(pool, auth.id).await?;
+
+    Ok(ResponseJson(ApiResponse::success(
+        NotificationListResponse {
+            notifications,
+            unread_count,
+        },
+    )))
+}
+
+pub async fn get_unread_count(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<UnreadCountResponse>>, ApiError> {
+    let unread_count = Notification::count_unread(&deployment.db().pool, auth.id).await?;
+    Ok(ResponseJson(ApiResponse::success(UnreadCountResponse {
+        unread_count,
+    })))
+}
+
+pub async fn mark_notification_read(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<UnreadCountResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+    Notification::mark_read(pool, auth.id, notification_id).await?;
+    let unread_count = Notification::count_unread(pool, auth.id).await?;
+    Ok(ResponseJson(ApiResponse::success(UnreadCountResponse {
+        unread_count,
+    })))
+}
+
+pub async fn mark_all_notifications_read(
+    auth: AuthUser,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<UnreadCountResponse>>, ApiError> {
+    Notification::mark_all_read(&deployment.db().pool, auth.id).await?;
+    Ok(ResponseJson(ApiResponse::success(UnreadCountResponse {
+        unread_count: 0,
+    })))
+}
+
+/// Record a failed issue sync in every user's inbox. Failures here are logged
+/// rather than returned so they never mask the original sync error.
+pub(crate) async fn record_sync_error(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    provider: &str,
+    error: &str,
+) {
+    let notification = CreateNotification {
+        kind: NotificationKind::SyncError,
+        title: format!("{} sync failed: {}", provider, project.name),
+        message: error.to_string(),
+        project_id: Some(project.id),
+        task_id: None,
+        workspace_id: None,
+    };
+    if let Err(e) = Notification::create_for_all_users(&deployment.db().pool, &notification).await {
+        tracing::error!(
+            "Failed to record {} sync error notification: {}",
+            provider,
+            e
+        );
+    }
+}
+
+/// Notify the users newly `@mentioned` in `text`. Failures are logged rather
+/// than returned so they never fail the edit that mentioned them.
+pub(crate) async fn record_mentions(
+    deployment: &DeploymentImpl,
+    source: MentionSource,
+    source_id: Uuid,
+    task: &Task,
+    workspace_id: Option<Uuid>,
+    author: Option<&AuthUser>,
+    text: &str,
+) {
+    let ctx = MentionContext {
+        source,
+        source_id,
+        task,
+        workspace_id,
+        author_id: author.map(|user| user.id),
+        author_name: author.map(|user| user.username.as_str()),
+    };
+    if let Err(e) = mentions::record_mentions(&deployment.db().pool, &ctx, text).await {
+        tracing::error!("Failed to record mentions for task {}: {}", task.id, e);
+    }
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/notifications", get(list_notifications))
+        .route("/notifications/unread-count", get(get_unread_count))
+        .route("/notifications/read-all", post(mark_all_notifications_read))
+        .route(
+            "/notifications/{notification_id}/read",
+            post(mark_notification_read),
+        )
+}
@@ -0,0 +1,153 @@
+use axum::{
+    Extension, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    pending_start::PendingStart,
+    project::Project,
+    task::{Task, TaskStatus},
+};
+use deployment::Deployment;
+use services::services::container::ContainerService;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl, error::ApiError, middleware::AuthUser, routes::tasks::auto_start_task,
+};
+
+pub async fn list_pending_starts(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<PendingStart>>>, ApiError> {
+    let pending = PendingStart::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(pending)))
+}
+
+/// Take the project's pending start off the list, with its task
+async fn take_pending_start(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    pending_start_id: Uuid,
+) -> Result<Task, ApiError> {
+    let pool = &deployment.db().pool;
+    let pending = PendingStart::find_by_id(pool, pending_start_id)
+        .await?
+        .filter(|pending| pending.project_id == project.id)
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Pending start not found"))?;
+    // Whoever removes it first decides
+    if PendingStart::delete(pool, pending.id).await? == 0 {
+        return Err(ApiError::Conflict(
+            "Pending start was already approved or denied".to_string(),
+        ));
+    }
+    Task::find_by_id(pool, pending.task_id)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::TaskNotFound, "Task not found"))
+}
+
+/// Start the task's attempt as auto-start would have. A task that was moved
+/// out of In Progress while it waited is moved back.
+pub(crate) async fn approve(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    pending_start_id: Uuid,
+    approved_by: Option<Uuid>,
+) -> Result<Task, ApiError> {
+    let pool = &deployment.db().pool;
+    let mut task = take_pending_start(deployment, project, pending_start_id).await?;
+
+    if task.status != TaskStatus::InProgress {
+        Task::update_status(pool, task.id, TaskStatus::InProgress).await?;
+        task.status = TaskStatus::InProgress;
+    }
+    let has_running = deployment
+        .container()
+        .has_running_processes(task.id)
+        .await
+        .unwrap_or(false);
+    if !has_running {
+        auto_start_task(deployment, &task).await?;
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "pending_start_approved",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "approved_by": approved_by.map(|id| id.to_string()),
+            }),
+        )
+        .await;
+
+    Ok(task)
+}
+
+/// Drop the start and move the task back to To Do if nothing else started it
+pub(crate) async fn deny(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    pending_start_id: Uuid,
+    denied_by: Option<Uuid>,
+) -> Result<Task, ApiError> {
+    let pool = &deployment.db().pool;
+    let mut task = take_pending_start(deployment, project, pending_start_id).await?;
+
+    let has_running = deployment
+        .container()
+        .has_running_processes(task.id)
+        .await
+        .unwrap_or(true);
+    if task.status == TaskStatus::InProgress && !has_running {
+        Task::update_status(pool, task.id, TaskStatus::Todo).await?;
+        task.status = TaskStatus::Todo;
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "pending_start_denied",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "denied_by": denied_by.map(|id| id.to_string()),
+            }),
+        )
+        .await;
+
+    Ok(task)
+}
+
+pub async fn approve_pending_start(
+    auth: AuthUser,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, pending_start_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let task = approve(&deployment, &project, pending_start_id, Some(auth.id)).await?;
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
+
+pub async fn deny_pending_start(
+    auth: AuthUser,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, pending_start_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    let task = deny(&deployment, &project, pending_start_id, Some(auth.id)).await?;
+    Ok(ResponseJson(ApiResponse::success(task)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/pending-starts", get(list_pending_starts))
+        .route(
+            "/pending-starts/{pending_start_id}/approve",
+            post(approve_pending_start),
+        )
+        .route(
+            "/pending-starts/{pending_start_id}/deny",
+            post(deny_pending_start),
+        )
+}
@@ -0,0 +1,180 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, post, put},
+};
+use db::models::{
+    project::Project,
+    project_group::{
+        CreateProjectGroup, ProjectFavorite, ProjectGroup, ProjectPlacement, SetProjectPlacement,
+        UpdateProjectGroup,
+    },
+};
+use deployment::Deployment;
+use serde::Serialize;
+use ts_rs::TS;
+use utils::{
+    response::{ApiResponse, ErrorCode},
+    validation::Validate,
+};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::{AuthUser, OptionalAuth},
+};
+
+/// Everything the project list needs to render folders, ordering and stars
+#[derive(Debug, Serialize, TS)]
+pub struct ProjectLayout {
+    pub groups: Vec<ProjectGroup>,
+    pub placements: Vec<ProjectPlacement>,
+    pub favorite_project_ids: Vec<Uuid>,
+}
+
+async fn ensure_unique_name(
+    deployment: &DeploymentImpl,
+    name: &str,
+    exclude: Option<Uuid>,
+) -> Result<(), ApiError> {
+    let taken = ProjectGroup::find_all(&deployment.db().pool)
+        .await?
+        .iter()
+        .any(|group| Some(group.id) != exclude && group.name.eq_ignore_ascii_case(name));
+    if taken {
+        return Err(ApiError::Conflict(format!(
+            "A project group named '{name}' already exists"
+        )));
+    }
+    Ok(())
+}
+
+pub async fn list_groups(
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProjectGroup>>>, ApiError> {
+    let groups = ProjectGroup::find_all(&deployment.db().pool).await?;
+    Ok(ResponseJson(ApiResponse::success(groups)))
+}
+
+pub async fn create_group(
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateProjectGroup>,
+) -> Result<ResponseJson<ApiResponse<ProjectGroup>>, ApiError> {
+    payload.validate()?;
+    let name = payload.name.trim();
+    ensure_unique_name(&deployment, name, None).await?;
+    let group = ProjectGroup::create(&deployment.db().pool, name).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "project_group_created",
+            serde_json::json!({ "group_id": group.id.to_string() }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(group)))
+}
+
+pub async fn update_group(
+    State(deployment): State<DeploymentImpl>,
+    Path(group_id): Path<Uuid>,
+    Json(payload): Json<UpdateProjectGroup>,
+) -> Result<ResponseJson<ApiResponse<ProjectGroup>>, ApiError> {
+    payload.validate()?;
+    let pool = &deployment.db().pool;
+    let existing = ProjectGroup::find_by_id(pool, group_id)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Project group not found"))?;
+    if let Some(name) = &payload.name {
+        ensure_unique_name(&deployment, name.trim(), Some(existing.id)).await?;
+    }
+    let group = ProjectGroup::update(pool, &existing, &payload).await?;
+    Ok(ResponseJson(ApiResponse::success(group)))
+}
+
+pub async fn delete_group(
+    State(deployment): State<DeploymentImpl>,
+    Path(group_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = ProjectGroup::delete(&deployment.db().pool, group_id).await?;
+    if deleted == 0 {
+        return Err(ApiError::coded(
+            ErrorCode::NotFound,
+            "Project group not found",
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub async fn get_project_layout(
+    OptionalAuth(auth): OptionalAuth,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<ProjectLayout>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let favorite_project_ids = match auth {
+        Some(user) => ProjectFavorite::find_project_ids(pool, user.id).await?,
+        None => Vec::new(),
+    };
+    Ok(ResponseJson(ApiResponse::success(ProjectLayout {
+        groups: ProjectGroup::find_all(pool).await?,
+        placements: ProjectPlacement::find_all(pool).await?,
+        favorite_project_ids,
+    })))
+}
+
+pub async fn set_project_placement(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetProjectPlacement>,
+) -> Result<ResponseJson<ApiResponse<ProjectPlacement>>, ApiError> {
+    let pool = &deployment.db().pool;
+    if let Some(group_id) = payload.group_id
+        && ProjectGroup::find_by_id(pool, group_id).await?.is_none()
+    {
+        return Err(ApiError::coded(
+            ErrorCode::NotFound,
+            "Project group not found",
+        ));
+    }
+    let placement = ProjectPlacement::set(pool, project.id, &payload).await?;
+    Ok(ResponseJson(ApiResponse::success(placement)))
+}
+
+pub async fn favorite_project(
+    auth: AuthUser,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    ProjectFavorite::add(&deployment.db().pool, auth.id, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub async fn unfavorite_project(
+    auth: AuthUser,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    ProjectFavorite::remove(&deployment.db().pool, auth.id, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/project-groups", get(list_groups).post(create_group))
+        .route(
+            "/project-groups/{group_id}",
+            put(update_group).delete(delete_group),
+        )
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/placement", put(set_project_placement))
+        .route(
+            "/favorite",
+            post(favorite_project).delete(unfavorite_project),
+        )
+}
@@ -0,0 +1,189 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use db::models::{
+    project::Project,
+    project_instruction::{
+        CreateProjectInstruction, ProjectInstruction, ProjectInstructionError,
+        ProjectInstructionVersion, SetTaskInstruction, TaskInstruction, UpdateProjectInstruction,
+    },
+    task::Task,
+};
+use deployment::Deployment;
+use utils::{response::ApiResponse, validation::Validate};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Load an instruction, making sure it belongs to `project_id`
+async fn load_instruction(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    instruction_id: Uuid,
+) -> Result<ProjectInstruction, ApiError> {
+    ProjectInstruction::find_by_id(&deployment.db().pool, instruction_id)
+        .await?
+        .filter(|instruction| instruction.project_id == project_id)
+        .ok_or(ApiError::ProjectInstruction(
+            ProjectInstructionError::NotFound,
+        ))
+}
+
+pub async fn list_instructions(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProjectInstruction>>>, ApiError> {
+    let instructions =
+        ProjectInstruction::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(instructions)))
+}
+
+pub async fn create_instruction(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateProjectInstruction>,
+) -> Result<ResponseJson<ApiResponse<ProjectInstruction>>, ApiError> {
+    payload.validate()?;
+
+    let instruction =
+        ProjectInstruction::create(&deployment.db().pool, project.id, &payload).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "project_instruction_created",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "instruction_id": instruction.id.to_string(),
+                "is_default": instruction.is_default,
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(instruction)))
+}
+
+pub async fn get_instruction(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, instruction_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<ProjectInstruction>>, ApiError> {
+    let instruction = load_instruction(&deployment, project.id, instruction_id).await?;
+    Ok(ResponseJson(ApiResponse::success(instruction)))
+}
+
+pub async fn update_instruction(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, instruction_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateProjectInstruction>,
+) -> Result<ResponseJson<ApiResponse<ProjectInstruction>>, ApiError> {
+    payload.validate()?;
+
+    let existing = load_instruction(&deployment, project.id, instruction_id).await?;
+    let instruction =
+        ProjectInstruction::update(&deployment.db().pool, existing.id, &payload).await?;
+
+    if instruction.current_version != existing.current_version {
+        deployment
+            .track_if_analytics_allowed(
+                "project_instruction_versioned",
+                serde_json::json!({
+                    "project_id": project.id.to_string(),
+                    "instruction_id": instruction.id.to_string(),
+                    "version": instruction.current_version,
+                }),
+            )
+            .await;
+    }
+
+    Ok(ResponseJson(ApiResponse::success(instruction)))
+}
+
+pub async fn delete_instruction(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, instruction_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let instruction = load_instruction(&deployment, project.id, instruction_id).await?;
+    ProjectInstruction::delete(&deployment.db().pool, instruction.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub async fn list_instruction_versions(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, instruction_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProjectInstructionVersion>>>, ApiError> {
+    let instruction = load_instruction(&deployment, project.id, instruction_id).await?;
+    let versions = ProjectInstruction::find_versions(&deployment.db().pool, instruction.id).await?;
+    Ok(ResponseJson(ApiResponse::success(versions)))
+}
+
+pub async fn get_task_instruction(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TaskInstruction>>>, ApiError> {
+    let selection = TaskInstruction::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(selection)))
+}
+
+pub async fn set_task_instruction(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskInstruction>,
+) -> Result<ResponseJson<ApiResponse<TaskInstruction>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let instruction =
+        load_instruction(&deployment, task.project_id, payload.instruction_id).await?;
+
+    if let Some(version) = payload.version
+        && ProjectInstruction::find_version(pool, instruction.id, version)
+            .await?
+            .is_none()
+    {
+        return Err(ProjectInstructionError::VersionNotFound(version).into());
+    }
+
+    let selection = TaskInstruction::set(pool, task.id, &payload).await?;
+    Ok(ResponseJson(ApiResponse::success(selection)))
+}
+
+pub async fn clear_task_instruction(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    TaskInstruction::clear(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/instructions",
+            get(list_instructions).post(create_instruction),
+        )
+        .route(
+            "/instructions/{instruction_id}",
+            get(get_instruction)
+                .put(update_instruction)
+                .delete(delete_instruction),
+        )
+        .route(
+            "/instructions/{instruction_id}/versions",
+            get(list_instruction_versions),
+        )
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/instruction",
+        get(get_task_instruction)
+            .put(set_task_instruction)
+            .delete(clear_task_instruction),
+    )
+}
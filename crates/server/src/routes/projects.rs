@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use anyhow;
 use axum::{
@@ -13,9 +13,14 @@ use axum::{
     routing::{get, post},
 };
 use db::models::{
-    project::{CreateProject, Project, ProjectError, SearchResult, UpdateProject},
+    project::{
+        CreateProject, CreateProjectFromTemplate, DuplicateProject, Project, ProjectError,
+        SearchResult, UpdateProject,
+    },
+    project_group::{ProjectFavorite, ProjectPlacement},
     project_repo::{CreateProjectRepo, ProjectRepo, UpdateProjectRepo},
     repo::Repo,
+    repo_health::RepoHealth,
 };
 use deployment::Deployment;
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
@@ -27,15 +32,22 @@ use services::services::{
 use ts_rs::TS;
 use utils::{
     api::projects::{RemoteProject, RemoteProjectMembersResponse},
-    response::ApiResponse,
+    response::{ApiResponse, ErrorCode},
+    validation::Validate,
 };
 use uuid::Uuid;
 
 use crate::{
     DeploymentImpl,
     error::ApiError,
-    middleware::load_project_middleware,
-    routes::{github_issues, gitlab_issues, vortex_issues},
+    middleware::{OptionalAuth, load_project_middleware},
+    routes::{
+        bitbucket_issues, calendar, capacity, diff_exclusions, email_intake, executor_slots,
+        gitea_issues, github_issues, gitlab_issues, issue_connections, issue_sync,
+        maintenance_jobs, pending_starts, project_groups, project_instructions, quick_add, sla,
+        status_page, status_transitions, task_drafts, task_groups, task_labels, timeline,
+        token_budgets, vortex_issues, vortex_oauth, webhooks,
+    },
 };
 
 #[derive(Deserialize, TS)]
@@ -49,10 +61,43 @@ pub struct CreateRemoteProjectRequest {
     pub name: String,
 }
 
+#[derive(Debug, Deserialize, TS)]
+pub struct ProjectListQuery {
+    /// A project group id, `ungrouped`, or `favorites`
+    pub group: Option<String>,
+}
+
 pub async fn get_projects(
+    OptionalAuth(auth): OptionalAuth,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ProjectListQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<Project>>>, ApiError> {
-    let projects = Project::find_all(&deployment.db().pool).await?;
+    let pool = &deployment.db().pool;
+    let mut projects = Project::find_all(pool).await?;
+    let placements: HashMap<Uuid, ProjectPlacement> = ProjectPlacement::find_all(pool)
+        .await?
+        .into_iter()
+        .map(|placement| (placement.project_id, placement))
+        .collect();
+    let group_of = |project: &Project| placements.get(&project.id).and_then(|p| p.group_id);
+
+    match query.group.as_deref() {
+        None | Some("") => {}
+        Some("favorites") => {
+            let user = auth.ok_or(ApiError::Unauthorized)?;
+            let favorites = ProjectFavorite::find_project_ids(pool, user.id).await?;
+            projects.retain(|project| favorites.contains(&project.id));
+        }
+        Some("ungrouped") => projects.retain(|project| group_of(project).is_none()),
+        Some(group) => {
+            let group_id = Uuid::parse_str(group)
+                .map_err(|_| ApiError::BadRequest(format!("Invalid project group: {group}")))?;
+            projects.retain(|project| group_of(project) == Some(group_id));
+        }
+    }
+
+    // Stable sort keeps the default order among projects sharing a position
+    projects.sort_by_key(|project| placements.get(&project.id).map_or(0, |p| p.position));
     Ok(ResponseJson(ApiResponse::success(projects)))
 }
 
@@ -224,6 +269,7 @@ pub async fn create_project(
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateProject>,
 ) -> Result<ResponseJson<ApiResponse<Project>>, ApiError> {
+    payload.validate()?;
     tracing::debug!("Creating project '{}'", payload.name);
     let repo_count = payload.repositories.len();
 
@@ -266,20 +312,136 @@ pub async fn create_project(
     }
 }
 
+pub async fn create_project_from_template(
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateProjectFromTemplate>,
+) -> Result<ResponseJson<ApiResponse<Project>>, ApiError> {
+    payload.validate()?;
+    let pool = &deployment.db().pool;
+    let template = Project::find_by_id(pool, payload.template_project_id)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::ProjectNotFound, "Template project not found"))?;
+
+    match deployment
+        .project()
+        .create_from_template(pool, deployment.repo(), &template, payload)
+        .await
+    {
+        Ok(project) => {
+            deployment
+                .track_if_analytics_allowed(
+                    "project_created",
+                    serde_json::json!({
+                        "project_id": project.id.to_string(),
+                        "template_project_id": template.id.to_string(),
+                        "trigger": "template",
+                    }),
+                )
+                .await;
+
+            Ok(ResponseJson(ApiResponse::success(project)))
+        }
+        Err(ProjectServiceError::DuplicateGitRepoPath) => Ok(ResponseJson(ApiResponse::error(
+            "Duplicate repository path provided",
+        ))),
+        Err(ProjectServiceError::DuplicateRepositoryName) => Ok(ResponseJson(ApiResponse::error(
+            "Duplicate repository name provided",
+        ))),
+        Err(ProjectServiceError::PathNotFound(_)) => Ok(ResponseJson(ApiResponse::error(
+            "The specified path does not exist",
+        ))),
+        Err(ProjectServiceError::PathNotDirectory(_)) => Ok(ResponseJson(ApiResponse::error(
+            "The specified path is not a directory",
+        ))),
+        Err(ProjectServiceError::NotGitRepository(_)) => Ok(ResponseJson(ApiResponse::error(
+            "The specified directory is not a git repository",
+        ))),
+        Err(e) => Err(ProjectError::CreateFailed(e.to_string()).into()),
+    }
+}
+
+pub async fn duplicate_project(
+    Extension(source): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<DuplicateProject>,
+) -> Result<ResponseJson<ApiResponse<Project>>, ApiError> {
+    payload.validate()?;
+    let open_tasks_only = payload.open_tasks_only;
+    let include_images = payload.include_images;
+    let remapped_repos = payload.repo_remap.len();
+
+    match deployment
+        .project()
+        .duplicate_project(&deployment.db().pool, deployment.repo(), &source, payload)
+        .await
+    {
+        Ok(project) => {
+            deployment
+                .track_if_analytics_allowed(
+                    "project_created",
+                    serde_json::json!({
+                        "project_id": project.id.to_string(),
+                        "source_project_id": source.id.to_string(),
+                        "open_tasks_only": open_tasks_only,
+                        "include_images": include_images,
+                        "remapped_repos": remapped_repos,
+                        "trigger": "duplicate",
+                    }),
+                )
+                .await;
+
+            Ok(ResponseJson(ApiResponse::success(project)))
+        }
+        Err(ProjectServiceError::DuplicateGitRepoPath) => Ok(ResponseJson(ApiResponse::error(
+            "Duplicate repository path provided",
+        ))),
+        Err(ProjectServiceError::DuplicateRepositoryName) => Ok(ResponseJson(ApiResponse::error(
+            "Duplicate repository name provided",
+        ))),
+        Err(ProjectServiceError::PathNotFound(_)) => Ok(ResponseJson(ApiResponse::error(
+            "The specified path does not exist",
+        ))),
+        Err(ProjectServiceError::PathNotDirectory(_)) => Ok(ResponseJson(ApiResponse::error(
+            "The specified path is not a directory",
+        ))),
+        Err(ProjectServiceError::NotGitRepository(_)) => Ok(ResponseJson(ApiResponse::error(
+            "The specified directory is not a git repository",
+        ))),
+        Err(e) => Err(ProjectError::CreateFailed(e.to_string()).into()),
+    }
+}
+
 pub async fn update_project(
     Extension(existing_project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<UpdateProject>,
-) -> Result<ResponseJson<ApiResponse<Project>>, StatusCode> {
+) -> Result<ResponseJson<ApiResponse<Project>>, ApiError> {
+    payload.validate()?;
     match deployment
         .project()
         .update_project(&deployment.db().pool, &existing_project, payload)
         .await
     {
-        Ok(project) => Ok(ResponseJson(ApiResponse::success(project))),
+        Ok(update) => {
+            if !update.changed_fields.is_empty() {
+                deployment
+                    .track_if_analytics_allowed(
+                        "project_updated",
+                        serde_json::json!({
+                            "project_id": update.project.id.to_string(),
+                            "changed_fields": update.changed_fields,
+                        }),
+                    )
+                    .await;
+            }
+            Ok(ResponseJson(ApiResponse::success(update.project)))
+        }
         Err(e) => {
             tracing::error!("Failed to update project: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+            Err(ApiError::coded(
+                ErrorCode::Internal,
+                "Failed to update project",
+            ))
         }
     }
 }
@@ -436,11 +598,22 @@ pub async fn get_project_repositories(
     Ok(ResponseJson(ApiResponse::success(repositories)))
 }
 
+/// The project's repos whose checkouts are missing or no longer git
+/// repositories, as of their last check
+pub async fn get_broken_repositories(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<RepoHealth>>>, ApiError> {
+    let broken = RepoHealth::find_broken_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(broken)))
+}
+
 pub async fn add_project_repository(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateProjectRepo>,
 ) -> Result<ResponseJson<ApiResponse<Repo>>, ApiError> {
+    payload.validate()?;
     tracing::debug!(
         "Adding repository '{}' to project {} (path: {})",
         payload.display_name,
@@ -566,8 +739,9 @@ pub async fn get_project_repository(
 ) -> Result<ResponseJson<ApiResponse<ProjectRepo>>, ApiError> {
     match ProjectRepo::find_by_project_and_repo(&deployment.db().pool, project_id, repo_id).await {
         Ok(Some(project_repo)) => Ok(ResponseJson(ApiResponse::success(project_repo))),
-        Ok(None) => Err(ApiError::BadRequest(
-            "Repository not found in project".to_string(),
+        Ok(None) => Err(ApiError::coded(
+            ErrorCode::RepoNotFound,
+            "Repository not found in project",
         )),
         Err(e) => Err(e.into()),
     }
@@ -580,8 +754,9 @@ pub async fn update_project_repository(
 ) -> Result<ResponseJson<ApiResponse<ProjectRepo>>, ApiError> {
     match ProjectRepo::update(&deployment.db().pool, project_id, repo_id, &payload).await {
         Ok(project_repo) => Ok(ResponseJson(ApiResponse::success(project_repo))),
-        Err(db::models::project_repo::ProjectRepoError::NotFound) => Err(ApiError::BadRequest(
-            "Repository not found in project".to_string(),
+        Err(db::models::project_repo::ProjectRepoError::NotFound) => Err(ApiError::coded(
+            ErrorCode::RepoNotFound,
+            "Repository not found in project",
         )),
         Err(e) => Err(e.into()),
     }
@@ -601,13 +776,39 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
             post(link_project_to_existing_remote).delete(unlink_project),
         )
         .route("/link/create", post(create_and_link_remote_project))
+        .route("/duplicate", post(duplicate_project))
         .route(
             "/repositories",
             get(get_project_repositories).post(add_project_repository),
         )
+        .route("/repositories/broken", get(get_broken_repositories))
         .merge(github_issues::router())
         .merge(gitlab_issues::router())
+        .merge(gitea_issues::router())
+        .merge(bitbucket_issues::router())
+        .merge(issue_connections::router())
         .merge(vortex_issues::router())
+        .merge(vortex_oauth::project_router())
+        .merge(issue_sync::project_router())
+        .merge(timeline::project_router())
+        .merge(capacity::project_router())
+        .merge(sla::project_router())
+        .merge(task_labels::project_router())
+        .merge(token_budgets::project_router())
+        .merge(maintenance_jobs::project_router())
+        .merge(executor_slots::project_router())
+        .merge(pending_starts::project_router())
+        .merge(task_drafts::project_router())
+        .merge(task_groups::project_router())
+        .merge(quick_add::project_router())
+        .merge(project_instructions::router())
+        .merge(project_groups::project_router())
+        .merge(status_transitions::project_router())
+        .merge(webhooks::project_router())
+        .merge(email_intake::project_router())
+        .merge(calendar::project_router())
+        .merge(status_page::project_router())
+        .merge(diff_exclusions::project_router())
         .layer(from_fn_with_state(
             deployment.clone(),
             load_project_middleware,
@@ -622,6 +823,8 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
                 .delete(delete_project_repository),
         )
         .route("/stream/ws", get(stream_projects_ws))
+        .route("/layout", get(project_groups::get_project_layout))
+        .route("/from-template", post(create_project_from_template))
         .nest("/{id}", project_id_router);
 
     Router::new().nest("/projects", projects_router).route(
@@ -0,0 +1,119 @@
+use axum::{
+    Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::post,
+};
+use chrono::Local;
+use db::models::{
+    project::Project,
+    task::{CreateTask, ExecutionMode, Task},
+    task_label::TaskLabel,
+    task_owner::TaskOwner,
+    task_schedule::{SetTaskSchedule, TaskSchedule},
+    user::User,
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::quick_add::{QuickAdd, parse_quick_add};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl, error::ApiError, middleware::OptionalAuth, routes::tasks::check_task_validation,
+};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct QuickAddRequest {
+    /// e.g. `Fix login bug #bug !high @alice due:fri seq`
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct QuickAddResponse {
+    pub task: Task,
+    /// How the line was read
+    pub parsed: QuickAdd,
+}
+
+/// Create a task in `project` from a quick-add line, also used by the Slack
+/// `create` command
+pub(crate) async fn create_quick_add_task(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    creator_id: Option<Uuid>,
+    text: &str,
+) -> Result<QuickAddResponse, ApiError> {
+    let pool = &deployment.db().pool;
+    let parsed = parse_quick_add(text, Local::now().date_naive())
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    // Resolve the assignee before anything is created
+    let assignee = match &parsed.assignee {
+        Some(username) => Some(
+            User::find_by_username(pool, username)
+                .await?
+                .ok_or_else(|| ApiError::BadRequest(format!("No user named '{username}'")))?,
+        ),
+        None => None,
+    };
+    check_task_validation(deployment, &parsed.title, None).await?;
+
+    let create = CreateTask::from_title_description(project.id, parsed.title.clone(), None);
+    let task = Task::create(pool, &create, Uuid::new_v4()).await?;
+
+    if let Some(creator_id) = creator_id {
+        TaskOwner::set_creator(pool, task.id, creator_id).await?;
+    }
+    if let Some(assignee) = &assignee {
+        TaskOwner::set_assignee(pool, task.id, Some(assignee.id)).await?;
+    }
+    let mut labels = parsed.labels.clone();
+    labels.extend(parsed.priority.map(|priority| priority.label()));
+    if !labels.is_empty() {
+        TaskLabel::set(pool, task.id, &labels).await?;
+    }
+    if let Some(due_at) = parsed.due_at() {
+        let schedule = SetTaskSchedule {
+            starts_at: None,
+            due_at: Some(due_at),
+        };
+        TaskSchedule::set(pool, task.id, &schedule).await?;
+    }
+    if parsed.execution_mode == Some(ExecutionMode::Sequential) {
+        Task::add_to_queue(pool, task.id, task.project_id).await?;
+    }
+    // Re-fetch for the queue position
+    let task = Task::find_by_id(pool, task.id).await?.unwrap_or(task);
+
+    deployment
+        .track_if_analytics_allowed(
+            "task_quick_added",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "project_id": project.id.to_string(),
+                "labels": parsed.labels.len(),
+                "has_priority": parsed.priority.is_some(),
+                "has_assignee": assignee.is_some(),
+                "has_due_date": parsed.due_date.is_some(),
+            }),
+        )
+        .await;
+
+    Ok(QuickAddResponse { task, parsed })
+}
+
+/// Create a fully specified task from a single line
+pub async fn quick_add_task(
+    OptionalAuth(auth): OptionalAuth,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<QuickAddRequest>,
+) -> Result<ResponseJson<ApiResponse<QuickAddResponse>>, ApiError> {
+    let creator_id = auth.map(|user| user.id);
+    let response = create_quick_add_task(&deployment, &project, creator_id, &payload.text).await?;
+    Ok(ResponseJson(ApiResponse::success(response)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route("/tasks/quick-add", post(quick_add_task))
+}
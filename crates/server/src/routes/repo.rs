@@ -2,14 +2,18 @@ use axum::{
     Router,
     extract::{Path, State},
     response::Json as ResponseJson,
-    routing::{get, post},
+    routing::{delete, get, post},
+};
+use db::models::{
+    managed_branch::ManagedBranch,
+    repo::Repo,
+    repo_path_rule::{CreateRepoPathRule, RepoPathRule},
 };
-use db::models::repo::Repo;
 use deployment::Deployment;
-use serde::Deserialize;
-use services::services::git::GitBranch;
+use serde::{Deserialize, Serialize};
+use services::services::{git::GitBranch, path_rules};
 use ts_rs::TS;
-use utils::response::ApiResponse;
+use utils::response::{ApiResponse, ErrorCode};
 use uuid::Uuid;
 
 use crate::{DeploymentImpl, error::ApiError};
@@ -28,6 +32,20 @@ pub struct InitRepoRequest {
     pub folder_name: String,
 }
 
+#[derive(Debug, Deserialize, TS)]
+pub struct RelocateRepoRequest {
+    /// Where the repository's checkout lives now
+    pub path: String,
+}
+
+/// A branch vibe-kanban created in the repo, and whether it is still there
+#[derive(Debug, Serialize, TS)]
+pub struct ManagedBranchStatus {
+    #[serde(flatten)]
+    pub branch: ManagedBranch,
+    pub exists: bool,
+}
+
 pub async fn register_repo(
     State(deployment): State<DeploymentImpl>,
     ResponseJson(payload): ResponseJson<RegisterRepoRequest>,
@@ -61,6 +79,32 @@ pub async fn init_repo(
     Ok(ResponseJson(ApiResponse::success(repo)))
 }
 
+/// Point a repo whose checkout was moved at its new location
+pub async fn relocate_repo(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+    ResponseJson(payload): ResponseJson<RelocateRepoRequest>,
+) -> Result<ResponseJson<ApiResponse<Repo>>, ApiError> {
+    let repo = deployment
+        .repo()
+        .relocate(
+            &deployment.db().pool,
+            deployment.git(),
+            repo_id,
+            &payload.path,
+        )
+        .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "repo_relocated",
+            serde_json::json!({ "repo_id": repo.id.to_string() }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(repo)))
+}
+
 pub async fn get_repo_branches(
     State(deployment): State<DeploymentImpl>,
     Path(repo_id): Path<Uuid>,
@@ -74,9 +118,95 @@ pub async fn get_repo_branches(
     Ok(ResponseJson(ApiResponse::success(branches)))
 }
 
+pub async fn get_managed_branches(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<Vec<ManagedBranchStatus>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let repo = deployment.repo().get_by_id(pool, repo_id).await?;
+
+    let branches = ManagedBranch::find_by_repo_id(pool, repo.id)
+        .await?
+        .into_iter()
+        .map(|branch| ManagedBranchStatus {
+            exists: branch.deleted_at.is_none()
+                && deployment
+                    .git()
+                    .check_branch_exists(&repo.path, &branch.branch_name)
+                    .unwrap_or(false),
+            branch,
+        })
+        .collect();
+    Ok(ResponseJson(ApiResponse::success(branches)))
+}
+
+pub async fn list_path_rules(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<Vec<RepoPathRule>>>, ApiError> {
+    let rules = RepoPathRule::find_by_repo_id(&deployment.db().pool, repo_id).await?;
+    Ok(ResponseJson(ApiResponse::success(rules)))
+}
+
+pub async fn create_path_rule(
+    State(deployment): State<DeploymentImpl>,
+    Path(repo_id): Path<Uuid>,
+    ResponseJson(payload): ResponseJson<CreateRepoPathRule>,
+) -> Result<ResponseJson<ApiResponse<RepoPathRule>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let repo = deployment.repo().get_by_id(pool, repo_id).await?;
+
+    let pattern = payload.pattern.trim();
+    if pattern.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Path pattern cannot be empty".to_string(),
+        ));
+    }
+    path_rules::compile_pattern(pattern)?;
+
+    let rule = RepoPathRule::create(pool, repo.id, &payload).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "repo_path_rule_created",
+            serde_json::json!({
+                "repo_id": repo.id.to_string(),
+                "kind": rule.kind.to_string(),
+                "action": rule.action.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(rule)))
+}
+
+pub async fn delete_path_rule(
+    State(deployment): State<DeploymentImpl>,
+    Path((repo_id, rule_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = RepoPathRule::delete(&deployment.db().pool, repo_id, rule_id).await?;
+    if deleted == 0 {
+        return Err(ApiError::coded(ErrorCode::NotFound, "Path rule not found"));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
 pub fn router() -> Router<DeploymentImpl> {
     Router::new()
         .route("/repos", post(register_repo))
         .route("/repos/init", post(init_repo))
+        .route("/repos/{repo_id}/relocate", post(relocate_repo))
         .route("/repos/{repo_id}/branches", get(get_repo_branches))
+        .route(
+            "/repos/{repo_id}/managed-branches",
+            get(get_managed_branches),
+        )
+        .route(
+            "/repos/{repo_id}/path-rules",
+            get(list_path_rules).post(create_path_rule),
+        )
+        .route(
+            "/repos/{repo_id}/path-rules/{rule_id}",
+            delete(delete_path_rule),
+        )
 }
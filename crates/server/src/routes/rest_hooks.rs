@@ -0,0 +1,86 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use db::models::{
+    project::Project,
+    rest_hook_subscription::{CreateRestHookSubscription, RestHookSubscription},
+};
+use deployment::Deployment;
+use services::services::rest_hooks::RestHookService;
+use utils::{
+    response::{ApiResponse, ErrorCode},
+    validation::Validate,
+};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+pub async fn list_rest_hooks(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<RestHookSubscription>>>, ApiError> {
+    let subscriptions = RestHookSubscription::find_all(&deployment.db().pool).await?;
+    Ok(ResponseJson(ApiResponse::success(subscriptions)))
+}
+
+/// Subscribe a target URL to an event. The target is sent a verification POST
+/// first and must answer with a 2xx status.
+pub async fn subscribe_rest_hook(
+    RequireAdmin(user): RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateRestHookSubscription>,
+) -> Result<ResponseJson<ApiResponse<RestHookSubscription>>, ApiError> {
+    payload.validate()?;
+    let pool = &deployment.db().pool;
+    if let Some(project_id) = payload.project_id
+        && Project::find_by_id(pool, project_id).await?.is_none()
+    {
+        return Err(ApiError::coded(
+            ErrorCode::ProjectNotFound,
+            "Project not found",
+        ));
+    }
+
+    RestHookService::new(deployment.db().clone())
+        .verify_target(&payload.target_url, payload.event)
+        .await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    let subscription = RestHookSubscription::create(pool, &payload, Some(user.id)).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "rest_hook_subscribed",
+            serde_json::json!({
+                "event": payload.event,
+                "project_scoped": payload.project_id.is_some(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(subscription)))
+}
+
+pub async fn unsubscribe_rest_hook(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Path(id): Path<Uuid>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = RestHookSubscription::delete(&deployment.db().pool, id).await?;
+    if deleted == 0 {
+        return Err(ApiError::coded(
+            ErrorCode::NotFound,
+            "Subscription not found",
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/hooks", get(list_rest_hooks).post(subscribe_rest_hook))
+        .route("/hooks/{id}", delete(unsubscribe_rest_hook))
+}
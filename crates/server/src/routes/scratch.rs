@@ -11,7 +11,7 @@ use db::models::scratch::{CreateScratch, Scratch, ScratchType, UpdateScratch};
 use deployment::Deployment;
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use serde::Deserialize;
-use utils::response::ApiResponse;
+use utils::response::{ApiResponse, ErrorCode};
 use uuid::Uuid;
 
 use crate::{DeploymentImpl, error::ApiError};
@@ -36,7 +36,7 @@ pub async fn get_scratch(
 ) -> Result<ResponseJson<ApiResponse<Scratch>>, ApiError> {
     let scratch = Scratch::find_by_id(&deployment.db().pool, id, &scratch_type)
         .await?
-        .ok_or_else(|| ApiError::BadRequest("Scratch not found".to_string()))?;
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Scratch not found"))?;
     Ok(ResponseJson(ApiResponse::success(scratch)))
 }
 
@@ -49,8 +49,9 @@ pub async fn create_scratch(
     if matches!(scratch_type, ScratchType::DraftFollowUp)
         && deployment.queued_message_service().has_queued(id)
     {
-        return Err(ApiError::BadRequest(
-            "Cannot edit scratch while a message is queued".to_string(),
+        return Err(ApiError::coded(
+            ErrorCode::MessageQueued,
+            "Cannot edit scratch while a message is queued",
         ));
     }
 
@@ -73,8 +74,9 @@ pub async fn update_scratch(
     if matches!(scratch_type, ScratchType::DraftFollowUp)
         && deployment.queued_message_service().has_queued(id)
     {
-        return Err(ApiError::BadRequest(
-            "Cannot edit scratch while a message is queued".to_string(),
+        return Err(ApiError::coded(
+            ErrorCode::MessageQueued,
+            "Cannot edit scratch while a message is queued",
         ));
     }
 
@@ -95,7 +97,7 @@ pub async fn delete_scratch(
 ) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
     let rows = Scratch::delete(&deployment.db().pool, id, &scratch_type).await?;
     if rows == 0 {
-        return Err(ApiError::BadRequest("Scratch not found".to_string()));
+        return Err(ApiError::coded(ErrorCode::NotFound, "Scratch not found"));
     }
     Ok(ResponseJson(ApiResponse::success(())))
 }
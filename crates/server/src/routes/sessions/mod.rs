@@ -8,6 +8,7 @@ use axum::{
     routing::{get, post},
 };
 use db::models::{
+    diff_comment::DiffComment,
     execution_process::{ExecutionProcess, ExecutionProcessRunReason},
     project_repo::ProjectRepo,
     scratch::{Scratch, ScratchType},
@@ -29,8 +30,10 @@ use utils::response::ApiResponse;
 use uuid::Uuid;
 
 use crate::{
-    DeploymentImpl, error::ApiError, middleware::load_session_middleware,
-    routes::task_attempts::util::restore_worktrees_to_process,
+    DeploymentImpl,
+    error::ApiError,
+    middleware::load_session_middleware,
+    routes::task_attempts::{diff_comments, util::restore_worktrees_to_process},
 };
 
 #[derive(Debug, Deserialize)]
@@ -92,6 +95,8 @@ pub struct CreateFollowUpAttempt {
     pub retry_process_id: Option<Uuid>,
     pub force_when_dirty: Option<bool>,
     pub perform_git_reset: Option<bool>,
+    /// Append the workspace's unresolved diff comments and resolve them once sent
+    pub include_diff_comments: Option<bool>,
 }
 
 pub async fn follow_up(
@@ -174,7 +179,19 @@ pub async fn follow_up(
     let latest_agent_session_id =
         ExecutionProcess::find_latest_coding_agent_turn_session_id(pool, session.id).await?;
 
-    let prompt = payload.prompt;
+    let mut prompt = payload.prompt;
+    let mut sent_comment_ids = Vec::new();
+    if payload.include_diff_comments.unwrap_or(false) {
+        let (review, ids) = diff_comments::review_prompt(&deployment, &workspace).await?;
+        if let Some(review) = review {
+            prompt = if prompt.trim().is_empty() {
+                review
+            } else {
+                format!("{prompt}\n\n{review}")
+            };
+            sent_comment_ids = ids;
+        }
+    }
 
     let project_repos = ProjectRepo::find_by_project_id_with_names(pool, project.id).await?;
     let cleanup_action = deployment
@@ -216,6 +233,14 @@ pub async fn follow_up(
         )
         .await?;
 
+    if let Err(e) = DiffComment::resolve_many(pool, &sent_comment_ids).await {
+        tracing::warn!(
+            "Failed to resolve diff comments for workspace {}: {}",
+            workspace.id,
+            e
+        );
+    }
+
     // Clear the draft follow-up scratch on successful spawn
     // This ensures the scratch is wiped even if the user navigates away quickly
     if let Err(e) = Scratch::delete(pool, session.id, &ScratchType::DraftFollowUp).await {
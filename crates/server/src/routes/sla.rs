@@ -0,0 +1,80 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use db::models::{
+    project::Project,
+    sla_rule::{CreateSlaRule, SlaRule},
+    task_sla_breach::TaskSlaBreach,
+};
+use deployment::Deployment;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+pub async fn list_sla_rules(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<SlaRule>>>, ApiError> {
+    let rules = SlaRule::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(rules)))
+}
+
+pub async fn create_sla_rule(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateSlaRule>,
+) -> Result<ResponseJson<ApiResponse<SlaRule>>, ApiError> {
+    if !payload.threshold_hours.is_finite() || payload.threshold_hours <= 0.0 {
+        return Err(ApiError::BadRequest(
+            "Threshold must be a positive number of hours".to_string(),
+        ));
+    }
+    let rule = SlaRule::create(&deployment.db().pool, project.id, &payload).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "project_sla_rule_created",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "kind": rule.kind.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(rule)))
+}
+
+pub async fn delete_sla_rule(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, rule_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    if SlaRule::delete(&deployment.db().pool, project.id, rule_id).await? == 0 {
+        return Err(ApiError::coded(ErrorCode::NotFound, "SLA rule not found"));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Breaches that are still open, i.e. the tasks currently breaching an SLA
+pub async fn list_sla_breaches(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskSlaBreach>>>, ApiError> {
+    let breaches =
+        TaskSlaBreach::find_open_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(breaches)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/sla-rules", get(list_sla_rules).post(create_sla_rule))
+        .route("/sla-rules/{rule_id}", delete(delete_sla_rule))
+        .route("/sla-breaches", get(list_sla_breaches))
+}
@@ -0,0 +1,309 @@
+use axum::{
+    Json, Router,
+    body::Bytes,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::Json as ResponseJson,
+    routing::{delete, get, post},
+};
+use chrono::Utc;
+use db::models::{
+    pending_start::PendingStart,
+    project::Project,
+    project_webhook::WebhookProvider,
+    slack_user_link::{SetSlackUserLink, SlackUserLink},
+    task::{Task, TaskWithAttemptStatus},
+    user::User,
+};
+use deployment::Deployment;
+use services::services::slack::{
+    PendingStartAction, PendingStartDecision, SlackCommand, SlackMessage, SlashCommand,
+    help_message, mention, parse_command, parse_interaction, parse_slash_command,
+    pending_starts_message, send_response, slack_signature_matches, status_message,
+};
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::RequireAdmin,
+    routes::{pending_starts, quick_add::create_quick_add_task, webhooks::authenticated_projects},
+};
+
+/// Tasks listed by one `status` reply
+const MAX_STATUS_TASKS: usize = 5;
+
+/// Projects whose Slack signing secret signed the request
+async fn slack_projects(
+    deployment: &DeploymentImpl,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<Vec<Project>, ApiError> {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    let (Some(timestamp), Some(signature)) = (
+        header("X-Slack-Request-Timestamp"),
+        header("X-Slack-Signature"),
+    ) else {
+        return Err(ApiError::Unauthorized);
+    };
+    let now = Utc::now().timestamp();
+    authenticated_projects(deployment, WebhookProvider::Slack, |stored| {
+        slack_signature_matches(stored, timestamp, signature, body, now)
+    })
+    .await
+}
+
+/// The local user an admin linked to the Slack user, if any. Anyone else acts
+/// only as their Slack identity.
+async fn local_user_id(
+    deployment: &DeploymentImpl,
+    slack_user_id: Option<&str>,
+) -> Result<Option<Uuid>, ApiError> {
+    let Some(slack_user_id) = slack_user_id.filter(|id| !id.is_empty()) else {
+        return Ok(None);
+    };
+    Ok(SlackUserLink::find_user_id(&deployment.db().pool, slack_user_id).await?)
+}
+
+/// Slack shows a generic failure for error responses, so errors are replied
+/// as messages instead
+fn error_message(error: ApiError) -> SlackMessage {
+    match error {
+        ApiError::BadRequest(message) | ApiError::Conflict(message) => {
+            SlackMessage::ephemeral(message)
+        }
+        ApiError::Coded(coded) if coded.code == ErrorCode::NotFound => {
+            SlackMessage::ephemeral(coded.message)
+        }
+        error => {
+            tracing::error!("Slack request failed: {}", error);
+            SlackMessage::ephemeral("Something went wrong; see the server logs for details")
+        }
+    }
+}
+
+/// Tasks of the projects whose id is `query` or whose title contains it. Exact
+/// title matches win over partial ones.
+async fn find_tasks(
+    deployment: &DeploymentImpl,
+    projects: &[Project],
+    query: &str,
+) -> Result<Vec<TaskWithAttemptStatus>, ApiError> {
+    let pool = &deployment.db().pool;
+    if let Ok(task_id) = query.parse::<Uuid>() {
+        for project in projects {
+            if let Some(task) =
+                Task::find_by_id_with_attempt_status(pool, project.id, task_id).await?
+            {
+                return Ok(vec![task]);
+            }
+        }
+        return Ok(Vec::new());
+    }
+
+    let query = query.to_lowercase();
+    let mut matches = Vec::new();
+    for project in projects {
+        let tasks = Task::find_by_project_id_with_attempt_status(pool, project.id).await?;
+        matches.extend(
+            tasks
+                .into_iter()
+                .filter(|task| task.title.to_lowercase().contains(&query)),
+        );
+    }
+    if matches
+        .iter()
+        .any(|task| task.title.to_lowercase() == query)
+    {
+        matches.retain(|task| task.title.to_lowercase() == query);
+    }
+    matches.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    Ok(matches)
+}
+
+async fn run_command(
+    deployment: &DeploymentImpl,
+    projects: &[Project],
+    command: &SlashCommand,
+) -> Result<SlackMessage, ApiError> {
+    match parse_command(&command.text) {
+        SlackCommand::Create(text) => {
+            let project = match projects {
+                [project] => project,
+                _ => {
+                    let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+                    return Err(ApiError::BadRequest(format!(
+                        "This Slack app is connected to several projects ({}), so it can't tell \
+                         which to create the task in; give each project its own Slack app",
+                        names.join(", ")
+                    )));
+                }
+            };
+            let creator_id = local_user_id(deployment, Some(&command.user_id)).await?;
+            let created = create_quick_add_task(deployment, project, creator_id, &text).await?;
+            Ok(SlackMessage::in_channel(format!(
+                "{} created *{}* in {}",
+                mention(&command.user_id),
+                created.task.title,
+                project.name
+            )))
+        }
+        SlackCommand::Status(query) => {
+            let mut tasks = find_tasks(deployment, projects, &query).await?;
+            if tasks.is_empty() {
+                return Ok(SlackMessage::ephemeral(format!(
+                    "No task matches '{query}'"
+                )));
+            }
+            let more = tasks.len().saturating_sub(MAX_STATUS_TASKS);
+            tasks.truncate(MAX_STATUS_TASKS);
+            let mut message = status_message(&tasks);
+            if more > 0 {
+                message.text.push_str(&format!("\n…and {more} more"));
+            }
+            Ok(message)
+        }
+        SlackCommand::Pending => {
+            let pool = &deployment.db().pool;
+            let mut pending = Vec::new();
+            for project in projects {
+                pending.extend(PendingStart::find_by_project_id(pool, project.id).await?);
+            }
+            Ok(pending_starts_message(&pending))
+        }
+        SlackCommand::Help => Ok(help_message(&command.command)),
+    }
+}
+
+/// Slash commands are form-encoded and answered with a message
+pub async fn receive_slack_command(
+    State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<ResponseJson<SlackMessage>, ApiError> {
+    let projects = slack_projects(&deployment, &headers, &body).await?;
+    let command = parse_slash_command(&body);
+    let message = run_command(&deployment, &projects, &command)
+        .await
+        .unwrap_or_else(error_message);
+    Ok(ResponseJson(message))
+}
+
+async fn decide_pending_start(
+    deployment: &DeploymentImpl,
+    projects: &[Project],
+    action: &PendingStartAction,
+) -> Result<SlackMessage, ApiError> {
+    let pending = PendingStart::find_by_id(&deployment.db().pool, action.pending_start_id)
+        .await?
+        .ok_or_else(|| {
+            ApiError::Conflict("This start was already approved or denied".to_string())
+        })?;
+    let project = projects
+        .iter()
+        .find(|project| project.id == pending.project_id)
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Pending start not found"))?;
+
+    let user_id = local_user_id(deployment, action.user_id.as_deref()).await?;
+    let by = action
+        .user_id
+        .as_deref()
+        .map(|slack_user_id| format!(" by {}", mention(slack_user_id)))
+        .unwrap_or_default();
+    let text = match action.decision {
+        PendingStartDecision::Approve => {
+            let task = pending_starts::approve(deployment, project, pending.id, user_id).await?;
+            format!("Start of *{}* approved{}", task.title, by)
+        }
+        PendingStartDecision::Deny => {
+            let task = pending_starts::deny(deployment, project, pending.id, user_id).await?;
+            format!("Start of *{}* denied{}", task.title, by)
+        }
+    };
+    Ok(SlackMessage::in_channel(text))
+}
+
+/// Button clicks are acknowledged right away, since starting an attempt can
+/// take longer than Slack waits; the outcome replaces the buttons once known.
+pub async fn receive_slack_interaction(
+    State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<StatusCode, ApiError> {
+    let projects = slack_projects(&deployment, &headers, &body).await?;
+    let Some(action) = parse_interaction(&body)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid Slack interaction payload: {e}")))?
+    else {
+        return Ok(StatusCode::OK);
+    };
+
+    tokio::spawn(async move {
+        let message = decide_pending_start(&deployment, &projects, &action)
+            .await
+            .unwrap_or_else(error_message)
+            .replacing_original();
+        if let Some(response_url) = &action.response_url
+            && let Err(e) = send_response(response_url, &message).await
+        {
+            tracing::warn!("Failed to reply to Slack interaction: {}", e);
+        }
+    });
+    Ok(StatusCode::OK)
+}
+
+pub async fn list_slack_user_links(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<SlackUserLink>>>, ApiError> {
+    let links = SlackUserLink::find_all(&deployment.db().pool).await?;
+    Ok(ResponseJson(ApiResponse::success(links)))
+}
+
+pub async fn set_slack_user_link(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetSlackUserLink>,
+) -> Result<ResponseJson<ApiResponse<SlackUserLink>>, ApiError> {
+    let slack_user_id = payload.slack_user_id.trim();
+    if slack_user_id.is_empty() {
+        return Err(ApiError::BadRequest(
+            "Enter the Slack user id, e.g. U2147483697".to_string(),
+        ));
+    }
+    let pool = &deployment.db().pool;
+    if User::find_by_id(pool, payload.user_id).await?.is_none() {
+        return Err(ApiError::coded(ErrorCode::NotFound, "User not found"));
+    }
+    let link = SlackUserLink::set(pool, slack_user_id, payload.user_id).await?;
+    Ok(ResponseJson(ApiResponse::success(link)))
+}
+
+pub async fn delete_slack_user_link(
+    _admin: RequireAdmin,
+    State(deployment): State<DeploymentImpl>,
+    Path(slack_user_id): Path<String>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = SlackUserLink::delete(&deployment.db().pool, &slack_user_id).await?;
+    if deleted == 0 {
+        return Err(ApiError::coded(
+            ErrorCode::NotFound,
+            "Slack user link not found",
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/slack/commands", post(receive_slack_command))
+        .route("/slack/interactions", post(receive_slack_interaction))
+        .route(
+            "/slack/user-links",
+            get(list_slack_user_links).put(set_slack_user_link),
+        )
+        .route(
+            "/slack/user-links/{slack_user_id}",
+            delete(delete_slack_user_link),
+        )
+}
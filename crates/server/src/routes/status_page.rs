@@ -0,0 +1,129 @@
+use axum::{
+    Extension, Router,
+    extract::{Path, State},
+    http::header,
+    response::{Html, IntoResponse, Json as ResponseJson},
+    routing::get,
+};
+use db::models::{project::Project, project_status_page::ProjectStatusPage};
+use deployment::Deployment;
+use serde::Serialize;
+use services::services::status_page::{PublicProjectStatus, render_status_page};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin, secret_token};
+
+/// Completed tasks listed on the page
+const RECENTLY_COMPLETED_LIMIT: i64 = 10;
+
+#[derive(Debug, Serialize, TS)]
+pub struct RotatedStatusPage {
+    pub status_page: ProjectStatusPage,
+    /// Only returned here; it can't be looked up again
+    pub token: String,
+}
+
+async fn public_status(
+    deployment: &DeploymentImpl,
+    token: &str,
+) -> Result<PublicProjectStatus, ApiError> {
+    let pool = &deployment.db().pool;
+    let page = ProjectStatusPage::find_by_token_hash(pool, &secret_token::hash_token(token))
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+    let project = Project::find_by_id(pool, page.project_id)
+        .await?
+        .ok_or(ApiError::Unauthorized)?;
+
+    let counts = ProjectStatusPage::status_counts(pool, project.id).await?;
+    let completed =
+        ProjectStatusPage::recently_completed(pool, project.id, RECENTLY_COMPLETED_LIMIT).await?;
+    let queue_length = ProjectStatusPage::queue_length(pool, project.id).await?;
+    Ok(PublicProjectStatus::new(
+        project.name,
+        &counts,
+        completed,
+        queue_length,
+    ))
+}
+
+/// Public, token-authenticated summary of a project's board. Any origin may
+/// read it so wikis can fetch it from the browser.
+pub async fn get_public_status(
+    State(deployment): State<DeploymentImpl>,
+    Path(token): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let status = public_status(&deployment, &token).await?;
+    Ok((
+        [(header::ACCESS_CONTROL_ALLOW_ORIGIN, "*")],
+        ResponseJson(ApiResponse::success(status)),
+    ))
+}
+
+/// The same summary as a standalone page, for wikis that embed pages in frames
+pub async fn get_public_status_html(
+    State(deployment): State<DeploymentImpl>,
+    Path(token): Path<String>,
+) -> Result<Html<String>, ApiError> {
+    let status = public_status(&deployment, &token).await?;
+    Ok(Html(render_status_page(&status)))
+}
+
+pub async fn get_project_status_page(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<ProjectStatusPage>>>, ApiError> {
+    let page = ProjectStatusPage::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(page)))
+}
+
+/// Enable the page, or issue a new token so the old URL stops working
+pub async fn rotate_project_status_page(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<RotatedStatusPage>>, ApiError> {
+    let token = secret_token::generate_token();
+    let status_page = ProjectStatusPage::rotate(
+        &deployment.db().pool,
+        project.id,
+        &secret_token::hash_token(&token),
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(RotatedStatusPage {
+        status_page,
+        token,
+    })))
+}
+
+pub async fn delete_project_status_page(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = ProjectStatusPage::delete(&deployment.db().pool, project.id).await?;
+    if deleted == 0 {
+        return Err(ApiError::BadRequest("Status page not enabled".to_string()));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/public/projects/{token}/status", get(get_public_status))
+        .route(
+            "/public/projects/{token}/status.html",
+            get(get_public_status_html),
+        )
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/status-page",
+        get(get_project_status_page)
+            .post(rotate_project_status_page)
+            .delete(delete_project_status_page),
+    )
+}
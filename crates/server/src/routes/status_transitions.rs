@@ -0,0 +1,49 @@
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    project::Project,
+    project_status_transition::{ProjectStatusTransition, SetStatusTransitions, StatusTransition},
+};
+use deployment::Deployment;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+pub async fn get_status_transitions(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<StatusTransition>>>, ApiError> {
+    let transitions =
+        ProjectStatusTransition::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(transitions)))
+}
+
+pub async fn set_status_transitions(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetStatusTransitions>,
+) -> Result<ResponseJson<ApiResponse<Vec<StatusTransition>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    ProjectStatusTransition::replace(pool, project.id, &payload.transitions).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "project_status_transitions_updated",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "rule_count": payload.transitions.len(),
+            }),
+        )
+        .await;
+
+    let transitions = ProjectStatusTransition::find_by_project_id(pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(transitions)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/status-transitions",
+        get(get_status_transitions).put(set_status_transitions),
+    )
+}
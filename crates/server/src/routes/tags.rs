@@ -9,7 +9,7 @@ use db::models::tag::{CreateTag, Tag, UpdateTag};
 use deployment::Deployment;
 use serde::Deserialize;
 use ts_rs::TS;
-use utils::response::ApiResponse;
+use utils::{response::ApiResponse, validation::Validate};
 
 use crate::{DeploymentImpl, error::ApiError, middleware::load_tag_middleware};
 
@@ -38,6 +38,7 @@ pub async fn create_tag(
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateTag>,
 ) -> Result<ResponseJson<ApiResponse<Tag>>, ApiError> {
+    payload.validate()?;
     let tag = Tag::create(&deployment.db().pool, &payload).await?;
 
     deployment
@@ -58,6 +59,7 @@ pub async fn update_tag(
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<UpdateTag>,
 ) -> Result<ResponseJson<ApiResponse<Tag>>, ApiError> {
+    payload.validate()?;
     let updated_tag = Tag::update(&deployment.db().pool, tag.id, &payload).await?;
 
     deployment
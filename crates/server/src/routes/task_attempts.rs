@@ -1,8 +1,19 @@
+pub mod approvals;
+pub mod bitbucket_pr;
 pub mod codex_setup;
 pub mod cursor_setup;
+pub mod diff_comments;
+pub mod diff_summary;
 pub mod gh_cli_setup;
+pub mod gitlab_mr;
 pub mod images;
+pub mod logs;
+pub mod manifest;
 pub mod pr;
+pub mod pr_set;
+pub mod proposed_tasks;
+pub mod pull_requests;
+pub mod summary;
 pub mod util;
 
 use std::{
@@ -23,13 +34,17 @@ use axum::{
 };
 use db::models::{
     execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+    managed_branch::ManagedBranch,
     merge::{Merge, MergeStatus, PrMerge, PullRequestInfo},
     project_repo::ProjectRepo,
     repo::{Repo, RepoError},
+    repo_path_rule::RepoPathRuleAction,
+    secret_scan_finding::SecretScanFinding,
     session::{CreateSession, Session},
     task::{Task, TaskRelationships, TaskStatus},
     workspace::{CreateWorkspace, Workspace, WorkspaceError},
     workspace_repo::{CreateWorkspaceRepo, RepoWithTargetBranch, WorkspaceRepo},
+    workspace_summary::WorkspaceSummary,
 };
 use deployment::Deployment;
 use executors::{
@@ -46,17 +61,25 @@ use services::services::{
     container::ContainerService,
     git::{ConflictOp, GitCliError, GitServiceError},
     github::GitHubService,
+    managed_branches,
+    merge_cleanup::{self, MergeCleanupOptions},
+    path_rules::{self, PathRuleViolation},
+    process_tree::ExecutionProcessTree,
+    workspace_manager::WorkspaceManager,
 };
 use sqlx::Error as SqlxError;
 use ts_rs::TS;
-use utils::response::ApiResponse;
+use utils::response::{ApiResponse, ErrorCode};
 use uuid::Uuid;
 
 use crate::{
-    DeploymentImpl, error::ApiError, middleware::load_workspace_middleware,
-    routes::task_attempts::gh_cli_setup::GhCliSetupError,
+    DeploymentImpl,
+    error::ApiError,
+    middleware::load_workspace_middleware,
+    routes::{
+        task_attempts::gh_cli_setup::GhCliSetupError, tasks::resume_queue_after_dependency_done,
+    },
 };
-use services::services::workspace_manager::WorkspaceManager;
 
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct RebaseTaskAttemptRequest {
@@ -114,7 +137,56 @@ pub struct CreateTaskAttemptBody {
 #[derive(Debug, Serialize, Deserialize, ts_rs::TS)]
 pub struct WorkspaceRepoInput {
     pub repo_id: Uuid,
-    pub target_branch: String,
+    /// Defaults to the project repo's default target branch, then the repo's current branch
+    #[serde(default)]
+    #[ts(optional)]
+    pub target_branch: Option<String>,
+}
+
+/// Target branch for a repo when the client doesn't pick one: the project repo's configured
+/// default, else the branch currently checked out, else `main`
+pub(crate) async fn default_target_branch(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    repo: &Repo,
+) -> Result<String, ApiError> {
+    let configured =
+        ProjectRepo::find_by_project_and_repo(&deployment.db().pool, project_id, repo.id)
+            .await?
+            .and_then(|project_repo| project_repo.default_target_branch);
+    Ok(configured.unwrap_or_else(|| {
+        deployment
+            .git()
+            .get_current_branch(&repo.path)
+            .unwrap_or_else(|_| "main".to_string())
+    }))
+}
+
+/// Resolve each requested repo's target branch, filling in defaults where none was given
+pub(crate) async fn resolve_workspace_repos(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+    repos: &[WorkspaceRepoInput],
+) -> Result<Vec<CreateWorkspaceRepo>, ApiError> {
+    let mut workspace_repos = Vec::with_capacity(repos.len());
+    for input in repos {
+        let target_branch = match &input.target_branch {
+            Some(branch) if !branch.is_empty() => branch.clone(),
+            _ => {
+                let repo = Repo::find_by_id(&deployment.db().pool, input.repo_id)
+                    .await?
+                    .ok_or_else(|| {
+                        ApiError::coded(ErrorCode::RepoNotFound, "Repository not found")
+                    })?;
+                default_target_branch(deployment, project_id, &repo).await?
+            }
+        };
+        workspace_repos.push(CreateWorkspaceRepo {
+            repo_id: input.repo_id,
+            target_branch,
+        });
+    }
+    Ok(workspace_repos)
 }
 
 #[derive(Debug, Deserialize, Serialize, TS)]
@@ -133,8 +205,9 @@ pub async fn create_task_attempt(
     let executor_profile_id = payload.executor_profile_id.clone();
 
     if payload.repos.is_empty() {
-        return Err(ApiError::BadRequest(
-            "At least one repository is required".to_string(),
+        return Err(ApiError::coded(
+            ErrorCode::RepoRequired,
+            "At least one repository is required",
         ));
     }
 
@@ -142,6 +215,10 @@ pub async fn create_task_attempt(
     let task = Task::find_by_id(&deployment.db().pool, payload.task_id)
         .await?
         .ok_or(SqlxError::RowNotFound)?;
+    deployment
+        .container()
+        .ensure_no_running_attempt(task.id)
+        .await?;
 
     let project = task
         .parent_project(pool)
@@ -171,14 +248,7 @@ pub async fn create_task_attempt(
     )
     .await?;
 
-    let workspace_repos: Vec<CreateWorkspaceRepo> = payload
-        .repos
-        .iter()
-        .map(|r| CreateWorkspaceRepo {
-            repo_id: r.repo_id,
-            target_branch: r.target_branch.clone(),
-        })
-        .collect();
+    let workspace_repos = resolve_workspace_repos(&deployment, project.id, &payload.repos).await?;
 
     WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos).await?;
     if let Err(err) = deployment
@@ -304,6 +374,9 @@ async fn handle_task_attempt_diff_ws(
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct MergeTaskAttemptRequest {
     pub repo_id: Uuid,
+    /// Revert changes to paths protected by a rejecting rule instead of refusing to merge
+    #[serde(default)]
+    pub revert_protected_paths: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize, TS)]
@@ -335,6 +408,39 @@ pub async fn merge_task_attempt(
     let workspace_path = Path::new(&container_ref);
     let worktree_path = workspace_path.join(repo.name);
 
+    let mut rejected: Vec<String> =
+        path_rules::check_workspace(pool, deployment.git(), &workspace, Some(repo.id))
+            .await?
+            .into_iter()
+            .filter(|violation| violation.action == RepoPathRuleAction::Reject)
+            .map(|violation| violation.file_path)
+            .collect();
+    rejected.dedup();
+    if !rejected.is_empty() {
+        if !request.revert_protected_paths {
+            return Err(ApiError::Conflict(format!(
+                "Changes touch protected paths: {}",
+                rejected.join(", ")
+            )));
+        }
+        deployment.git().revert_paths_to_branch(
+            &worktree_path,
+            &workspace_repo.target_branch,
+            &rejected,
+            "Revert changes to protected paths",
+        )?;
+        deployment
+            .track_if_analytics_allowed(
+                "protected_paths_reverted",
+                serde_json::json!({
+                    "workspace_id": workspace.id.to_string(),
+                    "repo_id": repo.id.to_string(),
+                    "file_count": rejected.len(),
+                }),
+            )
+            .await;
+    }
+
     let task = workspace
         .parent_task(pool)
         .await?
@@ -352,6 +458,14 @@ pub async fn merge_task_attempt(
         commit_message.push_str(description);
     }
 
+    // Prefer the agent-written commit message, if one was generated
+    if let Some(generated) = WorkspaceSummary::find_by_workspace_id(pool, workspace.id)
+        .await?
+        .and_then(|summary| summary.commit_message)
+    {
+        commit_message = generated;
+    }
+
     let merge_commit_id = deployment.git().merge_changes(
         &repo.path,
         &worktree_path,
@@ -395,6 +509,22 @@ pub async fn merge_task_attempt(
         }
     }
 
+    // With every repository merged, the worktrees and branch are no longer needed
+    if let Some(workspace) = Workspace::find_by_id(pool, workspace.id).await? {
+        let options = MergeCleanupOptions::from_config(&deployment.config().read().await);
+        merge_cleanup::cleanup_after_merge(
+            pool,
+            deployment.git(),
+            &workspace,
+            Some(&merge_commit_id),
+            options,
+        )
+        .await?;
+    }
+
+    // Queued tasks waiting on this one may be ready now
+    resume_queue_after_dependency_done(&deployment, &task).await;
+
     // Try broadcast update to other users in organization
     if let Ok(publisher) = deployment.share_publisher() {
         if let Err(err) = publisher.update_shared_task_by_id(task.id).await {
@@ -936,6 +1066,7 @@ pub async fn rename_branch(
     }
 
     Workspace::update_branch_name(pool, workspace.id, new_branch_name).await?;
+    ManagedBranch::rename(pool, workspace.id, &old_branch, new_branch_name).await?;
     // What will become of me?
     let updated_children_count = WorkspaceRepo::update_target_branch_for_children_of_workspace(
         pool,
@@ -1478,6 +1609,38 @@ pub async fn get_task_attempt_repos(
     Ok(ResponseJson(ApiResponse::success(repos)))
 }
 
+pub async fn get_secret_scan_findings(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<SecretScanFinding>>>, ApiError> {
+    let findings =
+        SecretScanFinding::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(findings)))
+}
+
+pub async fn get_path_rule_violations(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<PathRuleViolation>>>, ApiError> {
+    let violations =
+        path_rules::check_workspace(&deployment.db().pool, deployment.git(), &workspace, None)
+            .await?;
+    Ok(ResponseJson(ApiResponse::success(violations)))
+}
+
+/// Live process trees of the workspace's running executions
+pub async fn get_task_attempt_processes(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ExecutionProcessTree>>>, ApiError> {
+    let trees = deployment
+        .container()
+        .process_tracker()
+        .trees_for_workspace(workspace.id)
+        .await;
+    Ok(ResponseJson(ApiResponse::success(trees)))
+}
+
 #[derive(Debug, Serialize, Deserialize, TS)]
 #[serde(tag = "type", rename_all = "snake_case")]
 #[ts(tag = "type", rename_all = "snake_case")]
@@ -1534,6 +1697,10 @@ pub async fn delete_worktree(
     // Clear container_ref in database first
     Workspace::clear_container_ref(pool, workspace_id).await?;
 
+    let keep_branches = deployment.config().read().await.keep_branches_after_cleanup;
+    let pool = pool.clone();
+    let git = deployment.git().clone();
+
     // Spawn background cleanup
     tokio::spawn(async move {
         tracing::info!(
@@ -1553,6 +1720,9 @@ pub async fn delete_worktree(
             );
         } else {
             tracing::info!("Worktree cleanup completed for workspace {}", workspace_id);
+            if !keep_branches {
+                managed_branches::cleanup_branches(&pool, &git, &repositories, &branch).await;
+            }
         }
     });
 
@@ -1593,7 +1763,21 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/change-target-branch", post(change_target_branch))
         .route("/rename-branch", post(rename_branch))
         .route("/repos", get(get_task_attempt_repos))
+        .route("/secret-findings", get(get_secret_scan_findings))
+        .route("/path-violations", get(get_path_rule_violations))
+        .route("/processes", get(get_task_attempt_processes))
         .route("/worktree", delete(delete_worktree))
+        .merge(approvals::router())
+        .merge(proposed_tasks::router())
+        .merge(diff_comments::router())
+        .merge(diff_summary::router())
+        .merge(gitlab_mr::router())
+        .merge(bitbucket_pr::router())
+        .merge(pr_set::router())
+        .merge(pull_requests::router())
+        .merge(manifest::router())
+        .merge(logs::router())
+        .merge(summary::router())
         .layer(from_fn_with_state(
             deployment.clone(),
             load_workspace_middleware,
@@ -0,0 +1,133 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{execution_process::ExecutionProcess, session::Session, workspace::Workspace};
+use deployment::Deployment;
+use serde::Deserialize;
+use services::services::approvals::{ApprovalError, PendingApprovalInfo};
+use ts_rs::TS;
+use utils::{
+    approvals::{ApprovalResponse, ApprovalStatus},
+    response::ApiResponse,
+};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct DenyApprovalRequest {
+    pub reason: Option<String>,
+}
+
+/// Whether `execution_process_id` ran inside `workspace`
+async fn belongs_to_workspace(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    execution_process_id: Uuid,
+) -> bool {
+    ExecutionProcess::load_context(&deployment.db().pool, execution_process_id)
+        .await
+        .is_ok_and(|ctx| ctx.workspace.id == workspace.id)
+}
+
+pub async fn get_pending_approvals(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<PendingApprovalInfo>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let mut execution_process_ids = Vec::new();
+    for session in Session::find_by_workspace_id(pool, workspace.id).await? {
+        let processes = ExecutionProcess::find_by_session_id(pool, session.id, false).await?;
+        execution_process_ids.extend(processes.into_iter().map(|process| process.id));
+    }
+
+    let pending = deployment
+        .approvals()
+        .pending_for_execution_processes(&execution_process_ids);
+    Ok(ResponseJson(ApiResponse::success(pending)))
+}
+
+async fn respond(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    approval_id: &str,
+    status: ApprovalStatus,
+) -> Result<ApprovalStatus, ApiError> {
+    let approvals = deployment.approvals();
+    let execution_process_id = approvals
+        .pending_execution_process_id(approval_id)
+        .ok_or(ApprovalError::NotFound)?;
+    if !belongs_to_workspace(deployment, workspace, execution_process_id).await {
+        return Err(ApprovalError::NotFound.into());
+    }
+
+    let (status, context) = approvals
+        .respond(
+            &deployment.db().pool,
+            approval_id,
+            ApprovalResponse {
+                execution_process_id,
+                status,
+            },
+        )
+        .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "approval_responded",
+            serde_json::json!({
+                "approval_id": approval_id,
+                "status": format!("{:?}", status),
+                "tool_name": context.tool_name,
+                "execution_process_id": context.execution_process_id.to_string(),
+                "workspace_id": workspace.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(status)
+}
+
+pub async fn approve_approval(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, approval_id)): Path<(Uuid, String)>,
+) -> Result<ResponseJson<ApiResponse<ApprovalStatus>>, ApiError> {
+    let status = respond(
+        &deployment,
+        &workspace,
+        &approval_id,
+        ApprovalStatus::Approved,
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(status)))
+}
+
+pub async fn deny_approval(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, approval_id)): Path<(Uuid, String)>,
+    Json(payload): Json<DenyApprovalRequest>,
+) -> Result<ResponseJson<ApiResponse<ApprovalStatus>>, ApiError> {
+    let status = respond(
+        &deployment,
+        &workspace,
+        &approval_id,
+        ApprovalStatus::Denied {
+            reason: payload.reason,
+        },
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(status)))
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/approvals", get(get_pending_approvals))
+        .route("/approvals/{approval_id}/approve", post(approve_approval))
+        .route("/approvals/{approval_id}/deny", post(deny_approval))
+}
@@ -0,0 +1,232 @@
+use std::path::PathBuf;
+
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    bitbucket_pull_request::BitbucketPullRequest,
+    project::Project,
+    repo::{Repo, RepoError},
+    secret_scan_finding::{SecretScanFinding, SecretScanOperation},
+    workspace::{Workspace, WorkspaceError},
+    workspace_repo::WorkspaceRepo,
+    workspace_summary::WorkspaceSummary,
+};
+use deployment::Deployment;
+use serde::Deserialize;
+use services::services::{
+    bitbucket_issues::{BitbucketService, CreateBitbucketPullRequest},
+    container::ContainerService,
+    git::{GitCliError, GitServiceError},
+    gitlab_issues::{DEFAULT_MR_DESCRIPTION_TEMPLATE, render_mr_description},
+    secret_scan,
+};
+use ts_rs::TS;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::{bitbucket_issues::bitbucket_connection, task_attempts::pr::CreatePrError},
+};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateBitbucketPrRequest {
+    pub title: String,
+    /// Falls back to a description built from the task when omitted
+    pub body: Option<String>,
+    pub target_branch: Option<String>,
+    pub repo_id: Uuid,
+    #[serde(default)]
+    pub close_source_branch: bool,
+    /// Push even if the secret scan flags the branch's changes
+    #[serde(default)]
+    pub allow_secrets: bool,
+}
+
+fn map_git_error(e: GitServiceError) -> Result<CreatePrError, ApiError> {
+    match e {
+        GitServiceError::GitCLI(GitCliError::AuthFailed(_)) => Ok(CreatePrError::GitCliNotLoggedIn),
+        GitServiceError::GitCLI(GitCliError::NotAvailable) => Ok(CreatePrError::GitCliNotInstalled),
+        e => Err(ApiError::GitService(e)),
+    }
+}
+
+pub async fn get_bitbucket_pull_requests(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<BitbucketPullRequest>>>, ApiError> {
+    let pull_requests =
+        BitbucketPullRequest::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(pull_requests)))
+}
+
+/// Push the workspace branch and open a Bitbucket Cloud pull request for it
+pub async fn create_bitbucket_pull_request(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateBitbucketPrRequest>,
+) -> Result<ResponseJson<ApiResponse<BitbucketPullRequest, CreatePrError>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let task = workspace
+        .parent_task(pool)
+        .await?
+        .ok_or(ApiError::Workspace(WorkspaceError::TaskNotFound))?;
+    let project = Project::find_by_id(pool, task.project_id)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::ProjectNotFound, "Project not found"))?;
+    let (default_workspace, default_slug, credentials) = bitbucket_connection(&project)
+        .ok_or_else(|| ApiError::integration_not_configured("Bitbucket"))?;
+
+    let workspace_repo =
+        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, request.repo_id)
+            .await?
+            .ok_or(RepoError::NotFound)?;
+    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
+        .await?
+        .ok_or(RepoError::NotFound)?;
+
+    // The repo's own remote decides the Bitbucket repository in multi-repo projects;
+    // the project setting covers remotes that don't point at bitbucket.org
+    let (bitbucket_workspace, repo_slug) = deployment
+        .git()
+        .get_remote_url(&repo.path)
+        .ok()
+        .and_then(|url| BitbucketService::parse_repo_url(&url).ok())
+        .unwrap_or((default_workspace, default_slug));
+
+    let target_branch = request
+        .target_branch
+        .clone()
+        .unwrap_or_else(|| workspace_repo.target_branch.clone());
+
+    match deployment
+        .git()
+        .check_remote_branch_exists(&repo.path, &target_branch)
+    {
+        Ok(true) => {}
+        Ok(false) => {
+            return Ok(ResponseJson(ApiResponse::error_with_data(
+                CreatePrError::TargetBranchNotFound {
+                    branch: target_branch,
+                },
+            )));
+        }
+        Err(e) => {
+            return Ok(ResponseJson(ApiResponse::error_with_data(map_git_error(
+                e,
+            )?)));
+        }
+    }
+
+    // Scan what is about to be pushed before it leaves the machine
+    let findings =
+        secret_scan::scan_workspace(pool, deployment.git(), &workspace, Some(repo.id)).await?;
+    if !findings.is_empty() {
+        let findings = SecretScanFinding::record(
+            pool,
+            workspace.id,
+            SecretScanOperation::CreatePr,
+            request.allow_secrets,
+            &findings,
+        )
+        .await?;
+        if !request.allow_secrets {
+            return Ok(ResponseJson(ApiResponse::error_with_data(
+                CreatePrError::SecretsDetected { findings },
+            )));
+        }
+    }
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let worktree_path = PathBuf::from(&container_ref).join(&repo.name);
+    if let Err(e) = deployment
+        .git()
+        .push_to_github(&worktree_path, &workspace.branch, false)
+    {
+        tracing::error!("Failed to push branch to Bitbucket: {}", e);
+        return Ok(ResponseJson(ApiResponse::error_with_data(map_git_error(
+            e,
+        )?)));
+    }
+
+    // Bitbucket wants the bare branch name, not `origin/main`
+    let remote_prefix = format!(
+        "{}/",
+        deployment
+            .git()
+            .get_remote_name_from_branch_name(&worktree_path, &target_branch)
+            .unwrap_or_else(|_| "origin".to_string())
+    );
+    let target_branch = target_branch
+        .strip_prefix(&remote_prefix)
+        .unwrap_or(&target_branch)
+        .to_string();
+
+    let generated = WorkspaceSummary::find_by_workspace_id(pool, workspace.id)
+        .await?
+        .and_then(|summary| summary.pr_description);
+    let description = request.body.clone().or(generated).unwrap_or_else(|| {
+        render_mr_description(
+            DEFAULT_MR_DESCRIPTION_TEMPLATE,
+            &task.title,
+            task.description.as_deref(),
+            &workspace.branch,
+            &target_branch,
+        )
+    });
+
+    let pr_request = CreateBitbucketPullRequest {
+        source_branch: workspace.branch.clone(),
+        target_branch: target_branch.clone(),
+        title: request.title.clone(),
+        description,
+        close_source_branch: request.close_source_branch,
+    };
+    let created = BitbucketService::new()
+        .create_pull_request(&credentials, &bitbucket_workspace, &repo_slug, &pr_request)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to create Bitbucket PR for attempt {}: {}",
+                workspace.id,
+                e
+            );
+            ApiError::BadRequest(e.to_string())
+        })?;
+
+    let pull_request = BitbucketPullRequest::upsert(
+        pool,
+        workspace.id,
+        repo.id,
+        created.id,
+        &created.html_url,
+        &created.state,
+        &target_branch,
+    )
+    .await?;
+
+    if let Err(e) = utils::browser::open_browser(&pull_request.web_url).await {
+        tracing::warn!("Failed to open PR in browser: {}", e);
+    }
+    deployment
+        .track_if_analytics_allowed(
+            "bitbucket_pr_created",
+            serde_json::json!({
+                "workspace_id": workspace.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(pull_request)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/bitbucket/pr",
+        get(get_bitbucket_pull_requests).post(create_bitbucket_pull_request),
+    )
+}
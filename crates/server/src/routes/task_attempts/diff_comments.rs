@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, put},
+};
+use db::models::{
+    diff_comment::{CreateDiffComment, DiffComment, UpdateDiffComment},
+    mention::{Mention, MentionSource},
+    task::Task,
+    workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
+};
+use deployment::Deployment;
+use services::services::diff_comments::compile_review_prompt;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::{AuthUser, OptionalAuth},
+    routes::notifications::record_mentions,
+};
+
+/// Load a comment, making sure it belongs to `workspace`
+async fn load_comment(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    comment_id: Uuid,
+) -> Result<DiffComment, ApiError> {
+    DiffComment::find_by_id(&deployment.db().pool, comment_id)
+        .await?
+        .filter(|comment| comment.workspace_id == workspace.id)
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Diff comment not found"))
+}
+
+/// Notify the users newly mentioned in a comment
+async fn record_comment_mentions(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    comment: &DiffComment,
+    author: Option<&AuthUser>,
+) -> Result<(), ApiError> {
+    let Some(task) = Task::find_by_id(&deployment.db().pool, workspace.task_id).await? else {
+        return Ok(());
+    };
+    record_mentions(
+        deployment,
+        MentionSource::DiffComment,
+        comment.id,
+        &task,
+        Some(workspace.id),
+        author,
+        &comment.body,
+    )
+    .await;
+    Ok(())
+}
+
+/// The follow-up prompt built from the workspace's unresolved comments
+pub(crate) async fn review_prompt(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+) -> Result<(Option<String>, Vec<Uuid>), ApiError> {
+    let pool = &deployment.db().pool;
+    let comments = DiffComment::find_by_workspace_id(pool, workspace.id).await?;
+    let repo_names: HashMap<Uuid, String> =
+        WorkspaceRepo::find_repos_for_workspace(pool, workspace.id)
+            .await?
+            .into_iter()
+            .map(|repo| (repo.id, repo.name))
+            .collect();
+    let ids = comments
+        .iter()
+        .filter(|comment| !comment.resolved)
+        .map(|comment| comment.id)
+        .collect();
+    Ok((compile_review_prompt(&comments, &repo_names), ids))
+}
+
+pub async fn get_diff_comments(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<DiffComment>>>, ApiError> {
+    let comments = DiffComment::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(comments)))
+}
+
+pub async fn create_diff_comment(
+    OptionalAuth(auth): OptionalAuth,
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateDiffComment>,
+) -> Result<ResponseJson<ApiResponse<DiffComment>>, ApiError> {
+    let pool = &deployment.db().pool;
+    if payload.body.trim().is_empty() {
+        return Err(ApiError::BadRequest("Comment cannot be empty".to_string()));
+    }
+    if payload.line_number.is_some_and(|line| line < 1) {
+        return Err(ApiError::BadRequest("Line numbers start at 1".to_string()));
+    }
+    let in_workspace = WorkspaceRepo::find_by_workspace_id(pool, workspace.id)
+        .await?
+        .iter()
+        .any(|wr| wr.repo_id == payload.repo_id);
+    if !in_workspace {
+        return Err(ApiError::BadRequest(
+            "Repository is not part of this workspace".to_string(),
+        ));
+    }
+
+    let comment = DiffComment::create(
+        pool,
+        workspace.id,
+        auth.as_ref().map(|user| user.id),
+        &payload,
+    )
+    .await?;
+    record_comment_mentions(&deployment, &workspace, &comment, auth.as_ref()).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "diff_comment_created",
+            serde_json::json!({
+                "workspace_id": workspace.id.to_string(),
+                "line_anchored": comment.line_number.is_some(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(comment)))
+}
+
+pub async fn update_diff_comment(
+    OptionalAuth(auth): OptionalAuth,
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, comment_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<UpdateDiffComment>,
+) -> Result<ResponseJson<ApiResponse<DiffComment>>, ApiError> {
+    let existing = load_comment(&deployment, &workspace, comment_id).await?;
+    let comment = DiffComment::update(&deployment.db().pool, &existing, &payload).await?;
+    if comment.body != existing.body {
+        record_comment_mentions(&deployment, &workspace, &comment, auth.as_ref()).await?;
+    }
+    Ok(ResponseJson(ApiResponse::success(comment)))
+}
+
+pub async fn delete_diff_comment(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, comment_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let comment = load_comment(&deployment, &workspace, comment_id).await?;
+    let pool = &deployment.db().pool;
+    DiffComment::delete(pool, comment.id).await?;
+    Mention::delete_by_source(pool, MentionSource::DiffComment, comment.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Preview of the prompt `include_diff_comments` would send with the next follow-up
+pub async fn get_review_prompt(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<String>>>, ApiError> {
+    let (prompt, _) = review_prompt(&deployment, &workspace).await?;
+    Ok(ResponseJson(ApiResponse::success(prompt)))
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/diff-comments",
+            get(get_diff_comments).post(create_diff_comment),
+        )
+        .route("/diff-comments/prompt", get(get_review_prompt))
+        .route(
+            "/diff-comments/{comment_id}",
+            put(update_diff_comment).delete(delete_diff_comment),
+        )
+}
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use axum::{
+    Extension, Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use db::models::{
+    workspace::Workspace,
+    workspace_repo::{RepoWithTargetBranch, WorkspaceRepo},
+};
+use deployment::Deployment;
+use serde::Deserialize;
+use services::services::{
+    container::ContainerService,
+    diff_exclusions::DiffExclusions,
+    diff_size_guard::{self, DiffSizeGuard, DiffSizeSummary},
+    git::DiffTarget,
+};
+use ts_rs::TS;
+use utils::{diff::Diff, response::ApiResponse};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct DiffFileQuery {
+    pub repo_id: Uuid,
+    /// Path relative to the repo root, without the repo name prefix of the
+    /// diff stream
+    pub path: String,
+}
+
+/// Worktree diff of one repo against the merge base with its target branch
+async fn repo_diffs(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    repo: &RepoWithTargetBranch,
+    path_filter: Option<&[&str]>,
+) -> Result<Vec<Diff>, ApiError> {
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(workspace)
+        .await?;
+    let worktree_path = PathBuf::from(container_ref).join(&repo.repo.name);
+    let base_commit = deployment.git().get_base_commit(
+        &repo.repo.path,
+        &workspace.branch,
+        &repo.target_branch,
+    )?;
+    Ok(deployment.git().get_diffs(
+        DiffTarget::Worktree {
+            worktree_path: &worktree_path,
+            base_commit: &base_commit,
+        },
+        path_filter,
+    )?)
+}
+
+/// File counts and line stats of the attempt's diff, rolled up per directory,
+/// and whether it is past the size guard
+pub async fn get_diff_summary(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<DiffSizeSummary>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let exclusions = DiffExclusions::for_workspace(pool, &workspace).await?;
+    let guard = DiffSizeGuard::from_config(&deployment.config().read().await.diff_size_guard);
+
+    let workspace_repos =
+        WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, workspace.id).await?;
+
+    let mut repos = Vec::new();
+    for repo in workspace_repos {
+        let mut diffs = repo_diffs(&deployment, &workspace, &repo, None).await?;
+        exclusions.retain(&mut diffs);
+        repos.push((repo.repo.name, diffs));
+    }
+
+    Ok(ResponseJson(ApiResponse::success(
+        diff_size_guard::summarize(&repos, guard),
+    )))
+}
+
+/// Full contents of one changed file, for diffs whose stream only carries stats
+pub async fn get_diff_file(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<DiffFileQuery>,
+) -> Result<ResponseJson<ApiResponse<Diff>>, ApiError> {
+    let repo = WorkspaceRepo::find_repos_with_target_branch_for_workspace(
+        &deployment.db().pool,
+        workspace.id,
+    )
+    .await?
+    .into_iter()
+    .find(|repo| repo.repo.id == query.repo_id)
+    .ok_or_else(|| ApiError::BadRequest("Repository is not part of this attempt".to_string()))?;
+
+    let diff = repo_diffs(&deployment, &workspace, &repo, Some(&[query.path.as_str()]))
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| ApiError::BadRequest(format!("{} has no changes", query.path)))?;
+    Ok(ResponseJson(ApiResponse::success(diff)))
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/diff/summary", get(get_diff_summary))
+        .route("/diff/file", get(get_diff_file))
+}
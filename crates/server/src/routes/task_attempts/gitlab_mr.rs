@@ -0,0 +1,269 @@
+use std::path::PathBuf;
+
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    gitlab_merge_request::GitLabMergeRequest,
+    project::Project,
+    repo::{Repo, RepoError},
+    secret_scan_finding::{SecretScanFinding, SecretScanOperation},
+    workspace::{Workspace, WorkspaceError},
+    workspace_repo::WorkspaceRepo,
+    workspace_summary::WorkspaceSummary,
+};
+use deployment::Deployment;
+use serde::Deserialize;
+use services::services::{
+    container::ContainerService,
+    git::{GitCliError, GitServiceError},
+    gitlab_issues::{
+        CreateGitLabMergeRequest, DEFAULT_MR_DESCRIPTION_TEMPLATE, GitLabIssuesService,
+        render_mr_description,
+    },
+    secret_scan,
+};
+use ts_rs::TS;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, routes::task_attempts::pr::CreatePrError};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreateGitLabMrRequest {
+    /// Falls back to the agent-written title, then the task's title, when omitted
+    pub title: Option<String>,
+    /// Falls back to the agent-written description, then the configured
+    /// description template, when omitted
+    pub body: Option<String>,
+    pub target_branch: Option<String>,
+    pub repo_id: Uuid,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub remove_source_branch: bool,
+    /// Push even if the secret scan flags the branch's changes
+    #[serde(default)]
+    pub allow_secrets: bool,
+}
+
+fn map_git_error(e: GitServiceError) -> Result<CreatePrError, ApiError> {
+    match e {
+        GitServiceError::GitCLI(GitCliError::AuthFailed(_)) => Ok(CreatePrError::GitCliNotLoggedIn),
+        GitServiceError::GitCLI(GitCliError::NotAvailable) => Ok(CreatePrError::GitCliNotInstalled),
+        e => Err(ApiError::GitService(e)),
+    }
+}
+
+pub async fn get_gitlab_merge_requests(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<GitLabMergeRequest>>>, ApiError> {
+    let merge_requests =
+        GitLabMergeRequest::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(merge_requests)))
+}
+
+/// Push the workspace branch and open a GitLab merge request for it
+pub async fn create_gitlab_merge_request(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreateGitLabMrRequest>,
+) -> Result<ResponseJson<ApiResponse<GitLabMergeRequest, CreatePrError>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let task = workspace
+        .parent_task(pool)
+        .await?
+        .ok_or(ApiError::Workspace(WorkspaceError::TaskNotFound))?;
+    let project = Project::find_by_id(pool, task.project_id)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::ProjectNotFound, "Project not found"))?;
+    let token = project
+        .gitlab_token
+        .clone()
+        .ok_or_else(|| ApiError::BadRequest("GitLab token not set for this project".to_string()))?;
+
+    let workspace_repo =
+        WorkspaceRepo::find_by_workspace_and_repo_id(pool, workspace.id, request.repo_id)
+            .await?
+            .ok_or(RepoError::NotFound)?;
+    let repo = Repo::find_by_id(pool, workspace_repo.repo_id)
+        .await?
+        .ok_or(RepoError::NotFound)?;
+
+    let service = GitLabIssuesService::for_instance(project.gitlab_base_url.as_deref())
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    // The repo's own remote decides the GitLab project; the project setting covers
+    // remotes that aren't on the project's GitLab instance
+    let project_path = deployment
+        .git()
+        .get_remote_url(&repo.path)
+        .ok()
+        .and_then(|url| service.remote_project_path(&url))
+        .or_else(|| {
+            project
+                .gitlab_project_url
+                .as_deref()
+                .and_then(|url| GitLabIssuesService::parse_project_url(url).ok())
+        })
+        .ok_or_else(|| {
+            ApiError::BadRequest(format!(
+                "Could not determine the GitLab project for repository '{}'",
+                repo.name
+            ))
+        })?;
+
+    let target_branch = request
+        .target_branch
+        .clone()
+        .unwrap_or_else(|| workspace_repo.target_branch.clone());
+
+    match deployment
+        .git()
+        .check_remote_branch_exists(&repo.path, &target_branch)
+    {
+        Ok(true) => {}
+        Ok(false) => {
+            return Ok(ResponseJson(ApiResponse::error_with_data(
+                CreatePrError::TargetBranchNotFound {
+                    branch: target_branch,
+                },
+            )));
+        }
+        Err(e) => {
+            return Ok(ResponseJson(ApiResponse::error_with_data(map_git_error(
+                e,
+            )?)));
+        }
+    }
+
+    // Scan what is about to be pushed before it leaves the machine
+    let findings =
+        secret_scan::scan_workspace(pool, deployment.git(), &workspace, Some(repo.id)).await?;
+    if !findings.is_empty() {
+        let findings = SecretScanFinding::record(
+            pool,
+            workspace.id,
+            SecretScanOperation::CreatePr,
+            request.allow_secrets,
+            &findings,
+        )
+        .await?;
+        if !request.allow_secrets {
+            return Ok(ResponseJson(ApiResponse::error_with_data(
+                CreatePrError::SecretsDetected { findings },
+            )));
+        }
+    }
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let worktree_path = PathBuf::from(&container_ref).join(&repo.name);
+    if let Err(e) = deployment
+        .git()
+        .push_to_github(&worktree_path, &workspace.branch, false)
+    {
+        tracing::error!("Failed to push branch to GitLab: {}", e);
+        return Ok(ResponseJson(ApiResponse::error_with_data(map_git_error(
+            e,
+        )?)));
+    }
+
+    // GitLab wants the bare branch name, not `origin/main`
+    let remote_prefix = format!(
+        "{}/",
+        deployment
+            .git()
+            .get_remote_name_from_branch_name(&worktree_path, &target_branch)
+            .unwrap_or_else(|_| "origin".to_string())
+    );
+    let target_branch = target_branch
+        .strip_prefix(&remote_prefix)
+        .unwrap_or(&target_branch)
+        .to_string();
+
+    let summary = WorkspaceSummary::find_by_workspace_id(pool, workspace.id).await?;
+    let title = request
+        .title
+        .clone()
+        .filter(|title| !title.trim().is_empty())
+        .or_else(|| summary.as_ref().and_then(|s| s.pr_title.clone()))
+        .unwrap_or_else(|| task.title.clone());
+    let generated = summary.and_then(|summary| summary.pr_description);
+    let description = match request.body.clone().or(generated) {
+        Some(body) => body,
+        None => {
+            let config = deployment.config().read().await;
+            let template = config
+                .gitlab_mr_description_template
+                .as_deref()
+                .unwrap_or(DEFAULT_MR_DESCRIPTION_TEMPLATE);
+            render_mr_description(
+                template,
+                &task.title,
+                task.description.as_deref(),
+                &workspace.branch,
+                &target_branch,
+            )
+        }
+    };
+    let labels: Vec<&str> = request
+        .labels
+        .iter()
+        .map(|label| label.trim())
+        .filter(|label| !label.is_empty())
+        .collect();
+
+    let mr_request = CreateGitLabMergeRequest {
+        source_branch: workspace.branch.clone(),
+        target_branch: target_branch.clone(),
+        title,
+        description,
+        labels: (!labels.is_empty()).then(|| labels.join(",")),
+        remove_source_branch: request.remove_source_branch,
+    };
+    let created = service
+        .create_merge_request(&token, &project_path, &mr_request)
+        .await
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to create GitLab MR for attempt {}: {}",
+                workspace.id,
+                e
+            );
+            ApiError::BadRequest(e.to_string())
+        })?;
+
+    let merge_request = GitLabMergeRequest::upsert(
+        pool,
+        workspace.id,
+        repo.id,
+        created.iid,
+        &created.web_url,
+        &created.state,
+        &target_branch,
+    )
+    .await?;
+
+    if let Err(e) = utils::browser::open_browser(&merge_request.web_url).await {
+        tracing::warn!("Failed to open MR in browser: {}", e);
+    }
+    deployment
+        .track_if_analytics_allowed(
+            "gitlab_mr_created",
+            serde_json::json!({
+                "workspace_id": workspace.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(merge_request)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/gitlab/mr",
+        get(get_gitlab_merge_requests).post(create_gitlab_merge_request),
+    )
+}
@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use axum::{
+    Extension, Router,
+    body::Body,
+    extract::{Query, State},
+    http::{HeaderValue, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use db::models::{
+    execution_process::ExecutionProcess, execution_process_logs::ExecutionProcessLogs,
+    session::Session, workspace::Workspace,
+};
+use deployment::Deployment;
+use futures_util::{StreamExt, stream};
+use serde::Deserialize;
+use services::services::log_redaction::LogRedactor;
+use sqlx::SqlitePool;
+use utils::log_msg::LogMsg;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogDownloadFormat {
+    #[default]
+    Text,
+    Ndjson,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LogDownloadQuery {
+    #[serde(default)]
+    pub format: LogDownloadFormat,
+}
+
+/// One process's stdout and stderr, each line prefixed with the time it was
+/// stored. Agent conversation patches are left out; they are rebuilt from
+/// the same output.
+async fn render_process_logs(
+    pool: &SqlitePool,
+    process: &ExecutionProcess,
+    redactor: &LogRedactor,
+    format: LogDownloadFormat,
+) -> String {
+    let records = match ExecutionProcessLogs::find_by_execution_id(pool, process.id).await {
+        Ok(records) => records,
+        Err(e) => {
+            tracing::warn!("Failed to load logs for process {}: {}", process.id, e);
+            return String::new();
+        }
+    };
+    let run_reason = serde_json::to_value(&process.run_reason)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    if matches!(format, LogDownloadFormat::Text) {
+        out.push_str(&format!(
+            "=== {} {} started {} ({:?}) ===\n",
+            run_reason,
+            process.id,
+            process.started_at.to_rfc3339(),
+            process.status
+        ));
+    }
+
+    for record in &records {
+        for line in record.logs.lines().filter(|line| !line.trim().is_empty()) {
+            let (stream, text) = match serde_json::from_str::<LogMsg>(line) {
+                Ok(LogMsg::Stdout(text)) => ("stdout", text),
+                Ok(LogMsg::Stderr(text)) => ("stderr", text),
+                Ok(_) => continue,
+                Err(e) => {
+                    tracing::debug!("Skipping unparseable log line for {}: {}", process.id, e);
+                    continue;
+                }
+            };
+            let text = redactor.redact(&text);
+            let timestamp = record.inserted_at.to_rfc3339();
+            match format {
+                LogDownloadFormat::Text => {
+                    for text_line in text.lines() {
+                        out.push_str(&format!("{timestamp} {stream} {text_line}\n"));
+                    }
+                }
+                LogDownloadFormat::Ndjson => {
+                    let entry = serde_json::json!({
+                        "timestamp": timestamp,
+                        "execution_process_id": process.id,
+                        "run_reason": run_reason,
+                        "stream": stream,
+                        "text": text,
+                    });
+                    out.push_str(&entry.to_string());
+                    out.push('\n');
+                }
+            }
+        }
+    }
+
+    if matches!(format, LogDownloadFormat::Text) {
+        out.push('\n');
+    }
+    out
+}
+
+/// The attempt's combined process logs as a file attachment, oldest process
+/// first. The current redaction settings are applied again, so output stored
+/// before a pattern was added is masked too.
+pub async fn download_logs(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<LogDownloadQuery>,
+) -> Result<Response, ApiError> {
+    let pool = deployment.db().pool.clone();
+    let mut processes = Vec::new();
+    for session in Session::find_by_workspace_id(&pool, workspace.id).await? {
+        processes.extend(ExecutionProcess::find_by_session_id(&pool, session.id, false).await?);
+    }
+    processes.sort_by_key(|process| process.created_at);
+
+    let redactor = Arc::new(LogRedactor::from_config(
+        &deployment.config().read().await.log_redaction,
+    ));
+    let format = query.format;
+
+    let body = Body::from_stream(stream::iter(processes).then(move |process| {
+        let (pool, redactor) = (pool.clone(), redactor.clone());
+        async move {
+            Ok::<_, std::io::Error>(render_process_logs(&pool, &process, &redactor, format).await)
+        }
+    }));
+
+    let (content_type, extension) = match format {
+        LogDownloadFormat::Text => ("text/plain; charset=utf-8", "log"),
+        LogDownloadFormat::Ndjson => ("application/x-ndjson", "ndjson"),
+    };
+    let mut response = body.into_response();
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    if let Ok(disposition) = HeaderValue::from_str(&format!(
+        "attachment; filename=\"logs-{}.{}\"",
+        workspace.id, extension
+    )) {
+        headers.insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    Ok(response)
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/logs/download", get(download_logs))
+}
@@ -0,0 +1,56 @@
+use axum::{
+    Extension, Router,
+    extract::State,
+    http::{HeaderValue, header},
+    response::{IntoResponse, Json as ResponseJson, Response},
+    routing::get,
+};
+use db::models::{workspace::Workspace, workspace_manifest::WorkspaceManifest};
+use deployment::Deployment;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+async fn load_manifest(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+) -> Result<WorkspaceManifest, ApiError> {
+    WorkspaceManifest::find_by_workspace_id(&deployment.db().pool, workspace.id)
+        .await?
+        .ok_or_else(|| {
+            ApiError::BadRequest("No manifest was recorded for this attempt".to_string())
+        })
+}
+
+pub async fn get_manifest(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<WorkspaceManifest>>, ApiError> {
+    let manifest = load_manifest(&deployment, &workspace).await?;
+    Ok(ResponseJson(ApiResponse::success(manifest)))
+}
+
+/// The bare manifest as a JSON file attachment
+pub async fn download_manifest(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<Response, ApiError> {
+    let manifest = load_manifest(&deployment, &workspace).await?;
+    let mut response = ResponseJson(manifest).into_response();
+    if let Ok(disposition) = HeaderValue::from_str(&format!(
+        "attachment; filename=\"manifest-{}.json\"",
+        workspace.id
+    )) {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    Ok(response)
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/manifest", get(get_manifest))
+        .route("/manifest/download", get(download_manifest))
+}
@@ -6,31 +6,28 @@ use axum::{
     response::Json as ResponseJson,
 };
 use db::models::{
-    execution_process::{ExecutionProcess, ExecutionProcessRunReason},
     merge::{Merge, MergeStatus},
     repo::{Repo, RepoError},
-    session::{CreateSession, Session},
+    secret_scan_finding::{SecretScanFinding, SecretScanOperation},
     task::{Task, TaskStatus},
     workspace::{Workspace, WorkspaceError},
     workspace_repo::WorkspaceRepo,
+    workspace_summary::WorkspaceSummary,
 };
 use deployment::Deployment;
-use executors::actions::{
-    ExecutorAction, ExecutorActionType, coding_agent_follow_up::CodingAgentFollowUpRequest,
-    coding_agent_initial::CodingAgentInitialRequest,
-};
 use git2::BranchType;
 use serde::{Deserialize, Serialize};
 use services::services::{
     container::ContainerService,
     git::{GitCliError, GitServiceError},
     github::{CreatePrRequest, GitHubService, GitHubServiceError, UnifiedPrComment},
+    secret_scan,
 };
 use ts_rs::TS;
 use utils::response::ApiResponse;
 use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError};
+use crate::{DeploymentImpl, error::ApiError, routes::task_attempts::util::start_agent_turn};
 
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct CreateGitHubPrRequest {
@@ -41,6 +38,9 @@ pub struct CreateGitHubPrRequest {
     pub repo_id: Uuid,
     #[serde(default)]
     pub auto_generate_description: bool,
+    /// Push even if the secret scan flags the branch's changes
+    #[serde(default)]
+    pub allow_secrets: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, TS)]
@@ -52,6 +52,7 @@ pub enum CreatePrError {
     GitCliNotLoggedIn,
     GitCliNotInstalled,
     TargetBranchNotFound { branch: String },
+    SecretsDetected { findings: Vec<SecretScanFinding> },
 }
 
 #[derive(Debug, Serialize, TS)]
@@ -119,66 +120,7 @@ async fn trigger_pr_description_follow_up(
 
     drop(config); // Release the lock before async operations
 
-    // Get or create a session for this follow-up
-    let session =
-        match Session::find_latest_by_workspace_id(&deployment.db().pool, workspace.id).await? {
-            Some(s) => s,
-            None => {
-                Session::create(
-                    &deployment.db().pool,
-                    &CreateSession { executor: None },
-                    Uuid::new_v4(),
-                    workspace.id,
-                )
-                .await?
-            }
-        };
-
-    // Get executor profile from the latest coding agent process in this session
-    let executor_profile_id =
-        ExecutionProcess::latest_executor_profile_for_session(&deployment.db().pool, session.id)
-            .await?;
-
-    // Get latest agent session ID if one exists (for coding agent continuity)
-    let latest_agent_session_id = ExecutionProcess::find_latest_coding_agent_turn_session_id(
-        &deployment.db().pool,
-        session.id,
-    )
-    .await?;
-
-    let working_dir = workspace
-        .agent_working_dir
-        .as_ref()
-        .filter(|dir| !dir.is_empty())
-        .cloned();
-
-    // Build the action type (follow-up if session exists, otherwise initial)
-    let action_type = if let Some(agent_session_id) = latest_agent_session_id {
-        ExecutorActionType::CodingAgentFollowUpRequest(CodingAgentFollowUpRequest {
-            prompt,
-            session_id: agent_session_id,
-            executor_profile_id: executor_profile_id.clone(),
-            working_dir: working_dir.clone(),
-        })
-    } else {
-        ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
-            prompt,
-            executor_profile_id: executor_profile_id.clone(),
-            working_dir,
-        })
-    };
-
-    let action = ExecutorAction::new(action_type, None);
-
-    deployment
-        .container()
-        .start_execution(
-            workspace,
-            &session,
-            &action,
-            &ExecutionProcessRunReason::CodingAgent,
-        )
-        .await?;
+    start_agent_turn(deployment, workspace, prompt).await?;
 
     Ok(())
 }
@@ -238,6 +180,36 @@ pub async fn create_github_pr(
         Ok(true) => {}
     }
 
+    // Scan what is about to be pushed before it leaves the machine
+    let findings =
+        secret_scan::scan_workspace(pool, deployment.git(), &workspace, Some(repo.id)).await?;
+    if !findings.is_empty() {
+        let findings = SecretScanFinding::record(
+            pool,
+            workspace.id,
+            SecretScanOperation::CreatePr,
+            request.allow_secrets,
+            &findings,
+        )
+        .await?;
+        deployment
+            .track_if_analytics_allowed(
+                "secret_scan_flagged",
+                serde_json::json!({
+                    "workspace_id": workspace.id.to_string(),
+                    "operation": "create_pr",
+                    "finding_count": findings.len(),
+                    "overridden": request.allow_secrets,
+                }),
+            )
+            .await;
+        if !request.allow_secrets {
+            return Ok(ResponseJson(ApiResponse::error_with_data(
+                CreatePrError::SecretsDetected { findings },
+            )));
+        }
+    }
+
     // Push the branch to GitHub first
     if let Err(e) = deployment
         .git()
@@ -278,10 +250,17 @@ pub async fn create_github_pr(
     } else {
         target_branch
     };
+    // Fall back to the agent-written description, if one was generated
+    let body = match request.body.clone() {
+        Some(body) => Some(body),
+        None => WorkspaceSummary::find_by_workspace_id(pool, workspace.id)
+            .await?
+            .and_then(|summary| summary.pr_description),
+    };
     // Create the PR using GitHub service
     let pr_request = CreatePrRequest {
         title: request.title.clone(),
-        body: request.body.clone(),
+        body,
         head_branch: workspace.branch.clone(),
         base_branch: norm_target_branch_name.clone(),
         draft: request.draft,
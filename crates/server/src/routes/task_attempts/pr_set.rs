@@ -0,0 +1,357 @@
+use std::path::{Path, PathBuf};
+
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    merge::{Merge, MergeStatus, PullRequestInfo},
+    repo::Repo,
+    secret_scan_finding::{SecretScanFinding, SecretScanOperation},
+    workspace::Workspace,
+    workspace_ci_status::{CiStatus, WorkspaceCiStatus},
+    workspace_repo::{RepoWithTargetBranch, WorkspaceRepo},
+    workspace_summary::WorkspaceSummary,
+};
+use deployment::Deployment;
+use git2::BranchType;
+use serde::{Deserialize, Serialize};
+use services::services::{
+    container::ContainerService,
+    git::{GitCliError, GitServiceError},
+    github::{CreatePrRequest, GitHubRepoInfo, GitHubService, GitHubServiceError},
+    pr_set::{PrSetMember, PrSetStatus, with_cross_links},
+    secret_scan,
+};
+use sqlx::SqlitePool;
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, routes::task_attempts::pr::CreatePrError};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CreatePrSetRequest {
+    pub title: String,
+    /// Falls back to the agent-written description when omitted
+    pub body: Option<String>,
+    pub draft: Option<bool>,
+    /// Push even if the secret scan flags the branch's changes
+    #[serde(default)]
+    pub allow_secrets: bool,
+}
+
+/// Why no PR set was opened. Pull requests already opened for the set are
+/// closed again, so a failure leaves no partial set behind.
+#[derive(Debug, Serialize, Deserialize, TS)]
+pub struct CreatePrSetError {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    pub error: CreatePrError,
+}
+
+/// A repository of the set that still needs its pull request opened
+struct PendingPr {
+    repo: RepoWithTargetBranch,
+    repo_info: GitHubRepoInfo,
+    worktree_path: PathBuf,
+    target_branch: String,
+}
+
+fn map_git_error(e: GitServiceError) -> Result<CreatePrError, ApiError> {
+    match e {
+        GitServiceError::GitCLI(GitCliError::AuthFailed(_)) => Ok(CreatePrError::GitCliNotLoggedIn),
+        GitServiceError::GitCLI(GitCliError::NotAvailable) => Ok(CreatePrError::GitCliNotInstalled),
+        e => Err(ApiError::GitService(e)),
+    }
+}
+
+fn map_github_error(e: GitHubServiceError) -> Result<CreatePrError, ApiError> {
+    match e {
+        GitHubServiceError::GhCliNotInstalled(_) => Ok(CreatePrError::GithubCliNotInstalled),
+        GitHubServiceError::AuthFailed(_) => Ok(CreatePrError::GithubCliNotLoggedIn),
+        e => Err(ApiError::GitHubService(e)),
+    }
+}
+
+fn repo_failed(
+    repo: &Repo,
+    error: CreatePrError,
+) -> ResponseJson<ApiResponse<PrSetStatus, CreatePrSetError>> {
+    ResponseJson(ApiResponse::error_with_data(CreatePrSetError {
+        repo_id: repo.id,
+        repo_name: repo.name.clone(),
+        error,
+    }))
+}
+
+/// The most recent pull request of each repository that is still open or merged
+fn live_prs(merges: Vec<Merge>) -> Vec<(Uuid, PullRequestInfo)> {
+    let mut prs: Vec<(Uuid, PullRequestInfo)> = Vec::new();
+    // Merges come newest first, so the first PR seen per repo wins
+    for merge in merges {
+        if let Merge::Pr(pr) = merge
+            && !prs.iter().any(|(repo_id, _)| *repo_id == pr.repo_id)
+            && matches!(pr.pr_info.status, MergeStatus::Open | MergeStatus::Merged)
+        {
+            prs.push((pr.repo_id, pr.pr_info));
+        }
+    }
+    prs
+}
+
+async fn load_pr_set_status(
+    pool: &SqlitePool,
+    workspace_id: Uuid,
+) -> Result<PrSetStatus, ApiError> {
+    let repos =
+        WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, workspace_id).await?;
+    let prs = live_prs(Merge::find_by_workspace_id(pool, workspace_id).await?);
+    let ci_status = WorkspaceCiStatus::find_by_workspace_id(pool, workspace_id)
+        .await?
+        .map_or(CiStatus::None, |ci| ci.status);
+
+    let mut members = Vec::new();
+    let mut missing_repo_ids = Vec::new();
+    for repo in repos {
+        match prs.iter().find(|(repo_id, _)| *repo_id == repo.repo.id) {
+            Some((_, pr)) => members.push(PrSetMember::from_pr(repo.repo.id, repo.repo.name, pr)),
+            None => missing_repo_ids.push(repo.repo.id),
+        }
+    }
+    Ok(PrSetStatus::new(members, missing_repo_ids, ci_status))
+}
+
+/// PR APIs want the bare branch name, not the local `origin/main`
+fn bare_target_branch(
+    deployment: &DeploymentImpl,
+    repo_path: &Path,
+    worktree_path: &Path,
+    target_branch: &str,
+) -> Result<String, GitServiceError> {
+    if !matches!(
+        deployment
+            .git()
+            .find_branch_type(repo_path, target_branch)?,
+        BranchType::Remote
+    ) {
+        return Ok(target_branch.to_string());
+    }
+    let remote = deployment
+        .git()
+        .get_remote_name_from_branch_name(worktree_path, target_branch)?;
+    Ok(target_branch
+        .strip_prefix(&format!("{remote}/"))
+        .unwrap_or(target_branch)
+        .to_string())
+}
+
+/// Close the pull requests opened so far after a later repository failed
+async fn roll_back(github: &GitHubService, opened: &[(PendingPr, PullRequestInfo)], failed: &str) {
+    let comment = format!(
+        "Closed automatically: this pull request is part of a multi-repo change and \
+         the pull request for `{failed}` could not be opened."
+    );
+    for (pending, pr) in opened {
+        if let Err(e) = github
+            .close_pr(&pending.repo_info, pr.number, &comment)
+            .await
+        {
+            tracing::warn!(
+                "Failed to close PR #{} while rolling back a PR set: {}",
+                pr.number,
+                e
+            );
+        }
+    }
+}
+
+pub async fn get_pr_set(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<PrSetStatus>>, ApiError> {
+    let status = load_pr_set_status(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(status)))
+}
+
+/// Open cross-linked GitHub pull requests for every repository of the
+/// workspace that doesn't have one yet. Either every pull request is opened
+/// or none is left open.
+pub async fn create_pr_set(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Json(request): Json<CreatePrSetRequest>,
+) -> Result<ResponseJson<ApiResponse<PrSetStatus, CreatePrSetError>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let repos =
+        WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, workspace.id).await?;
+    if repos.len() < 2 {
+        return Err(ApiError::BadRequest(
+            "PR sets need a workspace with more than one repository".to_string(),
+        ));
+    }
+    let existing = live_prs(Merge::find_by_workspace_id(pool, workspace.id).await?);
+
+    let container_ref = deployment
+        .container()
+        .ensure_container_exists(&workspace)
+        .await?;
+    let workspace_path = PathBuf::from(&container_ref);
+
+    // Check every repository before anything leaves the machine
+    let mut pending = Vec::new();
+    for repo in repos
+        .into_iter()
+        .filter(|repo| !existing.iter().any(|(repo_id, _)| *repo_id == repo.repo.id))
+    {
+        match deployment
+            .git()
+            .check_remote_branch_exists(&repo.repo.path, &repo.target_branch)
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                return Ok(repo_failed(
+                    &repo.repo,
+                    CreatePrError::TargetBranchNotFound {
+                        branch: repo.target_branch.clone(),
+                    },
+                ));
+            }
+            Err(e) => return Ok(repo_failed(&repo.repo, map_git_error(e)?)),
+        }
+        let repo_info = deployment.git().get_github_repo_info(&repo.repo.path)?;
+        let worktree_path = workspace_path.join(&repo.repo.name);
+        let target_branch = bare_target_branch(
+            &deployment,
+            &repo.repo.path,
+            &worktree_path,
+            &repo.target_branch,
+        )?;
+        pending.push(PendingPr {
+            repo,
+            repo_info,
+            worktree_path,
+            target_branch,
+        });
+    }
+    if pending.is_empty() {
+        return Err(ApiError::Conflict(
+            "Every repository of this workspace already has a pull request".to_string(),
+        ));
+    }
+
+    // Scan what is about to be pushed before it leaves the machine
+    let mut findings = Vec::new();
+    for pr in &pending {
+        findings.extend(
+            secret_scan::scan_workspace(pool, deployment.git(), &workspace, Some(pr.repo.repo.id))
+                .await?,
+        );
+    }
+    if !findings.is_empty() {
+        let findings = SecretScanFinding::record(
+            pool,
+            workspace.id,
+            SecretScanOperation::CreatePr,
+            request.allow_secrets,
+            &findings,
+        )
+        .await?;
+        if !request.allow_secrets {
+            let flagged = pending
+                .iter()
+                .map(|pr| &pr.repo.repo)
+                .find(|repo| findings.first().is_some_and(|f| f.repo_id == repo.id))
+                .unwrap_or(&pending[0].repo.repo);
+            return Ok(repo_failed(
+                flagged,
+                CreatePrError::SecretsDetected { findings },
+            ));
+        }
+    }
+
+    for pr in &pending {
+        if let Err(e) = deployment
+            .git()
+            .push_to_github(&pr.worktree_path, &workspace.branch, false)
+        {
+            tracing::error!("Failed to push branch for PR set: {}", e);
+            return Ok(repo_failed(&pr.repo.repo, map_git_error(e)?));
+        }
+    }
+
+    let body = match request.body.clone() {
+        Some(body) => body,
+        None => WorkspaceSummary::find_by_workspace_id(pool, workspace.id)
+            .await?
+            .and_then(|summary| summary.pr_description)
+            .unwrap_or_default(),
+    };
+    let github = GitHubService::new()?;
+    let mut opened: Vec<(PendingPr, PullRequestInfo)> = Vec::new();
+    for pr in pending {
+        let pr_request = CreatePrRequest {
+            title: request.title.clone(),
+            body: Some(body.clone()),
+            head_branch: workspace.branch.clone(),
+            base_branch: pr.target_branch.clone(),
+            draft: request.draft,
+        };
+        match github.create_pr(&pr.repo_info, &pr_request).await {
+            Ok(info) => opened.push((pr, info)),
+            Err(e) => {
+                tracing::error!(
+                    "Failed to create PR for {} in PR set of attempt {}: {}",
+                    pr.repo.repo.name,
+                    workspace.id,
+                    e
+                );
+                roll_back(&github, &opened, &pr.repo.repo.name).await;
+                return Ok(repo_failed(&pr.repo.repo, map_github_error(e)?));
+            }
+        }
+    }
+
+    for (pr, info) in &opened {
+        Merge::create_pr(
+            pool,
+            workspace.id,
+            pr.repo.repo.id,
+            &pr.target_branch,
+            info.number,
+            &info.url,
+        )
+        .await?;
+    }
+
+    // Link the new pull requests to each other and to the ones already open
+    let status = load_pr_set_status(pool, workspace.id).await?;
+    for (pr, info) in &opened {
+        let linked = with_cross_links(&body, &status.members, pr.repo.repo.id);
+        if let Err(e) = github
+            .update_pr_body(&pr.repo_info, info.number, &linked)
+            .await
+        {
+            tracing::warn!(
+                "Failed to add PR set links to PR #{} of {}: {}",
+                info.number,
+                pr.repo.repo.name,
+                e
+            );
+        }
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "github_pr_set_created",
+            serde_json::json!({
+                "workspace_id": workspace.id.to_string(),
+                "pr_count": opened.len(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(status)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/pr-set", get(get_pr_set).post(create_pr_set))
+}
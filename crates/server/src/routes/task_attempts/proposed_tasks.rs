@@ -0,0 +1,135 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    proposed_task::{ProposedTask, ProposedTaskStatus},
+    task::{CreateTask, Task},
+    workspace::Workspace,
+};
+use deployment::Deployment;
+use serde::Deserialize;
+use sqlx::Error as SqlxError;
+use ts_rs::TS;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Optional edits applied to a proposal before it becomes a task
+#[derive(Debug, Default, Deserialize, TS)]
+pub struct AcceptProposedTaskRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Load a pending proposal, making sure it came from `workspace`
+async fn load_pending_proposal(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    proposal_id: Uuid,
+) -> Result<ProposedTask, ApiError> {
+    let proposal = ProposedTask::find_by_id(&deployment.db().pool, proposal_id)
+        .await?
+        .filter(|proposal| proposal.workspace_id == workspace.id)
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Proposed task not found"))?;
+    if proposal.status != ProposedTaskStatus::Pending {
+        return Err(ApiError::Conflict(format!(
+            "Proposed task was already {}",
+            proposal.status
+        )));
+    }
+    Ok(proposal)
+}
+
+pub async fn get_proposed_tasks(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProposedTask>>>, ApiError> {
+    let proposals = ProposedTask::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(proposals)))
+}
+
+/// Create a `todo` child task of the workspace from the proposal
+pub async fn accept_proposed_task(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, proposal_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<AcceptProposedTaskRequest>,
+) -> Result<ResponseJson<ApiResponse<ProposedTask>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let proposal = load_pending_proposal(&deployment, &workspace, proposal_id).await?;
+    let parent_task = workspace
+        .parent_task(pool)
+        .await?
+        .ok_or(SqlxError::RowNotFound)?;
+
+    let title = payload
+        .title
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or(proposal.title);
+    let description = payload.description.or(proposal.description);
+    let task = Task::create(
+        pool,
+        &CreateTask {
+            parent_workspace_id: Some(workspace.id),
+            ..CreateTask::from_title_description(parent_task.project_id, title, description)
+        },
+        Uuid::new_v4(),
+    )
+    .await?;
+
+    let proposal = ProposedTask::resolve(
+        pool,
+        proposal.id,
+        ProposedTaskStatus::Accepted,
+        Some(task.id),
+    )
+    .await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "proposed_task_accepted",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "parent_task_id": parent_task.id.to_string(),
+                "workspace_id": workspace.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(proposal)))
+}
+
+pub async fn reject_proposed_task(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, proposal_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<ProposedTask>>, ApiError> {
+    let proposal = load_pending_proposal(&deployment, &workspace, proposal_id).await?;
+    let proposal = ProposedTask::resolve(
+        &deployment.db().pool,
+        proposal.id,
+        ProposedTaskStatus::Rejected,
+        None,
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(proposal)))
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/proposed-tasks", get(get_proposed_tasks))
+        .route(
+            "/proposed-tasks/{proposal_id}/accept",
+            post(accept_proposed_task),
+        )
+        .route(
+            "/proposed-tasks/{proposal_id}/reject",
+            post(reject_proposed_task),
+        )
+}
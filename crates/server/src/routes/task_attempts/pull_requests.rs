@@ -0,0 +1,54 @@
+use axum::{
+    Extension, Router,
+    extract::State,
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    project::{Project, ProjectError},
+    workspace::Workspace,
+};
+use deployment::Deployment;
+use services::services::ci_status::{
+    WorkspacePullRequest, refresh_workspace_ci, workspace_pull_requests,
+};
+use utils::response::{ApiResponse, ErrorCode};
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// The attempt's pull and merge requests with their last polled review state
+/// and CI checks
+pub async fn get_pull_requests(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<WorkspacePullRequest>>>, ApiError> {
+    let pull_requests = workspace_pull_requests(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(pull_requests)))
+}
+
+/// Poll review and CI now rather than waiting for the monitor
+pub async fn refresh_pull_requests(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<WorkspacePullRequest>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let task = workspace
+        .parent_task(pool)
+        .await?
+        .ok_or_else(|| ApiError::coded(ErrorCode::TaskNotFound, "Task not found"))?;
+    let project = Project::find_by_id(pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    refresh_workspace_ci(pool, &project, workspace.id)
+        .await
+        .map_err(|e| ApiError::Conflict(format!("Could not check pull requests: {e}")))?;
+
+    let pull_requests = workspace_pull_requests(pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(pull_requests)))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/pull-requests", get(get_pull_requests))
+        .route("/pull-requests/refresh", post(refresh_pull_requests))
+}
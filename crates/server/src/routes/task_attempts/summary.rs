@@ -0,0 +1,78 @@
+use axum::{
+    Extension, Router,
+    extract::State,
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    execution_process::{ExecutionProcess, ExecutionProcessStatus},
+    workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
+    workspace_summary::WorkspaceSummary,
+};
+use deployment::Deployment;
+use services::services::{change_summary::summary_prompt, diff_exclusions::DiffExclusions};
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, routes::task_attempts::util::start_agent_turn};
+
+pub async fn get_summary(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<WorkspaceSummary>>>, ApiError> {
+    let summary =
+        WorkspaceSummary::find_by_workspace_id(&deployment.db().pool, workspace.id).await?;
+    Ok(ResponseJson(ApiResponse::success(summary)))
+}
+
+/// Ask the attempt's coding agent to write a commit message and PR description
+/// from its diff. The summary is stored when the agent's run finishes.
+pub async fn generate_summary(
+    Extension(workspace): Extension<Workspace>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<WorkspaceSummary>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    if let Some(execution_process_id) = WorkspaceSummary::find_by_workspace_id(pool, workspace.id)
+        .await?
+        .and_then(|summary| summary.execution_process_id)
+        && ExecutionProcess::find_by_id(pool, execution_process_id)
+            .await?
+            .is_some_and(|process| process.status == ExecutionProcessStatus::Running)
+    {
+        return Err(ApiError::Conflict(
+            "A summary is already being generated for this attempt".to_string(),
+        ));
+    }
+
+    let repos = WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, workspace.id)
+        .await?
+        .into_iter()
+        .map(|repo| (repo.repo.name, repo.target_branch))
+        .collect::<Vec<_>>();
+    let exclusions = DiffExclusions::for_workspace(pool, &workspace).await?;
+    let execution_process = start_agent_turn(
+        &deployment,
+        &workspace,
+        summary_prompt(&repos, exclusions.patterns()),
+    )
+    .await?;
+    let summary =
+        WorkspaceSummary::start_generating(pool, workspace.id, execution_process.id).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "workspace_summary_requested",
+            serde_json::json!({ "workspace_id": workspace.id.to_string() }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(summary)))
+}
+
+/// Routes mounted under `/task-attempts/{id}`
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/summary", get(get_summary))
+        .route("/generate-summary", post(generate_summary))
+}
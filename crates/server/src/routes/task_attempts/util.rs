@@ -1,10 +1,17 @@
 use std::path::PathBuf;
 
 use db::models::{
-    execution_process::ExecutionProcess, execution_process_repo_state::ExecutionProcessRepoState,
-    workspace::Workspace, workspace_repo::WorkspaceRepo,
+    execution_process::{ExecutionProcess, ExecutionProcessRunReason},
+    execution_process_repo_state::ExecutionProcessRepoState,
+    session::{CreateSession, Session},
+    workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
 };
 use deployment::Deployment;
+use executors::actions::{
+    ExecutorAction, ExecutorActionType, coding_agent_follow_up::CodingAgentFollowUpRequest,
+    coding_agent_initial::CodingAgentInitialRequest,
+};
 use services::services::{container::ContainerService, git::WorktreeResetOptions};
 use sqlx::SqlitePool;
 use uuid::Uuid;
@@ -81,3 +88,72 @@ pub async fn restore_worktrees_to_process(
 
     Ok(())
 }
+
+/// Send `prompt` to the workspace's coding agent, continuing its latest session
+/// when there is one
+pub async fn start_agent_turn(
+    deployment: &DeploymentImpl,
+    workspace: &Workspace,
+    prompt: String,
+) -> Result<ExecutionProcess, ApiError> {
+    // Get or create a session for this follow-up
+    let session =
+        match Session::find_latest_by_workspace_id(&deployment.db().pool, workspace.id).await? {
+            Some(s) => s,
+            None => {
+                Session::create(
+                    &deployment.db().pool,
+                    &CreateSession { executor: None },
+                    Uuid::new_v4(),
+                    workspace.id,
+                )
+                .await?
+            }
+        };
+
+    // Get executor profile from the latest coding agent process in this session
+    let executor_profile_id =
+        ExecutionProcess::latest_executor_profile_for_session(&deployment.db().pool, session.id)
+            .await?;
+
+    // Get latest agent session ID if one exists (for coding agent continuity)
+    let latest_agent_session_id = ExecutionProcess::find_latest_coding_agent_turn_session_id(
+        &deployment.db().pool,
+        session.id,
+    )
+    .await?;
+
+    let working_dir = workspace
+        .agent_working_dir
+        .as_ref()
+        .filter(|dir| !dir.is_empty())
+        .cloned();
+
+    // Build the action type (follow-up if session exists, otherwise initial)
+    let action_type = if let Some(agent_session_id) = latest_agent_session_id {
+        ExecutorActionType::CodingAgentFollowUpRequest(CodingAgentFollowUpRequest {
+            prompt,
+            session_id: agent_session_id,
+            executor_profile_id: executor_profile_id.clone(),
+            working_dir: working_dir.clone(),
+        })
+    } else {
+        ExecutorActionType::CodingAgentInitialRequest(CodingAgentInitialRequest {
+            prompt,
+            executor_profile_id: executor_profile_id.clone(),
+            working_dir,
+        })
+    };
+
+    let action = ExecutorAction::new(action_type, None);
+
+    Ok(deployment
+        .container()
+        .start_execution(
+            workspace,
+            &session,
+            &action,
+            &ExecutionProcessRunReason::CodingAgent,
+        )
+        .await?)
+}
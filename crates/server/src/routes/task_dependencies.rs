@@ -0,0 +1,94 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use db::models::{
+    task::Task,
+    task_dependency::{CreateTaskDependency, TaskDependency},
+};
+use deployment::Deployment;
+use services::services::sequential_queue::creates_cycle;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+pub async fn get_task_dependencies(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskDependency>>>, ApiError> {
+    let dependencies = TaskDependency::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(dependencies)))
+}
+
+/// Make the task wait for another task of the same project. Rejected when
+/// the other task already depends on this one.
+pub async fn add_task_dependency(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CreateTaskDependency>,
+) -> Result<ResponseJson<ApiResponse<TaskDependency>>, ApiError> {
+    let pool = &deployment.db().pool;
+    if payload.depends_on_task_id == task.id {
+        return Err(ApiError::BadRequest(
+            "A task cannot depend on itself".to_string(),
+        ));
+    }
+    let depends_on = Task::find_by_id(pool, payload.depends_on_task_id)
+        .await?
+        .filter(|other| other.project_id == task.project_id)
+        .ok_or_else(|| {
+            ApiError::coded(ErrorCode::TaskNotFound, "Task not found in this project")
+        })?;
+
+    let existing = TaskDependency::find_by_project_id(pool, task.project_id).await?;
+    if creates_cycle(&existing, task.id, depends_on.id) {
+        return Err(ApiError::coded(
+            ErrorCode::DependencyCycle,
+            format!("\"{}\" already depends on this task", depends_on.title),
+        )
+        .with_param("depends_on_task_id", depends_on.id));
+    }
+
+    let dependency = TaskDependency::create(pool, task.id, depends_on.id).await?;
+
+    deployment
+        .track_if_analytics_allowed(
+            "task_dependency_added",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "depends_on_task_id": depends_on.id.to_string(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(dependency)))
+}
+
+pub async fn remove_task_dependency(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_task_id, depends_on_task_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let removed =
+        TaskDependency::delete(&deployment.db().pool, task.id, depends_on_task_id).await?;
+    if removed == 0 {
+        return Err(ApiError::coded(ErrorCode::NotFound, "Dependency not found"));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/dependencies",
+            get(get_task_dependencies).post(add_task_dependency),
+        )
+        .route(
+            "/dependencies/{depends_on_task_id}",
+            delete(remove_task_dependency),
+        )
+}
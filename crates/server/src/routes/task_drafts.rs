@@ -0,0 +1,93 @@
+use axum::{
+    Extension, Json, Router,
+    extract::State,
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    project::Project,
+    task::{Task, TaskStatus, TaskWithAttemptStatus},
+    task_draft::{TaskDraft, TriageTaskDrafts},
+    task_label::TaskLabel,
+};
+use deployment::Deployment;
+use services::services::events::task_patch;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, routes::task_labels::push_labels_to_github_issue};
+
+pub async fn list_task_drafts(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskDraft>>>, ApiError> {
+    let drafts = TaskDraft::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(drafts)))
+}
+
+/// Label the drafts and put them on the board, in To Do or, when dismissed, in
+/// Cancelled. Tasks that are no longer drafts of the project are skipped.
+pub async fn triage_task_drafts(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<TriageTaskDrafts>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskWithAttemptStatus>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let drafts = TaskDraft::task_ids_for_project(pool, project.id).await?;
+
+    let mut triaged = Vec::new();
+    for task_id in payload.task_ids {
+        if !drafts.contains(&task_id) {
+            continue;
+        }
+        let Some(task) = Task::find_by_id(pool, task_id).await? else {
+            continue;
+        };
+
+        // Labels and status change while the task is still a draft, so the board
+        // only hears about it once it is complete
+        if !payload.labels.is_empty() {
+            let previous = TaskLabel::find_by_task_id(pool, task.id).await?;
+            let mut labels = previous.clone();
+            labels.extend(payload.labels.iter().cloned());
+            let labels = TaskLabel::set(pool, task.id, &labels).await?;
+            if labels != previous {
+                push_labels_to_github_issue(&deployment, &task, &labels).await?;
+            }
+        }
+        if payload.dismiss {
+            Task::update_status(pool, task.id, TaskStatus::Cancelled).await?;
+        }
+
+        // Whoever removes it first decides
+        if TaskDraft::delete(pool, task.id).await? == 0 {
+            continue;
+        }
+        if let Some(task) = Task::find_by_id_with_attempt_status(pool, project.id, task.id).await? {
+            deployment
+                .events()
+                .msg_store()
+                .push_patch(task_patch::add(&task));
+            triaged.push(task);
+        }
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "task_drafts_triaged",
+            serde_json::json!({
+                "project_id": project.id.to_string(),
+                "triaged": triaged.len(),
+                "dismissed": payload.dismiss,
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(triaged)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/task-drafts", get(list_task_drafts))
+        .route("/task-drafts/triage", post(triage_task_drafts))
+}
@@ -0,0 +1,24 @@
+use axum::{Extension, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    project::Project,
+    task_group::{TaskGroup, TaskGroupWithProgress},
+};
+use deployment::Deployment;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// The project's task groups, such as imported GitHub milestones, with the
+/// tasks in each and how many are done
+pub async fn list_task_groups(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskGroupWithProgress>>>, ApiError> {
+    let groups = TaskGroup::find_with_progress(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(groups)))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route("/task-groups", get(list_task_groups))
+}
@@ -0,0 +1,152 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{delete, get},
+};
+use db::models::{
+    issue_link::IssueLink,
+    label_profile_rule::{LabelProfileRule, UpsertLabelProfileRule},
+    project::{Project, ProjectError},
+    task::Task,
+    task_label::{SetTaskLabels, TaskLabel},
+};
+use deployment::Deployment;
+use executors::profile::ExecutorConfigs;
+use services::services::{github_issues::GitHubIssuesService, issue_provider::IssueProviderKind};
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin, routes::notifications};
+
+pub async fn get_task_labels(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<String>>>, ApiError> {
+    let labels = TaskLabel::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(labels)))
+}
+
+/// Replace a task's labels. When the project exports labels and the task was
+/// imported from a GitHub issue, the issue's labels are replaced too.
+pub async fn set_task_labels(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTaskLabels>,
+) -> Result<ResponseJson<ApiResponse<Vec<String>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let previous = TaskLabel::find_by_task_id(pool, task.id).await?;
+    let labels = TaskLabel::set(pool, task.id, &payload.labels).await?;
+    if labels != previous {
+        push_labels_to_github_issue(&deployment, &task, &labels).await?;
+    }
+    Ok(ResponseJson(ApiResponse::success(labels)))
+}
+
+/// A failed push is reported as a sync error notification rather than failing
+/// the request, since the local labels are already saved
+pub(crate) async fn push_labels_to_github_issue(
+    deployment: &DeploymentImpl,
+    task: &Task,
+    labels: &[String],
+) -> Result<(), ApiError> {
+    let Some(issue_number) = IssueLink::find_by_task_id(&deployment.db().pool, task.id)
+        .await?
+        .filter(|link| link.provider == IssueProviderKind::GitHub.name())
+        .and_then(|link| link.external_id.parse::<i64>().ok())
+    else {
+        return Ok(());
+    };
+
+    let project = Project::find_by_id(&deployment.db().pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    if !project.github_label_sync.exports() {
+        return Ok(());
+    }
+    let (Some(repo_url), Some(token)) = (&project.github_repo_url, &project.github_token) else {
+        return Ok(());
+    };
+    let (owner, repo) = GitHubIssuesService::parse_repo_url(repo_url)
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    if let Err(e) = GitHubIssuesService::new()
+        .set_issue_labels(token, &owner, &repo, issue_number, labels)
+        .await
+    {
+        let error = format!("Updating labels on issue #{} failed: {}", issue_number, e);
+        notifications::record_sync_error(deployment, &project, "GitHub", &error).await;
+    }
+    Ok(())
+}
+
+pub async fn list_label_profile_rules(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<LabelProfileRule>>>, ApiError> {
+    let rules = LabelProfileRule::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(rules)))
+}
+
+/// Start tasks with the label using the profile; replaces any rule the label
+/// already has
+pub async fn upsert_label_profile_rule(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<UpsertLabelProfileRule>,
+) -> Result<ResponseJson<ApiResponse<LabelProfileRule>>, ApiError> {
+    let label = payload.label.trim();
+    if label.is_empty() {
+        return Err(ApiError::BadRequest("Label is required".to_string()));
+    }
+    if ExecutorConfigs::get_cached()
+        .get_coding_agent(&payload.executor_profile_id)
+        .is_none()
+    {
+        return Err(ApiError::BadRequest(format!(
+            "Unknown executor profile: {}",
+            payload.executor_profile_id
+        )));
+    }
+    let rule = LabelProfileRule::upsert(
+        &deployment.db().pool,
+        project.id,
+        label,
+        &payload.executor_profile_id,
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(rule)))
+}
+
+pub async fn delete_label_profile_rule(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_project_id, rule_id)): Path<(Uuid, Uuid)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    if LabelProfileRule::delete(&deployment.db().pool, project.id, rule_id).await? == 0 {
+        return Err(ApiError::BadRequest(
+            "Label profile rule not found".to_string(),
+        ));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new().route("/labels", get(get_task_labels).put(set_task_labels))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/label-profile-rules",
+            get(list_label_profile_rules).post(upsert_label_profile_rule),
+        )
+        .route(
+            "/label-profile-rules/{rule_id}",
+            delete(delete_label_profile_rule),
+        )
+}
@@ -0,0 +1,254 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use db::models::{
+    feed_event::{CreateFeedEvent, FeedEvent, FeedEventKind},
+    notification::{CreateNotification, Notification, NotificationKind},
+    task::{Task, TaskStatus},
+    task_review::{TaskReview, TaskReviewStatus},
+    user::{User, UserError},
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utils::response::{ApiResponse, ErrorCode};
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::AuthUser,
+    routes::{
+        gitlab_issues::sync_gitlab_issue_status,
+        issue_connections::sync_connected_issue_status,
+        tasks::{close_github_issue_for_task, ensure_ci_allows_done, sync_vortex_task_status},
+    },
+};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct RequestReviewRequest {
+    pub reviewer_ids: Vec<Uuid>,
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ReviewResponseRequest {
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct ReviewActionResponse {
+    pub review: TaskReview,
+    pub task: Task,
+}
+
+pub async fn list_reviews(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskReview>>>, ApiError> {
+    let reviews = TaskReview::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(reviews)))
+}
+
+pub async fn request_review(
+    auth: AuthUser,
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<RequestReviewRequest>,
+) -> Result<ResponseJson<ApiResponse<Vec<TaskReview>>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    if task.status != TaskStatus::InReview {
+        return Err(ApiError::Conflict(
+            "Reviews can only be requested for tasks in review".to_string(),
+        ));
+    }
+    if payload.reviewer_ids.is_empty() {
+        return Err(ApiError::BadRequest(
+            "At least one reviewer is required".to_string(),
+        ));
+    }
+
+    let mut reviewers = Vec::with_capacity(payload.reviewer_ids.len());
+    for reviewer_id in &payload.reviewer_ids {
+        let reviewer = User::find_by_id(pool, *reviewer_id)
+            .await?
+            .ok_or(ApiError::User(UserError::NotFound))?;
+        reviewers.push(reviewer);
+    }
+
+    let message = match payload.message.as_deref().map(str::trim) {
+        Some(message) if !message.is_empty() => {
+            format!("{} asked you to review: {}", auth.username, message)
+        }
+        _ => format!("{} asked you to review this task", auth.username),
+    };
+
+    let mut reviews = Vec::with_capacity(reviewers.len());
+    for reviewer in reviewers {
+        let review = TaskReview::request(pool, task.id, reviewer.id, Some(auth.id)).await?;
+        let notification = CreateNotification {
+            kind: NotificationKind::ReviewRequested,
+            title: format!("Review requested: {}", task.title),
+            message: message.clone(),
+            project_id: Some(task.project_id),
+            task_id: Some(task.id),
+            workspace_id: None,
+        };
+        if let Err(e) = Notification::create(pool, reviewer.id, &notification).await {
+            tracing::error!(
+                "Failed to notify {} of review request: {}",
+                reviewer.username,
+                e
+            );
+        }
+        let feed_event = CreateFeedEvent {
+            kind: FeedEventKind::ReviewRequested,
+            title: task.title.clone(),
+            project_id: Some(task.project_id),
+            task_id: Some(task.id),
+            workspace_id: None,
+        };
+        if let Err(e) = FeedEvent::create(pool, reviewer.id, &feed_event).await {
+            tracing::error!(
+                "Failed to add review request to {}'s feed: {}",
+                reviewer.username,
+                e
+            );
+        }
+        reviews.push(review);
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "task_review_requested",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "reviewer_count": reviews.len(),
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(reviews)))
+}
+
+pub async fn approve_review(
+    auth: AuthUser,
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_task_id, review_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<ReviewResponseRequest>,
+) -> Result<ResponseJson<ApiResponse<ReviewActionResponse>>, ApiError> {
+    respond(
+        auth,
+        task,
+        deployment,
+        review_id,
+        TaskReviewStatus::Approved,
+        payload.comment,
+    )
+    .await
+}
+
+pub async fn request_changes(
+    auth: AuthUser,
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_task_id, review_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<ReviewResponseRequest>,
+) -> Result<ResponseJson<ApiResponse<ReviewActionResponse>>, ApiError> {
+    respond(
+        auth,
+        task,
+        deployment,
+        review_id,
+        TaskReviewStatus::ChangesRequested,
+        payload.comment,
+    )
+    .await
+}
+
+async fn respond(
+    auth: AuthUser,
+    task: Task,
+    deployment: DeploymentImpl,
+    review_id: Uuid,
+    status: TaskReviewStatus,
+    comment: Option<String>,
+) -> Result<ResponseJson<ApiResponse<ReviewActionResponse>>, ApiError> {
+    let pool = &deployment.db().pool;
+
+    let review = TaskReview::find_by_id(pool, review_id)
+        .await?
+        .filter(|review| review.task_id == task.id)
+        .ok_or_else(|| ApiError::coded(ErrorCode::NotFound, "Review not found for this task"))?;
+    if review.reviewer_id != auth.id {
+        return Err(ApiError::Forbidden(
+            "Only the requested reviewer can respond to this review".to_string(),
+        ));
+    }
+
+    let comment = comment.filter(|c| !c.trim().is_empty());
+    let review = TaskReview::respond(pool, review.id, status, comment.as_deref()).await?;
+
+    // The last outstanding approval completes the task, unless it has to wait for CI
+    let mut task = task;
+    if status == TaskReviewStatus::Approved
+        && task.status == TaskStatus::InReview
+        && TaskReview::all_approved(pool, task.id).await?
+        && ensure_ci_allows_done(&deployment, &task)
+            .await
+            .inspect_err(|e| tracing::info!("Task {} stays in review: {}", task.id, e))
+            .is_ok()
+    {
+        Task::update_status(pool, task.id, TaskStatus::Done).await?;
+        task.status = TaskStatus::Done;
+
+        let comment = format!(
+            "Task approved by {} in Vibe-Kanban.\n\nTask: {}",
+            auth.username, task.title
+        );
+        if let Err(e) = sync_vortex_task_status(&deployment, &task, &comment).await {
+            tracing::warn!("Failed to sync Vortex status for task {}: {}", task.id, e);
+        }
+        if let Err(e) = sync_connected_issue_status(&deployment, &task).await {
+            tracing::warn!("Failed to sync issue status for task {}: {}", task.id, e);
+        }
+        if let Err(e) = sync_gitlab_issue_status(&deployment, &task).await {
+            tracing::warn!("Failed to sync GitLab status for task {}: {}", task.id, e);
+        }
+        if let Err(e) = close_github_issue_for_task(&deployment, &task, &comment).await {
+            tracing::warn!("Failed to close GitHub issue for task {}: {}", task.id, e);
+        }
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "task_review_submitted",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "status": review.status.to_string(),
+                "task_completed": task.status == TaskStatus::Done,
+            }),
+        )
+        .await;
+
+    Ok(ResponseJson(ApiResponse::success(ReviewActionResponse {
+        review,
+        task,
+    })))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/reviews", get(list_reviews))
+        .route("/request-review", post(request_review))
+        .route("/reviews/{review_id}/approve", post(approve_review))
+        .route(
+            "/reviews/{review_id}/request-changes",
+            post(request_changes),
+        )
+}
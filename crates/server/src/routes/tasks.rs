@@ -12,34 +12,71 @@ use axum::{
     response::{IntoResponse, Json as ResponseJson},
     routing::{delete, get, post, put},
 };
+use chrono::{DateTime, Utc};
 use db::models::{
     image::TaskImage,
+    mention::MentionSource,
+    pending_start::PendingStart,
     project::{Project, ProjectError},
+    project_executor_weight::ProjectExecutorWeight,
     project_repo::ProjectRepo,
+    project_status_transition::ProjectStatusTransition,
     repo::Repo,
+    secret_scan_finding::{SecretScanFinding, SecretScanOperation},
     task::{CreateTask, ExecutionMode, Task, TaskStatus, TaskWithAttemptStatus, UpdateTask},
+    task_dependency::TaskDependency,
+    task_draft::TaskDraft,
+    task_estimate::TaskEstimate,
+    task_label::TaskLabel,
+    task_owner::TaskOwner,
     workspace::{CreateWorkspace, Workspace},
     workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
 };
 use deployment::Deployment;
-use executors::profile::ExecutorConfigs;
 use executors::profile::ExecutorProfileId;
 use futures_util::{SinkExt, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use services::services::{
-    container::ContainerService, share::ShareError, workspace_manager::WorkspaceManager,
+    capacity::queue_eta,
+    ci_status,
+    container::ContainerService,
+    executor_slots::SlotPermit,
+    issue_provider::IssueProviderKind,
+    managed_branches,
+    secret_scan,
+    sequential_queue::plan_queue,
+    share::ShareError,
+    status_transitions,
+    task_duplicates::{PotentialDuplicate, find_potential_duplicates},
+    task_validation::{TaskValidationReport, validate_task},
+    workspace_manager::WorkspaceManager,
 };
-use sqlx::Error as SqlxError;
+use sqlx::{Error as SqlxError, SqlitePool};
 use ts_rs::TS;
-use utils::{api::oauth::LoginStatus, response::ApiResponse};
+use utils::{
+    api::oauth::LoginStatus,
+    response::{ApiResponse, ErrorCode},
+    validation::Validate,
+};
 use uuid::Uuid;
 
 use crate::{
-    DeploymentImpl, error::ApiError, middleware::load_task_middleware,
-    routes::task_attempts::WorkspaceRepoInput,
-};
-use services::services::vortex_issues::{
-    VortexIssuesService, extract_vortex_issue_id_from_description, is_vortex_imported_task,
+    DeploymentImpl,
+    error::ApiError,
+    middleware::{OptionalAuth, load_task_middleware},
+    routes::{
+        calendar, capacity,
+        executor_slots::auto_start_profile,
+        gitlab_issues::sync_gitlab_issue_status,
+        issue_connections::sync_connected_issue_status,
+        issue_sync::linked_issue,
+        my_tasks,
+        notifications::record_mentions,
+        project_instructions,
+        task_attempts::{self, WorkspaceRepoInput},
+        task_dependencies, task_labels, task_reviews, token_budgets,
+        vortex_issues::sync_vortex_content,
+    },
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -51,9 +88,11 @@ pub async fn get_tasks(
     State(deployment): State<DeploymentImpl>,
     Query(query): Query<TaskQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<TaskWithAttemptStatus>>>, ApiError> {
-    let tasks =
-        Task::find_by_project_id_with_attempt_status(&deployment.db().pool, query.project_id)
-            .await?;
+    let pool = &deployment.db().pool;
+    let mut tasks = Task::find_by_project_id_with_attempt_status(pool, query.project_id).await?;
+    // Drafts are listed separately until they are triaged
+    let drafts = TaskDraft::task_ids_for_project(pool, query.project_id).await?;
+    tasks.retain(|task| !drafts.contains(&task.id));
 
     Ok(ResponseJson(ApiResponse::success(tasks)))
 }
@@ -113,9 +152,11 @@ pub async fn get_task(
 }
 
 pub async fn create_task(
+    OptionalAuth(auth): OptionalAuth,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateTask>,
 ) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    payload.validate()?;
     let id = Uuid::new_v4();
 
     tracing::debug!(
@@ -124,11 +165,28 @@ pub async fn create_task(
         payload.project_id
     );
 
+    check_task_validation(&deployment, &payload.title, payload.description.as_deref()).await?;
+
     let task = Task::create(&deployment.db().pool, &payload, id).await?;
 
     if let Some(image_ids) = &payload.image_ids {
         TaskImage::associate_many_dedup(&deployment.db().pool, task.id, image_ids).await?;
     }
+    if let Some(user) = &auth {
+        TaskOwner::set_creator(&deployment.db().pool, task.id, user.id).await?;
+    }
+    if let Some(description) = &task.description {
+        record_mentions(
+            &deployment,
+            MentionSource::TaskDescription,
+            task.id,
+            &task,
+            None,
+            auth.as_ref(),
+            description,
+        )
+        .await;
+    }
 
     deployment
         .track_if_analytics_allowed(
@@ -145,23 +203,102 @@ pub async fn create_task(
     Ok(ResponseJson(ApiResponse::success(task)))
 }
 
+/// Run the configured validation rules, rejecting the request if any rule is set to error
+pub(crate) async fn check_task_validation(
+    deployment: &DeploymentImpl,
+    title: &str,
+    description: Option<&str>,
+) -> Result<(), ApiError> {
+    let report = validate_task(
+        &deployment.config().read().await.task_validation,
+        title,
+        description,
+    );
+    for warning in report.warnings() {
+        tracing::warn!("Task validation warning: {}", warning.message);
+    }
+    match report.error_message() {
+        Some(message) => Err(ApiError::BadRequest(message)),
+        None => Ok(()),
+    }
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct ValidateTaskRequest {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+/// Dry-run the validation rules so the UI can show warnings before saving
+pub async fn validate_task_content(
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<ValidateTaskRequest>,
+) -> Result<ResponseJson<ApiResponse<TaskValidationReport>>, ApiError> {
+    let report = validate_task(
+        &deployment.config().read().await.task_validation,
+        &payload.title,
+        payload.description.as_deref(),
+    );
+    Ok(ResponseJson(ApiResponse::success(report)))
+}
+
+#[derive(Debug, Deserialize, TS)]
+pub struct CheckDuplicatesRequest {
+    pub project_id: Uuid,
+    pub title: String,
+    /// The task being edited, which shouldn't be reported as its own duplicate
+    pub exclude_task_id: Option<Uuid>,
+}
+
+/// Open tasks of the project whose titles are similar to the one being written,
+/// so the UI can point at them before another copy is created
+pub async fn check_duplicates(
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<CheckDuplicatesRequest>,
+) -> Result<ResponseJson<ApiResponse<Vec<PotentialDuplicate>>>, ApiError> {
+    let duplicates = find_potential_duplicates(
+        &deployment.db().pool,
+        payload.project_id,
+        &payload.title,
+        payload.exclude_task_id,
+    )
+    .await?;
+    Ok(ResponseJson(ApiResponse::success(duplicates)))
+}
+
 #[derive(Debug, Deserialize, TS)]
 pub struct CreateAndStartTaskRequest {
     pub task: CreateTask,
-    pub executor_profile_id: ExecutorProfileId,
+    /// Resolved from the project's label rules and history when not given
+    #[serde(default)]
+    #[ts(optional)]
+    pub executor_profile_id: Option<ExecutorProfileId>,
     pub repos: Vec<WorkspaceRepoInput>,
+    #[serde(default)]
+    #[ts(optional)]
+    pub labels: Option<Vec<String>>,
 }
 
 pub async fn create_task_and_start(
+    OptionalAuth(auth): OptionalAuth,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<CreateAndStartTaskRequest>,
 ) -> Result<ResponseJson<ApiResponse<TaskWithAttemptStatus>>, ApiError> {
+    payload.task.validate()?;
     if payload.repos.is_empty() {
-        return Err(ApiError::BadRequest(
-            "At least one repository is required".to_string(),
+        return Err(ApiError::coded(
+            ErrorCode::RepoRequired,
+            "At least one repository is required",
         ));
     }
 
+    check_task_validation(
+        &deployment,
+        &payload.task.title,
+        payload.task.description.as_deref(),
+    )
+    .await?;
+
     let pool = &deployment.db().pool;
 
     let task_id = Uuid::new_v4();
@@ -170,6 +307,24 @@ pub async fn create_task_and_start(
     if let Some(image_ids) = &payload.task.image_ids {
         TaskImage::associate_many_dedup(pool, task.id, image_ids).await?;
     }
+    if let Some(user) = &auth {
+        TaskOwner::set_creator(pool, task.id, user.id).await?;
+    }
+    if let Some(labels) = &payload.labels {
+        TaskLabel::set(pool, task.id, labels).await?;
+    }
+    if let Some(description) = &task.description {
+        record_mentions(
+            &deployment,
+            MentionSource::TaskDescription,
+            task.id,
+            &task,
+            None,
+            auth.as_ref(),
+            description,
+        )
+        .await;
+    }
 
     deployment
         .track_if_analytics_allowed(
@@ -210,19 +365,20 @@ pub async fn create_task_and_start(
     )
     .await?;
 
-    let workspace_repos: Vec<CreateWorkspaceRepo> = payload
-        .repos
-        .iter()
-        .map(|r| CreateWorkspaceRepo {
-            repo_id: r.repo_id,
-            target_branch: r.target_branch.clone(),
-        })
-        .collect();
+    let workspace_repos =
+        task_attempts::resolve_workspace_repos(&deployment, project.id, &payload.repos).await?;
     WorkspaceRepo::create_many(&deployment.db().pool, workspace.id, &workspace_repos).await?;
 
+    let executor_profile_id = match payload.executor_profile_id.clone() {
+        Some(profile) => profile,
+        None => auto_start_profile(&deployment, &task)
+            .await
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?,
+    };
+
     let is_attempt_running = deployment
         .container()
-        .start_workspace(&workspace, payload.executor_profile_id.clone())
+        .start_workspace(&workspace, executor_profile_id.clone())
         .await
         .inspect_err(|err| tracing::error!("Failed to start task attempt: {}", err))
         .is_ok();
@@ -231,8 +387,8 @@ pub async fn create_task_and_start(
             "task_attempt_started",
             serde_json::json!({
                 "task_id": task.id.to_string(),
-                "executor": &payload.executor_profile_id.executor,
-                "variant": &payload.executor_profile_id.variant,
+                "executor": &executor_profile_id.executor,
+                "variant": &executor_profile_id.variant,
                 "workspace_id": workspace.id.to_string(),
             }),
         )
@@ -247,18 +403,27 @@ pub async fn create_task_and_start(
         task,
         has_in_progress_attempt: is_attempt_running,
         last_attempt_failed: false,
-        executor: payload.executor_profile_id.executor.to_string(),
+        executor: executor_profile_id.executor.to_string(),
         latest_workspace_id: Some(workspace.id),
         latest_workspace_container_ref: workspace.container_ref.clone(),
     })))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UpdateTaskQuery {
+    /// Let an admin bypass the project's status transition rules
+    #[serde(default)]
+    pub force_status: bool,
+}
+
 pub async fn update_task(
+    OptionalAuth(auth): OptionalAuth,
     Extension(existing_task): Extension<Task>,
     State(deployment): State<DeploymentImpl>,
-
+    Query(query): Query<UpdateTaskQuery>,
     Json(payload): Json<UpdateTask>,
 ) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
+    payload.validate()?;
     ensure_shared_task_auth(&existing_task, &deployment).await?;
 
     // Use existing values if not provided in update
@@ -276,6 +441,34 @@ pub async fn update_task(
         .parent_workspace_id
         .or(existing_task.parent_workspace_id);
 
+    if query.force_status && !auth.as_ref().is_some_and(|user| user.is_admin()) {
+        return Err(ApiError::Forbidden(
+            "Only admins can override status transition rules".to_string(),
+        ));
+    }
+    if !query.force_status {
+        let rules = ProjectStatusTransition::find_by_project_id(
+            &deployment.db().pool,
+            existing_task.project_id,
+        )
+        .await?;
+        if !status_transitions::is_transition_allowed(&rules, &existing_task.status, &status) {
+            return Err(ApiError::Conflict(status_transitions::rejection_message(
+                &rules,
+                &existing_task.status,
+                &status,
+            )));
+        }
+        if status == TaskStatus::Done && existing_task.status != TaskStatus::Done {
+            ensure_ci_allows_done(&deployment, &existing_task).await?;
+        }
+    }
+
+    // Only re-validate when the text changes, so status moves on older tasks keep working
+    if title != existing_task.title || description != existing_task.description {
+        check_task_validation(&deployment, &title, description.as_deref()).await?;
+    }
+
     // Check if status is changing TO InProgress (for auto-start)
     let status_changing_to_in_progress =
         existing_task.status != TaskStatus::InProgress && status == TaskStatus::InProgress;
@@ -285,9 +478,12 @@ pub async fn update_task(
         existing_task.status != TaskStatus::InReview && status == TaskStatus::InReview;
 
     // Check if a sequential task is leaving InProgress (triggers next queue item)
-    let sequential_task_leaving_in_progress = existing_task.execution_mode == ExecutionMode::Sequential
+    let sequential_task_leaving_in_progress = existing_task.execution_mode
+        == ExecutionMode::Sequential
         && existing_task.status == TaskStatus::InProgress
-        && (status == TaskStatus::InReview || status == TaskStatus::Done || status == TaskStatus::Cancelled);
+        && (status == TaskStatus::InReview
+            || status == TaskStatus::Done
+            || status == TaskStatus::Cancelled);
 
     let task = Task::update(
         &deployment.db().pool,
@@ -324,6 +520,25 @@ pub async fn update_task(
         TaskImage::associate_many_dedup(&deployment.db().pool, task.id, image_ids).await?;
     }
 
+    if description != existing_task.description {
+        record_mentions(
+            &deployment,
+            MentionSource::TaskDescription,
+            task.id,
+            &task,
+            None,
+            auth.as_ref(),
+            description.as_deref().unwrap_or_default(),
+        )
+        .await;
+    }
+
+    if (title != existing_task.title || description != existing_task.description)
+        && let Err(e) = sync_vortex_content(&deployment, &task).await
+    {
+        tracing::warn!("Failed to sync Vortex content for task {}: {}", task.id, e);
+    }
+
     // Auto-start Claude when task moves to InProgress and no attempt is running
     if status_changing_to_in_progress {
         let has_running = deployment
@@ -334,7 +549,8 @@ pub async fn update_task(
 
         if !has_running {
             // Try to auto-start the task
-            if let Err(e) = auto_start_task(&deployment, &task).await {
+            let requested_by = auth.as_ref().map(|user| user.id);
+            if let Err(e) = request_auto_start(&deployment, &task, requested_by).await {
                 tracing::warn!("Failed to auto-start task {}: {}", task.id, e);
                 // Don't fail the update, just log the warning
             }
@@ -342,11 +558,27 @@ pub async fn update_task(
     }
 
     if status_changing_to_in_review {
-        if let Err(e) = sync_vortex_task_status(&deployment, &task).await {
+        let comment = format!(
+            "Task moved to review in Vibe-Kanban.\n\nTask: {}",
+            task.title
+        );
+        if let Err(e) = sync_vortex_task_status(&deployment, &task, &comment).await {
             tracing::warn!("Failed to sync Vortex status for task {}: {}", task.id, e);
         }
     }
 
+    if existing_task.status != task.status
+        && let Err(e) = sync_connected_issue_status(&deployment, &task).await
+    {
+        tracing::warn!("Failed to sync issue status for task {}: {}", task.id, e);
+    }
+
+    if existing_task.status != task.status
+        && let Err(e) = sync_gitlab_issue_status(&deployment, &task).await
+    {
+        tracing::warn!("Failed to sync GitLab status for task {}: {}", task.id, e);
+    }
+
     // Auto-start next task in queue when a sequential task leaves InProgress
     if sequential_task_leaving_in_progress {
         if let Err(e) = start_next_in_queue(&deployment, existing_task.project_id).await {
@@ -356,6 +588,8 @@ pub async fn update_task(
                 e
             );
         }
+    } else if existing_task.status != TaskStatus::Done && task.status == TaskStatus::Done {
+        resume_queue_after_dependency_done(&deployment, &task).await;
     }
 
     // Re-fetch the task to get updated execution_mode and queue_position
@@ -374,8 +608,44 @@ pub async fn update_task(
     Ok(ResponseJson(ApiResponse::success(task)))
 }
 
+/// The next todo task in the project's sequential queue whose dependencies
+/// are all done
+async fn next_ready_in_queue(
+    pool: &SqlitePool,
+    project_id: Uuid,
+) -> Result<Option<Task>, ApiError> {
+    let queue = Task::find_sequential_queue_for_project(pool, project_id).await?;
+    let dependencies = TaskDependency::find_by_project_id(pool, project_id).await?;
+    let next = plan_queue(&queue, &dependencies).next_ready();
+    Ok(queue.into_iter().find(|t| Some(t.id) == next))
+}
+
+/// A task other tasks depend on was just marked done; the queue may have been
+/// held waiting for it, so pick up the next ready task
+pub(crate) async fn resume_queue_after_dependency_done(deployment: &DeploymentImpl, task: &Task) {
+    match TaskDependency::has_dependents(&deployment.db().pool, task.id).await {
+        Ok(true) => {}
+        Ok(false) => return,
+        Err(e) => {
+            tracing::warn!("Failed to load dependents of task {}: {}", task.id, e);
+            return;
+        }
+    }
+    if let Err(e) = start_next_in_queue(deployment, task.project_id).await {
+        tracing::warn!(
+            "Failed to resume queue for project {} after task {} was done: {}",
+            task.project_id,
+            task.id,
+            e
+        );
+    }
+}
+
 /// Start the next task in the sequential queue for a project
-async fn start_next_in_queue(deployment: &DeploymentImpl, project_id: Uuid) -> Result<(), ApiError> {
+async fn start_next_in_queue(
+    deployment: &DeploymentImpl,
+    project_id: Uuid,
+) -> Result<(), ApiError> {
     let pool = &deployment.db().pool;
 
     // Check if there's already a sequential task in progress
@@ -387,11 +657,11 @@ async fn start_next_in_queue(deployment: &DeploymentImpl, project_id: Uuid) -> R
         return Ok(());
     }
 
-    // Get the next task in the queue
-    let next_task = match Task::get_next_in_queue(pool, project_id).await? {
+    // Get the next task in the queue that isn't waiting on a dependency
+    let next_task = match next_ready_in_queue(pool, project_id).await? {
         Some(task) => task,
         None => {
-            tracing::debug!("No more tasks in queue for project {}", project_id);
+            tracing::debug!("No ready tasks in queue for project {}", project_id);
             return Ok(());
         }
     };
@@ -408,10 +678,10 @@ async fn start_next_in_queue(deployment: &DeploymentImpl, project_id: Uuid) -> R
     // Fetch the updated task
     let task = Task::find_by_id(pool, next_task.id)
         .await?
-        .ok_or(ApiError::BadRequest("Task not found".to_string()))?;
+        .ok_or_else(|| ApiError::coded(ErrorCode::TaskNotFound, "Task not found"))?;
 
     // Auto-start the task
-    auto_start_task(deployment, &task).await?;
+    request_auto_start(deployment, &task, None).await?;
 
     deployment
         .track_if_analytics_allowed(
@@ -426,9 +696,79 @@ async fn start_next_in_queue(deployment: &DeploymentImpl, project_id: Uuid) -> R
     Ok(())
 }
 
-/// Auto-start a task by creating a workspace and starting the agent
-async fn auto_start_task(deployment: &DeploymentImpl, task: &Task) -> Result<(), ApiError> {
+/// Auto-start a task, unless its project wants starts confirmed; then record a
+/// pending start for someone to approve instead
+async fn request_auto_start(
+    deployment: &DeploymentImpl,
+    task: &Task,
+    requested_by: Option<Uuid>,
+) -> Result<(), ApiError> {
     let pool = &deployment.db().pool;
+    let project = Project::find_by_id(pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    if !project.confirm_auto_start {
+        return auto_start_task(deployment, task).await;
+    }
+
+    if PendingStart::create(pool, task.id, task.project_id, requested_by)
+        .await?
+        .is_some()
+    {
+        tracing::info!("Task {} is waiting for its start to be approved", task.id);
+    }
+    Ok(())
+}
+
+/// Auto-start a task once an executor slot is free for its project. Starts
+/// right away when one is; otherwise the start waits in the background for the
+/// project's turn.
+pub(crate) async fn auto_start_task(deployment: &DeploymentImpl, task: &Task) -> Result<(), ApiError> {
+    let slots = deployment.container().executor_slots().clone();
+    let weight = ProjectExecutorWeight::find_by_project_id(&deployment.db().pool, task.project_id)
+        .await?
+        .slot_weight();
+    if let Some(permit) = slots.try_acquire(task.project_id, weight) {
+        return start_auto_attempt(deployment, task, permit).await;
+    }
+
+    tracing::info!("Task {} is waiting for an executor slot", task.id);
+    let deployment = deployment.clone();
+    let (task_id, project_id) = (task.id, task.project_id);
+    tokio::spawn(async move {
+        let permit = slots.acquire(project_id, weight).await;
+        // The task may have moved on, or been started by hand, while it waited
+        let task = match Task::find_by_id(&deployment.db().pool, task_id).await {
+            Ok(Some(task)) if task.status == TaskStatus::InProgress => task,
+            _ => return,
+        };
+        if deployment
+            .container()
+            .has_running_processes(task.id)
+            .await
+            .unwrap_or(true)
+        {
+            return;
+        }
+        if let Err(e) = start_auto_attempt(&deployment, &task, permit).await {
+            tracing::warn!("Failed to auto-start task {}: {}", task.id, e);
+        }
+    });
+    Ok(())
+}
+
+/// Create a workspace for an auto-started task and start the agent, holding
+/// `permit` until the attempt finishes
+async fn start_auto_attempt(
+    deployment: &DeploymentImpl,
+    task: &Task,
+    permit: SlotPermit,
+) -> Result<(), ApiError> {
+    let pool = &deployment.db().pool;
+    deployment
+        .container()
+        .ensure_no_running_attempt(task.id)
+        .await?;
 
     // Get project repos with their full details
     let repos = ProjectRepo::find_repos_for_project(pool, task.project_id).await?;
@@ -440,9 +780,8 @@ async fn auto_start_task(deployment: &DeploymentImpl, task: &Task) -> Result<(),
         return Ok(());
     }
 
-    // Get recommended executor profile
-    let executor_configs = ExecutorConfigs::get_cached();
-    let executor_profile_id = match executor_configs.get_recommended_executor_profile().await {
+    // Prefer the profile that has worked best on this project
+    let executor_profile_id = match auto_start_profile(deployment, task).await {
         Ok(profile) => profile,
         Err(e) => {
             tracing::info!("Cannot auto-start task {}: {}", task.id, e);
@@ -478,13 +817,11 @@ async fn auto_start_task(deployment: &DeploymentImpl, task: &Task) -> Result<(),
     )
     .await?;
 
-    // Create workspace repos using each repo's current branch as target
+    // Create workspace repos targeting each repo's default branch
     let mut workspace_repos: Vec<CreateWorkspaceRepo> = Vec::new();
     for repo in &repos {
-        let target_branch = deployment
-            .git()
-            .get_current_branch(&repo.path)
-            .unwrap_or_else(|_| "main".to_string());
+        let target_branch =
+            task_attempts::default_target_branch(deployment, task.project_id, repo).await?;
         workspace_repos.push(CreateWorkspaceRepo {
             repo_id: repo.id,
             target_branch,
@@ -493,11 +830,16 @@ async fn auto_start_task(deployment: &DeploymentImpl, task: &Task) -> Result<(),
     WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos).await?;
 
     // Start the workspace
+    let slots = deployment.container().executor_slots();
+    slots.hold(workspace.id, permit);
     deployment
         .container()
         .start_workspace(&workspace, executor_profile_id.clone())
         .await
-        .inspect_err(|err| tracing::error!("Failed to auto-start task attempt: {}", err))?;
+        .inspect_err(|err| {
+            slots.release(workspace.id);
+            tracing::error!("Failed to auto-start task attempt: {}", err)
+        })?;
 
     deployment
         .track_if_analytics_allowed(
@@ -515,60 +857,76 @@ async fn auto_start_task(deployment: &DeploymentImpl, task: &Task) -> Result<(),
     Ok(())
 }
 
-async fn sync_vortex_task_status(deployment: &DeploymentImpl, task: &Task) -> Result<(), ApiError> {
-    let description = match &task.description {
-        Some(d) => d,
-        None => return Ok(()),
+pub(crate) async fn sync_vortex_task_status(
+    deployment: &DeploymentImpl,
+    task: &Task,
+    comment: &str,
+) -> Result<(), ApiError> {
+    let project = Project::find_by_id(&deployment.db().pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    let Some((provider, vortex_issue_id)) =
+        linked_issue(deployment, &project, task, IssueProviderKind::Vortex).await?
+    else {
+        return Ok(());
     };
 
-    if !is_vortex_imported_task(description) {
-        return Ok(());
+    if let Err(e) = provider.update_status(&vortex_issue_id, task.status.clone()).await {
+        tracing::warn!("Failed to update Vortex issue status: {}", e);
     }
 
-    let vortex_issue_id = match extract_vortex_issue_id_from_description(description) {
-        Some(id) => id,
-        None => {
-            tracing::debug!("Could not extract Vortex issue ID from task {}", task.id);
-            return Ok(());
-        }
-    };
+    if let Err(e) = provider.add_comment(&vortex_issue_id, comment).await {
+        tracing::warn!("Failed to add Vortex comment: {}", e);
+    }
+
+    deployment
+        .track_if_analytics_allowed(
+            "vortex_status_synced",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "vortex_issue_id": vortex_issue_id,
+                "new_status": task.status.to_string(),
+            }),
+        )
+        .await;
 
+    Ok(())
+}
+
+
+/// Close the GitHub issue a task was imported from, leaving `comment` on it
+pub(crate) async fn close_github_issue_for_task(
+    deployment: &DeploymentImpl,
+    task: &Task,
+    comment: &str,
+) -> Result<(), ApiError> {
     let project = Project::find_by_id(&deployment.db().pool, task.project_id)
         .await?
         .ok_or(ProjectError::ProjectNotFound)?;
-
-    let token = match &project.vortex_token {
-        Some(t) => t.clone(),
-        None => return Ok(()),
+    let Some((provider, issue_number)) =
+        linked_issue(deployment, &project, task, IssueProviderKind::GitHub).await?
+    else {
+        return Ok(());
     };
 
-    let service = VortexIssuesService::new();
-
-    if let Err(e) = service
-        .update_issue_status(&token, &vortex_issue_id, "In Review")
-        .await
-    {
-        tracing::warn!("Failed to update Vortex issue status: {}", e);
+    if let Err(e) = provider.add_comment(&issue_number, comment).await {
+        tracing::warn!("Failed to add GitHub issue comment: {}", e);
     }
 
-    let comment_content = format!(
-        "Task moved to review in Vibe-Kanban.\n\nTask: {}",
-        task.title
-    );
-    if let Err(e) = service
-        .add_comment_as_current_user(&token, &vortex_issue_id, &comment_content)
+    if let Err(e) = provider
+        .update_status(&issue_number, TaskStatus::Done)
         .await
     {
-        tracing::warn!("Failed to add Vortex comment: {}", e);
+        tracing::warn!("Failed to close GitHub issue #{}: {}", issue_number, e);
+        return Ok(());
     }
 
     deployment
         .track_if_analytics_allowed(
-            "vortex_status_synced",
+            "github_issue_closed",
             serde_json::json!({
                 "task_id": task.id.to_string(),
-                "vortex_issue_id": vortex_issue_id,
-                "new_status": "In Review",
+                "issue_number": issue_number,
             }),
         )
         .await;
@@ -576,6 +934,25 @@ async fn sync_vortex_task_status(deployment: &DeploymentImpl, task: &Task) -> Re
     Ok(())
 }
 
+/// Refuse to complete a task while CI is pending or failing on its pull
+/// requests, in projects that wait for CI
+pub(crate) async fn ensure_ci_allows_done(
+    deployment: &DeploymentImpl,
+    task: &Task,
+) -> Result<(), ApiError> {
+    let pool = &deployment.db().pool;
+    let project = Project::find_by_id(pool, task.project_id)
+        .await?
+        .ok_or(ProjectError::ProjectNotFound)?;
+    match ci_status::done_blocked_reason(pool, &project, task.id).await {
+        Ok(None) => Ok(()),
+        Ok(Some(reason)) => Err(ApiError::Conflict(reason)),
+        Err(e) => Err(ApiError::Conflict(format!(
+            "Could not check CI before completing the task: {e}"
+        ))),
+    }
+}
+
 async fn ensure_shared_task_auth(
     existing_task: &Task,
     deployment: &local_deployment::LocalDeployment,
@@ -603,7 +980,10 @@ pub async fn delete_task(
         .has_running_processes(task.id)
         .await?
     {
-        return Err(ApiError::Conflict("Task has running execution processes. Please wait for them to complete or stop them first.".to_string()));
+        return Err(ApiError::coded(
+            ErrorCode::TaskHasRunningProcesses,
+            "Task has running execution processes. Please wait for them to complete or stop them first.",
+        ));
     }
 
     let pool = &deployment.db().pool;
@@ -679,6 +1059,8 @@ pub async fn delete_task(
 
     let task_id = task.id;
     let pool = pool.clone();
+    let keep_branches = deployment.config().read().await.keep_branches_after_cleanup;
+    let git = deployment.git().clone();
     tokio::spawn(async move {
         tracing::info!(
             "Starting background cleanup for task {} ({} workspaces, {} repos)",
@@ -697,6 +1079,8 @@ pub async fn delete_task(
                     workspace_dir.display(),
                     e
                 );
+            } else if !keep_branches {
+                managed_branches::cleanup_branches(&pool, &git, &repositories, branch).await;
             }
         }
 
@@ -722,13 +1106,22 @@ pub struct ShareTaskResponse {
     pub shared_task_id: Uuid,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct ShareTaskQuery {
+    /// Share even if the secret scan flags an attempt's changes
+    #[serde(default)]
+    pub allow_secrets: bool,
+}
+
 pub async fn share_task(
     Extension(task): Extension<Task>,
     State(deployment): State<DeploymentImpl>,
+    Query(query): Query<ShareTaskQuery>,
 ) -> Result<ResponseJson<ApiResponse<ShareTaskResponse>>, ApiError> {
     let Ok(publisher) = deployment.share_publisher() else {
         return Err(ShareError::MissingConfig("share publisher unavailable").into());
     };
+    scan_task_attempts_for_secrets(&deployment, &task, query.allow_secrets).await?;
     let profile = deployment
         .auth_context()
         .cached_profile()
@@ -749,6 +1142,73 @@ pub async fn share_task(
     })))
 }
 
+/// Scan every attempt of a task before it's shared, recording anything found. Fails unless
+/// `allow_secrets` is set.
+async fn scan_task_attempts_for_secrets(
+    deployment: &DeploymentImpl,
+    task: &Task,
+    allow_secrets: bool,
+) -> Result<(), ApiError> {
+    let pool = &deployment.db().pool;
+    let mut flagged = Vec::new();
+    for workspace in Workspace::fetch_all(pool, Some(task.id)).await? {
+        let findings =
+            match secret_scan::scan_workspace(pool, deployment.git(), &workspace, None).await {
+                Ok(findings) => findings,
+                Err(e) => {
+                    // Attempts whose branch is gone have nothing left to leak
+                    tracing::warn!("Skipping secret scan for workspace {}: {}", workspace.id, e);
+                    continue;
+                }
+            };
+        if !findings.is_empty() {
+            flagged.extend(
+                SecretScanFinding::record(
+                    pool,
+                    workspace.id,
+                    SecretScanOperation::Share,
+                    allow_secrets,
+                    &findings,
+                )
+                .await?,
+            );
+        }
+    }
+
+    if flagged.is_empty() {
+        return Ok(());
+    }
+    deployment
+        .track_if_analytics_allowed(
+            "secret_scan_flagged",
+            serde_json::json!({
+                "task_id": task.id.to_string(),
+                "operation": "share",
+                "finding_count": flagged.len(),
+                "overridden": allow_secrets,
+            }),
+        )
+        .await;
+    if allow_secrets {
+        return Ok(());
+    }
+
+    let locations: Vec<String> = flagged
+        .iter()
+        .take(5)
+        .map(|f| format!("{} in {}:{}", f.kind, f.file_path, f.line_number))
+        .collect();
+    let more = flagged.len() - locations.len();
+    let summary = if more > 0 {
+        format!("{}, and {more} more", locations.join(", "))
+    } else {
+        locations.join(", ")
+    };
+    Err(ApiError::Conflict(format!(
+        "Possible secrets found in attempt changes ({summary}). Remove them or share again with the override enabled."
+    )))
+}
+
 #[derive(Debug, Serialize, Deserialize, TS)]
 pub struct ReorderQueueRequest {
     pub new_position: i32,
@@ -762,8 +1222,9 @@ pub async fn reorder_queue(
 ) -> Result<ResponseJson<ApiResponse<Task>>, ApiError> {
     // Verify task is in sequential mode
     if task.execution_mode != ExecutionMode::Sequential {
-        return Err(ApiError::BadRequest(
-            "Task is not in sequential mode".to_string(),
+        return Err(ApiError::coded(
+            ErrorCode::QueueNotSequential,
+            "Task is not in sequential mode",
         ));
     }
 
@@ -780,13 +1241,25 @@ pub async fn reorder_queue(
     Ok(ResponseJson(ApiResponse::success(updated_task)))
 }
 
-/// Get the sequential queue for a project
+/// Get the sequential queue for a project. Started tasks come first, then
+/// todo tasks in the order they will run given their dependencies.
 pub async fn get_sequential_queue(
     State(deployment): State<DeploymentImpl>,
     Query(query): Query<TaskQuery>,
 ) -> Result<ResponseJson<ApiResponse<Vec<Task>>>, ApiError> {
-    let tasks =
-        Task::find_sequential_queue_for_project(&deployment.db().pool, query.project_id).await?;
+    let pool = &deployment.db().pool;
+    let queue = Task::find_sequential_queue_for_project(pool, query.project_id).await?;
+    let dependencies = TaskDependency::find_by_project_id(pool, query.project_id).await?;
+    let plan = plan_queue(&queue, &dependencies);
+
+    let (mut tasks, todo): (Vec<Task>, Vec<Task>) = queue
+        .into_iter()
+        .partition(|t| t.status != TaskStatus::Todo);
+    tasks.extend(
+        plan.order
+            .iter()
+            .filter_map(|id| todo.iter().find(|t| t.id == *id).cloned()),
+    );
     Ok(ResponseJson(ApiResponse::success(tasks)))
 }
 
@@ -796,6 +1269,34 @@ pub struct QueueProcessingStatus {
     pub is_processing: bool,
     pub current_task_id: Option<Uuid>,
     pub queue_length: usize,
+    /// When the queue should drain, from the hour estimates of queued tasks
+    pub estimated_completion_at: Option<DateTime<Utc>>,
+    /// Queued tasks without an hour estimate, left out of the ETA
+    pub unestimated_tasks: usize,
+    /// Todo tasks held back until their dependencies are done
+    pub blocked_task_ids: Vec<Uuid>,
+}
+
+impl QueueProcessingStatus {
+    async fn new(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        queue: &[Task],
+        current_task_id: Option<Uuid>,
+    ) -> Result<Self, ApiError> {
+        let estimates = TaskEstimate::find_open_by_project_id(pool, project_id).await?;
+        let queued: Vec<Uuid> = queue.iter().map(|t| t.id).collect();
+        let eta = queue_eta(Utc::now(), &queued, &estimates);
+        let dependencies = TaskDependency::find_by_project_id(pool, project_id).await?;
+        Ok(Self {
+            is_processing: current_task_id.is_some(),
+            current_task_id,
+            queue_length: queue.len(),
+            estimated_completion_at: eta.estimated_completion_at,
+            unestimated_tasks: eta.unestimated_tasks,
+            blocked_task_ids: plan_queue(queue, &dependencies).blocked,
+        })
+    }
 }
 
 /// Start processing the sequential queue for a project
@@ -809,22 +1310,23 @@ pub async fn start_queue_processing(
     if Task::has_running_sequential_task(pool, query.project_id).await? {
         let queue = Task::find_sequential_queue_for_project(pool, query.project_id).await?;
         let current = queue.iter().find(|t| t.status == TaskStatus::InProgress);
+        let status =
+            QueueProcessingStatus::new(pool, query.project_id, &queue, current.map(|t| t.id))
+                .await?;
         return Ok(ResponseJson(ApiResponse::success(QueueProcessingStatus {
             is_processing: true,
-            current_task_id: current.map(|t| t.id),
-            queue_length: queue.len(),
+            ..status
         })));
     }
 
-    // Get the next task in the queue
-    let next_task = match Task::get_next_in_queue(pool, query.project_id).await? {
+    // Get the next task in the queue that isn't waiting on a dependency
+    let next_task = match next_ready_in_queue(pool, query.project_id).await? {
         Some(task) => task,
         None => {
-            return Ok(ResponseJson(ApiResponse::success(QueueProcessingStatus {
-                is_processing: false,
-                current_task_id: None,
-                queue_length: 0,
-            })));
+            let queue = Task::find_sequential_queue_for_project(pool, query.project_id).await?;
+            return Ok(ResponseJson(ApiResponse::success(
+                QueueProcessingStatus::new(pool, query.project_id, &queue, None).await?,
+            )));
         }
     };
 
@@ -834,7 +1336,7 @@ pub async fn start_queue_processing(
     // Fetch the updated task
     let task = Task::find_by_id(pool, next_task.id)
         .await?
-        .ok_or(ApiError::BadRequest("Task not found".to_string()))?;
+        .ok_or_else(|| ApiError::coded(ErrorCode::TaskNotFound, "Task not found"))?;
 
     // Auto-start the task
     auto_start_task(&deployment, &task).await?;
@@ -851,11 +1353,9 @@ pub async fn start_queue_processing(
         )
         .await;
 
-    Ok(ResponseJson(ApiResponse::success(QueueProcessingStatus {
-        is_processing: true,
-        current_task_id: Some(task.id),
-        queue_length: queue.len(),
-    })))
+    Ok(ResponseJson(ApiResponse::success(
+        QueueProcessingStatus::new(pool, query.project_id, &queue, Some(task.id)).await?,
+    )))
 }
 
 /// Get queue processing status for a project
@@ -866,13 +1366,10 @@ pub async fn get_queue_status(
     let pool = &deployment.db().pool;
     let queue = Task::find_sequential_queue_for_project(pool, query.project_id).await?;
     let current = queue.iter().find(|t| t.status == TaskStatus::InProgress);
-    let is_processing = current.is_some();
 
-    Ok(ResponseJson(ApiResponse::success(QueueProcessingStatus {
-        is_processing,
-        current_task_id: current.map(|t| t.id),
-        queue_length: queue.len(),
-    })))
+    Ok(ResponseJson(ApiResponse::success(
+        QueueProcessingStatus::new(pool, query.project_id, &queue, current.map(|t| t.id)).await?,
+    )))
 }
 
 pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
@@ -880,7 +1377,15 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/", put(update_task))
         .route("/", delete(delete_task))
         .route("/share", post(share_task))
-        .route("/reorder-queue", post(reorder_queue));
+        .route("/reorder-queue", post(reorder_queue))
+        .merge(task_reviews::router())
+        .merge(project_instructions::task_router())
+        .merge(my_tasks::task_router())
+        .merge(calendar::task_router())
+        .merge(task_labels::task_router())
+        .merge(task_dependencies::task_router())
+        .merge(capacity::task_router())
+        .merge(token_budgets::task_router());
 
     let task_id_router = Router::new()
         .route("/", get(get_task))
@@ -891,6 +1396,8 @@ pub fn router(deployment: &DeploymentImpl) -> Router<DeploymentImpl> {
         .route("/", get(get_tasks).post(create_task))
         .route("/stream/ws", get(stream_tasks_ws))
         .route("/create-and-start", post(create_task_and_start))
+        .route("/validate", post(validate_task_content))
+        .route("/check-duplicates", post(check_duplicates))
         .route("/queue", get(get_sequential_queue))
         .route("/queue/status", get(get_queue_status))
         .route("/queue/start", post(start_queue_processing))
@@ -0,0 +1,105 @@
+use axum::{
+    Extension, Router,
+    extract::{Query, State},
+    response::Json as ResponseJson,
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use db::models::{
+    project::Project,
+    task::{Task, TimelineTask},
+    task_activity::TaskActivity,
+    task_dependency::TaskDependency,
+};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::board_history::{BoardSnapshotTask, board_at};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{DeploymentImpl, error::ApiError};
+
+/// Tasks and the edges between them, shaped for a Gantt chart
+#[derive(Debug, Serialize, TS)]
+pub struct ProjectTimeline {
+    pub tasks: Vec<TimelineTask>,
+    pub dependencies: Vec<TimelineDependency>,
+}
+
+/// `task_id` can't finish before `depends_on_task_id`, because it was spawned
+/// from one of that task's attempts or was declared to depend on it
+#[derive(Debug, Serialize, TS)]
+pub struct TimelineDependency {
+    pub task_id: Uuid,
+    pub depends_on_task_id: Uuid,
+}
+
+pub async fn get_project_timeline(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<ProjectTimeline>>, ApiError> {
+    let pool = &deployment.db().pool;
+    let tasks = Task::find_timeline_by_project_id(pool, project.id).await?;
+    let mut dependencies: Vec<TimelineDependency> = tasks
+        .iter()
+        .filter_map(|task| {
+            task.parent_task_id.map(|parent| TimelineDependency {
+                task_id: task.id,
+                depends_on_task_id: parent,
+            })
+        })
+        .collect();
+    for dependency in TaskDependency::find_by_project_id(pool, project.id).await? {
+        if !dependencies.iter().any(|d| {
+            d.task_id == dependency.task_id && d.depends_on_task_id == dependency.depends_on_task_id
+        }) {
+            dependencies.push(TimelineDependency {
+                task_id: dependency.task_id,
+                depends_on_task_id: dependency.depends_on_task_id,
+            });
+        }
+    }
+
+    Ok(ResponseJson(ApiResponse::success(ProjectTimeline {
+        tasks,
+        dependencies,
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BoardAtQuery {
+    pub timestamp: DateTime<Utc>,
+}
+
+/// The board as it was at a point in time
+#[derive(Debug, Serialize, TS)]
+pub struct BoardSnapshot {
+    pub timestamp: DateTime<Utc>,
+    pub tasks: Vec<BoardSnapshotTask>,
+}
+
+/// Rebuild the board at `timestamp` from task activity. Titles are as of the
+/// task's last change before then; tasks that existed before activity was
+/// recorded appear from their creation in their state at that time.
+pub async fn get_board_at(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<BoardAtQuery>,
+) -> Result<ResponseJson<ApiResponse<BoardSnapshot>>, ApiError> {
+    let activity =
+        TaskActivity::find_by_project_id_until(&deployment.db().pool, project.id, query.timestamp)
+            .await?;
+
+    Ok(ResponseJson(ApiResponse::success(BoardSnapshot {
+        timestamp: query.timestamp,
+        tasks: board_at(&activity),
+    })))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/timeline", get(get_project_timeline))
+        .route("/board-at", get(get_board_at))
+}
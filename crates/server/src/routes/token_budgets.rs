@@ -0,0 +1,91 @@
+use axum::{Extension, Json, Router, extract::State, response::Json as ResponseJson, routing::get};
+use db::models::{
+    project::Project,
+    task::Task,
+    token_budget::{SetTokenBudget, TokenBudget},
+};
+use deployment::Deployment;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+fn validate_max_tokens(max_tokens: Option<i64>) -> Result<(), ApiError> {
+    match max_tokens {
+        Some(max) if max <= 0 => Err(ApiError::BadRequest(
+            "Token budget must be a positive number of tokens".to_string(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+pub async fn get_project_token_budget(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TokenBudget>>>, ApiError> {
+    let budget = TokenBudget::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(budget)))
+}
+
+pub async fn set_project_token_budget(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTokenBudget>,
+) -> Result<ResponseJson<ApiResponse<Option<TokenBudget>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    validate_max_tokens(payload.max_tokens)?;
+    let budget = match payload.max_tokens {
+        Some(max_tokens) => {
+            Some(TokenBudget::set_for_project(pool, project.id, max_tokens, payload.action).await?)
+        }
+        None => {
+            TokenBudget::delete_for_project(pool, project.id).await?;
+            None
+        }
+    };
+    Ok(ResponseJson(ApiResponse::success(budget)))
+}
+
+/// The task's own budget; attempts fall back to the project budget without one
+pub async fn get_task_token_budget(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Option<TokenBudget>>>, ApiError> {
+    let budget = TokenBudget::find_by_task_id(&deployment.db().pool, task.id).await?;
+    Ok(ResponseJson(ApiResponse::success(budget)))
+}
+
+pub async fn set_task_token_budget(
+    Extension(task): Extension<Task>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<SetTokenBudget>,
+) -> Result<ResponseJson<ApiResponse<Option<TokenBudget>>>, ApiError> {
+    let pool = &deployment.db().pool;
+    validate_max_tokens(payload.max_tokens)?;
+    let budget = match payload.max_tokens {
+        Some(max_tokens) => {
+            Some(TokenBudget::set_for_task(pool, task.id, max_tokens, payload.action).await?)
+        }
+        None => {
+            TokenBudget::delete_for_task(pool, task.id).await?;
+            None
+        }
+    };
+    Ok(ResponseJson(ApiResponse::success(budget)))
+}
+
+/// Routes mounted under `/tasks/{task_id}`
+pub fn task_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/token-budget",
+        get(get_task_token_budget).put(set_task_token_budget),
+    )
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new().route(
+        "/token-budget",
+        get(get_project_token_budget).put(set_project_token_budget),
+    )
+}
@@ -9,7 +9,10 @@ use db::models::user::{UpdateUser, User, UserError, UserPublic, UserRole};
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
-use utils::{password::hash_password, response::ApiResponse};
+use utils::{
+    password::hash_password,
+    response::{ApiResponse, ErrorCode},
+};
 use uuid::Uuid;
 
 use crate::{DeploymentImpl, error::ApiError};
@@ -121,16 +124,20 @@ async fn create_user(
 
     // Validate username
     if payload.username.is_empty() || payload.username.len() < 3 {
-        return Err(ApiError::BadRequest(
-            "Username must be at least 3 characters".to_string(),
-        ));
+        return Err(ApiError::coded(
+            ErrorCode::UsernameTooShort,
+            "Username must be at least 3 characters",
+        )
+        .with_param("min_length", 3));
     }
 
     // Validate password
     if payload.password.len() < 8 {
-        return Err(ApiError::BadRequest(
-            "Password must be at least 8 characters".to_string(),
-        ));
+        return Err(ApiError::coded(
+            ErrorCode::PasswordTooShort,
+            "Password must be at least 8 characters",
+        )
+        .with_param("min_length", 8));
     }
 
     // Determine role
@@ -199,9 +206,11 @@ async fn update_user(
     // If password is being changed, hash it and update separately
     if let Some(new_password) = &payload.password {
         if new_password.len() < 8 {
-            return Err(ApiError::BadRequest(
-                "Password must be at least 8 characters".to_string(),
-            ));
+            return Err(ApiError::coded(
+                ErrorCode::PasswordTooShort,
+                "Password must be at least 8 characters",
+            )
+            .with_param("min_length", 8));
         }
         let password_hash = hash_password(new_password)
             .map_err(|_| ApiError::BadRequest("Failed to hash password".to_string()))?;
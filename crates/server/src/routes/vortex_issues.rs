@@ -1,25 +1,38 @@
 use axum::{
     Extension, Json, Router,
     extract::{Query, State},
-    response::Json as ResponseJson,
+    response::{Json as ResponseJson, Response},
     routing::{get, post},
 };
 use db::models::{
-    image::TaskImage,
+    issue_link::IssueLink,
+    issue_sync_conflict::{CreateIssueSyncConflict, IssueSyncConflict, IssueSyncSide},
     project::Project,
-    task::{CreateTask, Task, TaskStatus},
+    task::Task,
+    vortex_oauth_token::VortexOAuthToken,
 };
 use deployment::Deployment;
 use serde::{Deserialize, Serialize};
 use services::services::{
-    image::ImageService,
-    vortex_issues::{ListVortexIssuesParams, VortexAttachment, VortexIssue, VortexIssuesService},
+    issue_provider::IssueProviderKind,
+    vortex_issues::{
+        ContentSyncAction, ListVortexIssuesParams, VortexContent, VortexIssue, VortexIssuesService,
+        VortexProject,
+    },
 };
 use ts_rs::TS;
 use utils::response::ApiResponse;
-use uuid::Uuid;
 
-use crate::{DeploymentImpl, error::ApiError};
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    routes::{
+        issue_sync::{ImportedIssue, SyncIssuesQuery, import_issue_by_id, sync_issues},
+        vortex_oauth::vortex_access_token,
+    },
+};
+
+const DEFAULT_SYNC_CONFLICTS_LIMIT: i64 = 50;
 
 #[derive(Debug, Deserialize)]
 pub struct ListVortexIssuesQuery {
@@ -40,12 +53,6 @@ pub struct ImportVortexIssueRequest {
     pub issue_id: String,
 }
 
-#[derive(Debug, Serialize, TS)]
-pub struct ImportVortexIssueResponse {
-    pub task: Task,
-    pub issue: VortexIssue,
-}
-
 #[derive(Debug, Serialize, TS)]
 pub struct VortexConfigStatus {
     pub has_project_id: bool,
@@ -53,27 +60,50 @@ pub struct VortexConfigStatus {
     pub project_id: Option<String>,
     pub sync_enabled: bool,
     pub sync_labels: Option<String>,
+    pub two_way_sync: bool,
 }
 
 pub async fn get_vortex_config_status(
     Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
 ) -> Result<ResponseJson<ApiResponse<VortexConfigStatus>>, ApiError> {
+    let has_oauth_token = VortexOAuthToken::find_by_project_id(&deployment.db().pool, project.id)
+        .await?
+        .is_some();
     let status = VortexConfigStatus {
         has_project_id: project.vortex_project_id.is_some(),
-        has_token: project.vortex_token.is_some(),
+        has_token: has_oauth_token || project.vortex_token.is_some(),
         project_id: project.vortex_project_id.clone(),
         sync_enabled: project.vortex_sync_enabled,
         sync_labels: project.vortex_sync_labels.clone(),
+        two_way_sync: project.vortex_two_way_sync,
     };
     Ok(ResponseJson(ApiResponse::success(status)))
 }
 
+/// Projects the project's Vortex credentials can access, so the Vortex
+/// project ID can be picked from a list instead of pasted
+pub async fn list_vortex_projects(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<VortexProject>>>, ApiError> {
+    let Some(token) = vortex_access_token(&deployment, &project).await? else {
+        return Err(ApiError::integration_not_configured("Vortex"));
+    };
+
+    let projects = VortexIssuesService::new().list_projects(&token).await?;
+
+    Ok(ResponseJson(ApiResponse::success(projects)))
+}
+
 pub async fn list_vortex_issues(
     Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
     Query(query): Query<ListVortexIssuesQuery>,
 ) -> Result<ResponseJson<ApiResponse<VortexIssuesResponse>>, ApiError> {
-    let (vortex_project_id, token) = match (&project.vortex_project_id, &project.vortex_token) {
-        (Some(pid), Some(tok)) => (pid.clone(), tok.clone()),
+    let token = vortex_access_token(&deployment, &project).await?;
+    let (vortex_project_id, token) = match (&project.vortex_project_id, token) {
+        (Some(pid), Some(tok)) => (pid.clone(), tok),
         _ => {
             return Ok(ResponseJson(ApiResponse::success(VortexIssuesResponse {
                 issues: vec![],
@@ -94,8 +124,7 @@ pub async fn list_vortex_issues(
 
     let issues = service
         .list_issues(&token, &vortex_project_id, &params)
-        .await
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        .await?;
 
     Ok(ResponseJson(ApiResponse::success(VortexIssuesResponse {
         issues,
@@ -103,287 +132,191 @@ pub async fn list_vortex_issues(
     })))
 }
 
-struct ImportedImage {
-    id: Uuid,
-    file_path: String,
-    original_name: String,
-}
-
-async fn import_vortex_attachments(
-    image_service: &ImageService,
-    vortex_service: &VortexIssuesService,
-    token: &str,
-    attachments: &[VortexAttachment],
-) -> Vec<ImportedImage> {
-    let mut images = Vec::new();
-
-    for attachment in attachments {
-        if !attachment.is_image {
-            continue;
-        }
-
-        let download_url = match &attachment.download_url {
-            Some(url) => url,
-            None => continue,
-        };
-
-        match vortex_service
-            .download_attachment(token, download_url)
-            .await
-        {
-            Ok(data) => match image_service.store_image(&data, &attachment.filename).await {
-                Ok(image) => {
-                    tracing::debug!("Imported Vortex attachment: {}", attachment.filename);
-                    let markdown_path =
-                        format!("{}/{}", utils::path::VIBE_IMAGES_DIR, image.file_path);
-                    images.push(ImportedImage {
-                        id: image.id,
-                        file_path: markdown_path,
-                        original_name: attachment.filename.clone(),
-                    });
-                }
-                Err(e) => {
-                    tracing::warn!(
-                        "Failed to store Vortex attachment {}: {}",
-                        attachment.filename,
-                        e
-                    );
-                }
-            },
-            Err(e) => {
-                tracing::warn!(
-                    "Failed to download Vortex attachment {}: {}",
-                    attachment.filename,
-                    e
-                );
-            }
-        }
-    }
-
-    images
-}
-
 pub async fn import_vortex_issue(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
     Json(payload): Json<ImportVortexIssueRequest>,
-) -> Result<ResponseJson<ApiResponse<ImportVortexIssueResponse>>, ApiError> {
-    let token = match &project.vortex_token {
-        Some(tok) => tok.clone(),
-        None => {
-            return Err(ApiError::BadRequest(
-                "Vortex token not configured for this project".to_string(),
-            ));
-        }
-    };
-
-    let vortex_service = VortexIssuesService::new();
-
-    let issue = vortex_service
-        .get_issue(&token, &payload.issue_id)
-        .await
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-
-    let attachments = vortex_service
-        .get_issue_attachments(&token, &payload.issue_id)
-        .await
-        .unwrap_or_default();
-
-    let image_service = ImageService::new(deployment.db().pool.clone())?;
-
-    let imported_images =
-        import_vortex_attachments(&image_service, &vortex_service, &token, &attachments).await;
-
-    let issue_url = format!("https://vortextask.com/issues/{}", payload.issue_id);
-
-    let images_markdown = if !imported_images.is_empty() {
-        let image_lines: Vec<String> = imported_images
-            .iter()
-            .map(|img| format!("![{}]({})", img.original_name, img.file_path))
-            .collect();
-        format!("\n\n## Attachments\n\n{}", image_lines.join("\n\n"))
-    } else {
-        String::new()
-    };
-
-    let description = format!(
-        "Imported from Vortex Issue #{}\n{}\n\n{}{}",
-        issue.key,
-        issue_url,
-        issue.description.clone().unwrap_or_default(),
-        images_markdown
-    );
-
-    let image_ids: Vec<Uuid> = imported_images.iter().map(|img| img.id).collect();
-
-    let create_task = CreateTask {
-        project_id: project.id,
-        title: issue.title.clone(),
-        description: Some(description),
-        status: Some(TaskStatus::Todo),
-        execution_mode: None,
-        parent_workspace_id: None,
-        image_ids: if image_ids.is_empty() {
-            None
-        } else {
-            Some(image_ids.clone())
-        },
-        shared_task_id: None,
-    };
-
-    let task_id = Uuid::new_v4();
-    let task = Task::create(&deployment.db().pool, &create_task, task_id).await?;
-
-    if !image_ids.is_empty() {
-        TaskImage::associate_many_dedup(&deployment.db().pool, task.id, &image_ids).await?;
-    }
-
-    deployment
-        .track_if_analytics_allowed(
-            "vortex_issue_imported",
-            serde_json::json!({
-                "project_id": project.id.to_string(),
-                "issue_key": issue.key,
-                "task_id": task.id.to_string(),
-                "images_imported": image_ids.len(),
-            }),
-        )
-        .await;
-
-    Ok(ResponseJson(ApiResponse::success(
-        ImportVortexIssueResponse { task, issue },
-    )))
+) -> Result<ResponseJson<ApiResponse<ImportedIssue>>, ApiError> {
+    import_issue_by_id(
+        &deployment,
+        &project,
+        IssueProviderKind::Vortex,
+        &payload.issue_id,
+    )
+    .await
 }
 
+/// Import open issues that have no task yet. With `dry_run` set, returns an
+/// [`IssueSyncPreview`](super::issue_sync::IssueSyncPreview) instead and
+/// writes nothing.
 pub async fn sync_vortex_issues(
     Extension(project): Extension<Project>,
     State(deployment): State<DeploymentImpl>,
-) -> Result<ResponseJson<ApiResponse<Vec<ImportVortexIssueResponse>>>, ApiError> {
-    let (vortex_project_id, token) = match (&project.vortex_project_id, &project.vortex_token) {
-        (Some(pid), Some(tok)) => (pid.clone(), tok.clone()),
-        _ => {
-            return Err(ApiError::BadRequest(
-                "Vortex configuration not set for this project".to_string(),
-            ));
-        }
-    };
+    Query(query): Query<SyncIssuesQuery>,
+) -> Result<Response, ApiError> {
+    sync_issues(project, deployment, query, IssueProviderKind::Vortex).await
+}
 
-    let vortex_service = VortexIssuesService::new();
+/// Two-way sync of one linked task's title and description with its issue.
+/// Conflicts are resolved by [`VortexIssuesService::sync_content`] and
+/// recorded here with both sides as they were.
+async fn sync_linked_content(
+    deployment: &DeploymentImpl,
+    token: &str,
+    task: &Task,
+    link: &IssueLink,
+) -> Result<ContentSyncAction, ApiError> {
+    let kind = IssueProviderKind::Vortex;
+    let (header, body) = kind.split_import_header(task.description.as_deref().unwrap_or_default());
+    let task_content = VortexContent::new(&task.title, Some(body));
+    let last_synced = link
+        .synced_title
+        .as_deref()
+        .map(|title| VortexContent::new(title, link.synced_description.as_deref()));
+
+    let sync = VortexIssuesService::new()
+        .sync_content(
+            token,
+            &link.external_id,
+            last_synced.as_ref(),
+            &task_content,
+            task.updated_at,
+        )
+        .await?;
+
+    let pool = &deployment.db().pool;
+    if let ContentSyncAction::Conflict { winner } = sync.action {
+        IssueSyncConflict::create(
+            pool,
+            &CreateIssueSyncConflict {
+                task_id: task.id,
+                provider: kind.name(),
+                external_id: &link.external_id,
+                winner,
+                task_title: &task_content.title,
+                task_description: task_content.description.as_deref(),
+                issue_title: &sync.issue.title,
+                issue_description: sync.issue.description.as_deref(),
+            },
+        )
+        .await?;
+    }
+    if sync.action.source() == IssueSyncSide::Issue {
+        let description = format!(
+            "{}{}",
+            header,
+            sync.synced.description.as_deref().unwrap_or_default()
+        );
+        Task::update(
+            pool,
+            task.id,
+            task.project_id,
+            sync.synced.title.clone(),
+            (!description.trim().is_empty()).then_some(description),
+            task.status.clone(),
+            task.parent_workspace_id,
+        )
+        .await?;
+    }
+    IssueLink::record_content_synced(
+        pool,
+        task.id,
+        &sync.synced.title,
+        sync.synced.description.as_deref(),
+    )
+    .await?;
+    Ok(sync.action)
+}
 
-    let params = ListVortexIssuesParams {
-        status: Some("Open".to_string()),
-        priority: None,
-        labels: project.vortex_sync_labels.clone(),
-        page: Some(1),
-        limit: Some(100),
+/// Sync a task's title and description with its Vortex issue when the project
+/// syncs both ways. `None` when nothing was synced.
+pub(crate) async fn sync_vortex_content(
+    deployment: &DeploymentImpl,
+    task: &Task,
+) -> Result<Option<ContentSyncAction>, ApiError> {
+    let pool = &deployment.db().pool;
+    let Some(project) = Project::find_by_id(pool, task.project_id)
+        .await?
+        .filter(|project| project.vortex_two_way_sync)
+    else {
+        return Ok(None);
     };
-
-    let issues = vortex_service
-        .list_issues(&token, &vortex_project_id, &params)
+    let Some(link) = IssueLink::find_by_task_id(pool, task.id)
+        .await?
+        .filter(|link| link.provider == IssueProviderKind::Vortex.name())
+    else {
+        return Ok(None);
+    };
+    let Some(token) = vortex_access_token(deployment, &project).await? else {
+        return Ok(None);
+    };
+    sync_linked_content(deployment, &token, task, &link)
         .await
-        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-
-    let existing_tasks =
-        Task::find_by_project_id_with_attempt_status(&deployment.db().pool, project.id).await?;
-    let existing_issue_keys: Vec<String> = existing_tasks
-        .iter()
-        .filter_map(|t| {
-            t.description.as_ref().and_then(|d| {
-                if d.starts_with("Imported from Vortex Issue #") {
-                    d.lines().next().and_then(|line| {
-                        line.strip_prefix("Imported from Vortex Issue #")
-                            .map(|s| s.to_string())
-                    })
-                } else {
-                    None
-                }
-            })
-        })
-        .collect();
-
-    let image_service = ImageService::new(deployment.db().pool.clone())?;
+        .map(Some)
+}
 
-    let mut imported = Vec::new();
+/// Sync the content of every task linked to a Vortex issue, returning how many
+/// tasks took their issue's content. A task that fails to sync is logged and
+/// skipped.
+pub(crate) async fn sync_project_vortex_content(
+    deployment: &DeploymentImpl,
+    project: &Project,
+) -> Result<usize, ApiError> {
+    if !project.vortex_two_way_sync {
+        return Ok(0);
+    }
+    let Some(token) = vortex_access_token(deployment, project).await? else {
+        return Ok(0);
+    };
+    let pool = &deployment.db().pool;
+    let links =
+        IssueLink::find_by_project_id(pool, project.id, IssueProviderKind::Vortex.name()).await?;
 
-    for issue in issues {
-        if existing_issue_keys.contains(&issue.key) {
+    let mut updated = 0;
+    for link in links {
+        let Some(task) = Task::find_by_id(pool, link.task_id).await? else {
             continue;
-        }
-
-        let attachments = vortex_service
-            .get_issue_attachments(&token, &issue.id)
-            .await
-            .unwrap_or_default();
-
-        let imported_images =
-            import_vortex_attachments(&image_service, &vortex_service, &token, &attachments).await;
-
-        let issue_url = format!("https://vortextask.com/issues/{}", issue.id);
-
-        let images_markdown = if !imported_images.is_empty() {
-            let image_lines: Vec<String> = imported_images
-                .iter()
-                .map(|img| format!("![{}]({})", img.original_name, img.file_path))
-                .collect();
-            format!("\n\n## Attachments\n\n{}", image_lines.join("\n\n"))
-        } else {
-            String::new()
-        };
-
-        let description = format!(
-            "Imported from Vortex Issue #{}\n{}\n\n{}{}",
-            issue.key,
-            issue_url,
-            issue.description.clone().unwrap_or_default(),
-            images_markdown
-        );
-
-        let image_ids: Vec<Uuid> = imported_images.iter().map(|img| img.id).collect();
-
-        let create_task = CreateTask {
-            project_id: project.id,
-            title: issue.title.clone(),
-            description: Some(description),
-            status: Some(TaskStatus::Todo),
-            execution_mode: None,
-            parent_workspace_id: None,
-            image_ids: if image_ids.is_empty() {
-                None
-            } else {
-                Some(image_ids.clone())
-            },
-            shared_task_id: None,
         };
-
-        let task_id = Uuid::new_v4();
-        let task = Task::create(&deployment.db().pool, &create_task, task_id).await?;
-
-        if !image_ids.is_empty() {
-            TaskImage::associate_many_dedup(&deployment.db().pool, task.id, &image_ids).await?;
+        match sync_linked_content(deployment, &token, &task, &link).await {
+            Ok(action) if action.source() == IssueSyncSide::Issue => updated += 1,
+            Ok(_) => {}
+            Err(e) => tracing::warn!(
+                "Failed to sync task {} with Vortex issue {}: {}",
+                task.id,
+                link.external_id,
+                e
+            ),
         }
-
-        imported.push(ImportVortexIssueResponse { task, issue });
     }
+    Ok(updated)
+}
 
-    Project::update_vortex_last_sync(&deployment.db().pool, project.id).await?;
-
-    deployment
-        .track_if_analytics_allowed(
-            "vortex_issues_synced",
-            serde_json::json!({
-                "project_id": project.id.to_string(),
-                "imported_count": imported.len(),
-            }),
-        )
-        .await;
+#[derive(Debug, Deserialize)]
+pub struct SyncConflictsQuery {
+    pub limit: Option<i64>,
+}
 
-    Ok(ResponseJson(ApiResponse::success(imported)))
+/// Title and description conflicts resolved by two-way sync, newest first
+pub async fn list_vortex_sync_conflicts(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<SyncConflictsQuery>,
+) -> Result<ResponseJson<ApiResponse<Vec<IssueSyncConflict>>>, ApiError> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SYNC_CONFLICTS_LIMIT)
+        .clamp(1, 500);
+    let conflicts =
+        IssueSyncConflict::find_by_project_id(&deployment.db().pool, project.id, limit).await?;
+    Ok(ResponseJson(ApiResponse::success(conflicts)))
 }
 
 pub fn router() -> Router<DeploymentImpl> {
     Router::new()
         .route("/vortex/config", get(get_vortex_config_status))
+        .route("/vortex/projects", get(list_vortex_projects))
         .route("/vortex/issues", get(list_vortex_issues))
         .route("/vortex/issues/import", post(import_vortex_issue))
         .route("/vortex/issues/sync", post(sync_vortex_issues))
+        .route("/vortex/sync-conflicts", get(list_vortex_sync_conflicts))
 }
@@ -0,0 +1,177 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    http::{Response, StatusCode},
+    response::Json as ResponseJson,
+    routing::{get, post},
+};
+use chrono::{DateTime, Utc};
+use db::models::{project::Project, vortex_oauth_token::VortexOAuthToken};
+use deployment::Deployment;
+use serde::{Deserialize, Serialize};
+use services::services::{container::ContainerService, vortex_oauth::VortexOAuthError};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+
+use crate::{DeploymentImpl, error::ApiError, middleware::RequireAdmin};
+
+#[derive(Debug, Deserialize, TS)]
+pub struct StartVortexOAuthRequest {
+    /// Where Vortex sends the user back to; must point at `/api/vortex/oauth/callback`
+    pub redirect_uri: String,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct StartVortexOAuthResponse {
+    pub authorize_url: String,
+}
+
+#[derive(Debug, Serialize, TS)]
+pub struct VortexOAuthStatus {
+    /// Whether a Vortex OAuth app is set up in settings
+    pub configured: bool,
+    pub connected: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VortexOAuthCallbackQuery {
+    pub code: Option<String>,
+    pub state: Option<String>,
+    pub error: Option<String>,
+}
+
+fn oauth_error(error: VortexOAuthError) -> ApiError {
+    match error {
+        VortexOAuthError::Database(e) => ApiError::Database(e),
+        e => ApiError::BadRequest(e.to_string()),
+    }
+}
+
+/// Token for calling the Vortex API on behalf of a project, preferring the
+/// project's OAuth connection over a pasted API token
+pub(crate) async fn vortex_access_token(
+    deployment: &DeploymentImpl,
+    project: &Project,
+) -> Result<Option<String>, ApiError> {
+    deployment
+        .container()
+        .vortex_oauth()
+        .await
+        .access_token(&deployment.db().pool, project)
+        .await
+        .map_err(oauth_error)
+}
+
+pub async fn get_vortex_oauth_status(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<VortexOAuthStatus>>, ApiError> {
+    let token = VortexOAuthToken::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(VortexOAuthStatus {
+        configured: deployment.container().vortex_oauth().await.is_configured(),
+        connected: token.is_some(),
+        expires_at: token.and_then(|t| t.expires_at),
+    })))
+}
+
+pub async fn start_vortex_oauth(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Json(payload): Json<StartVortexOAuthRequest>,
+) -> Result<ResponseJson<ApiResponse<StartVortexOAuthResponse>>, ApiError> {
+    let authorize_url = deployment
+        .container()
+        .vortex_oauth()
+        .await
+        .authorize_url(project.id, &payload.redirect_uri)
+        .map_err(oauth_error)?;
+    Ok(ResponseJson(ApiResponse::success(
+        StartVortexOAuthResponse { authorize_url },
+    )))
+}
+
+pub async fn disconnect_vortex_oauth(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    VortexOAuthToken::delete(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+/// Vortex redirects the browser here after the user approves access. The
+/// encrypted `state` says which project the tokens belong to.
+pub async fn vortex_oauth_callback(
+    State(deployment): State<DeploymentImpl>,
+    Query(query): Query<VortexOAuthCallbackQuery>,
+) -> Response<String> {
+    if let Some(error) = query.error {
+        return html_response(
+            StatusCode::BAD_REQUEST,
+            format!("Vortex authorization failed: {error}"),
+        );
+    }
+    let (Some(code), Some(state)) = (query.code, query.state) else {
+        return html_response(
+            StatusCode::BAD_REQUEST,
+            "Missing code or state in callback".to_string(),
+        );
+    };
+
+    let result = deployment
+        .container()
+        .vortex_oauth()
+        .await
+        .complete_authorization(&deployment.db().pool, &code, &state)
+        .await;
+    match result {
+        Ok(project_id) => {
+            deployment
+                .track_if_analytics_allowed(
+                    "vortex_oauth_connected",
+                    serde_json::json!({ "project_id": project_id.to_string() }),
+                )
+                .await;
+            html_response(
+                StatusCode::OK,
+                "Vortex connected. You can close this window.".to_string(),
+            )
+        }
+        Err(e) => {
+            tracing::warn!("Vortex OAuth callback failed: {}", e);
+            html_response(StatusCode::BAD_REQUEST, e.to_string())
+        }
+    }
+}
+
+fn html_response(status: StatusCode, message: String) -> Response<String> {
+    let body = format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\"><title>Vortex</title></head>\
+         <body style=\"font-family: sans-serif; margin: 3rem;\"><h1>{}</h1></body></html>",
+        message
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    );
+    Response::builder()
+        .status(status)
+        .header("content-type", "text/html; charset=utf-8")
+        .body(body)
+        .unwrap()
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route(
+            "/vortex/oauth",
+            get(get_vortex_oauth_status).delete(disconnect_vortex_oauth),
+        )
+        .route("/vortex/oauth/authorize", post(start_vortex_oauth))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new().route("/vortex/oauth/callback", get(vortex_oauth_callback))
+}
@@ -0,0 +1,475 @@
+use axum::{
+    Extension, Json, Router,
+    body::Bytes,
+    extract::{Path, State},
+    http::HeaderMap,
+    response::Json as ResponseJson,
+    routing::{get, post, put},
+};
+use db::models::{
+    issue_link::IssueLink,
+    issue_sync_conflict::IssueSyncSide,
+    notification::{CreateNotification, Notification, NotificationKind},
+    project::Project,
+    project_webhook::{ProjectWebhook, SetProjectWebhook, WebhookProvider},
+    task::{Task, TaskStatus},
+};
+use deployment::Deployment;
+use serde::Serialize;
+use services::services::{
+    gitea_issues::GiteaIssuesService,
+    github_issues::GitHubIssuesService,
+    issue_provider::{IssueProviderKind, ProviderIssue},
+    vortex_issues::VortexIssue,
+    webhooks::{
+        IssueWebhookEvent, ParsedWebhook, VortexWebhook, github_signature_matches,
+        gitlab_project_matches, labels_match, parse_gitea_webhook, parse_github_webhook,
+        parse_gitlab_webhook, parse_vortex_webhook, secrets_match, signature_matches,
+        vortex_signature_matches,
+    },
+};
+use ts_rs::TS;
+use utils::response::ApiResponse;
+use uuid::Uuid;
+
+use crate::{
+    DeploymentImpl,
+    error::ApiError,
+    middleware::RequireAdmin,
+    routes::{issue_sync, vortex_issues::sync_vortex_content},
+};
+
+/// What a webhook delivery changed, for the provider's delivery log
+#[derive(Debug, Default, Serialize, TS)]
+pub struct WebhookDeliveryResult {
+    pub projects: usize,
+    pub tasks_created: usize,
+    pub tasks_updated: usize,
+}
+
+/// A project's sync settings for the provider a delivery came from
+struct IssueSource {
+    kind: IssueProviderKind,
+    sync_enabled: bool,
+    sync_labels: Option<String>,
+}
+
+impl IssueSource {
+    fn github(project: &Project) -> Self {
+        Self {
+            kind: IssueProviderKind::GitHub,
+            sync_enabled: project.github_sync_enabled,
+            sync_labels: project.github_sync_labels.clone(),
+        }
+    }
+
+    fn gitlab(project: &Project) -> Self {
+        Self {
+            kind: IssueProviderKind::GitLab,
+            sync_enabled: project.gitlab_sync_enabled,
+            sync_labels: project.gitlab_sync_labels.clone(),
+        }
+    }
+
+    fn gitea(project: &Project) -> Self {
+        Self {
+            kind: IssueProviderKind::Gitea,
+            sync_enabled: project.gitea_sync_enabled,
+            sync_labels: project.gitea_sync_labels.clone(),
+        }
+    }
+}
+
+/// Projects whose stored secret for `provider` passes `verify`
+pub(crate) async fn authenticated_projects(
+    deployment: &DeploymentImpl,
+    provider: WebhookProvider,
+    verify: impl Fn(&str) -> bool,
+) -> Result<Vec<Project>, ApiError> {
+    let pool = &deployment.db().pool;
+    let mut projects = Vec::new();
+    for webhook in ProjectWebhook::find_by_provider(pool, provider).await? {
+        if verify(&webhook.secret)
+            && let Some(project) = Project::find_by_id(pool, webhook.project_id).await?
+        {
+            projects.push(project);
+        }
+    }
+    if projects.is_empty() {
+        return Err(ApiError::Unauthorized);
+    }
+    Ok(projects)
+}
+
+/// Apply a parsed issue event to one project's tasks
+async fn apply_issue_event(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    source: &IssueSource,
+    event: &IssueWebhookEvent,
+    result: &mut WebhookDeliveryResult,
+) -> Result<(), ApiError> {
+    let pool = &deployment.db().pool;
+    let number = match event {
+        IssueWebhookEvent::Opened(issue) | IssueWebhookEvent::Updated(issue) => issue.number,
+        IssueWebhookEvent::Closed { number }
+        | IssueWebhookEvent::Reopened { number }
+        | IssueWebhookEvent::Commented { number, .. } => *number,
+    };
+    let external_id = number.to_string();
+    let mut linked: Vec<Task> = Vec::new();
+    for link in IssueLink::find_by_project_id(pool, project.id, source.kind.name()).await? {
+        if link.external_id == external_id
+            && let Some(task) = Task::find_by_id(pool, link.task_id).await?
+        {
+            linked.push(task);
+        }
+    }
+
+    match event {
+        IssueWebhookEvent::Opened(issue) => {
+            if !linked.is_empty()
+                || !source.sync_enabled
+                || !labels_match(source.sync_labels.as_deref(), &issue.labels)
+            {
+                return Ok(());
+            }
+            let Some(provider) =
+                issue_sync::find_issue_provider(deployment, project, source.kind).await?
+            else {
+                return Ok(());
+            };
+            issue_sync::import_synced_issue(
+                deployment,
+                project,
+                provider.as_ref(),
+                ProviderIssue::from(issue.clone()),
+            )
+            .await?;
+            result.tasks_created += 1;
+        }
+        IssueWebhookEvent::Updated(issue) => {
+            for task in linked.into_iter().filter(|t| t.title != issue.title) {
+                Task::update(
+                    pool,
+                    task.id,
+                    task.project_id,
+                    issue.title.clone(),
+                    task.description,
+                    task.status,
+                    task.parent_workspace_id,
+                )
+                .await?;
+                result.tasks_updated += 1;
+            }
+        }
+        IssueWebhookEvent::Closed { .. } => {
+            for task in linked
+                .iter()
+                .filter(|t| !matches!(t.status, TaskStatus::Done | TaskStatus::Cancelled))
+            {
+                Task::update_status(pool, task.id, TaskStatus::Done).await?;
+                result.tasks_updated += 1;
+            }
+        }
+        IssueWebhookEvent::Reopened { .. } => {
+            for task in linked
+                .iter()
+                .filter(|t| matches!(t.status, TaskStatus::Done | TaskStatus::Cancelled))
+            {
+                Task::update_status(pool, task.id, TaskStatus::Todo).await?;
+                result.tasks_updated += 1;
+            }
+        }
+        IssueWebhookEvent::Commented {
+            author, body, url, ..
+        } => {
+            for task in &linked {
+                let notification = CreateNotification {
+                    kind: NotificationKind::IssueComment,
+                    title: format!(
+                        "@{} commented on {} issue #{}: {}",
+                        author,
+                        source.kind.name(),
+                        number,
+                        task.title
+                    ),
+                    message: format!("{body}\n\n{url}"),
+                    project_id: Some(project.id),
+                    task_id: Some(task.id),
+                    workspace_id: None,
+                };
+                Notification::create_for_all_users(pool, &notification).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// GitHub signs the body with the shared secret, like Gitea, but prefixes the
+/// signature with the algorithm
+pub async fn receive_github_webhook(
+    State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<ResponseJson<ApiResponse<WebhookDeliveryResult>>, ApiError> {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    let signature = header("X-Hub-Signature-256").ok_or(ApiError::Unauthorized)?;
+    let projects = authenticated_projects(&deployment, WebhookProvider::Github, |stored| {
+        github_signature_matches(stored, signature, &body)
+    })
+    .await?;
+
+    let mut result = WebhookDeliveryResult::default();
+    let event = header("X-GitHub-Event").unwrap_or_default();
+    let Some(ParsedWebhook { repository, event }) = parse_github_webhook(event, &body)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid GitHub webhook payload: {e}")))?
+    else {
+        return Ok(ResponseJson(ApiResponse::success(result)));
+    };
+
+    for project in projects.iter().filter(|p| {
+        p.github_repo_url
+            .as_deref()
+            .and_then(|url| GitHubIssuesService::parse_repo_url(url).ok())
+            .is_some_and(|(owner, name)| {
+                format!("{owner}/{name}").eq_ignore_ascii_case(&repository)
+            })
+    }) {
+        apply_issue_event(
+            &deployment,
+            project,
+            &IssueSource::github(project),
+            &event,
+            &mut result,
+        )
+        .await?;
+        result.projects += 1;
+    }
+
+    Ok(ResponseJson(ApiResponse::success(result)))
+}
+
+pub async fn receive_gitlab_webhook(
+    State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<ResponseJson<ApiResponse<WebhookDeliveryResult>>, ApiError> {
+    let secret = headers
+        .get("X-Gitlab-Token")
+        .and_then(|value| value.to_str().ok())
+        .ok_or(ApiError::Unauthorized)?;
+    let projects = authenticated_projects(&deployment, WebhookProvider::Gitlab, |stored| {
+        secrets_match(stored, secret)
+    })
+    .await?;
+
+    let mut result = WebhookDeliveryResult::default();
+    let Some(ParsedWebhook { repository, event }) = parse_gitlab_webhook(&body)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid GitLab webhook payload: {e}")))?
+    else {
+        return Ok(ResponseJson(ApiResponse::success(result)));
+    };
+
+    for project in projects.iter().filter(|p| {
+        p.gitlab_project_url
+            .as_deref()
+            .is_some_and(|url| gitlab_project_matches(url, &repository))
+    }) {
+        apply_issue_event(
+            &deployment,
+            project,
+            &IssueSource::gitlab(project),
+            &event,
+            &mut result,
+        )
+        .await?;
+        result.projects += 1;
+    }
+
+    Ok(ResponseJson(ApiResponse::success(result)))
+}
+
+/// Gitea and Forgejo sign the body with the shared secret rather than sending it
+pub async fn receive_gitea_webhook(
+    State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<ResponseJson<ApiResponse<WebhookDeliveryResult>>, ApiError> {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    let signature = header("X-Gitea-Signature")
+        .or_else(|| header("X-Forgejo-Signature"))
+        .ok_or(ApiError::Unauthorized)?;
+    let projects = authenticated_projects(&deployment, WebhookProvider::Gitea, |stored| {
+        signature_matches(stored, signature, &body)
+    })
+    .await?;
+
+    let mut result = WebhookDeliveryResult::default();
+    let event = header("X-Gitea-Event")
+        .or_else(|| header("X-Forgejo-Event"))
+        .unwrap_or_default();
+    let Some(ParsedWebhook { repository, event }) = parse_gitea_webhook(event, &body)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid Gitea webhook payload: {e}")))?
+    else {
+        return Ok(ResponseJson(ApiResponse::success(result)));
+    };
+
+    for project in projects.iter().filter(|p| {
+        p.gitea_repo
+            .as_deref()
+            .and_then(|repo| GiteaIssuesService::parse_repo(repo).ok())
+            .is_some_and(|(owner, name)| {
+                format!("{owner}/{name}").eq_ignore_ascii_case(&repository)
+            })
+    }) {
+        apply_issue_event(
+            &deployment,
+            project,
+            &IssueSource::gitea(project),
+            &event,
+            &mut result,
+        )
+        .await?;
+        result.projects += 1;
+    }
+
+    Ok(ResponseJson(ApiResponse::success(result)))
+}
+
+/// Import a created or changed Vortex issue that passes the project's sync
+/// labels, or bring an already linked one up to date when the project syncs
+/// content both ways
+async fn apply_vortex_issue(
+    deployment: &DeploymentImpl,
+    project: &Project,
+    issue: &VortexIssue,
+    result: &mut WebhookDeliveryResult,
+) -> Result<(), ApiError> {
+    let pool = &deployment.db().pool;
+    let kind = IssueProviderKind::Vortex;
+    let link = IssueLink::find_by_project_id(pool, project.id, kind.name())
+        .await?
+        .into_iter()
+        .find(|link| link.external_id == issue.id);
+
+    if let Some(link) = link {
+        let Some(task) = Task::find_by_id(pool, link.task_id).await? else {
+            return Ok(());
+        };
+        // Vortex retries failed deliveries, which wouldn't help while it's unreachable
+        match sync_vortex_content(deployment, &task).await {
+            Ok(Some(action)) if action.source() == IssueSyncSide::Issue => {
+                result.tasks_updated += 1;
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Failed to sync Vortex issue {}: {}", issue.key, e),
+        }
+        return Ok(());
+    }
+
+    let issue = ProviderIssue::from(issue.clone());
+    if issue.closed
+        || !project.vortex_sync_enabled
+        || !labels_match(project.vortex_sync_labels.as_deref(), &issue.labels)
+    {
+        return Ok(());
+    }
+    let Some(provider) = issue_sync::find_issue_provider(deployment, project, kind).await? else {
+        return Ok(());
+    };
+    issue_sync::import_synced_issue(deployment, project, provider.as_ref(), issue).await?;
+    result.tasks_created += 1;
+    Ok(())
+}
+
+/// Vortex sends the whole issue with each event, so an issue that gains a sync
+/// label after it was created is imported by its update
+pub async fn receive_vortex_webhook(
+    State(deployment): State<DeploymentImpl>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<ResponseJson<ApiResponse<WebhookDeliveryResult>>, ApiError> {
+    let signature = headers
+        .get("X-Vortex-Signature")
+        .and_then(|value| value.to_str().ok())
+        .ok_or(ApiError::Unauthorized)?;
+    let projects = authenticated_projects(&deployment, WebhookProvider::Vortex, |stored| {
+        vortex_signature_matches(stored, signature, &body)
+    })
+    .await?;
+
+    let mut result = WebhookDeliveryResult::default();
+    let Some(VortexWebhook { issue, .. }) = parse_vortex_webhook(&body)
+        .map_err(|e| ApiError::BadRequest(format!("Invalid Vortex webhook payload: {e}")))?
+    else {
+        return Ok(ResponseJson(ApiResponse::success(result)));
+    };
+
+    for project in projects.iter().filter(|p| {
+        p.vortex_project_id
+            .as_deref()
+            .is_some_and(|id| issue.project_id.as_deref() == Some(id))
+    }) {
+        apply_vortex_issue(&deployment, project, &issue, &mut result).await?;
+        result.projects += 1;
+    }
+
+    Ok(ResponseJson(ApiResponse::success(result)))
+}
+
+pub async fn list_project_webhooks(
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+) -> Result<ResponseJson<ApiResponse<Vec<ProjectWebhook>>>, ApiError> {
+    let webhooks = ProjectWebhook::find_by_project_id(&deployment.db().pool, project.id).await?;
+    Ok(ResponseJson(ApiResponse::success(webhooks)))
+}
+
+pub async fn set_project_webhook(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, provider)): Path<(Uuid, WebhookProvider)>,
+    Json(payload): Json<SetProjectWebhook>,
+) -> Result<ResponseJson<ApiResponse<ProjectWebhook>>, ApiError> {
+    let secret = payload.secret.trim();
+    if secret.len() < 16 {
+        return Err(ApiError::BadRequest(
+            "Webhook secret must be at least 16 characters".to_string(),
+        ));
+    }
+    let webhook = ProjectWebhook::set(&deployment.db().pool, project.id, provider, secret).await?;
+    Ok(ResponseJson(ApiResponse::success(webhook)))
+}
+
+pub async fn delete_project_webhook(
+    _admin: RequireAdmin,
+    Extension(project): Extension<Project>,
+    State(deployment): State<DeploymentImpl>,
+    Path((_id, provider)): Path<(Uuid, WebhookProvider)>,
+) -> Result<ResponseJson<ApiResponse<()>>, ApiError> {
+    let deleted = ProjectWebhook::delete(&deployment.db().pool, project.id, provider).await?;
+    if deleted == 0 {
+        return Err(ApiError::BadRequest("Webhook not configured".to_string()));
+    }
+    Ok(ResponseJson(ApiResponse::success(())))
+}
+
+pub fn router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/webhooks/github", post(receive_github_webhook))
+        .route("/webhooks/gitlab", post(receive_gitlab_webhook))
+        .route("/webhooks/gitea", post(receive_gitea_webhook))
+        .route("/webhooks/vortex", post(receive_vortex_webhook))
+}
+
+/// Routes mounted under `/projects/{id}`
+pub fn project_router() -> Router<DeploymentImpl> {
+    Router::new()
+        .route("/webhooks", get(list_project_webhooks))
+        .route(
+            "/webhooks/{provider}",
+            put(set_project_webhook).delete(delete_project_webhook),
+        )
+}
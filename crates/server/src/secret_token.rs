@@ -0,0 +1,27 @@
+//! Random tokens that grant access on their own, such as kiosk tokens and
+//! public feed URLs. Only [`hash_token`] of a token is stored, so a leaked
+//! database can't be replayed against the server.
+
+use std::fmt::Write;
+
+use rand::{Rng, distributions::Alphanumeric};
+use sha2::{Digest, Sha256};
+
+const TOKEN_LENGTH: usize = 48;
+
+pub fn generate_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(TOKEN_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Hex-encoded SHA-256 of `token`, as kept in the database
+pub fn hash_token(token: &str) -> String {
+    let mut output = String::with_capacity(64);
+    for byte in Sha256::digest(token.as_bytes()) {
+        let _ = write!(output, "{:02x}", byte);
+    }
+    output
+}
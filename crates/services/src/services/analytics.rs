@@ -1,12 +1,13 @@
 use std::{
     collections::hash_map::DefaultHasher,
     hash::{Hash, Hasher},
-    time::Duration,
 };
 
 use os_info;
 use serde_json::{Value, json};
 
+use crate::services::http_client::{HttpClient, SendVia};
+
 #[derive(Debug, Clone)]
 pub struct AnalyticsContext {
     pub user_id: String,
@@ -38,17 +39,11 @@ impl AnalyticsConfig {
 #[derive(Clone, Debug)]
 pub struct AnalyticsService {
     config: AnalyticsConfig,
-    client: reqwest::Client,
 }
 
 impl AnalyticsService {
     pub fn new(config: AnalyticsConfig) -> Self {
-        let client = reqwest::Client::builder()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .unwrap();
-
-        Self { config, client }
+        Self { config }
     }
 
     pub fn track_event(&self, user_id: &str, event_name: &str, properties: Option<Value>) {
@@ -82,15 +77,15 @@ impl AnalyticsService {
             payload["properties"] = event_properties;
         }
 
-        let client = self.client.clone();
+        let http = HttpClient::shared().background();
         let event_name = event_name.to_string();
 
         tokio::spawn(async move {
-            match client
+            match http
                 .post(&endpoint)
                 .header("Content-Type", "application/json")
                 .json(&payload)
-                .send()
+                .send_via(&http)
                 .await
             {
                 Ok(response) => {
@@ -1,4 +1,5 @@
 pub mod executor_approvals;
+pub mod policy;
 
 use std::{collections::HashMap, sync::Arc, time::Duration as StdDuration};
 
@@ -14,10 +15,14 @@ use executors::{
         utils::patch::{ConversationPatch, extract_normalized_entry_from_patch},
     },
 };
+use chrono::{DateTime, Utc};
 use futures::future::{BoxFuture, FutureExt, Shared};
+use serde::Serialize;
+use serde_json::Value;
 use sqlx::{Error as SqlxError, SqlitePool};
 use thiserror::Error;
 use tokio::sync::{RwLock, oneshot};
+use ts_rs::TS;
 use utils::{
     approvals::{ApprovalRequest, ApprovalResponse, ApprovalStatus},
     log_msg::LogMsg,
@@ -31,9 +36,25 @@ struct PendingApproval {
     entry: NormalizedEntry,
     execution_process_id: Uuid,
     tool_name: String,
+    tool_input: Value,
+    created_at: DateTime<Utc>,
+    timeout_at: DateTime<Utc>,
+    policy_rule: Option<String>,
     response_tx: oneshot::Sender<ApprovalStatus>,
 }
 
+/// A tool call currently waiting on a human decision
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PendingApprovalInfo {
+    pub id: String,
+    pub execution_process_id: Uuid,
+    pub tool_name: String,
+    pub tool_input: Value,
+    pub policy_rule: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub timeout_at: DateTime<Utc>,
+}
+
 type ApprovalWaiter = Shared<BoxFuture<'static, ApprovalStatus>>;
 
 #[derive(Debug)]
@@ -106,6 +127,10 @@ impl Approvals {
                         entry: matching_tool,
                         execution_process_id: request.execution_process_id,
                         tool_name: request.tool_name.clone(),
+                        tool_input: request.tool_input.clone(),
+                        created_at: request.created_at,
+                        timeout_at: request.timeout_at,
+                        policy_rule: request.policy_rule.clone(),
                         response_tx: tx,
                     },
                 );
@@ -133,6 +158,34 @@ impl Approvals {
         Ok((request, waiter))
     }
 
+    /// Approvals still waiting on a decision for any of the given execution processes
+    pub fn pending_for_execution_processes(
+        &self,
+        execution_process_ids: &[Uuid],
+    ) -> Vec<PendingApprovalInfo> {
+        let mut pending: Vec<PendingApprovalInfo> = self
+            .pending
+            .iter()
+            .filter(|entry| execution_process_ids.contains(&entry.execution_process_id))
+            .map(|entry| PendingApprovalInfo {
+                id: entry.key().clone(),
+                execution_process_id: entry.execution_process_id,
+                tool_name: entry.tool_name.clone(),
+                tool_input: entry.tool_input.clone(),
+                policy_rule: entry.policy_rule.clone(),
+                created_at: entry.created_at,
+                timeout_at: entry.timeout_at,
+            })
+            .collect();
+        pending.sort_by_key(|approval| approval.created_at);
+        pending
+    }
+
+    /// Execution process an approval belongs to, if it is still pending
+    pub fn pending_execution_process_id(&self, id: &str) -> Option<Uuid> {
+        self.pending.get(id).map(|p| p.execution_process_id)
+    }
+
     #[tracing::instrument(skip(self, id, req))]
     pub async fn respond(
         &self,
@@ -7,13 +7,17 @@ use serde_json::Value;
 use utils::approvals::{ApprovalRequest, ApprovalStatus, CreateApprovalRequest};
 use uuid::Uuid;
 
-use crate::services::{approvals::Approvals, notification::NotificationService};
+use crate::services::{
+    approvals::{Approvals, policy::ApprovalPolicy},
+    notification::NotificationService,
+};
 
 pub struct ExecutorApprovalBridge {
     approvals: Approvals,
     db: DBService,
     notification_service: NotificationService,
     execution_process_id: Uuid,
+    policy: Option<ApprovalPolicy>,
 }
 
 impl ExecutorApprovalBridge {
@@ -22,27 +26,27 @@ impl ExecutorApprovalBridge {
         db: DBService,
         notification_service: NotificationService,
         execution_process_id: Uuid,
+        policy: Option<ApprovalPolicy>,
     ) -> Arc<Self> {
         Arc::new(Self {
             approvals,
             db,
             notification_service,
             execution_process_id,
+            policy,
         })
     }
-}
 
-#[async_trait]
-impl ExecutorApprovalService for ExecutorApprovalBridge {
-    async fn request_tool_approval(
+    async fn wait_for_approval(
         &self,
         tool_name: &str,
         tool_input: Value,
         tool_call_id: &str,
+        policy_rule: Option<String>,
     ) -> Result<ApprovalStatus, ExecutorApprovalError> {
         super::ensure_task_in_review(&self.db.pool, self.execution_process_id).await;
 
-        let request = ApprovalRequest::from_create(
+        let mut request = ApprovalRequest::from_create(
             CreateApprovalRequest {
                 tool_name: tool_name.to_string(),
                 tool_input,
@@ -50,6 +54,11 @@ impl ExecutorApprovalService for ExecutorApprovalBridge {
             },
             self.execution_process_id,
         );
+        request.policy_rule = policy_rule;
+        let message = match &request.policy_rule {
+            Some(rule) => format!("Tool '{}' matched approval rule '{}'", tool_name, rule),
+            None => format!("Tool '{}' requires approval", tool_name),
+        };
 
         let (_, waiter) = self
             .approvals
@@ -59,10 +68,7 @@ impl ExecutorApprovalService for ExecutorApprovalBridge {
 
         // Play notification sound when approval is needed
         self.notification_service
-            .notify(
-                "Approval Needed",
-                &format!("Tool '{}' requires approval", tool_name),
-            )
+            .notify("Approval Needed", &message)
             .await;
 
         let status = waiter.clone().await;
@@ -76,3 +82,46 @@ impl ExecutorApprovalService for ExecutorApprovalBridge {
         Ok(status)
     }
 }
+
+#[async_trait]
+impl ExecutorApprovalService for ExecutorApprovalBridge {
+    async fn request_tool_approval(
+        &self,
+        tool_name: &str,
+        tool_input: Value,
+        tool_call_id: &str,
+    ) -> Result<ApprovalStatus, ExecutorApprovalError> {
+        let policy_rule = self
+            .policy
+            .as_ref()
+            .and_then(|policy| policy.evaluate(&tool_input));
+        self.wait_for_approval(tool_name, tool_input, tool_call_id, policy_rule)
+            .await
+    }
+
+    fn has_tool_policy(&self) -> bool {
+        self.policy.is_some()
+    }
+
+    async fn check_tool_policy(
+        &self,
+        tool_name: &str,
+        tool_input: Value,
+        tool_call_id: &str,
+    ) -> Result<ApprovalStatus, ExecutorApprovalError> {
+        let Some(rule) = self
+            .policy
+            .as_ref()
+            .and_then(|policy| policy.evaluate(&tool_input))
+        else {
+            return Ok(ApprovalStatus::Approved);
+        };
+        tracing::info!(
+            "Tool '{}' matched approval rule '{}', waiting for approval",
+            tool_name,
+            rule
+        );
+        self.wait_for_approval(tool_name, tool_input, tool_call_id, Some(rule))
+            .await
+    }
+}
@@ -0,0 +1,186 @@
+use std::path::{Component, Path, PathBuf};
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::services::config::ApprovalPolicyConfig;
+
+/// Rule name reported for recursive deletes that reach outside the worktree
+pub const DELETE_OUTSIDE_WORKTREE_RULE: &str = "delete_outside_worktree";
+
+/// Compiled approval policy for a single attempt's worktree
+#[derive(Debug, Clone)]
+pub struct ApprovalPolicy {
+    rules: Vec<(String, Regex)>,
+    worktree: PathBuf,
+    block_delete_outside_worktree: bool,
+}
+
+impl ApprovalPolicy {
+    /// Build the policy for `worktree`, or `None` when the policy is disabled
+    pub fn from_config(config: &ApprovalPolicyConfig, worktree: &Path) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+        let rules = config
+            .rules
+            .iter()
+            .filter_map(|rule| match Regex::new(&rule.pattern) {
+                Ok(re) => Some((rule.name.clone(), re)),
+                Err(e) => {
+                    tracing::warn!("Ignoring invalid approval rule '{}': {}", rule.name, e);
+                    None
+                }
+            })
+            .collect();
+        Some(Self {
+            rules,
+            worktree: normalize(worktree),
+            block_delete_outside_worktree: config.block_delete_outside_worktree,
+        })
+    }
+
+    /// Name of the first rule the tool call trips, if any
+    pub fn evaluate(&self, tool_input: &Value) -> Option<String> {
+        let command = command_text(tool_input)?;
+        if let Some((name, _)) = self.rules.iter().find(|(_, re)| re.is_match(&command)) {
+            return Some(name.clone());
+        }
+        if self.block_delete_outside_worktree && self.deletes_outside_worktree(&command) {
+            return Some(DELETE_OUTSIDE_WORKTREE_RULE.to_string());
+        }
+        None
+    }
+
+    fn deletes_outside_worktree(&self, command: &str) -> bool {
+        command
+            .split(['\n', ';', '&', '|'])
+            .any(|segment| self.segment_deletes_outside_worktree(segment))
+    }
+
+    fn segment_deletes_outside_worktree(&self, segment: &str) -> bool {
+        let mut tokens = segment
+            .split_whitespace()
+            .map(|token| token.trim_matches(|c| c == '"' || c == '\''))
+            .skip_while(|token| *token == "sudo" || token.contains('='));
+        if tokens.next() != Some("rm") {
+            return false;
+        }
+
+        let (flags, targets): (Vec<&str>, Vec<&str>) =
+            tokens.partition(|token| token.starts_with('-'));
+        let recursive = flags.iter().any(|flag| {
+            *flag == "--recursive" || (!flag.starts_with("--") && flag.contains(['r', 'R']))
+        });
+        recursive
+            && targets
+                .iter()
+                .any(|target| self.is_outside_worktree(target))
+    }
+
+    fn is_outside_worktree(&self, target: &str) -> bool {
+        if target.starts_with('~') || target.starts_with('$') {
+            return true;
+        }
+        !normalize(&self.worktree.join(target)).starts_with(&self.worktree)
+    }
+}
+
+/// The shell command a tool call would run. Executors pass either a string or an argv array.
+fn command_text(tool_input: &Value) -> Option<String> {
+    match tool_input.get("command")? {
+        Value::String(command) => Some(command.clone()),
+        Value::Array(parts) => Some(
+            parts
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        _ => None,
+    }
+}
+
+/// Resolve `.` and `..` lexically; the target may not exist yet
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn policy() -> ApprovalPolicy {
+        let config = ApprovalPolicyConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        ApprovalPolicy::from_config(&config, Path::new("/tmp/worktrees/vk-1234")).unwrap()
+    }
+
+    #[test]
+    fn matches_default_rules() {
+        let policy = policy();
+        assert_eq!(
+            policy.evaluate(&json!({ "command": "pnpm run build && npm publish --access public" })),
+            Some("package_publish".to_string())
+        );
+        assert_eq!(
+            policy.evaluate(&json!({ "command": ["bash", "-lc", "sqlx migrate run"] })),
+            Some("database_migration".to_string())
+        );
+        assert_eq!(policy.evaluate(&json!({ "command": "cargo test" })), None);
+        assert_eq!(policy.evaluate(&json!({ "file_path": "publish.rs" })), None);
+    }
+
+    #[test]
+    fn flags_recursive_deletes_outside_worktree() {
+        let policy = policy();
+        let outside = [
+            "rm -rf /",
+            "cd src; rm -fr ../../other",
+            "sudo rm -r ~/projects",
+            "rm --recursive $HOME/.cache",
+        ];
+        for command in outside {
+            assert_eq!(
+                policy.evaluate(&json!({ "command": command })).as_deref(),
+                Some(DELETE_OUTSIDE_WORKTREE_RULE),
+                "{command}"
+            );
+        }
+
+        let inside = [
+            "rm -rf target node_modules",
+            "rm -rf /tmp/worktrees/vk-1234/dist",
+            "rm ../stray.txt",
+        ];
+        for command in inside {
+            assert_eq!(
+                policy.evaluate(&json!({ "command": command })),
+                None,
+                "{command}"
+            );
+        }
+    }
+
+    #[test]
+    fn disabled_policy_is_none() {
+        assert!(
+            ApprovalPolicy::from_config(&ApprovalPolicyConfig::default(), Path::new("/tmp"))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,274 @@
+//! Tasks of an Asana project, read through the REST API with a personal
+//! access token.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const API_BASE: &str = "https://app.asana.com/api/1.0";
+
+/// Tasks fetched per request; the API's limit
+const PAGE_SIZE: usize = 100;
+
+const TASK_FIELDS: &str = "name,notes,completed,permalink_url";
+
+#[derive(Debug, Error)]
+pub enum AsanaError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Asana API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Asana rejected the access token; update it in project settings")]
+    Unauthorized,
+    #[error("Not found in Asana; check the id and that the token's user can see it")]
+    NotFound,
+    #[error("Invalid Asana project: {0}")]
+    InvalidProject(String),
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AsanaAttachment {
+    pub gid: String,
+    pub name: String,
+    /// Short-lived signed URL; missing for links to external services
+    pub download_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct AsanaTask {
+    pub gid: String,
+    pub name: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub completed: bool,
+    #[serde(default)]
+    pub permalink_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct DataResponse<T> {
+    data: T,
+    #[serde(default)]
+    next_page: Option<NextPage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NextPage {
+    offset: String,
+}
+
+pub struct AsanaService {
+    http: HttpClient,
+}
+
+impl AsanaService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    /// The gid of a project, given the gid itself or a project URL in either
+    /// the `/0/<gid>/...` or the `/1/<workspace>/project/<gid>/...` form
+    pub fn normalize_project_gid(input: &str) -> Result<String, AsanaError> {
+        let trimmed = input.trim();
+        let is_gid = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        if is_gid(trimmed) {
+            return Ok(trimmed.to_string());
+        }
+
+        let path = trimmed
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .split_once("app.asana.com/")
+            .map(|(_, path)| path)
+            .unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let gid = match segments.as_slice() {
+            ["0", gid, ..] => Some(*gid),
+            _ => segments
+                .windows(2)
+                .find(|pair| pair[0] == "project")
+                .map(|pair| pair[1]),
+        };
+        gid.filter(|gid| is_gid(gid))
+            .map(str::to_string)
+            .ok_or_else(|| AsanaError::InvalidProject(input.to_string()))
+    }
+
+    async fn send<T: for<'de> Deserialize<'de>>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<DataResponse<T>, AsanaError> {
+        let response = request.send_via(&self.http).await?;
+        let status = response.status();
+        match status.as_u16() {
+            401 => return Err(AsanaError::Unauthorized),
+            403 | 404 => return Err(AsanaError::NotFound),
+            _ => {}
+        }
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(AsanaError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| AsanaError::ParseError(e.to_string()))
+    }
+
+    /// Check that the project exists and the token can read it
+    pub async fn check_project(&self, token: &str, project_gid: &str) -> Result<(), AsanaError> {
+        let url = format!("{API_BASE}/projects/{project_gid}");
+        self.send::<Value>(self.http.get(&url).bearer_auth(token))
+            .await
+            .map(|_| ())
+    }
+
+    /// Up to `limit` of the project's incomplete tasks
+    pub async fn list_open_tasks(
+        &self,
+        token: &str,
+        project_gid: &str,
+        limit: usize,
+    ) -> Result<Vec<AsanaTask>, AsanaError> {
+        let url = format!("{API_BASE}/projects/{project_gid}/tasks");
+        let limit = limit.max(1);
+        let mut tasks = Vec::new();
+        let mut offset: Option<String> = None;
+        loop {
+            let page_size = PAGE_SIZE.min(limit - tasks.len()).to_string();
+            let mut query = vec![
+                ("opt_fields", TASK_FIELDS),
+                ("completed_since", "now"),
+                ("limit", page_size.as_str()),
+            ];
+            if let Some(offset) = &offset {
+                query.push(("offset", offset));
+            }
+            let response: DataResponse<Vec<AsanaTask>> = self
+                .send(self.http.get(&url).bearer_auth(token).query(&query))
+                .await?;
+
+            tasks.extend(response.data);
+            offset = response.next_page.map(|page| page.offset);
+            if offset.is_none() || tasks.len() >= limit {
+                break;
+            }
+        }
+        Ok(tasks)
+    }
+
+    pub async fn get_task(&self, token: &str, task_gid: &str) -> Result<AsanaTask, AsanaError> {
+        let url = format!("{API_BASE}/tasks/{}", urlencoding::encode(task_gid.trim()));
+        self.send(
+            self.http
+                .get(&url)
+                .bearer_auth(token)
+                .query(&[("opt_fields", TASK_FIELDS)]),
+        )
+        .await
+        .map(|response| response.data)
+    }
+
+    pub async fn list_attachments(
+        &self,
+        token: &str,
+        task_gid: &str,
+    ) -> Result<Vec<AsanaAttachment>, AsanaError> {
+        let url = format!("{API_BASE}/attachments");
+        self.send(
+            self.http
+                .get(&url)
+                .bearer_auth(token)
+                .query(&[("parent", task_gid), ("opt_fields", "name,download_url")]),
+        )
+        .await
+        .map(|response| response.data)
+    }
+
+    /// Download an attachment from its signed URL; no credentials are sent
+    pub async fn download_attachment(&self, url: &str) -> Result<Vec<u8>, AsanaError> {
+        let response = self.http.get(url).send_via(&self.http).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AsanaError::Api {
+                status: status.as_u16(),
+                message: format!("failed to download {url}"),
+            });
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub async fn complete_task(&self, token: &str, task_gid: &str) -> Result<(), AsanaError> {
+        let url = format!("{API_BASE}/tasks/{}", urlencoding::encode(task_gid));
+        self.send::<Value>(
+            self.http
+                .put(&url)
+                .bearer_auth(token)
+                .json(&json!({ "data": { "completed": true } })),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn add_comment(
+        &self,
+        token: &str,
+        task_gid: &str,
+        text: &str,
+    ) -> Result<(), AsanaError> {
+        let url = format!("{API_BASE}/tasks/{}/stories", urlencoding::encode(task_gid));
+        self.send::<Value>(
+            self.http
+                .post(&url)
+                .bearer_auth(token)
+                .json(&json!({ "data": { "text": text } })),
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+impl Default for AsanaService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_project_gids() {
+        let gid = "1203456789012345";
+        assert_eq!(AsanaService::normalize_project_gid(gid).unwrap(), gid);
+        assert_eq!(
+            AsanaService::normalize_project_gid("https://app.asana.com/0/1203456789012345/list")
+                .unwrap(),
+            gid
+        );
+        assert_eq!(
+            AsanaService::normalize_project_gid(
+                "https://app.asana.com/1/1100000000000001/project/1203456789012345/board?focus=true"
+            )
+            .unwrap(),
+            gid
+        );
+        assert!(AsanaService::normalize_project_gid("Roadmap").is_err());
+        assert!(AsanaService::normalize_project_gid("https://app.asana.com/0/home").is_err());
+    }
+}
@@ -0,0 +1,422 @@
+//! Work item access for Azure DevOps Boards through the REST API,
+//! authenticated with a personal access token.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const API_VERSION: &str = "7.1";
+
+/// Work items fetched per batch request; the API's limit
+const BATCH_SIZE: usize = 200;
+
+/// States that end a work item in the Agile, Scrum, Basic and CMMI processes
+const CLOSED_STATES: &str = "'Closed', 'Done', 'Removed', 'Resolved'";
+
+#[derive(Debug, Error)]
+pub enum AzureWorkItemsError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Azure DevOps API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Azure DevOps rejected the access token; update it in project settings")]
+    Unauthorized,
+    #[error("Work item #{0} not found")]
+    NotFound(i64),
+    #[error("Invalid organization URL: {0}")]
+    InvalidOrganizationUrl(String),
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct AzureAttachment {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct AzureWorkItem {
+    pub id: i64,
+    pub title: String,
+    /// HTML, as stored by Azure DevOps
+    pub description: Option<String>,
+    pub state: String,
+    /// e.g. `Bug`, `User Story`, `Task`
+    pub work_item_type: String,
+    pub tags: Vec<String>,
+    pub assigned_to: Option<String>,
+    pub html_url: String,
+    pub attachments: Vec<AzureAttachment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WiqlResponse {
+    #[serde(rename = "workItems", default)]
+    work_items: Vec<WiqlReference>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WiqlReference {
+    id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ValueList<T> {
+    value: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawWorkItem {
+    id: i64,
+    #[serde(default)]
+    fields: serde_json::Map<String, Value>,
+    #[serde(default)]
+    relations: Vec<RawRelation>,
+    #[serde(rename = "_links", default)]
+    links: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawRelation {
+    rel: String,
+    url: String,
+    #[serde(default)]
+    attributes: Value,
+}
+
+impl RawWorkItem {
+    fn field(&self, name: &str) -> Option<String> {
+        self.fields
+            .get(name)
+            .and_then(Value::as_str)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    }
+
+    fn into_work_item(self) -> AzureWorkItem {
+        let tags = self
+            .field("System.Tags")
+            .map(|tags| {
+                tags.split(';')
+                    .map(str::trim)
+                    .filter(|tag| !tag.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let assigned_to = self
+            .fields
+            .get("System.AssignedTo")
+            .and_then(|user| user.get("displayName"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let attachments = self
+            .relations
+            .iter()
+            .filter(|relation| relation.rel == "AttachedFile")
+            .map(|relation| AzureAttachment {
+                name: relation
+                    .attributes
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("attachment")
+                    .to_string(),
+                url: relation.url.clone(),
+            })
+            .collect();
+        AzureWorkItem {
+            id: self.id,
+            title: self.field("System.Title").unwrap_or_default(),
+            description: self.field("System.Description"),
+            state: self.field("System.State").unwrap_or_default(),
+            work_item_type: self.field("System.WorkItemType").unwrap_or_default(),
+            tags,
+            assigned_to,
+            html_url: self
+                .links
+                .pointer("/html/href")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            attachments,
+        }
+    }
+}
+
+pub struct AzureWorkItemsService {
+    http: HttpClient,
+}
+
+impl AzureWorkItemsService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    /// `https://dev.azure.com/{org}` or an Azure DevOps Server collection
+    /// URL, without a trailing slash
+    pub fn normalize_organization_url(url: &str) -> Result<String, AzureWorkItemsError> {
+        let trimmed = url.trim().trim_end_matches('/');
+        let parsed = url::Url::parse(trimmed)
+            .map_err(|_| AzureWorkItemsError::InvalidOrganizationUrl(url.to_string()))?;
+        if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+            return Err(AzureWorkItemsError::InvalidOrganizationUrl(url.to_string()));
+        }
+        Ok(trimmed.to_string())
+    }
+
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, AzureWorkItemsError> {
+        let response = request.send_via(&self.http).await?;
+        let status = response.status();
+        // Azure DevOps answers a bad token with 203 and a sign-in page
+        if status.as_u16() == 401 || status.as_u16() == 203 {
+            return Err(AzureWorkItemsError::Unauthorized);
+        }
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(AzureWorkItemsError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        Ok(response)
+    }
+
+    /// Ids of open work items in `project` matching the optional WIQL
+    /// `filter`, most recently changed first
+    pub async fn query_work_item_ids(
+        &self,
+        organization_url: &str,
+        project: &str,
+        pat: &str,
+        filter: Option<&str>,
+        top: usize,
+    ) -> Result<Vec<i64>, AzureWorkItemsError> {
+        let url = format!(
+            "{}/{}/_apis/wit/wiql?$top={}&api-version={}",
+            organization_url,
+            urlencoding::encode(project),
+            top.clamp(1, 20_000),
+            API_VERSION
+        );
+        let response = self
+            .send(
+                self.http
+                    .post(&url)
+                    .basic_auth("", Some(pat))
+                    .json(&json!({ "query": build_wiql(filter) })),
+            )
+            .await?;
+        let body: WiqlResponse = response
+            .json()
+            .await
+            .map_err(|e| AzureWorkItemsError::ParseError(e.to_string()))?;
+        Ok(body.work_items.into_iter().map(|item| item.id).collect())
+    }
+
+    /// Work items by id, with their attachments, in the order given
+    pub async fn get_work_items(
+        &self,
+        organization_url: &str,
+        pat: &str,
+        ids: &[i64],
+    ) -> Result<Vec<AzureWorkItem>, AzureWorkItemsError> {
+        let mut items = Vec::with_capacity(ids.len());
+        for batch in ids.chunks(BATCH_SIZE) {
+            let ids: Vec<String> = batch.iter().map(i64::to_string).collect();
+            let url = format!(
+                "{}/_apis/wit/workitems?ids={}&$expand=all&errorPolicy=omit&api-version={}",
+                organization_url,
+                ids.join(","),
+                API_VERSION
+            );
+            let response = self
+                .send(self.http.get(&url).basic_auth("", Some(pat)))
+                .await?;
+            // With errorPolicy=omit, deleted or inaccessible items come back as null
+            let body: ValueList<Option<RawWorkItem>> = response
+                .json()
+                .await
+                .map_err(|e| AzureWorkItemsError::ParseError(e.to_string()))?;
+            items.extend(
+                body.value
+                    .into_iter()
+                    .flatten()
+                    .map(RawWorkItem::into_work_item),
+            );
+        }
+        Ok(items)
+    }
+
+    pub async fn list_work_items(
+        &self,
+        organization_url: &str,
+        project: &str,
+        pat: &str,
+        filter: Option<&str>,
+        top: usize,
+    ) -> Result<Vec<AzureWorkItem>, AzureWorkItemsError> {
+        let ids = self
+            .query_work_item_ids(organization_url, project, pat, filter, top)
+            .await?;
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+        self.get_work_items(organization_url, pat, &ids).await
+    }
+
+    pub async fn get_work_item(
+        &self,
+        organization_url: &str,
+        pat: &str,
+        id: i64,
+    ) -> Result<AzureWorkItem, AzureWorkItemsError> {
+        self.get_work_items(organization_url, pat, &[id])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(AzureWorkItemsError::NotFound(id))
+    }
+
+    pub async fn download_attachment(
+        &self,
+        pat: &str,
+        attachment: &AzureAttachment,
+    ) -> Result<Vec<u8>, AzureWorkItemsError> {
+        let response = self
+            .send(self.http.get(&attachment.url).basic_auth("", Some(pat)))
+            .await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Comment on a work item. Comments are rendered as HTML, so `text` is
+    /// escaped and its line breaks kept.
+    pub async fn add_comment(
+        &self,
+        organization_url: &str,
+        project: &str,
+        pat: &str,
+        id: i64,
+        text: &str,
+    ) -> Result<(), AzureWorkItemsError> {
+        let url = format!(
+            "{}/{}/_apis/wit/workItems/{}/comments?api-version={}-preview.4",
+            organization_url,
+            urlencoding::encode(project),
+            id,
+            API_VERSION
+        );
+        let html = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('\n', "<br>");
+        self.send(
+            self.http
+                .post(&url)
+                .basic_auth("", Some(pat))
+                .json(&json!({ "text": html })),
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+impl Default for AzureWorkItemsService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// WIQL selecting open work items of the current project, narrowed by
+/// `filter` (a WIQL condition such as `[System.AreaPath] UNDER 'Web'`)
+pub fn build_wiql(filter: Option<&str>) -> String {
+    let mut wiql = format!(
+        "SELECT [System.Id] FROM WorkItems \
+         WHERE [System.TeamProject] = @project \
+         AND [System.State] NOT IN ({CLOSED_STATES})"
+    );
+    if let Some(filter) = filter.map(str::trim).filter(|f| !f.is_empty()) {
+        wiql.push_str(&format!(" AND ({filter})"));
+    }
+    wiql.push_str(" ORDER BY [System.ChangedDate] DESC");
+    wiql
+}
+
+/// Plain text of a work item's HTML description, keeping line breaks
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let inner = &rest[start + 1..start + end];
+        let closing = inner.starts_with('/');
+        let tag = inner
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        // Line breaks, and the end of each block element, start a new line
+        if tag == "br" || (closing && matches!(tag.as_str(), "p" | "div" | "li" | "tr")) {
+            text.push('\n');
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+
+    let decoded = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+    let lines: Vec<&str> = decoded.lines().map(str::trim_end).collect();
+    let mut result = lines.join("\n");
+    while result.contains("\n\n\n") {
+        result = result.replace("\n\n\n", "\n\n");
+    }
+    result.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_wiql_with_optional_filter() {
+        let plain = build_wiql(None);
+        assert!(plain.contains("[System.TeamProject] = @project"));
+        assert!(plain.ends_with("ORDER BY [System.ChangedDate] DESC"));
+        assert_eq!(build_wiql(Some("  ")), plain);
+
+        let filtered = build_wiql(Some("[System.Tags] CONTAINS 'agent'"));
+        assert!(filtered.contains("AND ([System.Tags] CONTAINS 'agent') ORDER BY"));
+    }
+
+    #[test]
+    fn converts_description_html_to_text() {
+        assert_eq!(
+            html_to_text(
+                "<div>Steps:</div><div><br></div><ol><li>Open &quot;Settings&quot;</li>\
+                 <li>Click&nbsp;save</li></ol><p>a &lt; b &amp;&amp; c</p>"
+            ),
+            "Steps:\n\nOpen \"Settings\"\nClick save\na < b && c"
+        );
+    }
+}
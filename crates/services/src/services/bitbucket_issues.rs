@@ -0,0 +1,341 @@
+//! Bitbucket Cloud issues and pull requests, authenticated with a username and
+//! app password.
+
+use chrono::{DateTime, Utc};
+use reqwest::RequestBuilder;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const BITBUCKET_API_BASE: &str = "https://api.bitbucket.org/2.0";
+
+#[derive(Debug, Error)]
+pub enum BitbucketError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Bitbucket API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Invalid repository URL format: {0}")]
+    InvalidRepoUrl(String),
+}
+
+/// Username and app password for basic auth
+#[derive(Debug, Clone)]
+pub struct BitbucketCredentials {
+    pub username: String,
+    pub app_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(from = "RawBitbucketIssue")]
+pub struct BitbucketIssue {
+    pub id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    /// `new`, `open`, `resolved`, `on hold`, `invalid`, `duplicate`, `wontfix` or `closed`
+    pub state: String,
+    pub kind: Option<String>,
+    pub priority: Option<String>,
+    pub html_url: String,
+    pub reporter: Option<String>,
+    #[ts(type = "string")]
+    pub created_on: DateTime<Utc>,
+    #[ts(type = "string")]
+    pub updated_on: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawBitbucketIssue {
+    id: i64,
+    title: String,
+    content: Option<BitbucketContent>,
+    state: String,
+    kind: Option<String>,
+    priority: Option<String>,
+    links: BitbucketLinks,
+    reporter: Option<BitbucketAccount>,
+    created_on: DateTime<Utc>,
+    updated_on: DateTime<Utc>,
+}
+
+impl From<RawBitbucketIssue> for BitbucketIssue {
+    fn from(raw: RawBitbucketIssue) -> Self {
+        Self {
+            id: raw.id,
+            title: raw.title,
+            description: raw
+                .content
+                .and_then(|c| c.raw)
+                .filter(|raw| !raw.is_empty()),
+            state: raw.state,
+            kind: raw.kind,
+            priority: raw.priority,
+            html_url: raw.links.html.href,
+            reporter: raw.reporter.map(|r| r.display_name),
+            created_on: raw.created_on,
+            updated_on: raw.updated_on,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketContent {
+    raw: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketLinks {
+    html: BitbucketLink,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketLink {
+    href: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketAccount {
+    display_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketPage<T> {
+    values: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ListBitbucketIssuesParams {
+    /// Only issues in these states; defaults to the unresolved ones
+    pub states: Vec<String>,
+    pub pagelen: Option<i32>,
+    pub page: Option<i32>,
+}
+
+impl Default for ListBitbucketIssuesParams {
+    fn default() -> Self {
+        Self {
+            states: vec!["new".to_string(), "open".to_string()],
+            pagelen: Some(30),
+            page: Some(1),
+        }
+    }
+}
+
+/// The fields of a created pull request that get stored on the workspace
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawBitbucketPullRequest")]
+pub struct BitbucketPullRequestInfo {
+    pub id: i64,
+    pub html_url: String,
+    pub state: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawBitbucketPullRequest {
+    id: i64,
+    state: String,
+    links: BitbucketLinks,
+}
+
+impl From<RawBitbucketPullRequest> for BitbucketPullRequestInfo {
+    fn from(raw: RawBitbucketPullRequest) -> Self {
+        Self {
+            id: raw.id,
+            html_url: raw.links.html.href,
+            state: raw.state,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateBitbucketPullRequest {
+    pub source_branch: String,
+    pub target_branch: String,
+    pub title: String,
+    pub description: String,
+    pub close_source_branch: bool,
+}
+
+/// Bitbucket's `q` filter for a set of issue states
+fn state_query(states: &[String]) -> Option<String> {
+    let clauses: Vec<String> = states
+        .iter()
+        .map(|state| format!("state=\"{}\"", state.replace('"', "")))
+        .collect();
+    (!clauses.is_empty()).then(|| clauses.join(" OR "))
+}
+
+pub struct BitbucketService {
+    http: HttpClient,
+}
+
+impl BitbucketService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    /// Workspace and repo slug from a `bitbucket.org` clone or web URL
+    pub fn parse_repo_url(url: &str) -> Result<(String, String), BitbucketError> {
+        let re = regex::Regex::new(
+            r"bitbucket\.org[:/](?P<workspace>[^/]+)/(?P<slug>[^/]+?)(?:\.git)?(?:/.*)?$",
+        )
+        .map_err(|_| BitbucketError::InvalidRepoUrl(url.to_string()))?;
+        let caps = re
+            .captures(url.trim())
+            .ok_or_else(|| BitbucketError::InvalidRepoUrl(url.to_string()))?;
+        Ok((caps["workspace"].to_string(), caps["slug"].to_string()))
+    }
+
+    fn authed(
+        &self,
+        request: RequestBuilder,
+        credentials: &BitbucketCredentials,
+    ) -> RequestBuilder {
+        request
+            .basic_auth(&credentials.username, Some(&credentials.app_password))
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+    }
+
+    async fn parse_response<T: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<T, BitbucketError> {
+        let status = response.status();
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(BitbucketError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        Ok(response.json().await?)
+    }
+
+    pub async fn list_issues(
+        &self,
+        credentials: &BitbucketCredentials,
+        workspace: &str,
+        repo_slug: &str,
+        params: &ListBitbucketIssuesParams,
+    ) -> Result<Vec<BitbucketIssue>, BitbucketError> {
+        let url = format!(
+            "{}/repositories/{}/{}/issues",
+            BITBUCKET_API_BASE, workspace, repo_slug
+        );
+
+        let mut request = self
+            .authed(self.http.get(&url), credentials)
+            .query(&[("sort", "-updated_on")]);
+        if let Some(q) = state_query(&params.states) {
+            request = request.query(&[("q", q)]);
+        }
+        if let Some(pagelen) = params.pagelen {
+            request = request.query(&[("pagelen", pagelen.to_string())]);
+        }
+        if let Some(page) = params.page {
+            request = request.query(&[("page", page.to_string())]);
+        }
+
+        let page: BitbucketPage<BitbucketIssue> =
+            Self::parse_response(request.send_via(&self.http).await?).await?;
+        Ok(page.values)
+    }
+
+    pub async fn get_issue(
+        &self,
+        credentials: &BitbucketCredentials,
+        workspace: &str,
+        repo_slug: &str,
+        issue_id: i64,
+    ) -> Result<BitbucketIssue, BitbucketError> {
+        let url = format!(
+            "{}/repositories/{}/{}/issues/{}",
+            BITBUCKET_API_BASE, workspace, repo_slug, issue_id
+        );
+        let response = self
+            .authed(self.http.get(&url), credentials)
+            .send_via(&self.http)
+            .await?;
+        Self::parse_response(response).await
+    }
+
+    pub async fn create_pull_request(
+        &self,
+        credentials: &BitbucketCredentials,
+        workspace: &str,
+        repo_slug: &str,
+        request: &CreateBitbucketPullRequest,
+    ) -> Result<BitbucketPullRequestInfo, BitbucketError> {
+        let url = format!(
+            "{}/repositories/{}/{}/pullrequests",
+            BITBUCKET_API_BASE, workspace, repo_slug
+        );
+        let body = serde_json::json!({
+            "title": request.title,
+            "description": request.description,
+            "source": { "branch": { "name": request.source_branch } },
+            "destination": { "branch": { "name": request.target_branch } },
+            "close_source_branch": request.close_source_branch,
+        });
+        let response = self
+            .authed(self.http.post(&url), credentials)
+            .json(&body)
+            .send_via(&self.http)
+            .await?;
+        Self::parse_response(response).await
+    }
+}
+
+impl Default for BitbucketService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bitbucket_remotes() {
+        let expected = ("acme".to_string(), "web-app".to_string());
+        assert_eq!(
+            BitbucketService::parse_repo_url("git@bitbucket.org:acme/web-app.git").unwrap(),
+            expected
+        );
+        assert_eq!(
+            BitbucketService::parse_repo_url("https://bitbucket.org/acme/web-app/src/main/")
+                .unwrap(),
+            expected
+        );
+        assert!(BitbucketService::parse_repo_url("https://github.com/acme/web-app").is_err());
+    }
+
+    #[test]
+    fn flattens_issue_payload() {
+        let issue: BitbucketIssue = serde_json::from_str(
+            r#"{"id": 4, "title": "Crash", "content": {"raw": ""}, "state": "new",
+                "kind": "bug", "priority": "major",
+                "links": {"html": {"href": "https://bitbucket.org/acme/web-app/issues/4"}},
+                "reporter": {"display_name": "Ana"},
+                "created_on": "2025-01-02T03:04:05.000000+00:00",
+                "updated_on": "2025-01-02T03:04:05.000000+00:00"}"#,
+        )
+        .unwrap();
+        assert_eq!(issue.description, None);
+        assert_eq!(issue.reporter.as_deref(), Some("Ana"));
+        assert_eq!(
+            state_query(&ListBitbucketIssuesParams::default().states).as_deref(),
+            Some(r#"state="new" OR state="open""#)
+        );
+    }
+}
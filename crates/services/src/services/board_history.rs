@@ -0,0 +1,115 @@
+//! The project board as it was at a past moment, rebuilt from task activity,
+//! for looking back at how a sprint evolved.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use db::models::{
+    task::TaskStatus,
+    task_activity::{TaskActivity, TaskActivityKind},
+};
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+pub struct BoardSnapshotTask {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    /// When the task entered `status`
+    pub status_since: DateTime<Utc>,
+}
+
+/// Tasks on the board once `activity`, oldest first, has happened, ordered by
+/// when they entered their status. Deleted tasks are left out.
+pub fn board_at(activity: &[TaskActivity]) -> Vec<BoardSnapshotTask> {
+    let mut board: HashMap<Uuid, BoardSnapshotTask> = HashMap::new();
+    for event in activity {
+        if event.kind == TaskActivityKind::Deleted {
+            board.remove(&event.task_id);
+            continue;
+        }
+        let status_since = match board.get(&event.task_id) {
+            Some(task) if task.status == event.status => task.status_since,
+            _ => event.created_at,
+        };
+        board.insert(
+            event.task_id,
+            BoardSnapshotTask {
+                task_id: event.task_id,
+                title: event.title.clone(),
+                status: event.status.clone(),
+                status_since,
+            },
+        );
+    }
+
+    let mut tasks: Vec<BoardSnapshotTask> = board.into_values().collect();
+    tasks.sort_by_key(|task| (task.status_since, task.task_id));
+    tasks
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn event(
+        task_id: Uuid,
+        kind: TaskActivityKind,
+        title: &str,
+        status: TaskStatus,
+        minute: u32,
+    ) -> TaskActivity {
+        TaskActivity {
+            task_id,
+            kind,
+            title: title.to_string(),
+            status,
+            created_at: Utc.with_ymd_and_hms(2026, 3, 2, 9, minute, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn replays_status_changes_and_deletions() {
+        let (login, docs) = (Uuid::new_v4(), Uuid::new_v4());
+        let activity = vec![
+            event(
+                login,
+                TaskActivityKind::Created,
+                "Fix login",
+                TaskStatus::Todo,
+                0,
+            ),
+            event(docs, TaskActivityKind::Created, "Docs", TaskStatus::Todo, 1),
+            event(
+                login,
+                TaskActivityKind::Updated,
+                "Fix login",
+                TaskStatus::InProgress,
+                5,
+            ),
+            event(
+                login,
+                TaskActivityKind::Updated,
+                "Fix the login",
+                TaskStatus::InProgress,
+                7,
+            ),
+            event(docs, TaskActivityKind::Deleted, "Docs", TaskStatus::Todo, 9),
+        ];
+
+        assert_eq!(
+            board_at(&activity),
+            vec![BoardSnapshotTask {
+                task_id: login,
+                title: "Fix the login".to_string(),
+                status: TaskStatus::InProgress,
+                status_since: Utc.with_ymd_and_hms(2026, 3, 2, 9, 5, 0).unwrap(),
+            }]
+        );
+        assert_eq!(board_at(&activity[..2]).len(), 2);
+    }
+}
@@ -0,0 +1,131 @@
+//! iCalendar (RFC 5545) rendering for read-only project calendar feeds.
+
+use chrono::{DateTime, Utc};
+
+/// Longest content line in octets before it must be folded
+const MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    /// Stable identifier so calendar clients update events instead of duplicating them
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub start: DateTime<Utc>,
+    /// None for point-in-time events such as deadlines
+    pub end: Option<DateTime<Utc>>,
+    pub updated: DateTime<Utc>,
+}
+
+fn format_timestamp(at: &DateTime<Utc>) -> String {
+    at.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT property value
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folding it onto continuation lines without splitting characters
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+pub fn render_ics(calendar_name: &str, events: &[CalendarEvent]) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//Vibe Kanban//Project Calendar//EN");
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+    push_line(&mut out, "METHOD:PUBLISH");
+    push_line(
+        &mut out,
+        &format!("X-WR-CALNAME:{}", escape_text(calendar_name)),
+    );
+    for event in events {
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{}", escape_text(&event.uid)));
+        push_line(
+            &mut out,
+            &format!("DTSTAMP:{}", format_timestamp(&event.updated)),
+        );
+        push_line(
+            &mut out,
+            &format!("DTSTART:{}", format_timestamp(&event.start)),
+        );
+        if let Some(end) = &event.end {
+            push_line(&mut out, &format!("DTEND:{}", format_timestamp(end)));
+        }
+        push_line(
+            &mut out,
+            &format!("SUMMARY:{}", escape_text(&event.summary)),
+        );
+        if let Some(description) = &event.description {
+            push_line(
+                &mut out,
+                &format!("DESCRIPTION:{}", escape_text(description)),
+            );
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn renders_escaped_event() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+        let ics = render_ics(
+            "Board",
+            &[CalendarEvent {
+                uid: "task-1-due@vibe-kanban".to_string(),
+                summary: "Due: fix login, again; soon".to_string(),
+                description: Some("line one\nline two".to_string()),
+                start: at,
+                end: None,
+                updated: at,
+            }],
+        );
+        assert!(ics.contains("DTSTART:20260304T050607Z\r\n"));
+        assert!(ics.contains("SUMMARY:Due: fix login\\, again\\; soon\r\n"));
+        assert!(ics.contains("DESCRIPTION:line one\\nline two\r\n"));
+        assert!(!ics.contains("DTEND"));
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let mut out = String::new();
+        push_line(&mut out, &format!("SUMMARY:{}", "é".repeat(60)));
+        assert!(out.split("\r\n").all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert_eq!(
+            out.replace("\r\n ", ""),
+            format!("SUMMARY:{}\r\n", "é".repeat(60))
+        );
+    }
+}
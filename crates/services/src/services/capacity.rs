@@ -0,0 +1,184 @@
+//! Capacity planning from task estimates: how much open, estimated work sits
+//! with each assignee compared to their weekly capacity, and when the
+//! sequential queue should drain.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, Utc};
+use db::models::{
+    task_estimate::{EstimateUnit, OpenTaskEstimate},
+    user_capacity::UserCapacity,
+};
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Estimated work summed per unit; hours and points are never converted
+#[derive(Debug, Clone, Default, PartialEq, Serialize, TS)]
+pub struct EstimatedWork {
+    pub hours: f64,
+    pub points: f64,
+    pub tasks: usize,
+}
+
+impl EstimatedWork {
+    fn add(&mut self, value: f64, unit: EstimateUnit) {
+        match unit {
+            EstimateUnit::Hours => self.hours += value,
+            EstimateUnit::Points => self.points += value,
+        }
+        self.tasks += 1;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct UserCapacityUsage {
+    pub user_id: Uuid,
+    pub username: String,
+    pub queued: EstimatedWork,
+    pub weekly_hours: Option<f64>,
+    pub weekly_points: Option<f64>,
+    /// Queued work as a share of weekly capacity, taking the fuller of the two
+    /// units; above 1.0 the user is overbooked. `None` when no capacity is set.
+    pub utilization: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ProjectCapacity {
+    pub users: Vec<UserCapacityUsage>,
+    /// Estimated work on tasks nobody is assigned to
+    pub unassigned: EstimatedWork,
+}
+
+/// Compare each user's open work against their capacity. Users appear when
+/// they have capacity set or estimated work assigned.
+pub fn summarize_capacity(
+    users: &[(Uuid, String)],
+    capacities: &[UserCapacity],
+    open: &[OpenTaskEstimate],
+) -> ProjectCapacity {
+    let mut unassigned = EstimatedWork::default();
+    let mut queued: HashMap<Uuid, EstimatedWork> = HashMap::new();
+    for estimate in open {
+        match estimate.assignee_user_id {
+            Some(user_id) => queued
+                .entry(user_id)
+                .or_default()
+                .add(estimate.value, estimate.unit),
+            None => unassigned.add(estimate.value, estimate.unit),
+        }
+    }
+
+    let users = users
+        .iter()
+        .filter_map(|(user_id, username)| {
+            let capacity = capacities.iter().find(|c| c.user_id == *user_id);
+            let work = queued.remove(user_id);
+            if capacity.is_none() && work.is_none() {
+                return None;
+            }
+            let work = work.unwrap_or_default();
+            let weekly_hours = capacity.and_then(|c| c.weekly_hours);
+            let weekly_points = capacity.and_then(|c| c.weekly_points);
+            let ratio = |used: f64, available: Option<f64>| {
+                available.filter(|a| *a > 0.0).map(|a| used / a)
+            };
+            let utilization = match (
+                ratio(work.hours, weekly_hours),
+                ratio(work.points, weekly_points),
+            ) {
+                (Some(h), Some(p)) => Some(h.max(p)),
+                (h, p) => h.or(p),
+            };
+            Some(UserCapacityUsage {
+                user_id: *user_id,
+                username: username.clone(),
+                queued: work,
+                weekly_hours,
+                weekly_points,
+                utilization,
+            })
+        })
+        .collect();
+
+    ProjectCapacity { users, unassigned }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueEta {
+    pub estimated_completion_at: Option<DateTime<Utc>>,
+    /// Queued tasks without an hour estimate, which the ETA leaves out
+    pub unestimated_tasks: usize,
+}
+
+/// When the queued tasks should be done if they run back to back from `now`.
+/// Only hour estimates count towards the ETA, since points have no duration.
+pub fn queue_eta(now: DateTime<Utc>, queue: &[Uuid], estimates: &[OpenTaskEstimate]) -> QueueEta {
+    let mut hours = None;
+    let mut unestimated_tasks = 0;
+    for task_id in queue {
+        match estimates
+            .iter()
+            .find(|e| e.task_id == *task_id && e.unit == EstimateUnit::Hours)
+        {
+            Some(estimate) => *hours.get_or_insert(0.0) += estimate.value,
+            None => unestimated_tasks += 1,
+        }
+    }
+    QueueEta {
+        estimated_completion_at: hours
+            .map(|h: f64| now + Duration::seconds((h * 3600.0).round() as i64)),
+        unestimated_tasks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn estimate(
+        task_id: Uuid,
+        assignee: Option<Uuid>,
+        value: f64,
+        unit: EstimateUnit,
+    ) -> OpenTaskEstimate {
+        OpenTaskEstimate {
+            task_id,
+            assignee_user_id: assignee,
+            value,
+            unit,
+        }
+    }
+
+    #[test]
+    fn compares_assigned_work_with_capacity_and_sums_queue_hours() {
+        let (alice, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let tasks: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let open = vec![
+            estimate(tasks[0], Some(alice), 30.0, EstimateUnit::Hours),
+            estimate(tasks[1], Some(alice), 5.0, EstimateUnit::Points),
+            estimate(tasks[2], None, 2.5, EstimateUnit::Hours),
+        ];
+        let capacities = vec![UserCapacity {
+            user_id: alice,
+            weekly_hours: Some(20.0),
+            weekly_points: Some(10.0),
+            updated_at: Utc::now(),
+        }];
+        let users = vec![(alice, "alice".to_string()), (bob, "bob".to_string())];
+
+        let summary = summarize_capacity(&users, &capacities, &open);
+        assert_eq!(summary.users.len(), 1);
+        assert_eq!(summary.users[0].queued.tasks, 2);
+        assert_eq!(summary.users[0].utilization, Some(1.5));
+        assert_eq!(summary.unassigned.hours, 2.5);
+
+        let now = Utc::now();
+        let eta = queue_eta(now, &tasks, &open);
+        assert_eq!(
+            eta.estimated_completion_at,
+            Some(now + Duration::minutes(32 * 60 + 30))
+        );
+        assert_eq!(eta.unestimated_tasks, 2);
+    }
+}
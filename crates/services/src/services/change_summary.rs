@@ -0,0 +1,109 @@
+//! Commit message and PR description generated by the coding agent from an
+//! attempt's diff. The agent replies with a fenced `change-summary` block that
+//! is parsed when its run finishes.
+
+use serde::Deserialize;
+
+use crate::services::follow_up_tasks::last_fenced_block;
+
+/// Info string of the fenced block agents reply with
+pub const CHANGE_SUMMARY_FENCE: &str = "change-summary";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ChangeSummary {
+    pub commit_message: String,
+    #[serde(default)]
+    pub pr_title: String,
+    #[serde(default)]
+    pub pr_description: String,
+}
+
+/// Prompt asking the agent to summarize the branch's changes against each repo's
+/// target branch, given as `(repo name, target branch)` pairs, leaving out files
+/// matching the project's `excluded` patterns
+pub fn summary_prompt(repos: &[(String, String)], excluded: &[String]) -> String {
+    let diffs = repos
+        .iter()
+        .map(|(name, target_branch)| {
+            format!("- `{name}`: run `git diff {target_branch}...HEAD` in that directory")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let exclusions = if excluded.is_empty() {
+        String::new()
+    } else {
+        let patterns = excluded
+            .iter()
+            .map(|pattern| format!("`{pattern}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(
+            "Leave out changes to files matching these gitignore-style patterns, such as \
+             generated or vendored code, and don't mention those files: {patterns}\n\n"
+        )
+    };
+    format!(
+        "Summarize the changes on this branch. Do not modify any files.\n\n\
+         Review the diff of each repository against its target branch:\n{diffs}\n\n\
+         {exclusions}Then reply with a fenced code block tagged `{CHANGE_SUMMARY_FENCE}` \
+         containing a JSON object with:\n\
+         - `commit_message`: a conventional commit message, a subject line of at most 72 \
+         characters, a blank line, then a short body\n\
+         - `pr_title`: a concise pull request title\n\
+         - `pr_description`: a markdown pull request description explaining what changed and why"
+    )
+}
+
+/// Read the summary from the last `change-summary` block in an agent message.
+/// A missing PR title or description falls back to the commit message's subject
+/// and body.
+pub fn parse_change_summary(message: &str) -> Option<ChangeSummary> {
+    let body = last_fenced_block(message, CHANGE_SUMMARY_FENCE)?;
+    let summary: ChangeSummary = serde_json::from_str(body).ok()?;
+    let commit_message = summary.commit_message.trim().to_string();
+    if commit_message.is_empty() {
+        return None;
+    }
+
+    let (subject, rest) = commit_message
+        .split_once('\n')
+        .unwrap_or((commit_message.as_str(), ""));
+    let pr_title = match summary.pr_title.trim() {
+        "" => subject.trim().to_string(),
+        title => title.to_string(),
+    };
+    let pr_description = match summary.pr_description.trim() {
+        "" => rest.trim().to_string(),
+        description => description.to_string(),
+    };
+    Some(ChangeSummary {
+        commit_message,
+        pr_title,
+        pr_description,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_summary_and_fills_missing_fields_from_commit_message() {
+        let message = "Here is the summary.\n\n```change-summary\n{\"commit_message\": \"fix(auth): refresh expired sessions\\n\\nRetry once with the refresh token.\"}\n```\n";
+        assert_eq!(
+            parse_change_summary(message),
+            Some(ChangeSummary {
+                commit_message:
+                    "fix(auth): refresh expired sessions\n\nRetry once with the refresh token."
+                        .to_string(),
+                pr_title: "fix(auth): refresh expired sessions".to_string(),
+                pr_description: "Retry once with the refresh token.".to_string(),
+            })
+        );
+        assert_eq!(
+            parse_change_summary("```change-summary\n{\"commit_message\": \" \"}\n```"),
+            None
+        );
+        assert_eq!(parse_change_summary("No block here"), None);
+    }
+}
@@ -0,0 +1,480 @@
+//! CI results and review state of the pull and merge requests opened from
+//! workspaces. They're polled and cached per pull request, with the combined CI
+//! result on the workspace so projects can keep tasks out of Done until CI
+//! passes.
+
+use std::{collections::BTreeSet, time::Duration};
+
+use chrono::{DateTime, Utc};
+use db::{
+    DBService,
+    models::{
+        gitlab_merge_request::GitLabMergeRequest,
+        merge::{Merge, MergeStatus},
+        project::Project,
+        pull_request_status::{CiCheck, PullRequestStatus, ReviewState},
+        task::{Task, TaskStatus},
+        workspace::{Workspace, WorkspaceError},
+        workspace_ci_status::{CiStatus, WorkspaceCiStatus},
+    },
+};
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::{SqlitePool, error::Error as SqlxError};
+use thiserror::Error;
+use tokio::time::interval;
+use tracing::{error, info};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::services::{
+    github::{GitHubRepoInfo, GitHubService, GitHubServiceError},
+    gitlab_issues::{GitLabIssuesError, GitLabIssuesService},
+};
+
+#[derive(Debug, Error)]
+pub enum CiStatusError {
+    #[error(transparent)]
+    GitHub(#[from] GitHubServiceError),
+    #[error(transparent)]
+    GitLab(#[from] GitLabIssuesError),
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
+    #[error(transparent)]
+    Sqlx(#[from] SqlxError),
+}
+
+/// Fold several results together: any failure fails, then anything still running
+pub fn combine(statuses: impl IntoIterator<Item = CiStatus>) -> CiStatus {
+    statuses
+        .into_iter()
+        .max_by_key(|status| match status {
+            CiStatus::None => 0,
+            CiStatus::Success => 1,
+            CiStatus::Pending => 2,
+            CiStatus::Failure => 3,
+        })
+        .unwrap_or(CiStatus::None)
+}
+
+/// One entry of a pull request's `statusCheckRollup`, which mixes Actions
+/// check runs with legacy commit status contexts
+fn check_from_rollup_entry(check: &Value) -> CiCheck {
+    let text = |key: &str| check.get(key).and_then(Value::as_str);
+    let status = if let Some(state) = text("state") {
+        match state {
+            "SUCCESS" => CiStatus::Success,
+            "PENDING" | "EXPECTED" => CiStatus::Pending,
+            _ => CiStatus::Failure,
+        }
+    } else {
+        match (text("status"), text("conclusion")) {
+            (Some("COMPLETED"), Some("SUCCESS" | "NEUTRAL" | "SKIPPED")) => CiStatus::Success,
+            (Some("COMPLETED"), _) => CiStatus::Failure,
+            _ => CiStatus::Pending,
+        }
+    };
+    CiCheck {
+        name: text("name")
+            .or(text("context"))
+            .unwrap_or("check")
+            .to_string(),
+        status,
+        url: text("detailsUrl")
+            .or(text("targetUrl"))
+            .filter(|url| !url.is_empty())
+            .map(str::to_string),
+    }
+}
+
+pub fn checks_from_rollup(rollup: &[Value]) -> Vec<CiCheck> {
+    rollup.iter().map(check_from_rollup_entry).collect()
+}
+
+/// Combined result of a pull request's `statusCheckRollup`
+pub fn status_from_check_rollup(rollup: &[Value]) -> CiStatus {
+    combine(checks_from_rollup(rollup).iter().map(|check| check.status))
+}
+
+/// Review state from a pull request's `reviewDecision`
+pub fn review_state_from_github(decision: Option<&str>) -> ReviewState {
+    match decision {
+        Some("APPROVED") => ReviewState::Approved,
+        Some("CHANGES_REQUESTED") => ReviewState::ChangesRequested,
+        Some("REVIEW_REQUIRED") => ReviewState::ReviewRequired,
+        _ => ReviewState::None,
+    }
+}
+
+/// Review state from a merge request's `detailed_merge_status`. GitLab only
+/// says when approval is still missing, so approved merge requests read as
+/// `None`.
+pub fn review_state_from_gitlab(detailed_merge_status: Option<&str>) -> ReviewState {
+    match detailed_merge_status {
+        Some("not_approved") => ReviewState::ReviewRequired,
+        Some("requested_changes") => ReviewState::ChangesRequested,
+        _ => ReviewState::None,
+    }
+}
+
+fn merge_status_from_gitlab(state: &str) -> MergeStatus {
+    match state {
+        "opened" => MergeStatus::Open,
+        "merged" => MergeStatus::Merged,
+        "closed" | "locked" => MergeStatus::Closed,
+        _ => MergeStatus::Unknown,
+    }
+}
+
+/// Result of a GitLab merge request's head pipeline
+pub fn status_from_gitlab_pipeline(status: Option<&str>) -> CiStatus {
+    match status {
+        None => CiStatus::None,
+        Some("success" | "skipped") => CiStatus::Success,
+        Some("failed" | "canceled") => CiStatus::Failure,
+        Some(_) => CiStatus::Pending,
+    }
+}
+
+/// Check CI and review on the workspace's open pull and merge requests and
+/// cache the results. Merge requests found closed or merged are recorded as
+/// such, and merged ones move the task to Done if the project wants that.
+pub async fn refresh_workspace_ci(
+    pool: &SqlitePool,
+    project: &Project,
+    workspace_id: Uuid,
+) -> Result<CiStatus, CiStatusError> {
+    let mut statuses = Vec::new();
+
+    for merge in Merge::find_by_workspace_id(pool, workspace_id).await? {
+        let Merge::Pr(pr) = merge else {
+            continue;
+        };
+        if !matches!(pr.pr_info.status, MergeStatus::Open) {
+            continue;
+        }
+        let repo_info = GitHubRepoInfo::from_remote_url(&pr.pr_info.url)?;
+        let pr_checks = GitHubService::new()?
+            .get_pr_checks(&repo_info, pr.pr_info.number)
+            .await?;
+        let checks = checks_from_rollup(&pr_checks.status_check_rollup);
+        let status = combine(checks.iter().map(|check| check.status));
+        PullRequestStatus::upsert(
+            pool,
+            workspace_id,
+            &pr.pr_info.url,
+            review_state_from_github(pr_checks.review_decision.as_deref()),
+            &checks,
+            status,
+        )
+        .await?;
+        statuses.push(status);
+    }
+
+    let merge_requests = GitLabMergeRequest::find_by_workspace_id(pool, workspace_id).await?;
+    if let (Some(project_url), Some(token)) = (&project.gitlab_project_url, &project.gitlab_token) {
+        let project_path = GitLabIssuesService::parse_project_url(project_url)?;
+        let service = GitLabIssuesService::for_instance(project.gitlab_base_url.as_deref())?;
+        for mr in merge_requests.iter().filter(|mr| mr.state == "opened") {
+            let info = service
+                .get_merge_request(token, &project_path, mr.mr_iid)
+                .await?;
+            if info.state != mr.state {
+                GitLabMergeRequest::update_state(pool, mr.id, &info.state).await?;
+                if info.state == "merged"
+                    && project.done_on_merge
+                    && let Some(workspace) = Workspace::find_by_id(pool, workspace_id).await?
+                {
+                    info!(
+                        "MR !{} was merged, updating task {} to done",
+                        mr.mr_iid, workspace.task_id
+                    );
+                    Task::update_status(pool, workspace.task_id, TaskStatus::Done).await?;
+                }
+                continue;
+            }
+            let status =
+                status_from_gitlab_pipeline(info.head_pipeline.as_ref().map(|p| p.status.as_str()));
+            let checks: Vec<CiCheck> = info
+                .head_pipeline
+                .iter()
+                .map(|pipeline| CiCheck {
+                    name: "pipeline".to_string(),
+                    status,
+                    url: pipeline.web_url.clone(),
+                })
+                .collect();
+            PullRequestStatus::upsert(
+                pool,
+                workspace_id,
+                &mr.web_url,
+                review_state_from_gitlab(info.detailed_merge_status.as_deref()),
+                &checks,
+                status,
+            )
+            .await?;
+            statuses.push(status);
+        }
+    }
+
+    let status = combine(statuses);
+    WorkspaceCiStatus::upsert(pool, workspace_id, status).await?;
+    Ok(status)
+}
+
+#[derive(Debug, Clone, Copy, Serialize, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum PullRequestProvider {
+    Github,
+    Gitlab,
+}
+
+/// A pull or merge request of a workspace with its last polled review state
+/// and checks
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct WorkspacePullRequest {
+    pub provider: PullRequestProvider,
+    pub number: i64,
+    pub url: String,
+    pub state: MergeStatus,
+    pub review_state: ReviewState,
+    pub ci_status: CiStatus,
+    pub checks: Vec<CiCheck>,
+    /// None until review and CI are first polled
+    pub checked_at: Option<DateTime<Utc>>,
+}
+
+/// The workspace's pull and merge requests, oldest first, with what was last
+/// polled for each
+pub async fn workspace_pull_requests(
+    pool: &SqlitePool,
+    workspace_id: Uuid,
+) -> Result<Vec<WorkspacePullRequest>, SqlxError> {
+    let mut polled = PullRequestStatus::find_by_workspace_id(pool, workspace_id).await?;
+    let mut pull_request = |provider, number, url: String, state| {
+        let status = polled
+            .iter()
+            .position(|status| status.url == url)
+            .map(|index| polled.swap_remove(index));
+        WorkspacePullRequest {
+            provider,
+            number,
+            state,
+            review_state: status
+                .as_ref()
+                .map_or(ReviewState::None, |status| status.review_state),
+            ci_status: status
+                .as_ref()
+                .map_or(CiStatus::None, |status| status.ci_status),
+            checked_at: status.as_ref().map(|status| status.checked_at),
+            checks: status.map(|status| status.checks.0).unwrap_or_default(),
+            url,
+        }
+    };
+
+    let mut pull_requests = Vec::new();
+    // Merges come newest first
+    for merge in Merge::find_by_workspace_id(pool, workspace_id)
+        .await?
+        .into_iter()
+        .rev()
+    {
+        if let Merge::Pr(pr) = merge {
+            pull_requests.push(pull_request(
+                PullRequestProvider::Github,
+                pr.pr_info.number,
+                pr.pr_info.url,
+                pr.pr_info.status,
+            ));
+        }
+    }
+    for mr in GitLabMergeRequest::find_by_workspace_id(pool, workspace_id).await? {
+        pull_requests.push(pull_request(
+            PullRequestProvider::Gitlab,
+            mr.mr_iid,
+            mr.web_url,
+            merge_status_from_gitlab(&mr.state),
+        ));
+    }
+    Ok(pull_requests)
+}
+
+/// Why the task can't be Done yet under the project's CI gate, checking CI on
+/// every workspace of the task now
+pub async fn done_blocked_reason(
+    pool: &SqlitePool,
+    project: &Project,
+    task_id: Uuid,
+) -> Result<Option<String>, CiStatusError> {
+    if !project.wait_for_ci {
+        return Ok(None);
+    }
+    let mut statuses = Vec::new();
+    for workspace in Workspace::fetch_all(pool, Some(task_id)).await? {
+        statuses.push(refresh_workspace_ci(pool, project, workspace.id).await?);
+    }
+    Ok(match combine(statuses) {
+        CiStatus::Pending => Some(
+            "CI is still running on this task's pull requests; it can move to Done once CI passes"
+                .to_string(),
+        ),
+        CiStatus::Failure => Some(
+            "CI is failing on this task's pull requests; it can move to Done once CI passes"
+                .to_string(),
+        ),
+        CiStatus::None | CiStatus::Success => None,
+    })
+}
+
+/// Keeps the cached CI and review status of workspaces with open pull or merge
+/// requests current
+pub struct CiMonitorService {
+    db: DBService,
+    poll_interval: Duration,
+}
+
+impl CiMonitorService {
+    pub async fn spawn(db: DBService) -> tokio::task::JoinHandle<()> {
+        let service = Self {
+            db,
+            poll_interval: Duration::from_secs(120),
+        };
+        tokio::spawn(async move {
+            service.start().await;
+        })
+    }
+
+    async fn start(&self) {
+        info!(
+            "Starting CI monitoring service with interval {:?}",
+            self.poll_interval
+        );
+
+        let mut interval = interval(self.poll_interval);
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.check_all().await {
+                error!("Error checking CI status: {}", e);
+            }
+        }
+    }
+
+    async fn check_all(&self) -> Result<(), CiStatusError> {
+        let pool = &self.db.pool;
+        let mut workspace_ids = BTreeSet::new();
+        for pr in Merge::get_open_prs(pool).await? {
+            workspace_ids.insert(pr.workspace_id);
+        }
+        for mr in GitLabMergeRequest::find_open(pool).await? {
+            workspace_ids.insert(mr.workspace_id);
+        }
+
+        for workspace_id in workspace_ids {
+            let Some(workspace) = Workspace::find_by_id(pool, workspace_id).await? else {
+                continue;
+            };
+            let Some(task) = workspace.parent_task(pool).await? else {
+                continue;
+            };
+            let Some(project) = Project::find_by_id(pool, task.project_id).await? else {
+                continue;
+            };
+            if let Err(e) = refresh_workspace_ci(pool, &project, workspace_id).await {
+                error!("Error checking CI for workspace {}: {}", workspace_id, e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn rollup_fails_on_any_failure_then_waits_on_pending() {
+        let passing =
+            json!({"__typename": "CheckRun", "status": "COMPLETED", "conclusion": "SUCCESS"});
+        let skipped =
+            json!({"__typename": "CheckRun", "status": "COMPLETED", "conclusion": "SKIPPED"});
+        let running = json!({"__typename": "CheckRun", "status": "IN_PROGRESS", "conclusion": ""});
+        let failed_context = json!({"__typename": "StatusContext", "state": "ERROR"});
+
+        assert_eq!(status_from_check_rollup(&[]), CiStatus::None);
+        assert_eq!(
+            status_from_check_rollup(&[passing.clone(), skipped]),
+            CiStatus::Success
+        );
+        assert_eq!(
+            status_from_check_rollup(&[passing.clone(), running.clone()]),
+            CiStatus::Pending
+        );
+        assert_eq!(
+            status_from_check_rollup(&[running, failed_context, passing]),
+            CiStatus::Failure
+        );
+    }
+
+    #[test]
+    fn reads_individual_checks_and_review_decisions() {
+        let rollup = [
+            json!({
+                "__typename": "CheckRun",
+                "name": "build",
+                "status": "COMPLETED",
+                "conclusion": "FAILURE",
+                "detailsUrl": "https://github.com/o/r/actions/runs/1",
+            }),
+            json!({"__typename": "StatusContext", "context": "ci/lint", "state": "PENDING", "targetUrl": ""}),
+        ];
+        assert_eq!(
+            checks_from_rollup(&rollup),
+            vec![
+                CiCheck {
+                    name: "build".to_string(),
+                    status: CiStatus::Failure,
+                    url: Some("https://github.com/o/r/actions/runs/1".to_string()),
+                },
+                CiCheck {
+                    name: "ci/lint".to_string(),
+                    status: CiStatus::Pending,
+                    url: None,
+                },
+            ]
+        );
+
+        assert_eq!(
+            review_state_from_github(Some("CHANGES_REQUESTED")),
+            ReviewState::ChangesRequested
+        );
+        assert_eq!(review_state_from_github(Some("")), ReviewState::None);
+        assert_eq!(
+            review_state_from_gitlab(Some("not_approved")),
+            ReviewState::ReviewRequired
+        );
+        assert_eq!(
+            review_state_from_gitlab(Some("mergeable")),
+            ReviewState::None
+        );
+    }
+
+    #[test]
+    fn gitlab_pipelines_map_onto_ci_status() {
+        assert_eq!(status_from_gitlab_pipeline(None), CiStatus::None);
+        assert_eq!(
+            status_from_gitlab_pipeline(Some("success")),
+            CiStatus::Success
+        );
+        assert_eq!(
+            status_from_gitlab_pipeline(Some("running")),
+            CiStatus::Pending
+        );
+        assert_eq!(
+            status_from_gitlab_pipeline(Some("canceled")),
+            CiStatus::Failure
+        );
+        assert!(!combine([CiStatus::None, CiStatus::Success]).blocks_done());
+    }
+}
@@ -0,0 +1,349 @@
+//! Tasks of a ClickUp list, read through the v2 REST API with a personal API
+//! token.
+
+use db::models::task::TaskStatus;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const API_BASE: &str = "https://api.clickup.com/api/v2";
+
+#[derive(Debug, Error)]
+pub enum ClickUpError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("ClickUp API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("ClickUp rejected the API token; update it in project settings")]
+    Unauthorized,
+    #[error("Not found in ClickUp; check the id and that the token's user can see it")]
+    NotFound,
+    #[error("Invalid ClickUp list: {0}")]
+    InvalidList(String),
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ClickUpStatus {
+    pub status: String,
+    /// `open`, `custom`, `done` or `closed`
+    #[serde(rename = "type")]
+    pub status_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ClickUpTag {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ClickUpAttachment {
+    pub id: String,
+    #[serde(default)]
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ClickUpTask {
+    pub id: String,
+    pub name: String,
+    /// Plain-text description
+    #[serde(default)]
+    pub text_content: Option<String>,
+    pub status: ClickUpStatus,
+    #[serde(default)]
+    pub tags: Vec<ClickUpTag>,
+    #[serde(default)]
+    pub url: String,
+    /// Only returned when fetching a single task
+    #[serde(default)]
+    pub attachments: Vec<ClickUpAttachment>,
+}
+
+impl ClickUpTask {
+    pub fn task_status(&self) -> TaskStatus {
+        map_clickup_status(&self.status)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TasksPage {
+    tasks: Vec<ClickUpTask>,
+    #[serde(default)]
+    last_page: Option<bool>,
+}
+
+/// Options for listing a list's tasks
+#[derive(Debug, Clone, Default)]
+pub struct ListClickUpTasksParams {
+    /// Only tasks with at least one of these tags
+    pub tags: Vec<String>,
+    /// Also return tasks in closed statuses
+    pub include_closed: bool,
+    pub limit: usize,
+}
+
+pub struct ClickUpService {
+    http: HttpClient,
+}
+
+impl ClickUpService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    /// The id of a list, given the id itself or a list URL in either the
+    /// `/v/li/<id>` or the `/v/l/<view>-<id>-<n>` form
+    pub fn normalize_list_id(input: &str) -> Result<String, ClickUpError> {
+        let trimmed = input.trim();
+        let is_id = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric());
+        if is_id(trimmed) {
+            return Ok(trimmed.to_string());
+        }
+
+        let path = trimmed
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .split_once("app.clickup.com/")
+            .map(|(_, path)| path)
+            .unwrap_or_default();
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let id = segments.windows(2).find_map(|pair| match pair[0] {
+            "li" => Some(pair[1]),
+            "l" => pair[1].split('-').nth(1),
+            _ => None,
+        });
+        id.filter(|id| is_id(id))
+            .map(str::to_string)
+            .ok_or_else(|| ClickUpError::InvalidList(input.to_string()))
+    }
+
+    async fn send<T: for<'de> Deserialize<'de>>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, ClickUpError> {
+        let response = request.send_via(&self.http).await?;
+        let status = response.status();
+        match status.as_u16() {
+            401 => return Err(ClickUpError::Unauthorized),
+            403 | 404 => return Err(ClickUpError::NotFound),
+            _ => {}
+        }
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(ClickUpError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| ClickUpError::ParseError(e.to_string()))
+    }
+
+    /// Check that the list exists and the token can read it
+    pub async fn check_list(&self, token: &str, list_id: &str) -> Result<(), ClickUpError> {
+        let url = format!("{API_BASE}/list/{list_id}");
+        self.send::<Value>(self.http.get(&url).header("Authorization", token))
+            .await
+            .map(|_| ())
+    }
+
+    /// Up to `params.limit` of the list's tasks, excluding subtasks
+    pub async fn list_tasks(
+        &self,
+        token: &str,
+        list_id: &str,
+        params: &ListClickUpTasksParams,
+    ) -> Result<Vec<ClickUpTask>, ClickUpError> {
+        let url = format!("{API_BASE}/list/{list_id}/task");
+        let limit = params.limit.max(1);
+        let mut tasks = Vec::new();
+        let mut page = 0;
+        loop {
+            let mut query = vec![
+                ("page", page.to_string()),
+                ("include_closed", params.include_closed.to_string()),
+                ("subtasks", "false".to_string()),
+            ];
+            query.extend(params.tags.iter().map(|tag| ("tags[]", tag.clone())));
+            let response: TasksPage = self
+                .send(
+                    self.http
+                        .get(&url)
+                        .header("Authorization", token)
+                        .query(&query),
+                )
+                .await?;
+
+            let is_last = response.last_page.unwrap_or(true) || response.tasks.is_empty();
+            tasks.extend(response.tasks);
+            if is_last || tasks.len() >= limit {
+                break;
+            }
+            page += 1;
+        }
+        tasks.truncate(limit);
+        Ok(tasks)
+    }
+
+    pub async fn get_task(&self, token: &str, task_id: &str) -> Result<ClickUpTask, ClickUpError> {
+        let url = format!("{API_BASE}/task/{}", urlencoding::encode(task_id.trim()));
+        self.send(self.http.get(&url).header("Authorization", token))
+            .await
+    }
+
+    /// Download an attachment; ClickUp attachment URLs are public
+    pub async fn download_attachment(&self, url: &str) -> Result<Vec<u8>, ClickUpError> {
+        let response = self.http.get(url).send_via(&self.http).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClickUpError::Api {
+                status: status.as_u16(),
+                message: format!("failed to download {url}"),
+            });
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    pub async fn add_comment(
+        &self,
+        token: &str,
+        task_id: &str,
+        text: &str,
+    ) -> Result<(), ClickUpError> {
+        let url = format!("{API_BASE}/task/{}/comment", urlencoding::encode(task_id));
+        self.send::<Value>(
+            self.http
+                .post(&url)
+                .header("Authorization", token)
+                .json(&json!({ "comment_text": text, "notify_all": false })),
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+impl Default for ClickUpService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The task status a ClickUp status corresponds to. ClickUp statuses are
+/// user-defined, so custom ones are matched by name.
+pub fn map_clickup_status(status: &ClickUpStatus) -> TaskStatus {
+    let name = status.status.to_lowercase();
+    if name.contains("cancel") || name.contains("won't") || name.contains("wont") {
+        return TaskStatus::Cancelled;
+    }
+    match status.status_type.as_str() {
+        "done" | "closed" => TaskStatus::Done,
+        "open" => TaskStatus::Todo,
+        _ if name.contains("review") => TaskStatus::InReview,
+        _ if name.contains("progress") || name.contains("doing") => TaskStatus::InProgress,
+        _ if name.contains("done") || name.contains("complete") => TaskStatus::Done,
+        _ => TaskStatus::Todo,
+    }
+}
+
+/// Tags from a comma-separated filter, trimmed and lowercased as ClickUp
+/// stores them
+pub fn parse_tag_filter(filter: Option<&str>) -> Vec<String> {
+    filter
+        .unwrap_or_default()
+        .split(',')
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(name: &str, status_type: &str) -> ClickUpStatus {
+        ClickUpStatus {
+            status: name.to_string(),
+            status_type: status_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn normalizes_list_ids() {
+        assert_eq!(
+            ClickUpService::normalize_list_id("901500000001").unwrap(),
+            "901500000001"
+        );
+        assert_eq!(
+            ClickUpService::normalize_list_id(
+                "https://app.clickup.com/9015000000/v/li/901500000001"
+            )
+            .unwrap(),
+            "901500000001"
+        );
+        assert_eq!(
+            ClickUpService::normalize_list_id(
+                "https://app.clickup.com/9015000000/v/l/6-901500000001-1?pr=90150000"
+            )
+            .unwrap(),
+            "901500000001"
+        );
+        assert!(ClickUpService::normalize_list_id("My list").is_err());
+        assert!(
+            ClickUpService::normalize_list_id("https://app.clickup.com/9015000000/home").is_err()
+        );
+    }
+
+    #[test]
+    fn maps_statuses() {
+        assert_eq!(
+            map_clickup_status(&status("to do", "open")),
+            TaskStatus::Todo
+        );
+        assert_eq!(
+            map_clickup_status(&status("in progress", "custom")),
+            TaskStatus::InProgress
+        );
+        assert_eq!(
+            map_clickup_status(&status("Code Review", "custom")),
+            TaskStatus::InReview
+        );
+        assert_eq!(
+            map_clickup_status(&status("shipped", "done")),
+            TaskStatus::Done
+        );
+        assert_eq!(
+            map_clickup_status(&status("complete", "closed")),
+            TaskStatus::Done
+        );
+        assert_eq!(
+            map_clickup_status(&status("cancelled", "closed")),
+            TaskStatus::Cancelled
+        );
+        assert_eq!(
+            map_clickup_status(&status("blocked", "custom")),
+            TaskStatus::Todo
+        );
+    }
+
+    #[test]
+    fn parses_tag_filters() {
+        assert_eq!(
+            parse_tag_filter(Some(" Bug, ,frontend ")),
+            vec!["bug".to_string(), "frontend".to_string()]
+        );
+        assert!(parse_tag_filter(None).is_empty());
+    }
+}
@@ -26,6 +26,14 @@ pub type EditorType = versions::v8::EditorType;
 pub type GitHubConfig = versions::v8::GitHubConfig;
 pub type UiLanguage = versions::v8::UiLanguage;
 pub type ShowcaseState = versions::v8::ShowcaseState;
+pub type TaskValidationConfig = versions::v8::TaskValidationConfig;
+pub type ValidationLevel = versions::v8::ValidationLevel;
+pub type ApprovalPolicyConfig = versions::v8::ApprovalPolicyConfig;
+pub type ApprovalRule = versions::v8::ApprovalRule;
+pub type LogRedactionConfig = versions::v8::LogRedactionConfig;
+pub type NetworkConfig = versions::v8::NetworkConfig;
+pub type DiffSizeGuardConfig = versions::v8::DiffSizeGuardConfig;
+pub type IssueSyncScheduleConfig = versions::v8::IssueSyncScheduleConfig;
 
 /// Will always return config, trying old schemas or eventually returning default
 pub async fn load_config_from_file(config_path: &PathBuf) -> Config {
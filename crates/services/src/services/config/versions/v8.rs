@@ -1,6 +1,7 @@
 use anyhow::Error;
 use executors::{executors::BaseCodingAgent, profile::ExecutorProfileId};
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
 use ts_rs::TS;
 pub use v7::{
     EditorConfig, EditorType, GitHubConfig, NotificationConfig, ShowcaseState, SoundFile,
@@ -17,6 +18,224 @@ fn default_pr_auto_description_enabled() -> bool {
     true
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS, EnumString, PartialEq, Eq, Default)]
+#[ts(use_ts_enum)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValidationLevel {
+    Off,
+    #[default]
+    Warn,
+    Error,
+}
+
+/// Rules checked against task titles and descriptions on create and update
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+pub struct TaskValidationConfig {
+    /// Markdown headings every description must contain, e.g. "Acceptance Criteria"
+    #[serde(default)]
+    pub required_sections: Vec<String>,
+    #[serde(default)]
+    pub required_sections_level: ValidationLevel,
+    #[serde(default)]
+    pub max_description_length: Option<usize>,
+    #[serde(default)]
+    pub max_description_length_level: ValidationLevel,
+    /// Extra regexes treated like credentials, on top of the built-in secret patterns
+    #[serde(default)]
+    pub forbidden_patterns: Vec<String>,
+    #[serde(default = "default_secrets_level")]
+    pub secrets_level: ValidationLevel,
+}
+
+fn default_secrets_level() -> ValidationLevel {
+    ValidationLevel::Error
+}
+
+impl Default for TaskValidationConfig {
+    fn default() -> Self {
+        Self {
+            required_sections: Vec::new(),
+            required_sections_level: ValidationLevel::default(),
+            max_description_length: None,
+            max_description_length_level: ValidationLevel::default(),
+            forbidden_patterns: Vec::new(),
+            secrets_level: default_secrets_level(),
+        }
+    }
+}
+
+/// A named regex; shell commands matching it wait for human approval
+#[derive(Clone, Debug, Serialize, Deserialize, TS, PartialEq, Eq)]
+pub struct ApprovalRule {
+    pub name: String,
+    pub pattern: String,
+}
+
+/// Gates dangerous executor tool calls behind a human approval, even when the
+/// executor otherwise runs without asking
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+pub struct ApprovalPolicyConfig {
+    /// Only Claude Code can enforce the policy, so other agents won't start
+    /// while it's on
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_approval_rules")]
+    pub rules: Vec<ApprovalRule>,
+    /// Pause recursive deletes that reach outside the attempt's worktree
+    #[serde(default = "default_block_delete_outside_worktree")]
+    pub block_delete_outside_worktree: bool,
+}
+
+fn default_approval_rules() -> Vec<ApprovalRule> {
+    vec![
+        ApprovalRule {
+            name: "package_publish".to_string(),
+            pattern:
+                r"\b(?:npm|pnpm|yarn|cargo|poetry)\s+publish\b|\btwine\s+upload\b|\bgem\s+push\b"
+                    .to_string(),
+        },
+        ApprovalRule {
+            name: "database_migration".to_string(),
+            pattern: [
+                r"\bsqlx\s+migrate\s+run\b",
+                r"\bdiesel\s+migration\s+run\b",
+                r"\bprisma\s+migrate\s+(?:deploy|dev|reset)\b",
+                r"\balembic\s+upgrade\b",
+                r"\brails\s+db:migrate\b",
+                r"\bmanage\.py\s+migrate\b",
+                r"\bknex\s+migrate:latest\b",
+            ]
+            .join("|"),
+        },
+    ]
+}
+
+fn default_block_delete_outside_worktree() -> bool {
+    true
+}
+
+impl Default for ApprovalPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rules: default_approval_rules(),
+            block_delete_outside_worktree: default_block_delete_outside_worktree(),
+        }
+    }
+}
+
+/// What gets masked in executor output before it is streamed, stored or shared.
+/// Agents readily echo environment variables into their logs.
+#[derive(Clone, Debug, Serialize, Deserialize, TS)]
+pub struct LogRedactionConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// API keys, tokens and other well-known credential formats
+    #[serde(default = "default_true")]
+    pub secrets: bool,
+    #[serde(default = "default_true")]
+    pub emails: bool,
+    /// Hostnames under these domains are masked, e.g. "corp.example.com"
+    #[serde(default)]
+    pub internal_domains: Vec<String>,
+    /// Extra regexes; whatever they match is masked
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for LogRedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            secrets: true,
+            emails: true,
+            internal_domains: Vec::new(),
+            patterns: Vec::new(),
+        }
+    }
+}
+
+/// Outbound network settings for integrations, for corporate networks that
+/// route traffic through a proxy or intercept TLS
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TS)]
+pub struct NetworkConfig {
+    /// e.g. "http://proxy.corp.example.com:3128". When unset, `VK_HTTP_PROXY` and
+    /// then `HTTPS_PROXY`/`HTTP_PROXY` apply.
+    #[serde(default)]
+    pub http_proxy: Option<String>,
+    /// PEM file of additional CA certificates to trust, such as the root of a
+    /// TLS-inspecting proxy
+    #[serde(default)]
+    pub ca_certificates_path: Option<String>,
+}
+
+/// When an attempt's diff grows past these limits, the diff view streams only
+/// per-file stats and loads file contents on demand
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+pub struct DiffSizeGuardConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default = "default_diff_guard_max_files")]
+    pub max_files: u32,
+    /// Combined size of the old and new contents of all changed files
+    #[serde(default = "default_diff_guard_max_bytes")]
+    pub max_bytes: u32,
+}
+
+fn default_diff_guard_max_files() -> u32 {
+    300
+}
+
+fn default_diff_guard_max_bytes() -> u32 {
+    5 * 1024 * 1024
+}
+
+impl Default for DiffSizeGuardConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_files: default_diff_guard_max_files(),
+            max_bytes: default_diff_guard_max_bytes(),
+        }
+    }
+}
+
+/// Background sync of the issue providers that projects have sync enabled for
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS)]
+pub struct IssueSyncScheduleConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default = "default_issue_sync_interval_minutes")]
+    pub interval_minutes: u32,
+    /// Up to this many seconds are added to each interval at random, so
+    /// instances started together don't hit the providers at the same moment
+    #[serde(default = "default_issue_sync_jitter_seconds")]
+    pub jitter_seconds: u32,
+}
+
+fn default_issue_sync_interval_minutes() -> u32 {
+    15
+}
+
+fn default_issue_sync_jitter_seconds() -> u32 {
+    60
+}
+
+impl Default for IssueSyncScheduleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_minutes: default_issue_sync_interval_minutes(),
+            jitter_seconds: default_issue_sync_jitter_seconds(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, TS)]
 pub struct Config {
     pub config_version: String,
@@ -41,6 +260,52 @@ pub struct Config {
     pub pr_auto_description_enabled: bool,
     #[serde(default)]
     pub pr_auto_description_prompt: Option<String>,
+    /// Description template for GitLab merge requests opened without a body
+    #[serde(default)]
+    pub gitlab_mr_description_template: Option<String>,
+    /// Mailgun webhook signing key that inbound email deliveries must be signed with
+    #[serde(default)]
+    pub email_intake_signing_key: Option<String>,
+    /// OAuth app registered with Vortex, so projects can connect without pasting
+    /// a long-lived API token
+    #[serde(default)]
+    pub vortex_oauth_client_id: Option<String>,
+    #[serde(default)]
+    pub vortex_oauth_client_secret: Option<String>,
+    #[serde(default)]
+    pub task_validation: TaskValidationConfig,
+    #[serde(default)]
+    pub approval_policy: ApprovalPolicyConfig,
+    #[serde(default)]
+    pub log_redaction: LogRedactionConfig,
+    /// Ask coding agents to propose follow-up tasks for human review
+    #[serde(default)]
+    pub follow_up_task_proposals: bool,
+    /// Keep attempt branches when their worktrees are cleaned up, instead of
+    /// deleting the ones already merged into their target branch
+    #[serde(default)]
+    pub keep_branches_after_cleanup: bool,
+    /// Keep the worktrees and branch of a workspace once its changes have
+    /// landed, instead of cleaning them up right away
+    #[serde(default)]
+    pub keep_workspace_after_merge: bool,
+    /// Also delete the workspace branch on the remote once its changes have landed
+    #[serde(default)]
+    pub delete_remote_branch_after_merge: bool,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    /// How many automatically started attempts may run at once, shared across
+    /// projects by their weights. Zero means no limit.
+    #[serde(default)]
+    pub auto_start_slots: u32,
+    /// Refuse to start an attempt for a task while another attempt for it is
+    /// still running
+    #[serde(default)]
+    pub one_attempt_per_task: bool,
+    #[serde(default)]
+    pub diff_size_guard: DiffSizeGuardConfig,
+    #[serde(default)]
+    pub issue_sync_schedule: IssueSyncScheduleConfig,
 }
 
 impl Config {
@@ -66,6 +331,22 @@ impl Config {
             showcases: old_config.showcases,
             pr_auto_description_enabled: true,
             pr_auto_description_prompt: None,
+            gitlab_mr_description_template: None,
+            email_intake_signing_key: None,
+            vortex_oauth_client_id: None,
+            vortex_oauth_client_secret: None,
+            task_validation: TaskValidationConfig::default(),
+            approval_policy: ApprovalPolicyConfig::default(),
+            log_redaction: LogRedactionConfig::default(),
+            follow_up_task_proposals: false,
+            keep_branches_after_cleanup: false,
+            keep_workspace_after_merge: false,
+            delete_remote_branch_after_merge: false,
+            network: NetworkConfig::default(),
+            auto_start_slots: 0,
+            one_attempt_per_task: false,
+            diff_size_guard: DiffSizeGuardConfig::default(),
+            issue_sync_schedule: IssueSyncScheduleConfig::default(),
         }
     }
 
@@ -116,6 +397,22 @@ impl Default for Config {
             showcases: ShowcaseState::default(),
             pr_auto_description_enabled: true,
             pr_auto_description_prompt: None,
+            gitlab_mr_description_template: None,
+            email_intake_signing_key: None,
+            vortex_oauth_client_id: None,
+            vortex_oauth_client_secret: None,
+            task_validation: TaskValidationConfig::default(),
+            approval_policy: ApprovalPolicyConfig::default(),
+            log_redaction: LogRedactionConfig::default(),
+            follow_up_task_proposals: false,
+            keep_branches_after_cleanup: false,
+            keep_workspace_after_merge: false,
+            delete_remote_branch_after_merge: false,
+            network: NetworkConfig::default(),
+            auto_start_slots: 0,
+            one_attempt_per_task: false,
+            diff_size_guard: DiffSizeGuardConfig::default(),
+            issue_sync_schedule: IssueSyncScheduleConfig::default(),
         }
     }
 }
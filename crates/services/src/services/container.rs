@@ -18,13 +18,16 @@ use db::{
         execution_process_repo_state::{
             CreateExecutionProcessRepoState, ExecutionProcessRepoState,
         },
+        maintenance_job::MaintenanceJob,
+        notification::{CreateNotification, Notification, NotificationKind},
         project::{Project, UpdateProject},
+        project_instruction::TaskInstruction,
         project_repo::{ProjectRepo, ProjectRepoWithName},
         repo::Repo,
         session::{CreateSession, Session, SessionError},
-        task::{Task, TaskStatus},
-        workspace::{Workspace, WorkspaceError},
-        workspace_repo::WorkspaceRepo,
+        task::{CreateTask, Task, TaskStatus},
+        workspace::{CreateWorkspace, Workspace, WorkspaceError},
+        workspace_repo::{CreateWorkspaceRepo, WorkspaceRepo},
     },
 };
 use executors::{
@@ -33,7 +36,7 @@ use executors::{
         coding_agent_initial::CodingAgentInitialRequest,
         script::{ScriptContext, ScriptRequest, ScriptRequestLanguage},
     },
-    executors::{ExecutorError, StandardCodingAgentExecutor},
+    executors::{BaseCodingAgent, ExecutorError, StandardCodingAgentExecutor},
     logs::{NormalizedEntry, NormalizedEntryError, NormalizedEntryType, utils::ConversationPatch},
     profile::{ExecutorConfigs, ExecutorProfileId},
 };
@@ -49,10 +52,20 @@ use utils::{
 use uuid::Uuid;
 
 use crate::services::{
+    executor_slots::{ExecutorSlots, SlotPermit},
+    follow_up_tasks,
     git::{GitService, GitServiceError},
+    issue_comments,
+    issue_provider::IssueProviderRegistry,
     notification::NotificationService,
+    process_tree::ProcessTracker,
+    recovery::{InterruptedProcess, RecoveryError, RecoveryLog, RecoveryReport},
+    repo::{RepoError, RepoService},
     share::SharePublisher,
+    task_locks::TaskLocks,
+    vortex_oauth::VortexOAuthService,
     workspace_manager::WorkspaceError as WorkspaceManagerError,
+    workspace_manifest,
     worktree_manager::WorktreeError,
 };
 pub type ContainerRef = String;
@@ -73,10 +86,20 @@ pub enum ContainerError {
     WorkspaceManager(#[from] WorkspaceManagerError),
     #[error(transparent)]
     Session(#[from] SessionError),
+    #[error(transparent)]
+    Repo(#[from] RepoError),
     #[error("Io error: {0}")]
     Io(#[from] std::io::Error),
     #[error("Failed to kill process: {0}")]
     KillFailed(std::io::Error),
+    #[error("Another attempt for this task is still running. Stop it before starting a new one.")]
+    AttemptAlreadyRunning,
+    #[error("Repository {name} is unavailable: {reason}")]
+    RepoUnavailable { name: String, reason: String },
+    #[error(
+        "The command approval policy is on, but {0} can't enforce it. Turn the policy off in Settings or use Claude Code."
+    )]
+    ApprovalPolicyUnsupported(BaseCodingAgent),
     #[error(transparent)]
     Other(#[from] AnyhowError), // Catches any unclassified errors
 }
@@ -93,6 +116,18 @@ pub trait ContainerService {
 
     fn notification_service(&self) -> &NotificationService;
 
+    /// Slots shared by automatically started attempts
+    fn executor_slots(&self) -> &ExecutorSlots;
+
+    /// Enforces the one-attempt-per-task option when starting attempts
+    fn task_locks(&self) -> &TaskLocks;
+
+    /// Processes interrupted by the last shutdown
+    fn recovery_log(&self) -> &RecoveryLog;
+
+    /// Process trees of running executions
+    fn process_tracker(&self) -> &ProcessTracker;
+
     fn workspace_to_current_dir(&self, workspace: &Workspace) -> PathBuf;
 
     async fn create(&self, workspace: &Workspace) -> Result<ContainerRef, ContainerError>;
@@ -167,6 +202,8 @@ pub trait ContainerService {
         share_publisher: Option<&SharePublisher>,
         ctx: &ExecutionContext,
     ) {
+        self.executor_slots().release(ctx.workspace.id);
+
         match Task::update_status(&self.db().pool, ctx.task.id, TaskStatus::InReview).await {
             Ok(_) => {
                 if let Some(publisher) = share_publisher
@@ -190,14 +227,20 @@ pub trait ContainerService {
         }
 
         let title = format!("Task Complete: {}", ctx.task.title);
-        let message = match ctx.execution_process.status {
-            ExecutionProcessStatus::Completed => format!(
-                "✅ '{}' completed successfully\nBranch: {:?}\nExecutor: {:?}",
-                ctx.task.title, ctx.workspace.branch, ctx.session.executor
+        let (kind, message) = match ctx.execution_process.status {
+            ExecutionProcessStatus::Completed => (
+                NotificationKind::AttemptCompleted,
+                format!(
+                    "✅ '{}' completed successfully\nBranch: {:?}\nExecutor: {:?}",
+                    ctx.task.title, ctx.workspace.branch, ctx.session.executor
+                ),
             ),
-            ExecutionProcessStatus::Failed => format!(
-                "❌ '{}' execution failed\nBranch: {:?}\nExecutor: {:?}",
-                ctx.task.title, ctx.workspace.branch, ctx.session.executor
+            ExecutionProcessStatus::Failed => (
+                NotificationKind::AttemptFailed,
+                format!(
+                    "❌ '{}' execution failed\nBranch: {:?}\nExecutor: {:?}",
+                    ctx.task.title, ctx.workspace.branch, ctx.session.executor
+                ),
             ),
             _ => {
                 tracing::warn!(
@@ -208,11 +251,33 @@ pub trait ContainerService {
             }
         };
         self.notification_service().notify(&title, &message).await;
+
+        // Persist to the inbox so the event isn't lost when no client is connected
+        let inbox_entry = CreateNotification {
+            kind,
+            title,
+            message,
+            project_id: Some(ctx.task.project_id),
+            task_id: Some(ctx.task.id),
+            workspace_id: Some(ctx.workspace.id),
+        };
+        if let Err(e) = Notification::create_for_all_users(&self.db().pool, &inbox_entry).await {
+            tracing::error!("Failed to record completion notification: {}", e);
+        }
+
+        // Comment on the linked issue in the background so a queued task isn't held up
+        let (db, git, ctx) = (self.db().clone(), self.git().clone(), ctx.clone());
+        let providers = IssueProviderRegistry::new(db.pool.clone(), self.vortex_oauth().await);
+        tokio::spawn(async move {
+            issue_comments::post_attempt_comment(&db, &git, &providers, &ctx).await
+        });
     }
 
-    /// Cleanup executions marked as running in the db, call at startup
+    /// Cleanup executions marked as running in the db, call at startup. The
+    /// processes are recorded in the recovery log.
     async fn cleanup_orphan_executions(&self) -> Result<(), ContainerError> {
         let running_processes = ExecutionProcess::find_running(&self.db().pool).await?;
+        let mut interrupted = Vec::new();
         for process in running_processes {
             tracing::info!(
                 "Found orphaned execution process {} for session {}",
@@ -235,8 +300,22 @@ pub trait ContainerService {
                 );
                 continue;
             }
+            let ctx = ExecutionProcess::load_context(&self.db().pool, process.id)
+                .await
+                .ok();
+            if let Some(ctx) = &ctx {
+                interrupted.push(InterruptedProcess {
+                    execution_process_id: process.id,
+                    session_id: process.session_id,
+                    workspace_id: ctx.workspace.id,
+                    task_id: ctx.task.id,
+                    task_title: ctx.task.title.clone(),
+                    run_reason: process.run_reason.clone(),
+                    started_at: process.started_at,
+                });
+            }
             // Capture after-head commit OID per repository
-            if let Ok(ctx) = ExecutionProcess::load_context(&self.db().pool, process.id).await
+            if let Some(ctx) = &ctx
                 && let Some(ref container_ref) = ctx.workspace.container_ref
             {
                 let workspace_root = PathBuf::from(container_ref);
@@ -295,9 +374,16 @@ pub trait ContainerService {
                 }
             }
         }
+        self.recovery_log().record_interrupted(interrupted);
         Ok(())
     }
 
+    /// Reconcile the database against the workspace directories on disk,
+    /// along with the processes interrupted at startup
+    async fn recovery_report(&self) -> Result<RecoveryReport, RecoveryError> {
+        RecoveryReport::collect(&self.db().pool, self.recovery_log().interrupted()).await
+    }
+
     /// Backfill before_head_commit for legacy execution processes.
     /// Rules:
     /// - If a process has after_head_commit and missing before_head_commit,
@@ -401,31 +487,11 @@ pub trait ContainerService {
                             pool,
                             pr.project_id,
                             &UpdateProject {
-                                name: Some(project.name.clone()),
-                                dev_script: project.dev_script.clone(),
-                                dev_script_working_dir: if needs_dev_script_working_dir {
-                                    Some(name.clone())
-                                } else {
-                                    project.dev_script_working_dir.clone()
-                                },
-                                default_agent_working_dir: if needs_default_agent_working_dir {
-                                    Some(name.clone())
-                                } else {
-                                    project.default_agent_working_dir.clone()
-                                },
-                                github_repo_url: None,
-                                github_token: None,
-                                github_sync_enabled: None,
-                                github_sync_labels: None,
-                                gitlab_project_url: None,
-                                gitlab_token: None,
-                                gitlab_sync_enabled: None,
-                                gitlab_sync_labels: None,
-                                vortex_api_url: None,
-                                vortex_project_id: None,
-                                vortex_token: None,
-                                vortex_sync_enabled: None,
-                                vortex_sync_labels: None,
+                                dev_script_working_dir: needs_dev_script_working_dir
+                                    .then(|| Some(name.clone())),
+                                default_agent_working_dir: needs_default_agent_working_dir
+                                    .then(|| Some(name.clone())),
+                                ..Default::default()
                             },
                         )
                         .await?;
@@ -622,6 +688,12 @@ pub trait ContainerService {
 
     async fn git_branch_prefix(&self) -> String;
 
+    /// Whether coding agents are asked to propose follow-up tasks
+    async fn follow_up_task_proposals_enabled(&self) -> bool;
+
+    /// Vortex OAuth client built from the current config
+    async fn vortex_oauth(&self) -> VortexOAuthService;
+
     async fn git_branch_from_workspace(&self, workspace_id: &Uuid, task_title: &str) -> String {
         let task_title_id = git_branch_id(task_title);
         let prefix = self.git_branch_prefix().await;
@@ -891,20 +963,134 @@ pub trait ContainerService {
         })
     }
 
+    /// Create a task for a run of a maintenance job and start an attempt on it
+    /// with the job's profile, across the project's repos on their default
+    /// target branches. A `permit` is held until the attempt finishes.
+    async fn start_maintenance_job(
+        &self,
+        job: &MaintenanceJob,
+        permit: Option<SlotPermit>,
+    ) -> Result<Task, ContainerError> {
+        let pool = &self.db().pool;
+        let project = Project::find_by_id(pool, job.project_id)
+            .await?
+            .ok_or(SqlxError::RowNotFound)?;
+        let project_repos = ProjectRepo::find_by_project_id(pool, project.id).await?;
+        if project_repos.is_empty() {
+            return Err(anyhow!("Project {} has no repositories", project.id).into());
+        }
+
+        let task = Task::create(
+            pool,
+            &CreateTask::from_title_description(
+                project.id,
+                job.name.clone(),
+                Some(job.prompt.clone()),
+            ),
+            Uuid::new_v4(),
+        )
+        .await?;
+        MaintenanceJob::record_run(pool, job.id, Some(task.id)).await?;
+
+        let workspace_id = Uuid::new_v4();
+        let branch = self
+            .git_branch_from_workspace(&workspace_id, &task.title)
+            .await;
+        let agent_working_dir = project
+            .default_agent_working_dir
+            .filter(|dir| !dir.is_empty());
+        let workspace = Workspace::create(
+            pool,
+            &CreateWorkspace {
+                branch,
+                agent_working_dir,
+            },
+            workspace_id,
+            task.id,
+        )
+        .await?;
+
+        let mut workspace_repos = Vec::with_capacity(project_repos.len());
+        for project_repo in project_repos {
+            let target_branch = match project_repo.default_target_branch {
+                Some(branch) => branch,
+                None => Repo::find_by_id(pool, project_repo.repo_id)
+                    .await?
+                    .and_then(|repo| self.git().get_current_branch(&repo.path).ok())
+                    .unwrap_or_else(|| "main".to_string()),
+            };
+            workspace_repos.push(CreateWorkspaceRepo {
+                repo_id: project_repo.repo_id,
+                target_branch,
+            });
+        }
+        WorkspaceRepo::create_many(pool, workspace.id, &workspace_repos).await?;
+
+        if let Some(permit) = permit {
+            self.executor_slots().hold(workspace.id, permit);
+        }
+        self.start_workspace(&workspace, job.executor_profile_id.0.clone())
+            .await
+            .inspect_err(|_| self.executor_slots().release(workspace.id))?;
+        tracing::info!("Started maintenance job {} as task {}", job.id, task.id);
+        Ok(task)
+    }
+
+    /// With the one-attempt-per-task option on, fail if an attempt for
+    /// `task_id` is still running
+    async fn ensure_no_running_attempt(&self, task_id: Uuid) -> Result<(), ContainerError> {
+        if self.task_locks().is_enabled()
+            && ExecutionProcess::has_running_non_dev_server_processes_for_task(
+                &self.db().pool,
+                task_id,
+            )
+            .await?
+        {
+            return Err(ContainerError::AttemptAlreadyRunning);
+        }
+        Ok(())
+    }
+
     async fn start_workspace(
         &self,
         workspace: &Workspace,
         executor_profile_id: ExecutorProfileId,
     ) -> Result<ExecutionProcess, ContainerError> {
-        // Create container
-        self.create(workspace).await?;
-
         // Get parent task
         let task = workspace
             .parent_task(&self.db().pool)
             .await?
             .ok_or(SqlxError::RowNotFound)?;
 
+        // Held until the new attempt's first process is recorded as running
+        let _task_lock = if self.task_locks().is_enabled() {
+            let lock = self
+                .task_locks()
+                .try_lock(task.id)
+                .ok_or(ContainerError::AttemptAlreadyRunning)?;
+            self.ensure_no_running_attempt(task.id).await?;
+            Some(lock)
+        } else {
+            None
+        };
+
+        // Catch checkouts that were moved or deleted before creating worktrees
+        let repo_service = RepoService::new();
+        for repo in WorkspaceRepo::find_repos_for_workspace(&self.db().pool, workspace.id).await? {
+            if let Some(reason) = repo_service
+                .check_health(&self.db().pool, self.git(), &repo)
+                .await?
+            {
+                return Err(ContainerError::RepoUnavailable {
+                    name: repo.display_name,
+                    reason,
+                });
+            }
+        }
+
+        // Create container
+        self.create(workspace).await?;
+
         // Get parent project
         let project = task
             .parent_project(&self.db().pool)
@@ -918,6 +1104,22 @@ pub trait ContainerService {
             .await?
             .ok_or(SqlxError::RowNotFound)?;
 
+        if let Err(e) = workspace_manifest::record(
+            &self.db().pool,
+            self.git(),
+            &workspace,
+            project.id,
+            &executor_profile_id,
+        )
+        .await
+        {
+            tracing::warn!(
+                "Failed to record manifest for workspace {}: {}",
+                workspace.id,
+                e
+            );
+        }
+
         // Create a session for this workspace
         let session = Session::create(
             &self.db().pool,
@@ -929,7 +1131,16 @@ pub trait ContainerService {
         )
         .await?;
 
-        let prompt = task.to_prompt();
+        // Project instruction documents only go into the initial request; follow-ups
+        // continue the same agent session, which has already seen them
+        let mut prompt =
+            match TaskInstruction::resolve(&self.db().pool, task.id, project.id).await? {
+                Some(instruction) => instruction.apply_to_prompt(&task.to_prompt()),
+                None => task.to_prompt(),
+            };
+        if self.follow_up_task_proposals_enabled().await {
+            prompt = follow_up_tasks::apply_to_prompt(&prompt);
+        }
 
         let repos_with_setup: Vec<_> = project_repos
             .iter()
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use db::models::diff_comment::DiffComment;
+use uuid::Uuid;
+
+/// Turn unresolved review comments into a follow-up prompt, or `None` if there are none.
+/// `repo_names` prefixes paths with their repo when the workspace spans several.
+pub fn compile_review_prompt(
+    comments: &[DiffComment],
+    repo_names: &HashMap<Uuid, String>,
+) -> Option<String> {
+    let open: Vec<&DiffComment> = comments.iter().filter(|c| !c.resolved).collect();
+    if open.is_empty() {
+        return None;
+    }
+
+    let mut prompt =
+        String::from("Please address the following review comments on your changes:\n");
+    for comment in open {
+        let path = match repo_names.get(&comment.repo_id) {
+            Some(repo) if repo_names.len() > 1 => format!("{repo}/{}", comment.file_path),
+            _ => comment.file_path.clone(),
+        };
+        let anchor = match comment.line_number {
+            Some(line) => format!("{path}:{line}"),
+            None => format!("{path} (whole file)"),
+        };
+        prompt.push_str(&format!("\n- {anchor}\n"));
+        for line in comment.body.trim().lines() {
+            prompt.push_str(&format!("  {line}\n"));
+        }
+    }
+    Some(prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn comment(repo_id: Uuid, line_number: Option<i64>, body: &str, resolved: bool) -> DiffComment {
+        DiffComment {
+            id: Uuid::new_v4(),
+            workspace_id: Uuid::nil(),
+            repo_id,
+            file_path: "src/lib.rs".to_string(),
+            line_number,
+            body: body.to_string(),
+            author_user_id: None,
+            resolved,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn compiles_open_comments_with_anchors() {
+        let repo = Uuid::new_v4();
+        let names = HashMap::from([(repo, "api".to_string())]);
+        let prompt = compile_review_prompt(
+            &[
+                comment(repo, None, "Split this module up", false),
+                comment(
+                    repo,
+                    Some(42),
+                    "Handle the error\ninstead of unwrapping",
+                    false,
+                ),
+                comment(repo, Some(7), "Already fixed", true),
+            ],
+            &names,
+        )
+        .unwrap();
+
+        assert!(prompt.contains("- src/lib.rs (whole file)\n  Split this module up\n"));
+        assert!(prompt.contains("- src/lib.rs:42\n  Handle the error\n  instead of unwrapping\n"));
+        assert!(!prompt.contains("Already fixed"));
+    }
+
+    #[test]
+    fn prefixes_repo_for_multi_repo_workspaces() {
+        let (api, web) = (Uuid::new_v4(), Uuid::new_v4());
+        let names = HashMap::from([(api, "api".to_string()), (web, "web".to_string())]);
+        let prompt =
+            compile_review_prompt(&[comment(web, Some(3), "Typo", false)], &names).unwrap();
+        assert!(prompt.contains("- web/src/lib.rs:3\n"));
+    }
+
+    #[test]
+    fn nothing_to_compile_when_all_resolved() {
+        let repo = Uuid::new_v4();
+        assert!(
+            compile_review_prompt(&[comment(repo, None, "Done", true)], &HashMap::new()).is_none()
+        );
+    }
+}
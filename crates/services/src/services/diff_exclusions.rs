@@ -0,0 +1,130 @@
+//! Per-project paths left out of attempt diffs, linked-issue reports and PR
+//! descriptions, so reviews focus on the real changes rather than fixtures,
+//! generated files or vendored code.
+
+use std::path::Path;
+
+use db::models::{project_diff_exclusion::ProjectDiffExclusion, task::Task, workspace::Workspace};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use sqlx::SqlitePool;
+use utils::diff::Diff;
+use uuid::Uuid;
+
+use crate::services::path_rules;
+
+/// Compiled exclusion patterns of one project
+#[derive(Clone)]
+pub struct DiffExclusions {
+    patterns: Vec<String>,
+    matcher: Gitignore,
+}
+
+impl Default for DiffExclusions {
+    fn default() -> Self {
+        Self {
+            patterns: Vec::new(),
+            matcher: Gitignore::empty(),
+        }
+    }
+}
+
+impl DiffExclusions {
+    pub fn new(exclusions: &[ProjectDiffExclusion]) -> Self {
+        let mut builder = GitignoreBuilder::new("");
+        let mut patterns = Vec::new();
+        for exclusion in exclusions {
+            // Validate each pattern on its own so one bad pattern doesn't drop the rest
+            if let Err(e) = path_rules::compile_pattern(&exclusion.pattern) {
+                tracing::warn!("Skipping diff exclusion {}: {}", exclusion.id, e);
+                continue;
+            }
+            if builder.add_line(None, &exclusion.pattern).is_ok() {
+                patterns.push(exclusion.pattern.clone());
+            }
+        }
+        match builder.build() {
+            Ok(matcher) => Self { patterns, matcher },
+            Err(e) => {
+                tracing::warn!("Failed to compile diff exclusions: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub async fn for_project(pool: &SqlitePool, project_id: Uuid) -> Result<Self, sqlx::Error> {
+        let exclusions = ProjectDiffExclusion::find_by_project_id(pool, project_id).await?;
+        Ok(Self::new(&exclusions))
+    }
+
+    pub async fn for_workspace(
+        pool: &SqlitePool,
+        workspace: &Workspace,
+    ) -> Result<Self, sqlx::Error> {
+        match Task::find_by_id(pool, workspace.task_id).await? {
+            Some(task) => Self::for_project(pool, task.project_id).await,
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    /// Whether a repo-relative path is excluded
+    pub fn is_excluded(&self, file_path: &str) -> bool {
+        !self.is_empty()
+            && self
+                .matcher
+                .matched_path_or_any_parents(Path::new(file_path), false)
+                .is_ignore()
+    }
+
+    /// Drop diffs of excluded files. A rename stays when either side is kept.
+    pub fn retain(&self, diffs: &mut Vec<Diff>) {
+        if self.is_empty() {
+            return;
+        }
+        diffs.retain(|diff| {
+            [diff.old_path.as_deref(), diff.new_path.as_deref()]
+                .into_iter()
+                .flatten()
+                .any(|path| !self.is_excluded(path))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn exclusions(patterns: &[&str]) -> DiffExclusions {
+        let exclusions: Vec<ProjectDiffExclusion> = patterns
+            .iter()
+            .map(|pattern| ProjectDiffExclusion {
+                id: Uuid::new_v4(),
+                project_id: Uuid::nil(),
+                pattern: pattern.to_string(),
+                created_at: Utc::now(),
+            })
+            .collect();
+        DiffExclusions::new(&exclusions)
+    }
+
+    #[test]
+    fn excludes_directories_and_globs() {
+        let exclusions = exclusions(&["vendor/", "*.snap", "tests/fixtures/**"]);
+
+        assert!(exclusions.is_excluded("vendor/lib/a.js"));
+        assert!(exclusions.is_excluded("src/__snapshots__/app.snap"));
+        assert!(exclusions.is_excluded("tests/fixtures/big.json"));
+        assert!(!exclusions.is_excluded("src/vendor.rs"));
+        assert!(!exclusions.is_excluded("tests/api.rs"));
+        assert!(!DiffExclusions::default().is_excluded("vendor/lib/a.js"));
+    }
+}
@@ -0,0 +1,159 @@
+//! Keeps review of very large attempt diffs responsive. Past the configured
+//! limits the diff view only gets per-file stats, rolled up per directory, and
+//! fetches the contents of individual files on demand.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use ts_rs::TS;
+use utils::diff::{self, Diff};
+
+use crate::services::{config::DiffSizeGuardConfig, git::GitService};
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiffSizeGuard {
+    pub max_files: usize,
+    pub max_bytes: usize,
+}
+
+impl DiffSizeGuard {
+    /// `None` when the guard is turned off
+    pub fn from_config(config: &DiffSizeGuardConfig) -> Option<Self> {
+        config.enabled.then(|| Self {
+            max_files: config.max_files as usize,
+            max_bytes: config.max_bytes as usize,
+        })
+    }
+
+    pub fn is_exceeded(&self, diffs: &[Diff]) -> bool {
+        diffs.len() > self.max_files
+            || diffs.iter().map(content_bytes).sum::<usize>() > self.max_bytes
+    }
+}
+
+/// Size of the old and new contents of a diff
+pub fn content_bytes(diff: &Diff) -> usize {
+    diff.old_content.as_ref().map_or(0, String::len)
+        + diff.new_content.as_ref().map_or(0, String::len)
+}
+
+/// Added and removed lines, from the precomputed stats when contents were omitted
+fn line_counts(diff: &Diff) -> (usize, usize) {
+    match (diff.additions, diff.deletions) {
+        (Some(additions), Some(deletions)) => (additions, deletions),
+        _ => diff::compute_line_change_counts(
+            diff.old_content.as_deref().unwrap_or(""),
+            diff.new_content.as_deref().unwrap_or(""),
+        ),
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, TS)]
+pub struct DirectoryRollup {
+    /// Directory of the changed files, prefixed with the repo name like the
+    /// paths of the diff stream
+    pub path: String,
+    pub files: usize,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, TS)]
+pub struct DiffSizeSummary {
+    /// Whether any repo's diff is past the configured limits, so the diff
+    /// stream only carries stats
+    pub oversized: bool,
+    pub files: usize,
+    pub bytes: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    pub directories: Vec<DirectoryRollup>,
+}
+
+/// Summarize the diffs of each repo of a workspace, keyed by repo name
+pub fn summarize(repos: &[(String, Vec<Diff>)], guard: Option<DiffSizeGuard>) -> DiffSizeSummary {
+    let mut summary = DiffSizeSummary::default();
+    let mut directories: BTreeMap<String, DirectoryRollup> = BTreeMap::new();
+
+    for (repo_name, diffs) in repos {
+        summary.oversized |= guard.is_some_and(|guard| guard.is_exceeded(diffs));
+        for diff in diffs {
+            let (additions, deletions) = line_counts(diff);
+            summary.files += 1;
+            summary.bytes += content_bytes(diff);
+            summary.additions += additions;
+            summary.deletions += deletions;
+
+            let file_path = GitService::diff_path(diff);
+            let path = match file_path.rsplit_once('/') {
+                Some((dir, _)) => format!("{repo_name}/{dir}"),
+                None => repo_name.clone(),
+            };
+            let rollup = directories
+                .entry(path.clone())
+                .or_insert_with(|| DirectoryRollup {
+                    path,
+                    ..Default::default()
+                });
+            rollup.files += 1;
+            rollup.additions += additions;
+            rollup.deletions += deletions;
+        }
+    }
+
+    summary.directories = directories.into_values().collect();
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use utils::diff::DiffChangeKind;
+
+    use super::*;
+
+    fn modified(path: &str, old: &str, new: &str) -> Diff {
+        Diff {
+            change: DiffChangeKind::Modified,
+            old_path: Some(path.to_string()),
+            new_path: Some(path.to_string()),
+            old_content: Some(old.to_string()),
+            new_content: Some(new.to_string()),
+            content_omitted: false,
+            additions: None,
+            deletions: None,
+        }
+    }
+
+    #[test]
+    fn rolls_up_per_directory_and_flags_oversized_repos() {
+        let repos = vec![
+            (
+                "app".to_string(),
+                vec![
+                    modified("src/a.rs", "a\n", "a\nb\n"),
+                    modified("src/b.rs", "x\ny\n", "x\n"),
+                    modified("README.md", "", "hello\n"),
+                ],
+            ),
+            ("lib".to_string(), vec![modified("src/c.rs", "", "c\n")]),
+        ];
+        let guard = DiffSizeGuard {
+            max_files: 2,
+            max_bytes: 1024,
+        };
+
+        let summary = summarize(&repos, Some(guard));
+
+        assert!(summary.oversized);
+        assert_eq!(summary.files, 4);
+        assert_eq!((summary.additions, summary.deletions), (3, 1));
+        let paths: Vec<_> = summary
+            .directories
+            .iter()
+            .map(|d| d.path.as_str())
+            .collect();
+        assert_eq!(paths, vec!["app", "app/src", "lib/src"]);
+        assert_eq!(summary.directories[1].files, 2);
+        assert!(!summarize(&repos, None).oversized);
+    }
+}
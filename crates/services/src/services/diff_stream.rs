@@ -20,6 +20,8 @@ use utils::{
 };
 
 use crate::services::{
+    diff_exclusions::DiffExclusions,
+    diff_size_guard::DiffSizeGuard,
     filesystem_watcher::{self, FilesystemWatcherError},
     git::{Commit, DiffTarget, GitService, GitServiceError},
 };
@@ -88,6 +90,7 @@ struct DiffWatcherContext {
     full_sent: Arc<std::sync::RwLock<HashSet<String>>>,
     stats_only: bool,
     path_prefix: Option<String>,
+    exclusions: Arc<DiffExclusions>,
     tx: mpsc::Sender<Result<LogMsg, io::Error>>,
 }
 
@@ -97,8 +100,11 @@ impl DiffWatcherContext {
         events: Vec<DebouncedEvent>,
         canonical_worktree_path: &Path,
     ) -> bool {
-        let changed_paths =
-            extract_changed_paths(&events, canonical_worktree_path, &self.worktree_path);
+        let changed_paths: Vec<String> =
+            extract_changed_paths(&events, canonical_worktree_path, &self.worktree_path)
+                .into_iter()
+                .filter(|path| !self.exclusions.is_excluded(path))
+                .collect();
 
         if changed_paths.is_empty() {
             return true;
@@ -145,13 +151,18 @@ impl DiffWatcherContext {
     }
 }
 
+/// Stream the worktree diff against `base_commit`. When the initial diff is
+/// past `size_guard`, the stream carries only stats, including later updates.
 pub async fn create(
     git_service: GitService,
     worktree_path: PathBuf,
     base_commit: Commit,
     stats_only: bool,
     path_prefix: Option<String>,
+    exclusions: DiffExclusions,
+    size_guard: Option<DiffSizeGuard>,
 ) -> Result<DiffStreamHandle, DiffStreamError> {
+    let exclusions = Arc::new(exclusions);
     let (tx, rx) = mpsc::channel::<Result<LogMsg, io::Error>>(DIFF_STREAM_CHANNEL_CAPACITY);
 
     let cumulative = Arc::new(AtomicUsize::new(0));
@@ -179,7 +190,7 @@ pub async fn create(
         })
         .await;
 
-        let initial_diffs_raw = match initial_diffs_result {
+        let mut initial_diffs_raw = match initial_diffs_result {
             Ok(Ok(diffs)) => diffs,
             Ok(Err(e)) => {
                 tracing::error!("Failed to get initial diffs: {e}");
@@ -193,6 +204,10 @@ pub async fn create(
             }
         };
 
+        exclusions.retain(&mut initial_diffs_raw);
+        let stats_only =
+            stats_only || size_guard.is_some_and(|guard| guard.is_exceeded(&initial_diffs_raw));
+
         let mut initial_diffs = Vec::with_capacity(initial_diffs_raw.len());
         for mut diff in initial_diffs_raw {
             apply_stream_omit_policy(&mut diff, &cumulative, stats_only);
@@ -245,6 +260,7 @@ pub async fn create(
             full_sent,
             stats_only,
             path_prefix,
+            exclusions,
             tx: tx_clone,
         };
 
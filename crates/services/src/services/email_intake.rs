@@ -0,0 +1,172 @@
+//! Helpers for turning inbound email (delivered by a Mailgun-style routing
+//! webhook) into tasks.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use super::webhooks::signature_matches;
+
+/// Deliveries signed longer ago than this are rejected as possible replays
+pub const MAX_DELIVERY_AGE_SECS: i64 = 5 * 60;
+
+/// Title used when a message arrives without a subject
+pub const UNTITLED_EMAIL_TITLE: &str = "Untitled email";
+
+/// Longest task title taken from a subject line, in characters
+const MAX_TITLE_CHARS: usize = 200;
+
+/// Check a Mailgun webhook signature: the hex HMAC-SHA256 of `timestamp` followed
+/// by `token`, keyed with the account's webhook signing key, and that the
+/// timestamp is recent. `now` is in Unix seconds.
+pub fn mailgun_signature_matches(
+    signing_key: &str,
+    timestamp: &str,
+    token: &str,
+    signature: &str,
+    now: i64,
+) -> bool {
+    if signing_key.trim().is_empty() {
+        return false;
+    }
+    let Ok(sent_at) = timestamp.parse::<i64>() else {
+        return false;
+    };
+    if (now - sent_at).abs() > MAX_DELIVERY_AGE_SECS {
+        return false;
+    }
+    signature_matches(
+        signing_key,
+        signature,
+        format!("{timestamp}{token}").as_bytes(),
+    )
+}
+
+/// Tokens of recently accepted deliveries, so a captured signature can't be
+/// sent again while its timestamp is still recent
+#[derive(Debug, Default)]
+pub struct MailgunTokens {
+    /// Token to the Unix timestamp it was signed with
+    seen: Mutex<HashMap<String, i64>>,
+}
+
+impl MailgunTokens {
+    /// Record the token of a delivery whose signature matched, returning false
+    /// if it was already used
+    pub fn first_use(&self, timestamp: &str, token: &str, now: i64) -> bool {
+        let Ok(sent_at) = timestamp.parse::<i64>() else {
+            return false;
+        };
+        let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        // A token is only worth keeping while its signature could still be accepted
+        seen.retain(|_, sent_at| now - *sent_at <= MAX_DELIVERY_AGE_SECS);
+        seen.insert(token.to_string(), sent_at).is_none()
+    }
+}
+
+/// Lowercased addresses from a `To`-style header, e.g.
+/// `Board <tasks@example.com>, other@example.com`
+pub fn recipient_addresses(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            let address = match (part.rfind('<'), part.rfind('>')) {
+                (Some(start), Some(end)) if start < end => &part[start + 1..end],
+                _ => part,
+            };
+            let address = address.trim();
+            address.contains('@').then(|| address.to_ascii_lowercase())
+        })
+        .collect()
+}
+
+/// Task title for a message: its subject without reply/forward prefixes
+pub fn task_title(subject: Option<&str>) -> String {
+    let mut title = subject.unwrap_or_default().trim();
+    loop {
+        let lower = title.to_ascii_lowercase();
+        let Some(prefix) = ["re:", "fw:", "fwd:"]
+            .into_iter()
+            .find(|prefix| lower.starts_with(prefix))
+        else {
+            break;
+        };
+        title = title[prefix.len()..].trim_start();
+    }
+    if title.is_empty() {
+        return UNTITLED_EMAIL_TITLE.to_string();
+    }
+    title.chars().take(MAX_TITLE_CHARS).collect()
+}
+
+/// Task description for a message, with its sender on the first line
+pub fn task_description(sender: &str, body: Option<&str>) -> String {
+    format!(
+        "Imported from email from {}\n\n{}",
+        sender.trim(),
+        body.unwrap_or_default().trim()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn sign(key: &str, timestamp: &str, token: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes()).unwrap();
+        mac.update(format!("{timestamp}{token}").as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn rejects_stale_or_unkeyed_signatures() {
+        let timestamp = NOW.to_string();
+        let signature = sign("key", &timestamp, "token");
+        assert!(mailgun_signature_matches(
+            "key", &timestamp, "token", &signature, NOW
+        ));
+        assert!(!mailgun_signature_matches(
+            "key",
+            &timestamp,
+            "token",
+            &signature,
+            NOW + MAX_DELIVERY_AGE_SECS + 1
+        ));
+
+        let unkeyed = sign("", &timestamp, "token");
+        assert!(!mailgun_signature_matches(
+            "", &timestamp, "token", &unkeyed, NOW
+        ));
+    }
+
+    #[test]
+    fn rejects_reused_tokens() {
+        let tokens = MailgunTokens::default();
+        let timestamp = NOW.to_string();
+        assert!(tokens.first_use(&timestamp, "token", NOW));
+        assert!(!tokens.first_use(&timestamp, "token", NOW + 60));
+        assert!(tokens.first_use(&timestamp, "other", NOW + 60));
+    }
+
+    #[test]
+    fn parses_recipient_header() {
+        assert_eq!(
+            recipient_addresses("Board <Tasks@Example.com>, other@example.com, undisclosed"),
+            vec![
+                "tasks@example.com".to_string(),
+                "other@example.com".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn strips_reply_prefixes_from_title() {
+        assert_eq!(task_title(Some("Re: FWD: Login broken")), "Login broken");
+        assert_eq!(task_title(Some("  ")), UNTITLED_EMAIL_TITLE);
+        assert_eq!(task_title(None), UNTITLED_EMAIL_TITLE);
+    }
+}
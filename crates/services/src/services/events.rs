@@ -13,6 +13,10 @@ use tokio::sync::RwLock;
 use utils::msg_store::MsgStore;
 use uuid::Uuid;
 
+#[path = "events/batch.rs"]
+pub mod batch;
+#[path = "events/bus.rs"]
+pub mod bus;
 #[path = "events/patches.rs"]
 pub mod patches;
 #[path = "events/streams.rs"]
@@ -20,10 +24,13 @@ mod streams;
 #[path = "events/types.rs"]
 pub mod types;
 
+pub use batch::TaskUpdateBatcher;
+pub use bus::{BusEvent, EventBus, EventBusStatus, EventTopic};
 pub use patches::{
-    execution_process_patch, project_patch, scratch_patch, task_patch, workspace_patch,
+    execution_process_patch, import_patch, project_patch, scratch_patch, task_patch,
+    workspace_patch,
 };
-pub use types::{EventError, EventPatch, EventPatchInner, HookTables, RecordTypes};
+pub use types::{EventError, EventPatch, EventPatchInner, HookTables, ImportProgress, RecordTypes};
 
 #[derive(Clone)]
 pub struct EventService {
@@ -31,47 +38,41 @@ pub struct EventService {
     db: DBService,
     #[allow(dead_code)]
     entry_count: Arc<RwLock<usize>>,
+    task_batcher: TaskUpdateBatcher,
+    bus: EventBus,
 }
 
 impl EventService {
-    /// Creates a new EventService that will work with a DBService configured with hooks
-    pub fn new(db: DBService, msg_store: Arc<MsgStore>, entry_count: Arc<RwLock<usize>>) -> Self {
+    /// Creates a new EventService that will work with a DBService configured with hooks.
+    /// The batcher must be the same one passed to `create_hook`.
+    pub fn new(
+        db: DBService,
+        msg_store: Arc<MsgStore>,
+        entry_count: Arc<RwLock<usize>>,
+        task_batcher: TaskUpdateBatcher,
+    ) -> Self {
+        task_batcher.spawn_flusher(db.pool.clone());
+        let bus = EventBus::new();
+        bus.spawn_feeder(&msg_store);
         Self {
             msg_store,
             db,
             entry_count,
+            task_batcher,
+            bus,
         }
     }
 
-    async fn push_task_update_for_task(
+    async fn queue_task_update_for_session(
         pool: &SqlitePool,
-        msg_store: Arc<MsgStore>,
-        task_id: Uuid,
-    ) -> Result<(), SqlxError> {
-        if let Some(task) = Task::find_by_id(pool, task_id).await? {
-            let tasks = Task::find_by_project_id_with_attempt_status(pool, task.project_id).await?;
-
-            if let Some(task_with_status) = tasks
-                .into_iter()
-                .find(|task_with_status| task_with_status.id == task_id)
-            {
-                msg_store.push_patch(task_patch::replace(&task_with_status));
-            }
-        }
-
-        Ok(())
-    }
-
-    async fn push_task_update_for_session(
-        pool: &SqlitePool,
-        msg_store: Arc<MsgStore>,
+        task_batcher: &TaskUpdateBatcher,
         session_id: Uuid,
     ) -> Result<(), SqlxError> {
         use db::models::session::Session;
         if let Some(session) = Session::find_by_id(pool, session_id).await?
             && let Some(workspace) = Workspace::find_by_id(pool, session.workspace_id).await?
         {
-            Self::push_task_update_for_task(pool, msg_store, workspace.task_id).await?;
+            task_batcher.task_updated(workspace.task_id);
         }
 
         Ok(())
@@ -81,6 +82,7 @@ impl EventService {
     pub fn create_hook(
         msg_store: Arc<MsgStore>,
         entry_count: Arc<RwLock<usize>>,
+        task_batcher: TaskUpdateBatcher,
         db_service: DBService,
     ) -> impl for<'a> Fn(
         &'a mut sqlx::sqlite::SqliteConnection,
@@ -93,11 +95,13 @@ impl EventService {
             let msg_store_for_hook = msg_store.clone();
             let entry_count_for_hook = entry_count.clone();
             let db_for_hook = db_service.clone();
+            let task_batcher = task_batcher.clone();
             Box::pin(async move {
                 let mut handle = conn.lock_handle().await?;
                 let runtime_handle = tokio::runtime::Handle::current();
                 handle.set_preupdate_hook({
                     let msg_store_for_preupdate = msg_store_for_hook.clone();
+                    let task_batcher = task_batcher.clone();
                     move |preupdate: sqlx::sqlite::PreupdateHookResult<'_>| {
                         if preupdate.operation != SqliteOperation::Delete {
                             return;
@@ -107,6 +111,7 @@ impl EventService {
                             "tasks" => {
                                 if let Ok(value) = preupdate.get_old_column_value(0)
                                     && let Ok(task_id) = <Uuid as Decode<Sqlite>>::decode(value)
+                                    && task_batcher.task_removed(task_id)
                                 {
                                     let patch = task_patch::remove(task_id);
                                     msg_store_for_preupdate.push_patch(patch);
@@ -159,6 +164,7 @@ impl EventService {
                     let entry_count_for_hook = entry_count_for_hook.clone();
                     let msg_store_for_hook = msg_store_for_hook.clone();
                     let db = db_for_hook.clone();
+                    let task_batcher = task_batcher.clone();
 
                     if let Ok(table) = HookTables::from_str(hook.table) {
                         let rowid = hook.rowid;
@@ -258,28 +264,12 @@ impl EventService {
                             // Handle task-related operations with direct patches
                             match &record_type {
                                 RecordTypes::Task(task) => {
-                                    // Convert Task to TaskWithAttemptStatus
-                                    if let Ok(task_list) =
-                                        Task::find_by_project_id_with_attempt_status(
-                                            &db.pool,
-                                            task.project_id,
-                                        )
-                                        .await
-                                        && let Some(task_with_status) =
-                                            task_list.into_iter().find(|t| t.id == task.id)
-                                    {
-                                        let patch = match hook.operation {
-                                            SqliteOperation::Insert => {
-                                                task_patch::add(&task_with_status)
-                                            }
-                                            SqliteOperation::Update => {
-                                                task_patch::replace(&task_with_status)
-                                            }
-                                            _ => task_patch::replace(&task_with_status), // fallback
-                                        };
-                                        msg_store_for_hook.push_patch(patch);
-                                        return;
+                                    // Published as TaskWithAttemptStatus by the batcher
+                                    match hook.operation {
+                                        SqliteOperation::Insert => task_batcher.task_added(task.id),
+                                        _ => task_batcher.task_updated(task.id),
                                     }
+                                    return;
                                 }
                                 RecordTypes::DeletedTask {
                                     task_id: Some(task_id),
@@ -318,42 +308,16 @@ impl EventService {
                                 }
                                 RecordTypes::Workspace(workspace) => {
                                     // Workspaces should update the parent task with fresh data
-                                    if let Ok(Some(task)) =
-                                        Task::find_by_id(&db.pool, workspace.task_id).await
-                                        && let Ok(task_list) =
-                                            Task::find_by_project_id_with_attempt_status(
-                                                &db.pool,
-                                                task.project_id,
-                                            )
-                                            .await
-                                        && let Some(task_with_status) =
-                                            task_list.into_iter().find(|t| t.id == workspace.task_id)
-                                    {
-                                        let patch = task_patch::replace(&task_with_status);
-                                        msg_store_for_hook.push_patch(patch);
-                                        return;
-                                    }
+                                    task_batcher.task_updated(workspace.task_id);
+                                    return;
                                 }
                                 RecordTypes::DeletedWorkspace {
                                     task_id: Some(task_id),
                                     ..
                                 } => {
                                     // Workspace deletion should update the parent task with fresh data
-                                    if let Ok(Some(task)) =
-                                        Task::find_by_id(&db.pool, *task_id).await
-                                        && let Ok(task_list) =
-                                            Task::find_by_project_id_with_attempt_status(
-                                                &db.pool,
-                                                task.project_id,
-                                            )
-                                            .await
-                                        && let Some(task_with_status) =
-                                            task_list.into_iter().find(|t| t.id == *task_id)
-                                    {
-                                        let patch = task_patch::replace(&task_with_status);
-                                        msg_store_for_hook.push_patch(patch);
-                                        return;
-                                    }
+                                    task_batcher.task_updated(*task_id);
+                                    return;
                                 }
                                 RecordTypes::ExecutionProcess(process) => {
                                     let patch = match hook.operation {
@@ -367,9 +331,9 @@ impl EventService {
                                     };
                                     msg_store_for_hook.push_patch(patch);
 
-                                    if let Err(err) = EventService::push_task_update_for_session(
+                                    if let Err(err) = EventService::queue_task_update_for_session(
                                         &db.pool,
-                                        msg_store_for_hook.clone(),
+                                        &task_batcher,
                                         process.session_id,
                                     )
                                     .await
@@ -392,9 +356,9 @@ impl EventService {
 
                                     if let Some(session_id) = session_id
                                         && let Err(err) =
-                                            EventService::push_task_update_for_session(
+                                            EventService::queue_task_update_for_session(
                                                 &db.pool,
-                                                msg_store_for_hook.clone(),
+                                                &task_batcher,
                                                 *session_id,
                                             )
                                             .await
@@ -445,4 +409,13 @@ impl EventService {
     pub fn msg_store(&self) -> &Arc<MsgStore> {
         &self.msg_store
     }
+
+    pub fn task_batcher(&self) -> &TaskUpdateBatcher {
+        &self.task_batcher
+    }
+
+    /// Offset-addressed feed of every patch published here; see [`bus`]
+    pub fn bus(&self) -> &EventBus {
+        &self.bus
+    }
 }
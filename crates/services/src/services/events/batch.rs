@@ -0,0 +1,152 @@
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use db::models::task::Task;
+use sqlx::SqlitePool;
+use tokio::sync::Notify;
+use utils::msg_store::MsgStore;
+use uuid::Uuid;
+
+use super::patches::task_patch;
+
+/// How long task changes are collected before a coalesced patch is published
+const TASK_UPDATE_WINDOW: Duration = Duration::from_millis(75);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingTaskChange {
+    Added,
+    Updated,
+}
+
+/// Collects task refreshes triggered by DB hooks and publishes one patch per task per
+/// window, so bursts of writes (e.g. an issue sync touching hundreds of rows) don't
+/// fan out into a storm of individual WS messages.
+#[derive(Clone)]
+pub struct TaskUpdateBatcher {
+    msg_store: Arc<MsgStore>,
+    pending: Arc<Mutex<HashMap<Uuid, PendingTaskChange>>>,
+    notify: Arc<Notify>,
+}
+
+impl TaskUpdateBatcher {
+    pub fn new(msg_store: Arc<MsgStore>) -> Self {
+        Self {
+            msg_store,
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Queue a freshly inserted task. An insert always wins over a pending update.
+    pub fn task_added(&self, task_id: Uuid) {
+        self.pending
+            .lock()
+            .unwrap()
+            .insert(task_id, PendingTaskChange::Added);
+        self.notify.notify_one();
+    }
+
+    /// Queue a refresh of a task whose row or attempt state changed
+    pub fn task_updated(&self, task_id: Uuid) {
+        self.pending
+            .lock()
+            .unwrap()
+            .entry(task_id)
+            .or_insert(PendingTaskChange::Updated);
+        self.notify.notify_one();
+    }
+
+    /// Drop any pending change for a deleted task. Returns `false` when the task was
+    /// added within the current window, i.e. clients never saw it and need no removal.
+    pub fn task_removed(&self, task_id: Uuid) -> bool {
+        match self.pending.lock().unwrap().entry(task_id) {
+            Entry::Occupied(entry) => entry.remove() != PendingTaskChange::Added,
+            Entry::Vacant(_) => true,
+        }
+    }
+
+    /// Publish all pending task changes immediately
+    pub async fn flush(&self, pool: &SqlitePool) {
+        let pending = std::mem::take(&mut *self.pending.lock().unwrap());
+
+        for (task_id, change) in pending {
+            let task_with_status = match Task::find_by_id(pool, task_id).await {
+                Ok(Some(task)) => {
+                    Task::find_by_id_with_attempt_status(pool, task.project_id, task_id).await
+                }
+                Ok(None) => continue,
+                Err(e) => Err(e),
+            };
+
+            match task_with_status {
+                Ok(Some(task)) => {
+                    let patch = match change {
+                        PendingTaskChange::Added => task_patch::add(&task),
+                        PendingTaskChange::Updated => task_patch::replace(&task),
+                    };
+                    self.msg_store.push_patch(patch);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to load task {} for batched update: {:?}",
+                        task_id,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    /// Spawn the background loop that flushes pending changes once per window
+    pub fn spawn_flusher(&self, pool: SqlitePool) {
+        let batcher = self.clone();
+        tokio::spawn(async move {
+            loop {
+                batcher.notify.notified().await;
+                tokio::time::sleep(TASK_UPDATE_WINDOW).await;
+                batcher.flush(&pool).await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending(batcher: &TaskUpdateBatcher, task_id: Uuid) -> Option<PendingTaskChange> {
+        batcher.pending.lock().unwrap().get(&task_id).copied()
+    }
+
+    #[test]
+    fn updates_coalesce_into_pending_add() {
+        let batcher = TaskUpdateBatcher::new(Arc::new(MsgStore::new()));
+        let task_id = Uuid::new_v4();
+
+        batcher.task_added(task_id);
+        batcher.task_updated(task_id);
+        batcher.task_updated(task_id);
+
+        assert_eq!(batcher.pending.lock().unwrap().len(), 1);
+        assert_eq!(pending(&batcher, task_id), Some(PendingTaskChange::Added));
+    }
+
+    #[test]
+    fn removing_unpublished_task_skips_remove_patch() {
+        let batcher = TaskUpdateBatcher::new(Arc::new(MsgStore::new()));
+        let added = Uuid::new_v4();
+        let updated = Uuid::new_v4();
+
+        batcher.task_added(added);
+        batcher.task_updated(updated);
+
+        assert!(!batcher.task_removed(added));
+        assert!(batcher.task_removed(updated));
+        assert!(batcher.task_removed(Uuid::new_v4()));
+        assert!(batcher.pending.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,287 @@
+//! Event bus over the patches published on the events store.
+//!
+//! Every JSON patch gets a monotonically increasing offset and a topic, and the
+//! most recent [`BUFFER_CAPACITY`] events are kept in a ring buffer. Readers pull
+//! from an offset rather than a live broadcast, so a slow reader catches up from
+//! the buffer instead of dropping events, and an SSE client reconnecting with
+//! `Last-Event-ID` resumes where it stopped.
+//!
+//! Named consumers commit the offset of each event once handled. A consumer that
+//! restarts resumes after its last committed offset, so delivery is at least
+//! once: anything read but not yet committed is delivered again. Offsets and the
+//! buffer live in memory and start over when the server restarts.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use chrono::{DateTime, Utc};
+use futures::{StreamExt, stream::BoxStream};
+use json_patch::Patch;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use tokio::sync::{broadcast::error::RecvError, watch};
+use ts_rs::TS;
+use utils::{log_msg::LogMsg, msg_store::MsgStore};
+
+/// Events kept for replay
+pub const BUFFER_CAPACITY: usize = 10_000;
+
+/// Collection an event's patch targets, taken from the first segment of its path
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS, EnumString, Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EventTopic {
+    Tasks,
+    Projects,
+    Workspaces,
+    ExecutionProcesses,
+    Scratch,
+    Imports,
+    /// Legacy `/entries` patches for records without a dedicated collection
+    Records,
+}
+
+impl EventTopic {
+    pub fn of(patch: &Patch) -> Self {
+        let path = patch.0.first().map(|op| op.path().as_str());
+        match path.and_then(|path| path.split('/').nth(1)) {
+            Some("tasks") => Self::Tasks,
+            Some("projects") => Self::Projects,
+            Some("workspaces") => Self::Workspaces,
+            Some("execution_processes") => Self::ExecutionProcesses,
+            Some("scratch") => Self::Scratch,
+            Some("imports") => Self::Imports,
+            _ => Self::Records,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BusEvent {
+    /// Position on the bus, starting at 1
+    pub offset: u64,
+    pub topic: EventTopic,
+    pub patch: Patch,
+    pub published_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ConsumerOffset {
+    pub consumer: String,
+    #[ts(type = "number")]
+    pub offset: u64,
+    /// Events published after the committed offset
+    #[ts(type = "number")]
+    pub lag: u64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct EventBusStatus {
+    /// Oldest offset still in the buffer; zero when the buffer is empty
+    #[ts(type = "number")]
+    pub first_offset: u64,
+    #[ts(type = "number")]
+    pub last_offset: u64,
+    pub consumers: Vec<ConsumerOffset>,
+}
+
+#[derive(Default)]
+struct BusInner {
+    events: VecDeque<BusEvent>,
+    committed: HashMap<String, u64>,
+}
+
+#[derive(Clone)]
+pub struct EventBus {
+    inner: Arc<Mutex<BusInner>>,
+    head: Arc<watch::Sender<u64>>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(BusInner::default())),
+            head: Arc::new(watch::channel(0).0),
+        }
+    }
+
+    /// Append a patch to the bus and return its offset
+    pub fn publish(&self, patch: Patch) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        let offset = *self.head.borrow() + 1;
+        if inner.events.len() == BUFFER_CAPACITY {
+            inner.events.pop_front();
+        }
+        inner.events.push_back(BusEvent {
+            offset,
+            topic: EventTopic::of(&patch),
+            patch,
+            published_at: Utc::now(),
+        });
+        self.head.send_replace(offset);
+        offset
+    }
+
+    /// Offset of the most recently published event
+    pub fn head(&self) -> u64 {
+        *self.head.borrow()
+    }
+
+    /// Buffered events published after `offset`
+    pub fn read_after(&self, offset: u64) -> Vec<BusEvent> {
+        let inner = self.inner.lock().unwrap();
+        if let Some(first) = inner.events.front()
+            && first.offset > offset + 1
+        {
+            tracing::warn!(
+                "Events {}..{} were evicted from the bus before being read",
+                offset + 1,
+                first.offset
+            );
+        }
+        inner
+            .events
+            .iter()
+            .skip_while(|event| event.offset <= offset)
+            .cloned()
+            .collect()
+    }
+
+    /// Events published after `offset`: buffered ones first, then live ones as
+    /// they arrive
+    pub fn subscribe(&self, offset: u64) -> BoxStream<'static, BusEvent> {
+        let state = (
+            self.clone(),
+            self.head.subscribe(),
+            offset,
+            VecDeque::<BusEvent>::new(),
+        );
+        futures::stream::unfold(
+            state,
+            |(bus, mut head, mut offset, mut pending)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        offset = event.offset;
+                        return Some((event, (bus, head, offset, pending)));
+                    }
+                    pending = bus.read_after(offset).into();
+                    if pending.is_empty() {
+                        head.wait_for(|&head| head > offset).await.ok()?;
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+
+    /// Events for a named consumer, resuming after its last committed offset. A
+    /// consumer that has never committed starts with events published from now on.
+    pub fn consume(&self, consumer: &str) -> BoxStream<'static, BusEvent> {
+        let offset = self.committed(consumer).unwrap_or_else(|| self.head());
+        self.subscribe(offset)
+    }
+
+    pub fn committed(&self, consumer: &str) -> Option<u64> {
+        self.inner.lock().unwrap().committed.get(consumer).copied()
+    }
+
+    /// Record that `consumer` has handled every event up to `offset`. Offsets only
+    /// move forward.
+    pub fn commit(&self, consumer: &str, offset: u64) {
+        let mut inner = self.inner.lock().unwrap();
+        let committed = inner.committed.entry(consumer.to_string()).or_default();
+        *committed = (*committed).max(offset);
+    }
+
+    pub fn status(&self) -> EventBusStatus {
+        let last_offset = self.head();
+        let inner = self.inner.lock().unwrap();
+        let mut consumers: Vec<ConsumerOffset> = inner
+            .committed
+            .iter()
+            .map(|(consumer, &offset)| ConsumerOffset {
+                consumer: consumer.clone(),
+                offset,
+                lag: last_offset.saturating_sub(offset),
+            })
+            .collect();
+        consumers.sort_by(|a, b| a.consumer.cmp(&b.consumer));
+        EventBusStatus {
+            first_offset: inner.events.front().map_or(0, |event| event.offset),
+            last_offset,
+            consumers,
+        }
+    }
+
+    /// Publish every patch pushed to the events store onto the bus
+    pub fn spawn_feeder(&self, msg_store: &MsgStore) {
+        let bus = self.clone();
+        let mut receiver = msg_store.get_receiver();
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(LogMsg::JsonPatch(patch)) => {
+                        bus.publish(patch);
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Event bus feeder skipped {} events", skipped);
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(path: &str) -> Patch {
+        serde_json::from_value(serde_json::json!([
+            { "op": "add", "path": path, "value": {} }
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn topics_follow_patch_paths() {
+        assert_eq!(EventTopic::of(&patch("/tasks/abc")), EventTopic::Tasks);
+        assert_eq!(
+            EventTopic::of(&patch("/execution_processes/abc")),
+            EventTopic::ExecutionProcesses
+        );
+        assert_eq!(EventTopic::of(&patch("/scratch")), EventTopic::Scratch);
+        assert_eq!(EventTopic::of(&patch("/entries/3")), EventTopic::Records);
+    }
+
+    #[test]
+    fn consumers_resume_after_committed_offset() {
+        let bus = EventBus::new();
+        bus.publish(patch("/tasks/a"));
+        assert_eq!(bus.committed("hooks"), None);
+
+        bus.publish(patch("/tasks/b"));
+        bus.publish(patch("/projects/c"));
+        bus.commit("hooks", 2);
+        bus.commit("hooks", 1);
+
+        let pending: Vec<u64> = bus.read_after(2).iter().map(|e| e.offset).collect();
+        assert_eq!(pending, vec![3]);
+        let status = bus.status();
+        assert_eq!((status.first_offset, status.last_offset), (1, 3));
+        assert_eq!(status.consumers[0].offset, 2);
+        assert_eq!(status.consumers[0].lag, 1);
+    }
+}
@@ -5,6 +5,8 @@ use db::models::{
 use json_patch::{AddOperation, Patch, PatchOperation, RemoveOperation, ReplaceOperation};
 use uuid::Uuid;
 
+use super::types::ImportProgress;
+
 // Shared helper to escape JSON Pointer segments
 fn escape_pointer_segment(s: &str) -> String {
     s.replace('~', "~0").replace('/', "~1")
@@ -217,3 +219,37 @@ pub mod scratch_patch {
         })])
     }
 }
+
+/// Helper functions for creating bulk import progress patches
+pub mod import_patch {
+    use super::*;
+
+    fn import_path(import_id: Uuid) -> String {
+        format!(
+            "/imports/{}",
+            escape_pointer_segment(&import_id.to_string())
+        )
+    }
+
+    /// Create patch announcing a new import
+    pub fn add(progress: &ImportProgress) -> Patch {
+        Patch(vec![PatchOperation::Add(AddOperation {
+            path: import_path(progress.import_id)
+                .try_into()
+                .expect("Import path should be valid"),
+            value: serde_json::to_value(progress)
+                .expect("Import progress serialization should not fail"),
+        })])
+    }
+
+    /// Create patch for updating an import's progress
+    pub fn replace(progress: &ImportProgress) -> Patch {
+        Patch(vec![PatchOperation::Replace(ReplaceOperation {
+            path: import_path(progress.import_id)
+                .try_into()
+                .expect("Import path should be valid"),
+            value: serde_json::to_value(progress)
+                .expect("Import progress serialization should not fail"),
+        })])
+    }
+}
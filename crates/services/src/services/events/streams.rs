@@ -4,6 +4,7 @@ use db::models::{
     scratch::Scratch,
     session::Session,
     task::{Task, TaskWithAttemptStatus},
+    task_draft::TaskDraft,
 };
 use futures::StreamExt;
 use serde_json::json;
@@ -24,12 +25,18 @@ impl EventService {
         project_id: Uuid,
     ) -> Result<futures::stream::BoxStream<'static, Result<LogMsg, std::io::Error>>, EventError>
     {
-        // Get initial snapshot of tasks
+        // Publish anything still waiting in the batch window so the new subscriber
+        // doesn't receive stale task patches after its snapshot
+        self.task_batcher.flush(&self.db.pool).await;
+
+        // Get initial snapshot of tasks, leaving out drafts awaiting triage
         let tasks = Task::find_by_project_id_with_attempt_status(&self.db.pool, project_id).await?;
+        let drafts = TaskDraft::task_ids_for_project(&self.db.pool, project_id).await?;
 
         // Convert task array to object keyed by task ID
         let tasks_map: serde_json::Map<String, serde_json::Value> = tasks
             .into_iter()
+            .filter(|task| !drafts.contains(&task.id))
             .map(|task| (task.id.to_string(), serde_json::to_value(task).unwrap()))
             .collect();
 
@@ -64,6 +71,9 @@ impl EventService {
                                                     op.value.clone(),
                                                 )
                                                 && task.project_id == project_id
+                                                && !TaskDraft::exists(&db_pool, task.id)
+                                                    .await
+                                                    .unwrap_or(false)
                                             {
                                                 return Some(Ok(LogMsg::JsonPatch(patch)));
                                             }
@@ -75,6 +85,9 @@ impl EventService {
                                                     op.value.clone(),
                                                 )
                                                 && task.project_id == project_id
+                                                && !TaskDraft::exists(&db_pool, task.id)
+                                                    .await
+                                                    .unwrap_or(false)
                                             {
                                                 return Some(Ok(LogMsg::JsonPatch(patch)));
                                             }
@@ -79,3 +79,19 @@ pub struct EventPatch {
     pub(crate) path: String,
     pub(crate) value: EventPatchInner,
 }
+
+/// Progress of a bulk issue import, published at `/imports/{import_id}` on the
+/// events stream while the import runs in the background
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ImportProgress {
+    pub import_id: Uuid,
+    pub project_id: Uuid,
+    /// Issues selected for import; zero until the provider has been queried
+    pub total: usize,
+    pub imported: usize,
+    /// Issues that did not match the filters or already have a task
+    pub skipped: usize,
+    pub failed: usize,
+    pub errors: Vec<String>,
+    pub done: bool,
+}
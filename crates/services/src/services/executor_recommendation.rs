@@ -0,0 +1,169 @@
+//! Which executor profile has worked best on a project, judged from how its
+//! past attempts went, for picking the profile of automatically started
+//! attempts.
+
+use std::collections::HashMap;
+
+use db::models::execution_process::AgentAttemptOutcome;
+use executors::profile::ExecutorProfileId;
+use serde::Serialize;
+use ts_rs::TS;
+
+/// Attempts a profile needs before it can be recommended
+pub const MIN_ATTEMPTS: usize = 3;
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ProfileStats {
+    pub executor_profile_id: ExecutorProfileId,
+    pub attempts: usize,
+    /// Attempts that were merged or whose task was completed
+    pub successful_attempts: usize,
+    pub success_rate: f64,
+    /// Follow-ups sent per attempt after the initial run
+    pub avg_follow_ups: f64,
+    /// Share of agent runs that failed
+    pub failed_run_rate: f64,
+    pub avg_agent_minutes: f64,
+    /// Success rate smoothed towards 50% for profiles with few attempts
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ExecutorRecommendation {
+    /// None until some profile has at least [`MIN_ATTEMPTS`] attempts
+    pub recommended: Option<ExecutorProfileId>,
+    pub reason: String,
+    pub attempts_considered: usize,
+    /// Best first
+    pub profiles: Vec<ProfileStats>,
+}
+
+pub fn recommend(outcomes: &[AgentAttemptOutcome]) -> ExecutorRecommendation {
+    let mut by_profile: HashMap<&ExecutorProfileId, Vec<&AgentAttemptOutcome>> = HashMap::new();
+    for outcome in outcomes {
+        by_profile
+            .entry(&outcome.executor_profile_id)
+            .or_default()
+            .push(outcome);
+    }
+
+    let mut profiles: Vec<ProfileStats> = by_profile
+        .into_iter()
+        .map(|(profile, attempts)| profile_stats(profile, &attempts))
+        .collect();
+    profiles.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.avg_follow_ups.total_cmp(&b.avg_follow_ups))
+            .then(a.avg_agent_minutes.total_cmp(&b.avg_agent_minutes))
+            .then_with(|| {
+                a.executor_profile_id
+                    .to_string()
+                    .cmp(&b.executor_profile_id.to_string())
+            })
+    });
+
+    let best = profiles
+        .iter()
+        .find(|profile| profile.attempts >= MIN_ATTEMPTS);
+    let reason = match best {
+        Some(best) => format!(
+            "{} succeeded in {} of {} attempts ({:.0}%), the best of {} profile(s) used on this project",
+            best.executor_profile_id,
+            best.successful_attempts,
+            best.attempts,
+            best.success_rate * 100.0,
+            profiles.len()
+        ),
+        None => format!(
+            "Not enough history: no profile has {MIN_ATTEMPTS} attempts on this project yet"
+        ),
+    };
+
+    ExecutorRecommendation {
+        recommended: best.map(|profile| profile.executor_profile_id.clone()),
+        reason,
+        attempts_considered: outcomes.len(),
+        profiles,
+    }
+}
+
+fn profile_stats(profile: &ExecutorProfileId, attempts: &[&AgentAttemptOutcome]) -> ProfileStats {
+    let count = attempts.len();
+    let successful_attempts = attempts
+        .iter()
+        .filter(|attempt| attempt.merged || attempt.task_done)
+        .count();
+    let runs: i64 = attempts.iter().map(|attempt| attempt.agent_runs).sum();
+    let failed_runs: i64 = attempts.iter().map(|attempt| attempt.failed_runs).sum();
+    let minutes: f64 = attempts.iter().map(|attempt| attempt.agent_minutes).sum();
+
+    ProfileStats {
+        executor_profile_id: profile.clone(),
+        attempts: count,
+        successful_attempts,
+        success_rate: successful_attempts as f64 / count as f64,
+        avg_follow_ups: (runs - count as i64).max(0) as f64 / count as f64,
+        failed_run_rate: if runs > 0 {
+            failed_runs as f64 / runs as f64
+        } else {
+            0.0
+        },
+        avg_agent_minutes: minutes / count as f64,
+        score: (successful_attempts as f64 + 1.0) / (count as f64 + 2.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use executors::executors::BaseCodingAgent;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn outcome(profile: &ExecutorProfileId, merged: bool, agent_runs: i64) -> AgentAttemptOutcome {
+        AgentAttemptOutcome {
+            workspace_id: Uuid::new_v4(),
+            executor_profile_id: profile.clone(),
+            agent_runs,
+            failed_runs: 0,
+            agent_minutes: 10.0,
+            merged,
+            task_done: false,
+        }
+    }
+
+    #[test]
+    fn recommends_the_most_successful_profile_with_enough_history() {
+        let claude = ExecutorProfileId::new(BaseCodingAgent::ClaudeCode);
+        let plan = ExecutorProfileId::with_variant(BaseCodingAgent::ClaudeCode, "PLAN".into());
+        let codex = ExecutorProfileId::new(BaseCodingAgent::Codex);
+        let outcomes = vec![
+            outcome(&claude, true, 1),
+            outcome(&claude, true, 2),
+            outcome(&claude, false, 3),
+            outcome(&codex, true, 1),
+            outcome(&codex, false, 1),
+            outcome(&codex, false, 1),
+            // Perfect, but too few attempts to trust
+            outcome(&plan, true, 1),
+            outcome(&plan, true, 1),
+        ];
+
+        let recommendation = recommend(&outcomes);
+        assert_eq!(recommendation.recommended, Some(claude.clone()));
+        assert_eq!(recommendation.attempts_considered, 8);
+        assert_eq!(recommendation.profiles[0].executor_profile_id, plan);
+        let stats = &recommendation.profiles[1];
+        assert_eq!(stats.executor_profile_id, claude);
+        assert_eq!(stats.successful_attempts, 2);
+        assert_eq!(stats.avg_follow_ups, 1.0);
+    }
+
+    #[test]
+    fn no_recommendation_without_history() {
+        let recommendation = recommend(&[]);
+        assert_eq!(recommendation.recommended, None);
+        assert!(recommendation.profiles.is_empty());
+    }
+}
@@ -0,0 +1,283 @@
+//! Limits how many automatically started attempts (status changes, queue
+//! progression, maintenance jobs) run at once on this machine, and shares the
+//! slots across projects instead of first come, first served.
+//!
+//! Slots are handed out by stride scheduling: each grant advances a project's
+//! pass by `1 / weight`, and a freed slot goes to the waiting project with the
+//! lowest pass. A project with weight 2 gets twice the slots of one with weight
+//! 1 while both are waiting, and a project that starts waiting joins at the
+//! current pass rather than with credit for the time it was idle.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use serde::Serialize;
+use tokio::sync::oneshot;
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Default)]
+struct ProjectSlots {
+    weight: u32,
+    pass: f64,
+    running: usize,
+    waiting: VecDeque<oneshot::Sender<SlotPermit>>,
+}
+
+#[derive(Default)]
+struct SlotsState {
+    /// Zero means no limit
+    capacity: usize,
+    running: usize,
+    /// Pass of the most recent grant
+    virtual_time: f64,
+    projects: HashMap<Uuid, ProjectSlots>,
+}
+
+impl SlotsState {
+    fn has_free_slot(&self) -> bool {
+        self.capacity == 0 || self.running < self.capacity
+    }
+
+    fn anyone_waiting(&self) -> bool {
+        self.projects.values().any(|p| !p.waiting.is_empty())
+    }
+
+    /// The entry for `project_id`, with an idle project's pass brought up to
+    /// the current one
+    fn join(&mut self, project_id: Uuid, weight: u32) -> &mut ProjectSlots {
+        let virtual_time = self.virtual_time;
+        let project = self.projects.entry(project_id).or_default();
+        project.weight = weight.max(1);
+        if project.running == 0 && project.waiting.is_empty() {
+            project.pass = project.pass.max(virtual_time);
+        }
+        project
+    }
+
+    fn take_slot(&mut self, project_id: Uuid) {
+        let Some(project) = self.projects.get_mut(&project_id) else {
+            return;
+        };
+        project.running += 1;
+        self.virtual_time = self.virtual_time.max(project.pass);
+        project.pass += 1.0 / project.weight as f64;
+        self.running += 1;
+    }
+
+    fn return_slot(&mut self, project_id: Uuid) {
+        self.running = self.running.saturating_sub(1);
+        if let Some(project) = self.projects.get_mut(&project_id) {
+            project.running = project.running.saturating_sub(1);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ProjectSlotUsage {
+    pub project_id: Uuid,
+    pub weight: u32,
+    pub running: usize,
+    pub waiting: usize,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ExecutorSlotsStatus {
+    /// Zero means no limit
+    pub capacity: usize,
+    pub running: usize,
+    pub projects: Vec<ProjectSlotUsage>,
+}
+
+#[derive(Clone, Default)]
+pub struct ExecutorSlots {
+    state: Arc<Mutex<SlotsState>>,
+    /// Permits held by running attempts, by workspace
+    held: Arc<Mutex<HashMap<Uuid, SlotPermit>>>,
+}
+
+/// A slot, given back when dropped
+pub struct SlotPermit {
+    /// `None` once the slot has been given back some other way
+    slots: Option<ExecutorSlots>,
+    project_id: Uuid,
+}
+
+impl Drop for SlotPermit {
+    fn drop(&mut self) {
+        if let Some(slots) = self.slots.take() {
+            let mut state = slots.state.lock().unwrap();
+            state.return_slot(self.project_id);
+            slots.dispatch(&mut state);
+        }
+    }
+}
+
+impl ExecutorSlots {
+    pub fn new(capacity: usize) -> Self {
+        let slots = Self::default();
+        slots.set_capacity(capacity);
+        slots
+    }
+
+    /// Change the number of slots. Waiting projects get any slots this frees
+    /// up; attempts already running keep theirs.
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.capacity = capacity;
+        self.dispatch(&mut state);
+    }
+
+    /// A slot for `project_id` if one is free and no other project is waiting
+    pub fn try_acquire(&self, project_id: Uuid, weight: u32) -> Option<SlotPermit> {
+        let mut state = self.state.lock().unwrap();
+        if state.anyone_waiting() || !state.has_free_slot() {
+            return None;
+        }
+        state.join(project_id, weight);
+        state.take_slot(project_id);
+        Some(self.permit(project_id))
+    }
+
+    /// Wait for a slot for `project_id`, taking its turn among the projects
+    /// already waiting
+    pub async fn acquire(&self, project_id: Uuid, weight: u32) -> SlotPermit {
+        if let Some(permit) = self.try_acquire(project_id, weight) {
+            return permit;
+        }
+        match self.enqueue(project_id, weight).await {
+            Ok(permit) => permit,
+            // Senders are only dropped along with the slots themselves
+            Err(_) => std::future::pending().await,
+        }
+    }
+
+    fn enqueue(&self, project_id: Uuid, weight: u32) -> oneshot::Receiver<SlotPermit> {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.state.lock().unwrap();
+        state.join(project_id, weight).waiting.push_back(sender);
+        self.dispatch(&mut state);
+        receiver
+    }
+
+    /// Keep `permit` until the attempt on `workspace_id` finishes
+    pub fn hold(&self, workspace_id: Uuid, permit: SlotPermit) {
+        self.held.lock().unwrap().insert(workspace_id, permit);
+    }
+
+    /// Give back the slot held by the attempt on `workspace_id`, if any
+    pub fn release(&self, workspace_id: Uuid) {
+        let permit = self.held.lock().unwrap().remove(&workspace_id);
+        drop(permit);
+    }
+
+    pub fn status(&self) -> ExecutorSlotsStatus {
+        let state = self.state.lock().unwrap();
+        let mut projects: Vec<ProjectSlotUsage> = state
+            .projects
+            .iter()
+            .filter(|(_, p)| p.running > 0 || !p.waiting.is_empty())
+            .map(|(project_id, p)| ProjectSlotUsage {
+                project_id: *project_id,
+                weight: p.weight,
+                running: p.running,
+                waiting: p.waiting.len(),
+            })
+            .collect();
+        projects.sort_by_key(|p| p.project_id);
+        ExecutorSlotsStatus {
+            capacity: state.capacity,
+            running: state.running,
+            projects,
+        }
+    }
+
+    fn permit(&self, project_id: Uuid) -> SlotPermit {
+        SlotPermit {
+            slots: Some(self.clone()),
+            project_id,
+        }
+    }
+
+    /// Hand free slots to waiting projects, lowest pass first
+    fn dispatch(&self, state: &mut SlotsState) {
+        while state.has_free_slot() {
+            let waiting = state
+                .projects
+                .iter()
+                .filter(|(_, p)| !p.waiting.is_empty())
+                .map(|(project_id, p)| (*project_id, p.pass));
+            let Some(project_id) = next_project(waiting) else {
+                break;
+            };
+            let Some(sender) = state
+                .projects
+                .get_mut(&project_id)
+                .and_then(|p| p.waiting.pop_front())
+            else {
+                break;
+            };
+            if sender.is_closed() {
+                continue;
+            }
+            state.take_slot(project_id);
+            if let Err(mut permit) = sender.send(self.permit(project_id)) {
+                // The waiter just gave up. Dropping the permit normally would
+                // re-enter the lock, so give the slot back by hand.
+                permit.slots = None;
+                state.return_slot(project_id);
+            }
+        }
+
+        // An idle project at or behind the current pass is the same as a new one
+        let virtual_time = state.virtual_time;
+        state
+            .projects
+            .retain(|_, p| p.running > 0 || !p.waiting.is_empty() || p.pass > virtual_time);
+    }
+}
+
+/// The waiting project with the lowest pass, ties going to the lowest id
+pub fn next_project(waiting: impl Iterator<Item = (Uuid, f64)>) -> Option<Uuid> {
+    waiting
+        .min_by(|(a_id, a_pass), (b_id, b_pass)| a_pass.total_cmp(b_pass).then(a_id.cmp(b_id)))
+        .map(|(project_id, _)| project_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_shared_by_weight() {
+        let slots = ExecutorSlots::new(1);
+        let heavy = Uuid::from_u128(1);
+        let light = Uuid::from_u128(2);
+
+        let mut permit = slots.try_acquire(light, 1).unwrap();
+        assert!(slots.try_acquire(heavy, 2).is_none());
+        let mut heavy_waiting: VecDeque<_> = (0..4).map(|_| slots.enqueue(heavy, 2)).collect();
+        let mut light_waiting: VecDeque<_> = (0..4).map(|_| slots.enqueue(light, 1)).collect();
+
+        let mut order = Vec::new();
+        for _ in 0..6 {
+            drop(permit);
+            permit = match heavy_waiting.front_mut().and_then(|r| r.try_recv().ok()) {
+                Some(permit) => {
+                    heavy_waiting.pop_front();
+                    order.push(heavy);
+                    permit
+                }
+                None => {
+                    let permit = light_waiting.pop_front().unwrap().try_recv().unwrap();
+                    order.push(light);
+                    permit
+                }
+            };
+            assert_eq!(slots.status().running, 1);
+        }
+        assert_eq!(order, vec![heavy, heavy, heavy, light, heavy, light]);
+    }
+}
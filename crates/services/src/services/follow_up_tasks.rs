@@ -0,0 +1,109 @@
+use serde::Deserialize;
+
+/// Info string of the fenced block agents use to propose follow-up tasks
+pub const FOLLOW_UP_TASKS_FENCE: &str = "follow-up-tasks";
+
+/// Most proposals kept from a single run, so a runaway agent can't flood the board
+const MAX_PROPOSALS: usize = 10;
+
+/// Appended to the initial prompt when follow-up proposals are enabled
+pub const FOLLOW_UP_TASKS_PROMPT: &str = "If you come across work that is out of scope for this task but should be done later, list it at the very end of your final message in a fenced code block tagged `follow-up-tasks`, containing a JSON array of objects with a `title` and an optional `description`. Omit the block when there is nothing to follow up on.";
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProposedFollowUp {
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Append the follow-up instructions to a task prompt
+pub fn apply_to_prompt(prompt: &str) -> String {
+    format!("{prompt}\n\n{FOLLOW_UP_TASKS_PROMPT}")
+}
+
+/// Extract follow-up proposals from the last `follow-up-tasks` block in an agent message.
+/// The block should hold a JSON array; a plain markdown list of titles is accepted too.
+pub fn parse_follow_up_tasks(message: &str) -> Vec<ProposedFollowUp> {
+    let Some(body) = last_fenced_block(message, FOLLOW_UP_TASKS_FENCE) else {
+        return Vec::new();
+    };
+
+    let proposals = serde_json::from_str::<Vec<ProposedFollowUp>>(body).unwrap_or_else(|_| {
+        body.lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                line.strip_prefix("- ")
+                    .or_else(|| line.strip_prefix("* "))
+                    .map(|title| ProposedFollowUp {
+                        title: title.to_string(),
+                        description: None,
+                    })
+            })
+            .collect()
+    });
+
+    proposals
+        .into_iter()
+        .filter_map(|proposal| {
+            let title = proposal.title.trim();
+            (!title.is_empty()).then(|| ProposedFollowUp {
+                title: title.to_string(),
+                description: proposal
+                    .description
+                    .map(|d| d.trim().to_string())
+                    .filter(|d| !d.is_empty()),
+            })
+        })
+        .take(MAX_PROPOSALS)
+        .collect()
+}
+
+/// Body of the last fenced code block in `message` whose info string is `fence`
+pub fn last_fenced_block<'a>(message: &'a str, fence: &str) -> Option<&'a str> {
+    let opening = format!("```{fence}");
+    let start = message.rfind(&opening)? + opening.len();
+    let rest = &message[start..];
+    let body_start = rest.find('\n')? + 1;
+    let body = &rest[body_start..];
+    let end = body.find("```").unwrap_or(body.len());
+    Some(body[..end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_block() {
+        let message = "Done with the refactor.\n\n```follow-up-tasks\n[\n  {\"title\": \"Add tests for the parser\", \"description\": \"Cover empty input\"},\n  {\"title\": \"  \"},\n  {\"title\": \"Remove the old flag\"}\n]\n```\n";
+        assert_eq!(
+            parse_follow_up_tasks(message),
+            vec![
+                ProposedFollowUp {
+                    title: "Add tests for the parser".to_string(),
+                    description: Some("Cover empty input".to_string()),
+                },
+                ProposedFollowUp {
+                    title: "Remove the old flag".to_string(),
+                    description: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn falls_back_to_markdown_list() {
+        let message =
+            "```follow-up-tasks\n- Update the docs\n* Bump the schema version\nnot a task\n```";
+        let titles: Vec<String> = parse_follow_up_tasks(message)
+            .into_iter()
+            .map(|p| p.title)
+            .collect();
+        assert_eq!(titles, ["Update the docs", "Bump the schema version"]);
+    }
+
+    #[test]
+    fn ignores_messages_without_block() {
+        assert!(parse_follow_up_tasks("```rust\nfn main() {}\n```").is_empty());
+    }
+}
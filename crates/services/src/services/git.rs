@@ -290,6 +290,39 @@ impl GitService {
         Ok(true)
     }
 
+    /// Restore `paths` in a worktree to their content on `base_branch`, removing files the
+    /// base branch doesn't have, and commit the result. Returns false if nothing changed.
+    pub fn revert_paths_to_branch(
+        &self,
+        worktree_path: &Path,
+        base_branch: &str,
+        paths: &[String],
+        message: &str,
+    ) -> Result<bool, GitServiceError> {
+        let git = GitCli::new();
+        for path in paths {
+            let spec = format!("{base_branch}:{path}");
+            let in_base = git
+                .git(worktree_path, ["cat-file", "-e", spec.as_str()])
+                .is_ok();
+            let result = if in_base {
+                git.git(
+                    worktree_path,
+                    ["checkout", base_branch, "--", path.as_str()],
+                )
+            } else {
+                git.git(
+                    worktree_path,
+                    ["rm", "-q", "-f", "--ignore-unmatch", "--", path.as_str()],
+                )
+            };
+            result.map_err(|e| {
+                GitServiceError::InvalidRepository(format!("failed to revert {path}: {e}"))
+            })?;
+        }
+        self.commit(worktree_path, message)
+    }
+
     /// Get diffs between branches or worktree changes
     pub fn get_diffs(
         &self,
@@ -1573,6 +1606,71 @@ impl GitService {
         Ok(())
     }
 
+    /// Delete a local branch, but only when everything on it is already in
+    /// `base_branch` and it isn't checked out. Returns whether it was deleted.
+    pub fn delete_branch_if_merged(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+        base_branch: &str,
+    ) -> Result<bool, GitServiceError> {
+        let repo = self.open_repo(repo_path)?;
+        let mut branch = repo.find_branch(branch_name, BranchType::Local)?;
+        if branch.is_head() {
+            return Ok(false);
+        }
+        let tip = branch.get().peel_to_commit()?.id();
+        let base = Self::find_branch(&repo, base_branch)?
+            .get()
+            .peel_to_commit()?
+            .id();
+        if tip != base && !repo.graph_descendant_of(base, tip)? {
+            return Ok(false);
+        }
+        branch.delete()?;
+        Ok(true)
+    }
+
+    /// Delete a local branch whose changes landed elsewhere, e.g. through a
+    /// squash-merged PR, unless it's checked out. Returns whether it was deleted.
+    pub fn delete_branch(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Result<bool, GitServiceError> {
+        let repo = self.open_repo(repo_path)?;
+        let mut branch = repo.find_branch(branch_name, BranchType::Local)?;
+        if branch.is_head() {
+            return Ok(false);
+        }
+        branch.delete()?;
+        Ok(true)
+    }
+
+    /// Delete `branch_name` on the default remote, along with its
+    /// remote-tracking branch
+    pub fn delete_remote_branch(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Result<(), GitServiceError> {
+        let repo = self.open_repo(repo_path)?;
+        let remote_name = self.default_remote_name(&repo);
+        let remote = repo.find_remote(&remote_name)?;
+        let remote_url = remote
+            .url()
+            .ok_or_else(|| GitServiceError::InvalidRepository("Remote has no URL".to_string()))?;
+
+        GitCli::new().delete_remote_branch(repo_path, remote_url, branch_name)?;
+
+        if let Ok(mut tracking) =
+            repo.find_branch(&format!("{remote_name}/{branch_name}"), BranchType::Remote)
+        {
+            tracking.delete()?;
+        }
+        Ok(())
+    }
+
     /// Checkout an existing branch
     pub fn checkout_branch(
         &self,
@@ -1690,21 +1788,43 @@ impl GitService {
         }
     }
 
-    /// Extract GitHub owner and repo name from git repo path
-    pub fn get_github_repo_info(
-        &self,
-        repo_path: &Path,
-    ) -> Result<GitHubRepoInfo, GitServiceError> {
+    /// URL of the repo's default remote
+    pub fn get_remote_url(&self, repo_path: &Path) -> Result<String, GitServiceError> {
         let repo = self.open_repo(repo_path)?;
         let remote_name = self.default_remote_name(&repo);
         let remote = repo.find_remote(&remote_name).map_err(|_| {
             GitServiceError::InvalidRepository(format!("No '{remote_name}' remote found"))
         })?;
 
-        let url = remote
+        remote
             .url()
-            .ok_or_else(|| GitServiceError::InvalidRepository("Remote has no URL".to_string()))?;
-        GitHubRepoInfo::from_remote_url(url).map_err(|e| {
+            .map(|url| url.to_string())
+            .ok_or_else(|| GitServiceError::InvalidRepository("Remote has no URL".to_string()))
+    }
+
+    /// First commit reachable from HEAD along first parents, which stays the
+    /// same across clones and moves of the repository
+    pub fn get_root_commit(&self, repo_path: &Path) -> Result<String, GitServiceError> {
+        let repo = self.open_repo(repo_path)?;
+        let mut revwalk = repo.revwalk()?;
+        revwalk.push_head()?;
+        revwalk.simplify_first_parent()?;
+        let mut root = None;
+        for oid in revwalk {
+            root = Some(oid?);
+        }
+        root.map(|oid| oid.to_string()).ok_or_else(|| {
+            GitServiceError::InvalidRepository("Repository has no commits".to_string())
+        })
+    }
+
+    /// Extract GitHub owner and repo name from git repo path
+    pub fn get_github_repo_info(
+        &self,
+        repo_path: &Path,
+    ) -> Result<GitHubRepoInfo, GitServiceError> {
+        let url = self.get_remote_url(repo_path)?;
+        GitHubRepoInfo::from_remote_url(&url).map_err(|e| {
             GitServiceError::InvalidRepository(format!("Failed to parse remote URL: {e}"))
         })
     }
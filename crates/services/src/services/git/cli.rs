@@ -392,6 +392,28 @@ impl GitCli {
         }
     }
 
+    /// Delete `branch` on the remote
+    pub fn delete_remote_branch(
+        &self,
+        repo_path: &Path,
+        remote_url: &str,
+        branch: &str,
+    ) -> Result<(), GitCliError> {
+        let envs = vec![(OsString::from("GIT_TERMINAL_PROMPT"), OsString::from("0"))];
+
+        let args = [
+            OsString::from("push"),
+            OsString::from(remote_url),
+            OsString::from(format!(":refs/heads/{branch}")),
+        ];
+
+        match self.git_with_env(repo_path, args, &envs) {
+            Ok(_) => Ok(()),
+            Err(GitCliError::CommandFailed(msg)) => Err(self.classify_cli_error(msg)),
+            Err(err) => Err(err),
+        }
+    }
+
     /// This directly queries the remote without fetching.
     pub fn check_remote_branch_exists(
         &self,
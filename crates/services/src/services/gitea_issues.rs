@@ -0,0 +1,370 @@
+//! Issue access for self-hosted Gitea and Forgejo instances, which share the same
+//! `/api/v1` REST API.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+#[derive(Debug, Error)]
+pub enum GiteaIssuesError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Gitea API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Invalid repository format: {0}")]
+    InvalidRepo(String),
+    #[error("Invalid base URL: {0}")]
+    InvalidBaseUrl(String),
+    #[error("#{0} is a pull request, not an issue")]
+    PullRequest(i64),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct GiteaIssue {
+    pub number: i64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub html_url: String,
+    pub user: GiteaUser,
+    pub labels: Vec<GiteaLabel>,
+    #[ts(type = "string")]
+    pub created_at: DateTime<Utc>,
+    #[ts(type = "string")]
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub assignees: Option<Vec<GiteaUser>>,
+    pub milestone: Option<GiteaMilestone>,
+    /// Set when the entry is a pull request, which shares the issue numbering
+    #[serde(default, skip_serializing)]
+    #[ts(skip)]
+    pub pull_request: Option<serde_json::Value>,
+}
+
+impl GiteaIssue {
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct GiteaUser {
+    pub login: String,
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct GiteaLabel {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct GiteaMilestone {
+    pub id: i64,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ListGiteaIssuesParams {
+    pub state: Option<String>,
+    /// Comma-separated label names
+    pub labels: Option<String>,
+    pub limit: Option<i32>,
+    pub page: Option<i32>,
+}
+
+impl Default for ListGiteaIssuesParams {
+    fn default() -> Self {
+        Self {
+            state: Some("open".to_string()),
+            labels: None,
+            limit: Some(30),
+            page: Some(1),
+        }
+    }
+}
+
+pub struct GiteaIssuesService {
+    http: HttpClient,
+}
+
+impl GiteaIssuesService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    /// Split an `owner/repo` setting, also accepting a full repository URL
+    pub fn parse_repo(repo: &str) -> Result<(String, String), GiteaIssuesError> {
+        let trimmed = repo.trim().trim_end_matches('/');
+        let trimmed = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+        let path = match trimmed.split_once("://") {
+            Some((_, rest)) => rest.split_once('/').map(|(_, path)| path).unwrap_or(""),
+            None => trimmed,
+        };
+        let mut parts = path.split('/').filter(|part| !part.is_empty());
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(owner), Some(name), None) => Ok((owner.to_string(), name.to_string())),
+            _ => Err(GiteaIssuesError::InvalidRepo(repo.to_string())),
+        }
+    }
+
+    /// API root for an instance, e.g. `https://git.example.com/api/v1`
+    pub fn api_base(base_url: &str) -> Result<String, GiteaIssuesError> {
+        let base = base_url.trim().trim_end_matches('/');
+        if !base.starts_with("http://") && !base.starts_with("https://") {
+            return Err(GiteaIssuesError::InvalidBaseUrl(base_url.to_string()));
+        }
+        let base = base.strip_suffix("/api/v1").unwrap_or(base);
+        Ok(format!("{base}/api/v1"))
+    }
+
+    pub async fn list_issues(
+        &self,
+        base_url: &str,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        params: &ListGiteaIssuesParams,
+    ) -> Result<Vec<GiteaIssue>, GiteaIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues",
+            Self::api_base(base_url)?,
+            owner,
+            repo
+        );
+
+        let mut request = self
+            .http
+            .get(&url)
+            .header("Authorization", format!("token {}", token))
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .query(&[("type", "issues")]);
+
+        if let Some(state) = &params.state {
+            request = request.query(&[("state", state)]);
+        }
+        if let Some(labels) = &params.labels {
+            request = request.query(&[("labels", labels)]);
+        }
+        if let Some(limit) = params.limit {
+            request = request.query(&[("limit", limit.to_string())]);
+        }
+        if let Some(page) = params.page {
+            request = request.query(&[("page", page.to_string())]);
+        }
+
+        let response = request.send_via(&self.http).await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GiteaIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        // `type=issues` is ignored by older Gitea releases, which list pull
+        // requests alongside issues
+        let mut issues: Vec<GiteaIssue> = response.json().await?;
+        issues.retain(|issue| !issue.is_pull_request());
+        Ok(issues)
+    }
+
+    pub async fn get_issue(
+        &self,
+        base_url: &str,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        number: i64,
+    ) -> Result<GiteaIssue, GiteaIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            Self::api_base(base_url)?,
+            owner,
+            repo,
+            number
+        );
+
+        let response = self
+            .http
+            .get(&url)
+            .header("Authorization", format!("token {}", token))
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GiteaIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let issue: GiteaIssue = response.json().await?;
+        if issue.is_pull_request() {
+            return Err(GiteaIssuesError::PullRequest(number));
+        }
+        Ok(issue)
+    }
+
+    /// Open or close an issue, with `state` being `open` or `closed`
+    pub async fn update_issue_state(
+        &self,
+        base_url: &str,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        state: &str,
+    ) -> Result<(), GiteaIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            Self::api_base(base_url)?,
+            owner,
+            repo,
+            number
+        );
+        let request = self
+            .http
+            .patch(&url)
+            .header("Authorization", format!("token {}", token))
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .json(&serde_json::json!({ "state": state }));
+        Self::expect_success(request.send_via(&self.http).await?).await
+    }
+
+    pub async fn add_comment(
+        &self,
+        base_url: &str,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        number: i64,
+        body: &str,
+    ) -> Result<(), GiteaIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments",
+            Self::api_base(base_url)?,
+            owner,
+            repo,
+            number
+        );
+        let request = self
+            .http
+            .post(&url)
+            .header("Authorization", format!("token {}", token))
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .json(&serde_json::json!({ "body": body }));
+        Self::expect_success(request.send_via(&self.http).await?).await
+    }
+
+    async fn expect_success(response: reqwest::Response) -> Result<(), GiteaIssuesError> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        Err(GiteaIssuesError::Api {
+            status: status.as_u16(),
+            message,
+        })
+    }
+}
+
+impl Default for GiteaIssuesService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_repo_settings() {
+        assert_eq!(
+            GiteaIssuesService::parse_repo("acme/api").unwrap(),
+            ("acme".to_string(), "api".to_string())
+        );
+        assert_eq!(
+            GiteaIssuesService::parse_repo("https://git.example.com/acme/api.git").unwrap(),
+            ("acme".to_string(), "api".to_string())
+        );
+        assert!(GiteaIssuesService::parse_repo("acme").is_err());
+    }
+
+    #[test]
+    fn detects_pull_requests() {
+        let entries: Vec<GiteaIssue> = serde_json::from_value(serde_json::json!([
+            {
+                "number": 1,
+                "title": "Crash on save",
+                "body": null,
+                "state": "open",
+                "html_url": "https://git.example.com/acme/api/issues/1",
+                "user": { "login": "alice", "avatar_url": null },
+                "labels": [],
+                "created_at": "2025-01-01T00:00:00Z",
+                "updated_at": "2025-01-01T00:00:00Z",
+                "milestone": null,
+                "pull_request": null
+            },
+            {
+                "number": 2,
+                "title": "Fix crash on save",
+                "body": null,
+                "state": "open",
+                "html_url": "https://git.example.com/acme/api/pulls/2",
+                "user": { "login": "bob", "avatar_url": null },
+                "labels": [],
+                "created_at": "2025-01-01T00:00:00Z",
+                "updated_at": "2025-01-01T00:00:00Z",
+                "milestone": null,
+                "pull_request": { "merged": false }
+            }
+        ]))
+        .unwrap();
+        let flags: Vec<bool> = entries.iter().map(GiteaIssue::is_pull_request).collect();
+        assert_eq!(flags, vec![false, true]);
+    }
+
+    #[test]
+    fn normalizes_api_base() {
+        assert_eq!(
+            GiteaIssuesService::api_base("https://codeberg.org/").unwrap(),
+            "https://codeberg.org/api/v1"
+        );
+        assert_eq!(
+            GiteaIssuesService::api_base("https://git.example.com/api/v1").unwrap(),
+            "https://git.example.com/api/v1"
+        );
+        assert!(GiteaIssuesService::api_base("git.example.com").is_err());
+    }
+}
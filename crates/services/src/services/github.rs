@@ -13,7 +13,7 @@ use ts_rs::TS;
 mod cli;
 
 use cli::{GhCli, GhCliError, PrComment, PrReviewComment};
-pub use cli::{PrCommentAuthor, ReviewCommentUser};
+pub use cli::{PrChecks, PrCommentAuthor, ReviewCommentUser};
 
 /// Unified PR comment that can be either a general comment or review comment
 #[derive(Debug, Clone, Serialize, TS)]
@@ -278,6 +278,68 @@ impl GitHubService {
         .await
     }
 
+    /// Checks and commit statuses on a pull request's head, and its review
+    /// decision, as `gh` reports them
+    pub async fn get_pr_checks(
+        &self,
+        repo_info: &GitHubRepoInfo,
+        pr_number: i64,
+    ) -> Result<PrChecks, GitHubServiceError> {
+        let owner = repo_info.owner.clone();
+        let repo = repo_info.repo_name.clone();
+        let cli = self.gh_cli.clone();
+        task::spawn_blocking(move || cli.get_pr_checks(&owner, &repo, pr_number))
+            .await
+            .map_err(|err| {
+                GitHubServiceError::PullRequest(format!(
+                    "Failed to execute GitHub CLI for PR #{pr_number} checks: {err}"
+                ))
+            })?
+            .map_err(GitHubServiceError::from)
+    }
+
+    /// Replace a pull request's description
+    pub async fn update_pr_body(
+        &self,
+        repo_info: &GitHubRepoInfo,
+        pr_number: i64,
+        body: &str,
+    ) -> Result<(), GitHubServiceError> {
+        let owner = repo_info.owner.clone();
+        let repo = repo_info.repo_name.clone();
+        let body = body.to_string();
+        let cli = self.gh_cli.clone();
+        task::spawn_blocking(move || cli.edit_pr_body(&owner, &repo, pr_number, &body))
+            .await
+            .map_err(|err| {
+                GitHubServiceError::PullRequest(format!(
+                    "Failed to execute GitHub CLI for editing PR #{pr_number}: {err}"
+                ))
+            })?
+            .map_err(GitHubServiceError::from)
+    }
+
+    /// Close a pull request with an explanatory comment
+    pub async fn close_pr(
+        &self,
+        repo_info: &GitHubRepoInfo,
+        pr_number: i64,
+        comment: &str,
+    ) -> Result<(), GitHubServiceError> {
+        let owner = repo_info.owner.clone();
+        let repo = repo_info.repo_name.clone();
+        let comment = comment.to_string();
+        let cli = self.gh_cli.clone();
+        task::spawn_blocking(move || cli.close_pr(&owner, &repo, pr_number, &comment))
+            .await
+            .map_err(|err| {
+                GitHubServiceError::PullRequest(format!(
+                    "Failed to execute GitHub CLI for closing PR #{pr_number}: {err}"
+                ))
+            })?
+            .map_err(GitHubServiceError::from)
+    }
+
     /// List all pull requests for a branch (including closed/merged)
     pub async fn list_all_prs_for_branch(
         &self,
@@ -58,6 +58,19 @@ pub struct PrReviewComment {
     pub author_association: String,
 }
 
+/// CI results and review decision of a PR (from `gh pr view`)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrChecks {
+    /// Actions check runs mixed with legacy commit status contexts
+    #[serde(default)]
+    pub status_check_rollup: Vec<Value>,
+    /// `APPROVED`, `CHANGES_REQUESTED` or `REVIEW_REQUIRED`; empty when the
+    /// repository requires no review
+    #[serde(default)]
+    pub review_decision: Option<String>,
+}
+
 /// High-level errors originating from the GitHub CLI.
 #[derive(Debug, Error)]
 pub enum GhCliError {
@@ -232,6 +245,70 @@ impl GhCli {
         Self::parse_pr_comments(&raw)
     }
 
+    /// Fetch the checks and commit statuses reported on a pull request's head,
+    /// and its review decision.
+    pub fn get_pr_checks(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: i64,
+    ) -> Result<PrChecks, GhCliError> {
+        let raw = self.run([
+            "pr",
+            "view",
+            &pr_number.to_string(),
+            "--repo",
+            &format!("{owner}/{repo}"),
+            "--json",
+            "statusCheckRollup,reviewDecision",
+        ])?;
+        serde_json::from_str(raw.trim()).map_err(|err| {
+            GhCliError::UnexpectedOutput(format!(
+                "Failed to parse gh pr view response: {err}; raw: {raw}"
+            ))
+        })
+    }
+
+    /// Replace the description of a pull request.
+    pub fn edit_pr_body(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: i64,
+        body: &str,
+    ) -> Result<(), GhCliError> {
+        self.run([
+            "pr",
+            "edit",
+            &pr_number.to_string(),
+            "--repo",
+            &format!("{owner}/{repo}"),
+            "--body",
+            body,
+        ])?;
+        Ok(())
+    }
+
+    /// Close a pull request, leaving a comment that explains why.
+    pub fn close_pr(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: i64,
+        comment: &str,
+    ) -> Result<(), GhCliError> {
+        self.run([
+            "pr",
+            "close",
+            &pr_number.to_string(),
+            "--repo",
+            &format!("{owner}/{repo}"),
+            "--comment",
+            comment,
+        ])?;
+        Ok(())
+    }
+
     /// Fetch inline review comments for a pull request via API.
     pub fn get_pr_review_comments(
         &self,
@@ -1,21 +1,32 @@
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use reqwest::{RequestBuilder, Response, StatusCode, Url, header::ETAG};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use ts_rs::TS;
 
+use crate::services::{
+    github_rate_limit::{self, CachedPage, GitHubRateLimits},
+    http_client::{HttpClient, HttpClientError, SendVia},
+};
+
 const GITHUB_API_BASE: &str = "https://api.github.com";
 
 #[derive(Debug, Error)]
 pub enum GitHubIssuesError {
     #[error("HTTP request failed: {0}")]
     Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
     #[error("GitHub API error: {status} - {message}")]
     Api { status: u16, message: String },
     #[error("Invalid repository URL format: {0}")]
     InvalidRepoUrl(String),
     #[error("Authentication required")]
     AuthRequired,
+    #[error("Invalid GitHub API response: {0}")]
+    InvalidResponse(#[from] serde_json::Error),
+    #[error("GitHub API rate limit exceeded; retry after {reset_at}")]
+    RateLimited { reset_at: DateTime<Utc> },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -47,10 +58,93 @@ pub struct GitHubLabel {
     pub color: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
 pub struct GitHubMilestone {
     pub title: String,
     pub number: i64,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// `open` or `closed`
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub open_issues: i64,
+    #[serde(default)]
+    pub closed_issues: i64,
+    #[serde(default)]
+    #[ts(type = "string | null")]
+    pub due_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct GitHubPullRequest {
+    pub number: i64,
+    pub title: String,
+    pub body: Option<String>,
+    pub state: String,
+    pub html_url: String,
+    pub diff_url: String,
+    pub user: GitHubUser,
+    pub labels: Vec<GitHubLabel>,
+    #[serde(default)]
+    pub draft: bool,
+    pub requested_reviewers: Vec<GitHubUser>,
+    pub head: GitHubPullRequestRef,
+    #[ts(type = "string")]
+    pub created_at: DateTime<Utc>,
+    #[ts(type = "string")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct GitHubPullRequestRef {
+    #[serde(rename = "ref")]
+    #[ts(rename = "ref")]
+    pub branch: String,
+    pub sha: String,
+}
+
+/// Overall CI result for a commit, folding commit statuses and check runs together
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, TS, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(rename_all = "snake_case")]
+pub enum GitHubCiStatus {
+    Success,
+    Pending,
+    Failure,
+    /// No CI reported anything for the commit
+    None,
+}
+
+impl std::fmt::Display for GitHubCiStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::Success => "passing",
+            Self::Pending => "pending",
+            Self::Failure => "failing",
+            Self::None => "none",
+        };
+        f.write_str(label)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CombinedStatus {
+    state: String,
+    total_count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRunList {
+    check_runs: Vec<CheckRun>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRun {
+    status: String,
+    conclusion: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -76,14 +170,64 @@ impl Default for ListIssuesParams {
     }
 }
 
+/// Narrows a bulk import down to the issues a user picked. Empty fields match
+/// everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+pub struct GitHubIssueFilter {
+    /// Issue must carry every one of these labels
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Milestone title
+    pub milestone: Option<String>,
+    /// Assignee login
+    pub assignee: Option<String>,
+    #[ts(type = "string | null")]
+    pub created_after: Option<DateTime<Utc>>,
+    #[ts(type = "string | null")]
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl GitHubIssueFilter {
+    pub fn matches(&self, issue: &GitHubIssue) -> bool {
+        let has_labels = self.labels.iter().all(|wanted| {
+            issue
+                .labels
+                .iter()
+                .any(|label| label.name.eq_ignore_ascii_case(wanted))
+        });
+        let in_milestone = self.milestone.as_ref().is_none_or(|wanted| {
+            issue
+                .milestone
+                .as_ref()
+                .is_some_and(|milestone| milestone.title.eq_ignore_ascii_case(wanted))
+        });
+        let assigned = self.assignee.as_ref().is_none_or(|wanted| {
+            let wanted = wanted.trim_start_matches('@');
+            issue
+                .assignees
+                .iter()
+                .any(|user| user.login.eq_ignore_ascii_case(wanted))
+        });
+        has_labels
+            && in_milestone
+            && assigned
+            && self
+                .created_after
+                .is_none_or(|after| issue.created_at >= after)
+            && self
+                .created_before
+                .is_none_or(|before| issue.created_at < before)
+    }
+}
+
 pub struct GitHubIssuesService {
-    client: Client,
+    http: HttpClient,
 }
 
 impl GitHubIssuesService {
     pub fn new() -> Self {
         Self {
-            client: Client::new(),
+            http: HttpClient::shared(),
         }
     }
 
@@ -117,6 +261,8 @@ impl GitHubIssuesService {
         Err(GitHubIssuesError::InvalidRepoUrl(url.to_string()))
     }
 
+    /// A page of issues. Pages fetched before are revalidated with their ETag,
+    /// and an unchanged page is served from the cache without using quota.
     pub async fn list_issues(
         &self,
         token: &str,
@@ -126,34 +272,108 @@ impl GitHubIssuesService {
     ) -> Result<Vec<GitHubIssue>, GitHubIssuesError> {
         let url = format!("{}/repos/{}/{}/issues", GITHUB_API_BASE, owner, repo);
 
-        let mut request = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/vnd.github+json")
-            .header("User-Agent", "vibe-kanban")
-            .header("X-GitHub-Api-Version", "2022-11-28");
-
+        let mut query: Vec<(&str, String)> = Vec::new();
         if let Some(state) = &params.state {
-            request = request.query(&[("state", state)]);
+            query.push(("state", state.clone()));
         }
         if let Some(labels) = &params.labels {
-            request = request.query(&[("labels", labels)]);
+            query.push(("labels", labels.clone()));
         }
         if let Some(sort) = &params.sort {
-            request = request.query(&[("sort", sort)]);
+            query.push(("sort", sort.clone()));
         }
         if let Some(direction) = &params.direction {
-            request = request.query(&[("direction", direction)]);
+            query.push(("direction", direction.clone()));
         }
         if let Some(per_page) = params.per_page {
-            request = request.query(&[("per_page", per_page.to_string())]);
+            query.push(("per_page", per_page.to_string()));
         }
         if let Some(page) = params.page {
-            request = request.query(&[("page", page.to_string())]);
+            query.push(("page", page.to_string()));
         }
+        let url = Url::parse_with_params(&url, &query)
+            .map_err(|_| GitHubIssuesError::InvalidRepoUrl(format!("{}/{}", owner, repo)))?;
+
+        let limits = GitHubRateLimits::shared();
+        let cached = limits.cached_page(token, url.as_str());
+        let mut request = self
+            .http
+            .get(url.clone())
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "vibe-kanban")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(cached) = &cached {
+            request = request.header("If-None-Match", &cached.etag);
+        }
+
+        let response = self.send(token, request).await?;
+        let status = response.status();
+
+        let body = if status == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            cached.body
+        } else {
+            if !status.is_success() {
+                let message = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "Unknown error".to_string());
+                return Err(GitHubIssuesError::Api {
+                    status: status.as_u16(),
+                    message,
+                });
+            }
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(str::to_string);
+            let body = response.text().await?;
+            if let Some(etag) = etag {
+                limits.store_page(
+                    token,
+                    url.as_str(),
+                    CachedPage {
+                        etag,
+                        body: body.clone(),
+                    },
+                );
+            }
+            body
+        };
+
+        let issues: Vec<GitHubIssue> = serde_json::from_str(&body)?;
+        let issues = issues
+            .into_iter()
+            .filter(|issue| !issue.html_url.contains("/pull/"))
+            .collect();
+
+        Ok(issues)
+    }
+
+    pub async fn get_issue(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<GitHubIssue, GitHubIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            GITHUB_API_BASE, owner, repo, issue_number
+        );
+
+        let request = self
+            .http
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "vibe-kanban")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        let response = self.send(token, request).await?;
 
-        let response = request.send().await?;
         let status = response.status();
 
         if !status.is_success() {
@@ -167,37 +387,250 @@ impl GitHubIssuesService {
             });
         }
 
-        let issues: Vec<GitHubIssue> = response.json().await?;
-        let issues = issues
-            .into_iter()
-            .filter(|issue| !issue.html_url.contains("/pull/"))
-            .collect();
-
-        Ok(issues)
+        let issue: GitHubIssue = response.json().await?;
+        Ok(issue)
     }
 
-    pub async fn get_issue(
+    /// Set an issue's state to `open` or `closed`
+    pub async fn update_issue_state(
         &self,
         token: &str,
         owner: &str,
         repo: &str,
         issue_number: i64,
-    ) -> Result<GitHubIssue, GitHubIssuesError> {
+        state: &str,
+    ) -> Result<(), GitHubIssuesError> {
         let url = format!(
             "{}/repos/{}/{}/issues/{}",
             GITHUB_API_BASE, owner, repo, issue_number
         );
 
-        let response = self
-            .client
-            .get(&url)
+        let request = self
+            .http
+            .patch(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "vibe-kanban")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .json(&serde_json::json!({ "state": state }));
+        let response = self.send(token, request).await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitHubIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Replace an issue's labels. Labels the repository does not have yet are
+    /// created by GitHub.
+    pub async fn set_issue_labels(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        labels: &[String],
+    ) -> Result<(), GitHubIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/labels",
+            GITHUB_API_BASE, owner, repo, issue_number
+        );
+
+        let request = self
+            .http
+            .put(&url)
             .header("Authorization", format!("Bearer {}", token))
             .header("Accept", "application/vnd.github+json")
             .header("User-Agent", "vibe-kanban")
             .header("X-GitHub-Api-Version", "2022-11-28")
-            .send()
+            .json(&serde_json::json!({ "labels": labels }));
+        let response = self.send(token, request).await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitHubIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Open and closed milestones, soonest due first
+    pub async fn list_milestones(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<GitHubMilestone>, GitHubIssuesError> {
+        let url = format!("{}/repos/{}/{}/milestones", GITHUB_API_BASE, owner, repo);
+        self.get_json(
+            &url,
+            token,
+            &[
+                ("state", "all".to_string()),
+                ("sort", "due_on".to_string()),
+                ("per_page", "100".to_string()),
+            ],
+        )
+        .await
+    }
+
+    /// Open pull requests, which `list_issues` leaves out
+    pub async fn list_pull_requests(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        per_page: i32,
+    ) -> Result<Vec<GitHubPullRequest>, GitHubIssuesError> {
+        let url = format!("{}/repos/{}/{}/pulls", GITHUB_API_BASE, owner, repo);
+        self.get_json(
+            &url,
+            token,
+            &[
+                ("state", "open".to_string()),
+                ("sort", "updated".to_string()),
+                ("direction", "desc".to_string()),
+                ("per_page", per_page.to_string()),
+            ],
+        )
+        .await
+    }
+
+    pub async fn get_pull_request(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<GitHubPullRequest, GitHubIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls/{}",
+            GITHUB_API_BASE, owner, repo, pull_number
+        );
+        self.get_json(&url, token, &[]).await
+    }
+
+    /// CI status of a commit, combining legacy commit statuses with Actions check runs
+    pub async fn get_ci_status(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<GitHubCiStatus, GitHubIssuesError> {
+        let base = format!(
+            "{}/repos/{}/{}/commits/{}",
+            GITHUB_API_BASE, owner, repo, sha
+        );
+        let combined: CombinedStatus = self.get_json(&format!("{base}/status"), token, &[]).await?;
+        let check_runs: CheckRunList = self
+            .get_json(
+                &format!("{base}/check-runs"),
+                token,
+                &[("per_page", "100".to_string())],
+            )
             .await?;
 
+        let mut results = Vec::new();
+        if combined.total_count > 0 {
+            results.push(match combined.state.as_str() {
+                "success" => GitHubCiStatus::Success,
+                "pending" => GitHubCiStatus::Pending,
+                _ => GitHubCiStatus::Failure,
+            });
+        }
+        results.extend(check_runs.check_runs.iter().map(|run| {
+            match (run.status.as_str(), run.conclusion.as_deref()) {
+                ("completed", Some("success" | "neutral" | "skipped")) => GitHubCiStatus::Success,
+                ("completed", _) => GitHubCiStatus::Failure,
+                _ => GitHubCiStatus::Pending,
+            }
+        }));
+
+        Ok(if results.is_empty() {
+            GitHubCiStatus::None
+        } else if results.contains(&GitHubCiStatus::Failure) {
+            GitHubCiStatus::Failure
+        } else if results.contains(&GitHubCiStatus::Pending) {
+            GitHubCiStatus::Pending
+        } else {
+            GitHubCiStatus::Success
+        })
+    }
+
+    /// Send an API request within the token's rate limit. A used-up quota or a
+    /// secondary rate limit that lifts within [`github_rate_limit::MAX_BACKOFF`]
+    /// is waited out; a longer one fails with [`GitHubIssuesError::RateLimited`].
+    async fn send(
+        &self,
+        token: &str,
+        request: RequestBuilder,
+    ) -> Result<Response, GitHubIssuesError> {
+        let limits = GitHubRateLimits::shared();
+        if let Some(reset_at) = limits.exhausted_until(token, Utc::now()) {
+            Self::back_off(reset_at).await?;
+        }
+
+        let retry = request.try_clone();
+        let response = request.send_via(&self.http).await?;
+        limits.record(token, response.headers());
+        let Some(retry_at) =
+            github_rate_limit::retry_at(response.status(), response.headers(), Utc::now())
+        else {
+            return Ok(response);
+        };
+        let Some(retry) = retry else {
+            return Err(GitHubIssuesError::RateLimited { reset_at: retry_at });
+        };
+
+        Self::back_off(retry_at).await?;
+        let response = retry.send_via(&self.http).await?;
+        limits.record(token, response.headers());
+        Ok(response)
+    }
+
+    async fn back_off(until: DateTime<Utc>) -> Result<(), GitHubIssuesError> {
+        let wait = github_rate_limit::backoff_until(until, Utc::now())
+            .ok_or(GitHubIssuesError::RateLimited { reset_at: until })?;
+        tracing::info!("GitHub rate limit reached; retrying in {}s", wait.as_secs());
+        tokio::time::sleep(wait).await;
+        Ok(())
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        token: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, GitHubIssuesError> {
+        let request = self
+            .http
+            .get(url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "vibe-kanban")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .query(query);
+        let response = self.send(token, request).await?;
+
         let status = response.status();
 
         if !status.is_success() {
@@ -211,9 +644,95 @@ impl GitHubIssuesService {
             });
         }
 
-        let issue: GitHubIssue = response.json().await?;
-        Ok(issue)
+        Ok(response.json().await?)
     }
+
+    pub async fn add_comment(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &str,
+    ) -> Result<(), GitHubIssuesError> {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}/comments",
+            GITHUB_API_BASE, owner, repo, issue_number
+        );
+
+        let request = self
+            .http
+            .post(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "vibe-kanban")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .json(&serde_json::json!({ "body": body }));
+        let response = self.send(token, request).await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitHubIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Download an image from an issue body. Only call this for
+    /// [`is_github_image_url`] URLs, so the token never leaves GitHub.
+    pub async fn download_image(
+        &self,
+        token: &str,
+        url: &str,
+    ) -> Result<Vec<u8>, GitHubIssuesError> {
+        let response = self
+            .http
+            .get(url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("User-Agent", "vibe-kanban")
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitHubIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+/// Whether `url` is an image uploaded to GitHub through an issue or comment,
+/// e.g. `https://github.com/user-attachments/assets/<id>`
+pub fn is_github_image_url(url: &str) -> bool {
+    let Ok(url) = url::Url::parse(url) else {
+        return false;
+    };
+    url.scheme() == "https"
+        && match url.host_str() {
+            Some("github.com") => url.path().contains("/assets/"),
+            Some(host) => matches!(
+                host,
+                "user-images.githubusercontent.com" | "private-user-images.githubusercontent.com"
+            ),
+            None => false,
+        }
 }
 
 impl Default for GitHubIssuesService {
@@ -221,3 +740,135 @@ impl Default for GitHubIssuesService {
         Self::new()
     }
 }
+
+/// Description for a task tracking a pull request review. The first line is the
+/// header `extract_github_pr_number_from_description` reads back.
+pub fn pull_request_task_description(pr: &GitHubPullRequest, ci_status: GitHubCiStatus) -> String {
+    let reviewers = if pr.requested_reviewers.is_empty() {
+        "none requested".to_string()
+    } else {
+        pr.requested_reviewers
+            .iter()
+            .map(|r| format!("@{}", r.login))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "Imported from GitHub Pull Request #{}\n{}\n\nDiff: {}\nAuthor: @{}\nBranch: {}\nCI: {}\nReviewers: {}\n\n{}",
+        pr.number,
+        pr.html_url,
+        pr.diff_url,
+        pr.user.login,
+        pr.head.branch,
+        ci_status,
+        reviewers,
+        pr.body.clone().unwrap_or_default()
+    )
+}
+
+/// Pull request number of a task created by the pull request import
+pub fn extract_github_pr_number_from_description(description: &str) -> Option<i64> {
+    description
+        .lines()
+        .next()?
+        .strip_prefix("Imported from GitHub Pull Request #")?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(labels: &[&str], milestone: Option<&str>, assignee: &str) -> GitHubIssue {
+        let user = |login: &str| GitHubUser {
+            login: login.to_string(),
+            avatar_url: String::new(),
+        };
+        GitHubIssue {
+            number: 1,
+            title: "Fix login".to_string(),
+            body: None,
+            state: "open".to_string(),
+            html_url: "https://github.com/acme/app/issues/1".to_string(),
+            user: user("octocat"),
+            labels: labels
+                .iter()
+                .map(|name| GitHubLabel {
+                    name: name.to_string(),
+                    color: String::new(),
+                })
+                .collect(),
+            created_at: "2026-03-10T12:00:00Z".parse().unwrap(),
+            updated_at: "2026-03-10T12:00:00Z".parse().unwrap(),
+            assignees: vec![user(assignee)],
+            milestone: milestone.map(|title| GitHubMilestone {
+                title: title.to_string(),
+                number: 1,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn only_github_uploads_are_github_images() {
+        assert!(is_github_image_url(
+            "https://github.com/user-attachments/assets/3c1d0f7e-52b1"
+        ));
+        assert!(is_github_image_url(
+            "https://user-images.githubusercontent.com/1/2-screen.png"
+        ));
+        assert!(!is_github_image_url("https://github.com/acme/app/issues/1"));
+        assert!(!is_github_image_url(
+            "https://github.com.evil.example/assets/x.png"
+        ));
+        assert!(!is_github_image_url(
+            "http://github.com/user-attachments/assets/x"
+        ));
+    }
+
+    #[test]
+    fn reads_milestones_listed_or_embedded_in_issues() {
+        let listed: GitHubMilestone = serde_json::from_str(
+            r#"{"number": 3, "title": "v2.0", "description": null, "state": "open",
+                "html_url": "https://github.com/acme/app/milestone/3",
+                "open_issues": 4, "closed_issues": 6, "due_on": "2026-04-01T07:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(listed.open_issues + listed.closed_issues, 10);
+        assert_eq!(listed.due_on, "2026-04-01T07:00:00Z".parse().ok());
+
+        let embedded: GitHubMilestone =
+            serde_json::from_str(r#"{"number": 3, "title": "v2.0"}"#).unwrap();
+        assert_eq!(embedded.number, 3);
+        assert!(embedded.due_on.is_none());
+    }
+
+    #[test]
+    fn filter_requires_every_criterion() {
+        let filter = GitHubIssueFilter {
+            labels: vec!["bug".to_string(), "Frontend".to_string()],
+            milestone: Some("sprint 12".to_string()),
+            assignee: Some("@alice".to_string()),
+            created_after: Some("2026-03-01T00:00:00Z".parse().unwrap()),
+            created_before: Some("2026-03-15T00:00:00Z".parse().unwrap()),
+        };
+
+        assert!(filter.matches(&issue(
+            &["frontend", "bug", "p1"],
+            Some("Sprint 12"),
+            "alice"
+        )));
+        assert!(!filter.matches(&issue(&["bug"], Some("Sprint 12"), "alice")));
+        assert!(!filter.matches(&issue(&["bug", "frontend"], None, "alice")));
+        assert!(!filter.matches(&issue(&["bug", "frontend"], Some("Sprint 12"), "bob")));
+        assert!(GitHubIssueFilter::default().matches(&issue(&[], None, "bob")));
+
+        let too_late = GitHubIssueFilter {
+            created_before: Some("2026-03-10T12:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(!too_late.matches(&issue(&[], None, "bob")));
+    }
+}
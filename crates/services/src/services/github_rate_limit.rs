@@ -0,0 +1,244 @@
+//! Keeps GitHub syncs within each token's API quota. The rate limit headers of
+//! every API response are tracked per token, so requests can wait out a quota
+//! that resets soon instead of failing, and issue listings are revalidated with
+//! their ETag so pages that haven't changed don't count against the quota.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{LazyLock, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::{StatusCode, header::HeaderMap};
+
+/// Longest wait for a rate limit to lift before a request fails instead
+pub const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Listing pages kept for revalidation, across all tokens
+const MAX_CACHED_PAGES: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimit {
+    /// From the `X-RateLimit-*` headers, which API responses carry and other
+    /// GitHub responses don't
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<i64>().ok();
+        Some(Self {
+            limit: header("x-ratelimit-limit")?.try_into().ok()?,
+            remaining: header("x-ratelimit-remaining")?.try_into().ok()?,
+            reset_at: Utc
+                .timestamp_opt(header("x-ratelimit-reset")?, 0)
+                .single()?,
+        })
+    }
+}
+
+/// When a response turned away by a rate limit may be retried: after its
+/// `Retry-After`, which secondary rate limits send, or else when the quota
+/// resets. `None` when the response wasn't rate limited.
+pub fn retry_at(
+    status: StatusCode,
+    headers: &HeaderMap,
+    now: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let retry_after = headers
+        .get("retry-after")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .map(|secs| now + chrono::Duration::seconds(secs));
+    let quota_reset = RateLimit::from_headers(headers)
+        .filter(|limit| limit.remaining == 0)
+        .map(|limit| limit.reset_at);
+    match retry_after.or(quota_reset) {
+        Some(at) => Some(at),
+        // A 403 without either header is a permissions error
+        None if status == StatusCode::TOO_MANY_REQUESTS => {
+            Some(now + chrono::Duration::seconds(60))
+        }
+        None => None,
+    }
+}
+
+/// How long to wait until `at`, if that's within [`MAX_BACKOFF`]
+pub fn backoff_until(at: DateTime<Utc>, now: DateTime<Utc>) -> Option<Duration> {
+    let wait = (at - now).to_std().unwrap_or_default();
+    (wait <= MAX_BACKOFF).then_some(wait)
+}
+
+/// A listing page as last returned, with the ETag to revalidate it
+#[derive(Debug, Clone)]
+pub struct CachedPage {
+    pub etag: String,
+    pub body: String,
+}
+
+/// Tokens are kept as hashes so the state never holds credentials
+fn token_key(token: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Debug, Default)]
+struct State {
+    limits: HashMap<u64, RateLimit>,
+    pages: HashMap<(u64, String), CachedPage>,
+    /// Cached pages, oldest first
+    page_order: VecDeque<(u64, String)>,
+}
+
+/// Rate limits and cached listing pages of the GitHub tokens in use
+#[derive(Debug, Default)]
+pub struct GitHubRateLimits {
+    state: Mutex<State>,
+}
+
+static SHARED: LazyLock<GitHubRateLimits> = LazyLock::new(GitHubRateLimits::default);
+
+impl GitHubRateLimits {
+    pub fn shared() -> &'static Self {
+        &SHARED
+    }
+
+    /// Remember the token's quota from a response's headers
+    pub fn record(&self, token: &str, headers: &HeaderMap) {
+        if let Some(limit) = RateLimit::from_headers(headers) {
+            self.state
+                .lock()
+                .unwrap()
+                .limits
+                .insert(token_key(token), limit);
+        }
+    }
+
+    pub fn get(&self, token: &str) -> Option<RateLimit> {
+        self.state
+            .lock()
+            .unwrap()
+            .limits
+            .get(&token_key(token))
+            .copied()
+    }
+
+    /// When the token's quota resets, if it is used up
+    pub fn exhausted_until(&self, token: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.get(token)
+            .filter(|limit| limit.remaining == 0 && limit.reset_at > now)
+            .map(|limit| limit.reset_at)
+    }
+
+    pub fn cached_page(&self, token: &str, url: &str) -> Option<CachedPage> {
+        self.state
+            .lock()
+            .unwrap()
+            .pages
+            .get(&(token_key(token), url.to_string()))
+            .cloned()
+    }
+
+    /// Keep a listing page for revalidation, dropping the oldest page when full
+    pub fn store_page(&self, token: &str, url: &str, page: CachedPage) {
+        let key = (token_key(token), url.to_string());
+        let mut state = self.state.lock().unwrap();
+        if state.pages.insert(key.clone(), page).is_none() {
+            state.page_order.push_back(key);
+        }
+        while state.page_order.len() > MAX_CACHED_PAGES {
+            if let Some(oldest) = state.page_order.pop_front() {
+                state.pages.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn tracks_quota_and_backs_off_until_reset() {
+        let now = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let limits = GitHubRateLimits::default();
+        let exhausted = headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1700000030"),
+        ]);
+
+        limits.record("token-a", &exhausted);
+        let reset_at = now + chrono::Duration::seconds(30);
+        assert_eq!(limits.exhausted_until("token-a", now), Some(reset_at));
+        assert_eq!(limits.exhausted_until("token-b", now), None);
+        assert_eq!(backoff_until(reset_at, now), Some(Duration::from_secs(30)));
+        assert_eq!(backoff_until(now + chrono::Duration::hours(1), now), None);
+
+        assert_eq!(
+            retry_at(StatusCode::FORBIDDEN, &exhausted, now),
+            Some(reset_at)
+        );
+        let secondary = headers(&[("retry-after", "5")]);
+        assert_eq!(
+            retry_at(StatusCode::FORBIDDEN, &secondary, now),
+            Some(now + chrono::Duration::seconds(5))
+        );
+        assert_eq!(
+            retry_at(StatusCode::FORBIDDEN, &HeaderMap::new(), now),
+            None
+        );
+        assert_eq!(retry_at(StatusCode::OK, &exhausted, now), None);
+    }
+
+    #[test]
+    fn evicts_oldest_cached_pages() {
+        let limits = GitHubRateLimits::default();
+        for page in 0..=MAX_CACHED_PAGES {
+            limits.store_page(
+                "token",
+                &format!("https://api.github.com/repos/o/r/issues?page={page}"),
+                CachedPage {
+                    etag: format!("\"{page}\""),
+                    body: "[]".to_string(),
+                },
+            );
+        }
+
+        assert!(
+            limits
+                .cached_page("token", "https://api.github.com/repos/o/r/issues?page=0")
+                .is_none()
+        );
+        let last = limits
+            .cached_page(
+                "token",
+                &format!("https://api.github.com/repos/o/r/issues?page={MAX_CACHED_PAGES}"),
+            )
+            .unwrap();
+        assert_eq!(last.etag, format!("\"{MAX_CACHED_PAGES}\""));
+        assert!(
+            limits
+                .cached_page("other", "https://api.github.com/repos/o/r/issues?page=1")
+                .is_none()
+        );
+    }
+}
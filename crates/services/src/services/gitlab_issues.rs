@@ -1,19 +1,25 @@
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use db::models::task::TaskStatus;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use ts_rs::TS;
 
-const GITLAB_API_BASE: &str = "https://gitlab.com/api/v4";
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const GITLAB_COM_API_BASE: &str = "https://gitlab.com/api/v4";
 
 #[derive(Debug, Error)]
 pub enum GitLabIssuesError {
     #[error("HTTP request failed: {0}")]
     Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
     #[error("GitLab API error: {status} - {message}")]
     Api { status: u16, message: String },
     #[error("Invalid project URL format: {0}")]
     InvalidProjectUrl(String),
+    #[error("Invalid GitLab instance URL: {0}")]
+    InvalidBaseUrl(String),
     #[error("Authentication required")]
     AuthRequired,
 }
@@ -47,6 +53,60 @@ pub struct GitLabMilestone {
     pub iid: i64,
 }
 
+/// The fields of a created merge request that get stored on the workspace
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabMergeRequestInfo {
+    pub iid: i64,
+    pub web_url: String,
+    pub state: String,
+    /// Latest pipeline of the source branch; missing right after creation
+    #[serde(default)]
+    pub head_pipeline: Option<GitLabPipeline>,
+    /// What stands between the merge request and merging, e.g. `not_approved`
+    #[serde(default)]
+    pub detailed_merge_status: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GitLabPipeline {
+    pub status: String,
+    #[serde(default)]
+    pub web_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateGitLabMergeRequest {
+    pub source_branch: String,
+    pub target_branch: String,
+    pub title: String,
+    pub description: String,
+    /// Comma-separated, as the GitLab API expects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<String>,
+    pub remove_source_branch: bool,
+}
+
+/// Used for merge request descriptions when no template is configured
+pub const DEFAULT_MR_DESCRIPTION_TEMPLATE: &str = "{task_description}\n\n---\nMerges `{branch}` into `{target_branch}` for task \"{task_title}\".";
+
+/// Fill a merge request description template. Supports `{task_title}`,
+/// `{task_description}`, `{branch}` and `{target_branch}`.
+pub fn render_mr_description(
+    template: &str,
+    task_title: &str,
+    task_description: Option<&str>,
+    branch: &str,
+    target_branch: &str,
+) -> String {
+    template
+        .replace("{task_title}", task_title)
+        .replace("{task_description}", task_description.unwrap_or_default())
+        .replace("{branch}", branch)
+        .replace("{target_branch}", target_branch)
+        .trim()
+        .to_string()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 pub struct ListGitLabIssuesParams {
     pub state: Option<String>,
@@ -71,16 +131,42 @@ impl Default for ListGitLabIssuesParams {
 }
 
 pub struct GitLabIssuesService {
-    client: Client,
+    http: HttpClient,
+    api_base: String,
 }
 
 impl GitLabIssuesService {
+    /// Client for gitlab.com
     pub fn new() -> Self {
         Self {
-            client: Client::new(),
+            http: HttpClient::shared(),
+            api_base: GITLAB_COM_API_BASE.to_string(),
+        }
+    }
+
+    /// Client for a project's GitLab instance, gitlab.com when none is configured
+    pub fn for_instance(base_url: Option<&str>) -> Result<Self, GitLabIssuesError> {
+        match base_url.map(str::trim).filter(|url| !url.is_empty()) {
+            Some(base_url) => Ok(Self {
+                http: HttpClient::shared(),
+                api_base: Self::api_base(base_url)?,
+            }),
+            None => Ok(Self::new()),
+        }
+    }
+
+    /// API root for an instance, e.g. `https://gitlab.example.com/api/v4`
+    pub fn api_base(base_url: &str) -> Result<String, GitLabIssuesError> {
+        let base = base_url.trim().trim_end_matches('/');
+        if !base.starts_with("http://") && !base.starts_with("https://") {
+            return Err(GitLabIssuesError::InvalidBaseUrl(base_url.to_string()));
         }
+        let base = base.strip_suffix("/api/v4").unwrap_or(base);
+        Ok(format!("{base}/api/v4"))
     }
 
+    /// URL-encoded project path from a `group/project` path or a project URL on
+    /// any GitLab host, including SSH remotes
     pub fn parse_project_url(url: &str) -> Result<String, GitLabIssuesError> {
         let url = url.trim();
 
@@ -92,18 +178,27 @@ impl GitLabIssuesService {
             return Ok(urlencoding::encode(url).to_string());
         }
 
-        let re = regex::Regex::new(r"gitlab\.com[:/](?P<path>.+?)(?:\.git)?(?:/)?$")
-            .map_err(|_| GitLabIssuesError::InvalidProjectUrl(url.to_string()))?;
-
-        if let Some(caps) = re.captures(url) {
-            let path = caps
-                .name("path")
-                .map(|m| m.as_str().trim_end_matches('/').to_string())
-                .ok_or_else(|| GitLabIssuesError::InvalidProjectUrl(url.to_string()))?;
-            return Ok(urlencoding::encode(&path).to_string());
-        }
+        let path = match url.split_once("://") {
+            Some((_, rest)) => rest.split_once('/').map(|(_, path)| path),
+            // scp-like `git@host:group/project.git`
+            None => url.split_once(':').map(|(_, path)| path),
+        };
+        let path = path
+            .map(|path| path.trim_end_matches('/'))
+            .map(|path| path.strip_suffix(".git").unwrap_or(path))
+            .filter(|path| path.contains('/'))
+            .ok_or_else(|| GitLabIssuesError::InvalidProjectUrl(url.to_string()))?;
+        Ok(urlencoding::encode(path).to_string())
+    }
 
-        Err(GitLabIssuesError::InvalidProjectUrl(url.to_string()))
+    /// Project path of a git remote, when the remote is hosted on this
+    /// client's GitLab instance
+    pub fn remote_project_path(&self, remote_url: &str) -> Option<String> {
+        let instance_host = url_host(&self.api_base)?;
+        url_host(remote_url)
+            .is_some_and(|host| host.eq_ignore_ascii_case(instance_host))
+            .then(|| Self::parse_project_url(remote_url).ok())
+            .flatten()
     }
 
     pub async fn list_issues(
@@ -112,10 +207,10 @@ impl GitLabIssuesService {
         project_path: &str,
         params: &ListGitLabIssuesParams,
     ) -> Result<Vec<GitLabIssue>, GitLabIssuesError> {
-        let url = format!("{}/projects/{}/issues", GITLAB_API_BASE, project_path);
+        let url = format!("{}/projects/{}/issues", self.api_base, project_path);
 
         let mut request = self
-            .client
+            .http
             .get(&url)
             .header("PRIVATE-TOKEN", token)
             .header("Accept", "application/json")
@@ -140,7 +235,7 @@ impl GitLabIssuesService {
             request = request.query(&[("page", page.to_string())]);
         }
 
-        let response = request.send().await?;
+        let response = request.send_via(&self.http).await?;
         let status = response.status();
 
         if !status.is_success() {
@@ -166,16 +261,16 @@ impl GitLabIssuesService {
     ) -> Result<GitLabIssue, GitLabIssuesError> {
         let url = format!(
             "{}/projects/{}/issues/{}",
-            GITLAB_API_BASE, project_path, issue_iid
+            self.api_base, project_path, issue_iid
         );
 
         let response = self
-            .client
+            .http
             .get(&url)
             .header("PRIVATE-TOKEN", token)
             .header("Accept", "application/json")
             .header("User-Agent", "vibe-kanban")
-            .send()
+            .send_via(&self.http)
             .await?;
 
         let status = response.status();
@@ -194,6 +289,222 @@ impl GitLabIssuesService {
         let issue: GitLabIssue = response.json().await?;
         Ok(issue)
     }
+
+    /// Change an issue's state and labels
+    pub async fn update_issue(
+        &self,
+        token: &str,
+        project_path: &str,
+        issue_iid: i64,
+        update: &UpdateGitLabIssue,
+    ) -> Result<(), GitLabIssuesError> {
+        let url = format!(
+            "{}/projects/{}/issues/{}",
+            self.api_base, project_path, issue_iid
+        );
+
+        let response = self
+            .http
+            .put(&url)
+            .header("PRIVATE-TOKEN", token)
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .json(update)
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitLabIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub async fn create_merge_request(
+        &self,
+        token: &str,
+        project_path: &str,
+        request: &CreateGitLabMergeRequest,
+    ) -> Result<GitLabMergeRequestInfo, GitLabIssuesError> {
+        let url = format!("{}/projects/{}/merge_requests", self.api_base, project_path);
+
+        let response = self
+            .http
+            .post(&url)
+            .header("PRIVATE-TOKEN", token)
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .json(request)
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitLabIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let merge_request: GitLabMergeRequestInfo = response.json().await?;
+        Ok(merge_request)
+    }
+
+    pub async fn get_merge_request(
+        &self,
+        token: &str,
+        project_path: &str,
+        mr_iid: i64,
+    ) -> Result<GitLabMergeRequestInfo, GitLabIssuesError> {
+        let url = format!(
+            "{}/projects/{}/merge_requests/{}",
+            self.api_base, project_path, mr_iid
+        );
+
+        let response = self
+            .http
+            .get(&url)
+            .header("PRIVATE-TOKEN", token)
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitLabIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Post a comment (a note, in GitLab's terms) on an issue
+    pub async fn add_issue_note(
+        &self,
+        token: &str,
+        project_path: &str,
+        issue_iid: i64,
+        body: &str,
+    ) -> Result<(), GitLabIssuesError> {
+        let url = format!(
+            "{}/projects/{}/issues/{}/notes",
+            self.api_base, project_path, issue_iid
+        );
+
+        let response = self
+            .http
+            .post(&url)
+            .header("PRIVATE-TOKEN", token)
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .json(&serde_json::json!({ "body": body }))
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitLabIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// API URL of a file uploaded to the project, for an upload an issue
+    /// description embeds: `/uploads/<secret>/<filename>`, relative or on this
+    /// client's instance
+    pub fn upload_api_url(&self, project_path: &str, url: &str) -> Option<String> {
+        let path = if url.starts_with('/') {
+            url
+        } else {
+            let instance_host = url_host(&self.api_base)?;
+            if !url_host(url).is_some_and(|host| host.eq_ignore_ascii_case(instance_host)) {
+                return None;
+            }
+            let rest = url.split_once("://")?.1;
+            &rest[rest.find('/')?..]
+        };
+        let (_, upload) = path.split_once("/uploads/")?;
+        let (secret, filename) = upload.split_once('/')?;
+        (!secret.is_empty() && !filename.is_empty() && !filename.contains('/')).then(|| {
+            format!(
+                "{}/projects/{}/uploads/{}/{}",
+                self.api_base, project_path, secret, filename
+            )
+        })
+    }
+
+    /// Download a project upload from its [`Self::upload_api_url`]
+    pub async fn download_upload(
+        &self,
+        token: &str,
+        upload_api_url: &str,
+    ) -> Result<Vec<u8>, GitLabIssuesError> {
+        let response = self
+            .http
+            .get(upload_api_url)
+            .header("PRIVATE-TOKEN", token)
+            .header("User-Agent", "vibe-kanban")
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(GitLabIssuesError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(response.bytes().await?.to_vec())
+    }
+}
+
+/// Host of an http(s), ssh or scp-like URL, without user or port
+fn url_host(url: &str) -> Option<&str> {
+    let authority = match url.split_once("://") {
+        Some((_, rest)) => rest.split('/').next()?,
+        None => url.split_once(':')?.0,
+    };
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let host = host.split(':').next()?;
+    (!host.is_empty()).then_some(host)
 }
 
 impl Default for GitLabIssuesService {
@@ -201,3 +512,152 @@ impl Default for GitLabIssuesService {
         Self::new()
     }
 }
+
+/// Label marking issues whose task is in review
+pub const IN_REVIEW_LABEL: &str = "in-review";
+
+/// Changes to apply with [`GitLabIssuesService::update_issue`]. Labels are
+/// comma-separated; unset fields are left alone.
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct UpdateGitLabIssue {
+    /// `close` or `reopen`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_event: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub add_labels: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remove_labels: Option<String>,
+}
+
+impl UpdateGitLabIssue {
+    /// The issue change that mirrors a task's status: closed once the task is
+    /// finished, open otherwise, and labelled while the task is in review
+    pub fn for_task_status(status: &TaskStatus) -> Self {
+        let (state_event, in_review) = match status {
+            TaskStatus::Done | TaskStatus::Cancelled => ("close", false),
+            TaskStatus::InReview => ("reopen", true),
+            TaskStatus::Todo | TaskStatus::InProgress => ("reopen", false),
+        };
+        let label = Some(IN_REVIEW_LABEL.to_string());
+        Self {
+            state_event: Some(state_event.to_string()),
+            add_labels: if in_review { label.clone() } else { None },
+            remove_labels: if in_review { None } else { label },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_default_mr_template() {
+        let description = render_mr_description(
+            DEFAULT_MR_DESCRIPTION_TEMPLATE,
+            "Fix login",
+            Some("Users get logged out on refresh"),
+            "vk/1a2b-fix-login",
+            "main",
+        );
+        assert_eq!(
+            description,
+            "Users get logged out on refresh\n\n---\nMerges `vk/1a2b-fix-login` into `main` for task \"Fix login\"."
+        );
+    }
+
+    #[test]
+    fn trims_when_task_has_no_description() {
+        let description = render_mr_description(
+            "{task_description}\n\nCloses {task_title}",
+            "Bug",
+            None,
+            "b",
+            "main",
+        );
+        assert_eq!(description, "Closes Bug");
+    }
+
+    #[test]
+    fn resolves_uploads_on_the_instance_only() {
+        let service =
+            GitLabIssuesService::for_instance(Some("https://gitlab.example.com")).unwrap();
+        let expected = Some(
+            "https://gitlab.example.com/api/v4/projects/group%2Fapp/uploads/0a1b/screen.png"
+                .to_string(),
+        );
+
+        assert_eq!(
+            service.upload_api_url("group%2Fapp", "/uploads/0a1b/screen.png"),
+            expected
+        );
+        assert_eq!(
+            service.upload_api_url(
+                "group%2Fapp",
+                "https://gitlab.example.com/group/app/uploads/0a1b/screen.png"
+            ),
+            expected
+        );
+        assert_eq!(
+            service.upload_api_url("group%2Fapp", "https://cdn.example.net/uploads/0a1b/x.png"),
+            None
+        );
+        assert_eq!(service.upload_api_url("group%2Fapp", "/uploads/0a1b"), None);
+    }
+
+    #[test]
+    fn mirrors_task_status_on_the_issue() {
+        let in_review = UpdateGitLabIssue::for_task_status(&TaskStatus::InReview);
+        assert_eq!(in_review.state_event.as_deref(), Some("reopen"));
+        assert_eq!(in_review.add_labels.as_deref(), Some(IN_REVIEW_LABEL));
+        assert_eq!(in_review.remove_labels, None);
+
+        let done = UpdateGitLabIssue::for_task_status(&TaskStatus::Done);
+        assert_eq!(done.state_event.as_deref(), Some("close"));
+        assert_eq!(done.remove_labels.as_deref(), Some(IN_REVIEW_LABEL));
+
+        let todo = UpdateGitLabIssue::for_task_status(&TaskStatus::Todo);
+        assert_eq!(todo.state_event.as_deref(), Some("reopen"));
+        assert_eq!(todo.add_labels, None);
+        assert_eq!(
+            serde_json::to_value(&todo).unwrap(),
+            serde_json::json!({ "state_event": "reopen", "remove_labels": "in-review" })
+        );
+    }
+
+    #[test]
+    fn resolves_projects_on_self_hosted_instances() {
+        assert_eq!(
+            GitLabIssuesService::api_base("https://git.example.com/").unwrap(),
+            "https://git.example.com/api/v4"
+        );
+        assert!(GitLabIssuesService::api_base("git.example.com").is_err());
+        assert_eq!(
+            GitLabIssuesService::parse_project_url("https://git.example.com/acme/platform/api.git")
+                .unwrap(),
+            "acme%2Fplatform%2Fapi"
+        );
+        assert_eq!(
+            GitLabIssuesService::parse_project_url("git@gitlab.com:acme/api.git").unwrap(),
+            "acme%2Fapi"
+        );
+
+        let service = GitLabIssuesService::for_instance(Some("https://git.example.com")).unwrap();
+        assert_eq!(
+            service
+                .remote_project_path("ssh://git@git.example.com:2222/acme/api.git")
+                .as_deref(),
+            Some("acme%2Fapi")
+        );
+        assert_eq!(
+            service.remote_project_path("https://github.com/acme/api.git"),
+            None
+        );
+        assert_eq!(
+            GitLabIssuesService::new()
+                .remote_project_path("git@gitlab.com:acme/api.git")
+                .as_deref(),
+            Some("acme%2Fapi")
+        );
+    }
+}
@@ -0,0 +1,417 @@
+//! Shared outbound HTTP client for integrations: issue trackers, REST hook
+//! deliveries, analytics and the share publisher.
+//!
+//! Every request goes through one connection pool with the same timeouts and
+//! proxy, and is admitted per host. At most `max_concurrent_per_host` requests
+//! run against a host at once, and one of those slots is always kept for
+//! interactive requests so background work (syncs, hook deliveries) can't starve
+//! the UI. After `failure_threshold` consecutive connection failures, timeouts or
+//! 5xx responses a host's circuit opens and its requests fail fast until
+//! `circuit_open_for` has passed; the requests after that probe the host again.
+//!
+//! The proxy and extra trusted CA certificates come from the network settings,
+//! falling back to `VK_HTTP_PROXY` and `VK_CA_CERTIFICATES`. Saving new settings
+//! swaps the shared client, so requests made after that use them.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant},
+};
+
+use reqwest::{Certificate, Client, IntoUrl, Method, Proxy, RequestBuilder, Response};
+use thiserror::Error;
+use tokio::sync::Semaphore;
+
+use crate::services::config::NetworkConfig;
+
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    pub max_concurrent_per_host: usize,
+    /// Proxy for all outbound requests. Without one, the standard
+    /// `HTTP_PROXY`/`HTTPS_PROXY` variables still apply.
+    pub proxy: Option<String>,
+    /// PEM file of CA certificates trusted in addition to the system roots
+    pub ca_certificates: Option<PathBuf>,
+    pub failure_threshold: u32,
+    pub circuit_open_for: Duration,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            max_concurrent_per_host: 8,
+            proxy: None,
+            ca_certificates: None,
+            failure_threshold: 5,
+            circuit_open_for: Duration::from_secs(30),
+        }
+    }
+}
+
+fn env_parse<T: FromStr>(name: &str) -> Option<T> {
+    std::env::var(name).ok()?.trim().parse().ok()
+}
+
+impl HttpClientConfig {
+    /// Defaults, overridden by the `VK_HTTP_*` environment variables
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            timeout: env_parse("VK_HTTP_TIMEOUT_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.timeout),
+            connect_timeout: env_parse("VK_HTTP_CONNECT_TIMEOUT_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.connect_timeout),
+            max_concurrent_per_host: env_parse("VK_HTTP_MAX_CONCURRENT_PER_HOST")
+                .filter(|&max: &usize| max >= 2)
+                .unwrap_or(defaults.max_concurrent_per_host),
+            proxy: std::env::var("VK_HTTP_PROXY")
+                .ok()
+                .filter(|proxy| !proxy.trim().is_empty()),
+            ca_certificates: std::env::var("VK_CA_CERTIFICATES")
+                .ok()
+                .filter(|path| !path.trim().is_empty())
+                .map(PathBuf::from),
+            failure_threshold: env_parse("VK_HTTP_CIRCUIT_FAILURES")
+                .filter(|&failures: &u32| failures > 0)
+                .unwrap_or(defaults.failure_threshold),
+            circuit_open_for: env_parse("VK_HTTP_CIRCUIT_OPEN_SECS")
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.circuit_open_for),
+        }
+    }
+
+    /// Environment defaults with the proxy and CA certificates from the network
+    /// settings, where set
+    pub fn with_network(mut self, network: &NetworkConfig) -> Self {
+        if let Some(proxy) = network
+            .http_proxy
+            .as_deref()
+            .map(str::trim)
+            .filter(|proxy| !proxy.is_empty())
+        {
+            self.proxy = Some(proxy.to_string());
+        }
+        if let Some(path) = network
+            .ca_certificates_path
+            .as_deref()
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+        {
+            self.ca_certificates = Some(PathBuf::from(path));
+        }
+        self
+    }
+
+    /// Check the proxy URL parses and the CA file holds at least one certificate
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(proxy) = &self.proxy {
+            Proxy::all(proxy).map_err(|e| format!("Invalid proxy URL {proxy}: {e}"))?;
+        }
+        if let Some(path) = &self.ca_certificates {
+            load_ca_certificates(path)?;
+        }
+        Ok(())
+    }
+}
+
+fn load_ca_certificates(path: &Path) -> Result<Vec<Certificate>, String> {
+    let pem = std::fs::read(path)
+        .map_err(|e| format!("Failed to read CA certificates {}: {e}", path.display()))?;
+    let certificates = Certificate::from_pem_bundle(&pem)
+        .map_err(|e| format!("Invalid CA certificates in {}: {e}", path.display()))?;
+    if certificates.is_empty() {
+        return Err(format!("No CA certificates found in {}", path.display()));
+    }
+    Ok(certificates)
+}
+
+/// Interactive requests answer something the user is waiting on; background
+/// requests never take a host's last free slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Interactive,
+    Background,
+}
+
+#[derive(Debug, Error)]
+pub enum HttpClientError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("{host} is unavailable after repeated failures; retrying in {}s", retry_in.as_secs().max(1))]
+    CircuitOpen { host: String, retry_in: Duration },
+}
+
+impl HttpClientError {
+    /// Timeouts and connection failures, which may succeed when retried
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Request(e) if e.is_timeout() || e.is_connect())
+    }
+
+    /// The request never reached the host
+    pub fn is_connect(&self) -> bool {
+        match self {
+            Self::Request(e) => e.is_connect(),
+            Self::CircuitOpen { .. } => true,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct CircuitBreaker {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Time left before the host may be tried again, if its circuit is open
+    fn check(&self, now: Instant) -> Result<(), Duration> {
+        match self.open_until {
+            Some(until) if now < until => Err(until - now),
+            _ => Ok(()),
+        }
+    }
+
+    fn record(&mut self, succeeded: bool, now: Instant, config: &HttpClientConfig) {
+        if succeeded {
+            *self = Self::default();
+            return;
+        }
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= config.failure_threshold {
+            self.open_until = Some(now + config.circuit_open_for);
+        }
+    }
+}
+
+#[derive(Debug)]
+struct HostState {
+    /// Every request takes one of these
+    slots: Semaphore,
+    /// Background requests also take one of these, one fewer than `slots`
+    background_slots: Semaphore,
+    breaker: Mutex<CircuitBreaker>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpClient {
+    client: Client,
+    config: Arc<HttpClientConfig>,
+    hosts: Arc<Mutex<HashMap<String, Arc<HostState>>>>,
+    priority: Priority,
+}
+
+static SHARED: RwLock<Option<HttpClient>> = RwLock::new(None);
+
+impl HttpClient {
+    pub fn new(config: HttpClientConfig) -> Self {
+        let mut builder = Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .user_agent(concat!("vibe-kanban/", env!("CARGO_PKG_VERSION")));
+        if let Some(proxy) = &config.proxy {
+            match Proxy::all(proxy) {
+                Ok(proxy) => builder = builder.proxy(proxy),
+                Err(e) => tracing::warn!("Ignoring invalid HTTP proxy {}: {}", proxy, e),
+            }
+        }
+        if let Some(path) = &config.ca_certificates {
+            match load_ca_certificates(path) {
+                Ok(certificates) => {
+                    for certificate in certificates {
+                        builder = builder.add_root_certificate(certificate);
+                    }
+                }
+                Err(e) => tracing::warn!("Ignoring extra CA certificates: {}", e),
+            }
+        }
+        let client = builder.build().unwrap_or_else(|e| {
+            tracing::error!("Failed to build HTTP client, using defaults: {}", e);
+            Client::new()
+        });
+        Self {
+            client,
+            config: Arc::new(config),
+            hosts: Arc::new(Mutex::new(HashMap::new())),
+            priority: Priority::Interactive,
+        }
+    }
+
+    /// Install the client every integration shares, replacing the current one.
+    /// Requests already in flight finish on the old client.
+    pub fn configure(config: HttpClientConfig) {
+        *SHARED.write().unwrap() = Some(Self::new(config));
+    }
+
+    /// The client installed by [`HttpClient::configure`], or one configured from
+    /// the environment if it hasn't been called
+    pub fn shared() -> Self {
+        if let Some(client) = SHARED.read().unwrap().as_ref() {
+            return client.clone();
+        }
+        SHARED
+            .write()
+            .unwrap()
+            .get_or_insert_with(|| Self::new(HttpClientConfig::from_env()))
+            .clone()
+    }
+
+    /// The same pool, sending at background priority
+    pub fn background(&self) -> Self {
+        Self {
+            priority: Priority::Background,
+            ..self.clone()
+        }
+    }
+
+    pub fn request<U: IntoUrl>(&self, method: Method, url: U) -> RequestBuilder {
+        self.client.request(method, url)
+    }
+
+    pub fn get<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.put(url)
+    }
+
+    pub fn patch<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.patch(url)
+    }
+
+    pub fn delete<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.delete(url)
+    }
+
+    fn host_state(&self, host: &str) -> Arc<HostState> {
+        let max = self.config.max_concurrent_per_host;
+        self.hosts
+            .lock()
+            .unwrap()
+            .entry(host.to_string())
+            .or_insert_with(|| {
+                Arc::new(HostState {
+                    slots: Semaphore::new(max),
+                    background_slots: Semaphore::new(max - 1),
+                    breaker: Mutex::new(CircuitBreaker::default()),
+                })
+            })
+            .clone()
+    }
+
+    /// Send a request once its host has a free slot and its circuit is closed
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, HttpClientError> {
+        let request = request.build()?;
+        let url = request.url();
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+        let state = self.host_state(&host);
+
+        let check = |state: &HostState| state.breaker.lock().unwrap().check(Instant::now());
+        check(&state).map_err(|retry_in| HttpClientError::CircuitOpen {
+            host: host.clone(),
+            retry_in,
+        })?;
+
+        let _background_slot = match self.priority {
+            Priority::Background => Some(
+                state
+                    .background_slots
+                    .acquire()
+                    .await
+                    .expect("host semaphores are never closed"),
+            ),
+            Priority::Interactive => None,
+        };
+        let _slot = state
+            .slots
+            .acquire()
+            .await
+            .expect("host semaphores are never closed");
+
+        // The circuit may have opened while this request was queued
+        check(&state).map_err(|retry_in| HttpClientError::CircuitOpen {
+            host: host.clone(),
+            retry_in,
+        })?;
+
+        let result = self.client.execute(request).await;
+        let succeeded = match &result {
+            Ok(response) => !response.status().is_server_error(),
+            Err(e) => !(e.is_timeout() || e.is_connect()),
+        };
+        state
+            .breaker
+            .lock()
+            .unwrap()
+            .record(succeeded, Instant::now(), &self.config);
+        if !succeeded {
+            tracing::debug!("Outbound request to {} failed", host);
+        }
+        Ok(result?)
+    }
+}
+
+/// `request.send_via(&http)` in place of `request.send()`, so a request built
+/// with the builder methods goes through the shared pool's limits
+pub trait SendVia {
+    fn send_via(
+        self,
+        http: &HttpClient,
+    ) -> impl Future<Output = Result<Response, HttpClientError>> + Send;
+}
+
+impl SendVia for RequestBuilder {
+    fn send_via(
+        self,
+        http: &HttpClient,
+    ) -> impl Future<Output = Result<Response, HttpClientError>> + Send {
+        http.send(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circuit_opens_after_consecutive_failures_and_resets_on_success() {
+        let config = HttpClientConfig {
+            failure_threshold: 2,
+            circuit_open_for: Duration::from_secs(30),
+            ..HttpClientConfig::default()
+        };
+        let now = Instant::now();
+        let mut breaker = CircuitBreaker::default();
+
+        breaker.record(false, now, &config);
+        assert!(breaker.check(now).is_ok());
+        breaker.record(true, now, &config);
+        breaker.record(false, now, &config);
+        assert!(breaker.check(now).is_ok());
+
+        breaker.record(false, now, &config);
+        assert_eq!(breaker.check(now), Err(Duration::from_secs(30)));
+        assert!(breaker.check(now + Duration::from_secs(31)).is_ok());
+
+        // A failed probe reopens the circuit straight away
+        breaker.record(false, now + Duration::from_secs(31), &config);
+        assert!(breaker.check(now + Duration::from_secs(32)).is_err());
+    }
+}
@@ -141,6 +141,10 @@ impl ImageService {
         Ok(())
     }
 
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
     pub fn get_absolute_path(&self, image: &Image) -> PathBuf {
         self.cache_dir.join(&image.file_path)
     }
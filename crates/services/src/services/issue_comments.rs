@@ -0,0 +1,236 @@
+//! Progress comments on the GitHub, GitLab or Vortex issue a task was imported
+//! from, posted when one of its attempts finishes so issue followers can keep up
+//! without joining the board.
+
+use db::{
+    DBService,
+    models::{
+        bitbucket_pull_request::BitbucketPullRequest,
+        coding_agent_turn::CodingAgentTurn,
+        execution_process::{
+            ExecutionContext, ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus,
+        },
+        gitlab_merge_request::GitLabMergeRequest,
+        merge::Merge,
+        workspace_repo::WorkspaceRepo,
+    },
+};
+use sqlx::SqlitePool;
+use utils::diff::compute_line_change_counts;
+
+use crate::services::{
+    diff_exclusions::DiffExclusions,
+    git::{DiffTarget, GitService},
+    issue_provider::IssueProviderRegistry,
+};
+
+/// What an attempt produced, as reported on the linked issue
+#[derive(Debug, Clone, Default)]
+pub struct AttemptReport {
+    pub succeeded: bool,
+    pub branch: String,
+    pub files_changed: usize,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Pull and merge requests opened from the attempt's branch
+    pub review_urls: Vec<String>,
+    /// The agent's final message from its last run
+    pub agent_summary: Option<String>,
+}
+
+impl AttemptReport {
+    pub fn to_markdown(&self) -> String {
+        let outcome = if self.succeeded {
+            "An agent finished working on this issue."
+        } else {
+            "An agent attempt on this issue failed."
+        };
+        let mut comment = format!(
+            "{}\n\nBranch: `{}`\nChanges: {} file{} changed, +{} -{}",
+            outcome,
+            self.branch,
+            self.files_changed,
+            if self.files_changed == 1 { "" } else { "s" },
+            self.additions,
+            self.deletions
+        );
+        match self.review_urls.as_slice() {
+            [] => {}
+            [url] => comment.push_str(&format!("\nPull request: {url}")),
+            urls => {
+                comment.push_str("\nPull requests:");
+                for url in urls {
+                    comment.push_str(&format!("\n- {url}"));
+                }
+            }
+        }
+        if let Some(summary) = &self.agent_summary {
+            comment.push_str("\n\n**Agent summary**\n");
+            for line in summary.lines() {
+                comment.push_str(&format!("\n> {line}"));
+            }
+        }
+        comment
+    }
+}
+
+/// Build the report for a finished attempt. Diff stats compare each repo's
+/// attempt branch against its target branch; repos that can't be diffed and
+/// the project's excluded paths are left out of the totals.
+async fn attempt_report(db: &DBService, git: &GitService, ctx: &ExecutionContext) -> AttemptReport {
+    let pool = &db.pool;
+    let mut report = AttemptReport {
+        succeeded: ctx.execution_process.status == ExecutionProcessStatus::Completed,
+        branch: ctx.workspace.branch.clone(),
+        ..Default::default()
+    };
+
+    let repos = WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, ctx.workspace.id)
+        .await
+        .unwrap_or_default();
+    let exclusions = DiffExclusions::for_project(pool, ctx.project.id)
+        .await
+        .unwrap_or_default();
+    for repo in repos {
+        let diffs = git.get_diffs(
+            DiffTarget::Branch {
+                repo_path: &repo.repo.path,
+                branch_name: &ctx.workspace.branch,
+                base_branch: &repo.target_branch,
+            },
+            None,
+        );
+        let Ok(mut diffs) = diffs else {
+            continue;
+        };
+        exclusions.retain(&mut diffs);
+        for diff in diffs {
+            report.files_changed += 1;
+            let (additions, deletions) = match (diff.additions, diff.deletions) {
+                (Some(additions), Some(deletions)) => (additions, deletions),
+                _ => compute_line_change_counts(
+                    diff.old_content.as_deref().unwrap_or_default(),
+                    diff.new_content.as_deref().unwrap_or_default(),
+                ),
+            };
+            report.additions += additions;
+            report.deletions += deletions;
+        }
+    }
+
+    for merge in Merge::find_by_workspace_id(pool, ctx.workspace.id)
+        .await
+        .unwrap_or_default()
+    {
+        if let Merge::Pr(pr) = merge {
+            report.review_urls.push(pr.pr_info.url);
+        }
+    }
+    for mr in GitLabMergeRequest::find_by_workspace_id(pool, ctx.workspace.id)
+        .await
+        .unwrap_or_default()
+    {
+        report.review_urls.push(mr.web_url);
+    }
+    for pr in BitbucketPullRequest::find_by_workspace_id(pool, ctx.workspace.id)
+        .await
+        .unwrap_or_default()
+    {
+        report.review_urls.push(pr.web_url);
+    }
+
+    report.agent_summary = last_agent_summary(pool, ctx).await;
+
+    report
+}
+
+/// The final message of the attempt's latest coding agent run. The run that
+/// finished may be a cleanup script, so it is looked up by workspace.
+async fn last_agent_summary(pool: &SqlitePool, ctx: &ExecutionContext) -> Option<String> {
+    let process = ExecutionProcess::find_latest_by_workspace_and_run_reason(
+        pool,
+        ctx.workspace.id,
+        &ExecutionProcessRunReason::CodingAgent,
+    )
+    .await
+    .ok()??;
+    CodingAgentTurn::find_by_execution_process_id(pool, process.id)
+        .await
+        .ok()??
+        .summary
+        .filter(|summary| !summary.trim().is_empty())
+}
+
+/// Comment on the task's linked issue, if the project has comments enabled and
+/// the provider is configured. Failures are logged; they never affect the attempt.
+pub async fn post_attempt_comment(
+    db: &DBService,
+    git: &GitService,
+    providers: &IssueProviderRegistry,
+    ctx: &ExecutionContext,
+) {
+    let project = &ctx.project;
+    if !project.comment_on_linked_issues {
+        return;
+    }
+    let (provider, issue_id) = match providers.linked(project, ctx.task.id).await {
+        Ok(Some(linked)) => linked,
+        Ok(None) => return,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to reach the linked issue of task {}: {}",
+                ctx.task.id,
+                e
+            );
+            return;
+        }
+    };
+
+    let comment = attempt_report(db, git, ctx).await.to_markdown();
+    if let Err(e) = provider.add_comment(&issue_id, &comment).await {
+        tracing::warn!(
+            "Failed to comment on linked {} issue {} for task {}: {}",
+            provider.kind().name(),
+            issue_id,
+            ctx.task.id,
+            e
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_branch_stats_and_pull_requests() {
+        let report = AttemptReport {
+            succeeded: true,
+            branch: "vk/1a2b-fix-login".to_string(),
+            files_changed: 1,
+            additions: 12,
+            deletions: 3,
+            review_urls: vec!["https://github.com/acme/app/pull/9".to_string()],
+            agent_summary: None,
+        };
+        assert_eq!(
+            report.to_markdown(),
+            "An agent finished working on this issue.\n\nBranch: `vk/1a2b-fix-login`\nChanges: 1 file changed, +12 -3\nPull request: https://github.com/acme/app/pull/9"
+        );
+    }
+
+    #[test]
+    fn report_quotes_the_agent_summary() {
+        let report = AttemptReport {
+            succeeded: true,
+            branch: "vk/1a2b-fix-login".to_string(),
+            agent_summary: Some("Fixed the redirect.\nAdded a test.".to_string()),
+            ..Default::default()
+        };
+        assert!(
+            report
+                .to_markdown()
+                .ends_with("**Agent summary**\n\n> Fixed the redirect.\n> Added a test.")
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Images embedded in issue bodies. Trackers host them behind the issue's
+//! permissions, so imported tasks get local copies and their markdown is
+//! pointed at those instead.
+
+use std::{collections::HashMap, sync::LazyLock};
+
+use regex::{Captures, Regex};
+
+/// `![alt](url)` and `![alt](url "title")`
+static MARKDOWN_IMAGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"!\[[^\]]*\]\(\s*<?(?P<url>[^\s)>]+)>?(?:\s+"[^"]*")?\s*\)"#).unwrap()
+});
+
+/// `<img src="url">` tags, which GitHub writes for resized screenshots
+static HTML_IMAGE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"<img\b[^>]*?\bsrc\s*=\s*["'](?P<url>[^"']+)["'][^>]*>"#).unwrap()
+});
+
+/// URLs of the images embedded in a markdown body, in order of appearance and
+/// without duplicates
+pub fn embedded_image_urls(body: &str) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for regex in [&*MARKDOWN_IMAGE, &*HTML_IMAGE] {
+        for caps in regex.captures_iter(body) {
+            let url = &caps["url"];
+            if !urls.iter().any(|seen| seen == url) {
+                urls.push(url.to_string());
+            }
+        }
+    }
+    urls
+}
+
+/// Point embedded images at new URLs. Only image references are rewritten, so
+/// a plain link to the same URL stays as it is. Rewritten `<img>` tags become
+/// markdown images, which task descriptions render.
+pub fn rewrite_image_urls(body: &str, replacements: &HashMap<String, String>) -> String {
+    if replacements.is_empty() {
+        return body.to_string();
+    }
+    let body = MARKDOWN_IMAGE.replace_all(body, |caps: &Captures| {
+        let whole = caps.get(0).unwrap();
+        let url = caps.name("url").unwrap();
+        match replacements.get(url.as_str()) {
+            Some(local) => format!(
+                "{}{}{}",
+                &whole.as_str()[..url.start() - whole.start()],
+                local,
+                &whole.as_str()[url.end() - whole.start()..]
+            ),
+            None => whole.as_str().to_string(),
+        }
+    });
+    HTML_IMAGE
+        .replace_all(&body, |caps: &Captures| {
+            match replacements.get(&caps["url"]) {
+                Some(local) => format!("![{}]({})", image_filename(&caps["url"]), local),
+                None => caps[0].to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Filename for an image downloaded from `url`: its last path segment, or
+/// `image` when the URL has none
+pub fn image_filename(url: &str) -> String {
+    url.split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit('/').next())
+        .filter(|name| !name.is_empty())
+        .map(|name| urlencoding::decode(name).map_or(name.to_string(), |n| n.into_owned()))
+        .unwrap_or_else(|| "image".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_and_rewrites_embedded_images() {
+        let body = concat!(
+            "Broken layout:\n",
+            "![screenshot](https://github.com/user-attachments/assets/1f2e)\n",
+            "<img width=\"400\" alt=\"after\" src=\"https://github.com/user-attachments/assets/9a8b\" />\n",
+            "<img src=\"https://github.com/user-attachments/assets/7c6d\">\n",
+            "![logs](/uploads/abc123/trace%20log.png \"trace\")\n",
+            "See [the screenshot](https://github.com/user-attachments/assets/1f2e).",
+        );
+
+        assert_eq!(
+            embedded_image_urls(body),
+            vec![
+                "https://github.com/user-attachments/assets/1f2e",
+                "/uploads/abc123/trace%20log.png",
+                "https://github.com/user-attachments/assets/9a8b",
+                "https://github.com/user-attachments/assets/7c6d",
+            ]
+        );
+
+        let replacements = HashMap::from([
+            (
+                "https://github.com/user-attachments/assets/1f2e".to_string(),
+                ".vibe-images/a.png".to_string(),
+            ),
+            (
+                "/uploads/abc123/trace%20log.png".to_string(),
+                ".vibe-images/b.png".to_string(),
+            ),
+            (
+                "https://github.com/user-attachments/assets/7c6d".to_string(),
+                ".vibe-images/c.png".to_string(),
+            ),
+        ]);
+        let rewritten = rewrite_image_urls(body, &replacements);
+        assert!(rewritten.contains("![screenshot](.vibe-images/a.png)"));
+        assert!(rewritten.contains("![logs](.vibe-images/b.png \"trace\")"));
+        assert!(rewritten.contains("src=\"https://github.com/user-attachments/assets/9a8b\""));
+        assert!(rewritten.contains("![7c6d](.vibe-images/c.png)"));
+        assert!(
+            rewritten.contains("[the screenshot](https://github.com/user-attachments/assets/1f2e)")
+        );
+
+        assert_eq!(
+            image_filename("/uploads/abc123/trace%20log.png"),
+            "trace log.png"
+        );
+        assert_eq!(image_filename("https://example.com/"), "image");
+    }
+}
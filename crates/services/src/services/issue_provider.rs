@@ -0,0 +1,889 @@
+//! A common interface over the issue trackers tasks are imported from, so the
+//! import and sync flows are written once and a new tracker only needs an
+//! [`IssueProvider`] implementation and an entry in [`IssueProviderRegistry`].
+//! Trackers connected with an API token keep their settings in an
+//! [`IssueConnection`]; their providers live in the submodules.
+
+mod asana;
+mod azure_devops;
+mod clickup;
+mod linear;
+mod notion;
+mod shortcut;
+
+use async_trait::async_trait;
+use db::models::{
+    issue_connection::{IssueConnection, IssueConnectionSettings},
+    issue_link::IssueLink,
+    project::Project,
+    task::TaskStatus,
+};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::services::{
+    asana_tasks::AsanaError,
+    azure_work_items::AzureWorkItemsError,
+    clickup_tasks::ClickUpError,
+    gitea_issues::{GiteaIssue, GiteaIssuesError, GiteaIssuesService, ListGiteaIssuesParams},
+    github_issues::{
+        GitHubIssue, GitHubIssuesError, GitHubIssuesService, ListIssuesParams, is_github_image_url,
+    },
+    gitlab_issues::{
+        GitLabIssue, GitLabIssuesError, GitLabIssuesService, ListGitLabIssuesParams,
+        UpdateGitLabIssue,
+    },
+    issue_images,
+    linear_issues::LinearIssuesError,
+    notion_database::NotionError,
+    shortcut_stories::ShortcutError,
+    token_cipher::{TokenCipher, TokenCipherError},
+    vortex_issues::{ListVortexIssuesParams, VortexIssue, VortexIssuesError, VortexIssuesService},
+    vortex_oauth::{VortexOAuthError, VortexOAuthService},
+    webhooks::WebhookIssue,
+};
+
+#[derive(Debug, Error)]
+pub enum IssueProviderError {
+    #[error(transparent)]
+    GitHub(#[from] GitHubIssuesError),
+    #[error(transparent)]
+    GitLab(#[from] GitLabIssuesError),
+    #[error(transparent)]
+    Gitea(#[from] GiteaIssuesError),
+    #[error(transparent)]
+    Vortex(#[from] VortexIssuesError),
+    #[error(transparent)]
+    VortexOAuth(#[from] VortexOAuthError),
+    #[error(transparent)]
+    Linear(#[from] LinearIssuesError),
+    #[error(transparent)]
+    AzureDevOps(#[from] AzureWorkItemsError),
+    #[error(transparent)]
+    Notion(#[from] NotionError),
+    #[error(transparent)]
+    Asana(#[from] AsanaError),
+    #[error(transparent)]
+    ClickUp(#[from] ClickUpError),
+    #[error(transparent)]
+    Shortcut(#[from] ShortcutError),
+    #[error(transparent)]
+    TokenCipher(#[from] TokenCipherError),
+    #[error("{0} configuration not set for this project")]
+    NotConfigured(&'static str),
+    #[error("Invalid issue id: {0}")]
+    InvalidIssueId(String),
+    #[error("{0}")]
+    InvalidConnection(String),
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueProviderKind {
+    GitHub,
+    GitLab,
+    Gitea,
+    Vortex,
+    Linear,
+    AzureDevOps,
+    Notion,
+    Asana,
+    ClickUp,
+    Shortcut,
+}
+
+impl IssueProviderKind {
+    pub const ALL: [Self; 10] = [
+        Self::GitHub,
+        Self::GitLab,
+        Self::Gitea,
+        Self::Vortex,
+        Self::Linear,
+        Self::AzureDevOps,
+        Self::Notion,
+        Self::Asana,
+        Self::ClickUp,
+        Self::Shortcut,
+    ];
+
+    /// Providers configured through an [`IssueConnection`]
+    pub const CONNECTED: [Self; 6] = [
+        Self::Linear,
+        Self::AzureDevOps,
+        Self::Notion,
+        Self::Asana,
+        Self::ClickUp,
+        Self::Shortcut,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GitHub => "GitHub",
+            Self::GitLab => "GitLab",
+            Self::Gitea => "Gitea",
+            Self::Vortex => "Vortex",
+            Self::Linear => "Linear",
+            Self::AzureDevOps => "Azure DevOps",
+            Self::Notion => "Notion",
+            Self::Asana => "Asana",
+            Self::ClickUp => "ClickUp",
+            Self::Shortcut => "Shortcut",
+        }
+    }
+
+    /// The provider's name in URLs and analytics events
+    pub fn slug(self) -> &'static str {
+        match self {
+            Self::GitHub => "github",
+            Self::GitLab => "gitlab",
+            Self::Gitea => "gitea",
+            Self::Vortex => "vortex",
+            Self::Linear => "linear",
+            Self::AzureDevOps => "azure-devops",
+            Self::Notion => "notion",
+            Self::Asana => "asana",
+            Self::ClickUp => "clickup",
+            Self::Shortcut => "shortcut",
+        }
+    }
+
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.slug() == slug)
+    }
+
+    pub fn is_connected(self) -> bool {
+        Self::CONNECTED.contains(&self)
+    }
+
+    /// Start of the first line of an imported task's description, followed by
+    /// the issue's key
+    pub fn import_prefix(self) -> &'static str {
+        match self {
+            Self::GitHub => "Imported from GitHub Issue #",
+            Self::GitLab => "Imported from GitLab Issue #",
+            Self::Gitea => "Imported from Gitea Issue #",
+            Self::Vortex => "Imported from Vortex Issue #",
+            Self::Linear => "Imported from Linear Issue ",
+            Self::AzureDevOps => "Imported from Azure DevOps Work Item #",
+            Self::Notion => "Imported from Notion page ",
+            Self::Asana => "Imported from Asana task ",
+            Self::ClickUp => "Imported from ClickUp task ",
+            Self::Shortcut => "Imported from Shortcut story ",
+        }
+    }
+
+    /// Split an imported task's description into its header, through the blank
+    /// line after the issue URL, and the issue body. Descriptions without the
+    /// header are all body.
+    pub fn split_import_header(self, description: &str) -> (&str, &str) {
+        if !description.starts_with(self.import_prefix()) {
+            return ("", description);
+        }
+        let mut end = 0;
+        for _ in 0..2 {
+            match description[end..].find('\n') {
+                Some(newline) => end += newline + 1,
+                None => return (description, ""),
+            }
+        }
+        if description[end..].starts_with('\n') {
+            end += 1;
+        }
+        description.split_at(end)
+    }
+
+    /// The provider with this [`name`](Self::name), as issue links store it
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+/// An issue as every provider describes it
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ProviderIssue {
+    /// What the provider's API addresses the issue by
+    pub id: String,
+    /// What people call the issue, e.g. `42` or `WEB-7`
+    pub key: String,
+    pub title: String,
+    pub body: Option<String>,
+    pub url: String,
+    pub labels: Vec<String>,
+    pub closed: bool,
+    /// Status the imported task starts in, for trackers whose states map onto
+    /// task statuses; others start in To Do
+    pub status: Option<TaskStatus>,
+}
+
+impl ProviderIssue {
+    /// Description of the task imported from this issue, headed by the issue's
+    /// key and URL
+    pub fn task_description(&self, kind: IssueProviderKind, extra_markdown: &str) -> String {
+        format!(
+            "{}{}\n{}\n\n{}{}",
+            kind.import_prefix(),
+            self.key,
+            self.url,
+            self.body.as_deref().unwrap_or_default(),
+            extra_markdown
+        )
+    }
+}
+
+impl From<GitHubIssue> for ProviderIssue {
+    fn from(issue: GitHubIssue) -> Self {
+        Self {
+            id: issue.number.to_string(),
+            key: issue.number.to_string(),
+            title: issue.title,
+            body: issue.body,
+            url: issue.html_url,
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            closed: issue.state == "closed",
+            status: None,
+        }
+    }
+}
+
+impl From<GitLabIssue> for ProviderIssue {
+    fn from(issue: GitLabIssue) -> Self {
+        Self {
+            id: issue.iid.to_string(),
+            key: issue.iid.to_string(),
+            title: issue.title,
+            body: issue.description,
+            url: issue.web_url,
+            labels: issue.labels,
+            closed: issue.state == "closed",
+            status: None,
+        }
+    }
+}
+
+impl From<GiteaIssue> for ProviderIssue {
+    fn from(issue: GiteaIssue) -> Self {
+        Self {
+            id: issue.number.to_string(),
+            key: issue.number.to_string(),
+            title: issue.title,
+            body: issue.body,
+            url: issue.html_url,
+            labels: issue.labels.into_iter().map(|label| label.name).collect(),
+            closed: issue.state == "closed",
+            status: None,
+        }
+    }
+}
+
+/// GitHub, GitLab and Gitea address issues by their number, so an opened issue
+/// from a webhook can be imported without fetching it again
+impl From<WebhookIssue> for ProviderIssue {
+    fn from(issue: WebhookIssue) -> Self {
+        Self {
+            id: issue.number.to_string(),
+            key: issue.number.to_string(),
+            title: issue.title,
+            body: issue.description,
+            url: issue.url,
+            labels: issue.labels,
+            closed: false,
+            status: None,
+        }
+    }
+}
+
+impl From<VortexIssue> for ProviderIssue {
+    fn from(issue: VortexIssue) -> Self {
+        Self {
+            url: format!("https://vortextask.com/issues/{}", issue.id),
+            closed: matches!(issue.status.as_str(), "Done" | "Closed" | "Cancelled"),
+            id: issue.id,
+            key: issue.key,
+            title: issue.title,
+            body: issue.description,
+            labels: issue.labels,
+            status: None,
+        }
+    }
+}
+
+/// An image attached to an issue, downloaded for the imported task
+#[derive(Debug, Clone)]
+pub struct IssueAttachment {
+    pub filename: String,
+    pub data: Vec<u8>,
+    /// URL the issue body embeds the image from. The imported description
+    /// points at the local copy instead; attachments without one are listed
+    /// after the body.
+    pub source_url: Option<String>,
+}
+
+#[async_trait]
+pub trait IssueProvider: Send + Sync {
+    fn kind(&self) -> IssueProviderKind;
+
+    /// Open issues with the project's sync labels, most recently updated first
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError>;
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError>;
+
+    /// Move the issue to the state matching a task status
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError>;
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError>;
+
+    /// Images attached to or embedded in the issue. Failing downloads are
+    /// skipped.
+    async fn fetch_attachments(&self, _issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        Vec::new()
+    }
+
+    /// Whether issue labels are copied onto imported tasks
+    fn imports_labels(&self) -> bool {
+        false
+    }
+}
+
+fn numeric_id(id: &str) -> Result<i64, IssueProviderError> {
+    id.parse()
+        .map_err(|_| IssueProviderError::InvalidIssueId(id.to_string()))
+}
+
+/// A trimmed optional setting, with blank values unset
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Check that `token` can reach what the settings point at, normalizing them
+/// the way the connection is stored, e.g. a pasted URL into an id
+pub async fn verify_connection(
+    token: &str,
+    settings: &mut IssueConnectionSettings,
+) -> Result<(), IssueProviderError> {
+    match settings {
+        IssueConnectionSettings::Linear(settings) => linear::verify(token, settings).await,
+        IssueConnectionSettings::AzureDevOps(settings) => {
+            azure_devops::verify(token, settings).await
+        }
+        IssueConnectionSettings::Notion(settings) => notion::verify(token, settings).await,
+        IssueConnectionSettings::Asana(settings) => asana::verify(token, settings).await,
+        IssueConnectionSettings::ClickUp(settings) => clickup::verify(token, settings).await,
+        IssueConnectionSettings::Shortcut(settings) => shortcut::verify(token, settings).await,
+    }
+}
+
+pub struct GitHubIssueProvider {
+    service: GitHubIssuesService,
+    token: String,
+    owner: String,
+    repo: String,
+    sync_labels: Option<String>,
+    import_labels: bool,
+}
+
+#[async_trait]
+impl IssueProvider for GitHubIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::GitHub
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let params = ListIssuesParams {
+            state: Some("open".to_string()),
+            labels: self.sync_labels.clone(),
+            sort: Some("updated".to_string()),
+            direction: Some("desc".to_string()),
+            per_page: Some(limit),
+            page: Some(1),
+        };
+        let issues = self
+            .service
+            .list_issues(&self.token, &self.owner, &self.repo, &params)
+            .await?;
+        Ok(issues.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        let issue = self
+            .service
+            .get_issue(&self.token, &self.owner, &self.repo, numeric_id(id)?)
+            .await?;
+        Ok(issue.into())
+    }
+
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        let state = match status {
+            TaskStatus::Done | TaskStatus::Cancelled => "closed",
+            TaskStatus::Todo | TaskStatus::InProgress | TaskStatus::InReview => "open",
+        };
+        self.service
+            .update_issue_state(&self.token, &self.owner, &self.repo, numeric_id(id)?, state)
+            .await?;
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service
+            .add_comment(&self.token, &self.owner, &self.repo, numeric_id(id)?, body)
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let mut downloaded = Vec::new();
+        for url in issue_images::embedded_image_urls(issue.body.as_deref().unwrap_or_default()) {
+            // Images hosted elsewhere keep working as links and never see the token
+            if !is_github_image_url(&url) {
+                continue;
+            }
+            match self.service.download_image(&self.token, &url).await {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: issue_images::image_filename(&url),
+                    data,
+                    source_url: Some(url),
+                }),
+                Err(e) => tracing::warn!("Failed to download GitHub image {}: {}", url, e),
+            }
+        }
+        downloaded
+    }
+
+    fn imports_labels(&self) -> bool {
+        self.import_labels
+    }
+}
+
+pub struct GitLabIssueProvider {
+    service: GitLabIssuesService,
+    token: String,
+    project_path: String,
+    sync_labels: Option<String>,
+}
+
+#[async_trait]
+impl IssueProvider for GitLabIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::GitLab
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let params = ListGitLabIssuesParams {
+            state: Some("opened".to_string()),
+            labels: self.sync_labels.clone(),
+            sort: Some("desc".to_string()),
+            order_by: Some("updated_at".to_string()),
+            per_page: Some(limit),
+            page: Some(1),
+        };
+        let issues = self
+            .service
+            .list_issues(&self.token, &self.project_path, &params)
+            .await?;
+        Ok(issues.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        let issue = self
+            .service
+            .get_issue(&self.token, &self.project_path, numeric_id(id)?)
+            .await?;
+        Ok(issue.into())
+    }
+
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        self.service
+            .update_issue(
+                &self.token,
+                &self.project_path,
+                numeric_id(id)?,
+                &UpdateGitLabIssue::for_task_status(&status),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service
+            .add_issue_note(&self.token, &self.project_path, numeric_id(id)?, body)
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let mut downloaded = Vec::new();
+        for url in issue_images::embedded_image_urls(issue.body.as_deref().unwrap_or_default()) {
+            // Only the project's own uploads need the token
+            let Some(api_url) = self.service.upload_api_url(&self.project_path, &url) else {
+                continue;
+            };
+            match self.service.download_upload(&self.token, &api_url).await {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: issue_images::image_filename(&url),
+                    data,
+                    source_url: Some(url),
+                }),
+                Err(e) => tracing::warn!("Failed to download GitLab upload {}: {}", url, e),
+            }
+        }
+        downloaded
+    }
+}
+
+pub struct GiteaIssueProvider {
+    service: GiteaIssuesService,
+    base_url: String,
+    token: String,
+    owner: String,
+    repo: String,
+    sync_labels: Option<String>,
+}
+
+#[async_trait]
+impl IssueProvider for GiteaIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::Gitea
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let params = ListGiteaIssuesParams {
+            state: Some("open".to_string()),
+            labels: self.sync_labels.clone(),
+            limit: Some(limit),
+            page: Some(1),
+        };
+        let issues = self
+            .service
+            .list_issues(
+                &self.base_url,
+                &self.token,
+                &self.owner,
+                &self.repo,
+                &params,
+            )
+            .await?;
+        Ok(issues.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        let issue = self
+            .service
+            .get_issue(
+                &self.base_url,
+                &self.token,
+                &self.owner,
+                &self.repo,
+                numeric_id(id)?,
+            )
+            .await?;
+        Ok(issue.into())
+    }
+
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        let state = match status {
+            TaskStatus::Done | TaskStatus::Cancelled => "closed",
+            TaskStatus::Todo | TaskStatus::InProgress | TaskStatus::InReview => "open",
+        };
+        self.service
+            .update_issue_state(
+                &self.base_url,
+                &self.token,
+                &self.owner,
+                &self.repo,
+                numeric_id(id)?,
+                state,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service
+            .add_comment(
+                &self.base_url,
+                &self.token,
+                &self.owner,
+                &self.repo,
+                numeric_id(id)?,
+                body,
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+pub struct VortexIssueProvider {
+    service: VortexIssuesService,
+    token: String,
+    project_id: Option<String>,
+    sync_labels: Option<String>,
+}
+
+#[async_trait]
+impl IssueProvider for VortexIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::Vortex
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let project_id = self
+            .project_id
+            .as_deref()
+            .ok_or(IssueProviderError::NotConfigured("Vortex"))?;
+        let params = ListVortexIssuesParams {
+            status: Some("Open".to_string()),
+            priority: None,
+            labels: self.sync_labels.clone(),
+            page: Some(1),
+            limit: Some(limit),
+        };
+        let issues = self
+            .service
+            .list_issues(&self.token, project_id, &params)
+            .await?;
+        Ok(issues.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        Ok(self.service.get_issue(&self.token, id).await?.into())
+    }
+
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        let status = match status {
+            TaskStatus::Todo => "Open",
+            TaskStatus::InProgress => "In Progress",
+            TaskStatus::InReview => "In Review",
+            TaskStatus::Done => "Done",
+            TaskStatus::Cancelled => "Cancelled",
+        };
+        self.service
+            .update_issue_status(&self.token, id, status)
+            .await?;
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service
+            .add_comment_as_current_user(&self.token, id, body)
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let attachments = self
+            .service
+            .get_issue_attachments(&self.token, &issue.id)
+            .await
+            .unwrap_or_default();
+
+        let mut downloaded = Vec::new();
+        for attachment in attachments {
+            let Some(download_url) = attachment.download_url.filter(|_| attachment.is_image) else {
+                continue;
+            };
+            match self
+                .service
+                .download_attachment(&self.token, &download_url)
+                .await
+            {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: attachment.filename,
+                    data,
+                    source_url: None,
+                }),
+                Err(e) => tracing::warn!(
+                    "Failed to download Vortex attachment {}: {}",
+                    attachment.filename,
+                    e
+                ),
+            }
+        }
+        downloaded
+    }
+}
+
+/// Builds the provider for a project's configured integration
+pub struct IssueProviderRegistry {
+    pool: SqlitePool,
+    vortex_oauth: VortexOAuthService,
+}
+
+impl IssueProviderRegistry {
+    pub fn new(pool: SqlitePool, vortex_oauth: VortexOAuthService) -> Self {
+        Self { pool, vortex_oauth }
+    }
+
+    /// The project's provider of `kind`, or `None` when the project has no
+    /// credentials for it
+    pub async fn provider(
+        &self,
+        project: &Project,
+        kind: IssueProviderKind,
+    ) -> Result<Option<Box<dyn IssueProvider>>, IssueProviderError> {
+        let provider: Box<dyn IssueProvider> = match kind {
+            IssueProviderKind::GitHub => {
+                let (Some(repo_url), Some(token)) =
+                    (&project.github_repo_url, &project.github_token)
+                else {
+                    return Ok(None);
+                };
+                let (owner, repo) = GitHubIssuesService::parse_repo_url(repo_url)?;
+                Box::new(GitHubIssueProvider {
+                    service: GitHubIssuesService::new(),
+                    token: token.clone(),
+                    owner,
+                    repo,
+                    sync_labels: project.github_sync_labels.clone(),
+                    import_labels: project.github_label_sync.imports(),
+                })
+            }
+            IssueProviderKind::GitLab => {
+                let (Some(project_url), Some(token)) =
+                    (&project.gitlab_project_url, &project.gitlab_token)
+                else {
+                    return Ok(None);
+                };
+                Box::new(GitLabIssueProvider {
+                    service: GitLabIssuesService::for_instance(project.gitlab_base_url.as_deref())?,
+                    token: token.clone(),
+                    project_path: GitLabIssuesService::parse_project_url(project_url)?,
+                    sync_labels: project.gitlab_sync_labels.clone(),
+                })
+            }
+            IssueProviderKind::Gitea => {
+                let (Some(base_url), Some(repo), Some(token)) = (
+                    &project.gitea_base_url,
+                    &project.gitea_repo,
+                    &project.gitea_token,
+                ) else {
+                    return Ok(None);
+                };
+                let (owner, repo) = GiteaIssuesService::parse_repo(repo)?;
+                Box::new(GiteaIssueProvider {
+                    service: GiteaIssuesService::new(),
+                    base_url: base_url.clone(),
+                    token: token.clone(),
+                    owner,
+                    repo,
+                    sync_labels: project.gitea_sync_labels.clone(),
+                })
+            }
+            IssueProviderKind::Vortex => {
+                let Some(token) = self.vortex_oauth.access_token(&self.pool, project).await? else {
+                    return Ok(None);
+                };
+                Box::new(VortexIssueProvider {
+                    service: VortexIssuesService::new(),
+                    token,
+                    project_id: project.vortex_project_id.clone(),
+                    sync_labels: project.vortex_sync_labels.clone(),
+                })
+            }
+            IssueProviderKind::Linear
+            | IssueProviderKind::AzureDevOps
+            | IssueProviderKind::Notion
+            | IssueProviderKind::Asana
+            | IssueProviderKind::ClickUp
+            | IssueProviderKind::Shortcut => {
+                let Some(connection) =
+                    IssueConnection::find(&self.pool, project.id, kind.name()).await?
+                else {
+                    return Ok(None);
+                };
+                let token =
+                    TokenCipher::shared().and_then(|cipher| cipher.decrypt(&connection.token))?;
+                match connection.settings.0 {
+                    IssueConnectionSettings::Linear(settings) => {
+                        Box::new(linear::LinearIssueProvider::new(token, settings))
+                    }
+                    IssueConnectionSettings::AzureDevOps(settings) => {
+                        Box::new(azure_devops::AzureDevOpsIssueProvider::new(token, settings))
+                    }
+                    IssueConnectionSettings::Notion(settings) => {
+                        Box::new(notion::NotionIssueProvider::new(token, settings))
+                    }
+                    IssueConnectionSettings::Asana(settings) => {
+                        Box::new(asana::AsanaIssueProvider::new(token, settings))
+                    }
+                    IssueConnectionSettings::ClickUp(settings) => {
+                        Box::new(clickup::ClickUpIssueProvider::new(token, settings))
+                    }
+                    IssueConnectionSettings::Shortcut(settings) => {
+                        Box::new(shortcut::ShortcutIssueProvider::new(token, settings))
+                    }
+                }
+            }
+        };
+        Ok(Some(provider))
+    }
+
+    /// The provider and issue id of the issue a task was imported from, if the
+    /// project is still configured for that provider
+    pub async fn linked(
+        &self,
+        project: &Project,
+        task_id: Uuid,
+    ) -> Result<Option<(Box<dyn IssueProvider>, String)>, IssueProviderError> {
+        let Some((kind, link)) = IssueLink::find_by_task_id(&self.pool, task_id)
+            .await?
+            .and_then(|link| Some((IssueProviderKind::from_name(&link.provider)?, link)))
+        else {
+            return Ok(None);
+        };
+        Ok(self
+            .provider(project, kind)
+            .await?
+            .map(|provider| (provider, link.external_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_imported_tasks_and_names_providers() {
+        let issue = ProviderIssue {
+            id: "9f2c".to_string(),
+            key: "WEB-7".to_string(),
+            title: "Fix login".to_string(),
+            body: Some("Users get logged out".to_string()),
+            url: "https://vortextask.com/issues/9f2c".to_string(),
+            labels: vec![],
+            closed: false,
+            status: None,
+        };
+        let description = issue.task_description(IssueProviderKind::Vortex, "");
+        assert_eq!(
+            description,
+            "Imported from Vortex Issue #WEB-7\nhttps://vortextask.com/issues/9f2c\n\nUsers get logged out"
+        );
+        assert_eq!(
+            IssueProviderKind::Vortex.split_import_header(&description),
+            (
+                "Imported from Vortex Issue #WEB-7\nhttps://vortextask.com/issues/9f2c\n\n",
+                "Users get logged out"
+            )
+        );
+        assert_eq!(
+            IssueProviderKind::GitHub.split_import_header(&description),
+            ("", description.as_str())
+        );
+        for kind in IssueProviderKind::ALL {
+            assert_eq!(IssueProviderKind::from_name(kind.name()), Some(kind));
+            assert_eq!(IssueProviderKind::from_slug(kind.slug()), Some(kind));
+        }
+        assert_eq!(IssueProviderKind::from_name("gitea"), None);
+        assert_eq!(
+            IssueProviderKind::from_slug("azure-devops"),
+            Some(IssueProviderKind::AzureDevOps)
+        );
+        assert!(!IssueProviderKind::GitHub.is_connected());
+        assert!(IssueProviderKind::Shortcut.is_connected());
+    }
+}
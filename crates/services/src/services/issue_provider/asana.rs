@@ -0,0 +1,121 @@
+//! Tasks of an Asana project, addressed by their gid
+
+use async_trait::async_trait;
+use db::models::{issue_connection::AsanaSettings, task::TaskStatus};
+
+use super::{IssueAttachment, IssueProvider, IssueProviderError, IssueProviderKind, ProviderIssue};
+use crate::services::asana_tasks::{AsanaService, AsanaTask};
+
+impl From<AsanaTask> for ProviderIssue {
+    fn from(task: AsanaTask) -> Self {
+        Self {
+            key: task.gid.clone(),
+            id: task.gid,
+            title: task.name,
+            body: Some(task.notes).filter(|notes| !notes.is_empty()),
+            url: task.permalink_url,
+            labels: Vec::new(),
+            closed: task.completed,
+            status: None,
+        }
+    }
+}
+
+/// Check the token can read the project
+pub(super) async fn verify(
+    token: &str,
+    settings: &mut AsanaSettings,
+) -> Result<(), IssueProviderError> {
+    settings.project_gid = AsanaService::normalize_project_gid(&settings.project_gid)?;
+    AsanaService::new()
+        .check_project(token, &settings.project_gid)
+        .await?;
+    Ok(())
+}
+
+pub struct AsanaIssueProvider {
+    service: AsanaService,
+    token: String,
+    settings: AsanaSettings,
+}
+
+impl AsanaIssueProvider {
+    pub(super) fn new(token: String, settings: AsanaSettings) -> Self {
+        Self {
+            service: AsanaService::new(),
+            token,
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for AsanaIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::Asana
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let tasks = self
+            .service
+            .list_open_tasks(
+                &self.token,
+                &self.settings.project_gid,
+                limit.max(1) as usize,
+            )
+            .await?;
+        Ok(tasks.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        Ok(self.service.get_task(&self.token, id).await?.into())
+    }
+
+    /// Asana tasks are only open or complete, so only Done is written back
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        if status == TaskStatus::Done {
+            self.service.complete_task(&self.token, id).await?;
+        }
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service.add_comment(&self.token, id, body).await?;
+        Ok(())
+    }
+
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let attachments = match self.service.list_attachments(&self.token, &issue.id).await {
+            Ok(attachments) => attachments,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to list attachments of Asana task {}: {}",
+                    issue.id,
+                    e
+                );
+                return Vec::new();
+            }
+        };
+
+        let mut downloaded = Vec::new();
+        for attachment in attachments {
+            // Links to files on external services have nothing to download
+            let Some(url) = &attachment.download_url else {
+                continue;
+            };
+            match self.service.download_attachment(url).await {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: attachment.name,
+                    data,
+                    source_url: None,
+                }),
+                Err(e) => tracing::warn!(
+                    "Failed to download Asana attachment {}: {}",
+                    attachment.name,
+                    e
+                ),
+            }
+        }
+        downloaded
+    }
+}
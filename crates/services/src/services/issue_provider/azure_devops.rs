@@ -0,0 +1,160 @@
+//! Azure DevOps Boards work items, addressed by their numeric id
+
+use async_trait::async_trait;
+use db::models::{issue_connection::AzureDevOpsSettings, task::TaskStatus};
+
+use super::{
+    IssueAttachment, IssueProvider, IssueProviderError, IssueProviderKind, ProviderIssue,
+    non_blank, numeric_id,
+};
+use crate::services::azure_work_items::{AzureWorkItem, AzureWorkItemsService, html_to_text};
+
+impl From<AzureWorkItem> for ProviderIssue {
+    fn from(work_item: AzureWorkItem) -> Self {
+        Self {
+            id: work_item.id.to_string(),
+            key: work_item.id.to_string(),
+            title: work_item.title,
+            body: work_item.description.as_deref().map(html_to_text),
+            url: work_item.html_url,
+            labels: work_item.tags,
+            closed: matches!(
+                work_item.state.as_str(),
+                "Closed" | "Done" | "Removed" | "Resolved"
+            ),
+            status: None,
+        }
+    }
+}
+
+/// Check the token can query the project's work items with the filter
+pub(super) async fn verify(
+    pat: &str,
+    settings: &mut AzureDevOpsSettings,
+) -> Result<(), IssueProviderError> {
+    settings.organization_url =
+        AzureWorkItemsService::normalize_organization_url(&settings.organization_url)?;
+    settings.azure_project = settings.azure_project.trim().to_string();
+    if settings.azure_project.is_empty() {
+        return Err(IssueProviderError::InvalidConnection(
+            "Azure DevOps project is required".to_string(),
+        ));
+    }
+    settings.wiql_filter = non_blank(settings.wiql_filter.take());
+
+    AzureWorkItemsService::new()
+        .query_work_item_ids(
+            &settings.organization_url,
+            &settings.azure_project,
+            pat,
+            settings.wiql_filter.as_deref(),
+            1,
+        )
+        .await?;
+    Ok(())
+}
+
+pub struct AzureDevOpsIssueProvider {
+    service: AzureWorkItemsService,
+    pat: String,
+    settings: AzureDevOpsSettings,
+}
+
+impl AzureDevOpsIssueProvider {
+    pub(super) fn new(pat: String, settings: AzureDevOpsSettings) -> Self {
+        Self {
+            service: AzureWorkItemsService::new(),
+            pat,
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for AzureDevOpsIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::AzureDevOps
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let work_items = self
+            .service
+            .list_work_items(
+                &self.settings.organization_url,
+                &self.settings.azure_project,
+                &self.pat,
+                self.settings.wiql_filter.as_deref(),
+                limit.max(1) as usize,
+            )
+            .await?;
+        Ok(work_items.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        let work_item = self
+            .service
+            .get_work_item(&self.settings.organization_url, &self.pat, numeric_id(id)?)
+            .await?;
+        Ok(work_item.into())
+    }
+
+    /// Work item states differ between processes, so they are left alone
+    async fn update_status(
+        &self,
+        _id: &str,
+        _status: TaskStatus,
+    ) -> Result<(), IssueProviderError> {
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service
+            .add_comment(
+                &self.settings.organization_url,
+                &self.settings.azure_project,
+                &self.pat,
+                numeric_id(id)?,
+                body,
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let Ok(id) = numeric_id(&issue.id) else {
+            return Vec::new();
+        };
+        let attachments = match self
+            .service
+            .get_work_item(&self.settings.organization_url, &self.pat, id)
+            .await
+        {
+            Ok(work_item) => work_item.attachments,
+            Err(e) => {
+                tracing::warn!("Failed to list attachments of work item {}: {}", id, e);
+                return Vec::new();
+            }
+        };
+
+        let mut downloaded = Vec::new();
+        for attachment in attachments {
+            match self
+                .service
+                .download_attachment(&self.pat, &attachment)
+                .await
+            {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: attachment.name,
+                    data,
+                    source_url: None,
+                }),
+                Err(e) => tracing::warn!(
+                    "Failed to download Azure DevOps attachment {}: {}",
+                    attachment.name,
+                    e
+                ),
+            }
+        }
+        downloaded
+    }
+}
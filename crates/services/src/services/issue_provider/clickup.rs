@@ -0,0 +1,133 @@
+//! Tasks of a ClickUp list, addressed by their id
+
+use async_trait::async_trait;
+use db::models::{issue_connection::ClickUpSettings, task::TaskStatus};
+
+use super::{
+    IssueAttachment, IssueProvider, IssueProviderError, IssueProviderKind, ProviderIssue, non_blank,
+};
+use crate::services::clickup_tasks::{
+    ClickUpService, ClickUpTask, ListClickUpTasksParams, parse_tag_filter,
+};
+
+impl From<ClickUpTask> for ProviderIssue {
+    fn from(task: ClickUpTask) -> Self {
+        let status = task.task_status();
+        Self {
+            key: task.id.clone(),
+            id: task.id,
+            title: task.name,
+            body: task.text_content.filter(|text| !text.is_empty()),
+            url: task.url,
+            labels: task.tags.into_iter().map(|tag| tag.name).collect(),
+            closed: matches!(status, TaskStatus::Done | TaskStatus::Cancelled),
+            status: Some(status),
+        }
+    }
+}
+
+/// Check the token can read the list
+pub(super) async fn verify(
+    token: &str,
+    settings: &mut ClickUpSettings,
+) -> Result<(), IssueProviderError> {
+    settings.list_id = ClickUpService::normalize_list_id(&settings.list_id)?;
+    settings.tag_filter = non_blank(settings.tag_filter.take());
+    ClickUpService::new()
+        .check_list(token, &settings.list_id)
+        .await?;
+    Ok(())
+}
+
+pub struct ClickUpIssueProvider {
+    service: ClickUpService,
+    token: String,
+    settings: ClickUpSettings,
+}
+
+impl ClickUpIssueProvider {
+    pub(super) fn new(token: String, settings: ClickUpSettings) -> Self {
+        Self {
+            service: ClickUpService::new(),
+            token,
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for ClickUpIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::ClickUp
+    }
+
+    /// Tasks with the connection's tags whose status doesn't map to Done or
+    /// Cancelled; custom done statuses aren't closed in ClickUp's terms
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let params = ListClickUpTasksParams {
+            tags: parse_tag_filter(self.settings.tag_filter.as_deref()),
+            include_closed: false,
+            limit: limit.max(1) as usize,
+        };
+        let tasks = self
+            .service
+            .list_tasks(&self.token, &self.settings.list_id, &params)
+            .await?;
+        Ok(tasks
+            .into_iter()
+            .map(ProviderIssue::from)
+            .filter(|issue| !issue.closed)
+            .collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        Ok(self.service.get_task(&self.token, id).await?.into())
+    }
+
+    /// ClickUp statuses are defined per list, so tasks are left alone
+    async fn update_status(
+        &self,
+        _id: &str,
+        _status: TaskStatus,
+    ) -> Result<(), IssueProviderError> {
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service.add_comment(&self.token, id, body).await?;
+        Ok(())
+    }
+
+    /// Only a single fetched task lists its attachments, so the task is
+    /// fetched again
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let attachments = match self.service.get_task(&self.token, &issue.id).await {
+            Ok(task) => task.attachments,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to list attachments of ClickUp task {}: {}",
+                    issue.id,
+                    e
+                );
+                return Vec::new();
+            }
+        };
+
+        let mut downloaded = Vec::new();
+        for attachment in attachments {
+            match self.service.download_attachment(&attachment.url).await {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: attachment.title,
+                    data,
+                    source_url: None,
+                }),
+                Err(e) => tracing::warn!(
+                    "Failed to download ClickUp attachment {}: {}",
+                    attachment.title,
+                    e
+                ),
+            }
+        }
+        downloaded
+    }
+}
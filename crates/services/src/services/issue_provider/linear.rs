@@ -0,0 +1,102 @@
+//! Linear issues, addressed by their identifier, e.g. `ENG-123`
+
+use async_trait::async_trait;
+use db::models::{issue_connection::LinearSettings, task::TaskStatus};
+
+use super::{IssueProvider, IssueProviderError, IssueProviderKind, ProviderIssue, non_blank};
+use crate::services::linear_issues::{LinearIssue, LinearIssuesService, state_for_status};
+
+impl From<LinearIssue> for ProviderIssue {
+    fn from(issue: LinearIssue) -> Self {
+        Self {
+            id: issue.identifier.clone(),
+            closed: matches!(issue.state.state_type.as_str(), "completed" | "canceled"),
+            key: issue.identifier,
+            title: issue.title,
+            body: issue.description,
+            url: issue.url,
+            labels: issue.labels,
+            status: None,
+        }
+    }
+}
+
+/// Check the key can see the team, and store the team's key with it
+pub(super) async fn verify(
+    api_key: &str,
+    settings: &mut LinearSettings,
+) -> Result<(), IssueProviderError> {
+    let team = LinearIssuesService::new()
+        .get_team(api_key, &settings.team_id)
+        .await?;
+    settings.team_key = team.key;
+    settings.sync_labels = non_blank(settings.sync_labels.take());
+    Ok(())
+}
+
+pub struct LinearIssueProvider {
+    service: LinearIssuesService,
+    api_key: String,
+    settings: LinearSettings,
+}
+
+impl LinearIssueProvider {
+    pub(super) fn new(api_key: String, settings: LinearSettings) -> Self {
+        Self {
+            service: LinearIssuesService::new(),
+            api_key,
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for LinearIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::Linear
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let issues = self
+            .service
+            .list_issues(
+                &self.api_key,
+                &self.settings.team_id,
+                self.settings.sync_labels.as_deref(),
+                limit,
+            )
+            .await?;
+        Ok(issues.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        Ok(self.service.get_issue(&self.api_key, id).await?.into())
+    }
+
+    /// Moves the issue to the team's workflow state for the status, unless the
+    /// connection has status sync turned off or the team has no such state
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        if !self.settings.status_sync_enabled {
+            return Ok(());
+        }
+        let issue = self.service.get_issue(&self.api_key, id).await?;
+        let states = self
+            .service
+            .list_workflow_states(&self.api_key, &self.settings.team_id)
+            .await?;
+        let Some(state) = state_for_status(&states, &status) else {
+            return Ok(());
+        };
+        if state.id != issue.state.id {
+            self.service
+                .update_issue_state(&self.api_key, &issue.id, &state.id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service.add_comment(&self.api_key, id, body).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! Rows of a Notion database, addressed by their page id
+
+use async_trait::async_trait;
+use db::models::{issue_connection::NotionSettings, task::TaskStatus};
+
+use super::{IssueAttachment, IssueProvider, IssueProviderError, IssueProviderKind, ProviderIssue};
+use crate::services::notion_database::{NotionPropertyMap, NotionRow, NotionService};
+
+impl From<NotionRow> for ProviderIssue {
+    fn from(row: NotionRow) -> Self {
+        let status = row.task_status();
+        Self {
+            key: row.id.clone(),
+            id: row.id,
+            title: row.title,
+            body: row.description,
+            url: row.html_url,
+            labels: Vec::new(),
+            closed: matches!(status, TaskStatus::Done | TaskStatus::Cancelled),
+            status: Some(status),
+        }
+    }
+}
+
+/// Check the database is shared with the integration
+pub(super) async fn verify(
+    token: &str,
+    settings: &mut NotionSettings,
+) -> Result<(), IssueProviderError> {
+    settings.database_id = NotionService::normalize_database_id(&settings.database_id)?;
+    for (property, default) in [
+        (&mut settings.status_property, "Status"),
+        (&mut settings.description_property, "Description"),
+    ] {
+        *property = property.trim().to_string();
+        if property.is_empty() {
+            *property = default.to_string();
+        }
+    }
+
+    NotionService::new()
+        .check_database(token, &settings.database_id)
+        .await?;
+    Ok(())
+}
+
+pub struct NotionIssueProvider {
+    service: NotionService,
+    token: String,
+    database_id: String,
+    properties: NotionPropertyMap,
+}
+
+impl NotionIssueProvider {
+    pub(super) fn new(token: String, settings: NotionSettings) -> Self {
+        Self {
+            service: NotionService::new(),
+            token,
+            database_id: settings.database_id,
+            properties: NotionPropertyMap {
+                status: settings.status_property,
+                description: settings.description_property,
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for NotionIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::Notion
+    }
+
+    /// Rows whose status isn't done or cancelled. Notion can't filter on a
+    /// property whose type isn't known up front, so rows are filtered here.
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let rows = self
+            .service
+            .query_rows(
+                &self.token,
+                &self.database_id,
+                &self.properties,
+                limit.max(1) as usize,
+            )
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(ProviderIssue::from)
+            .filter(|issue| !issue.closed)
+            .collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        let row = self
+            .service
+            .get_row(&self.token, id, &self.properties)
+            .await?;
+        Ok(row.into())
+    }
+
+    /// Notion statuses are named by each workspace, so rows are left alone
+    async fn update_status(
+        &self,
+        _id: &str,
+        _status: TaskStatus,
+    ) -> Result<(), IssueProviderError> {
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service.add_comment(&self.token, id, body).await?;
+        Ok(())
+    }
+
+    /// Files of the row's file properties. Their signed URLs expire, so the
+    /// row is fetched again.
+    async fn fetch_attachments(&self, issue: &ProviderIssue) -> Vec<IssueAttachment> {
+        let attachments = match self
+            .service
+            .get_row(&self.token, &issue.id, &self.properties)
+            .await
+        {
+            Ok(row) => row.attachments,
+            Err(e) => {
+                tracing::warn!("Failed to list files of Notion page {}: {}", issue.id, e);
+                return Vec::new();
+            }
+        };
+
+        let mut downloaded = Vec::new();
+        for attachment in attachments {
+            match self.service.download_attachment(&attachment).await {
+                Ok(data) => downloaded.push(IssueAttachment {
+                    filename: attachment.name,
+                    data,
+                    source_url: None,
+                }),
+                Err(e) => tracing::warn!(
+                    "Failed to download Notion attachment {}: {}",
+                    attachment.name,
+                    e
+                ),
+            }
+        }
+        downloaded
+    }
+}
@@ -0,0 +1,97 @@
+//! Stories of a Shortcut workspace, addressed by their numeric id
+
+use async_trait::async_trait;
+use db::models::{issue_connection::ShortcutSettings, task::TaskStatus};
+
+use super::{
+    IssueProvider, IssueProviderError, IssueProviderKind, ProviderIssue, non_blank, numeric_id,
+};
+use crate::services::shortcut_stories::{ShortcutService, ShortcutStory, parse_label_filter};
+
+impl From<ShortcutStory> for ProviderIssue {
+    fn from(story: ShortcutStory) -> Self {
+        Self {
+            id: story.id.to_string(),
+            key: story.id.to_string(),
+            title: story.name,
+            body: Some(story.description).filter(|description| !description.is_empty()),
+            url: story.app_url,
+            labels: story.labels.into_iter().map(|label| label.name).collect(),
+            closed: false,
+            status: None,
+        }
+    }
+}
+
+/// Check the token, and that the review state exists when one is picked
+pub(super) async fn verify(
+    token: &str,
+    settings: &mut ShortcutSettings,
+) -> Result<(), IssueProviderError> {
+    settings.labels = non_blank(settings.labels.take());
+    let workflows = ShortcutService::new().list_workflows(token).await?;
+    if let Some(state_id) = settings.review_state_id
+        && !workflows
+            .iter()
+            .flat_map(|workflow| &workflow.states)
+            .any(|state| state.id == state_id)
+    {
+        return Err(IssueProviderError::InvalidConnection(format!(
+            "Shortcut workflow state {state_id} does not exist"
+        )));
+    }
+    Ok(())
+}
+
+pub struct ShortcutIssueProvider {
+    service: ShortcutService,
+    token: String,
+    settings: ShortcutSettings,
+}
+
+impl ShortcutIssueProvider {
+    pub(super) fn new(token: String, settings: ShortcutSettings) -> Self {
+        Self {
+            service: ShortcutService::new(),
+            token,
+            settings,
+        }
+    }
+}
+
+#[async_trait]
+impl IssueProvider for ShortcutIssueProvider {
+    fn kind(&self) -> IssueProviderKind {
+        IssueProviderKind::Shortcut
+    }
+
+    async fn list_open_issues(&self, limit: i32) -> Result<Vec<ProviderIssue>, IssueProviderError> {
+        let labels = parse_label_filter(self.settings.labels.as_deref());
+        let mut stories = self.service.list_open_stories(&self.token, &labels).await?;
+        stories.truncate(limit.max(1) as usize);
+        Ok(stories.into_iter().map(ProviderIssue::from).collect())
+    }
+
+    async fn get_issue(&self, id: &str) -> Result<ProviderIssue, IssueProviderError> {
+        let story = self.service.get_story(&self.token, numeric_id(id)?).await?;
+        Ok(story.into())
+    }
+
+    /// Only review is written back, moving the story to the connection's
+    /// review state
+    async fn update_status(&self, id: &str, status: TaskStatus) -> Result<(), IssueProviderError> {
+        if status == TaskStatus::InReview {
+            self.service
+                .move_to_review(&self.token, numeric_id(id)?, self.settings.review_state_id)
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn add_comment(&self, id: &str, body: &str) -> Result<(), IssueProviderError> {
+        self.service
+            .add_comment(&self.token, numeric_id(id)?, body)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+//! Runs the issue syncs that projects have enabled on a schedule. A project is
+//! synced by one thing at a time, whether that is the scheduler or someone
+//! starting a sync by hand, and the outcome of each provider's last scheduled
+//! run is kept so failures can be shown next to the project.
+
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, Utc};
+use db::{DBService, models::project::Project};
+use serde::Serialize;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::services::config::{Config, IssueSyncScheduleConfig};
+
+/// Providers with a per-project `*_sync_enabled` flag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, TS)]
+pub enum SyncProvider {
+    GitHub,
+    GitLab,
+    Gitea,
+    Vortex,
+}
+
+impl SyncProvider {
+    pub const ALL: [Self; 4] = [Self::GitHub, Self::GitLab, Self::Gitea, Self::Vortex];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GitHub => "GitHub",
+            Self::GitLab => "GitLab",
+            Self::Gitea => "Gitea",
+            Self::Vortex => "Vortex",
+        }
+    }
+
+    pub fn is_enabled(self, project: &Project) -> bool {
+        match self {
+            Self::GitHub => project.github_sync_enabled,
+            Self::GitLab => project.gitlab_sync_enabled,
+            Self::Gitea => project.gitea_sync_enabled,
+            Self::Vortex => project.vortex_sync_enabled,
+        }
+    }
+}
+
+/// Runs one provider's sync of a project. The caller holds the project's sync
+/// lock while it runs.
+pub trait IssueSyncRunner: Send + Sync + 'static {
+    fn sync(
+        &self,
+        project: Project,
+        provider: SyncProvider,
+    ) -> impl Future<Output = Result<(), String>> + Send;
+}
+
+/// Outcome of the last scheduled sync of one provider of a project
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ScheduledSyncStatus {
+    pub provider: SyncProvider,
+    pub last_run_at: DateTime<Utc>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    /// Scheduled runs that failed in a row, reset by a successful one
+    pub consecutive_failures: u32,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct IssueSyncSchedule {
+    pub enabled: bool,
+    pub interval_minutes: u32,
+    pub next_run_at: Option<DateTime<Utc>>,
+    /// Whether a sync of the project is running right now
+    pub syncing: bool,
+    pub providers: Vec<ScheduledSyncStatus>,
+}
+
+/// Held while a project is being synced; dropping it lets the next sync start
+pub struct ProjectSyncGuard {
+    project_id: Uuid,
+    running: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+impl Drop for ProjectSyncGuard {
+    fn drop(&mut self) {
+        self.running.lock().unwrap().remove(&self.project_id);
+    }
+}
+
+/// Delay before the next pass: the interval plus a share of the jitter, where
+/// `random` is in `[0, 1)`
+pub fn next_delay(config: &IssueSyncScheduleConfig, random: f64) -> Duration {
+    let interval = Duration::from_secs(u64::from(config.interval_minutes.max(1)) * 60);
+    interval + Duration::from_secs_f64(f64::from(config.jitter_seconds) * random.clamp(0.0, 1.0))
+}
+
+/// A value in `[0, 1)` from the leading 48 bits of a v4 UUID, which are random
+fn random_fraction() -> f64 {
+    (Uuid::new_v4().as_u128() >> 80) as f64 / (1u64 << 48) as f64
+}
+
+#[derive(Default)]
+pub struct IssueSyncScheduler {
+    running: Arc<Mutex<HashSet<Uuid>>>,
+    statuses: Mutex<HashMap<Uuid, Vec<ScheduledSyncStatus>>>,
+    next_run_at: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl IssueSyncScheduler {
+    /// Take the project's sync lock, or `None` while another sync of it runs
+    pub fn try_lock(&self, project_id: Uuid) -> Option<ProjectSyncGuard> {
+        self.running
+            .lock()
+            .unwrap()
+            .insert(project_id)
+            .then(|| ProjectSyncGuard {
+                project_id,
+                running: self.running.clone(),
+            })
+    }
+
+    /// The schedule as it applies to one project
+    pub fn schedule(
+        &self,
+        project_id: Uuid,
+        config: &IssueSyncScheduleConfig,
+    ) -> IssueSyncSchedule {
+        IssueSyncSchedule {
+            enabled: config.enabled,
+            interval_minutes: config.interval_minutes,
+            next_run_at: config
+                .enabled
+                .then(|| *self.next_run_at.lock().unwrap())
+                .flatten(),
+            syncing: self.running.lock().unwrap().contains(&project_id),
+            providers: self
+                .statuses
+                .lock()
+                .unwrap()
+                .get(&project_id)
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn record(&self, project_id: Uuid, provider: SyncProvider, result: Result<(), String>) {
+        let now = Utc::now();
+        let mut statuses = self.statuses.lock().unwrap();
+        let statuses = statuses.entry(project_id).or_default();
+        let index = match statuses.iter().position(|s| s.provider == provider) {
+            Some(index) => index,
+            None => {
+                statuses.push(ScheduledSyncStatus {
+                    provider,
+                    last_run_at: now,
+                    last_success_at: None,
+                    last_error: None,
+                    consecutive_failures: 0,
+                });
+                statuses.len() - 1
+            }
+        };
+        let status = &mut statuses[index];
+        status.last_run_at = now;
+        match result {
+            Ok(()) => {
+                status.last_success_at = Some(now);
+                status.last_error = None;
+                status.consecutive_failures = 0;
+            }
+            Err(e) => {
+                status.last_error = Some(e);
+                status.consecutive_failures += 1;
+            }
+        }
+    }
+
+    pub async fn spawn<R: IssueSyncRunner>(
+        self: Arc<Self>,
+        db: DBService,
+        config: Arc<RwLock<Config>>,
+        runner: R,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            self.start(db, config, runner).await;
+        })
+    }
+
+    async fn start<R: IssueSyncRunner>(
+        &self,
+        db: DBService,
+        config: Arc<RwLock<Config>>,
+        runner: R,
+    ) {
+        info!("Starting scheduled issue sync service");
+        loop {
+            let schedule = config.read().await.issue_sync_schedule.clone();
+            let delay = next_delay(&schedule, random_fraction());
+            *self.next_run_at.lock().unwrap() = chrono::Duration::from_std(delay)
+                .ok()
+                .map(|delay| Utc::now() + delay);
+            tokio::time::sleep(delay).await;
+
+            // Settings may have changed while waiting
+            if !config.read().await.issue_sync_schedule.enabled {
+                continue;
+            }
+            if let Err(e) = self.run_pass(&db, &runner).await {
+                error!("Error running scheduled issue sync: {}", e);
+            }
+        }
+    }
+
+    async fn run_pass<R: IssueSyncRunner>(
+        &self,
+        db: &DBService,
+        runner: &R,
+    ) -> Result<(), sqlx::Error> {
+        for project in Project::find_all(&db.pool).await? {
+            let providers: Vec<_> = SyncProvider::ALL
+                .into_iter()
+                .filter(|provider| provider.is_enabled(&project))
+                .collect();
+            if providers.is_empty() {
+                continue;
+            }
+            // A manual sync is already bringing the project up to date
+            let Some(_guard) = self.try_lock(project.id) else {
+                continue;
+            };
+            for provider in providers {
+                let result = runner.sync(project.clone(), provider).await;
+                if let Err(e) = &result {
+                    warn!(
+                        "Scheduled {} sync of project {} failed: {}",
+                        provider.name(),
+                        project.id,
+                        e
+                    );
+                }
+                self.record(project.id, provider, result);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locks_projects_and_tracks_failures() {
+        let scheduler = IssueSyncScheduler::default();
+        let project_id = Uuid::new_v4();
+        let config = IssueSyncScheduleConfig::default();
+
+        let guard = scheduler.try_lock(project_id).unwrap();
+        assert!(scheduler.try_lock(project_id).is_none());
+        assert!(scheduler.try_lock(Uuid::new_v4()).is_some());
+        assert!(scheduler.schedule(project_id, &config).syncing);
+        drop(guard);
+        assert!(scheduler.try_lock(project_id).is_some());
+
+        scheduler.record(project_id, SyncProvider::GitHub, Err("boom".to_string()));
+        scheduler.record(project_id, SyncProvider::GitHub, Err("boom".to_string()));
+        scheduler.record(project_id, SyncProvider::GitLab, Ok(()));
+        let providers = scheduler.schedule(project_id, &config).providers;
+        assert_eq!(providers[0].consecutive_failures, 2);
+        assert_eq!(providers[0].last_error.as_deref(), Some("boom"));
+        assert!(providers[1].last_success_at.is_some());
+
+        scheduler.record(project_id, SyncProvider::GitHub, Ok(()));
+        let providers = scheduler.schedule(project_id, &config).providers;
+        assert_eq!(providers[0].consecutive_failures, 0);
+        assert!(providers[0].last_error.is_none());
+
+        assert_eq!(next_delay(&config, 0.0), Duration::from_secs(15 * 60));
+        assert_eq!(next_delay(&config, 0.5), Duration::from_secs(15 * 60 + 30));
+    }
+}
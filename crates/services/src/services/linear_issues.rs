@@ -0,0 +1,431 @@
+//! Issue access for Linear through its GraphQL API, authenticated with a
+//! personal API key.
+
+use db::models::task::TaskStatus;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const LINEAR_API_URL: &str = "https://api.linear.app/graphql";
+
+const ISSUE_FIELDS: &str = r#"
+    id
+    identifier
+    title
+    description
+    url
+    priority
+    createdAt
+    updatedAt
+    state { id name type position }
+    labels { nodes { name } }
+"#;
+
+#[derive(Debug, Error)]
+pub enum LinearIssuesError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Linear API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Linear rejected the API key; update it in project settings")]
+    Unauthorized,
+    #[error("Linear GraphQL error: {0}")]
+    GraphQl(String),
+    #[error("Not found in Linear: {0}")]
+    NotFound(String),
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct LinearTeam {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+/// A column of a team's workflow. `type` is one of `triage`, `backlog`,
+/// `unstarted`, `started`, `completed` or `canceled`.
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct LinearWorkflowState {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub state_type: String,
+    pub position: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all(deserialize = "camelCase"))]
+pub struct LinearIssue {
+    pub id: String,
+    /// Team key and number, e.g. `ENG-123`
+    pub identifier: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub url: String,
+    /// 0 = none, 1 = urgent ... 4 = low
+    pub priority: f64,
+    pub state: LinearWorkflowState,
+    #[serde(deserialize_with = "label_names")]
+    pub labels: Vec<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+fn label_names<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Label {
+        name: String,
+    }
+    #[derive(Deserialize)]
+    struct Labels {
+        nodes: Vec<Label>,
+    }
+    Ok(Labels::deserialize(deserializer)?
+        .nodes
+        .into_iter()
+        .map(|label| label.name)
+        .collect())
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse<T> {
+    data: Option<T>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Nodes<T> {
+    nodes: Vec<T>,
+}
+
+pub struct LinearIssuesService {
+    http: HttpClient,
+}
+
+impl LinearIssuesService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    async fn query<T: DeserializeOwned>(
+        &self,
+        api_key: &str,
+        query: &str,
+        variables: Value,
+    ) -> Result<T, LinearIssuesError> {
+        let response = self
+            .http
+            .post(LINEAR_API_URL)
+            .header("Authorization", api_key)
+            .header("Content-Type", "application/json")
+            .json(&json!({ "query": query, "variables": variables }))
+            .send_via(&self.http)
+            .await?;
+
+        let status = response.status();
+        if status.as_u16() == 401 {
+            return Err(LinearIssuesError::Unauthorized);
+        }
+        let body = response.text().await?;
+        // GraphQL errors come back with a 400 and a JSON body, so parse first
+        let parsed: GraphQlResponse<T> = match serde_json::from_str(&body) {
+            Ok(parsed) => parsed,
+            Err(_) if !status.is_success() => {
+                return Err(LinearIssuesError::Api {
+                    status: status.as_u16(),
+                    message: body,
+                });
+            }
+            Err(e) => return Err(LinearIssuesError::ParseError(e.to_string())),
+        };
+        if !parsed.errors.is_empty() {
+            let messages: Vec<String> = parsed.errors.into_iter().map(|e| e.message).collect();
+            return Err(LinearIssuesError::GraphQl(messages.join("; ")));
+        }
+        parsed
+            .data
+            .ok_or_else(|| LinearIssuesError::ParseError("response has no data".to_string()))
+    }
+
+    /// Teams the API key's user belongs to
+    pub async fn list_teams(&self, api_key: &str) -> Result<Vec<LinearTeam>, LinearIssuesError> {
+        #[derive(Deserialize)]
+        struct Data {
+            teams: Nodes<LinearTeam>,
+        }
+        let data: Data = self
+            .query(
+                api_key,
+                "query { teams { nodes { id key name } } }",
+                json!({}),
+            )
+            .await?;
+        Ok(data.teams.nodes)
+    }
+
+    pub async fn get_team(
+        &self,
+        api_key: &str,
+        team_id: &str,
+    ) -> Result<LinearTeam, LinearIssuesError> {
+        #[derive(Deserialize)]
+        struct Data {
+            team: Option<LinearTeam>,
+        }
+        let data: Data = self
+            .query(
+                api_key,
+                "query($id: String!) { team(id: $id) { id key name } }",
+                json!({ "id": team_id }),
+            )
+            .await?;
+        data.team
+            .ok_or_else(|| LinearIssuesError::NotFound(format!("team {team_id}")))
+    }
+
+    /// Open issues of a team, i.e. those not completed or canceled, optionally
+    /// only those with one of the comma-separated `labels`
+    pub async fn list_issues(
+        &self,
+        api_key: &str,
+        team_id: &str,
+        labels: Option<&str>,
+        first: i32,
+    ) -> Result<Vec<LinearIssue>, LinearIssuesError> {
+        #[derive(Deserialize)]
+        struct Data {
+            issues: Nodes<LinearIssue>,
+        }
+        let mut filter = json!({
+            "team": { "id": { "eq": team_id } },
+            "state": { "type": { "nin": ["completed", "canceled"] } },
+        });
+        let labels: Vec<&str> = labels
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .collect();
+        if !labels.is_empty() {
+            filter["labels"] = json!({ "name": { "in": labels } });
+        }
+        let query = format!(
+            "query($filter: IssueFilter, $first: Int) {{
+                issues(filter: $filter, first: $first, orderBy: updatedAt) {{
+                    nodes {{ {ISSUE_FIELDS} }}
+                }}
+            }}"
+        );
+        let data: Data = self
+            .query(
+                api_key,
+                &query,
+                json!({ "filter": filter, "first": first.clamp(1, 250) }),
+            )
+            .await?;
+        Ok(data.issues.nodes)
+    }
+
+    /// An issue by its id or identifier, e.g. `ENG-123`
+    pub async fn get_issue(
+        &self,
+        api_key: &str,
+        issue_id: &str,
+    ) -> Result<LinearIssue, LinearIssuesError> {
+        #[derive(Deserialize)]
+        struct Data {
+            issue: Option<LinearIssue>,
+        }
+        let query = format!("query($id: String!) {{ issue(id: $id) {{ {ISSUE_FIELDS} }} }}");
+        let data: Data = self
+            .query(api_key, &query, json!({ "id": issue_id }))
+            .await?;
+        data.issue
+            .ok_or_else(|| LinearIssuesError::NotFound(format!("issue {issue_id}")))
+    }
+
+    pub async fn list_workflow_states(
+        &self,
+        api_key: &str,
+        team_id: &str,
+    ) -> Result<Vec<LinearWorkflowState>, LinearIssuesError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Data {
+            workflow_states: Nodes<LinearWorkflowState>,
+        }
+        let data: Data = self
+            .query(
+                api_key,
+                "query($teamId: ID!) {
+                    workflowStates(filter: { team: { id: { eq: $teamId } } }) {
+                        nodes { id name type position }
+                    }
+                }",
+                json!({ "teamId": team_id }),
+            )
+            .await?;
+        Ok(data.workflow_states.nodes)
+    }
+
+    pub async fn update_issue_state(
+        &self,
+        api_key: &str,
+        issue_id: &str,
+        state_id: &str,
+    ) -> Result<(), LinearIssuesError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Data {
+            issue_update: Success,
+        }
+        #[derive(Deserialize)]
+        struct Success {
+            success: bool,
+        }
+        let data: Data = self
+            .query(
+                api_key,
+                "mutation($id: String!, $stateId: String!) {
+                    issueUpdate(id: $id, input: { stateId: $stateId }) { success }
+                }",
+                json!({ "id": issue_id, "stateId": state_id }),
+            )
+            .await?;
+        if !data.issue_update.success {
+            return Err(LinearIssuesError::GraphQl(format!(
+                "issue {issue_id} was not updated"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Comment on an issue, given its id or identifier
+    pub async fn add_comment(
+        &self,
+        api_key: &str,
+        issue_id: &str,
+        body: &str,
+    ) -> Result<(), LinearIssuesError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Data {
+            comment_create: Success,
+        }
+        #[derive(Deserialize)]
+        struct Success {
+            success: bool,
+        }
+        let data: Data = self
+            .query(
+                api_key,
+                "mutation($issueId: String!, $body: String!) {
+                    commentCreate(input: { issueId: $issueId, body: $body }) { success }
+                }",
+                json!({ "issueId": issue_id, "body": body }),
+            )
+            .await?;
+        if !data.comment_create.success {
+            return Err(LinearIssuesError::GraphQl(format!(
+                "comment on issue {issue_id} was not created"
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for LinearIssuesService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The workflow state a task moving to `status` should put its Linear issue
+/// in, if any. In Progress takes the team's first started state (preferring
+/// one named "In Progress"), In Review a started state with "review" in its
+/// name, and Done the first completed state. Other statuses leave the issue
+/// alone.
+pub fn state_for_status<'a>(
+    states: &'a [LinearWorkflowState],
+    status: &TaskStatus,
+) -> Option<&'a LinearWorkflowState> {
+    let first_of_type = |state_type: &str, preferred: &str| {
+        let mut candidates: Vec<&LinearWorkflowState> = states
+            .iter()
+            .filter(|state| state.state_type == state_type)
+            .collect();
+        candidates.sort_by(|a, b| a.position.total_cmp(&b.position));
+        candidates
+            .iter()
+            .find(|state| state.name.eq_ignore_ascii_case(preferred))
+            .or_else(|| candidates.first())
+            .copied()
+    };
+    match status {
+        TaskStatus::InProgress => first_of_type("started", "In Progress"),
+        TaskStatus::InReview => states.iter().find(|state| {
+            state.state_type == "started" && state.name.to_lowercase().contains("review")
+        }),
+        TaskStatus::Done => first_of_type("completed", "Done"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(name: &str, state_type: &str, position: f64) -> LinearWorkflowState {
+        LinearWorkflowState {
+            id: name.to_lowercase().replace(' ', "-"),
+            name: name.to_string(),
+            state_type: state_type.to_string(),
+            position,
+        }
+    }
+
+    #[test]
+    fn maps_task_statuses_to_workflow_states() {
+        let states = vec![
+            state("Todo", "unstarted", 0.0),
+            state("Code Review", "started", 2.0),
+            state("Doing", "started", 1.0),
+            state("Shipped", "completed", 3.0),
+            state("Canceled", "canceled", 4.0),
+        ];
+        let name = |status| state_for_status(&states, &status).map(|s| s.name.as_str());
+
+        assert_eq!(name(TaskStatus::InProgress), Some("Doing"));
+        assert_eq!(name(TaskStatus::InReview), Some("Code Review"));
+        assert_eq!(name(TaskStatus::Done), Some("Shipped"));
+        assert_eq!(name(TaskStatus::Todo), None);
+
+        let without_review = vec![
+            state("In Progress", "started", 5.0),
+            state("Next", "started", 1.0),
+        ];
+        assert_eq!(
+            state_for_status(&without_review, &TaskStatus::InProgress).map(|s| s.name.as_str()),
+            Some("In Progress")
+        );
+        assert!(state_for_status(&without_review, &TaskStatus::InReview).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+//! Schedules for maintenance jobs: recurring agent runs such as dependency bumps
+//! or lint fixes, written as standard five-field cron expressions evaluated in
+//! UTC.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use db::models::maintenance_job::MaintenanceJob;
+
+/// A parsed `minute hour day-of-month month day-of-week` expression. Each field
+/// accepts `*`, single values, `a-b` ranges, `/n` steps and comma-separated
+/// lists; day-of-week runs 0-7 with both 0 and 7 meaning Sunday. `@hourly`,
+/// `@daily`, `@weekly` and `@monthly` are accepted as shorthands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+/// How far ahead to look for the next run before treating a schedule as never
+/// matching, e.g. `0 0 30 2 *`
+const SEARCH_YEARS: i64 = 5;
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expression = match expression.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            expression => expression,
+        };
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(format!(
+                "Expected 5 fields (minute hour day-of-month month day-of-week), got {}",
+                fields.len()
+            ));
+        };
+
+        let mut days_of_week = parse_field(day_of_week, 0, 7, "day-of-week")?;
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59, "minute")?,
+            hours: parse_field(hour, 0, 23, "hour")?,
+            days_of_month: parse_field(day_of_month, 1, 31, "day-of-month")?,
+            months: parse_field(month, 1, 12, "month")?,
+            days_of_week,
+            any_day_of_month: day_of_month == "*",
+            any_day_of_week: day_of_week == "*",
+        })
+    }
+
+    /// The first minute strictly after `after` that matches, if any within the
+    /// next few years
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let limit = after + Duration::days(366 * SEARCH_YEARS);
+        let mut at = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        while at <= limit {
+            if !has(self.months, at.month()) {
+                let (year, month) = match at.month() {
+                    12 => (at.year() + 1, 1),
+                    month => (at.year(), month + 1),
+                };
+                at = midnight(NaiveDate::from_ymd_opt(year, month, 1)?);
+            } else if !self.matches_day(at) {
+                at = midnight(at.date_naive().succ_opt()?);
+            } else if !has(self.hours, at.hour()) {
+                at = at.with_minute(0)? + Duration::hours(1);
+            } else if !has(self.minutes, at.minute()) {
+                at += Duration::minutes(1);
+            } else {
+                return Some(at);
+            }
+        }
+        None
+    }
+
+    /// Whether a run was scheduled after `since` and at or before `now`
+    pub fn is_due(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        self.next_after(since).is_some_and(|next| next <= now)
+    }
+
+    /// Cron's rule: when both day fields are restricted, matching either one is
+    /// enough
+    fn matches_day(&self, at: DateTime<Utc>) -> bool {
+        let day_of_month = has(self.days_of_month, at.day());
+        let day_of_week = has(self.days_of_week, at.weekday().num_days_from_sunday());
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (true, false) => day_of_week,
+            (false, true) => day_of_month,
+            (false, false) => day_of_month || day_of_week,
+        }
+    }
+}
+
+/// The first of `jobs` with a run scheduled since it last ran (or was created).
+/// Jobs with an invalid schedule are skipped.
+pub fn next_due_job(jobs: &[MaintenanceJob]) -> Option<&MaintenanceJob> {
+    let now = Utc::now();
+    jobs.iter()
+        .find(|job| match CronSchedule::parse(&job.schedule) {
+            Ok(schedule) => schedule.is_due(job.last_run_at.unwrap_or(job.created_at), now),
+            Err(e) => {
+                tracing::warn!("Skipping maintenance job {}: {}", job.id, e);
+                false
+            }
+        })
+}
+
+fn has(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+fn midnight(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+}
+
+/// Parse one field into a bit set of the values it allows
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, String> {
+    let invalid = || format!("Invalid {name} field: {field}");
+    let value = |s: &str| -> Result<u32, String> {
+        let value: u32 = s.parse().map_err(|_| invalid())?;
+        if value < min || value > max {
+            return Err(format!(
+                "{name} must be between {min} and {max}, got {value}"
+            ));
+        }
+        Ok(value)
+    };
+
+    let mut set = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| invalid())?;
+                if step == 0 {
+                    return Err(invalid());
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            range => match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                // `5/15` runs from 5 to the end of the range
+                None if step > 1 => (value(range)?, max),
+                None => {
+                    let single = value(range)?;
+                    (single, single)
+                }
+            },
+        };
+        if start > end {
+            return Err(invalid());
+        }
+        for value in (start..=end).step_by(step as usize) {
+            set |= 1 << value;
+        }
+    }
+    Ok(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn finds_next_run_across_days_and_weekends() {
+        let weekdays = CronSchedule::parse("*/15 9-17 * * 1-5").unwrap();
+        // Friday evening rolls over to Monday morning
+        assert_eq!(
+            weekdays.next_after(at("2026-01-09T17:50:00Z")),
+            Some(at("2026-01-12T09:00:00Z"))
+        );
+        assert_eq!(
+            weekdays.next_after(at("2026-01-12T09:00:30Z")),
+            Some(at("2026-01-12T09:15:00Z"))
+        );
+
+        let daily = CronSchedule::parse("@daily").unwrap();
+        assert!(daily.is_due(at("2026-01-09T12:00:00Z"), at("2026-01-10T00:00:00Z")));
+        assert!(!daily.is_due(at("2026-01-10T00:00:00Z"), at("2026-01-10T23:59:00Z")));
+
+        let sundays = CronSchedule::parse("0 3 * * 7").unwrap();
+        assert_eq!(
+            sundays.next_after(at("2026-01-09T00:00:00Z")),
+            Some(at("2026-01-11T03:00:00Z"))
+        );
+        assert_eq!(
+            CronSchedule::parse("0 0 30 2 *")
+                .unwrap()
+                .next_after(at("2026-01-01T00:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("0 5-1 * * *").is_err());
+        assert!(CronSchedule::parse("0 0 * * mon").is_err());
+    }
+}
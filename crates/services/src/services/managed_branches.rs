@@ -0,0 +1,136 @@
+//! Deletes attempt branches once their worktrees are cleaned up. Only branches
+//! vibe-kanban recorded creating are touched, and only when they are already
+//! merged into the branch they started from or their changes are known to have
+//! landed; anything else is left alone.
+
+use db::models::{managed_branch::ManagedBranch, repo::Repo};
+use sqlx::SqlitePool;
+
+use crate::services::git::GitService;
+
+/// Delete `branch_name` from each repo whose worktree for it was just removed
+pub async fn cleanup_branches(
+    pool: &SqlitePool,
+    git: &GitService,
+    repos: &[Repo],
+    branch_name: &str,
+) {
+    for repo in repos {
+        let managed = match ManagedBranch::find_by_repo_and_name(pool, repo.id, branch_name).await {
+            Ok(Some(managed)) if managed.deleted_at.is_none() => managed,
+            Ok(_) => {
+                tracing::debug!(
+                    "Keeping branch {} in {}: not created by vibe-kanban",
+                    branch_name,
+                    repo.name
+                );
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to look up managed branch {}: {}", branch_name, e);
+                continue;
+            }
+        };
+
+        if !git
+            .check_branch_exists(&repo.path, branch_name)
+            .unwrap_or(true)
+        {
+            let _ = ManagedBranch::mark_deleted(pool, managed.id).await;
+            continue;
+        }
+
+        match git.delete_branch_if_merged(&repo.path, branch_name, &managed.base_branch) {
+            Ok(true) => {
+                tracing::info!("Deleted merged branch {} in {}", branch_name, repo.name);
+                let _ = ManagedBranch::mark_deleted(pool, managed.id).await;
+            }
+            Ok(false) => tracing::info!(
+                "Keeping branch {} in {}: not merged into {} or checked out",
+                branch_name,
+                repo.name,
+                managed.base_branch
+            ),
+            Err(e) => tracing::warn!(
+                "Failed to delete branch {} in {}: {}",
+                branch_name,
+                repo.name,
+                e
+            ),
+        }
+    }
+}
+
+/// Delete `branch_name` from each repo after its changes landed through a
+/// merge or merged PR, optionally on the remote too. Unlike [`cleanup_branches`]
+/// this doesn't require the branch to be merged locally, since squash and
+/// rebase merges leave the branch tip out of the target's history.
+pub async fn delete_landed_branches(
+    pool: &SqlitePool,
+    git: &GitService,
+    repos: &[Repo],
+    branch_name: &str,
+    delete_remote: bool,
+) {
+    for repo in repos {
+        let managed = match ManagedBranch::find_by_repo_and_name(pool, repo.id, branch_name).await {
+            Ok(Some(managed)) => managed,
+            Ok(None) => {
+                tracing::debug!(
+                    "Keeping branch {} in {}: not created by vibe-kanban",
+                    branch_name,
+                    repo.name
+                );
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("Failed to look up managed branch {}: {}", branch_name, e);
+                continue;
+            }
+        };
+
+        if managed.deleted_at.is_none() {
+            let deleted = if git
+                .check_branch_exists(&repo.path, branch_name)
+                .unwrap_or(false)
+            {
+                git.delete_branch(&repo.path, branch_name)
+            } else {
+                Ok(true)
+            };
+            match deleted {
+                Ok(true) => {
+                    tracing::info!("Deleted landed branch {} in {}", branch_name, repo.name);
+                    let _ = ManagedBranch::mark_deleted(pool, managed.id).await;
+                }
+                Ok(false) => tracing::info!(
+                    "Keeping branch {} in {}: checked out",
+                    branch_name,
+                    repo.name
+                ),
+                Err(e) => tracing::warn!(
+                    "Failed to delete branch {} in {}: {}",
+                    branch_name,
+                    repo.name,
+                    e
+                ),
+            }
+        }
+
+        if delete_remote
+            && git
+                .check_remote_branch_exists(&repo.path, branch_name)
+                .unwrap_or(false)
+        {
+            match git.delete_remote_branch(&repo.path, branch_name) {
+                Ok(()) => tracing::info!("Deleted remote branch {} of {}", branch_name, repo.name),
+                Err(e) => tracing::warn!(
+                    "Failed to delete remote branch {} of {}: {}",
+                    branch_name,
+                    repo.name,
+                    e
+                ),
+            }
+        }
+    }
+}
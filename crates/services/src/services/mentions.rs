@@ -0,0 +1,131 @@
+//! `@username` mentions in task descriptions and diff comments. Mentions of
+//! local users are stored per source and the newly mentioned users are sent a
+//! notification and get the mention in their feed, so editing a description
+//! doesn't notify the same user twice.
+
+use std::sync::LazyLock;
+
+use db::models::{
+    feed_event::{CreateFeedEvent, FeedEvent, FeedEventKind},
+    mention::{Mention, MentionSource},
+    notification::{CreateNotification, Notification, NotificationKind},
+    task::Task,
+    user::User,
+};
+use regex::Regex;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// `@name` at the start of the text or after a character that can't be part of
+/// a word, so email addresses aren't read as mentions
+static MENTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|[^\w@.])@(?P<name>[A-Za-z0-9_][A-Za-z0-9_.-]*)").unwrap());
+
+/// Length of the text quoted in a mention notification
+const EXCERPT_CHARS: usize = 200;
+
+/// Where the mentions being recorded were written
+pub struct MentionContext<'a> {
+    pub source: MentionSource,
+    /// The task or diff comment
+    pub source_id: Uuid,
+    pub task: &'a Task,
+    pub workspace_id: Option<Uuid>,
+    /// The user who wrote the text, when known
+    pub author_id: Option<Uuid>,
+    pub author_name: Option<&'a str>,
+}
+
+/// Usernames mentioned in `text`, in order of appearance and without duplicates.
+/// Punctuation ending a sentence after a mention isn't part of the name.
+pub fn parse_mentions(text: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for captures in MENTION.captures_iter(text) {
+        let name = captures["name"].trim_end_matches(['.', '-']);
+        if !name.is_empty() && !names.iter().any(|seen| seen == name) {
+            names.push(name.to_string());
+        }
+    }
+    names
+}
+
+fn excerpt(text: &str) -> String {
+    let text = text.trim();
+    if text.chars().count() <= EXCERPT_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(EXCERPT_CHARS).collect();
+    format!("{}…", cut.trim_end())
+}
+
+/// Store the local users `text` mentions and notify the ones it didn't mention
+/// before. Unknown names and the author mentioning themselves are ignored.
+/// Returns how many users were notified.
+pub async fn record_mentions(
+    pool: &SqlitePool,
+    ctx: &MentionContext<'_>,
+    text: &str,
+) -> Result<usize, sqlx::Error> {
+    let mut user_ids = Vec::new();
+    for name in parse_mentions(text) {
+        if let Some(user) = User::find_by_username(pool, &name).await?
+            && Some(user.id) != ctx.author_id
+        {
+            user_ids.push(user.id);
+        }
+    }
+
+    let added = Mention::replace_for_source(
+        pool,
+        ctx.source,
+        ctx.source_id,
+        ctx.task.id,
+        ctx.author_id,
+        &user_ids,
+    )
+    .await?;
+
+    let author = ctx.author_name.unwrap_or("Someone");
+    let place = match ctx.source {
+        MentionSource::TaskDescription => "the description of",
+        MentionSource::DiffComment => "a review comment on",
+    };
+    for user_id in &added {
+        let notification = CreateNotification {
+            kind: NotificationKind::Mention,
+            title: format!("{} mentioned you in {} {}", author, place, ctx.task.title),
+            message: excerpt(text),
+            project_id: Some(ctx.task.project_id),
+            task_id: Some(ctx.task.id),
+            workspace_id: ctx.workspace_id,
+        };
+        Notification::create(pool, *user_id, &notification).await?;
+        let feed_event = CreateFeedEvent {
+            kind: FeedEventKind::Mentioned,
+            title: ctx.task.title.clone(),
+            project_id: Some(ctx.task.project_id),
+            task_id: Some(ctx.task.id),
+            workspace_id: ctx.workspace_id,
+        };
+        FeedEvent::create(pool, *user_id, &feed_event).await?;
+    }
+    Ok(added.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_mentions() {
+        assert_eq!(
+            parse_mentions("@alice can you pair with @bob.smith? Thanks @alice."),
+            vec!["alice", "bob.smith"]
+        );
+        assert_eq!(
+            parse_mentions("(cc @carol_1, @dave-ops)"),
+            vec!["carol_1", "dave-ops"]
+        );
+        assert!(parse_mentions("mail me@example.com or @@eve or @ nobody").is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+//! Tidies up after a workspace's changes land, through a local merge or a
+//! merged pull request: records the merge commit, removes the worktrees and
+//! deletes the workspace branch, locally and optionally on the remote.
+
+use std::path::PathBuf;
+
+use db::models::{
+    execution_process::ExecutionProcess,
+    gitlab_merge_request::GitLabMergeRequest,
+    merge::{Merge, MergeStatus},
+    workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::services::{
+    config::Config, git::GitService, managed_branches, workspace_manager::WorkspaceManager,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct MergeCleanupOptions {
+    pub keep_workspace: bool,
+    pub keep_branches: bool,
+    pub delete_remote_branch: bool,
+}
+
+impl MergeCleanupOptions {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            keep_workspace: config.keep_workspace_after_merge,
+            keep_branches: config.keep_branches_after_cleanup,
+            delete_remote_branch: config.delete_remote_branch_after_merge,
+        }
+    }
+}
+
+/// Whether every repository of the workspace has landed, through a direct
+/// merge or a merged pull or merge request
+async fn all_repos_landed(pool: &SqlitePool, workspace_id: Uuid) -> Result<bool, sqlx::Error> {
+    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace_id).await?;
+    let merges = Merge::find_by_workspace_id(pool, workspace_id).await?;
+    let merge_requests = GitLabMergeRequest::find_by_workspace_id(pool, workspace_id).await?;
+
+    Ok(repos.iter().all(|repo| {
+        merges.iter().any(|merge| match merge {
+            Merge::Direct(direct) => direct.repo_id == repo.id,
+            Merge::Pr(pr) => {
+                pr.repo_id == repo.id && matches!(pr.pr_info.status, MergeStatus::Merged)
+            }
+        }) || merge_requests
+            .iter()
+            .any(|mr| mr.repo_id == repo.id && mr.state == "merged")
+    }))
+}
+
+/// Record `merge_commit_sha` on the workspace, then clean the workspace up
+/// once all of its repositories have landed and nothing is running in it.
+/// Returns whether the workspace was cleaned up.
+pub async fn cleanup_after_merge(
+    pool: &SqlitePool,
+    git: &GitService,
+    workspace: &Workspace,
+    merge_commit_sha: Option<&str>,
+    options: MergeCleanupOptions,
+) -> Result<bool, sqlx::Error> {
+    if let Some(sha) = merge_commit_sha {
+        Workspace::record_merge_commit(pool, workspace.id, sha).await?;
+    }
+    if options.keep_workspace || !all_repos_landed(pool, workspace.id).await? {
+        return Ok(false);
+    }
+    if ExecutionProcess::has_running_processes_for_workspace(pool, workspace.id).await? {
+        tracing::info!(
+            "Not cleaning up merged workspace {}: processes are still running",
+            workspace.id
+        );
+        return Ok(false);
+    }
+
+    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+    if let Some(container_ref) = &workspace.container_ref {
+        WorkspaceManager::cleanup_workspace(
+            &PathBuf::from(container_ref),
+            &repos,
+            &workspace.branch,
+        )
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                "Failed to clean up merged workspace {}: {}",
+                workspace.id,
+                e
+            );
+        });
+        Workspace::clear_container_ref(pool, workspace.id).await?;
+    }
+    if !options.keep_branches {
+        managed_branches::delete_landed_branches(
+            pool,
+            git,
+            &repos,
+            &workspace.branch,
+            options.delete_remote_branch,
+        )
+        .await;
+    }
+
+    tracing::info!("Cleaned up workspace {} after merge", workspace.id);
+    Ok(true)
+}
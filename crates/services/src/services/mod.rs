@@ -1,28 +1,80 @@
 pub mod analytics;
 pub mod approvals;
+pub mod asana_tasks;
 pub mod auth;
+pub mod azure_work_items;
+pub mod bitbucket_issues;
+pub mod board_history;
+pub mod calendar;
+pub mod capacity;
+pub mod change_summary;
+pub mod ci_status;
+pub mod clickup_tasks;
 pub mod config;
 pub mod container;
+pub mod diff_comments;
+pub mod diff_exclusions;
+pub mod diff_size_guard;
 pub mod diff_stream;
+pub mod email_intake;
 pub mod events;
+pub mod executor_recommendation;
+pub mod executor_slots;
 pub mod file_ranker;
 pub mod file_search_cache;
 pub mod filesystem;
 pub mod filesystem_watcher;
+pub mod follow_up_tasks;
 pub mod git;
+pub mod gitea_issues;
 pub mod github;
 pub mod github_issues;
+pub mod github_rate_limit;
 pub mod gitlab_issues;
+pub mod http_client;
 pub mod image;
+pub mod issue_comments;
+pub mod issue_images;
+pub mod issue_provider;
+pub mod issue_sync_scheduler;
+pub mod linear_issues;
+pub mod log_redaction;
+pub mod maintenance;
+pub mod managed_branches;
+pub mod mentions;
+pub mod merge_cleanup;
 pub mod notification;
+pub mod notion_database;
 pub mod oauth_credentials;
+pub mod path_rules;
 pub mod pr_monitor;
+pub mod pr_set;
+pub mod process_tree;
 pub mod project;
 pub mod queued_message;
+pub mod quick_add;
+pub mod recovery;
 pub mod remote_client;
 pub mod repo;
+pub mod rest_hooks;
+pub mod secret_scan;
 pub mod sequential_queue;
 pub mod share;
+pub mod shortcut_stories;
+pub mod sla_monitor;
+pub mod slack;
+pub mod status_page;
+pub mod status_transitions;
+pub mod storage_stats;
+pub mod task_duplicates;
+pub mod task_locks;
+pub mod task_validation;
+pub mod token_budget;
+pub mod token_cipher;
+pub mod user_feed;
 pub mod vortex_issues;
+pub mod vortex_oauth;
+pub mod webhooks;
 pub mod workspace_manager;
+pub mod workspace_manifest;
 pub mod worktree_manager;
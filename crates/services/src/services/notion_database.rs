@@ -0,0 +1,419 @@
+//! Rows of a Notion database, read through the public API with an internal
+//! integration secret. The database has to be shared with the integration.
+
+use db::models::task::TaskStatus;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const API_BASE: &str = "https://api.notion.com/v1";
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Rows fetched per query request; the API's limit
+const PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Error)]
+pub enum NotionError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Notion API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Notion rejected the integration secret; update it in project settings")]
+    Unauthorized,
+    #[error(
+        "Not found in Notion; check the id and that the database is shared with the integration"
+    )]
+    NotFound,
+    #[error("Invalid Notion database id: {0}")]
+    InvalidDatabaseId(String),
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct NotionAttachment {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct NotionRow {
+    /// Page id
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub html_url: String,
+    /// Files from every file property of the row
+    pub attachments: Vec<NotionAttachment>,
+}
+
+impl NotionRow {
+    /// Task status for the row's Notion status
+    pub fn task_status(&self) -> TaskStatus {
+        self.status
+            .as_deref()
+            .map(map_status)
+            .unwrap_or(TaskStatus::Todo)
+    }
+}
+
+/// Which properties hold a row's status and description
+#[derive(Debug, Clone)]
+pub struct NotionPropertyMap {
+    pub status: String,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct QueryResponse {
+    results: Vec<Value>,
+    #[serde(default)]
+    has_more: bool,
+    next_cursor: Option<String>,
+}
+
+pub struct NotionService {
+    http: HttpClient,
+}
+
+impl NotionService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    /// The 32 hex digit id of a database, given the id itself (with or
+    /// without dashes) or its URL, in dashed form
+    pub fn normalize_database_id(input: &str) -> Result<String, NotionError> {
+        let trimmed = input.trim();
+        // In a URL the id ends the last path segment, after the title slug
+        let candidate = trimmed
+            .split(['?', '#'])
+            .next()
+            .unwrap_or_default()
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+        let hex: String = candidate.chars().filter(|c| *c != '-').collect();
+        let hex = hex
+            .get(hex.len().saturating_sub(32)..)
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(NotionError::InvalidDatabaseId(input.to_string()));
+        }
+        Ok(format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        ))
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<Value, NotionError> {
+        let response = request.send_via(&self.http).await?;
+        let status = response.status();
+        match status.as_u16() {
+            401 => return Err(NotionError::Unauthorized),
+            404 => return Err(NotionError::NotFound),
+            _ => {}
+        }
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(NotionError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| NotionError::ParseError(e.to_string()))
+    }
+
+    fn request(&self, method: reqwest::Method, url: &str, token: &str) -> reqwest::RequestBuilder {
+        self.http
+            .request(method, url)
+            .bearer_auth(token)
+            .header("Notion-Version", NOTION_VERSION)
+    }
+
+    /// Check that the database exists and is shared with the integration
+    pub async fn check_database(&self, token: &str, database_id: &str) -> Result<(), NotionError> {
+        let url = format!("{API_BASE}/databases/{database_id}");
+        self.send(self.request(reqwest::Method::GET, &url, token))
+            .await
+            .map(|_| ())
+    }
+
+    /// Up to `limit` rows, most recently edited first
+    pub async fn query_rows(
+        &self,
+        token: &str,
+        database_id: &str,
+        properties: &NotionPropertyMap,
+        limit: usize,
+    ) -> Result<Vec<NotionRow>, NotionError> {
+        let url = format!("{API_BASE}/databases/{database_id}/query");
+        let limit = limit.max(1);
+        let mut rows = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut body = json!({
+                "page_size": PAGE_SIZE.min(limit - rows.len()),
+                "sorts": [{ "timestamp": "last_edited_time", "direction": "descending" }],
+            });
+            if let Some(cursor) = &cursor {
+                body["start_cursor"] = json!(cursor);
+            }
+            let response: QueryResponse = serde_json::from_value(
+                self.send(self.request(reqwest::Method::POST, &url, token).json(&body))
+                    .await?,
+            )
+            .map_err(|e| NotionError::ParseError(e.to_string()))?;
+
+            rows.extend(
+                response
+                    .results
+                    .iter()
+                    .filter_map(|page| parse_page(page, properties)),
+            );
+            cursor = response.next_cursor;
+            if !response.has_more || cursor.is_none() || rows.len() >= limit {
+                break;
+            }
+        }
+        Ok(rows)
+    }
+
+    pub async fn get_row(
+        &self,
+        token: &str,
+        page_id: &str,
+        properties: &NotionPropertyMap,
+    ) -> Result<NotionRow, NotionError> {
+        let url = format!("{API_BASE}/pages/{}", urlencoding::encode(page_id.trim()));
+        let page = self
+            .send(self.request(reqwest::Method::GET, &url, token))
+            .await?;
+        parse_page(&page, properties).ok_or(NotionError::NotFound)
+    }
+
+    /// Download a file attachment. Notion-hosted files come with a signed
+    /// URL, so no credentials are sent.
+    pub async fn download_attachment(
+        &self,
+        attachment: &NotionAttachment,
+    ) -> Result<Vec<u8>, NotionError> {
+        let response = self.http.get(&attachment.url).send_via(&self.http).await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(NotionError::Api {
+                status: status.as_u16(),
+                message: format!("failed to download {}", attachment.name),
+            });
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Comment on a row's page. The integration needs the comment capability.
+    pub async fn add_comment(
+        &self,
+        token: &str,
+        page_id: &str,
+        text: &str,
+    ) -> Result<(), NotionError> {
+        let url = format!("{API_BASE}/comments");
+        let body = json!({
+            "parent": { "page_id": page_id },
+            "rich_text": [{ "type": "text", "text": { "content": text } }],
+        });
+        self.send(self.request(reqwest::Method::POST, &url, token).json(&body))
+            .await
+            .map(|_| ())
+    }
+}
+
+impl Default for NotionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Concatenated plain text of a rich text array
+fn plain_text(value: Option<&Value>) -> String {
+    value
+        .and_then(Value::as_array)
+        .map(|parts| {
+            parts
+                .iter()
+                .filter_map(|part| part.get("plain_text").and_then(Value::as_str))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A row from a page object. Archived pages are skipped.
+fn parse_page(page: &Value, properties: &NotionPropertyMap) -> Option<NotionRow> {
+    if page.get("archived").and_then(Value::as_bool) == Some(true)
+        || page.get("in_trash").and_then(Value::as_bool) == Some(true)
+    {
+        return None;
+    }
+    let id = page.get("id")?.as_str()?.to_string();
+    let props = page.get("properties")?.as_object()?;
+
+    let property_type = |property: &Value| {
+        property
+            .get("type")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let title = props
+        .values()
+        .find(|property| property_type(property) == "title")
+        .map(|property| plain_text(property.get("title")))
+        .unwrap_or_default();
+
+    let status = props.get(&properties.status).and_then(|property| {
+        let kind = property_type(property);
+        property
+            .get(&kind)
+            .and_then(|value| value.get("name"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    });
+
+    let description = props
+        .get(&properties.description)
+        .filter(|property| property_type(property) == "rich_text")
+        .map(|property| plain_text(property.get("rich_text")))
+        .filter(|text| !text.trim().is_empty());
+
+    let attachments = props
+        .values()
+        .filter(|property| property_type(property) == "files")
+        .filter_map(|property| property.get("files").and_then(Value::as_array))
+        .flatten()
+        .filter_map(|file| {
+            let kind = file.get("type")?.as_str()?;
+            let url = file.get(kind)?.get("url")?.as_str()?;
+            Some(NotionAttachment {
+                name: file
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .unwrap_or("attachment")
+                    .to_string(),
+                url: url.to_string(),
+            })
+        })
+        .collect();
+
+    Some(NotionRow {
+        html_url: page
+            .get("url")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        id,
+        title,
+        description,
+        status,
+        attachments,
+    })
+}
+
+/// Task status for a Notion status or select option, matched on the names
+/// Notion's templates use. Anything unrecognized is treated as to do.
+pub fn map_status(name: &str) -> TaskStatus {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "in progress" | "in-progress" | "doing" | "started" | "active" => TaskStatus::InProgress,
+        "in review" | "review" | "reviewing" => TaskStatus::InReview,
+        "done" | "complete" | "completed" | "closed" | "shipped" => TaskStatus::Done,
+        "cancelled" | "canceled" | "won't do" | "archived" => TaskStatus::Cancelled,
+        _ => TaskStatus::Todo,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_database_ids() {
+        let dashed = "0f2b3c4d-5e6f-4a1b-9c8d-7e6f5a4b3c2d";
+        assert_eq!(
+            NotionService::normalize_database_id("0f2b3c4d5e6f4a1b9c8d7e6f5a4b3c2d").unwrap(),
+            dashed
+        );
+        assert_eq!(
+            NotionService::normalize_database_id(
+                "https://www.notion.so/acme/Roadmap-0f2b3c4d5e6f4a1b9c8d7e6f5a4b3c2d?v=123abc"
+            )
+            .unwrap(),
+            dashed
+        );
+        assert_eq!(
+            NotionService::normalize_database_id(dashed).unwrap(),
+            dashed
+        );
+        assert!(NotionService::normalize_database_id("Roadmap").is_err());
+    }
+
+    #[test]
+    fn parses_database_rows() {
+        let properties = NotionPropertyMap {
+            status: "Stage".to_string(),
+            description: "Details".to_string(),
+        };
+        let page = json!({
+            "id": "0f2b3c4d-5e6f-4a1b-9c8d-7e6f5a4b3c2d",
+            "url": "https://www.notion.so/Fix-login-0f2b3c4d5e6f4a1b9c8d7e6f5a4b3c2d",
+            "archived": false,
+            "properties": {
+                "Name": { "type": "title", "title": [
+                    { "plain_text": "Fix " }, { "plain_text": "login" }
+                ] },
+                "Stage": { "type": "select", "select": { "name": "In progress" } },
+                "Details": { "type": "rich_text", "rich_text": [
+                    { "plain_text": "Users are logged out on refresh" }
+                ] },
+                "Screenshots": { "type": "files", "files": [
+                    { "name": "before.png", "type": "file", "file": { "url": "https://files.example/before.png" } },
+                    { "name": "after.png", "type": "external", "external": { "url": "https://img.example/after.png" } }
+                ] }
+            }
+        });
+
+        let row = parse_page(&page, &properties).unwrap();
+        assert_eq!(row.title, "Fix login");
+        assert_eq!(row.status.as_deref(), Some("In progress"));
+        assert_eq!(row.task_status(), TaskStatus::InProgress);
+        assert_eq!(
+            row.description.as_deref(),
+            Some("Users are logged out on refresh")
+        );
+        let urls: Vec<&str> = row.attachments.iter().map(|a| a.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            vec![
+                "https://files.example/before.png",
+                "https://img.example/after.png"
+            ]
+        );
+
+        let archived = json!({ "id": "x", "archived": true, "properties": {} });
+        assert!(parse_page(&archived, &properties).is_none());
+    }
+}
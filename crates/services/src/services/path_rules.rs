@@ -0,0 +1,234 @@
+use std::path::Path;
+
+use db::models::{
+    repo_path_rule::{RepoPathRule, RepoPathRuleAction, RepoPathRuleKind},
+    workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
+};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use ts_rs::TS;
+use utils::diff::Diff;
+use uuid::Uuid;
+
+use crate::services::git::{DiffTarget, GitService, GitServiceError};
+
+#[derive(Debug, Error)]
+pub enum PathRuleError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error(transparent)]
+    GitService(#[from] GitServiceError),
+    #[error("Invalid path pattern '{0}': {1}")]
+    InvalidPattern(String, String),
+}
+
+/// A changed file that breaks one of its repo's path rules
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PathRuleViolation {
+    pub repo_id: Uuid,
+    pub file_path: String,
+    /// The deny rule that matched; `None` when the file is outside the allowlist
+    pub rule_id: Option<Uuid>,
+    pub pattern: Option<String>,
+    pub action: RepoPathRuleAction,
+}
+
+/// Compile a single gitignore-style pattern
+pub fn compile_pattern(pattern: &str) -> Result<Gitignore, PathRuleError> {
+    let invalid =
+        |e: ignore::Error| PathRuleError::InvalidPattern(pattern.to_string(), e.to_string());
+    let mut builder = GitignoreBuilder::new("");
+    builder.add_line(None, pattern).map_err(invalid)?;
+    builder.build().map_err(invalid)
+}
+
+/// Compiled path rules for one repository
+pub struct PathRuleSet {
+    repo_id: Uuid,
+    deny: Vec<(RepoPathRule, Gitignore)>,
+    allow: Vec<Gitignore>,
+    allow_action: Option<RepoPathRuleAction>,
+}
+
+impl PathRuleSet {
+    pub fn new(repo_id: Uuid, rules: Vec<RepoPathRule>) -> Self {
+        let mut set = Self {
+            repo_id,
+            deny: Vec::new(),
+            allow: Vec::new(),
+            allow_action: None,
+        };
+        for rule in rules {
+            let matcher = match compile_pattern(&rule.pattern) {
+                Ok(matcher) => matcher,
+                Err(e) => {
+                    tracing::warn!("Skipping path rule {}: {}", rule.id, e);
+                    continue;
+                }
+            };
+            match rule.kind {
+                RepoPathRuleKind::Deny => set.deny.push((rule, matcher)),
+                RepoPathRuleKind::Allow => {
+                    // The allowlist rejects if any of its rules asks to
+                    if set.allow_action != Some(RepoPathRuleAction::Reject) {
+                        set.allow_action = Some(rule.action);
+                    }
+                    set.allow.push(matcher);
+                }
+            }
+        }
+        set
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deny.is_empty() && self.allow.is_empty()
+    }
+
+    /// Violations for a single repo-relative path
+    pub fn check_path(&self, file_path: &str) -> Vec<PathRuleViolation> {
+        let path = Path::new(file_path);
+        let mut violations: Vec<PathRuleViolation> = self
+            .deny
+            .iter()
+            .filter(|(_, matcher)| is_match(matcher, path))
+            .map(|(rule, _)| PathRuleViolation {
+                repo_id: self.repo_id,
+                file_path: file_path.to_string(),
+                rule_id: Some(rule.id),
+                pattern: Some(rule.pattern.clone()),
+                action: rule.action,
+            })
+            .collect();
+
+        if let Some(action) = self.allow_action
+            && !self.allow.iter().any(|matcher| is_match(matcher, path))
+        {
+            violations.push(PathRuleViolation {
+                repo_id: self.repo_id,
+                file_path: file_path.to_string(),
+                rule_id: None,
+                pattern: None,
+                action,
+            });
+        }
+        violations
+    }
+
+    /// Check every path a set of diffs touches, including the source of renames
+    pub fn check_diffs(&self, diffs: &[Diff]) -> Vec<PathRuleViolation> {
+        let mut paths: Vec<&str> = diffs
+            .iter()
+            .flat_map(|diff| [diff.old_path.as_deref(), diff.new_path.as_deref()])
+            .flatten()
+            .collect();
+        paths.sort_unstable();
+        paths.dedup();
+        paths
+            .into_iter()
+            .flat_map(|path| self.check_path(path))
+            .collect()
+    }
+}
+
+fn is_match(matcher: &Gitignore, path: &Path) -> bool {
+    matcher.matched_path_or_any_parents(path, false).is_ignore()
+}
+
+/// Check the committed changes on a workspace branch against each repo's path rules.
+/// Pass `repo_id` to limit the check to a single repository.
+pub async fn check_workspace(
+    pool: &SqlitePool,
+    git: &GitService,
+    workspace: &Workspace,
+    repo_id: Option<Uuid>,
+) -> Result<Vec<PathRuleViolation>, PathRuleError> {
+    let workspace_repos = WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?;
+    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+
+    let mut violations = Vec::new();
+    for repo in repos {
+        if repo_id.is_some_and(|id| id != repo.id) {
+            continue;
+        }
+        let Some(workspace_repo) = workspace_repos.iter().find(|wr| wr.repo_id == repo.id) else {
+            continue;
+        };
+        let rules = PathRuleSet::new(repo.id, RepoPathRule::find_by_repo_id(pool, repo.id).await?);
+        if rules.is_empty() {
+            continue;
+        }
+        let diffs = git.get_diffs(
+            DiffTarget::Branch {
+                repo_path: &repo.path,
+                branch_name: &workspace.branch,
+                base_branch: &workspace_repo.target_branch,
+            },
+            None,
+        )?;
+        violations.extend(rules.check_diffs(&diffs));
+    }
+    Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn rule(pattern: &str, kind: RepoPathRuleKind, action: RepoPathRuleAction) -> RepoPathRule {
+        RepoPathRule {
+            id: Uuid::new_v4(),
+            repo_id: Uuid::nil(),
+            pattern: pattern.to_string(),
+            kind,
+            action,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn deny_rules_match_directories_and_globs() {
+        let rules = PathRuleSet::new(
+            Uuid::nil(),
+            vec![
+                rule("infra/", RepoPathRuleKind::Deny, RepoPathRuleAction::Reject),
+                rule("*.lock", RepoPathRuleKind::Deny, RepoPathRuleAction::Flag),
+            ],
+        );
+
+        let infra = rules.check_path("infra/terraform/main.tf");
+        assert_eq!(infra.len(), 1);
+        assert_eq!(infra[0].action, RepoPathRuleAction::Reject);
+        assert_eq!(
+            rules.check_path("frontend/pnpm.lock")[0].action,
+            RepoPathRuleAction::Flag
+        );
+        assert!(rules.check_path("src/infra.rs").is_empty());
+    }
+
+    #[test]
+    fn allow_rules_flag_everything_else() {
+        let rules = PathRuleSet::new(
+            Uuid::nil(),
+            vec![
+                rule("src/", RepoPathRuleKind::Allow, RepoPathRuleAction::Flag),
+                rule(
+                    "docs/**/*.md",
+                    RepoPathRuleKind::Allow,
+                    RepoPathRuleAction::Reject,
+                ),
+            ],
+        );
+
+        assert!(rules.check_path("src/lib.rs").is_empty());
+        assert!(rules.check_path("docs/guide/setup.md").is_empty());
+        let outside = rules.check_path("Cargo.toml");
+        assert_eq!(outside.len(), 1);
+        assert!(outside[0].rule_id.is_none());
+        assert_eq!(outside[0].action, RepoPathRuleAction::Reject);
+    }
+}
@@ -1,9 +1,10 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use db::{
     DBService,
     models::{
         merge::{Merge, MergeStatus, PrMerge},
+        project::Project,
         task::{Task, TaskStatus},
         workspace::{Workspace, WorkspaceError},
     },
@@ -11,12 +12,15 @@ use db::{
 use serde_json::json;
 use sqlx::error::Error as SqlxError;
 use thiserror::Error;
-use tokio::time::interval;
+use tokio::{sync::RwLock, time::interval};
 use tracing::{debug, error, info};
 
 use crate::services::{
     analytics::AnalyticsContext,
+    config::Config,
+    git::GitService,
     github::{GitHubRepoInfo, GitHubService, GitHubServiceError},
+    merge_cleanup::{self, MergeCleanupOptions},
     share::SharePublisher,
 };
 
@@ -33,6 +37,8 @@ enum PrMonitorError {
 /// Service to monitor GitHub PRs and update task status when they are merged
 pub struct PrMonitorService {
     db: DBService,
+    git: GitService,
+    config: Arc<RwLock<Config>>,
     poll_interval: Duration,
     analytics: Option<AnalyticsContext>,
     publisher: Option<SharePublisher>,
@@ -41,11 +47,15 @@ pub struct PrMonitorService {
 impl PrMonitorService {
     pub async fn spawn(
         db: DBService,
+        git: GitService,
+        config: Arc<RwLock<Config>>,
         analytics: Option<AnalyticsContext>,
         publisher: Option<SharePublisher>,
     ) -> tokio::task::JoinHandle<()> {
         let service = Self {
             db,
+            git,
+            config,
             poll_interval: Duration::from_secs(60), // Check every minute
             analytics,
             publisher,
@@ -115,45 +125,64 @@ impl PrMonitorService {
                 &self.db.pool,
                 pr_merge.id,
                 pr_status.status.clone(),
-                pr_status.merge_commit_sha,
+                pr_status.merge_commit_sha.clone(),
             )
             .await?;
 
-            // If the PR was merged, update the task status to done
+            // If the PR was merged, update the task status to done unless the
+            // project opted out
             if matches!(&pr_status.status, MergeStatus::Merged)
                 && let Some(workspace) =
                     Workspace::find_by_id(&self.db.pool, pr_merge.workspace_id).await?
             {
-                info!(
-                    "PR #{} was merged, updating task {} to done",
-                    pr_merge.pr_info.number, workspace.task_id
-                );
-                Task::update_status(&self.db.pool, workspace.task_id, TaskStatus::Done).await?;
-
-                // Track analytics event
-                if let Some(analytics) = &self.analytics
-                    && let Ok(Some(task)) = Task::find_by_id(&self.db.pool, workspace.task_id).await
+                let task = Task::find_by_id(&self.db.pool, workspace.task_id).await?;
+                let project = match &task {
+                    Some(task) => Project::find_by_id(&self.db.pool, task.project_id).await?,
+                    None => None,
+                };
+                if let Some(task) = &task
+                    && project.is_none_or(|project| project.done_on_merge)
                 {
-                    analytics.analytics_service.track_event(
-                        &analytics.user_id,
-                        "pr_merged",
-                        Some(json!({
-                            "task_id": workspace.task_id.to_string(),
-                            "workspace_id": workspace.id.to_string(),
-                            "project_id": task.project_id.to_string(),
-                        })),
+                    info!(
+                        "PR #{} was merged, updating task {} to done",
+                        pr_merge.pr_info.number, workspace.task_id
                     );
+                    Task::update_status(&self.db.pool, workspace.task_id, TaskStatus::Done).await?;
+
+                    // Track analytics event
+                    if let Some(analytics) = &self.analytics {
+                        analytics.analytics_service.track_event(
+                            &analytics.user_id,
+                            "pr_merged",
+                            Some(json!({
+                                "task_id": workspace.task_id.to_string(),
+                                "workspace_id": workspace.id.to_string(),
+                                "project_id": task.project_id.to_string(),
+                            })),
+                        );
+                    }
+
+                    if let Some(publisher) = &self.publisher
+                        && let Err(err) =
+                            publisher.update_shared_task_by_id(workspace.task_id).await
+                    {
+                        tracing::warn!(
+                            ?err,
+                            "Failed to propagate shared task update for {}",
+                            workspace.task_id
+                        );
+                    }
                 }
 
-                if let Some(publisher) = &self.publisher
-                    && let Err(err) = publisher.update_shared_task_by_id(workspace.task_id).await
-                {
-                    tracing::warn!(
-                        ?err,
-                        "Failed to propagate shared task update for {}",
-                        workspace.task_id
-                    );
-                }
+                let options = MergeCleanupOptions::from_config(&self.config.read().await);
+                merge_cleanup::cleanup_after_merge(
+                    &self.db.pool,
+                    &self.git,
+                    &workspace,
+                    pr_status.merge_commit_sha.as_deref(),
+                    options,
+                )
+                .await?;
             }
         }
 
@@ -0,0 +1,208 @@
+//! Coordinated pull requests across every repository of a multi-repo
+//! workspace: cross-linked descriptions and one combined status.
+
+use db::models::{
+    merge::{MergeStatus, PullRequestInfo},
+    workspace_ci_status::CiStatus,
+};
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Marks the start of the cross-link section so it can be replaced on re-runs
+const CROSS_LINKS_MARKER: &str = "<!-- vibe-kanban:pr-set -->";
+
+/// One repository's pull request within a workspace's PR set
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PrSetMember {
+    pub repo_id: Uuid,
+    pub repo_name: String,
+    /// `owner/repo` on GitHub
+    pub github_repo: String,
+    pub pr_number: i64,
+    pub pr_url: String,
+    pub status: MergeStatus,
+}
+
+/// How far along the PR set is as a whole
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum PrSetState {
+    /// No repository has a pull request yet
+    None,
+    /// Some repositories still lack a pull request
+    Incomplete,
+    Open,
+    /// Some pull requests merged while others are still open
+    PartiallyMerged,
+    Merged,
+    /// At least one pull request was closed without merging
+    Closed,
+}
+
+/// Combined status of a workspace's pull requests across its repositories
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PrSetStatus {
+    pub state: PrSetState,
+    pub ci_status: CiStatus,
+    pub members: Vec<PrSetMember>,
+    /// Workspace repositories without a pull request
+    pub missing_repo_ids: Vec<Uuid>,
+}
+
+impl PrSetMember {
+    pub fn from_pr(repo_id: Uuid, repo_name: String, pr: &PullRequestInfo) -> Self {
+        Self {
+            repo_id,
+            repo_name,
+            github_repo: github_repo_from_pr_url(&pr.url).unwrap_or_default(),
+            pr_number: pr.number,
+            pr_url: pr.url.clone(),
+            status: pr.status.clone(),
+        }
+    }
+}
+
+impl PrSetStatus {
+    pub fn new(
+        members: Vec<PrSetMember>,
+        missing_repo_ids: Vec<Uuid>,
+        ci_status: CiStatus,
+    ) -> Self {
+        let state = combined_state(&members, missing_repo_ids.len());
+        Self {
+            state,
+            ci_status,
+            members,
+            missing_repo_ids,
+        }
+    }
+}
+
+/// Fold member statuses together: a closed PR breaks the set, and it only
+/// counts as merged once every repository has merged
+pub fn combined_state(members: &[PrSetMember], missing: usize) -> PrSetState {
+    if members.is_empty() {
+        return PrSetState::None;
+    }
+    if members
+        .iter()
+        .any(|member| matches!(member.status, MergeStatus::Closed))
+    {
+        return PrSetState::Closed;
+    }
+    let merged = members
+        .iter()
+        .filter(|member| matches!(member.status, MergeStatus::Merged))
+        .count();
+    if missing > 0 {
+        PrSetState::Incomplete
+    } else if merged == members.len() {
+        PrSetState::Merged
+    } else if merged > 0 {
+        PrSetState::PartiallyMerged
+    } else {
+        PrSetState::Open
+    }
+}
+
+/// `owner/repo` of a pull request URL such as `https://github.com/owner/repo/pull/1`
+pub fn github_repo_from_pr_url(url: &str) -> Option<String> {
+    let path = url.split_once("://")?.1.split_once('/')?.1;
+    let (repo, _) = path.split_once("/pull/")?;
+    Some(repo.to_string())
+}
+
+/// `body` with a section linking every other pull request of the set,
+/// replacing the section from an earlier run
+pub fn with_cross_links(body: &str, members: &[PrSetMember], this_repo_id: Uuid) -> String {
+    let base = body
+        .split_once(CROSS_LINKS_MARKER)
+        .map_or(body, |(before, _)| before)
+        .trim_end();
+    if members.len() < 2 {
+        return base.to_string();
+    }
+
+    let mut section = format!("{CROSS_LINKS_MARKER}\n### Related pull requests\n\n");
+    section.push_str(&format!(
+        "This change spans {} repositories and should be merged together:\n\n",
+        members.len()
+    ));
+    for member in members {
+        let reference = format!("{}#{}", member.github_repo, member.pr_number);
+        if member.repo_id == this_repo_id {
+            section.push_str(&format!("- {reference} (this pull request)\n"));
+        } else {
+            section.push_str(&format!("- [{reference}]({})\n", member.pr_url));
+        }
+    }
+
+    if base.is_empty() {
+        section
+    } else {
+        format!("{base}\n\n{section}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, number: i64, status: MergeStatus) -> PrSetMember {
+        PrSetMember {
+            repo_id: Uuid::new_v4(),
+            repo_name: name.to_string(),
+            github_repo: format!("acme/{name}"),
+            pr_number: number,
+            pr_url: format!("https://github.com/acme/{name}/pull/{number}"),
+            status,
+        }
+    }
+
+    #[test]
+    fn set_is_merged_only_when_every_repo_merged() {
+        let open = member("api", 1, MergeStatus::Open);
+        let merged = member("web", 2, MergeStatus::Merged);
+
+        assert_eq!(combined_state(&[], 2), PrSetState::None);
+        assert_eq!(combined_state(&[open.clone()], 1), PrSetState::Incomplete);
+        assert_eq!(
+            combined_state(&[open.clone(), merged.clone()], 0),
+            PrSetState::PartiallyMerged
+        );
+        assert_eq!(
+            combined_state(&[merged.clone(), merged.clone()], 0),
+            PrSetState::Merged
+        );
+        assert_eq!(
+            combined_state(&[merged, member("cli", 3, MergeStatus::Closed)], 0),
+            PrSetState::Closed
+        );
+    }
+
+    #[test]
+    fn cross_links_point_at_the_other_pull_requests() {
+        let api = member("api", 12, MergeStatus::Open);
+        let web = member("web", 7, MergeStatus::Open);
+        let members = vec![api.clone(), web];
+
+        let body = with_cross_links("Adds the export endpoint", &members, api.repo_id);
+        assert!(body.starts_with("Adds the export endpoint\n\n"));
+        assert!(body.contains("- acme/api#12 (this pull request)\n"));
+        assert!(body.contains("- [acme/web#7](https://github.com/acme/web/pull/7)\n"));
+
+        // Re-linking replaces the section instead of stacking another one
+        let relinked = with_cross_links(&body, &members, api.repo_id);
+        assert_eq!(relinked, body);
+
+        assert_eq!(
+            with_cross_links(&body, &members[..1], api.repo_id),
+            "Adds the export endpoint"
+        );
+        assert_eq!(
+            github_repo_from_pr_url("https://github.com/acme/web/pull/7").as_deref(),
+            Some("acme/web")
+        );
+    }
+}
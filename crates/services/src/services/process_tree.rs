@@ -0,0 +1,296 @@
+//! Tracks the process tree of each running execution. Agents start browsers,
+//! dev servers and language servers that can outlive the executor, sometimes
+//! in a session of their own; whatever is left of the tree when an execution
+//! ends is killed.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde::Serialize;
+use ts_rs::TS;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, TS)]
+pub struct TrackedProcess {
+    pub pid: u32,
+    pub ppid: u32,
+    pub pgid: u32,
+    pub command: String,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct ExecutionProcessTree {
+    pub execution_process_id: Uuid,
+    pub process_group_id: u32,
+    pub processes: Vec<TrackedProcess>,
+}
+
+struct TrackedExecution {
+    workspace_id: Uuid,
+    pgid: u32,
+    /// Every descendant seen so far, keyed by pid, with its command so a
+    /// recycled pid isn't mistaken for one of ours
+    known: HashMap<u32, String>,
+}
+
+#[derive(Clone, Default)]
+pub struct ProcessTracker {
+    executions: Arc<Mutex<HashMap<Uuid, TrackedExecution>>>,
+}
+
+impl ProcessTracker {
+    /// Start tracking an execution whose leader `pid` heads its own process
+    /// group
+    pub fn register(&self, execution_process_id: Uuid, workspace_id: Uuid, pid: u32) {
+        self.executions.lock().unwrap().insert(
+            execution_process_id,
+            TrackedExecution {
+                workspace_id,
+                pgid: pid,
+                known: HashMap::new(),
+            },
+        );
+    }
+
+    /// Current process trees of the workspace's running executions
+    pub async fn trees_for_workspace(&self, workspace_id: Uuid) -> Vec<ExecutionProcessTree> {
+        let processes = list_processes().await;
+        let mut executions = self.executions.lock().unwrap();
+        executions
+            .iter_mut()
+            .filter(|(_, execution)| execution.workspace_id == workspace_id)
+            .map(|(id, execution)| ExecutionProcessTree {
+                execution_process_id: *id,
+                process_group_id: execution.pgid,
+                processes: execution.observe(&processes),
+            })
+            .collect()
+    }
+
+    /// Record the current descendants of every execution, so ones that
+    /// leave the process group can still be found once the executor exits
+    pub async fn refresh(&self) {
+        let processes = list_processes().await;
+        for execution in self.executions.lock().unwrap().values_mut() {
+            execution.observe(&processes);
+        }
+    }
+
+    /// Stop tracking an execution and kill whatever is left of its tree.
+    /// Returns how many processes were signalled.
+    pub async fn reap(&self, execution_process_id: Uuid) -> usize {
+        let Some(mut execution) = self
+            .executions
+            .lock()
+            .unwrap()
+            .remove(&execution_process_id)
+        else {
+            return 0;
+        };
+
+        let leftovers = execution.observe(&list_processes().await);
+        if leftovers.is_empty() {
+            return 0;
+        }
+        tracing::info!(
+            "Killing {} leftover processes of execution {}",
+            leftovers.len(),
+            execution_process_id
+        );
+        terminate(&leftovers, false);
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        let remaining = execution.observe(&list_processes().await);
+        terminate(&remaining, true);
+        leftovers.len()
+    }
+
+    /// Refresh every 15 seconds so descendants are known before they detach
+    pub fn spawn_refresh(&self) {
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(15));
+            loop {
+                interval.tick().await;
+                tracker.refresh().await;
+            }
+        });
+    }
+}
+
+impl TrackedExecution {
+    /// The execution's live processes, remembering any new ones
+    fn observe(&mut self, processes: &[TrackedProcess]) -> Vec<TrackedProcess> {
+        let tree = collect_tree(processes, self.pgid, &self.known);
+        self.known = tree
+            .iter()
+            .map(|process| (process.pid, process.command.clone()))
+            .collect();
+        tree
+    }
+}
+
+/// Processes in the group `pgid`, previously seen descendants that still
+/// run the same command, and every descendant of those
+fn collect_tree(
+    processes: &[TrackedProcess],
+    pgid: u32,
+    known: &HashMap<u32, String>,
+) -> Vec<TrackedProcess> {
+    let own_pid = std::process::id();
+    let mut members: HashSet<u32> = processes
+        .iter()
+        .filter(|process| {
+            process.pgid == pgid
+                || known
+                    .get(&process.pid)
+                    .is_some_and(|command| *command == process.command)
+        })
+        .map(|process| process.pid)
+        .collect();
+
+    loop {
+        let before = members.len();
+        for process in processes {
+            if members.contains(&process.ppid) {
+                members.insert(process.pid);
+            }
+        }
+        if members.len() == before {
+            break;
+        }
+    }
+
+    processes
+        .iter()
+        .filter(|process| {
+            members.contains(&process.pid) && process.pid > 1 && process.pid != own_pid
+        })
+        .cloned()
+        .collect()
+}
+
+/// Parse `ps -o pid=,ppid=,pgid=,comm=` output
+fn parse_ps_output(output: &str) -> Vec<TrackedProcess> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pid = fields.next()?.parse().ok()?;
+            let ppid = fields.next()?.parse().ok()?;
+            let pgid = fields.next()?.parse().ok()?;
+            let command = fields.collect::<Vec<_>>().join(" ");
+            Some(TrackedProcess {
+                pid,
+                ppid,
+                pgid,
+                command,
+            })
+        })
+        .collect()
+}
+
+#[cfg(unix)]
+async fn list_processes() -> Vec<TrackedProcess> {
+    match tokio::process::Command::new("ps")
+        .args(["-A", "-o", "pid=,ppid=,pgid=,comm="])
+        .output()
+        .await
+    {
+        Ok(output) if output.status.success() => {
+            parse_ps_output(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(output) => {
+            tracing::warn!("ps exited with {}", output.status);
+            Vec::new()
+        }
+        Err(e) => {
+            tracing::warn!("Failed to list processes: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(not(unix))]
+async fn list_processes() -> Vec<TrackedProcess> {
+    Vec::new()
+}
+
+#[cfg(unix)]
+fn terminate(processes: &[TrackedProcess], force: bool) {
+    use nix::{
+        sys::signal::{Signal, kill},
+        unistd::Pid,
+    };
+
+    let signal = if force {
+        Signal::SIGKILL
+    } else {
+        Signal::SIGTERM
+    };
+    for process in processes {
+        if let Err(e) = kill(Pid::from_raw(process.pid as i32), signal) {
+            tracing::debug!(
+                "Failed to send {:?} to process {}: {}",
+                signal,
+                process.pid,
+                e
+            );
+        }
+    }
+}
+
+#[cfg(not(unix))]
+fn terminate(_processes: &[TrackedProcess], _force: bool) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, ppid: u32, pgid: u32, command: &str) -> TrackedProcess {
+        TrackedProcess {
+            pid,
+            ppid,
+            pgid,
+            command: command.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_ps_output() {
+        let output = "    1     0     1 init\n  420     1   420 node\n  421   420   420 Google Chrome\nbad line\n";
+        assert_eq!(
+            parse_ps_output(output),
+            vec![
+                process(1, 0, 1, "init"),
+                process(420, 1, 420, "node"),
+                process(421, 420, 420, "Google Chrome"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_tree() {
+        let processes = vec![
+            process(1, 0, 1, "init"),
+            process(100, 1, 100, "claude"),
+            process(101, 100, 100, "npm"),
+            // Started its own session, then its parent exited
+            process(102, 1, 102, "chrome"),
+            process(103, 102, 102, "chrome-helper"),
+            // A recycled pid that used to be ours
+            process(104, 1, 104, "bash"),
+            process(200, 1, 200, "unrelated"),
+        ];
+        let known = HashMap::from([(102, "chrome".to_string()), (104, "vite".to_string())]);
+
+        let pids: Vec<u32> = collect_tree(&processes, 100, &known)
+            .iter()
+            .map(|process| process.pid)
+            .collect();
+        assert_eq!(pids, vec![100, 101, 102, 103]);
+    }
+}
@@ -4,10 +4,16 @@ use std::{
 };
 
 use db::models::{
-    project::{CreateProject, Project, ProjectError, SearchMatchType, SearchResult, UpdateProject},
-    project_repo::{CreateProjectRepo, ProjectRepo},
+    image::{Image, TaskImage},
+    project::{
+        CreateProject, CreateProjectFromTemplate, DuplicateProject, Project, ProjectError,
+        ProjectUpdate, SearchMatchType, SearchResult, UpdateProject,
+    },
+    project_group::{ProjectPlacement, SetProjectPlacement},
+    project_instruction::{CreateProjectInstruction, ProjectInstruction},
+    project_repo::{CreateProjectRepo, ProjectRepo, UpdateProjectRepo},
     repo::Repo,
-    task::Task,
+    task::{CreateTask, ExecutionMode, Task, TaskStatus},
 };
 use ignore::WalkBuilder;
 use sqlx::SqlitePool;
@@ -128,23 +134,8 @@ impl ProjectService {
                 pool,
                 project.id,
                 &UpdateProject {
-                    name: None,
-                    dev_script: None,
-                    dev_script_working_dir: None,
-                    default_agent_working_dir: Some(repo.name),
-                    github_repo_url: None,
-                    github_token: None,
-                    github_sync_enabled: None,
-                    github_sync_labels: None,
-                    gitlab_project_url: None,
-                    gitlab_token: None,
-                    gitlab_sync_enabled: None,
-                    gitlab_sync_labels: None,
-                    vortex_api_url: None,
-                    vortex_project_id: None,
-                    vortex_token: None,
-                    vortex_sync_enabled: None,
-                    vortex_sync_labels: None,
+                    default_agent_working_dir: Some(Some(repo.name)),
+                    ..Default::default()
                 },
             )
             .await?;
@@ -153,15 +144,220 @@ impl ProjectService {
         Ok(project)
     }
 
+    /// Create a project with `template`'s scripts, sync label filters, instruction profiles,
+    /// group placement and (unless overridden) repositories. Tasks are not copied.
+    pub async fn create_from_template(
+        &self,
+        pool: &SqlitePool,
+        repo_service: &RepoService,
+        template: &Project,
+        payload: CreateProjectFromTemplate,
+    ) -> Result<Project> {
+        let template_repos = ProjectRepo::find_by_project_id_with_names(pool, template.id).await?;
+        let repositories = match payload.repositories {
+            Some(repositories) => repositories,
+            None => ProjectRepo::find_repos_for_project(pool, template.id)
+                .await?
+                .into_iter()
+                .map(|repo| CreateProjectRepo {
+                    display_name: repo.display_name,
+                    git_repo_path: repo.path.to_string_lossy().to_string(),
+                })
+                .collect(),
+        };
+
+        let project = self
+            .create_project(
+                pool,
+                repo_service,
+                CreateProject {
+                    name: payload.name,
+                    repositories,
+                },
+            )
+            .await?;
+
+        Project::update(
+            pool,
+            project.id,
+            &UpdateProject {
+                dev_script: Some(template.dev_script.clone()),
+                dev_script_working_dir: Some(template.dev_script_working_dir.clone()),
+                default_agent_working_dir: template.default_agent_working_dir.clone().map(Some),
+                github_sync_labels: Some(template.github_sync_labels.clone()),
+                gitlab_sync_labels: Some(template.gitlab_sync_labels.clone()),
+                vortex_sync_labels: Some(template.vortex_sync_labels.clone()),
+                gitea_sync_labels: Some(template.gitea_sync_labels.clone()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        let repos = ProjectRepo::find_repos_for_project(pool, project.id).await?;
+        for repo in &repos {
+            // Same repo, else same name, else the only repo of a single-repo template
+            let source = template_repos
+                .iter()
+                .find(|t| t.repo_id == repo.id)
+                .or_else(|| template_repos.iter().find(|t| t.repo_name == repo.name))
+                .or_else(|| {
+                    (template_repos.len() == 1 && repos.len() == 1).then(|| &template_repos[0])
+                });
+            let Some(source) = source else {
+                continue;
+            };
+            ProjectRepo::update(
+                pool,
+                project.id,
+                repo.id,
+                &UpdateProjectRepo {
+                    setup_script: source.setup_script.clone(),
+                    cleanup_script: source.cleanup_script.clone(),
+                    copy_files: source.copy_files.clone(),
+                    parallel_setup_script: Some(source.parallel_setup_script),
+                    default_target_branch: source.default_target_branch.clone(),
+                },
+            )
+            .await
+            .map_err(|e| ProjectError::CreateFailed(e.to_string()))?;
+        }
+
+        for instruction in ProjectInstruction::find_by_project_id(pool, template.id).await? {
+            ProjectInstruction::create(
+                pool,
+                project.id,
+                &CreateProjectInstruction {
+                    name: instruction.name,
+                    content: instruction.content,
+                    is_default: Some(instruction.is_default),
+                },
+            )
+            .await
+            .map_err(|e| ProjectError::CreateFailed(e.to_string()))?;
+        }
+
+        if let Some(placement) = ProjectPlacement::find_all(pool)
+            .await?
+            .into_iter()
+            .find(|placement| placement.project_id == template.id)
+        {
+            ProjectPlacement::set(
+                pool,
+                project.id,
+                &SetProjectPlacement {
+                    group_id: placement.group_id,
+                    position: placement.position,
+                },
+            )
+            .await?;
+        }
+
+        Project::find_by_id(pool, project.id)
+            .await?
+            .ok_or(ProjectError::ProjectNotFound.into())
+    }
+
+    /// Copy `source` into a new project, including its tasks. Copied tasks start over as
+    /// `todo` unless they were already finished; attempts, sharing and parent links are not kept.
+    pub async fn duplicate_project(
+        &self,
+        pool: &SqlitePool,
+        repo_service: &RepoService,
+        source: &Project,
+        payload: DuplicateProject,
+    ) -> Result<Project> {
+        let repositories = ProjectRepo::find_repos_for_project(pool, source.id)
+            .await?
+            .into_iter()
+            .map(|repo| {
+                payload
+                    .repo_remap
+                    .get(&repo.id)
+                    .cloned()
+                    .unwrap_or_else(|| CreateProjectRepo {
+                        display_name: repo.display_name,
+                        git_repo_path: repo.path.to_string_lossy().to_string(),
+                    })
+            })
+            .collect();
+
+        let project = self
+            .create_from_template(
+                pool,
+                repo_service,
+                source,
+                CreateProjectFromTemplate {
+                    template_project_id: source.id,
+                    name: payload.name,
+                    repositories: Some(repositories),
+                },
+            )
+            .await?;
+
+        let mut tasks: Vec<Task> = Task::find_by_project_id_with_attempt_status(pool, source.id)
+            .await?
+            .into_iter()
+            .map(|task| task.task)
+            .filter(|task| {
+                !payload.open_tasks_only
+                    || !matches!(task.status, TaskStatus::Done | TaskStatus::Cancelled)
+            })
+            .collect();
+        // Queue order first so sequential copies keep their relative positions
+        tasks.sort_by_key(|task| {
+            (
+                task.queue_position.is_none(),
+                task.queue_position,
+                task.created_at,
+            )
+        });
+
+        for task in tasks {
+            let status = match task.status {
+                TaskStatus::InProgress | TaskStatus::InReview => TaskStatus::Todo,
+                status => status,
+            };
+            let copy = Task::create(
+                pool,
+                &CreateTask {
+                    status: Some(status),
+                    ..CreateTask::from_title_description(
+                        project.id,
+                        task.title.clone(),
+                        task.description.clone(),
+                    )
+                },
+                Uuid::new_v4(),
+            )
+            .await?;
+
+            if task.execution_mode == ExecutionMode::Sequential {
+                Task::add_to_queue(pool, copy.id, project.id).await?;
+            }
+            if payload.include_images {
+                let image_ids: Vec<Uuid> = Image::find_by_task_id(pool, task.id)
+                    .await?
+                    .into_iter()
+                    .map(|image| image.id)
+                    .collect();
+                if !image_ids.is_empty() {
+                    TaskImage::associate_many_dedup(pool, copy.id, &image_ids).await?;
+                }
+            }
+        }
+
+        Ok(project)
+    }
+
     pub async fn update_project(
         &self,
         pool: &SqlitePool,
         existing: &Project,
         payload: UpdateProject,
-    ) -> Result<Project> {
-        let project = Project::update(pool, existing.id, &payload).await?;
+    ) -> Result<ProjectUpdate> {
+        let update = Project::update(pool, existing.id, &payload).await?;
 
-        Ok(project)
+        Ok(update)
     }
 
     /// Link a project to a remote project and sync shared tasks
@@ -0,0 +1,174 @@
+//! One-line task entry for the keyboard and chat bots. Tokens like `#label`,
+//! `!high`, `@user`, `due:fri` and `seq` set the matching task fields and the
+//! remaining words become the title, e.g.
+//! `Fix login bug #bug !high @alice due:fri seq`.
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, Utc, Weekday};
+use db::models::task::ExecutionMode;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+use thiserror::Error;
+use ts_rs::TS;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QuickAddError {
+    #[error("Quick add needs a title besides its tokens")]
+    EmptyTitle,
+    #[error("Could not read due date '{0}'; use today, tomorrow, a weekday, +3d or YYYY-MM-DD")]
+    InvalidDueDate(String),
+}
+
+/// Priorities have no column of their own; they're stored as a `priority:<level>` label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, EnumString, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum QuickAddPriority {
+    Urgent,
+    High,
+    Medium,
+    Low,
+}
+
+impl QuickAddPriority {
+    pub fn label(&self) -> String {
+        format!("priority:{self}")
+    }
+}
+
+/// The task fields read from a quick-add line
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+pub struct QuickAdd {
+    pub title: String,
+    pub labels: Vec<String>,
+    pub priority: Option<QuickAddPriority>,
+    /// Username of the assignee, without the `@`
+    pub assignee: Option<String>,
+    pub due_date: Option<NaiveDate>,
+    pub execution_mode: Option<ExecutionMode>,
+}
+
+impl QuickAdd {
+    /// The end of the due day in local time
+    pub fn due_at(&self) -> Option<DateTime<Utc>> {
+        self.due_date?
+            .and_hms_opt(23, 59, 59)?
+            .and_local_timezone(Local)
+            .earliest()
+            .map(|due| due.with_timezone(&Utc))
+    }
+}
+
+/// Due date of a `due:` token. Weekdays mean the next one, counting today.
+fn parse_due(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let value = value.to_lowercase();
+    match value.as_str() {
+        "today" => return Some(today),
+        "tomorrow" => return Some(today + Duration::days(1)),
+        _ => {}
+    }
+    if let Some(days) = value
+        .strip_prefix('+')
+        .and_then(|days| days.strip_suffix('d'))
+        .and_then(|days| days.parse::<i64>().ok())
+    {
+        return Some(today + Duration::days(days));
+    }
+    if let Ok(weekday) = value.parse::<Weekday>() {
+        let ahead = (7 + weekday.num_days_from_monday() as i64
+            - today.weekday().num_days_from_monday() as i64)
+            % 7;
+        return Some(today + Duration::days(ahead));
+    }
+    NaiveDate::parse_from_str(&value, "%Y-%m-%d").ok()
+}
+
+/// Split a quick-add line into task fields. Tokens that don't read as a field,
+/// such as `!maybe`, stay in the title. `today` resolves relative due dates.
+pub fn parse_quick_add(line: &str, today: NaiveDate) -> Result<QuickAdd, QuickAddError> {
+    let mut title = Vec::new();
+    let mut labels: Vec<String> = Vec::new();
+    let mut priority = None;
+    let mut assignee = None;
+    let mut due_date = None;
+    let mut execution_mode = None;
+
+    for token in line.split_whitespace() {
+        if let Some(label) = token.strip_prefix('#').filter(|label| !label.is_empty()) {
+            if !labels.iter().any(|seen| seen.eq_ignore_ascii_case(label)) {
+                labels.push(label.to_string());
+            }
+        } else if let Some(level) = token
+            .strip_prefix('!')
+            .and_then(|level| level.parse::<QuickAddPriority>().ok())
+        {
+            priority = Some(level);
+        } else if let Some(user) = token.strip_prefix('@').filter(|user| !user.is_empty()) {
+            assignee = Some(user.to_string());
+        } else if let Some(value) = token.strip_prefix("due:") {
+            due_date = Some(
+                parse_due(value, today)
+                    .ok_or_else(|| QuickAddError::InvalidDueDate(value.to_string()))?,
+            );
+        } else if matches!(token, "seq" | "sequential") {
+            execution_mode = Some(ExecutionMode::Sequential);
+        } else if matches!(token, "par" | "parallel") {
+            execution_mode = Some(ExecutionMode::Parallel);
+        } else {
+            title.push(token);
+        }
+    }
+
+    if title.is_empty() {
+        return Err(QuickAddError::EmptyTitle);
+    }
+    Ok(QuickAdd {
+        title: title.join(" "),
+        labels,
+        priority,
+        assignee,
+        due_date,
+        execution_mode,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_quick_add_lines() {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let parsed =
+            parse_quick_add("Fix login bug #bug !high @alice due:fri seq #Bug", today).unwrap();
+        assert_eq!(
+            parsed,
+            QuickAdd {
+                title: "Fix login bug".to_string(),
+                labels: vec!["bug".to_string()],
+                priority: Some(QuickAddPriority::High),
+                assignee: Some("alice".to_string()),
+                due_date: NaiveDate::from_ymd_opt(2026, 3, 6),
+                execution_mode: Some(ExecutionMode::Sequential),
+            }
+        );
+
+        let parsed = parse_quick_add("Ship it !maybe # due:+2d", today).unwrap();
+        assert_eq!(parsed.title, "Ship it !maybe #");
+        assert_eq!(parsed.priority, None);
+        assert_eq!(parsed.due_date, NaiveDate::from_ymd_opt(2026, 3, 6));
+        assert_eq!(
+            parse_quick_add("Wrap up due:wed", today).unwrap().due_date,
+            Some(today)
+        );
+
+        assert_eq!(
+            parse_quick_add("#bug !low", today),
+            Err(QuickAddError::EmptyTitle)
+        );
+        assert_eq!(
+            parse_quick_add("Fix it due:someday", today),
+            Err(QuickAddError::InvalidDueDate("someday".to_string()))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Startup reconciliation. Boot used to clean up quietly after a crash or
+//! an unclean shutdown; this collects what was found instead so an admin can
+//! see it and decide what to fix.
+
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, Utc};
+use db::models::{
+    execution_process::ExecutionProcessRunReason,
+    session::{Session, StaleSession},
+    workspace::WorkspaceDirectory,
+};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use thiserror::Error;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::workspace_manager::{WorkspaceError, WorkspaceManager};
+
+#[derive(Debug, Error)]
+pub enum RecoveryError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
+}
+
+/// An execution process that was still marked running when the server
+/// started and was marked failed
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct InterruptedProcess {
+    pub execution_process_id: Uuid,
+    pub session_id: Uuid,
+    pub workspace_id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub run_reason: ExecutionProcessRunReason,
+    pub started_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct RecoveryReport {
+    pub generated_at: DateTime<Utc>,
+    pub interrupted_processes: Vec<InterruptedProcess>,
+    /// Workspaces whose directory no longer exists on disk
+    pub missing_workspaces: Vec<WorkspaceDirectory>,
+    /// Directories under the workspace base dir with no workspace record
+    pub orphaned_directories: Vec<String>,
+    pub stale_sessions: Vec<StaleSession>,
+}
+
+impl RecoveryReport {
+    pub async fn collect(
+        pool: &SqlitePool,
+        interrupted_processes: Vec<InterruptedProcess>,
+    ) -> Result<Self, RecoveryError> {
+        Ok(Self {
+            generated_at: Utc::now(),
+            interrupted_processes,
+            missing_workspaces: WorkspaceManager::find_missing_workspaces(pool).await?,
+            orphaned_directories: WorkspaceManager::find_orphan_directories(pool).await?,
+            stale_sessions: Session::find_stale(pool).await?,
+        })
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.interrupted_processes.is_empty()
+            && self.missing_workspaces.is_empty()
+            && self.orphaned_directories.is_empty()
+            && self.stale_sessions.is_empty()
+    }
+
+    pub fn log_summary(&self) {
+        if self.is_clean() {
+            tracing::info!("Startup recovery found nothing to reconcile");
+            return;
+        }
+        tracing::warn!(
+            "Startup recovery found {} interrupted processes, {} workspaces with missing directories, {} orphaned directories and {} stale sessions; see /api/admin/recovery-report",
+            self.interrupted_processes.len(),
+            self.missing_workspaces.len(),
+            self.orphaned_directories.len(),
+            self.stale_sessions.len()
+        );
+    }
+}
+
+/// Processes interrupted by the last shutdown, kept until an admin
+/// dismisses them
+#[derive(Clone, Default)]
+pub struct RecoveryLog {
+    interrupted: Arc<RwLock<Vec<InterruptedProcess>>>,
+}
+
+impl RecoveryLog {
+    pub fn record_interrupted(&self, processes: Vec<InterruptedProcess>) {
+        self.interrupted.write().unwrap().extend(processes);
+    }
+
+    pub fn interrupted(&self) -> Vec<InterruptedProcess> {
+        self.interrupted.read().unwrap().clone()
+    }
+
+    /// Forget the given processes, or all of them when `ids` is empty.
+    /// Returns how many were dismissed.
+    pub fn dismiss_interrupted(&self, ids: &[Uuid]) -> usize {
+        let mut interrupted = self.interrupted.write().unwrap();
+        let before = interrupted.len();
+        if ids.is_empty() {
+            interrupted.clear();
+        } else {
+            interrupted.retain(|process| !ids.contains(&process.execution_process_id));
+        }
+        before - interrupted.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interrupted_process() -> InterruptedProcess {
+        InterruptedProcess {
+            execution_process_id: Uuid::new_v4(),
+            session_id: Uuid::new_v4(),
+            workspace_id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            task_title: "Task".to_string(),
+            run_reason: ExecutionProcessRunReason::CodingAgent,
+            started_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_dismiss_interrupted() {
+        let log = RecoveryLog::default();
+        let (first, second) = (interrupted_process(), interrupted_process());
+        let first_id = first.execution_process_id;
+        log.record_interrupted(vec![first, second]);
+
+        assert_eq!(log.dismiss_interrupted(&[first_id]), 1);
+        assert_eq!(log.interrupted().len(), 1);
+        assert_eq!(log.dismiss_interrupted(&[first_id]), 0);
+        assert_eq!(log.dismiss_interrupted(&[]), 1);
+        assert!(log.interrupted().is_empty());
+    }
+}
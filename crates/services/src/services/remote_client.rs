@@ -8,7 +8,7 @@ use remote::routes::tasks::{
     AssignSharedTaskRequest, CheckTasksRequest, CreateSharedTaskRequest, SharedTaskResponse,
     UpdateSharedTaskRequest,
 };
-use reqwest::{Client, StatusCode};
+use reqwest::{StatusCode, header::USER_AGENT};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
@@ -33,7 +33,11 @@ use utils::{
 };
 use uuid::Uuid;
 
-use super::{auth::AuthContext, oauth_credentials::Credentials};
+use super::{
+    auth::AuthContext,
+    http_client::{HttpClient, HttpClientError, SendVia},
+    oauth_credentials::Credentials,
+};
 
 #[derive(Debug, Clone, Error)]
 pub enum RemoteClientError {
@@ -103,7 +107,6 @@ struct ApiErrorResponse {
 /// HTTP client for the remote OAuth server with automatic retries.
 pub struct RemoteClient {
     base: Url,
-    http: Client,
     auth_context: AuthContext,
 }
 
@@ -111,7 +114,6 @@ impl std::fmt::Debug for RemoteClient {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RemoteClient")
             .field("base", &self.base)
-            .field("http", &self.http)
             .field("auth_context", &"<present>")
             .finish()
     }
@@ -121,7 +123,6 @@ impl Clone for RemoteClient {
     fn clone(&self) -> Self {
         Self {
             base: self.base.clone(),
-            http: self.http.clone(),
             auth_context: self.auth_context.clone(),
         }
     }
@@ -133,16 +134,7 @@ impl RemoteClient {
 
     pub fn new(base_url: &str, auth_context: AuthContext) -> Result<Self, RemoteClientError> {
         let base = Url::parse(base_url).map_err(|e| RemoteClientError::Url(e.to_string()))?;
-        let http = Client::builder()
-            .timeout(Self::REQUEST_TIMEOUT)
-            .user_agent(concat!("remote-client/", env!("CARGO_PKG_VERSION")))
-            .build()
-            .map_err(|e| RemoteClientError::Transport(e.to_string()))?;
-        Ok(Self {
-            base,
-            http,
-            auth_context,
-        })
+        Ok(Self { base, auth_context })
     }
 
     /// Returns a valid access token, refreshing when it's about to expire.
@@ -280,7 +272,14 @@ impl RemoteClient {
             .map_err(|e| RemoteClientError::Url(e.to_string()))?;
 
         (|| async {
-            let mut req = self.http.request(method.clone(), url.clone());
+            let http = HttpClient::shared();
+            let mut req = http
+                .request(method.clone(), url.clone())
+                .timeout(Self::REQUEST_TIMEOUT)
+                .header(
+                    USER_AGENT,
+                    concat!("remote-client/", env!("CARGO_PKG_VERSION")),
+                );
 
             if requires_auth {
                 let token = self.require_token().await?;
@@ -291,7 +290,7 @@ impl RemoteClient {
                 req = req.json(b);
             }
 
-            let res = req.send().await.map_err(map_reqwest_error)?;
+            let res = req.send_via(&http).await.map_err(map_http_error)?;
 
             match res.status() {
                 s if s.is_success() => Ok(res),
@@ -606,10 +605,9 @@ pub struct CreateRemoteProjectPayload {
     pub metadata: Option<Value>,
 }
 
-fn map_reqwest_error(e: reqwest::Error) -> RemoteClientError {
-    if e.is_timeout() {
-        RemoteClientError::Timeout
-    } else {
-        RemoteClientError::Transport(e.to_string())
+fn map_http_error(e: HttpClientError) -> RemoteClientError {
+    match e {
+        HttpClientError::Request(e) if e.is_timeout() => RemoteClientError::Timeout,
+        e => RemoteClientError::Transport(e.to_string()),
     }
 }
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use db::models::repo::Repo as RepoModel;
+use db::models::{repo::Repo as RepoModel, repo_health::RepoHealth};
 use sqlx::SqlitePool;
 use thiserror::Error;
 use utils::path::expand_tilde;
@@ -28,6 +28,10 @@ pub enum RepoError {
     Git(#[from] GitServiceError),
     #[error("Invalid folder name: {0}")]
     InvalidFolderName(String),
+    #[error("Path holds a different repository: {0}")]
+    IdentityMismatch(PathBuf),
+    #[error("Path is already registered as another repository: {0}")]
+    PathInUse(PathBuf),
 }
 
 pub type Result<T> = std::result::Result<T, RepoError>;
@@ -125,4 +129,153 @@ impl RepoService {
         let repo = RepoModel::find_or_create(pool, &repo_path, folder_name).await?;
         Ok(repo)
     }
+
+    /// Check that the repo's path still holds a git repository, marking the
+    /// repo broken if not. Returns the reason when it is broken.
+    pub async fn check_health(
+        &self,
+        pool: &SqlitePool,
+        git: &GitService,
+        repo: &RepoModel,
+    ) -> Result<Option<String>> {
+        if let Err(e) = self.validate_git_repo_path(&repo.path) {
+            let reason = e.to_string();
+            RepoHealth::mark_broken(pool, repo.id, &reason).await?;
+            return Ok(Some(reason));
+        }
+
+        // The root commit never changes, so it is only looked up once
+        let known_root = RepoHealth::find_by_repo_id(pool, repo.id)
+            .await?
+            .and_then(|health| health.root_commit);
+        let root_commit = match known_root {
+            Some(_) => None,
+            None => git.get_root_commit(&repo.path).ok(),
+        };
+        let remote_url = git.get_remote_url(&repo.path).ok();
+        RepoHealth::record_healthy(pool, repo.id, root_commit.as_deref(), remote_url.as_deref())
+            .await?;
+        Ok(None)
+    }
+
+    /// Check every repo used by a project, returning how many are broken
+    pub async fn check_all(&self, pool: &SqlitePool, git: &GitService) -> Result<usize> {
+        let mut broken = 0;
+        for repo in RepoModel::find_in_projects(pool).await? {
+            if let Some(reason) = self.check_health(pool, git, &repo).await? {
+                tracing::warn!("Repository {} is broken: {}", repo.display_name, reason);
+                broken += 1;
+            }
+        }
+        Ok(broken)
+    }
+
+    /// Point a repo at the new location of its checkout. The repository there
+    /// must have the same first commit as the one last seen at the old path,
+    /// or failing that the same remote.
+    pub async fn relocate(
+        &self,
+        pool: &SqlitePool,
+        git: &GitService,
+        repo_id: Uuid,
+        new_path: &str,
+    ) -> Result<RepoModel> {
+        let repo = self.get_by_id(pool, repo_id).await?;
+        let normalized_path = self.normalize_path(new_path)?;
+        self.validate_git_repo_path(&normalized_path)?;
+
+        let root_commit = git.get_root_commit(&normalized_path).ok();
+        let remote_url = git.get_remote_url(&normalized_path).ok();
+        match RepoHealth::find_by_repo_id(pool, repo.id).await? {
+            Some(known) => {
+                if !is_same_repository(&known, root_commit.as_deref(), remote_url.as_deref()) {
+                    return Err(RepoError::IdentityMismatch(normalized_path));
+                }
+            }
+            None => tracing::warn!(
+                "No identity recorded for repository {}, relocating without verifying it",
+                repo.id
+            ),
+        }
+
+        RepoModel::update_path(pool, repo.id, &normalized_path)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db_err) if db_err.is_unique_violation() => {
+                    RepoError::PathInUse(normalized_path.clone())
+                }
+                _ => RepoError::from(e),
+            })?;
+        RepoHealth::record_healthy(pool, repo.id, root_commit.as_deref(), remote_url.as_deref())
+            .await?;
+        self.get_by_id(pool, repo.id).await
+    }
+}
+
+/// Whether a checkout with `root_commit` and `remote_url` is the repository
+/// `known` describes. The first commit decides when both sides have one.
+fn is_same_repository(
+    known: &RepoHealth,
+    root_commit: Option<&str>,
+    remote_url: Option<&str>,
+) -> bool {
+    if let (Some(expected), Some(actual)) = (known.root_commit.as_deref(), root_commit) {
+        return expected == actual;
+    }
+    match (known.remote_url.as_deref(), remote_url) {
+        (Some(expected), Some(actual)) => normalize_remote(expected) == normalize_remote(actual),
+        // Nothing to compare against
+        (None, _) if known.root_commit.is_none() => true,
+        _ => false,
+    }
+}
+
+fn normalize_remote(url: &str) -> String {
+    let url = url.trim().trim_end_matches('/');
+    url.strip_suffix(".git").unwrap_or(url).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn known(root_commit: Option<&str>, remote_url: Option<&str>) -> RepoHealth {
+        RepoHealth {
+            repo_id: Uuid::nil(),
+            root_commit: root_commit.map(str::to_string),
+            remote_url: remote_url.map(str::to_string),
+            broken_reason: Some("Path does not exist".to_string()),
+            checked_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn recognises_moved_repository() {
+        let both = known(Some("abc"), Some("https://github.com/o/r.git"));
+        assert!(is_same_repository(&both, Some("abc"), None));
+        // Different history means a different repository, whatever the remote
+        assert!(!is_same_repository(
+            &both,
+            Some("def"),
+            Some("https://github.com/o/r.git")
+        ));
+        // Without a root commit on the new side, fall back to the remote
+        assert!(is_same_repository(
+            &both,
+            None,
+            Some("https://github.com/O/r/")
+        ));
+
+        let remote_only = known(None, Some("git@github.com:o/r.git"));
+        assert!(is_same_repository(
+            &remote_only,
+            Some("abc"),
+            Some("git@github.com:o/r")
+        ));
+        assert!(!is_same_repository(&remote_only, Some("abc"), None));
+
+        assert!(is_same_repository(&known(None, None), None, None));
+    }
 }
@@ -0,0 +1,376 @@
+//! Outbound REST hooks: task and attempt events POSTed to subscribed target URLs,
+//! following the subscribe/unsubscribe model used by Zapier and Make.
+//!
+//! Events are derived from the task and execution process patches on the event
+//! bus, so no call site has to emit them explicitly. The dispatcher commits its
+//! bus offset as it goes, so a restarted dispatcher picks up where it stopped.
+
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use chrono::Utc;
+use db::{
+    DBService,
+    models::{
+        execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+        rest_hook_subscription::{RestHookEvent, RestHookSubscription},
+        session::Session,
+        sla_rule::SlaRule,
+        task::{Task, TaskStatus, TaskWithAttemptStatus},
+        workspace::Workspace,
+    },
+};
+use futures::StreamExt;
+use json_patch::{Patch, PatchOperation};
+use reqwest::{StatusCode, Url};
+use serde_json::{Value, json};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{
+    events::{EventBus, EventTopic},
+    http_client::{HttpClient, HttpClientError, SendVia},
+};
+
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+/// Name the dispatcher commits its event bus offsets under
+const EVENT_CONSUMER: &str = "rest_hooks";
+
+#[derive(Debug, Error)]
+pub enum RestHookError {
+    #[error("Target URL must be an absolute http(s) URL: {0}")]
+    InvalidUrl(String),
+    #[error(transparent)]
+    Request(#[from] HttpClientError),
+    #[error("Target URL rejected the verification request with status {0}")]
+    Rejected(u16),
+}
+
+/// Something that happened on the board, before subscriptions are looked up
+#[derive(Debug, Clone)]
+pub enum RestHookTrigger {
+    TaskCreated(Task),
+    TaskStatusChanged {
+        task: Task,
+        previous_status: TaskStatus,
+    },
+    AttemptFinished(ExecutionProcess),
+    SlaBreached {
+        task: Task,
+        rule: SlaRule,
+    },
+}
+
+impl RestHookTrigger {
+    pub fn event(&self) -> RestHookEvent {
+        match self {
+            Self::TaskCreated(_) => RestHookEvent::TaskCreated,
+            Self::TaskStatusChanged { .. } => RestHookEvent::TaskStatusChanged,
+            Self::AttemptFinished(_) => RestHookEvent::AttemptFinished,
+            Self::SlaBreached { .. } => RestHookEvent::SlaBreached,
+        }
+    }
+}
+
+/// Remembers the last status seen for tasks and coding agent runs, so patches can
+/// be turned into transitions
+#[derive(Debug, Default)]
+pub struct RestHookTracker {
+    task_statuses: HashMap<Uuid, TaskStatus>,
+    running_agents: HashSet<Uuid>,
+}
+
+impl RestHookTracker {
+    pub fn with_task_statuses(statuses: impl IntoIterator<Item = (Uuid, TaskStatus)>) -> Self {
+        Self {
+            task_statuses: statuses.into_iter().collect(),
+            running_agents: HashSet::new(),
+        }
+    }
+
+    pub fn observe(&mut self, patch: &Patch) -> Vec<RestHookTrigger> {
+        let mut triggers = Vec::new();
+        for operation in &patch.0 {
+            let (path, value, added) = match operation {
+                PatchOperation::Add(op) => (op.path.to_string(), Some(&op.value), true),
+                PatchOperation::Replace(op) => (op.path.to_string(), Some(&op.value), false),
+                PatchOperation::Remove(op) => (op.path.to_string(), None, false),
+                _ => continue,
+            };
+
+            if let Some(id) = path.strip_prefix("/tasks/") {
+                let Some(value) = value else {
+                    if let Ok(id) = id.parse() {
+                        self.task_statuses.remove(&id);
+                    }
+                    continue;
+                };
+                let Ok(task) = serde_json::from_value::<TaskWithAttemptStatus>(value.clone())
+                else {
+                    continue;
+                };
+                let task = task.task;
+                match self.task_statuses.insert(task.id, task.status.clone()) {
+                    None if added => triggers.push(RestHookTrigger::TaskCreated(task)),
+                    Some(previous_status) if previous_status != task.status => {
+                        triggers.push(RestHookTrigger::TaskStatusChanged {
+                            task,
+                            previous_status,
+                        })
+                    }
+                    _ => {}
+                }
+            } else if path.starts_with("/execution_processes/")
+                && let Some(value) = value
+                && let Ok(process) = serde_json::from_value::<ExecutionProcess>(value.clone())
+                && process.run_reason == ExecutionProcessRunReason::CodingAgent
+            {
+                if process.status == ExecutionProcessStatus::Running {
+                    self.running_agents.insert(process.id);
+                } else if self.running_agents.remove(&process.id) {
+                    triggers.push(RestHookTrigger::AttemptFinished(process));
+                }
+            }
+        }
+        triggers
+    }
+}
+
+#[derive(Clone)]
+pub struct RestHookService {
+    db: DBService,
+}
+
+impl RestHookService {
+    pub fn new(db: DBService) -> Self {
+        Self { db }
+    }
+
+    pub async fn spawn(db: DBService, bus: EventBus) -> tokio::task::JoinHandle<()> {
+        // Take the starting offset before reading current statuses so no transition
+        // falls in between
+        let mut events = bus.consume(EVENT_CONSUMER);
+        let service = Self::new(db);
+        tokio::spawn(async move {
+            let statuses = Task::find_all_statuses(&service.db.pool)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::error!("Failed to load task statuses for REST hooks: {}", e);
+                    Vec::new()
+                });
+            let mut tracker = RestHookTracker::with_task_statuses(statuses);
+            while let Some(event) = events.next().await {
+                if matches!(
+                    event.topic,
+                    EventTopic::Tasks | EventTopic::ExecutionProcesses
+                ) {
+                    for trigger in tracker.observe(&event.patch) {
+                        let service = service.clone();
+                        tokio::spawn(async move { service.dispatch(trigger).await });
+                    }
+                }
+                bus.commit(EVENT_CONSUMER, event.offset);
+            }
+        })
+    }
+
+    /// Confirm a new subscription's target accepts deliveries before it is stored
+    pub async fn verify_target(
+        &self,
+        target_url: &str,
+        event: RestHookEvent,
+    ) -> Result<(), RestHookError> {
+        let url = Url::parse(target_url)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .ok_or_else(|| RestHookError::InvalidUrl(target_url.to_string()))?;
+        // The user is waiting on the subscription, so this isn't a background request
+        let response = HttpClient::shared()
+            .post(url)
+            .timeout(DELIVERY_TIMEOUT)
+            .json(&json!({
+                "event": "subscription_verification",
+                "subscribed_event": event,
+            }))
+            .send_via(&HttpClient::shared())
+            .await?;
+        if !response.status().is_success() {
+            return Err(RestHookError::Rejected(response.status().as_u16()));
+        }
+        Ok(())
+    }
+
+    async fn project_id(&self, trigger: &RestHookTrigger) -> Option<Uuid> {
+        match trigger {
+            RestHookTrigger::TaskCreated(task)
+            | RestHookTrigger::TaskStatusChanged { task, .. }
+            | RestHookTrigger::SlaBreached { task, .. } => Some(task.project_id),
+            RestHookTrigger::AttemptFinished(process) => {
+                let pool = &self.db.pool;
+                let session = Session::find_by_id(pool, process.session_id).await.ok()??;
+                let workspace = Workspace::find_by_id(pool, session.workspace_id)
+                    .await
+                    .ok()??;
+                let task = Task::find_by_id(pool, workspace.task_id).await.ok()??;
+                Some(task.project_id)
+            }
+        }
+    }
+
+    fn payload(trigger: &RestHookTrigger, project_id: Uuid) -> Value {
+        let data = match trigger {
+            RestHookTrigger::TaskCreated(task) => json!(task),
+            RestHookTrigger::TaskStatusChanged {
+                task,
+                previous_status,
+            } => {
+                let mut data = json!(task);
+                data["previous_status"] = json!(previous_status);
+                data
+            }
+            RestHookTrigger::AttemptFinished(process) => json!({
+                "execution_process_id": process.id,
+                "session_id": process.session_id,
+                "status": process.status,
+                "exit_code": process.exit_code,
+                "started_at": process.started_at,
+                "completed_at": process.completed_at,
+            }),
+            RestHookTrigger::SlaBreached { task, rule } => {
+                let mut data = json!(task);
+                data["sla_rule"] = json!(rule);
+                data
+            }
+        };
+        json!({
+            "event": trigger.event(),
+            "occurred_at": Utc::now(),
+            "project_id": project_id,
+            "data": data,
+        })
+    }
+
+    /// Deliver a trigger to the subscriptions covering its project
+    pub async fn dispatch(&self, trigger: RestHookTrigger) {
+        let Some(project_id) = self.project_id(&trigger).await else {
+            return;
+        };
+        let subscriptions =
+            match RestHookSubscription::find_for_event(&self.db.pool, trigger.event(), project_id)
+                .await
+            {
+                Ok(subscriptions) => subscriptions,
+                Err(e) => {
+                    tracing::error!("Failed to load REST hook subscriptions: {}", e);
+                    return;
+                }
+            };
+        if subscriptions.is_empty() {
+            return;
+        }
+
+        let payload = Self::payload(&trigger, project_id);
+        let http = HttpClient::shared().background();
+        for subscription in subscriptions {
+            match http
+                .post(&subscription.target_url)
+                .timeout(DELIVERY_TIMEOUT)
+                .json(&payload)
+                .send_via(&http)
+                .await
+            {
+                // Zapier's signal that the subscriber is gone for good
+                Ok(response) if response.status() == StatusCode::GONE => {
+                    tracing::info!(
+                        "REST hook target {} is gone, removing subscription {}",
+                        subscription.target_url,
+                        subscription.id
+                    );
+                    if let Err(e) =
+                        RestHookSubscription::delete(&self.db.pool, subscription.id).await
+                    {
+                        tracing::error!("Failed to remove REST hook subscription: {}", e);
+                    }
+                }
+                Ok(response) if !response.status().is_success() => {
+                    tracing::warn!(
+                        "REST hook delivery to {} failed with status {}",
+                        subscription.target_url,
+                        response.status()
+                    );
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        "REST hook delivery to {} failed: {}",
+                        subscription.target_url,
+                        e
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use db::models::task::ExecutionMode;
+
+    use super::*;
+    use crate::services::events::task_patch;
+
+    fn task_with_status(id: Uuid, status: TaskStatus) -> TaskWithAttemptStatus {
+        TaskWithAttemptStatus {
+            task: Task {
+                id,
+                project_id: Uuid::new_v4(),
+                title: "Fix login".to_string(),
+                description: None,
+                status,
+                execution_mode: ExecutionMode::default(),
+                queue_position: None,
+                parent_workspace_id: None,
+                shared_task_id: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            has_in_progress_attempt: false,
+            last_attempt_failed: false,
+            executor: String::new(),
+            latest_workspace_id: None,
+            latest_workspace_container_ref: None,
+        }
+    }
+
+    #[test]
+    fn turns_task_patches_into_transitions() {
+        let id = Uuid::new_v4();
+        let mut tracker = RestHookTracker::default();
+
+        let created = tracker.observe(&task_patch::add(&task_with_status(id, TaskStatus::Todo)));
+        assert!(matches!(
+            created.as_slice(),
+            [RestHookTrigger::TaskCreated(_)]
+        ));
+
+        let renamed = tracker.observe(&task_patch::replace(&task_with_status(
+            id,
+            TaskStatus::Todo,
+        )));
+        assert!(renamed.is_empty());
+
+        let moved = tracker.observe(&task_patch::replace(&task_with_status(
+            id,
+            TaskStatus::InProgress,
+        )));
+        assert!(matches!(
+            moved.as_slice(),
+            [RestHookTrigger::TaskStatusChanged {
+                previous_status: TaskStatus::Todo,
+                ..
+            }]
+        ));
+    }
+}
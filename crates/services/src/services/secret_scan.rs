@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use db::models::{
+    secret_scan_finding::CreateSecretScanFinding, workspace::Workspace,
+    workspace_repo::WorkspaceRepo,
+};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use utils::{
+    diff::{Diff, added_line_indices},
+    secrets::{find_secrets, find_secrets_with_entropy},
+};
+use uuid::Uuid;
+
+use crate::services::git::{DiffTarget, GitService, GitServiceError};
+
+/// Files full of integrity hashes; only checked for known credential formats
+const LOCKFILES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "poetry.lock",
+    "Gemfile.lock",
+    "composer.lock",
+    "go.sum",
+];
+
+#[derive(Debug, Error)]
+pub enum SecretScanError {
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+    #[error(transparent)]
+    GitService(#[from] GitServiceError),
+}
+
+/// Scan the lines each diff adds. Removed lines are ignored since they never leave the machine
+/// in a way that wasn't already true before the attempt.
+pub fn scan_diffs(repo_id: Uuid, diffs: &[Diff]) -> Vec<CreateSecretScanFinding> {
+    let mut findings = Vec::new();
+    for diff in diffs {
+        let Some(new_content) = diff.new_content.as_deref() else {
+            continue;
+        };
+        let file_path = GitService::diff_path(diff);
+        let is_lockfile = Path::new(&file_path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| LOCKFILES.contains(&name));
+
+        let lines: Vec<&str> = new_content.lines().collect();
+        let old_content = diff.old_content.as_deref().unwrap_or("");
+        for index in added_line_indices(old_content, new_content) {
+            let Some(line) = lines.get(index) else {
+                continue;
+            };
+            let matches = if is_lockfile {
+                find_secrets(line)
+            } else {
+                find_secrets_with_entropy(line)
+            };
+            findings.extend(matches.into_iter().map(|m| CreateSecretScanFinding {
+                repo_id,
+                file_path: file_path.clone(),
+                line_number: index as i64 + 1,
+                kind: m.kind.to_string(),
+                redacted: m.redacted,
+            }));
+        }
+    }
+    findings
+}
+
+/// Scan the committed changes on a workspace branch against each repo's target branch.
+/// Pass `repo_id` to limit the scan to a single repository.
+pub async fn scan_workspace(
+    pool: &SqlitePool,
+    git: &GitService,
+    workspace: &Workspace,
+    repo_id: Option<Uuid>,
+) -> Result<Vec<CreateSecretScanFinding>, SecretScanError> {
+    let workspace_repos = WorkspaceRepo::find_by_workspace_id(pool, workspace.id).await?;
+    let repos = WorkspaceRepo::find_repos_for_workspace(pool, workspace.id).await?;
+
+    let mut findings = Vec::new();
+    for repo in repos {
+        if repo_id.is_some_and(|id| id != repo.id) {
+            continue;
+        }
+        let Some(workspace_repo) = workspace_repos.iter().find(|wr| wr.repo_id == repo.id) else {
+            continue;
+        };
+        let diffs = git.get_diffs(
+            DiffTarget::Branch {
+                repo_path: &repo.path,
+                branch_name: &workspace.branch,
+                base_branch: &workspace_repo.target_branch,
+            },
+            None,
+        )?;
+        findings.extend(scan_diffs(repo.id, &diffs));
+    }
+    Ok(findings)
+}
@@ -2,10 +2,17 @@
 //!
 //! Manages the sequential task queue, ensuring tasks run one at a time
 //! and automatically starting the next task when the current one completes.
+//! Tasks with unfinished dependencies are held back, and each task runs after
+//! the queued tasks it depends on.
+
+use std::collections::{HashMap, HashSet};
 
 use db::{
     DBService,
-    models::task::{ExecutionMode, Task, TaskStatus},
+    models::{
+        task::{ExecutionMode, Task, TaskStatus},
+        task_dependency::TaskDependency,
+    },
 };
 use thiserror::Error;
 use uuid::Uuid;
@@ -20,6 +27,104 @@ pub enum SequentialQueueError {
     NotSequentialMode,
 }
 
+/// The todo tasks of a sequential queue in the order they will run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueuePlan {
+    /// Each task after the queued tasks it depends on, otherwise in queue
+    /// position order. Tasks in a dependency cycle come last.
+    pub order: Vec<Uuid>,
+    /// Tasks waiting on a dependency that is not done yet
+    pub blocked: Vec<Uuid>,
+}
+
+impl QueuePlan {
+    /// The first task in run order that is not blocked
+    pub fn next_ready(&self) -> Option<Uuid> {
+        self.order
+            .iter()
+            .copied()
+            .find(|id| !self.blocked.contains(id))
+    }
+}
+
+/// Plan the todo tasks of `queue`, which is sorted by queue position.
+/// `dependencies` may include edges of tasks outside the queue.
+pub fn plan_queue(queue: &[Task], dependencies: &[TaskDependency]) -> QueuePlan {
+    let pending: Vec<Uuid> = queue
+        .iter()
+        .filter(|task| task.status == TaskStatus::Todo)
+        .map(|task| task.id)
+        .collect();
+    let pending_set: HashSet<Uuid> = pending.iter().copied().collect();
+
+    let mut blocked = Vec::new();
+    let mut waiting_on: HashMap<Uuid, usize> = HashMap::new();
+    for id in &pending {
+        let unmet: Vec<&TaskDependency> = dependencies
+            .iter()
+            .filter(|dep| dep.task_id == *id && !dep.is_satisfied())
+            .collect();
+        if !unmet.is_empty() {
+            blocked.push(*id);
+        }
+        let queued = unmet
+            .iter()
+            .filter(|dep| pending_set.contains(&dep.depends_on_task_id))
+            .count();
+        waiting_on.insert(*id, queued);
+    }
+
+    // Repeatedly take the first task by position whose queued dependencies
+    // have all been placed
+    let mut order = Vec::with_capacity(pending.len());
+    let mut placed = HashSet::new();
+    while let Some(id) = pending
+        .iter()
+        .copied()
+        .find(|id| !placed.contains(id) && waiting_on[id] == 0)
+    {
+        placed.insert(id);
+        order.push(id);
+        for dep in dependencies
+            .iter()
+            .filter(|dep| dep.depends_on_task_id == id && !dep.is_satisfied())
+        {
+            if let Some(count) = waiting_on.get_mut(&dep.task_id) {
+                *count = count.saturating_sub(1);
+            }
+        }
+    }
+    order.extend(pending.iter().copied().filter(|id| !placed.contains(id)));
+
+    QueuePlan { order, blocked }
+}
+
+/// Whether making `task_id` depend on `depends_on_task_id` would close a
+/// cycle, that is whether `depends_on_task_id` already depends on `task_id`
+/// directly or through other tasks
+pub fn creates_cycle(
+    dependencies: &[TaskDependency],
+    task_id: Uuid,
+    depends_on_task_id: Uuid,
+) -> bool {
+    let mut stack = vec![depends_on_task_id];
+    let mut seen = HashSet::new();
+    while let Some(id) = stack.pop() {
+        if id == task_id {
+            return true;
+        }
+        if seen.insert(id) {
+            stack.extend(
+                dependencies
+                    .iter()
+                    .filter(|dep| dep.task_id == id)
+                    .map(|dep| dep.depends_on_task_id),
+            );
+        }
+    }
+    false
+}
+
 /// Service for managing the sequential task queue
 #[derive(Clone)]
 pub struct SequentialQueueService {
@@ -37,13 +142,23 @@ impl SequentialQueueService {
         Ok(tasks)
     }
 
-    /// Get the next pending task in the queue for a project
+    /// Get the todo tasks in the queue in run order, with the blocked ones
+    pub async fn get_plan(&self, project_id: Uuid) -> Result<QueuePlan, SequentialQueueError> {
+        let queue = Task::find_sequential_queue_for_project(&self.db.pool, project_id).await?;
+        let dependencies = TaskDependency::find_by_project_id(&self.db.pool, project_id).await?;
+        Ok(plan_queue(&queue, &dependencies))
+    }
+
+    /// Get the next pending task in the queue for a project whose
+    /// dependencies are all done
     pub async fn get_next_pending(
         &self,
         project_id: Uuid,
     ) -> Result<Option<Task>, SequentialQueueError> {
-        let task = Task::get_next_in_queue(&self.db.pool, project_id).await?;
-        Ok(task)
+        let queue = Task::find_sequential_queue_for_project(&self.db.pool, project_id).await?;
+        let dependencies = TaskDependency::find_by_project_id(&self.db.pool, project_id).await?;
+        let next = plan_queue(&queue, &dependencies).next_ready();
+        Ok(queue.into_iter().find(|task| Some(task.id) == next))
     }
 
     /// Check if there's a running sequential task in the project
@@ -125,14 +240,18 @@ impl SequentialQueueService {
         Ok(())
     }
 
-    /// Check if a sequential task just completed and start the next one if available
+    /// Check if a sequential task, or a task queued tasks depend on, just
+    /// completed and start the next one if available
     /// Returns the next task if one was started, None otherwise
     pub async fn process_queue_after_completion(
         &self,
         completed_task: &Task,
     ) -> Result<Option<Task>, SequentialQueueError> {
-        // Only process if the task was sequential
-        if completed_task.execution_mode != ExecutionMode::Sequential {
+        // Only process if the task was sequential or unblocks others
+        if completed_task.execution_mode != ExecutionMode::Sequential
+            && !(completed_task.status == TaskStatus::Done
+                && TaskDependency::has_dependents(&self.db.pool, completed_task.id).await?)
+        {
             return Ok(None);
         }
 
@@ -168,3 +287,75 @@ impl SequentialQueueService {
         Ok(next_task)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn task(status: TaskStatus, position: i32) -> Task {
+        Task {
+            id: Uuid::new_v4(),
+            project_id: Uuid::nil(),
+            title: format!("task {position}"),
+            description: None,
+            status,
+            execution_mode: ExecutionMode::Sequential,
+            queue_position: Some(position),
+            parent_workspace_id: None,
+            shared_task_id: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn dependency(task: &Task, depends_on: &Task) -> TaskDependency {
+        TaskDependency {
+            task_id: task.id,
+            depends_on_task_id: depends_on.id,
+            depends_on_status: depends_on.status.clone(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn orders_queue_by_dependencies() {
+        let a = task(TaskStatus::Todo, 1);
+        let b = task(TaskStatus::Todo, 2);
+        let c = task(TaskStatus::Todo, 3);
+        let outside = task(TaskStatus::InReview, 0);
+        // a waits on c, c waits on a task outside the queue
+        let deps = vec![dependency(&a, &c), dependency(&c, &outside)];
+        let queue = vec![a.clone(), b.clone(), c.clone()];
+
+        let plan = plan_queue(&queue, &deps);
+        assert_eq!(plan.order, vec![b.id, c.id, a.id]);
+        assert_eq!(plan.blocked, vec![a.id, c.id]);
+        assert_eq!(plan.next_ready(), Some(b.id));
+
+        let done = Task {
+            status: TaskStatus::Done,
+            ..outside
+        };
+        let deps = vec![dependency(&a, &c), dependency(&c, &done)];
+        let plan = plan_queue(&[a.clone(), c.clone()], &deps);
+        assert_eq!(plan.order, vec![c.id, a.id]);
+        assert_eq!(plan.next_ready(), Some(c.id));
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let a = task(TaskStatus::Todo, 1);
+        let b = task(TaskStatus::Todo, 2);
+        let c = task(TaskStatus::Todo, 3);
+        let deps = vec![dependency(&b, &a), dependency(&c, &b)];
+        assert!(creates_cycle(&deps, a.id, c.id));
+        assert!(!creates_cycle(&deps, c.id, a.id));
+
+        let cyclic = vec![dependency(&a, &b), dependency(&b, &a)];
+        let plan = plan_queue(&[a.clone(), b.clone()], &cyclic);
+        assert_eq!(plan.order, vec![a.id, b.id]);
+        assert_eq!(plan.next_ready(), None);
+    }
+}
@@ -0,0 +1,289 @@
+//! Stories of a Shortcut (formerly Clubhouse) workspace, read through the v3
+//! REST API with an API token.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+use ts_rs::TS;
+
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+const API_BASE: &str = "https://api.app.shortcut.com/api/v3";
+
+/// Workflow state types of stories that are not finished
+const OPEN_STATE_TYPES: [&str; 3] = ["backlog", "unstarted", "started"];
+
+#[derive(Debug, Error)]
+pub enum ShortcutError {
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Shortcut API error: {status} - {message}")]
+    Api { status: u16, message: String },
+    #[error("Shortcut rejected the API token; update it in project settings")]
+    Unauthorized,
+    #[error("Not found in Shortcut; check the id and that the token can see it")]
+    NotFound,
+    #[error("No review workflow state found for story {0}; pick one in project settings")]
+    NoReviewState(i64),
+    #[error("Failed to parse API response: {0}")]
+    ParseError(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ShortcutLabel {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ShortcutStory {
+    #[ts(type = "number")]
+    pub id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub app_url: String,
+    #[serde(default)]
+    pub story_type: String,
+    #[ts(type = "number")]
+    pub workflow_id: i64,
+    #[ts(type = "number")]
+    pub workflow_state_id: i64,
+    #[serde(default)]
+    pub labels: Vec<ShortcutLabel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ShortcutWorkflowState {
+    #[ts(type = "number")]
+    pub id: i64,
+    pub name: String,
+    /// `backlog`, `unstarted`, `started` or `done`
+    #[serde(rename = "type")]
+    pub state_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct ShortcutWorkflow {
+    #[ts(type = "number")]
+    pub id: i64,
+    pub name: String,
+    pub states: Vec<ShortcutWorkflowState>,
+}
+
+impl ShortcutWorkflow {
+    /// The state named like a review step, for when none is configured
+    pub fn review_state(&self) -> Option<&ShortcutWorkflowState> {
+        self.states
+            .iter()
+            .filter(|state| state.state_type != "done")
+            .find(|state| state.name.to_lowercase().contains("review"))
+    }
+}
+
+pub struct ShortcutService {
+    http: HttpClient,
+}
+
+impl ShortcutService {
+    pub fn new() -> Self {
+        Self {
+            http: HttpClient::shared(),
+        }
+    }
+
+    async fn send<T: for<'de> Deserialize<'de>>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, ShortcutError> {
+        let response = request.send_via(&self.http).await?;
+        let status = response.status();
+        match status.as_u16() {
+            401 => return Err(ShortcutError::Unauthorized),
+            403 | 404 => return Err(ShortcutError::NotFound),
+            _ => {}
+        }
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(ShortcutError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| ShortcutError::ParseError(e.to_string()))
+    }
+
+    /// The workspace's workflows with their states; also checks the token
+    pub async fn list_workflows(
+        &self,
+        token: &str,
+    ) -> Result<Vec<ShortcutWorkflow>, ShortcutError> {
+        let url = format!("{API_BASE}/workflows");
+        self.send(self.http.get(&url).header("Shortcut-Token", token))
+            .await
+    }
+
+    /// Unfinished, unarchived stories with any of `labels`, or all of them
+    /// when no labels are given
+    pub async fn list_open_stories(
+        &self,
+        token: &str,
+        labels: &[String],
+    ) -> Result<Vec<ShortcutStory>, ShortcutError> {
+        let url = format!("{API_BASE}/stories/search");
+        let label_filters: Vec<Option<&str>> = if labels.is_empty() {
+            vec![None]
+        } else {
+            labels.iter().map(|label| Some(label.as_str())).collect()
+        };
+
+        let mut stories: Vec<ShortcutStory> = Vec::new();
+        for label in label_filters {
+            let mut body = json!({
+                "archived": false,
+                "workflow_state_types": OPEN_STATE_TYPES,
+            });
+            if let Some(label) = label {
+                body["label_name"] = json!(label);
+            }
+            let found: Vec<ShortcutStory> = self
+                .send(
+                    self.http
+                        .post(&url)
+                        .header("Shortcut-Token", token)
+                        .json(&body),
+                )
+                .await?;
+            for story in found {
+                if !stories.iter().any(|existing| existing.id == story.id) {
+                    stories.push(story);
+                }
+            }
+        }
+        Ok(stories)
+    }
+
+    pub async fn get_story(
+        &self,
+        token: &str,
+        story_id: i64,
+    ) -> Result<ShortcutStory, ShortcutError> {
+        let url = format!("{API_BASE}/stories/{story_id}");
+        self.send(self.http.get(&url).header("Shortcut-Token", token))
+            .await
+    }
+
+    /// Move a story to `state_id`, or with `None` to the review-like state of
+    /// its workflow
+    pub async fn move_to_review(
+        &self,
+        token: &str,
+        story_id: i64,
+        state_id: Option<i64>,
+    ) -> Result<(), ShortcutError> {
+        let state_id = match state_id {
+            Some(state_id) => state_id,
+            None => {
+                let story = self.get_story(token, story_id).await?;
+                self.list_workflows(token)
+                    .await?
+                    .iter()
+                    .find(|workflow| workflow.id == story.workflow_id)
+                    .and_then(ShortcutWorkflow::review_state)
+                    .map(|state| state.id)
+                    .ok_or(ShortcutError::NoReviewState(story_id))?
+            }
+        };
+
+        let url = format!("{API_BASE}/stories/{story_id}");
+        self.send::<serde_json::Value>(
+            self.http
+                .put(&url)
+                .header("Shortcut-Token", token)
+                .json(&json!({ "workflow_state_id": state_id })),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn add_comment(
+        &self,
+        token: &str,
+        story_id: i64,
+        text: &str,
+    ) -> Result<(), ShortcutError> {
+        let url = format!("{API_BASE}/stories/{story_id}/comments");
+        self.send::<serde_json::Value>(
+            self.http
+                .post(&url)
+                .header("Shortcut-Token", token)
+                .json(&json!({ "text": text })),
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+impl Default for ShortcutService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Labels from a comma-separated setting, trimmed
+pub fn parse_label_filter(filter: Option<&str>) -> Vec<String> {
+    filter
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(id: i64, name: &str, state_type: &str) -> ShortcutWorkflowState {
+        ShortcutWorkflowState {
+            id,
+            name: name.to_string(),
+            state_type: state_type.to_string(),
+        }
+    }
+
+    #[test]
+    fn finds_review_state() {
+        let workflow = ShortcutWorkflow {
+            id: 1,
+            name: "Engineering".to_string(),
+            states: vec![
+                state(10, "Ready for Development", "unstarted"),
+                state(11, "In Development", "started"),
+                state(12, "Ready for Review", "started"),
+                state(13, "Completed", "done"),
+            ],
+        };
+        assert_eq!(workflow.review_state().map(|s| s.id), Some(12));
+
+        let without_review = ShortcutWorkflow {
+            states: vec![state(20, "Reviewed", "done")],
+            ..workflow
+        };
+        assert!(without_review.review_state().is_none());
+    }
+
+    #[test]
+    fn parses_label_filters() {
+        assert_eq!(
+            parse_label_filter(Some("agent, ,backend ")),
+            vec!["agent".to_string(), "backend".to_string()]
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! Periodically checks every project's SLA rules, opening a breach (with a
+//! notification and a REST hook delivery) when a task goes over a limit and
+//! resolving it once the task moves on or sees activity again.
+
+use std::{collections::HashSet, time::Duration};
+
+use chrono::{DateTime, Utc};
+use db::{
+    DBService,
+    models::{
+        notification::{CreateNotification, Notification, NotificationKind},
+        sla_rule::{SlaRule, SlaRuleKind, SlaTaskState},
+        task::{Task, TaskStatus},
+        task_sla_breach::TaskSlaBreach,
+    },
+};
+use sqlx::error::Error as SqlxError;
+use tokio::time::interval;
+use tracing::{error, info};
+
+use crate::services::rest_hooks::{RestHookService, RestHookTrigger};
+
+/// Whether a task in the rule's status is over the rule's threshold at `now`
+pub fn is_breaching(rule: &SlaRule, task: &SlaTaskState, now: DateTime<Utc>) -> bool {
+    let since = match rule.kind {
+        SlaRuleKind::TimeInStatus => task.status_entered_at,
+        // Time spent waiting to enter the status doesn't count as idling in it
+        SlaRuleKind::Idle => task
+            .last_activity_at
+            .map_or(task.status_entered_at, |at| at.max(task.status_entered_at)),
+    };
+    (now - since).num_seconds() as f64 > rule.threshold_hours * 3600.0
+}
+
+/// Human-readable rule, e.g. "In Review for more than 3 days"
+pub fn describe_rule(rule: &SlaRule) -> String {
+    let status = match rule.status {
+        TaskStatus::Todo => "To Do",
+        TaskStatus::InProgress => "In Progress",
+        TaskStatus::InReview => "In Review",
+        TaskStatus::Done => "Done",
+        TaskStatus::Cancelled => "Cancelled",
+    };
+    let hours = rule.threshold_hours;
+    let days = hours / 24.0;
+    let limit = if days == 1.0 {
+        "1 day".to_string()
+    } else if days.fract() == 0.0 {
+        format!("{days} days")
+    } else {
+        format!("{hours}h")
+    };
+    match rule.kind {
+        SlaRuleKind::TimeInStatus => format!("{status} for more than {limit}"),
+        SlaRuleKind::Idle => format!("{status} with no attempt activity for {limit}"),
+    }
+}
+
+pub struct SlaMonitorService {
+    db: DBService,
+    rest_hooks: RestHookService,
+    poll_interval: Duration,
+}
+
+impl SlaMonitorService {
+    pub async fn spawn(db: DBService) -> tokio::task::JoinHandle<()> {
+        let service = Self {
+            rest_hooks: RestHookService::new(db.clone()),
+            db,
+            poll_interval: Duration::from_secs(300),
+        };
+        tokio::spawn(async move {
+            service.start().await;
+        })
+    }
+
+    async fn start(&self) {
+        info!(
+            "Starting SLA monitoring service with interval {:?}",
+            self.poll_interval
+        );
+        let mut interval = interval(self.poll_interval);
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.check_all_rules().await {
+                error!("Error checking SLA rules: {}", e);
+            }
+        }
+    }
+
+    async fn check_all_rules(&self) -> Result<(), SqlxError> {
+        for rule in SlaRule::find_all(&self.db.pool).await? {
+            if let Err(e) = self.check_rule(&rule).await {
+                error!("Error checking SLA rule {}: {}", rule.id, e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn check_rule(&self, rule: &SlaRule) -> Result<(), SqlxError> {
+        let pool = &self.db.pool;
+        let now = Utc::now();
+        let breaching: Vec<SlaTaskState> = rule
+            .find_task_states(pool)
+            .await?
+            .into_iter()
+            .filter(|task| is_breaching(rule, task, now))
+            .collect();
+        let open = TaskSlaBreach::find_open_by_rule_id(pool, rule.id).await?;
+
+        let breaching_ids: HashSet<_> = breaching.iter().map(|task| task.task_id).collect();
+        for breach in &open {
+            if !breaching_ids.contains(&breach.task_id) {
+                TaskSlaBreach::resolve(pool, breach.id).await?;
+            }
+        }
+
+        let already_open: HashSet<_> = open.iter().map(|breach| breach.task_id).collect();
+        for task in breaching {
+            if already_open.contains(&task.task_id) {
+                continue;
+            }
+            TaskSlaBreach::open(pool, task.task_id, rule.id).await?;
+            self.alert(rule, &task).await?;
+        }
+        Ok(())
+    }
+
+    async fn alert(&self, rule: &SlaRule, task: &SlaTaskState) -> Result<(), SqlxError> {
+        let pool = &self.db.pool;
+        Notification::create_for_all_users(
+            pool,
+            &CreateNotification {
+                kind: NotificationKind::SlaBreach,
+                title: format!("SLA breached: {}", task.title),
+                message: format!("Task has been {}", describe_rule(rule)),
+                project_id: Some(rule.project_id),
+                task_id: Some(task.task_id),
+                workspace_id: None,
+            },
+        )
+        .await?;
+
+        if let Some(task) = Task::find_by_id(pool, task.task_id).await? {
+            self.rest_hooks
+                .dispatch(RestHookTrigger::SlaBreached {
+                    task,
+                    rule: rule.clone(),
+                })
+                .await;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[test]
+    fn idle_rules_count_from_the_later_of_status_change_and_activity() {
+        let now = Utc::now();
+        let rule = |kind| SlaRule {
+            id: Uuid::new_v4(),
+            project_id: Uuid::new_v4(),
+            status: TaskStatus::InProgress,
+            kind,
+            threshold_hours: 24.0,
+            created_at: now,
+        };
+        let task = SlaTaskState {
+            task_id: Uuid::new_v4(),
+            title: "Fix login".to_string(),
+            status_entered_at: now - Duration::hours(30),
+            last_activity_at: Some(now - Duration::hours(2)),
+        };
+
+        assert!(is_breaching(&rule(SlaRuleKind::TimeInStatus), &task, now));
+        assert!(!is_breaching(&rule(SlaRuleKind::Idle), &task, now));
+        assert!(is_breaching(
+            &rule(SlaRuleKind::Idle),
+            &SlaTaskState {
+                last_activity_at: None,
+                ..task
+            },
+            now
+        ));
+        assert_eq!(
+            describe_rule(&rule(SlaRuleKind::Idle)),
+            "In Progress with no attempt activity for 1 day"
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! Slack slash commands and interactive messages. `/vk create <quick add line>`
+//! creates a task, `/vk status <task>` reports on tasks and `/vk pending` lists
+//! the starts waiting for approval, with buttons to approve or deny each.
+//!
+//! Slack signs each request with the app's signing secret, which projects store
+//! as their `slack` webhook secret.
+
+use db::models::{
+    pending_start::PendingStart,
+    task::{TaskStatus, TaskWithAttemptStatus},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use url::form_urlencoded;
+use uuid::Uuid;
+
+use crate::services::{
+    http_client::{HttpClient, HttpClientError, SendVia},
+    webhooks::signature_matches,
+};
+
+/// Requests signed longer ago than this are rejected as possible replays
+pub const MAX_REQUEST_AGE_SECS: i64 = 5 * 60;
+
+/// `action_id` of the approve button on pending starts
+pub const APPROVE_PENDING_START: &str = "approve_pending_start";
+
+/// `action_id` of the deny button on pending starts
+pub const DENY_PENDING_START: &str = "deny_pending_start";
+
+/// Only Slack's own hosts are sent interaction replies
+const RESPONSE_URL_PREFIX: &str = "https://hooks.slack.com/";
+
+/// Check an `X-Slack-Signature` header, `v0=` followed by the hex HMAC-SHA256
+/// of `v0:<timestamp>:<body>`, and that the timestamp is recent. `now` is in
+/// Unix seconds.
+pub fn slack_signature_matches(
+    signing_secret: &str,
+    timestamp: &str,
+    header: &str,
+    body: &[u8],
+    now: i64,
+) -> bool {
+    let Ok(sent_at) = timestamp.parse::<i64>() else {
+        return false;
+    };
+    if (now - sent_at).abs() > MAX_REQUEST_AGE_SECS {
+        return false;
+    }
+    let Some(signature_hex) = header.trim().strip_prefix("v0=") else {
+        return false;
+    };
+    let mut payload = format!("v0:{timestamp}:").into_bytes();
+    payload.extend_from_slice(body);
+    signature_matches(signing_secret, signature_hex, &payload)
+}
+
+/// The fields of a slash command delivery that are used
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlashCommand {
+    /// The command as invoked, e.g. `/vk`
+    pub command: String,
+    pub text: String,
+    /// Slack user id of whoever ran the command. Unlike the username it can't
+    /// be changed by its owner.
+    pub user_id: String,
+}
+
+/// Read a form-encoded slash command delivery
+pub fn parse_slash_command(body: &[u8]) -> SlashCommand {
+    let mut command = SlashCommand::default();
+    for (key, value) in form_urlencoded::parse(body) {
+        match key.as_ref() {
+            "command" => command.command = value.into_owned(),
+            "text" => command.text = value.into_owned(),
+            "user_id" => command.user_id = value.into_owned(),
+            _ => {}
+        }
+    }
+    command
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlackCommand {
+    /// `create <quick add line>`
+    Create(String),
+    /// `status <task title or id>`
+    Status(String),
+    /// `pending`
+    Pending,
+    Help,
+}
+
+/// Read the text after the slash command. Anything unrecognised asks for help.
+pub fn parse_command(text: &str) -> SlackCommand {
+    let text = text.trim();
+    let (verb, rest) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let rest = rest.trim();
+    match verb.to_lowercase().as_str() {
+        "create" | "add" if !rest.is_empty() => SlackCommand::Create(rest.to_string()),
+        "status" if !rest.is_empty() => SlackCommand::Status(rest.to_string()),
+        "pending" => SlackCommand::Pending,
+        _ => SlackCommand::Help,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingStartDecision {
+    Approve,
+    Deny,
+}
+
+/// A click on a pending start's approve or deny button
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingStartAction {
+    pub decision: PendingStartDecision,
+    pub pending_start_id: Uuid,
+    /// Slack user id of whoever clicked
+    pub user_id: Option<String>,
+    /// Where to send the message that replaces the buttons
+    pub response_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InteractionPayload {
+    user: InteractionUser,
+    #[serde(default)]
+    actions: Vec<InteractionAction>,
+    response_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InteractionUser {
+    id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InteractionAction {
+    action_id: String,
+    value: Option<String>,
+}
+
+/// Read an interaction delivery, whose JSON comes in the `payload` form field.
+/// `None` when it isn't a click on a pending start button.
+pub fn parse_interaction(body: &[u8]) -> Result<Option<PendingStartAction>, serde_json::Error> {
+    let payload = form_urlencoded::parse(body)
+        .find(|(key, _)| key == "payload")
+        .map(|(_, value)| value.into_owned())
+        .unwrap_or_default();
+    let payload: InteractionPayload = serde_json::from_str(&payload)?;
+
+    Ok(payload.actions.iter().find_map(|action| {
+        let decision = match action.action_id.as_str() {
+            APPROVE_PENDING_START => PendingStartDecision::Approve,
+            DENY_PENDING_START => PendingStartDecision::Deny,
+            _ => return None,
+        };
+        Some(PendingStartAction {
+            decision,
+            pending_start_id: action.value.as_deref()?.parse().ok()?,
+            user_id: payload.user.id.clone(),
+            response_url: payload
+                .response_url
+                .clone()
+                .filter(|url| url.starts_with(RESPONSE_URL_PREFIX)),
+        })
+    }))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlackResponseType {
+    /// Only shown to whoever ran the command
+    Ephemeral,
+    InChannel,
+}
+
+/// A message in the shape Slack expects in command and interaction replies
+#[derive(Debug, Clone, Serialize)]
+pub struct SlackMessage {
+    pub response_type: SlackResponseType,
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Value>,
+    /// Replace the message whose button was clicked
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub replace_original: bool,
+}
+
+impl SlackMessage {
+    pub fn ephemeral(text: impl Into<String>) -> Self {
+        Self {
+            response_type: SlackResponseType::Ephemeral,
+            text: text.into(),
+            blocks: Vec::new(),
+            replace_original: false,
+        }
+    }
+
+    pub fn in_channel(text: impl Into<String>) -> Self {
+        Self {
+            response_type: SlackResponseType::InChannel,
+            ..Self::ephemeral(text)
+        }
+    }
+
+    pub fn replacing_original(mut self) -> Self {
+        self.replace_original = true;
+        self
+    }
+}
+
+/// How messages refer to a Slack user. Slack shows the user's current name.
+pub fn mention(user_id: &str) -> String {
+    format!("<@{user_id}>")
+}
+
+pub fn help_message(command: &str) -> SlackMessage {
+    let command = if command.is_empty() { "/vk" } else { command };
+    SlackMessage::ephemeral(format!(
+        "`{command} create <task>` creates a task; `#label`, `!high`, `@user`, `due:fri` \
+         and `seq` set its fields\n\
+         `{command} status <task title or id>` shows where tasks stand\n\
+         `{command} pending` lists attempt starts waiting for approval"
+    ))
+}
+
+fn status_label(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Todo => "To Do",
+        TaskStatus::InProgress => "In Progress",
+        TaskStatus::InReview => "In Review",
+        TaskStatus::Done => "Done",
+        TaskStatus::Cancelled => "Cancelled",
+    }
+}
+
+/// One line per task, e.g. `*Fix login* is In Progress, with an attempt running`
+pub fn status_message(tasks: &[TaskWithAttemptStatus]) -> SlackMessage {
+    let lines: Vec<String> = tasks
+        .iter()
+        .map(|task| {
+            let attempt = if task.has_in_progress_attempt {
+                ", with an attempt running"
+            } else if task.last_attempt_failed {
+                "; its last attempt failed"
+            } else {
+                ""
+            };
+            format!(
+                "*{}* is {}{}",
+                task.title,
+                status_label(&task.status),
+                attempt
+            )
+        })
+        .collect();
+    SlackMessage::ephemeral(lines.join("\n"))
+}
+
+/// The pending starts, each with approve and deny buttons carrying its id
+pub fn pending_starts_message(pending: &[PendingStart]) -> SlackMessage {
+    if pending.is_empty() {
+        return SlackMessage::ephemeral("No attempt starts are waiting for approval");
+    }
+    let mut message = SlackMessage::ephemeral(format!(
+        "{} attempt start(s) waiting for approval",
+        pending.len()
+    ));
+    for start in pending {
+        let requested_by = start
+            .requested_by_username
+            .as_deref()
+            .map(|username| format!(", moved to In Progress by @{username}"))
+            .unwrap_or_default();
+        message.blocks.push(json!({
+            "type": "section",
+            "text": {
+                "type": "mrkdwn",
+                "text": format!("*{}*{}", start.task_title, requested_by),
+            },
+        }));
+        message.blocks.push(json!({
+            "type": "actions",
+            "elements": [
+                {
+                    "type": "button",
+                    "action_id": APPROVE_PENDING_START,
+                    "style": "primary",
+                    "text": { "type": "plain_text", "text": "Approve" },
+                    "value": start.id.to_string(),
+                },
+                {
+                    "type": "button",
+                    "action_id": DENY_PENDING_START,
+                    "style": "danger",
+                    "text": { "type": "plain_text", "text": "Deny" },
+                    "value": start.id.to_string(),
+                },
+            ],
+        }));
+    }
+    message
+}
+
+/// Reply to an interaction. Slack only takes an acknowledgement in the
+/// interaction's response, so the message is posted to its `response_url`.
+pub async fn send_response(
+    response_url: &str,
+    message: &SlackMessage,
+) -> Result<(), HttpClientError> {
+    let http = HttpClient::shared();
+    http.post(response_url)
+        .json(message)
+        .send_via(&http)
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    use super::*;
+
+    #[test]
+    fn verifies_and_reads_slack_requests() {
+        let body =
+            b"command=%2Fvk&text=create+Fix+login+%23bug&user_id=U2147483697&user_name=alice";
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"8f742231b10e8888abcd99yyyzzz85a5").unwrap();
+        mac.update(b"v0:1531420618:");
+        mac.update(body);
+        let header = format!("v0={}", hex::encode(mac.finalize().into_bytes()));
+        let secret = "8f742231b10e8888abcd99yyyzzz85a5";
+
+        assert!(slack_signature_matches(
+            secret,
+            "1531420618",
+            &header,
+            body,
+            1531420700
+        ));
+        // Replayed later, or signed with another secret
+        assert!(!slack_signature_matches(
+            secret,
+            "1531420618",
+            &header,
+            body,
+            1531429999
+        ));
+        assert!(!slack_signature_matches(
+            "another-secret",
+            "1531420618",
+            &header,
+            body,
+            1531420700
+        ));
+
+        let command = parse_slash_command(body);
+        assert_eq!(command.command, "/vk");
+        assert_eq!(command.user_id, "U2147483697");
+        assert_eq!(
+            parse_command(&command.text),
+            SlackCommand::Create("Fix login #bug".to_string())
+        );
+        assert_eq!(
+            parse_command(" Status  Fix login "),
+            SlackCommand::Status("Fix login".to_string())
+        );
+        assert_eq!(parse_command("pending"), SlackCommand::Pending);
+        assert_eq!(parse_command("create"), SlackCommand::Help);
+
+        let id = Uuid::new_v4();
+        let payload = json!({
+            "type": "block_actions",
+            "user": { "id": "U123", "username": "bob" },
+            "response_url": "https://hooks.slack.com/actions/T1/2/abc",
+            "actions": [{ "action_id": DENY_PENDING_START, "value": id.to_string() }],
+        });
+        let body: String = form_urlencoded::Serializer::new(String::new())
+            .append_pair("payload", &payload.to_string())
+            .finish();
+        assert_eq!(
+            parse_interaction(body.as_bytes()).unwrap(),
+            Some(PendingStartAction {
+                decision: PendingStartDecision::Deny,
+                pending_start_id: id,
+                user_id: Some("U123".to_string()),
+                response_url: Some("https://hooks.slack.com/actions/T1/2/abc".to_string()),
+            })
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Read-only project summaries for public status pages, served as JSON or as a
+//! self-contained HTML page that wikis can embed.
+
+use chrono::{DateTime, Utc};
+use db::models::{
+    project_status_page::{CompletedTask, StatusCount},
+    task::TaskStatus,
+};
+use serde::Serialize;
+use ts_rs::TS;
+
+/// Board columns in the order they're shown
+const COLUMNS: [TaskStatus; 5] = [
+    TaskStatus::Todo,
+    TaskStatus::InProgress,
+    TaskStatus::InReview,
+    TaskStatus::Done,
+    TaskStatus::Cancelled,
+];
+
+/// What a public status page shows. Only titles of completed tasks are
+/// included; descriptions, ids and people stay private.
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PublicProjectStatus {
+    pub project_name: String,
+    /// Every column, including empty ones
+    pub columns: Vec<StatusCount>,
+    /// Most recently completed first
+    pub recently_completed: Vec<CompletedTask>,
+    /// Sequential tasks waiting in To Do for the queue to reach them
+    #[ts(type = "number")]
+    pub queue_length: i64,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl PublicProjectStatus {
+    pub fn new(
+        project_name: String,
+        counts: &[StatusCount],
+        recently_completed: Vec<CompletedTask>,
+        queue_length: i64,
+    ) -> Self {
+        let columns = COLUMNS
+            .into_iter()
+            .map(|status| StatusCount {
+                count: counts
+                    .iter()
+                    .find(|count| count.status == status)
+                    .map_or(0, |count| count.count),
+                status,
+            })
+            .collect();
+        Self {
+            project_name,
+            columns,
+            recently_completed,
+            queue_length,
+            generated_at: Utc::now(),
+        }
+    }
+}
+
+fn column_name(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Todo => "To Do",
+        TaskStatus::InProgress => "In Progress",
+        TaskStatus::InReview => "In Review",
+        TaskStatus::Done => "Done",
+        TaskStatus::Cancelled => "Cancelled",
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub fn render_status_page(status: &PublicProjectStatus) -> String {
+    let name = escape_html(&status.project_name);
+    let columns: String = status
+        .columns
+        .iter()
+        .map(|column| {
+            format!(
+                "<li><span class=\"count\">{}</span> {}</li>",
+                column.count,
+                column_name(&column.status)
+            )
+        })
+        .collect();
+    let completed: String = if status.recently_completed.is_empty() {
+        "<li>Nothing completed yet</li>".to_string()
+    } else {
+        status
+            .recently_completed
+            .iter()
+            .map(|task| {
+                format!(
+                    "<li>{} <time datetime=\"{}\">{}</time></li>",
+                    escape_html(&task.title),
+                    task.completed_at.to_rfc3339(),
+                    task.completed_at.format("%Y-%m-%d")
+                )
+            })
+            .collect()
+    };
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html lang=\"en\">\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{name} status</title>\n\
+         <style>\
+         body{{font-family:system-ui,sans-serif;margin:1rem;color:#222}}\
+         ul{{list-style:none;padding:0}}\
+         .columns li{{display:inline-block;margin-right:1.5rem}}\
+         .count{{font-size:1.5rem;font-weight:600}}\
+         time{{color:#777;margin-left:.5rem}}\
+         </style>\n\
+         </head>\n\
+         <body>\n\
+         <h1>{name}</h1>\n\
+         <ul class=\"columns\">{columns}</ul>\n\
+         <p>{queue} task(s) waiting in the queue</p>\n\
+         <h2>Recently completed</h2>\n\
+         <ul>{completed}</ul>\n\
+         <footer><small>Updated <time datetime=\"{generated}\">{generated_label}</time></small></footer>\n\
+         </body>\n\
+         </html>\n",
+        queue = status.queue_length,
+        generated = status.generated_at.to_rfc3339(),
+        generated_label = status.generated_at.format("%Y-%m-%d %H:%M UTC"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn fills_empty_columns_and_escapes_titles() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+        let status = PublicProjectStatus::new(
+            "Web <app>".to_string(),
+            &[
+                StatusCount {
+                    status: TaskStatus::Done,
+                    count: 4,
+                },
+                StatusCount {
+                    status: TaskStatus::Todo,
+                    count: 2,
+                },
+            ],
+            vec![CompletedTask {
+                title: "Fix <script> & login".to_string(),
+                completed_at: at,
+            }],
+            1,
+        );
+        let counts: Vec<(TaskStatus, i64)> = status
+            .columns
+            .iter()
+            .map(|column| (column.status.clone(), column.count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![
+                (TaskStatus::Todo, 2),
+                (TaskStatus::InProgress, 0),
+                (TaskStatus::InReview, 0),
+                (TaskStatus::Done, 4),
+                (TaskStatus::Cancelled, 0),
+            ]
+        );
+
+        let html = render_status_page(&status);
+        assert!(html.contains("<h1>Web &lt;app&gt;</h1>"));
+        assert!(html.contains("Fix &lt;script&gt; &amp; login"));
+        assert!(html.contains("<span class=\"count\">4</span> Done"));
+        assert!(!html.contains("<script>"));
+    }
+}
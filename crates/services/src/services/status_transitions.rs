@@ -0,0 +1,81 @@
+use db::models::{project_status_transition::StatusTransition, task::TaskStatus};
+
+/// Whether a task may move from `from` to `to` under a project's rules.
+/// Staying put is always allowed, as is anything when the project has no rules.
+pub fn is_transition_allowed(
+    rules: &[StatusTransition],
+    from: &TaskStatus,
+    to: &TaskStatus,
+) -> bool {
+    from == to
+        || rules.is_empty()
+        || rules
+            .iter()
+            .any(|rule| &rule.from_status == from && &rule.to_status == to)
+}
+
+/// Message shown when a move is rejected, listing where the task can go instead
+pub fn rejection_message(rules: &[StatusTransition], from: &TaskStatus, to: &TaskStatus) -> String {
+    let allowed: Vec<String> = rules
+        .iter()
+        .filter(|rule| &rule.from_status == from)
+        .map(|rule| rule.to_status.to_string())
+        .collect();
+    if allowed.is_empty() {
+        format!("Tasks in '{from}' cannot change status in this project")
+    } else {
+        format!(
+            "Tasks cannot move from '{from}' to '{to}' in this project; allowed: {}",
+            allowed.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(from_status: TaskStatus, to_status: TaskStatus) -> StatusTransition {
+        StatusTransition {
+            from_status,
+            to_status,
+        }
+    }
+
+    #[test]
+    fn linear_workflow_blocks_skipping_to_done() {
+        let rules = [
+            rule(TaskStatus::Todo, TaskStatus::InProgress),
+            rule(TaskStatus::InProgress, TaskStatus::InReview),
+            rule(TaskStatus::InReview, TaskStatus::Done),
+        ];
+        assert!(is_transition_allowed(
+            &rules,
+            &TaskStatus::Todo,
+            &TaskStatus::InProgress
+        ));
+        assert!(is_transition_allowed(
+            &rules,
+            &TaskStatus::Done,
+            &TaskStatus::Done
+        ));
+        assert!(!is_transition_allowed(
+            &rules,
+            &TaskStatus::Todo,
+            &TaskStatus::Done
+        ));
+        assert_eq!(
+            rejection_message(&rules, &TaskStatus::Todo, &TaskStatus::Done),
+            "Tasks cannot move from 'todo' to 'done' in this project; allowed: inprogress"
+        );
+    }
+
+    #[test]
+    fn no_rules_allows_everything() {
+        assert!(is_transition_allowed(
+            &[],
+            &TaskStatus::Todo,
+            &TaskStatus::Done
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use db::{
+    models::workspace::Workspace,
+    stats::{DatabaseStats, OrphanedRecords},
+};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tracing::warn;
+use ts_rs::TS;
+use utils::path::normalize_container_ref;
+
+use super::workspace_manager::WorkspaceManager;
+
+#[derive(Debug, Clone, Default, Serialize, TS)]
+pub struct DirectoryUsage {
+    pub path: String,
+    pub size_bytes: u64,
+    pub file_count: u64,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct WorkspaceDiskUsage {
+    pub usage: DirectoryUsage,
+    pub workspace_count: u64,
+    /// Workspace directories on disk that no workspace row points at any more
+    pub orphaned_count: u64,
+    pub orphaned_size_bytes: u64,
+    pub oldest_orphan_modified_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct StorageStats {
+    pub database: DatabaseStats,
+    pub images: DirectoryUsage,
+    pub workspaces: WorkspaceDiskUsage,
+    pub orphaned_records: Vec<OrphanedRecords>,
+}
+
+impl StorageStats {
+    pub async fn collect(pool: &SqlitePool, image_dir: &Path) -> Result<Self, sqlx::Error> {
+        let database = DatabaseStats::collect(pool).await?;
+        let orphaned_records = OrphanedRecords::find_all(pool).await?;
+        let images = directory_usage(image_dir.to_path_buf()).await;
+        let workspaces = workspace_disk_usage(pool).await?;
+
+        Ok(Self {
+            database,
+            images,
+            workspaces,
+            orphaned_records,
+        })
+    }
+}
+
+async fn workspace_disk_usage(pool: &SqlitePool) -> Result<WorkspaceDiskUsage, sqlx::Error> {
+    let base_dir = WorkspaceManager::get_workspace_base_dir();
+    let mut stats = WorkspaceDiskUsage {
+        usage: DirectoryUsage {
+            path: base_dir.to_string_lossy().to_string(),
+            ..Default::default()
+        },
+        workspace_count: 0,
+        orphaned_count: 0,
+        orphaned_size_bytes: 0,
+        oldest_orphan_modified_at: None,
+    };
+
+    let Ok(entries) = fs::read_dir(&base_dir) else {
+        return Ok(stats);
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let usage = directory_usage(path.clone()).await;
+        stats.workspace_count += 1;
+        stats.usage.size_bytes += usage.size_bytes;
+        stats.usage.file_count += usage.file_count;
+
+        if !Workspace::container_ref_exists(pool, &normalize_container_ref(&path)).await? {
+            stats.orphaned_count += 1;
+            stats.orphaned_size_bytes += usage.size_bytes;
+            let modified = entry
+                .metadata()
+                .and_then(|m| m.modified())
+                .ok()
+                .map(DateTime::<Utc>::from);
+            if let Some(modified) = modified
+                && stats
+                    .oldest_orphan_modified_at
+                    .is_none_or(|oldest| modified < oldest)
+            {
+                stats.oldest_orphan_modified_at = Some(modified);
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Total size of all files below `path`. Symlinks are not followed and unreadable
+/// entries are skipped, so the result is a lower bound rather than an error.
+async fn directory_usage(path: PathBuf) -> DirectoryUsage {
+    let path_str = path.to_string_lossy().to_string();
+    let walk = tokio::task::spawn_blocking(move || {
+        let mut size_bytes = 0;
+        let mut file_count = 0;
+        let mut stack = vec![path];
+        while let Some(dir) = stack.pop() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(metadata) = entry.path().symlink_metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    stack.push(entry.path());
+                } else if metadata.is_file() {
+                    size_bytes += metadata.len();
+                    file_count += 1;
+                }
+            }
+        }
+        (size_bytes, file_count)
+    })
+    .await;
+
+    let (size_bytes, file_count) = walk.unwrap_or_else(|e| {
+        warn!("Failed to measure disk usage of {}: {}", path_str, e);
+        (0, 0)
+    });
+    DirectoryUsage {
+        path: path_str,
+        size_bytes,
+        file_count,
+    }
+}
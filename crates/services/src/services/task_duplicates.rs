@@ -0,0 +1,122 @@
+//! Finds open tasks that may duplicate a new one, since issues synced from a
+//! tracker often overlap tasks that were created by hand. Candidates come from
+//! the title trigram index and are ranked by the share of trigrams their
+//! titles have in common.
+
+use std::collections::HashSet;
+
+use db::models::task::{Task, TaskStatus};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use ts_rs::TS;
+use uuid::Uuid;
+
+/// Titles at least this similar are reported as potential duplicates
+pub const SIMILARITY_THRESHOLD: f64 = 0.4;
+
+/// Index matches scored per check
+const MAX_CANDIDATES: i64 = 50;
+
+/// Potential duplicates reported per check
+const MAX_DUPLICATES: usize = 5;
+
+#[derive(Debug, Clone, Serialize, TS)]
+pub struct PotentialDuplicate {
+    pub task_id: Uuid,
+    pub title: String,
+    pub status: TaskStatus,
+    /// Share of title trigrams the tasks have in common, from 0 to 1
+    pub similarity: f64,
+}
+
+/// Lowercased words of a title, ignoring punctuation
+fn words(title: &str) -> impl Iterator<Item = String> + '_ {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Trigrams of each word, padded like `pg_trgm` so word boundaries count
+fn trigrams(title: &str) -> HashSet<String> {
+    let mut trigrams = HashSet::new();
+    for word in words(title) {
+        let chars: Vec<char> = format!("  {word} ").chars().collect();
+        for window in chars.windows(3) {
+            trigrams.insert(window.iter().collect());
+        }
+    }
+    trigrams
+}
+
+/// Jaccard similarity of the titles' trigrams, from 0 to 1
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (trigrams(a), trigrams(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// FTS5 query matching titles that share a word with `title`. The trigram
+/// tokenizer can't match words shorter than three characters, so those are
+/// left out; `None` when no word is left.
+pub fn fts_query(title: &str) -> Option<String> {
+    let mut seen = HashSet::new();
+    let terms: Vec<String> = words(title)
+        .filter(|word| word.chars().count() >= 3 && seen.insert(word.clone()))
+        .map(|word| format!("\"{word}\""))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+/// Open tasks of the project with titles similar to `title`, most similar first
+pub async fn find_potential_duplicates(
+    pool: &SqlitePool,
+    project_id: Uuid,
+    title: &str,
+    exclude_task_id: Option<Uuid>,
+) -> Result<Vec<PotentialDuplicate>, sqlx::Error> {
+    let Some(query) = fts_query(title) else {
+        return Ok(Vec::new());
+    };
+    let candidates = Task::search_open_by_title(pool, project_id, &query, MAX_CANDIDATES).await?;
+
+    let mut duplicates: Vec<PotentialDuplicate> = candidates
+        .into_iter()
+        .filter(|task| Some(task.id) != exclude_task_id)
+        .map(|task| PotentialDuplicate {
+            similarity: title_similarity(title, &task.title),
+            task_id: task.id,
+            title: task.title,
+            status: task.status,
+        })
+        .filter(|duplicate| duplicate.similarity >= SIMILARITY_THRESHOLD)
+        .collect();
+    duplicates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    duplicates.truncate(MAX_DUPLICATES);
+    Ok(duplicates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_overlapping_titles() {
+        assert_eq!(title_similarity("Fix login crash", "fix LOGIN crash!"), 1.0);
+        assert!(
+            title_similarity("Fix login crash on Safari", "Login crashes on Safari")
+                >= SIMILARITY_THRESHOLD
+        );
+        assert!(title_similarity("Fix login crash on Safari", "Add dark mode") < 0.1);
+        assert_eq!(title_similarity("", "Add dark mode"), 0.0);
+
+        assert_eq!(
+            fts_query("Fix the \"login\" crash on iOS, login").as_deref(),
+            Some("\"fix\" OR \"the\" OR \"login\" OR \"crash\" OR \"ios\"")
+        );
+        assert_eq!(fts_query("UI: a b"), None);
+    }
+}
@@ -0,0 +1,61 @@
+//! Optional one-attempt-at-a-time rule for tasks. While it is on, a task whose
+//! attempt is still running cannot start another; the lock here covers the
+//! window between checking for running attempts and the new attempt's first
+//! process being recorded.
+
+use std::{
+    collections::HashSet,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use uuid::Uuid;
+
+#[derive(Clone, Default)]
+pub struct TaskLocks {
+    enabled: Arc<AtomicBool>,
+    starting: Arc<Mutex<HashSet<Uuid>>>,
+}
+
+/// Held while an attempt for the task is being started
+pub struct TaskLockGuard {
+    locks: TaskLocks,
+    task_id: Uuid,
+}
+
+impl Drop for TaskLockGuard {
+    fn drop(&mut self) {
+        self.locks.starting.lock().unwrap().remove(&self.task_id);
+    }
+}
+
+impl TaskLocks {
+    pub fn new(enabled: bool) -> Self {
+        let locks = Self::default();
+        locks.set_enabled(enabled);
+        locks
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Lock `task_id` for starting an attempt, or `None` if another attempt for
+    /// it is already being started
+    pub fn try_lock(&self, task_id: Uuid) -> Option<TaskLockGuard> {
+        self.starting
+            .lock()
+            .unwrap()
+            .insert(task_id)
+            .then(|| TaskLockGuard {
+                locks: self.clone(),
+                task_id,
+            })
+    }
+}
@@ -0,0 +1,120 @@
+//! Metering of the tokens a coding agent spends, read from the usage it
+//! reports in its raw output, so attempts can be held to a token budget.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// Running token total of one coding agent process. Agents that don't report
+/// usage are never metered above zero.
+#[derive(Debug, Default)]
+pub struct TokenMeter {
+    /// Claude: usage of each API message by id. The same message is repeated
+    /// once per content block, so only the latest report per id counts.
+    messages: HashMap<String, u64>,
+    /// Claude: session usage from the final `result` line
+    session_total: u64,
+    /// Codex: cumulative usage from the latest `token_count` event
+    codex_total: u64,
+}
+
+impl TokenMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take one line of the agent's stdout into account
+    pub fn observe(&mut self, line: &str) {
+        let Ok(json) = serde_json::from_str::<Value>(line.trim()) else {
+            return;
+        };
+
+        match json.get("type").and_then(Value::as_str) {
+            Some("assistant") => {
+                let message = &json["message"];
+                if let (Some(id), Some(tokens)) = (
+                    message.get("id").and_then(Value::as_str),
+                    usage_tokens(&message["usage"]),
+                ) {
+                    self.messages.insert(id.to_string(), tokens);
+                }
+            }
+            Some("result") => {
+                if let Some(tokens) = usage_tokens(&json["usage"]) {
+                    self.session_total = tokens;
+                }
+            }
+            _ => {}
+        }
+
+        let msg = &json["params"]["msg"];
+        if msg.get("type").and_then(Value::as_str) == Some("token_count")
+            && let Some(total) = msg["info"]["total_token_usage"]["total_tokens"].as_u64()
+        {
+            self.codex_total = total;
+        }
+    }
+
+    pub fn total(&self) -> u64 {
+        let claude = self.messages.values().sum::<u64>().max(self.session_total);
+        claude + self.codex_total
+    }
+}
+
+/// Input, output and cache tokens of a Claude `usage` object
+fn usage_tokens(usage: &Value) -> Option<u64> {
+    let usage = usage.as_object()?;
+    Some(
+        [
+            "input_tokens",
+            "output_tokens",
+            "cache_creation_input_tokens",
+            "cache_read_input_tokens",
+        ]
+        .iter()
+        .filter_map(|key| usage.get(*key).and_then(Value::as_u64))
+        .sum(),
+    )
+}
+
+/// How far `tokens_used` is over `max_tokens`, if it is
+pub fn overage(tokens_used: u64, max_tokens: u64) -> Option<u64> {
+    tokens_used.checked_sub(max_tokens).filter(|over| *over > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meters_claude_and_codex_usage() {
+        let mut meter = TokenMeter::new();
+        meter.observe(r#"{"type":"system","subtype":"init"}"#);
+        meter.observe(
+            r#"{"type":"assistant","message":{"id":"m1","role":"assistant","content":[],"usage":{"input_tokens":100,"output_tokens":5}}}"#,
+        );
+        meter.observe(
+            r#"{"type":"assistant","message":{"id":"m1","role":"assistant","content":[],"usage":{"input_tokens":100,"output_tokens":20}}}"#,
+        );
+        meter.observe(
+            r#"{"type":"assistant","message":{"id":"m2","role":"assistant","content":[],"usage":{"input_tokens":10,"cache_read_input_tokens":90,"output_tokens":30}}}"#,
+        );
+        assert_eq!(meter.total(), 250);
+
+        meter.observe(r#"{"type":"result","usage":{"input_tokens":110,"output_tokens":200}}"#);
+        assert_eq!(meter.total(), 310);
+
+        meter.observe(
+            r#"{"method":"codex/event/token_count","params":{"msg":{"type":"token_count","info":{"total_token_usage":{"total_tokens":1000}}}}}"#,
+        );
+        meter.observe("not json");
+        assert_eq!(meter.total(), 1310);
+    }
+
+    #[test]
+    fn overage_only_when_over_budget() {
+        assert_eq!(overage(900, 1000), None);
+        assert_eq!(overage(1000, 1000), None);
+        assert_eq!(overage(1250, 1000), Some(250));
+    }
+}
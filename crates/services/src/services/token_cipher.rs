@@ -0,0 +1,120 @@
+//! AES-256-GCM encryption for provider credentials kept in the database. The key
+//! is generated on first use and stored in the asset directory, outside the
+//! database, so a copied `db.sqlite` alone does not expose the tokens.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use aes_gcm::{
+    Aes256Gcm, Key, Nonce,
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use thiserror::Error;
+use utils::assets::asset_dir;
+
+const KEY_FILE: &str = "token_encryption.key";
+const NONCE_SIZE: usize = 12;
+
+static SHARED: OnceLock<TokenCipher> = OnceLock::new();
+
+#[derive(Debug, Error)]
+pub enum TokenCipherError {
+    #[error("Failed to read or write the token encryption key: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Token encryption key at {0} is malformed")]
+    InvalidKey(PathBuf),
+    #[error("Encrypted token could not be decrypted")]
+    Decrypt,
+    #[error("Token could not be encrypted")]
+    Encrypt,
+}
+
+pub struct TokenCipher {
+    cipher: Aes256Gcm,
+}
+
+impl TokenCipher {
+    pub fn new(key: [u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(&Key::<Aes256Gcm>::from(key)),
+        }
+    }
+
+    /// The process-wide cipher, keyed from the asset directory
+    pub fn shared() -> Result<&'static Self, TokenCipherError> {
+        if let Some(cipher) = SHARED.get() {
+            return Ok(cipher);
+        }
+        let cipher = Self::load_or_create(&asset_dir().join(KEY_FILE))?;
+        Ok(SHARED.get_or_init(|| cipher))
+    }
+
+    pub fn load_or_create(path: &Path) -> Result<Self, TokenCipherError> {
+        if path.exists() {
+            let key = URL_SAFE_NO_PAD
+                .decode(std::fs::read_to_string(path)?.trim())
+                .ok()
+                .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+                .ok_or_else(|| TokenCipherError::InvalidKey(path.to_path_buf()))?;
+            return Ok(Self::new(key));
+        }
+
+        let key: [u8; 32] = Aes256Gcm::generate_key(&mut OsRng).into();
+        std::fs::write(path, URL_SAFE_NO_PAD.encode(key))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(Self::new(key))
+    }
+
+    /// Encrypt to a URL-safe string holding the nonce followed by the ciphertext
+    pub fn encrypt(&self, plaintext: &str) -> Result<String, TokenCipherError> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_bytes())
+            .map_err(|_| TokenCipherError::Encrypt)?;
+        let mut combined = nonce.to_vec();
+        combined.extend_from_slice(&ciphertext);
+        Ok(URL_SAFE_NO_PAD.encode(combined))
+    }
+
+    pub fn decrypt(&self, encrypted: &str) -> Result<String, TokenCipherError> {
+        let decoded = URL_SAFE_NO_PAD
+            .decode(encrypted)
+            .map_err(|_| TokenCipherError::Decrypt)?;
+        if decoded.len() < NONCE_SIZE {
+            return Err(TokenCipherError::Decrypt);
+        }
+        let (nonce, ciphertext) = decoded.split_at(NONCE_SIZE);
+        let nonce: [u8; NONCE_SIZE] = nonce.try_into().map_err(|_| TokenCipherError::Decrypt)?;
+        let plaintext = self
+            .cipher
+            .decrypt(&Nonce::from(nonce), ciphertext)
+            .map_err(|_| TokenCipherError::Decrypt)?;
+        String::from_utf8(plaintext).map_err(|_| TokenCipherError::Decrypt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_rejects_tampering() {
+        let cipher = TokenCipher::new([7; 32]);
+        let encrypted = cipher.encrypt("vx_access_token").unwrap();
+        assert_ne!(encrypted, cipher.encrypt("vx_access_token").unwrap());
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), "vx_access_token");
+
+        let mut tampered = URL_SAFE_NO_PAD.decode(&encrypted).unwrap();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(cipher.decrypt(&URL_SAFE_NO_PAD.encode(tampered)).is_err());
+        assert!(TokenCipher::new([8; 32]).decrypt(&encrypted).is_err());
+    }
+}
@@ -0,0 +1,261 @@
+//! Per-user activity feed. Attempt and queue events are derived from the task
+//! and execution process patches on the event bus, and recorded for the users
+//! who created or are assigned to the task. Mentions and review requests are
+//! addressed to a single user, so they are recorded where they're made rather
+//! than published on the bus, which every event stream subscriber can read.
+
+use std::collections::{HashMap, HashSet};
+
+use db::{
+    DBService,
+    models::{
+        execution_process::{ExecutionProcess, ExecutionProcessRunReason, ExecutionProcessStatus},
+        feed_event::{CreateFeedEvent, FeedEvent, FeedEventKind},
+        session::Session,
+        task::{ExecutionMode, Task, TaskStatus, TaskWithAttemptStatus},
+        task_owner::TaskOwner,
+        workspace::Workspace,
+    },
+};
+use futures::StreamExt;
+use json_patch::{Patch, PatchOperation};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::services::events::{EventBus, EventTopic};
+
+/// Name the feed commits its event bus offsets under
+const EVENT_CONSUMER: &str = "user_feed";
+
+/// Something that happened to a task, before its owners are looked up
+#[derive(Debug, Clone)]
+pub enum FeedTrigger {
+    AttemptStarted(ExecutionProcess),
+    AttemptFinished(ExecutionProcess),
+    /// A sequential task moved from To Do to In Progress, which the queue does
+    /// when its turn comes
+    QueueStarted(Task),
+}
+
+/// Remembers the last status seen for tasks and coding agent runs, so patches can
+/// be turned into feed events
+#[derive(Debug, Default)]
+pub struct FeedTracker {
+    task_statuses: HashMap<Uuid, TaskStatus>,
+    running_agents: HashSet<Uuid>,
+}
+
+impl FeedTracker {
+    pub fn with_task_statuses(statuses: impl IntoIterator<Item = (Uuid, TaskStatus)>) -> Self {
+        Self {
+            task_statuses: statuses.into_iter().collect(),
+            running_agents: HashSet::new(),
+        }
+    }
+
+    pub fn observe(&mut self, patch: &Patch) -> Vec<FeedTrigger> {
+        let mut triggers = Vec::new();
+        for operation in &patch.0 {
+            let (path, value) = match operation {
+                PatchOperation::Add(op) => (op.path.to_string(), Some(&op.value)),
+                PatchOperation::Replace(op) => (op.path.to_string(), Some(&op.value)),
+                PatchOperation::Remove(op) => (op.path.to_string(), None),
+                _ => continue,
+            };
+
+            if let Some(id) = path.strip_prefix("/tasks/") {
+                let Some(value) = value else {
+                    if let Ok(id) = id.parse() {
+                        self.task_statuses.remove(&id);
+                    }
+                    continue;
+                };
+                let Ok(task) = serde_json::from_value::<TaskWithAttemptStatus>(value.clone())
+                else {
+                    continue;
+                };
+                let task = task.task;
+                let previous = self.task_statuses.insert(task.id, task.status.clone());
+                if previous == Some(TaskStatus::Todo)
+                    && task.status == TaskStatus::InProgress
+                    && task.execution_mode == ExecutionMode::Sequential
+                {
+                    triggers.push(FeedTrigger::QueueStarted(task));
+                }
+            } else if path.starts_with("/execution_processes/")
+                && let Some(value) = value
+                && let Ok(process) = serde_json::from_value::<ExecutionProcess>(value.clone())
+                && process.run_reason == ExecutionProcessRunReason::CodingAgent
+            {
+                if process.status == ExecutionProcessStatus::Running {
+                    if self.running_agents.insert(process.id) {
+                        triggers.push(FeedTrigger::AttemptStarted(process));
+                    }
+                } else if self.running_agents.remove(&process.id) {
+                    triggers.push(FeedTrigger::AttemptFinished(process));
+                }
+            }
+        }
+        triggers
+    }
+}
+
+/// Record a feed event for each of the task's creator and assignee
+async fn record_for_task_owners(
+    pool: &SqlitePool,
+    task: &Task,
+    kind: FeedEventKind,
+    workspace_id: Option<Uuid>,
+) -> Result<(), sqlx::Error> {
+    let Some(owner) = TaskOwner::find_by_task_id(pool, task.id).await? else {
+        return Ok(());
+    };
+    let mut users: Vec<Uuid> = owner.created_by_user_id.into_iter().collect();
+    if let Some(assignee) = owner.assignee_user_id
+        && !users.contains(&assignee)
+    {
+        users.push(assignee);
+    }
+
+    let data = CreateFeedEvent {
+        kind,
+        title: task.title.clone(),
+        project_id: Some(task.project_id),
+        task_id: Some(task.id),
+        workspace_id,
+    };
+    for user_id in users {
+        FeedEvent::create(pool, user_id, &data).await?;
+    }
+    Ok(())
+}
+
+#[derive(Clone)]
+pub struct UserFeedService {
+    db: DBService,
+}
+
+impl UserFeedService {
+    pub fn new(db: DBService) -> Self {
+        Self { db }
+    }
+
+    pub async fn spawn(db: DBService, bus: EventBus) -> tokio::task::JoinHandle<()> {
+        // Take the starting offset before reading current statuses so no transition
+        // falls in between
+        let mut events = bus.consume(EVENT_CONSUMER);
+        let service = Self::new(db);
+        tokio::spawn(async move {
+            let statuses = Task::find_all_statuses(&service.db.pool)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::error!("Failed to load task statuses for the user feed: {}", e);
+                    Vec::new()
+                });
+            let mut tracker = FeedTracker::with_task_statuses(statuses);
+            while let Some(event) = events.next().await {
+                if matches!(
+                    event.topic,
+                    EventTopic::Tasks | EventTopic::ExecutionProcesses
+                ) {
+                    for trigger in tracker.observe(&event.patch) {
+                        if let Err(e) = service.record(trigger).await {
+                            tracing::error!("Failed to record user feed event: {}", e);
+                        }
+                    }
+                }
+                bus.commit(EVENT_CONSUMER, event.offset);
+            }
+        })
+    }
+
+    async fn record(&self, trigger: FeedTrigger) -> Result<(), sqlx::Error> {
+        let pool = &self.db.pool;
+        let process = match trigger {
+            FeedTrigger::QueueStarted(task) => {
+                return record_for_task_owners(pool, &task, FeedEventKind::QueueStarted, None)
+                    .await;
+            }
+            FeedTrigger::AttemptStarted(process) | FeedTrigger::AttemptFinished(process) => process,
+        };
+        let kind = match process.status {
+            ExecutionProcessStatus::Running => FeedEventKind::AttemptStarted,
+            ExecutionProcessStatus::Completed => FeedEventKind::AttemptCompleted,
+            ExecutionProcessStatus::Failed => FeedEventKind::AttemptFailed,
+            // Someone stopped the run on purpose
+            ExecutionProcessStatus::Killed => return Ok(()),
+        };
+
+        let Some(session) = Session::find_by_id(pool, process.session_id).await? else {
+            return Ok(());
+        };
+        let Some(workspace) = Workspace::find_by_id(pool, session.workspace_id).await? else {
+            return Ok(());
+        };
+        let Some(task) = Task::find_by_id(pool, workspace.task_id).await? else {
+            return Ok(());
+        };
+        record_for_task_owners(pool, &task, kind, Some(workspace.id)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::services::events::task_patch;
+
+    fn task(id: Uuid, status: TaskStatus, execution_mode: ExecutionMode) -> TaskWithAttemptStatus {
+        TaskWithAttemptStatus {
+            task: Task {
+                id,
+                project_id: Uuid::new_v4(),
+                title: "Fix login".to_string(),
+                description: None,
+                status,
+                execution_mode,
+                queue_position: None,
+                parent_workspace_id: None,
+                shared_task_id: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            has_in_progress_attempt: false,
+            last_attempt_failed: false,
+            executor: String::new(),
+            latest_workspace_id: None,
+            latest_workspace_container_ref: None,
+        }
+    }
+
+    #[test]
+    fn reports_sequential_tasks_started_from_the_queue() {
+        let (queued, parallel) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut tracker = FeedTracker::with_task_statuses([
+            (queued, TaskStatus::Todo),
+            (parallel, TaskStatus::Todo),
+        ]);
+
+        let started = tracker.observe(&task_patch::replace(&task(
+            queued,
+            TaskStatus::InProgress,
+            ExecutionMode::Sequential,
+        )));
+        assert!(matches!(started.as_slice(), [FeedTrigger::QueueStarted(t)] if t.id == queued));
+
+        let moved = tracker.observe(&task_patch::replace(&task(
+            parallel,
+            TaskStatus::InProgress,
+            ExecutionMode::Parallel,
+        )));
+        assert!(moved.is_empty());
+
+        let unchanged = tracker.observe(&task_patch::replace(&task(
+            queued,
+            TaskStatus::InProgress,
+            ExecutionMode::Sequential,
+        )));
+        assert!(unchanged.is_empty());
+    }
+}
@@ -1,15 +1,26 @@
-use reqwest::Client;
+use std::time::Duration;
+
+use backon::{ExponentialBuilder, Retryable};
+use chrono::{DateTime, Utc};
+use db::models::issue_sync_conflict::IssueSyncSide;
+use reqwest::{RequestBuilder, Response, header::RETRY_AFTER};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::{debug, warn};
 use ts_rs::TS;
 
-const VORTEX_API_BASE: &str = "https://api.vortextask.com";
+use crate::services::http_client::{HttpClient, HttpClientError, SendVia};
+
+pub(crate) const VORTEX_API_BASE: &str = "https://api.vortextask.com";
+const MAX_RETRIES: usize = 3;
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
 
 #[derive(Debug, Error)]
 pub enum VortexIssuesError {
     #[error("HTTP request failed: {0}")]
     Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
     #[error("Vortex API error: {status} - {message}")]
     Api { status: u16, message: String },
     #[error("Authentication required")]
@@ -18,6 +29,68 @@ pub enum VortexIssuesError {
     InvalidConfig(String),
     #[error("Failed to parse API response: {0}")]
     ParseError(String),
+    #[error("Vortex rejected the token; update or reconnect it in project settings: {0}")]
+    Unauthorized(String),
+    #[error("Vortex token does not have access to this resource: {0}")]
+    Forbidden(String),
+    #[error("Not found in Vortex: {0}")]
+    NotFound(String),
+    #[error("Vortex rate limit exceeded: {message}")]
+    RateLimited {
+        /// Seconds to wait, from the `Retry-After` header
+        retry_after: Option<u64>,
+        message: String,
+    },
+}
+
+impl VortexIssuesError {
+    async fn from_response(response: Response) -> Self {
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok());
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        Self::from_status(status.as_u16(), retry_after, message)
+    }
+
+    fn from_status(status: u16, retry_after: Option<u64>, message: String) -> Self {
+        match status {
+            401 => Self::Unauthorized(message),
+            403 => Self::Forbidden(message),
+            404 => Self::NotFound(message),
+            429 => Self::RateLimited {
+                retry_after,
+                message,
+            },
+            _ => Self::Api { status, message },
+        }
+    }
+
+    /// Rate limits, server errors and network failures are worth retrying
+    pub fn should_retry(&self) -> bool {
+        match self {
+            Self::RateLimited { .. } => true,
+            Self::Api { status, .. } => *status >= 500,
+            Self::Request(e) => e.is_timeout() || e.is_connect(),
+            Self::Http(e) => e.is_transient(),
+            _ => false,
+        }
+    }
+
+    /// Failures where Vortex can't have acted on the request
+    fn is_safe_to_replay(&self) -> bool {
+        match self {
+            Self::RateLimited { .. } => true,
+            Self::Request(e) => e.is_connect(),
+            Self::Http(e) => e.is_connect(),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
@@ -137,23 +210,184 @@ struct VortexDataResponse<T> {
     data: T,
 }
 
+/// A project the token can see, for picking `vortex_project_id` in settings
+#[derive(Debug, Clone, Serialize, Deserialize, TS)]
+pub struct VortexProject {
+    pub id: String,
+    #[serde(default)]
+    pub key: Option<String>,
+    pub name: String,
+    #[serde(default, alias = "workspaceId")]
+    pub workspace_id: Option<String>,
+    #[serde(default, alias = "workspaceName")]
+    pub workspace_name: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct VortexAttachmentsResponse {
     #[serde(default)]
     data: Vec<VortexAttachment>,
 }
 
+/// A title and description as two-way sync compares them. For a task the
+/// description is its body without the import header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VortexContent {
+    pub title: String,
+    pub description: Option<String>,
+}
+
+impl VortexContent {
+    pub fn new(title: &str, description: Option<&str>) -> Self {
+        Self {
+            title: title.trim().to_string(),
+            description: description
+                .map(str::trim)
+                .filter(|description| !description.is_empty())
+                .map(str::to_string),
+        }
+    }
+}
+
+impl From<&VortexIssue> for VortexContent {
+    fn from(issue: &VortexIssue) -> Self {
+        Self::new(&issue.title, issue.description.as_deref())
+    }
+}
+
+/// What two-way sync does with a task and its issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentSyncAction {
+    /// Both sides already match
+    InSync,
+    /// Only the issue changed since the last sync; the task takes its content
+    Pull,
+    /// Only the task changed since the last sync; the issue takes its content
+    Push,
+    /// Both changed since the last sync, or they differ and were never synced.
+    /// The side edited last wins.
+    Conflict { winner: IssueSyncSide },
+}
+
+impl ContentSyncAction {
+    /// Side whose content both end up with
+    pub fn source(&self) -> IssueSyncSide {
+        match self {
+            Self::Pull
+            | Self::Conflict {
+                winner: IssueSyncSide::Issue,
+            } => IssueSyncSide::Issue,
+            Self::InSync
+            | Self::Push
+            | Self::Conflict {
+                winner: IssueSyncSide::Task,
+            } => IssueSyncSide::Task,
+        }
+    }
+}
+
+/// Decide which way content flows, given what both sides had after the last
+/// sync. An issue whose edit time can't be read loses conflicts.
+pub fn resolve_content_sync(
+    last_synced: Option<&VortexContent>,
+    task: &VortexContent,
+    task_updated_at: DateTime<Utc>,
+    issue: &VortexContent,
+    issue_updated_at: Option<DateTime<Utc>>,
+) -> ContentSyncAction {
+    if task == issue {
+        return ContentSyncAction::InSync;
+    }
+    let task_changed = last_synced != Some(task);
+    let issue_changed = last_synced != Some(issue);
+    match (task_changed, issue_changed) {
+        (false, _) => ContentSyncAction::Pull,
+        (true, false) => ContentSyncAction::Push,
+        (true, true) => ContentSyncAction::Conflict {
+            winner: if issue_updated_at.is_some_and(|issue_at| issue_at > task_updated_at) {
+                IssueSyncSide::Issue
+            } else {
+                IssueSyncSide::Task
+            },
+        },
+    }
+}
+
+/// Outcome of [`VortexIssuesService::sync_content`]
+#[derive(Debug, Clone)]
+pub struct ContentSync {
+    pub action: ContentSyncAction,
+    /// The issue's content before the sync
+    pub issue: VortexContent,
+    /// Content both sides have after the sync
+    pub synced: VortexContent,
+}
+
 pub struct VortexIssuesService {
-    client: Client,
+    http: HttpClient,
 }
 
 impl VortexIssuesService {
     pub fn new() -> Self {
         Self {
-            client: Client::new(),
+            http: HttpClient::shared(),
         }
     }
 
+    fn get(&self, url: &str, token: &str) -> RequestBuilder {
+        self.http
+            .get(url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("Accept", "application/json")
+    }
+
+    /// Send a request built by `request`, retrying rate limits and transient
+    /// failures with backoff. Requests that aren't `idempotent` are only
+    /// replayed when Vortex can't have acted on them.
+    async fn send(
+        &self,
+        idempotent: bool,
+        request: impl Fn() -> RequestBuilder,
+    ) -> Result<Response, VortexIssuesError> {
+        (|| async {
+            let response = request().send_via(&self.http).await?;
+            if response.status().is_success() {
+                Ok(response)
+            } else {
+                Err(VortexIssuesError::from_response(response).await)
+            }
+        })
+        .retry(
+            ExponentialBuilder::default()
+                .with_min_delay(Duration::from_secs(1))
+                .with_max_delay(MAX_RETRY_DELAY)
+                .with_max_times(MAX_RETRIES)
+                .with_jitter(),
+        )
+        .when(|e: &VortexIssuesError| {
+            if idempotent {
+                e.should_retry()
+            } else {
+                e.is_safe_to_replay()
+            }
+        })
+        .adjust(|e: &VortexIssuesError, delay| match e {
+            VortexIssuesError::RateLimited {
+                retry_after: Some(seconds),
+                ..
+            } => delay.map(|_| Duration::from_secs(*seconds).min(MAX_RETRY_DELAY)),
+            _ => delay,
+        })
+        .notify(|e: &VortexIssuesError, delay: Duration| {
+            warn!(
+                "Vortex API call failed, retrying after {:.2}s: {}",
+                delay.as_secs_f64(),
+                e
+            );
+        })
+        .await
+    }
+
     pub async fn list_issues(
         &self,
         token: &str,
@@ -163,41 +397,24 @@ impl VortexIssuesService {
         let url = format!("{}/api/issues", VORTEX_API_BASE);
         debug!("Vortex list_issues URL: {}", url);
 
-        let mut request = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/json")
-            .query(&[("projectId", project_id)]);
-
-        if let Some(status) = &params.status {
-            request = request.query(&[("status", status)]);
-        }
-        if let Some(priority) = &params.priority {
-            request = request.query(&[("priority", priority)]);
-        }
-        if let Some(limit) = params.limit {
-            request = request.query(&[("limit", limit.to_string())]);
-        }
-        if let Some(page) = params.page {
-            request = request.query(&[("page", page.to_string())]);
-        }
-
-        let response = request.send().await?;
-        let status = response.status();
-        debug!("Vortex API response status: {}", status);
-
-        if !status.is_success() {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            warn!("Vortex API error: {} - {}", status.as_u16(), message);
-            return Err(VortexIssuesError::Api {
-                status: status.as_u16(),
-                message,
-            });
-        }
+        let response = self
+            .send(true, || {
+                let mut request = self.get(&url, token).query(&[("projectId", project_id)]);
+                if let Some(status) = &params.status {
+                    request = request.query(&[("status", status)]);
+                }
+                if let Some(priority) = &params.priority {
+                    request = request.query(&[("priority", priority)]);
+                }
+                if let Some(limit) = params.limit {
+                    request = request.query(&[("limit", limit.to_string())]);
+                }
+                if let Some(page) = params.page {
+                    request = request.query(&[("page", page.to_string())]);
+                }
+                request
+            })
+            .await?;
 
         let body = response.text().await?;
         debug!("Vortex API response body length: {} chars", body.len());
@@ -222,27 +439,7 @@ impl VortexIssuesService {
     ) -> Result<VortexIssue, VortexIssuesError> {
         let url = format!("{}/api/issues/{}", VORTEX_API_BASE, issue_id);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/json")
-            .send()
-            .await?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(VortexIssuesError::Api {
-                status: status.as_u16(),
-                message,
-            });
-        }
-
+        let response = self.send(true, || self.get(&url, token)).await?;
         let body = response.text().await?;
 
         if let Ok(resp) = serde_json::from_str::<VortexDataResponse<VortexIssue>>(&body) {
@@ -260,18 +457,7 @@ impl VortexIssuesService {
     ) -> Result<Vec<VortexAttachment>, VortexIssuesError> {
         let url = format!("{}/api/issues/{}/attachments", VORTEX_API_BASE, issue_id);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/json")
-            .send()
-            .await?;
-
-        if !response.status().is_success() {
-            return Ok(vec![]);
-        }
-
+        let response = self.send(true, || self.get(&url, token)).await?;
         let body = response.text().await?;
 
         if let Ok(resp) = serde_json::from_str::<VortexAttachmentsResponse>(&body) {
@@ -297,19 +483,13 @@ impl VortexIssuesService {
         };
 
         let response = self
-            .client
-            .get(&full_url)
-            .header("Authorization", format!("Bearer {}", token))
-            .send()
+            .send(true, || {
+                self.http
+                    .get(&full_url)
+                    .header("Authorization", format!("Bearer {}", token))
+            })
             .await?;
 
-        if !response.status().is_success() {
-            return Err(VortexIssuesError::Api {
-                status: response.status().as_u16(),
-                message: "Failed to download attachment".to_string(),
-            });
-        }
-
         Ok(response.bytes().await?.to_vec())
     }
 
@@ -325,32 +505,79 @@ impl VortexIssuesService {
             "status": new_status
         });
 
-        let response = self
-            .client
-            .patch(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+        self.send(true, || {
+            self.http
+                .patch(&url)
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Accept", "application/json")
+                .header("Content-Type", "application/json")
+                .json(&body)
+        })
+        .await?;
 
-        let status_code = response.status();
+        Ok(())
+    }
 
-        if !status_code.is_success() {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            return Err(VortexIssuesError::Api {
-                status: status_code.as_u16(),
-                message,
-            });
-        }
+    pub async fn update_issue_content(
+        &self,
+        token: &str,
+        issue_id: &str,
+        content: &VortexContent,
+    ) -> Result<(), VortexIssuesError> {
+        let url = format!("{}/api/issues/{}", VORTEX_API_BASE, issue_id);
+
+        let body = serde_json::json!({
+            "title": content.title,
+            "description": content.description.as_deref().unwrap_or_default(),
+        });
+
+        self.send(true, || {
+            self.http
+                .patch(&url)
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Accept", "application/json")
+                .header("Content-Type", "application/json")
+                .json(&body)
+        })
+        .await?;
 
         Ok(())
     }
 
+    /// Reconcile a task's title and description with its issue, last write
+    /// wins. The issue is updated here when the task's content wins; applying
+    /// the issue's content to the task, and recording conflicts, is up to the
+    /// caller.
+    pub async fn sync_content(
+        &self,
+        token: &str,
+        issue_id: &str,
+        last_synced: Option<&VortexContent>,
+        task: &VortexContent,
+        task_updated_at: DateTime<Utc>,
+    ) -> Result<ContentSync, VortexIssuesError> {
+        let issue = self.get_issue(token, issue_id).await?;
+        let issue_updated_at = DateTime::parse_from_rfc3339(&issue.updated_at)
+            .ok()
+            .map(|updated_at| updated_at.with_timezone(&Utc));
+        let issue = VortexContent::from(&issue);
+
+        let action =
+            resolve_content_sync(last_synced, task, task_updated_at, &issue, issue_updated_at);
+        let synced = match action.source() {
+            IssueSyncSide::Issue => issue.clone(),
+            IssueSyncSide::Task => task.clone(),
+        };
+        if action != ContentSyncAction::InSync && action.source() == IssueSyncSide::Task {
+            self.update_issue_content(token, issue_id, &synced).await?;
+        }
+        Ok(ContentSync {
+            action,
+            issue,
+            synced,
+        })
+    }
+
     pub async fn add_comment(
         &self,
         token: &str,
@@ -367,54 +594,53 @@ impl VortexIssuesService {
             "content": content
         });
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+        // Replaying a comment after a server error could post it twice
+        self.send(false, || {
+            self.http
+                .post(&url)
+                .header("Authorization", format!("Bearer {}", token))
+                .header("Accept", "application/json")
+                .header("Content-Type", "application/json")
+                .json(&body)
+        })
+        .await?;
 
-        let status = response.status();
+        Ok(())
+    }
 
-        if !status.is_success() {
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            warn!(
-                "Failed to add Vortex comment: {} - {}",
-                status.as_u16(),
-                message
-            );
+    /// Projects across all workspaces the token has access to
+    pub async fn list_projects(
+        &self,
+        token: &str,
+    ) -> Result<Vec<VortexProject>, VortexIssuesError> {
+        let url = format!("{}/api/projects", VORTEX_API_BASE);
+
+        let response = self.send(true, || self.get(&url, token)).await?;
+        let body = response.text().await?;
+
+        if let Ok(resp) = serde_json::from_str::<VortexDataResponse<Vec<VortexProject>>>(&body) {
+            return Ok(resp.data);
         }
 
-        Ok(())
+        serde_json::from_str::<Vec<VortexProject>>(&body)
+            .map_err(|e| VortexIssuesError::ParseError(e.to_string()))
     }
 
     pub async fn get_current_user_id(&self, token: &str) -> Result<String, VortexIssuesError> {
         let url = format!("{}/api/users/me", VORTEX_API_BASE);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .header("Accept", "application/json")
-            .send()
-            .await?;
-
-        if response.status().is_success() {
-            let body = response.text().await?;
-            if let Ok(data) = serde_json::from_str::<serde_json::Value>(&body) {
-                if let Some(id) = data["data"]["id"].as_str() {
-                    return Ok(id.to_string());
-                }
-            }
-        }
+        let response = self.send(true, || self.get(&url, token)).await?;
+        let body = response.text().await?;
 
-        Ok("system".to_string())
+        let data = serde_json::from_str::<serde_json::Value>(&body)
+            .map_err(|e| VortexIssuesError::ParseError(e.to_string()))?;
+        data["data"]["id"]
+            .as_str()
+            .or_else(|| data["id"].as_str())
+            .map(str::to_string)
+            .ok_or_else(|| {
+                VortexIssuesError::ParseError("current user response has no id".to_string())
+            })
     }
 
     pub async fn add_comment_as_current_user(
@@ -433,23 +659,71 @@ impl Default for VortexIssuesService {
     }
 }
 
-pub fn extract_vortex_issue_id_from_description(description: &str) -> Option<String> {
-    if !description.starts_with("Imported from Vortex Issue #") {
-        return None;
-    }
-
-    let second_line = description.lines().nth(1)?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_error_statuses() {
+        let error = |status| VortexIssuesError::from_status(status, Some(5), String::new());
+
+        assert!(matches!(error(401), VortexIssuesError::Unauthorized(_)));
+        assert!(matches!(error(403), VortexIssuesError::Forbidden(_)));
+        assert!(matches!(error(404), VortexIssuesError::NotFound(_)));
+        assert!(matches!(
+            error(429),
+            VortexIssuesError::RateLimited {
+                retry_after: Some(5),
+                ..
+            }
+        ));
 
-    if let Some(id_part) = second_line.rsplit('/').next() {
-        let id = id_part.split('?').next()?.trim();
-        if !id.is_empty() {
-            return Some(id.to_string());
-        }
+        assert!(error(429).should_retry() && error(429).is_safe_to_replay());
+        assert!(error(503).should_retry() && !error(503).is_safe_to_replay());
+        assert!(!error(401).should_retry() && !error(422).should_retry());
     }
 
-    None
-}
+    #[test]
+    fn resolves_content_sync_by_last_write() {
+        let content = |title: &str| VortexContent::new(title, Some("Users get logged out"));
+        let base = content("Fix login");
+        let earlier = "2026-03-01T10:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let later = "2026-03-01T11:00:00Z".parse::<DateTime<Utc>>().unwrap();
 
-pub fn is_vortex_imported_task(description: &str) -> bool {
-    description.starts_with("Imported from Vortex Issue #")
+        let resolve = |last: Option<&VortexContent>, task: &str, issue: &str, issue_at| {
+            resolve_content_sync(last, &content(task), earlier, &content(issue), issue_at)
+        };
+        assert_eq!(
+            resolve(Some(&base), "Fix login", "Fix login", None),
+            ContentSyncAction::InSync
+        );
+        assert_eq!(
+            resolve(Some(&base), "Fix login", "Fix SSO login", None),
+            ContentSyncAction::Pull
+        );
+        assert_eq!(
+            resolve(Some(&base), "Fix SSO login", "Fix login", Some(later)),
+            ContentSyncAction::Push
+        );
+        assert_eq!(
+            resolve(Some(&base), "Fix SSO", "Fix SSO login", Some(later)),
+            ContentSyncAction::Conflict {
+                winner: IssueSyncSide::Issue
+            }
+        );
+        // Never synced, and the issue's edit time is unknown
+        assert_eq!(
+            resolve(None, "Fix SSO", "Fix SSO login", None),
+            ContentSyncAction::Conflict {
+                winner: IssueSyncSide::Task
+            }
+        );
+        assert_eq!(
+            VortexContent::new(" Fix login ", Some("  ")),
+            VortexContent {
+                title: "Fix login".to_string(),
+                description: None,
+            }
+        );
+    }
 }
@@ -0,0 +1,260 @@
+//! OAuth authorization-code flow (with PKCE) for connecting a project to Vortex,
+//! so users don't have to paste long-lived API tokens into project settings.
+//! Tokens are stored encrypted and refreshed shortly before they expire.
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, Utc};
+use db::models::{project::Project, vortex_oauth_token::VortexOAuthToken};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::services::{
+    config::Config,
+    http_client::{HttpClient, HttpClientError, SendVia},
+    token_cipher::{TokenCipher, TokenCipherError},
+    vortex_issues::VORTEX_API_BASE,
+};
+
+/// How long a user has to finish authorizing in the browser
+const STATE_TTL_MINUTES: i64 = 10;
+/// Access tokens this close to expiry are refreshed before use
+const REFRESH_LEEWAY_SECONDS: i64 = 60;
+
+#[derive(Debug, Error)]
+pub enum VortexOAuthError {
+    #[error("Vortex OAuth is not configured; set the client ID and secret in settings")]
+    NotConfigured,
+    #[error("Authorization request is invalid or has expired; start again")]
+    InvalidState,
+    #[error("HTTP request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error(transparent)]
+    Http(#[from] HttpClientError),
+    #[error("Vortex token endpoint returned {status}: {message}")]
+    TokenEndpoint { status: u16, message: String },
+    #[error("Vortex connection has expired; reconnect the project")]
+    Expired,
+    #[error(transparent)]
+    Cipher(#[from] TokenCipherError),
+    #[error(transparent)]
+    Database(#[from] sqlx::Error),
+}
+
+/// Carried through the browser in the `state` parameter, encrypted so it can't be
+/// forged or read
+#[derive(Debug, Serialize, Deserialize)]
+struct AuthorizationState {
+    project_id: Uuid,
+    redirect_uri: String,
+    code_verifier: String,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    /// Lifetime of the access token in seconds
+    expires_in: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+struct OAuthApp {
+    client_id: String,
+    client_secret: String,
+}
+
+pub struct VortexOAuthService {
+    http: HttpClient,
+    app: Option<OAuthApp>,
+}
+
+impl VortexOAuthService {
+    pub fn from_config(config: &Config) -> Self {
+        let app = match (
+            &config.vortex_oauth_client_id,
+            &config.vortex_oauth_client_secret,
+        ) {
+            (Some(client_id), Some(client_secret))
+                if !client_id.is_empty() && !client_secret.is_empty() =>
+            {
+                Some(OAuthApp {
+                    client_id: client_id.clone(),
+                    client_secret: client_secret.clone(),
+                })
+            }
+            _ => None,
+        };
+        Self {
+            http: HttpClient::shared(),
+            app,
+        }
+    }
+
+    pub fn is_configured(&self) -> bool {
+        self.app.is_some()
+    }
+
+    fn app(&self) -> Result<&OAuthApp, VortexOAuthError> {
+        self.app.as_ref().ok_or(VortexOAuthError::NotConfigured)
+    }
+
+    /// URL to send the user to. Vortex redirects back to `redirect_uri` with a code.
+    pub fn authorize_url(
+        &self,
+        project_id: Uuid,
+        redirect_uri: &str,
+    ) -> Result<String, VortexOAuthError> {
+        let app = self.app()?;
+        let code_verifier = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
+        let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()));
+        let state = AuthorizationState {
+            project_id,
+            redirect_uri: redirect_uri.to_string(),
+            code_verifier,
+            expires_at: Utc::now() + Duration::minutes(STATE_TTL_MINUTES),
+        };
+        let state = TokenCipher::shared()?
+            .encrypt(&serde_json::to_string(&state).map_err(|_| VortexOAuthError::InvalidState)?)?;
+
+        let url = Url::parse_with_params(
+            &format!("{VORTEX_API_BASE}/oauth/authorize"),
+            &[
+                ("response_type", "code"),
+                ("client_id", app.client_id.as_str()),
+                ("redirect_uri", redirect_uri),
+                ("state", state.as_str()),
+                ("code_challenge", code_challenge.as_str()),
+                ("code_challenge_method", "S256"),
+            ],
+        )
+        .map_err(|_| VortexOAuthError::InvalidState)?;
+        Ok(url.to_string())
+    }
+
+    /// Exchange the code from the redirect for tokens and store them on the
+    /// project the flow was started for. Returns that project's ID.
+    pub async fn complete_authorization(
+        &self,
+        pool: &SqlitePool,
+        code: &str,
+        state: &str,
+    ) -> Result<Uuid, VortexOAuthError> {
+        let app = self.app()?;
+        let state: AuthorizationState = TokenCipher::shared()?
+            .decrypt(state)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .ok_or(VortexOAuthError::InvalidState)?;
+        if state.expires_at < Utc::now() {
+            return Err(VortexOAuthError::InvalidState);
+        }
+
+        let tokens = self
+            .request_tokens(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", &state.redirect_uri),
+                ("client_id", &app.client_id),
+                ("client_secret", &app.client_secret),
+                ("code_verifier", &state.code_verifier),
+            ])
+            .await?;
+        Self::store(pool, state.project_id, &tokens).await?;
+        Ok(state.project_id)
+    }
+
+    async fn request_tokens(
+        &self,
+        form: &[(&str, &str)],
+    ) -> Result<TokenResponse, VortexOAuthError> {
+        let response = self
+            .http
+            .post(format!("{VORTEX_API_BASE}/oauth/token"))
+            .header("Accept", "application/json")
+            .header("User-Agent", "vibe-kanban")
+            .form(form)
+            .send_via(&self.http)
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Unknown error".to_string());
+            return Err(VortexOAuthError::TokenEndpoint {
+                status: status.as_u16(),
+                message,
+            });
+        }
+        Ok(response.json().await?)
+    }
+
+    async fn store(
+        pool: &SqlitePool,
+        project_id: Uuid,
+        tokens: &TokenResponse,
+    ) -> Result<VortexOAuthToken, VortexOAuthError> {
+        let cipher = TokenCipher::shared()?;
+        let access_token = cipher.encrypt(&tokens.access_token)?;
+        let refresh_token = tokens
+            .refresh_token
+            .as_deref()
+            .map(|token| cipher.encrypt(token))
+            .transpose()?;
+        let expires_at = tokens
+            .expires_in
+            .map(|seconds| Utc::now() + Duration::seconds(seconds));
+        Ok(VortexOAuthToken::upsert(
+            pool,
+            project_id,
+            &access_token,
+            refresh_token.as_deref(),
+            expires_at,
+        )
+        .await?)
+    }
+
+    /// Token to call the Vortex API with for a project: the OAuth access token
+    /// when the project is connected, refreshed if it is about to expire,
+    /// otherwise the API token pasted into project settings
+    pub async fn access_token(
+        &self,
+        pool: &SqlitePool,
+        project: &Project,
+    ) -> Result<Option<String>, VortexOAuthError> {
+        let Some(stored) = VortexOAuthToken::find_by_project_id(pool, project.id).await? else {
+            return Ok(project.vortex_token.clone());
+        };
+        let cipher = TokenCipher::shared()?;
+
+        let expiring = stored.expires_at.is_some_and(|expires_at| {
+            expires_at <= Utc::now() + Duration::seconds(REFRESH_LEEWAY_SECONDS)
+        });
+        if !expiring {
+            return Ok(Some(cipher.decrypt(&stored.access_token)?));
+        }
+
+        let refresh_token = stored
+            .refresh_token
+            .as_deref()
+            .map(|token| cipher.decrypt(token))
+            .transpose()?
+            .ok_or(VortexOAuthError::Expired)?;
+        let app = self.app()?;
+        let tokens = self
+            .request_tokens(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", &refresh_token),
+                ("client_id", &app.client_id),
+                ("client_secret", &app.client_secret),
+            ])
+            .await?;
+        Self::store(pool, project.id, &tokens).await?;
+        Ok(Some(tokens.access_token))
+    }
+}
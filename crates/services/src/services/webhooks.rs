@@ -0,0 +1,486 @@
+//! Provider-neutral handling of inbound issue-tracker webhooks. Each provider parses
+//! its payloads into an [`IssueWebhookEvent`] so the server applies them to tasks the
+//! same way regardless of where they came from.
+
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha2::Sha256;
+
+use crate::services::{gitlab_issues::GitLabIssuesService, vortex_issues::VortexIssue};
+
+/// Issue fields carried by open and update events
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookIssue {
+    pub number: i64,
+    pub title: String,
+    pub description: Option<String>,
+    pub url: String,
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueWebhookEvent {
+    Opened(WebhookIssue),
+    Updated(WebhookIssue),
+    Closed {
+        number: i64,
+    },
+    Reopened {
+        number: i64,
+    },
+    Commented {
+        number: i64,
+        author: String,
+        body: String,
+        url: String,
+    },
+}
+
+/// A delivery that maps onto an issue event, with the repository it came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedWebhook {
+    /// `owner/repo` on GitHub and Gitea, the full `group/subgroup/project` path on GitLab
+    pub repository: String,
+    pub event: IssueWebhookEvent,
+}
+
+/// Compare a presented secret against the stored one without leaking where they differ
+pub fn secrets_match(expected: &str, provided: &str) -> bool {
+    let (expected, provided) = (expected.as_bytes(), provided.as_bytes());
+    expected.len() == provided.len()
+        && expected
+            .iter()
+            .zip(provided)
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Check a Gitea/Forgejo `X-Gitea-Signature` header: the hex HMAC-SHA256 of the body
+pub fn signature_matches(secret: &str, signature_hex: &str, payload: &[u8]) -> bool {
+    let Ok(signature) = hex::decode(signature_hex.trim()) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(payload);
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Check a GitHub `X-Hub-Signature-256` header, `sha256=` followed by the hex
+/// HMAC-SHA256 of the body
+pub fn github_signature_matches(secret: &str, header: &str, payload: &[u8]) -> bool {
+    header
+        .trim()
+        .strip_prefix("sha256=")
+        .is_some_and(|signature_hex| signature_matches(secret, signature_hex, payload))
+}
+
+/// Check a Vortex `X-Vortex-Signature` header: the hex HMAC-SHA256 of the body,
+/// optionally prefixed with `sha256=`
+pub fn vortex_signature_matches(secret: &str, header: &str, payload: &[u8]) -> bool {
+    let header = header.trim();
+    signature_matches(
+        secret,
+        header.strip_prefix("sha256=").unwrap_or(header),
+        payload,
+    )
+}
+
+/// Whether an issue passes a project's comma-separated sync label filter
+pub fn labels_match(filter: Option<&str>, labels: &[String]) -> bool {
+    let wanted: Vec<&str> = filter
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .collect();
+    wanted.is_empty()
+        || wanted
+            .iter()
+            .any(|w| labels.iter().any(|l| l.eq_ignore_ascii_case(w)))
+}
+
+/// Whether a project's configured GitLab URL points at the project a hook came from
+pub fn gitlab_project_matches(project_url: &str, path_with_namespace: &str) -> bool {
+    GitLabIssuesService::parse_project_url(project_url)
+        .ok()
+        .and_then(|path| urlencoding::decode(&path).ok().map(|p| p.into_owned()))
+        .is_some_and(|path| path.eq_ignore_ascii_case(path_with_namespace))
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabHook {
+    object_kind: String,
+    #[serde(default)]
+    user: Option<GitLabHookUser>,
+    project: GitLabHookProject,
+    object_attributes: serde_json::Value,
+    #[serde(default)]
+    labels: Vec<GitLabHookLabel>,
+    #[serde(default)]
+    issue: Option<GitLabHookIssue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabHookUser {
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabHookProject {
+    path_with_namespace: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabHookLabel {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabHookIssue {
+    iid: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabIssueAttributes {
+    iid: i64,
+    title: String,
+    description: Option<String>,
+    url: String,
+    action: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabNoteAttributes {
+    note: String,
+    noteable_type: String,
+    url: String,
+}
+
+/// Parse a GitLab `Issue Hook` or `Note Hook` body. Other event kinds, and notes on
+/// anything but issues, yield `Ok(None)`.
+pub fn parse_gitlab_webhook(payload: &[u8]) -> Result<Option<ParsedWebhook>, serde_json::Error> {
+    let hook: GitLabHook = serde_json::from_slice(payload)?;
+    let event = match hook.object_kind.as_str() {
+        "issue" => {
+            let attrs: GitLabIssueAttributes = serde_json::from_value(hook.object_attributes)?;
+            let issue = WebhookIssue {
+                number: attrs.iid,
+                title: attrs.title,
+                description: attrs.description,
+                url: attrs.url,
+                labels: hook.labels.into_iter().map(|l| l.title).collect(),
+            };
+            match attrs.action.as_deref() {
+                Some("open") => IssueWebhookEvent::Opened(issue),
+                Some("update") => IssueWebhookEvent::Updated(issue),
+                Some("close") => IssueWebhookEvent::Closed {
+                    number: issue.number,
+                },
+                Some("reopen") => IssueWebhookEvent::Reopened {
+                    number: issue.number,
+                },
+                _ => return Ok(None),
+            }
+        }
+        "note" => {
+            let attrs: GitLabNoteAttributes = serde_json::from_value(hook.object_attributes)?;
+            let Some(issue) = hook.issue.filter(|_| attrs.noteable_type == "Issue") else {
+                return Ok(None);
+            };
+            IssueWebhookEvent::Commented {
+                number: issue.iid,
+                author: hook.user.map(|u| u.username).unwrap_or_default(),
+                body: attrs.note,
+                url: attrs.url,
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(ParsedWebhook {
+        repository: hook.project.path_with_namespace,
+        event,
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHook {
+    action: String,
+    repository: GiteaHookRepository,
+    issue: GiteaHookIssue,
+    #[serde(default)]
+    comment: Option<GiteaHookComment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHookRepository {
+    full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHookIssue {
+    number: i64,
+    title: String,
+    body: Option<String>,
+    html_url: String,
+    #[serde(default)]
+    labels: Vec<GiteaHookLabel>,
+    #[serde(default)]
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHookLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHookComment {
+    body: String,
+    html_url: String,
+    user: GiteaHookUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaHookUser {
+    login: String,
+}
+
+/// Parse a Gitea/Forgejo delivery given its `X-Gitea-Event` header. Only `issues`
+/// and `issue_comment` events on issues (not pull requests) yield an event.
+pub fn parse_gitea_webhook(
+    event: &str,
+    payload: &[u8],
+) -> Result<Option<ParsedWebhook>, serde_json::Error> {
+    if event != "issues" && event != "issue_comment" {
+        return Ok(None);
+    }
+    let hook: GiteaHook = serde_json::from_slice(payload)?;
+    if hook.issue.pull_request.is_some() {
+        return Ok(None);
+    }
+    let number = hook.issue.number;
+    let event = match (event, hook.action.as_str(), hook.comment) {
+        ("issue_comment", "created", Some(comment)) => IssueWebhookEvent::Commented {
+            number,
+            author: comment.user.login,
+            body: comment.body,
+            url: comment.html_url,
+        },
+        ("issues", action, _) => {
+            let issue = WebhookIssue {
+                number,
+                title: hook.issue.title,
+                description: hook.issue.body,
+                url: hook.issue.html_url,
+                labels: hook.issue.labels.into_iter().map(|l| l.name).collect(),
+            };
+            match action {
+                "opened" => IssueWebhookEvent::Opened(issue),
+                "edited" => IssueWebhookEvent::Updated(issue),
+                "closed" => IssueWebhookEvent::Closed { number },
+                "reopened" => IssueWebhookEvent::Reopened { number },
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(ParsedWebhook {
+        repository: hook.repository.full_name,
+        event,
+    }))
+}
+
+/// Parse a GitHub delivery given its `X-GitHub-Event` header. Gitea modelled its
+/// `issues` and `issue_comment` payloads on GitHub's, so they parse the same way.
+pub fn parse_github_webhook(
+    event: &str,
+    payload: &[u8],
+) -> Result<Option<ParsedWebhook>, serde_json::Error> {
+    parse_gitea_webhook(event, payload)
+}
+
+/// A Vortex issue that was created or changed. Vortex sends the whole issue
+/// with every event, so closing and reopening arrive as updates.
+#[derive(Debug, Clone)]
+pub struct VortexWebhook {
+    pub created: bool,
+    pub issue: VortexIssue,
+}
+
+#[derive(Debug, Deserialize)]
+struct VortexHook {
+    event: String,
+    data: serde_json::Value,
+}
+
+/// Parse a Vortex delivery. Events other than `issue.created` and
+/// `issue.updated` are ignored.
+pub fn parse_vortex_webhook(payload: &[u8]) -> Result<Option<VortexWebhook>, serde_json::Error> {
+    let hook: VortexHook = serde_json::from_slice(payload)?;
+    let created = match hook.event.as_str() {
+        "issue.created" => true,
+        "issue.updated" => false,
+        _ => return Ok(None),
+    };
+    Ok(Some(VortexWebhook {
+        created,
+        issue: serde_json::from_value(hook.data)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_gitlab_issue_and_note_hooks() {
+        let issue = br#"{
+            "object_kind": "issue",
+            "user": {"username": "ana"},
+            "project": {"path_with_namespace": "acme/platform/api"},
+            "object_attributes": {"iid": 12, "title": "Broken login", "description": "Steps...",
+                                  "url": "https://gitlab.com/acme/platform/api/-/issues/12", "action": "close"},
+            "labels": [{"title": "bug"}]
+        }"#;
+        let parsed = parse_gitlab_webhook(issue).unwrap().unwrap();
+        assert_eq!(parsed.repository, "acme/platform/api");
+        assert_eq!(parsed.event, IssueWebhookEvent::Closed { number: 12 });
+
+        let note = br#"{
+            "object_kind": "note",
+            "user": {"username": "ana"},
+            "project": {"path_with_namespace": "acme/api"},
+            "object_attributes": {"note": "Still happening", "noteable_type": "Issue",
+                                  "url": "https://gitlab.com/acme/api/-/issues/3#note_1"},
+            "issue": {"iid": 3}
+        }"#;
+        assert_eq!(
+            parse_gitlab_webhook(note).unwrap().unwrap().event,
+            IssueWebhookEvent::Commented {
+                number: 3,
+                author: "ana".to_string(),
+                body: "Still happening".to_string(),
+                url: "https://gitlab.com/acme/api/-/issues/3#note_1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_unrelated_gitlab_events() {
+        let push = br#"{"object_kind": "push", "project": {"path_with_namespace": "a/b"}, "object_attributes": null}"#;
+        assert!(parse_gitlab_webhook(push).unwrap().is_none());
+    }
+
+    #[test]
+    fn parses_gitea_issue_events_and_skips_pull_requests() {
+        let opened = br#"{
+            "action": "opened",
+            "repository": {"full_name": "acme/api"},
+            "issue": {"number": 5, "title": "Crash on start", "body": "Trace...",
+                      "html_url": "https://git.example.com/acme/api/issues/5",
+                      "labels": [{"name": "bug"}], "pull_request": null}
+        }"#;
+        let parsed = parse_gitea_webhook("issues", opened).unwrap().unwrap();
+        assert_eq!(parsed.repository, "acme/api");
+        assert!(
+            matches!(parsed.event, IssueWebhookEvent::Opened(ref issue) if issue.labels == ["bug"])
+        );
+
+        let pr_comment = br#"{
+            "action": "created",
+            "repository": {"full_name": "acme/api"},
+            "issue": {"number": 6, "title": "Add cache", "body": null, "html_url": "u",
+                      "pull_request": {"merged": false}},
+            "comment": {"body": "LGTM", "html_url": "u", "user": {"login": "ana"}}
+        }"#;
+        assert!(
+            parse_gitea_webhook("issue_comment", pr_comment)
+                .unwrap()
+                .is_none()
+        );
+        assert!(parse_gitea_webhook("push", b"{}").unwrap().is_none());
+    }
+
+    #[test]
+    fn verifies_gitea_signatures() {
+        let payload = br#"{"action":"opened"}"#;
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+        mac.update(payload);
+        let signature = hex::encode(mac.finalize().into_bytes());
+        assert!(signature_matches("s3cret", &signature, payload));
+        assert!(!signature_matches("other", &signature, payload));
+        assert!(!signature_matches("s3cret", "not-hex", payload));
+    }
+
+    #[test]
+    fn parses_github_deliveries_with_prefixed_signatures() {
+        let payload = br#"{
+            "action": "closed",
+            "repository": {"full_name": "acme/web"},
+            "issue": {"number": 42, "title": "Fix header", "body": null,
+                      "html_url": "https://github.com/acme/web/issues/42", "labels": []}
+        }"#;
+        let parsed = parse_github_webhook("issues", payload).unwrap().unwrap();
+        assert_eq!(parsed.repository, "acme/web");
+        assert_eq!(parsed.event, IssueWebhookEvent::Closed { number: 42 });
+        assert!(parse_github_webhook("ping", b"{}").unwrap().is_none());
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+        mac.update(payload);
+        let signature = hex::encode(mac.finalize().into_bytes());
+        assert!(github_signature_matches(
+            "s3cret",
+            &format!("sha256={signature}"),
+            payload
+        ));
+        assert!(!github_signature_matches("s3cret", &signature, payload));
+    }
+
+    #[test]
+    fn parses_vortex_issue_events() {
+        let payload = br#"{
+            "event": "issue.created",
+            "data": {"id": "9f2c", "project_id": "p1", "key": "WEB-7", "title": "Fix login",
+                     "description": "Users get logged out", "status": "Open",
+                     "labels": ["bug"], "updated_at": "2026-03-01T10:00:00Z"}
+        }"#;
+        let parsed = parse_vortex_webhook(payload).unwrap().unwrap();
+        assert!(parsed.created);
+        assert_eq!(parsed.issue.key, "WEB-7");
+        assert_eq!(parsed.issue.project_id.as_deref(), Some("p1"));
+        assert_eq!(parsed.issue.labels, ["bug"]);
+        assert!(
+            parse_vortex_webhook(br#"{"event": "comment.created", "data": {}}"#)
+                .unwrap()
+                .is_none()
+        );
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"s3cret").unwrap();
+        mac.update(payload);
+        let signature = hex::encode(mac.finalize().into_bytes());
+        assert!(vortex_signature_matches("s3cret", &signature, payload));
+        assert!(vortex_signature_matches(
+            "s3cret",
+            &format!("sha256={signature}"),
+            payload
+        ));
+        assert!(!vortex_signature_matches("other", &signature, payload));
+    }
+
+    #[test]
+    fn label_filter_and_secret_checks() {
+        let labels = vec!["Bug".to_string()];
+        assert!(labels_match(None, &labels));
+        assert!(labels_match(Some("feature, bug"), &labels));
+        assert!(!labels_match(Some("feature"), &labels));
+        assert!(secrets_match("s3cret", "s3cret"));
+        assert!(!secrets_match("s3cret", "s3creT"));
+        assert!(!secrets_match("s3cret", "s3cre"));
+        assert!(gitlab_project_matches(
+            "https://gitlab.com/acme/platform/api.git",
+            "acme/platform/api"
+        ));
+    }
+}
@@ -1,9 +1,13 @@
 use std::path::{Path, PathBuf};
 
-use db::models::{repo::Repo, workspace::Workspace as DbWorkspace};
+use db::models::{
+    repo::Repo,
+    workspace::{Workspace as DbWorkspace, WorkspaceDirectory},
+};
 use sqlx::{Pool, Sqlite};
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
+use utils::path::{MAX_DIR_NAME_LEN, normalize_container_ref, sanitize_path_component};
 use uuid::Uuid;
 
 use super::worktree_manager::{WorktreeCleanup, WorktreeError, WorktreeManager};
@@ -63,9 +67,12 @@ impl WorkspaceManager {
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_else(|| "repo".to_string());
 
-        // Sanitize branch name for filesystem (replace / with -)
-        let sanitized_branch = branch_name.replace('/', "-");
-        parent.join(format!("{}-{}", repo_name, sanitized_branch))
+        // Branch names contain `/` and can be long; keep the directory name
+        // valid and short enough for Windows
+        parent.join(sanitize_path_component(
+            &format!("{}-{}", repo_name, branch_name),
+            MAX_DIR_NAME_LEN,
+        ))
     }
 
     /// Create a workspace with worktrees for all repositories.
@@ -389,36 +396,58 @@ impl WorkspaceManager {
         }
     }
 
-    pub async fn cleanup_orphan_workspaces(db: &Pool<Sqlite>) {
-        if std::env::var("DISABLE_WORKTREE_ORPHAN_CLEANUP").is_ok() {
-            debug!(
-                "Orphan workspace cleanup is disabled via DISABLE_WORKTREE_ORPHAN_CLEANUP environment variable"
-            );
-            return;
+    /// Rewrite stored container_refs into their normalized form so that
+    /// orphan detection, which compares path strings, doesn't mistake a
+    /// workspace reached through a symlink or relative path for an orphan.
+    pub async fn normalize_container_refs(db: &Pool<Sqlite>) {
+        let directories = match DbWorkspace::find_all_directories(db).await {
+            Ok(directories) => directories,
+            Err(e) => {
+                error!("Failed to load workspace directories: {}", e);
+                return;
+            }
+        };
+
+        let mut updated = 0;
+        for directory in directories {
+            let normalized = normalize_container_ref(&directory.container_ref);
+            if normalized == directory.container_ref {
+                continue;
+            }
+            match DbWorkspace::update_container_ref(db, directory.workspace_id, &normalized).await {
+                Ok(()) => updated += 1,
+                Err(e) => warn!(
+                    "Failed to normalize container_ref of workspace {}: {}",
+                    directory.workspace_id, e
+                ),
+            }
+        }
+        if updated > 0 {
+            info!("Normalized {} workspace container_refs", updated);
         }
+    }
+
+    /// Workspaces whose recorded directory no longer exists on disk
+    pub async fn find_missing_workspaces(
+        db: &Pool<Sqlite>,
+    ) -> Result<Vec<WorkspaceDirectory>, sqlx::Error> {
+        Ok(DbWorkspace::find_all_directories(db)
+            .await?
+            .into_iter()
+            .filter(|directory| !Path::new(&directory.container_ref).exists())
+            .collect())
+    }
 
+    /// Directories under the workspace base dir that no workspace record
+    /// points at
+    pub async fn find_orphan_directories(db: &Pool<Sqlite>) -> Result<Vec<String>, WorkspaceError> {
         let workspace_base_dir = Self::get_workspace_base_dir();
         if !workspace_base_dir.exists() {
-            debug!(
-                "Workspace base directory {} does not exist, skipping orphan cleanup",
-                workspace_base_dir.display()
-            );
-            return;
+            return Ok(Vec::new());
         }
 
-        let entries = match std::fs::read_dir(&workspace_base_dir) {
-            Ok(entries) => entries,
-            Err(e) => {
-                error!(
-                    "Failed to read workspace base directory {}: {}",
-                    workspace_base_dir.display(),
-                    e
-                );
-                return;
-            }
-        };
-
-        for entry in entries {
+        let mut orphans = Vec::new();
+        for entry in std::fs::read_dir(&workspace_base_dir)? {
             let entry = match entry {
                 Ok(entry) => entry,
                 Err(e) => {
@@ -432,22 +461,38 @@ impl WorkspaceManager {
                 continue;
             }
 
-            let workspace_path_str = path.to_string_lossy().to_string();
+            let workspace_path_str = normalize_container_ref(&path);
             if let Ok(false) = DbWorkspace::container_ref_exists(db, &workspace_path_str).await {
-                info!("Found orphaned workspace: {}", workspace_path_str);
-                if let Err(e) = Self::cleanup_workspace_without_repos(&path).await {
-                    error!(
-                        "Failed to remove orphaned workspace {}: {}",
-                        workspace_path_str, e
-                    );
-                } else {
-                    info!(
-                        "Successfully removed orphaned workspace: {}",
-                        workspace_path_str
-                    );
+                orphans.push(workspace_path_str);
+            }
+        }
+        orphans.sort();
+        Ok(orphans)
+    }
+
+    /// Remove the given orphaned directories. Paths that are no longer
+    /// orphaned, or that are not directly under the workspace base dir, are
+    /// left alone. Returns the paths that were removed.
+    pub async fn remove_orphan_directories(
+        db: &Pool<Sqlite>,
+        paths: &[String],
+    ) -> Result<Vec<String>, WorkspaceError> {
+        let orphans = Self::find_orphan_directories(db).await?;
+        let mut removed = Vec::new();
+        for path in paths {
+            let normalized = normalize_container_ref(path);
+            if !orphans.contains(&normalized) {
+                continue;
+            }
+            match Self::cleanup_workspace_without_repos(Path::new(&normalized)).await {
+                Ok(()) => {
+                    info!("Removed orphaned workspace: {}", normalized);
+                    removed.push(normalized);
                 }
+                Err(e) => error!("Failed to remove orphaned workspace {}: {}", normalized, e),
             }
         }
+        Ok(removed)
     }
 
     async fn cleanup_workspace_without_repos(workspace_dir: &Path) -> Result<(), WorkspaceError> {
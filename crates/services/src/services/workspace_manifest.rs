@@ -0,0 +1,124 @@
+//! Reproducibility manifest recorded when an attempt starts: the commit each repo
+//! started from, the agent profile and its resolved configuration, which
+//! environment variables the agent saw and the host OS.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use chrono::Utc;
+use db::models::{
+    workspace::Workspace,
+    workspace_manifest::{ManifestOs, ManifestRepo, WorkspaceManifest},
+    workspace_repo::WorkspaceRepo,
+};
+use executors::profile::{ExecutorConfigs, ExecutorProfileId};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::services::git::GitService;
+
+/// Set on every agent process by the container service
+const INJECTED_ENV_VARS: [&str; 5] = [
+    "VK_PROJECT_NAME",
+    "VK_PROJECT_ID",
+    "VK_TASK_ID",
+    "VK_WORKSPACE_ID",
+    "VK_WORKSPACE_BRANCH",
+];
+
+/// Sorted, de-duplicated names of the variables an agent with this profile env
+/// runs with
+pub fn env_var_names(profile_env: Option<&HashMap<String, String>>) -> Vec<String> {
+    let mut names: Vec<String> = INJECTED_ENV_VARS
+        .iter()
+        .map(|name| name.to_string())
+        .chain(profile_env.into_iter().flat_map(|env| env.keys().cloned()))
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+fn os_info() -> ManifestOs {
+    let info = os_info::get();
+    ManifestOs {
+        os_type: info.os_type().to_string(),
+        version: info.version().to_string(),
+        architecture: info.architecture().map(str::to_string),
+    }
+}
+
+/// Capture and store the manifest for a workspace whose worktrees have just been
+/// created
+pub async fn record(
+    pool: &SqlitePool,
+    git: &GitService,
+    workspace: &Workspace,
+    project_id: Uuid,
+    executor_profile_id: &ExecutorProfileId,
+) -> Result<WorkspaceManifest, sqlx::Error> {
+    let workspace_root = workspace.container_ref.as_ref().map(PathBuf::from);
+    let repos = WorkspaceRepo::find_repos_with_target_branch_for_workspace(pool, workspace.id)
+        .await?
+        .into_iter()
+        .map(|repo| ManifestRepo {
+            base_commit: workspace_root
+                .as_ref()
+                .and_then(|root| git.get_head_info(&root.join(&repo.repo.name)).ok())
+                .map(|head| head.oid),
+            repo_id: repo.repo.id,
+            name: repo.repo.name,
+            target_branch: repo.target_branch,
+        })
+        .collect();
+
+    let agent = ExecutorConfigs::get_cached().get_coding_agent(executor_profile_id);
+    let manifest = WorkspaceManifest {
+        workspace_id: workspace.id,
+        task_id: workspace.task_id,
+        project_id,
+        branch: workspace.branch.clone(),
+        repos,
+        executor_profile_id: executor_profile_id.clone(),
+        executor_config: agent
+            .as_ref()
+            .and_then(|agent| serde_json::to_value(agent).ok()),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        env_var_names: env_var_names(
+            agent
+                .as_ref()
+                .and_then(|agent| agent.cmd_overrides().env.as_ref()),
+        ),
+        os: os_info(),
+        created_at: Utc::now(),
+    };
+    manifest.save(pool).await?;
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_var_names_merge_profile_env_without_values() {
+        let profile_env = HashMap::from([
+            (
+                "ANTHROPIC_BASE_URL".to_string(),
+                "https://proxy".to_string(),
+            ),
+            ("VK_TASK_ID".to_string(), "override".to_string()),
+        ]);
+        assert_eq!(
+            env_var_names(Some(&profile_env)),
+            vec![
+                "ANTHROPIC_BASE_URL",
+                "VK_PROJECT_ID",
+                "VK_PROJECT_NAME",
+                "VK_TASK_ID",
+                "VK_WORKSPACE_BRANCH",
+                "VK_WORKSPACE_ID",
+            ]
+        );
+        assert_eq!(env_var_names(None).len(), INJECTED_ENV_VARS.len());
+    }
+}
@@ -14,6 +14,9 @@ pub struct ApprovalRequest {
     pub execution_process_id: Uuid,
     pub created_at: DateTime<Utc>,
     pub timeout_at: DateTime<Utc>,
+    /// Name of the approval policy rule that paused this call, if any
+    #[serde(default)]
+    pub policy_rule: Option<String>,
 }
 
 impl ApprovalRequest {
@@ -27,6 +30,7 @@ impl ApprovalRequest {
             execution_process_id,
             created_at: now,
             timeout_at: now + Duration::seconds(APPROVAL_TIMEOUT_SECONDS),
+            policy_rule: None,
         }
     }
 }
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use git2::{DiffOptions, Patch};
 use serde::{Deserialize, Serialize};
-use similar::TextDiff;
+use similar::{ChangeTag, TextDiff};
 use ts_rs::TS;
 
 // Structs compatable with props: https://github.com/MrWangJustToDo/git-diff-view
@@ -89,6 +89,15 @@ pub fn compute_line_change_counts(old: &str, new: &str) -> (usize, usize) {
     }
 }
 
+/// 0-based indices of the lines in `new` that are not carried over from `old`.
+pub fn added_line_indices(old: &str, new: &str) -> Vec<usize> {
+    TextDiff::from_lines(old, new)
+        .iter_all_changes()
+        .filter(|change| change.tag() == ChangeTag::Insert)
+        .filter_map(|change| change.new_index())
+        .collect()
+}
+
 // ensure a line ends with a newline character
 fn ensure_newline(line: &str) -> Cow<'_, str> {
     if line.ends_with('\n') {
@@ -15,11 +15,13 @@ pub mod password;
 pub mod path;
 pub mod port_file;
 pub mod response;
+pub mod secrets;
 pub mod sentry;
 pub mod shell;
 pub mod stream_lines;
 pub mod text;
 pub mod tokio;
+pub mod validation;
 pub mod version;
 
 /// Cache for WSL2 detection result
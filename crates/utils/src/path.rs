@@ -101,6 +101,27 @@ pub fn normalize_macos_private_alias<P: AsRef<Path>>(p: P) -> PathBuf {
     p.to_path_buf()
 }
 
+/// Canonical form of a workspace directory as stored in `container_ref`:
+/// absolute, symlinks resolved when the directory exists, and with macOS
+/// `/private` aliases folded, so lookups can compare strings directly.
+pub fn normalize_container_ref<P: AsRef<Path>>(p: P) -> String {
+    let p = p.as_ref();
+    let absolute = match std::fs::canonicalize(p) {
+        Ok(canonical) => canonical,
+        Err(_) if p.is_relative() => std::env::current_dir()
+            .map(|cwd| cwd.join(p))
+            .unwrap_or_else(|_| p.to_path_buf()),
+        Err(_) => p.to_path_buf(),
+    };
+    let cleaned: PathBuf = absolute
+        .components()
+        .filter(|c| !matches!(c, std::path::Component::CurDir))
+        .collect();
+    normalize_macos_private_alias(cleaned)
+        .to_string_lossy()
+        .to_string()
+}
+
 pub fn get_vibe_kanban_temp_dir() -> std::path::PathBuf {
     let dir_name = if cfg!(debug_assertions) {
         "vibe-kanban-dev"
@@ -125,10 +146,80 @@ pub fn expand_tilde(path_str: &str) -> std::path::PathBuf {
     shellexpand::tilde(path_str).as_ref().into()
 }
 
+/// Longest file name we create for a workspace or worktree directory. Keeps
+/// full paths (plus the files inside a checkout) under Windows' MAX_PATH.
+pub const MAX_DIR_NAME_LEN: usize = 64;
+
+/// Names Windows refuses to create, with or without an extension
+const WINDOWS_RESERVED_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Make `name` usable as a single path component on every platform.
+/// Separators, characters Windows rejects and control characters become
+/// `-`, trailing dots and spaces are dropped and reserved device names get a
+/// `_` suffix. Names longer than `max_len` bytes are cut and end with a hash
+/// of the full name, so distinct long names stay distinct.
+pub fn sanitize_path_component(name: &str, max_len: usize) -> String {
+    let replaced: String = name
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '-',
+            c if c.is_control() => '-',
+            c => c,
+        })
+        .collect();
+    let mut sanitized = replaced.trim_end_matches(['.', ' ']).to_string();
+    if sanitized.is_empty() {
+        sanitized = "_".to_string();
+    }
+
+    let stem = sanitized.split('.').next().unwrap_or_default();
+    if WINDOWS_RESERVED_NAMES
+        .iter()
+        .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+    {
+        sanitized.insert(stem.len(), '_');
+    }
+
+    if sanitized.len() <= max_len {
+        return sanitized;
+    }
+    let hash = crate::text::short_hash(name);
+    let keep = max_len.saturating_sub(hash.len() + 1);
+    let head =
+        crate::text::truncate_to_char_boundary(&sanitized, keep).trim_end_matches(['.', ' ', '-']);
+    format!("{head}-{hash}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_normalize_container_ref() {
+        let dir = std::env::temp_dir();
+        let canonical = std::fs::canonicalize(&dir).unwrap();
+        let expected = normalize_macos_private_alias(&canonical)
+            .to_string_lossy()
+            .to_string();
+
+        // Trailing separators and `.` segments collapse to the same string
+        assert_eq!(normalize_container_ref(&dir), expected);
+        assert_eq!(
+            normalize_container_ref(format!("{}/./", dir.display())),
+            expected
+        );
+
+        // Missing directories keep their path, minus `.` segments
+        let missing = normalize_macos_private_alias(canonical.join("gone"));
+        assert_eq!(
+            normalize_container_ref(canonical.join(".").join("gone")),
+            missing.to_string_lossy()
+        );
+    }
+
     #[test]
     fn test_make_path_relative() {
         // Test with relative path (should remain unchanged)
@@ -172,4 +263,23 @@ mod tests {
             "hello-world.txt"
         );
     }
+
+    #[test]
+    fn test_sanitize_path_component() {
+        assert_eq!(
+            sanitize_path_component("repo-vk/1a2b-fix", 64),
+            "repo-vk-1a2b-fix"
+        );
+        assert_eq!(sanitize_path_component("what? <now>. ", 64), "what- -now-");
+        assert_eq!(sanitize_path_component("con", 64), "con_");
+        assert_eq!(sanitize_path_component("Lpt1.txt", 64), "Lpt1_.txt");
+        assert_eq!(sanitize_path_component("console", 64), "console");
+        assert_eq!(sanitize_path_component("...", 64), "_");
+
+        let long = "a".repeat(100);
+        let truncated = sanitize_path_component(&long, 64);
+        assert_eq!(truncated.len(), 64);
+        assert_eq!(truncated, sanitize_path_component(&long, 64));
+        assert_ne!(truncated, sanitize_path_component(&format!("{long}b"), 64));
+    }
 }
@@ -1,12 +1,58 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
+/// Machine-readable reason a request failed, so clients can localize the
+/// message and react to it without matching on English text. The generic
+/// codes mirror the HTTP status; the rest name a specific failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    Internal,
+    BadGateway,
+    ServiceUnavailable,
+    RepoRequired,
+    RepoNotFound,
+    ProjectNotFound,
+    TaskNotFound,
+    QueueNotSequential,
+    TaskHasRunningProcesses,
+    /// `provider` names the integration
+    IntegrationNotConfigured,
+    /// `path` is the offending path
+    PathNotFound,
+    PathNotDirectory,
+    NotAGitRepository,
+    /// `min_length` is the required length
+    UsernameTooShort,
+    PasswordTooShort,
+    MessageQueued,
+    MergeConflicts,
+    RebaseInProgress,
+    AttemptAlreadyRunning,
+    /// Params map each invalid field to what is wrong with it
+    ValidationFailed,
+    /// `depends_on_task_id` already depends on the task
+    DependencyCycle,
+}
+
 #[derive(Debug, Serialize, Deserialize, TS)]
 pub struct ApiResponse<T, E = T> {
     success: bool,
     data: Option<T>,
     error_data: Option<E>,
     message: Option<String>,
+    #[serde(default)]
+    error_code: Option<ErrorCode>,
+    /// Values interpolated into the message, keyed by name
+    #[serde(default)]
+    error_params: Option<BTreeMap<String, String>>,
 }
 
 impl<T, E> ApiResponse<T, E> {
@@ -17,6 +63,8 @@ impl<T, E> ApiResponse<T, E> {
             data: Some(data),
             message: None,
             error_data: None,
+            error_code: None,
+            error_params: None,
         }
     }
 
@@ -27,8 +75,18 @@ impl<T, E> ApiResponse<T, E> {
             data: None,
             message: Some(message.to_string()),
             error_data: None,
+            error_code: None,
+            error_params: None,
         }
     }
+
+    /// Attaches a machine-readable code, and the values interpolated into
+    /// the message, to an error response.
+    pub fn with_error_code(mut self, code: ErrorCode, params: BTreeMap<String, String>) -> Self {
+        self.error_code = Some(code);
+        self.error_params = (!params.is_empty()).then_some(params);
+        self
+    }
     /// Creates an error response, with no `data`, no `message`, but with arbitrary `error_data`.
     pub fn error_with_data(data: E) -> Self {
         ApiResponse {
@@ -36,6 +94,8 @@ impl<T, E> ApiResponse<T, E> {
             data: None,
             error_data: Some(data),
             message: None,
+            error_code: None,
+            error_params: None,
         }
     }
 
@@ -53,4 +113,9 @@ impl<T, E> ApiResponse<T, E> {
     pub fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    /// Returns the machine-readable error code if present.
+    pub fn error_code(&self) -> Option<ErrorCode> {
+        self.error_code
+    }
 }
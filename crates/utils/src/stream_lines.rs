@@ -1,7 +1,8 @@
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
 use futures::{Stream, StreamExt, TryStreamExt};
+use tokio::io::AsyncRead;
 use tokio_util::{
-    codec::{FramedRead, LinesCodec},
+    codec::{Decoder, FramedRead, LinesCodec},
     io::StreamReader,
 };
 
@@ -20,3 +21,47 @@ pub trait LinesStreamExt: Stream<Item = Result<String, std::io::Error>> + Sized
 }
 
 impl<S> LinesStreamExt for S where S: Stream<Item = Result<String, std::io::Error>> {}
+
+/// Longest piece of output held back waiting for a line ending
+const MAX_RAW_LINE_BYTES: usize = 64 * 1024;
+
+/// Frames raw process output into lines that keep their `\n` or `\r` ending, so
+/// joining the pieces reproduces the output exactly. Unlike chunks read straight
+/// off a pipe, a line never splits a multi-byte character or a token.
+#[derive(Debug, Default)]
+struct RawLinesCodec;
+
+impl Decoder for RawLinesCodec {
+    type Item = String;
+    type Error = std::io::Error;
+
+    fn decode(&mut self, buf: &mut BytesMut) -> Result<Option<String>, std::io::Error> {
+        let end = match buf.iter().position(|b| *b == b'\n' || *b == b'\r') {
+            Some(index) => index + 1,
+            None if buf.len() >= MAX_RAW_LINE_BYTES => buf.len(),
+            None => return Ok(None),
+        };
+        Ok(Some(
+            String::from_utf8_lossy(&buf.split_to(end)).into_owned(),
+        ))
+    }
+
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<String>, std::io::Error> {
+        if let Some(line) = self.decode(buf)? {
+            return Ok(Some(line));
+        }
+        if buf.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(String::from_utf8_lossy(&buf.split()).into_owned()))
+    }
+}
+
+/// Read process output as lines with their endings kept; the last line is
+/// emitted without one if the output doesn't end in a newline
+pub fn raw_lines<R>(reader: R) -> impl Stream<Item = std::io::Result<String>>
+where
+    R: AsyncRead,
+{
+    FramedRead::new(reader, RawLinesCodec)
+}
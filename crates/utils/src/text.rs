@@ -12,11 +12,25 @@ pub fn git_branch_id(input: &str) -> String {
     // 3. trim extra hyphens
     let trimmed = slug.trim_matches('-');
 
-    // 4. take up to 16 chars, then trim trailing hyphens again
+    // 4. titles with nothing ASCII (e.g. CJK) would leave an empty slug
+    if trimmed.is_empty() {
+        return short_hash(input);
+    }
+
+    // 5. take up to 16 chars, then trim trailing hyphens again
     let cut: String = trimmed.chars().take(16).collect();
     cut.trim_end_matches('-').to_string()
 }
 
+/// 8 hex chars of a 32-bit FNV-1a hash. Unlike `DefaultHasher` this is
+/// stable across Rust versions, so it is safe to use in names on disk.
+pub fn short_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0x811c9dc5u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+    format!("{hash:08x}")
+}
+
 pub fn short_uuid(u: &Uuid) -> String {
     // to_simple() gives you a 32-char hex string with no hyphens
     let full = u.simple().to_string();
@@ -43,6 +57,15 @@ pub fn truncate_to_char_boundary(content: &str, max_len: usize) -> &str {
 #[cfg(test)]
 mod tests {
 
+    #[test]
+    fn test_git_branch_id() {
+        use super::{git_branch_id, short_hash};
+
+        assert_eq!(git_branch_id("Fix the login bug!"), "fix-the-login-bu");
+        assert_eq!(git_branch_id("ログイン修正"), short_hash("ログイン修正"));
+        assert_eq!(short_hash("a"), "e40c292c");
+    }
+
     #[test]
     fn test_truncate_to_char_boundary() {
         use super::truncate_to_char_boundary;
@@ -0,0 +1,206 @@
+//! Field-level checks for request payloads, run before they reach services
+//! so bad input is reported against the field that caused it.
+
+use std::{collections::BTreeMap, fmt};
+
+use url::Url;
+
+pub const MAX_TITLE_LEN: usize = 255;
+pub const MAX_NAME_LEN: usize = 100;
+pub const MAX_DESCRIPTION_LEN: usize = 100_000;
+/// Labels per comma-separated sync label list
+pub const MAX_LABELS: usize = 20;
+pub const MAX_LABEL_LEN: usize = 50;
+
+/// Messages keyed by field name; the first problem found per field is kept
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors {
+    fields: BTreeMap<String, String>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.fields
+            .entry(field.to_string())
+            .or_insert_with(|| message.into());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn fields(&self) -> &BTreeMap<String, String> {
+        &self.fields
+    }
+
+    pub fn into_fields(self) -> BTreeMap<String, String> {
+        self.fields
+    }
+
+    pub fn into_result(self) -> Result<(), Self> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+
+    /// Non-blank text of at most `max_len` characters
+    pub fn required_text(&mut self, field: &str, value: &str, max_len: usize) {
+        if value.trim().is_empty() {
+            self.add(field, "must not be empty");
+        } else {
+            self.max_length(field, value, max_len);
+        }
+    }
+
+    /// Text of at most `max_len` characters
+    pub fn max_length(&mut self, field: &str, value: &str, max_len: usize) {
+        if value.chars().count() > max_len {
+            self.add(field, format!("must be at most {max_len} characters"));
+        }
+    }
+
+    /// An absolute `http` or `https` URL
+    pub fn http_url(&mut self, field: &str, value: &str) {
+        match Url::parse(value.trim()) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {}
+            _ => self.add(field, "must be an http or https URL"),
+        }
+    }
+
+    /// A repository given as an `owner/name` path or as an `https`, `ssh` or
+    /// `git@host:owner/name` URL. With `host` set, URLs must point at it.
+    pub fn repo_url(&mut self, field: &str, value: &str, host: Option<&str>) {
+        if !is_repo_url(value.trim(), host) {
+            let message = match host {
+                Some(host) => format!("must be an owner/name path or a {host} repository URL"),
+                None => "must be an owner/name path or a repository URL".to_string(),
+            };
+            self.add(field, message);
+        }
+    }
+
+    /// A comma-separated label list of at most [`MAX_LABELS`] labels, each at
+    /// most [`MAX_LABEL_LEN`] characters
+    pub fn label_list(&mut self, field: &str, value: &str) {
+        let labels: Vec<&str> = value
+            .split(',')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .collect();
+        if labels.len() > MAX_LABELS {
+            self.add(field, format!("must list at most {MAX_LABELS} labels"));
+        } else if labels
+            .iter()
+            .any(|label| label.chars().count() > MAX_LABEL_LEN)
+        {
+            self.add(
+                field,
+                format!("labels must be at most {MAX_LABEL_LEN} characters"),
+            );
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .fields
+            .iter()
+            .map(|(field, message)| format!("{field} {message}"))
+            .collect();
+        write!(f, "Invalid request: {}", parts.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// Request payloads that can be checked without touching the database
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
+
+fn is_path_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn has_repo_path(path: &str) -> bool {
+    let segments: Vec<&str> = path
+        .trim_matches('/')
+        .trim_end_matches(".git")
+        .split('/')
+        .collect();
+    segments.len() >= 2 && segments.iter().all(|s| is_path_segment(s))
+}
+
+fn is_repo_url(value: &str, host: Option<&str>) -> bool {
+    let host_matches = |actual: &str| host.is_none_or(|host| actual.eq_ignore_ascii_case(host));
+
+    if let Some((user_host, path)) = value.split_once(':')
+        && let Some((_, actual_host)) = user_host.split_once('@')
+        && !user_host.contains('/')
+    {
+        return host_matches(actual_host) && has_repo_path(path);
+    }
+    if let Ok(url) = Url::parse(value) {
+        return matches!(url.scheme(), "http" | "https" | "ssh")
+            && url.host_str().is_some_and(host_matches)
+            && has_repo_path(url.path());
+    }
+    has_repo_path(value) && !value.starts_with('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_field_errors() {
+        let mut errors = ValidationErrors::new();
+        errors.required_text("title", "   ", MAX_TITLE_LEN);
+        errors.max_length("title", &"x".repeat(300), MAX_TITLE_LEN);
+        errors.required_text("name", &"n".repeat(MAX_NAME_LEN + 1), MAX_NAME_LEN);
+        errors.http_url("vortex_api_url", "ftp://example.com");
+        errors.http_url("gitea_base_url", "https://gitea.example.com");
+        errors.label_list("github_sync_labels", "bug, ,help wanted");
+        errors.label_list("gitlab_sync_labels", &vec!["l"; MAX_LABELS + 1].join(","));
+
+        let fields = errors.clone().into_fields();
+        assert_eq!(fields["title"], "must not be empty");
+        assert_eq!(fields["name"], "must be at most 100 characters");
+        assert!(fields.contains_key("vortex_api_url"));
+        assert!(fields.contains_key("gitlab_sync_labels"));
+        assert!(!fields.contains_key("gitea_base_url"));
+        assert!(!fields.contains_key("github_sync_labels"));
+        assert!(errors.into_result().is_err());
+        assert!(ValidationErrors::new().into_result().is_ok());
+    }
+
+    #[test]
+    fn checks_repo_urls() {
+        let github = Some("github.com");
+        for ok in [
+            "owner/repo",
+            "https://github.com/owner/repo",
+            "https://github.com/owner/repo.git",
+            "git@github.com:owner/repo.git",
+        ] {
+            assert!(is_repo_url(ok, github), "{ok}");
+        }
+        for bad in [
+            "repo",
+            "https://gitlab.com/owner/repo",
+            "https://github.com/owner",
+            "owner/my repo",
+            "/abs/path",
+        ] {
+            assert!(!is_repo_url(bad, github), "{bad}");
+        }
+        assert!(is_repo_url("group/subgroup/project", Some("gitlab.com")));
+        assert!(is_repo_url("ssh://git@git.example.com/team/app", None));
+    }
+}
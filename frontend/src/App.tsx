@@ -6,6 +6,7 @@ import { Projects } from '@/pages/Projects';
 import { ProjectTasks } from '@/pages/ProjectTasks';
 import { FullAttemptLogsPage } from '@/pages/FullAttemptLogs';
 import { Login } from '@/pages/Login';
+import { Kiosk } from '@/pages/Kiosk';
 import { NormalLayout } from '@/components/layout/NormalLayout';
 import { usePostHog } from 'posthog-js/react';
 import { useAuth } from '@/hooks';
@@ -128,6 +129,9 @@ function AppContent() {
               {/* Login route (public) */}
               <Route path="/login" element={<Login />} />
 
+              {/* Wallboard entry point; stores a read-only kiosk token */}
+              <Route path="/kiosk" element={<Kiosk />} />
+
               {/* VS Code full-page logs route (outside NormalLayout for minimal UI) */}
               <Route
                 path="/projects/:projectId/tasks/:taskId/attempts/:attemptId/full"
@@ -17,7 +17,11 @@ import { useCallback, useEffect, useMemo, useState } from 'react';
 import { attemptsApi } from '@/lib/api.ts';
 import { useTranslation } from 'react-i18next';
 
-import { TaskWithAttemptStatus, Workspace } from 'shared/types';
+import {
+  SecretScanFinding,
+  TaskWithAttemptStatus,
+  Workspace,
+} from 'shared/types';
 import { Loader2 } from 'lucide-react';
 import NiceModal, { useModal } from '@ebay/nice-modal-react';
 import { useAuth, useRepoBranches } from '@/hooks';
@@ -59,6 +63,10 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
     const [autoGenerateDescription, setAutoGenerateDescription] = useState(
       config?.pr_auto_description_enabled ?? false
     );
+    const [secretFindings, setSecretFindings] = useState<SecretScanFinding[]>(
+      []
+    );
+    const [allowSecrets, setAllowSecrets] = useState(false);
 
     const { data: branches = [], isLoading: branchesLoading } = useRepoBranches(
       repoId,
@@ -80,7 +88,21 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
       setPrBody(task.description || '');
       setError(null);
       setGhCliHelp(null);
-    }, [modal.visible, isLoaded, task]);
+
+      // Prefer the agent-written summary when one has been generated
+      let cancelled = false;
+      attemptsApi
+        .getSummary(attempt.id)
+        .then((summary) => {
+          if (cancelled || !summary) return;
+          if (summary.pr_title) setPrTitle(summary.pr_title);
+          if (summary.pr_description) setPrBody(summary.pr_description);
+        })
+        .catch(() => {});
+      return () => {
+        cancelled = true;
+      };
+    }, [modal.visible, isLoaded, task, attempt.id]);
 
     // Set default base branch when branches are loaded
     useEffect(() => {
@@ -141,6 +163,7 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
         draft: isDraft,
         auto_generate_description: autoGenerateDescription,
         repo_id: repoId,
+        allow_secrets: allowSecrets,
       });
 
       if (result.success) {
@@ -148,6 +171,8 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
         setPrBody('');
         setPrBaseBranch('');
         setIsDraft(false);
+        setSecretFindings([]);
+        setAllowSecrets(false);
         setAutoGenerateDescription(
           config?.pr_auto_description_enabled ?? false
         );
@@ -206,6 +231,12 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
           );
           setGhCliHelp(null);
           return;
+        } else if (result.error.type === 'secrets_detected') {
+          setSecretFindings(result.error.findings);
+          setAllowSecrets(false);
+          setError(null);
+          setGhCliHelp(null);
+          return;
         }
       }
 
@@ -224,6 +255,7 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
       prTitle,
       isDraft,
       autoGenerateDescription,
+      allowSecrets,
       config?.pr_auto_description_enabled,
       modal,
       isMacEnvironment,
@@ -238,6 +270,8 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
       setPrBaseBranch('');
       setIsDraft(false);
       setAutoGenerateDescription(config?.pr_auto_description_enabled ?? false);
+      setSecretFindings([]);
+      setAllowSecrets(false);
     }, [modal, config?.pr_auto_description_enabled]);
 
     return (
@@ -351,6 +385,40 @@ const CreatePRDialogImpl = NiceModal.create<CreatePRDialogProps>(
                     </AlertDescription>
                   </Alert>
                 )}
+                {secretFindings.length > 0 && (
+                  <Alert variant="destructive">
+                    <AlertTitle>
+                      {t('createPrDialog.secrets.title', {
+                        count: secretFindings.length,
+                      })}
+                    </AlertTitle>
+                    <AlertDescription className="space-y-3">
+                      <p>{t('createPrDialog.secrets.description')}</p>
+                      <ul className="list-disc pl-5 font-mono text-xs">
+                        {secretFindings.map((finding) => (
+                          <li key={finding.id}>
+                            {finding.file_path}:{String(finding.line_number)}{' '}
+                            ({finding.kind}, {finding.redacted})
+                          </li>
+                        ))}
+                      </ul>
+                      <div className="flex items-center space-x-2">
+                        <Checkbox
+                          id="pr-allow-secrets"
+                          checked={allowSecrets}
+                          onCheckedChange={setAllowSecrets}
+                          className="h-5 w-5"
+                        />
+                        <Label
+                          htmlFor="pr-allow-secrets"
+                          className="cursor-pointer text-sm"
+                        >
+                          {t('createPrDialog.secrets.override')}
+                        </Label>
+                      </div>
+                    </AlertDescription>
+                  </Alert>
+                )}
                 {error && <Alert variant="destructive">{error}</Alert>}
               </div>
             )}
@@ -4,6 +4,7 @@ import NiceModal, { useModal } from '@ebay/nice-modal-react';
 import { defineModal } from '@/lib/modals';
 import { useDropzone } from 'react-dropzone';
 import { useForm, useStore } from '@tanstack/react-form';
+import { useQuery } from '@tanstack/react-query';
 import { Image as ImageIcon } from 'lucide-react';
 import {
   Dialog,
@@ -43,7 +44,9 @@ import {
   useKeyExit,
   Scope,
 } from '@/keyboard';
+import { useDebouncedCallback } from '@/hooks/useDebouncedCallback';
 import { useHotkeysContext } from 'react-hotkeys-hook';
+import { tasksApi } from '@/lib/api';
 import { cn } from '@/lib/utils';
 import type {
   TaskStatus,
@@ -103,10 +106,28 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
   );
   const [showDiscardWarning, setShowDiscardWarning] = useState(false);
   const forceCreateOnlyRef = useRef(false);
+  const [duplicateCheckTitle, setDuplicateCheckTitle] = useState('');
+  const { debounced: scheduleDuplicateCheck } = useDebouncedCallback(
+    (title: string) => setDuplicateCheckTitle(title.trim()),
+    400
+  );
 
   const { data: taskImages } = useTaskImages(
     editMode ? props.task.id : undefined
   );
+  // Only checked once the title is typed, so an unchanged title in edit mode
+  // doesn't flag anything
+  const editTaskId = editMode ? props.task.id : null;
+  const { data: potentialDuplicates = [] } = useQuery({
+    queryKey: ['taskDuplicates', projectId, duplicateCheckTitle, editTaskId],
+    queryFn: () =>
+      tasksApi.checkDuplicates({
+        project_id: projectId,
+        title: duplicateCheckTitle,
+        exclude_task_id: editTaskId,
+      }),
+    enabled: modal.visible && duplicateCheckTitle.length >= 3,
+  });
   const { data: projectRepos = [] } = useProjectRepos(projectId, {
     enabled: modal.visible,
   });
@@ -438,7 +459,10 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
                 <Input
                   id="task-title"
                   value={field.state.value}
-                  onChange={(e) => field.handleChange(e.target.value)}
+                  onChange={(e) => {
+                    field.handleChange(e.target.value);
+                    scheduleDuplicateCheck(e.target.value);
+                  }}
                   placeholder={t('taskFormDialog.titlePlaceholder')}
                   className="text-lg font-semibold placeholder:text-muted-foreground/60 border-none p-0"
                   disabled={isSubmitting}
@@ -448,6 +472,24 @@ const TaskFormDialogImpl = NiceModal.create<TaskFormDialogProps>((props) => {
             </form.Field>
           </div>
 
+          {potentialDuplicates.length > 0 && (
+            <div className="flex-none px-4 py-2 border border-1 border-border text-sm">
+              <p className="text-muted-foreground">
+                {t('taskFormDialog.possibleDuplicates')}
+              </p>
+              <ul className="mt-1 space-y-0.5">
+                {potentialDuplicates.map((duplicate) => (
+                  <li key={duplicate.task_id} className="flex gap-2">
+                    <span className="truncate">{duplicate.title}</span>
+                    <span className="shrink-0 text-muted-foreground">
+                      {t(`taskFormDialog.statusOptions.${duplicate.status}`)}
+                    </span>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          )}
+
           <div className="flex-1 p-4 min-h-0 overflow-y-auto overscroll-contain space-y-1 border border-1 border-border">
             {/* Description */}
             <form.Field name="description">
@@ -82,25 +82,8 @@ export function NoServerContent({
     updateProject.mutate(
       {
         projectId: project.id,
-        data: {
-          name: null,
-          dev_script: script,
-          dev_script_working_dir: project.dev_script_working_dir ?? null,
-          default_agent_working_dir: project.default_agent_working_dir ?? null,
-          github_repo_url: null,
-          github_token: null,
-          github_sync_enabled: null,
-          github_sync_labels: null,
-          gitlab_project_url: null,
-          gitlab_token: null,
-          gitlab_sync_enabled: null,
-          gitlab_sync_labels: null,
-          vortex_api_url: null,
-          vortex_project_id: null,
-          vortex_token: null,
-          vortex_sync_enabled: null,
-          vortex_sync_labels: null,
-        },
+        // Send only the script, since fields sent as null are cleared
+        data: { dev_script: script },
       },
       {
         onSuccess: () => {
@@ -16,6 +16,7 @@ import type {
   RefreshRequest,
   ApiResponse,
 } from 'shared/types';
+import { localizedErrorMessage } from '@/lib/api';
 
 // Storage keys
 const ACCESS_TOKEN_KEY = 'auth_access_token';
@@ -36,7 +37,7 @@ const authFetch = async <T,>(
   const data = await response.json();
 
   if (!response.ok) {
-    throw new Error(data.error || 'Request failed');
+    throw new Error(localizedErrorMessage(data, 'Request failed'));
   }
 
   return data;
@@ -57,7 +58,7 @@ const authFetchWithToken = async <T,>(
   const data = await response.json();
 
   if (!response.ok) {
-    throw new Error(data.error || 'Request failed');
+    throw new Error(localizedErrorMessage(data, 'Request failed'));
   }
 
   return data;
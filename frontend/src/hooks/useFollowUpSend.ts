@@ -49,6 +49,7 @@ export function useFollowUpSend({
         retry_process_id: null,
         force_when_dirty: null,
         perform_git_reset: null,
+        include_diff_comments: null,
       };
       await sessionsApi.followUp(sessionId, body);
       clearComments();
@@ -4,6 +4,7 @@ import { repoBranchKeys } from './useRepoBranches';
 
 type MergeParams = {
   repoId: string;
+  revertProtectedPaths?: boolean;
 };
 
 export function useMerge(
@@ -18,6 +19,7 @@ export function useMerge(
       if (!attemptId) return Promise.resolve();
       return attemptsApi.merge(attemptId, {
         repo_id: params.repoId,
+        revert_protected_paths: params.revertProtectedPaths ?? false,
       });
     },
     onSuccess: () => {
@@ -56,6 +56,7 @@ export function useRetryProcess(
         retry_process_id: executionProcessId,
         force_when_dirty: modalResult.forceWhenDirty ?? false,
         perform_git_reset: modalResult.performGitReset ?? true,
+        include_diff_comments: null,
       });
     },
     onSuccess: () => {
@@ -3,6 +3,7 @@
 import {
   ApprovalStatus,
   ApiResponse,
+  ErrorCode,
   Config,
   CreateFollowUpAttempt,
   EditorType,
@@ -14,6 +15,7 @@ import {
   DirectoryListResponse,
   DirectoryEntry,
   ExecutionProcess,
+  ExecutionProcessTree,
   ExecutionProcessRepoState,
   GitBranch,
   Project,
@@ -27,6 +29,7 @@ import {
   ShareTaskResponse,
   Task,
   TaskRelationships,
+  TaskReview,
   Tag,
   TagSearchParams,
   TaskWithAttemptStatus,
@@ -38,6 +41,8 @@ import {
   UpdateMcpServersBody,
   GetMcpServerResponse,
   ImageResponse,
+  ImportedIssue,
+  ProviderIssue,
   GitOperationError,
   ApprovalResponse,
   RebaseTaskAttemptRequest,
@@ -72,6 +77,8 @@ import {
   OpenEditorResponse,
   OpenEditorRequest,
   CreatePrError,
+  CreatePrSetError,
+  CreatePrSetRequest,
   Scratch,
   ScratchType,
   CreateScratch,
@@ -83,15 +90,145 @@ import {
   SharedTaskDetails,
   QueueStatus,
   QueueProcessingStatus,
+  TaskDependency,
+  CreateTaskDependency,
   PrCommentsResponse,
+  PrSetStatus,
   MergeTaskAttemptRequest,
   PushTaskAttemptRequest,
   RepoBranchStatus,
   AbortConflictsRequest,
+  CreateProjectInstruction,
+  NotificationListResponse,
+  ProjectInstruction,
+  ProjectInstructionVersion,
+  RequestReviewRequest,
+  ReviewActionResponse,
+  ReviewResponseRequest,
+  SecretScanFinding,
+  DuplicateProject,
+  GiteaConfigStatus,
+  GiteaIssuesResponse,
+  IssueConnection,
+  UpsertIssueConnection,
+  ShortcutWorkflow,
+  LinearTeam,
+  BitbucketConfigStatus,
+  BitbucketIssuesResponse,
+  ImportBitbucketIssueResponse,
+  CreateBitbucketPrRequest,
+  BitbucketPullRequest,
+  ProjectCalendarFeed,
+  ProjectStatusPage,
+  RotatedCalendarFeed,
+  RotatedStatusPage,
+  PublicProjectStatus,
+  IssueSyncPreview,
+  IssueSyncConflict,
+  TaskGroupWithProgress,
+  GitHubIssueFilter,
+  ImportProgress,
+  LabelSyncDirection,
+  ProjectEmailAddress,
+  SetProjectEmailAddress,
+  ProjectWebhook,
+  SetProjectWebhook,
+  WebhookProvider,
+  CreateGitLabMrRequest,
+  GitLabMergeRequest,
+  GitHubPullRequestsResponse,
+  ImportPullRequestResponse,
+  StatusTransition,
+  SetStatusTransitions,
+  Diff,
+  DiffComment,
+  DiffSizeSummary,
+  CreateDiffComment,
+  UpdateDiffComment,
+  ProposedTask,
+  AcceptProposedTaskRequest,
+  MyTaskGroup,
+  FeedPage,
+  QuickAddRequest,
+  QuickAddResponse,
+  SetTaskAssignee,
+  SetTaskSchedule,
+  TaskOwner,
+  TaskSchedule,
+  CreateProjectFromTemplate,
+  ProjectGroup,
+  ProjectLayout,
+  ProjectPlacement,
+  CreateProjectGroup,
+  UpdateProjectGroup,
+  SetProjectPlacement,
+  PathRuleViolation,
+  RepoPathRule,
+  CreateRepoPathRule,
+  ProjectDiffExclusion,
+  CreateProjectDiffExclusion,
+  RestHookSubscription,
+  CreateRestHookSubscription,
+  PendingApprovalInfo,
+  DenyApprovalRequest,
   Session,
+  SetTaskInstruction,
+  StorageStats,
+  SyncRun,
+  IssueSyncSchedule,
+  ProjectTimeline,
+  BoardSnapshot,
+  ProjectCapacity,
+  SlaRule,
+  CreateSlaRule,
+  LabelProfileRule,
+  UpsertLabelProfileRule,
+  PendingStart,
+  TaskDraft,
+  TriageTaskDrafts,
+  MaintenanceJob,
+  UpsertMaintenanceJob,
+  TaskSlaBreach,
+  TaskEstimate,
+  SetTaskEstimate,
+  TokenBudget,
+  SetTokenBudget,
+  UserCapacity,
+  SetUserCapacity,
+  KioskToken,
+  ManagedBranchStatus,
+  CreateKioskToken,
+  CreatedKioskToken,
+  TaskInstruction,
+  TaskValidationReport,
+  UnreadCountResponse,
+  UpdateProjectInstruction,
+  VacuumResponse,
+  EventBusStatus,
+  ExecutorSlotsStatus,
+  ReconcileWorkspacesRequest,
+  ReconcileWorkspacesResponse,
+  RecoveryFix,
+  RecoveryFixResponse,
+  RecoveryReport,
+  WorkspaceDirectory,
+  RelocateRepoRequest,
+  RepoHealth,
+  ProjectExecutorWeight,
+  ExecutorRecommendation,
+  ValidateTaskRequest,
+  CheckDuplicatesRequest,
+  PotentialDuplicate,
+  VortexOAuthStatus,
+  VortexProject,
+  StartVortexOAuthResponse,
   Workspace,
+  WorkspaceManifest,
+  WorkspaceSummary,
+  WorkspacePullRequest,
 } from 'shared/types';
 import type { WorkspaceWithSession } from '@/types/attempt';
+import i18n from '@/i18n';
 import { createWorkspaceWithSession } from '@/types/attempt';
 
 export interface GitHubConfigStatus {
@@ -100,6 +237,8 @@ export interface GitHubConfigStatus {
   repo_url: string | null;
   sync_enabled: boolean;
   sync_labels: string | null;
+  sync_pull_requests: boolean;
+  label_sync: LabelSyncDirection;
 }
 
 export interface GitHubIssue {
@@ -121,17 +260,14 @@ export interface GitHubIssuesResponse {
   has_github_config: boolean;
 }
 
-export interface ImportIssueResponse {
-  task: Task;
-  issue: GitHubIssue;
-}
-
 export interface GitLabConfigStatus {
   has_project_url: boolean;
   has_token: boolean;
+  base_url: string | null;
   project_url: string | null;
   sync_enabled: boolean;
   sync_labels: string | null;
+  two_way_sync: boolean;
 }
 
 export interface GitLabIssue {
@@ -153,11 +289,6 @@ export interface GitLabIssuesResponse {
   has_gitlab_config: boolean;
 }
 
-export interface ImportGitLabIssueResponse {
-  task: Task;
-  issue: GitLabIssue;
-}
-
 export interface VortexConfigStatus {
   has_project_id: boolean;
   has_token: boolean;
@@ -187,28 +318,50 @@ export interface VortexIssuesResponse {
   has_vortex_config: boolean;
 }
 
-export interface ImportVortexIssueResponse {
-  task: Task;
-  issue: VortexIssue;
-}
-
 export class ApiError<E = unknown> extends Error {
   public status?: number;
   public error_data?: E;
+  public code?: ErrorCode;
+  public params?: Partial<Record<string, string>>;
 
   constructor(
     message: string,
     public statusCode?: number,
     public response?: Response,
-    error_data?: E
+    error_data?: E,
+    code?: ErrorCode | null,
+    params?: Partial<Record<string, string>> | null
   ) {
     super(message);
     this.name = 'ApiError';
     this.status = statusCode;
     this.error_data = error_data;
+    this.code = code ?? undefined;
+    this.params = params ?? undefined;
   }
 }
 
+type ErrorBody = Pick<
+  ApiResponse<unknown>,
+  'message' | 'error_code' | 'error_params'
+>;
+
+/**
+ * The error message in the user's language when there is a translation for
+ * its code, otherwise the server's message
+ */
+export const localizedErrorMessage = (
+  body: Partial<ErrorBody>,
+  fallback: string
+): string => {
+  const message = body.message || fallback;
+  if (!body.error_code) return message;
+  return i18n.t(`common:apiErrors.${body.error_code}`, {
+    ...body.error_params,
+    defaultValue: message,
+  });
+};
+
 const makeRequest = async (url: string, options: RequestInit = {}) => {
   const headers = new Headers(options.headers ?? {});
   if (!headers.has('Content-Type')) {
@@ -242,10 +395,8 @@ const handleApiResponseAsResult = async <T, E>(
     let errorMessage = `Request failed with status ${response.status}`;
 
     try {
-      const errorData = await response.json();
-      if (errorData.message) {
-        errorMessage = errorData.message;
-      }
+      const errorData: Partial<ErrorBody> = await response.json();
+      errorMessage = localizedErrorMessage(errorData, errorMessage);
     } catch {
       errorMessage = response.statusText || errorMessage;
     }
@@ -263,7 +414,9 @@ const handleApiResponseAsResult = async <T, E>(
     return {
       success: false,
       error: result.error_data || undefined,
-      message: result.message || undefined,
+      message: result.message
+        ? localizedErrorMessage(result, result.message)
+        : undefined,
     };
   }
 
@@ -287,12 +440,11 @@ export const handleApiResponse = async <T, E = T>(
     }
 
     let errorMessage = `Request failed with status ${response.status}`;
+    let errorData: Partial<ErrorBody> = {};
 
     try {
-      const errorData = await response.json();
-      if (errorData.message) {
-        errorMessage = errorData.message;
-      }
+      errorData = await response.json();
+      errorMessage = localizedErrorMessage(errorData, errorMessage);
     } catch {
       // Fallback to status text if JSON parsing fails
       errorMessage = response.statusText || errorMessage;
@@ -300,12 +452,20 @@ export const handleApiResponse = async <T, E = T>(
 
     console.error('[API Error]', {
       message: errorMessage,
+      code: errorData.error_code,
       status: response.status,
       response,
       endpoint: response.url,
       timestamp: new Date().toISOString(),
     });
-    throw new ApiError<E>(errorMessage, response.status, response);
+    throw new ApiError<E>(
+      errorMessage,
+      response.status,
+      response,
+      undefined,
+      errorData.error_code,
+      errorData.error_params
+    );
   }
 
   if (response.status === 204) {
@@ -327,10 +487,12 @@ export const handleApiResponse = async <T, E = T>(
       });
       // Throw a properly typed error with the error data
       throw new ApiError<E>(
-        result.message || 'API request failed',
+        localizedErrorMessage(result, 'API request failed'),
         response.status,
         response,
-        result.error_data
+        result.error_data,
+        result.error_code,
+        result.error_params
       );
     }
 
@@ -342,9 +504,12 @@ export const handleApiResponse = async <T, E = T>(
       timestamp: new Date().toISOString(),
     });
     throw new ApiError<E>(
-      result.message || 'API request failed',
+      localizedErrorMessage(result, 'API request failed'),
       response.status,
-      response
+      response,
+      undefined,
+      result.error_code,
+      result.error_params
     );
   }
 
@@ -361,6 +526,146 @@ export const projectsApi = {
     return handleApiResponse<Project>(response);
   },
 
+  createFromTemplate: async (
+    data: CreateProjectFromTemplate
+  ): Promise<Project> => {
+    const response = await makeRequest('/api/projects/from-template', {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<Project>(response);
+  },
+
+  getStatusTransitions: async (id: string): Promise<StatusTransition[]> => {
+    const response = await makeRequest(
+      `/api/projects/${id}/status-transitions`
+    );
+    return handleApiResponse<StatusTransition[]>(response);
+  },
+
+  setStatusTransitions: async (
+    id: string,
+    data: SetStatusTransitions
+  ): Promise<StatusTransition[]> => {
+    const response = await makeRequest(
+      `/api/projects/${id}/status-transitions`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<StatusTransition[]>(response);
+  },
+
+  getWebhooks: async (id: string): Promise<ProjectWebhook[]> => {
+    const response = await makeRequest(`/api/projects/${id}/webhooks`);
+    return handleApiResponse<ProjectWebhook[]>(response);
+  },
+
+  setWebhook: async (
+    id: string,
+    provider: WebhookProvider,
+    data: SetProjectWebhook
+  ): Promise<ProjectWebhook> => {
+    const response = await makeRequest(
+      `/api/projects/${id}/webhooks/${provider}`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ProjectWebhook>(response);
+  },
+
+  deleteWebhook: async (
+    id: string,
+    provider: WebhookProvider
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${id}/webhooks/${provider}`,
+      {
+        method: 'DELETE',
+      }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  getEmailIntake: async (id: string): Promise<ProjectEmailAddress | null> => {
+    const response = await makeRequest(`/api/projects/${id}/email-intake`);
+    return handleApiResponse<ProjectEmailAddress | null>(response);
+  },
+
+  setEmailIntake: async (
+    id: string,
+    data: SetProjectEmailAddress
+  ): Promise<ProjectEmailAddress> => {
+    const response = await makeRequest(`/api/projects/${id}/email-intake`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<ProjectEmailAddress>(response);
+  },
+
+  deleteEmailIntake: async (id: string): Promise<void> => {
+    const response = await makeRequest(`/api/projects/${id}/email-intake`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+
+  getCalendarFeed: async (id: string): Promise<ProjectCalendarFeed | null> => {
+    const response = await makeRequest(`/api/projects/${id}/calendar-feed`);
+    return handleApiResponse<ProjectCalendarFeed | null>(response);
+  },
+
+  rotateCalendarFeed: async (id: string): Promise<RotatedCalendarFeed> => {
+    const response = await makeRequest(`/api/projects/${id}/calendar-feed`, {
+      method: 'POST',
+    });
+    return handleApiResponse<RotatedCalendarFeed>(response);
+  },
+
+  deleteCalendarFeed: async (id: string): Promise<void> => {
+    const response = await makeRequest(`/api/projects/${id}/calendar-feed`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+
+  getStatusPage: async (id: string): Promise<ProjectStatusPage | null> => {
+    const response = await makeRequest(`/api/projects/${id}/status-page`);
+    return handleApiResponse<ProjectStatusPage | null>(response);
+  },
+
+  rotateStatusPage: async (id: string): Promise<RotatedStatusPage> => {
+    const response = await makeRequest(`/api/projects/${id}/status-page`, {
+      method: 'POST',
+    });
+    return handleApiResponse<RotatedStatusPage>(response);
+  },
+
+  deleteStatusPage: async (id: string): Promise<void> => {
+    const response = await makeRequest(`/api/projects/${id}/status-page`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+
+  getPublicStatus: async (token: string): Promise<PublicProjectStatus> => {
+    const response = await makeRequest(
+      `/api/public/projects/${encodeURIComponent(token)}/status`
+    );
+    return handleApiResponse<PublicProjectStatus>(response);
+  },
+
+  duplicate: async (id: string, data: DuplicateProject): Promise<Project> => {
+    const response = await makeRequest(`/api/projects/${id}/duplicate`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<Project>(response);
+  },
+
   update: async (id: string, data: UpdateProject): Promise<Project> => {
     const response = await makeRequest(`/api/projects/${id}`, {
       method: 'PUT',
@@ -449,6 +754,14 @@ export const projectsApi = {
     return handleApiResponse<Repo[]>(response);
   },
 
+  /** Repos whose checkouts were moved or deleted */
+  getBrokenRepositories: async (projectId: string): Promise<RepoHealth[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/repositories/broken`
+    );
+    return handleApiResponse<RepoHealth[]>(response);
+  },
+
   addRepository: async (
     projectId: string,
     data: CreateProjectRepo
@@ -501,6 +814,42 @@ export const projectsApi = {
     return handleApiResponse<ProjectRepo>(response);
   },
 
+  getAll: async (group?: string): Promise<Project[]> => {
+    const query = group ? `?group=${encodeURIComponent(group)}` : '';
+    const response = await makeRequest(`/api/projects${query}`);
+    return handleApiResponse<Project[]>(response);
+  },
+
+  getLayout: async (): Promise<ProjectLayout> => {
+    const response = await makeRequest('/api/projects/layout');
+    return handleApiResponse<ProjectLayout>(response);
+  },
+
+  setPlacement: async (
+    projectId: string,
+    data: SetProjectPlacement
+  ): Promise<ProjectPlacement> => {
+    const response = await makeRequest(`/api/projects/${projectId}/placement`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<ProjectPlacement>(response);
+  },
+
+  favorite: async (projectId: string): Promise<void> => {
+    const response = await makeRequest(`/api/projects/${projectId}/favorite`, {
+      method: 'POST',
+    });
+    return handleApiResponse<void>(response);
+  },
+
+  unfavorite: async (projectId: string): Promise<void> => {
+    const response = await makeRequest(`/api/projects/${projectId}/favorite`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+
   getGitHubConfig: async (projectId: string): Promise<GitHubConfigStatus> => {
     const response = await makeRequest(
       `/api/projects/${projectId}/github/config`
@@ -527,7 +876,7 @@ export const projectsApi = {
   importGitHubIssue: async (
     projectId: string,
     issueNumber: number
-  ): Promise<ImportIssueResponse> => {
+  ): Promise<ImportedIssue> => {
     const response = await makeRequest(
       `/api/projects/${projectId}/github/issues/import`,
       {
@@ -535,17 +884,71 @@ export const projectsApi = {
         body: JSON.stringify({ issue_number: issueNumber }),
       }
     );
-    return handleApiResponse<ImportIssueResponse>(response);
+    return handleApiResponse<ImportedIssue>(response);
+  },
+
+  // Recreates the task for an issue whose task was deleted; 409 if one exists
+  restoreGitHubIssue: async (
+    projectId: string,
+    issueNumber: number
+  ): Promise<ImportedIssue> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/github/issues/restore`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ issue_number: issueNumber }),
+      }
+    );
+    return handleApiResponse<ImportedIssue>(response);
+  },
+
+  // Runs in the background; progress arrives on the events stream at /imports/{import_id}
+  bulkImportGitHubIssues: async (
+    projectId: string,
+    issueNumbers: number[],
+    filter: GitHubIssueFilter
+  ): Promise<ImportProgress> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/github/issues/bulk-import`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ issue_numbers: issueNumbers, ...filter }),
+      }
+    );
+    return handleApiResponse<ImportProgress>(response);
   },
 
-  syncGitHubIssues: async (projectId: string): Promise<ImportIssueResponse[]> => {
+  syncGitHubIssues: async (projectId: string): Promise<ImportedIssue[]> => {
     const response = await makeRequest(
       `/api/projects/${projectId}/github/issues/sync`,
       {
         method: 'POST',
       }
     );
-    return handleApiResponse<ImportIssueResponse[]>(response);
+    return handleApiResponse<ImportedIssue[]>(response);
+  },
+
+  listGitHubPullRequests: async (
+    projectId: string
+  ): Promise<GitHubPullRequestsResponse> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/github/pulls`
+    );
+    return handleApiResponse<GitHubPullRequestsResponse>(response);
+  },
+
+  importGitHubPullRequest: async (
+    projectId: string,
+    pullNumber: number
+  ): Promise<ImportPullRequestResponse> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/github/pulls/import`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ pull_number: pullNumber }),
+      }
+    );
+    return handleApiResponse<ImportPullRequestResponse>(response);
   },
 
   getGitLabConfig: async (projectId: string): Promise<GitLabConfigStatus> => {
@@ -574,7 +977,7 @@ export const projectsApi = {
   importGitLabIssue: async (
     projectId: string,
     issueIid: number
-  ): Promise<ImportGitLabIssueResponse> => {
+  ): Promise<ImportedIssue> => {
     const response = await makeRequest(
       `/api/projects/${projectId}/gitlab/issues/import`,
       {
@@ -582,136 +985,854 @@ export const projectsApi = {
         body: JSON.stringify({ issue_iid: issueIid }),
       }
     );
-    return handleApiResponse<ImportGitLabIssueResponse>(response);
+    return handleApiResponse<ImportedIssue>(response);
   },
 
-  syncGitLabIssues: async (projectId: string): Promise<ImportGitLabIssueResponse[]> => {
+  syncGitLabIssues: async (projectId: string): Promise<ImportedIssue[]> => {
     const response = await makeRequest(
       `/api/projects/${projectId}/gitlab/issues/sync`,
       {
         method: 'POST',
       }
     );
-    return handleApiResponse<ImportGitLabIssueResponse[]>(response);
+    return handleApiResponse<ImportedIssue[]>(response);
   },
 
-  // Vortex Integration
-  getVortexConfig: async (projectId: string): Promise<VortexConfigStatus> => {
+  // Gitea / Forgejo Integration
+  getGiteaConfig: async (projectId: string): Promise<GiteaConfigStatus> => {
     const response = await makeRequest(
-      `/api/projects/${projectId}/vortex/config`
+      `/api/projects/${projectId}/gitea/config`
     );
-    return handleApiResponse<VortexConfigStatus>(response);
+    return handleApiResponse<GiteaConfigStatus>(response);
   },
 
-  listVortexIssues: async (
+  listGiteaIssues: async (
     projectId: string,
-    params?: { status?: string; labels?: string; page?: number; per_page?: number }
-  ): Promise<VortexIssuesResponse> => {
+    params?: { state?: string; labels?: string; page?: number; limit?: number }
+  ): Promise<GiteaIssuesResponse> => {
     const searchParams = new URLSearchParams();
-    if (params?.status) searchParams.append('status', params.status);
+    if (params?.state) searchParams.append('state', params.state);
     if (params?.labels) searchParams.append('labels', params.labels);
     if (params?.page) searchParams.append('page', params.page.toString());
-    if (params?.per_page) searchParams.append('per_page', params.per_page.toString());
+    if (params?.limit) searchParams.append('limit', params.limit.toString());
     const query = searchParams.toString();
     const response = await makeRequest(
-      `/api/projects/${projectId}/vortex/issues${query ? `?${query}` : ''}`
+      `/api/projects/${projectId}/gitea/issues${query ? `?${query}` : ''}`
     );
-    return handleApiResponse<VortexIssuesResponse>(response);
+    return handleApiResponse<GiteaIssuesResponse>(response);
   },
 
-  importVortexIssue: async (
+  importGiteaIssue: async (
     projectId: string,
-    issueId: string
-  ): Promise<ImportVortexIssueResponse> => {
+    issueNumber: number
+  ): Promise<ImportedIssue> => {
     const response = await makeRequest(
-      `/api/projects/${projectId}/vortex/issues/import`,
+      `/api/projects/${projectId}/gitea/issues/import`,
       {
         method: 'POST',
-        body: JSON.stringify({ issue_id: issueId }),
+        body: JSON.stringify({ issue_number: issueNumber }),
       }
     );
-    return handleApiResponse<ImportVortexIssueResponse>(response);
+    return handleApiResponse<ImportedIssue>(response);
   },
 
-  syncVortexIssues: async (projectId: string): Promise<ImportVortexIssueResponse[]> => {
+  syncGiteaIssues: async (projectId: string): Promise<ImportedIssue[]> => {
     const response = await makeRequest(
-      `/api/projects/${projectId}/vortex/issues/sync`,
+      `/api/projects/${projectId}/gitea/issues/sync`,
       {
         method: 'POST',
       }
     );
-    return handleApiResponse<ImportVortexIssueResponse[]>(response);
-  },
-};
-
-// Task Management APIs
-export const tasksApi = {
-  getById: async (taskId: string): Promise<Task> => {
-    const response = await makeRequest(`/api/tasks/${taskId}`);
-    return handleApiResponse<Task>(response);
-  },
-
-  create: async (data: CreateTask): Promise<Task> => {
-    const response = await makeRequest(`/api/tasks`, {
-      method: 'POST',
-      body: JSON.stringify(data),
-    });
-    return handleApiResponse<Task>(response);
+    return handleApiResponse<ImportedIssue[]>(response);
   },
 
-  createAndStart: async (
-    data: CreateAndStartTaskRequest
-  ): Promise<TaskWithAttemptStatus> => {
-    const response = await makeRequest(`/api/tasks/create-and-start`, {
-      method: 'POST',
-      body: JSON.stringify(data),
-    });
-    return handleApiResponse<TaskWithAttemptStatus>(response);
+  // Trackers connected with an API token: Linear, Azure DevOps, Notion,
+  // Asana, ClickUp and Shortcut. `provider` is the tracker's slug, e.g.
+  // `azure-devops`.
+  listIssueConnections: async (
+    projectId: string
+  ): Promise<IssueConnection[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/issue-connections`
+    );
+    return handleApiResponse<IssueConnection[]>(response);
   },
 
-  update: async (taskId: string, data: UpdateTask): Promise<Task> => {
-    const response = await makeRequest(`/api/tasks/${taskId}`, {
-      method: 'PUT',
-      body: JSON.stringify(data),
-    });
-    return handleApiResponse<Task>(response);
+  upsertIssueConnection: async (
+    projectId: string,
+    data: UpsertIssueConnection
+  ): Promise<IssueConnection> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/issue-connections`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<IssueConnection>(response);
   },
 
-  delete: async (taskId: string): Promise<void> => {
-    const response = await makeRequest(`/api/tasks/${taskId}`, {
-      method: 'DELETE',
-    });
+  deleteIssueConnection: async (
+    projectId: string,
+    provider: string
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/issue-connections/${provider}`,
+      {
+        method: 'DELETE',
+      }
+    );
     return handleApiResponse<void>(response);
   },
 
-  share: async (taskId: string): Promise<ShareTaskResponse> => {
-    const response = await makeRequest(`/api/tasks/${taskId}/share`, {
-      method: 'POST',
-    });
-    return handleApiResponse<ShareTaskResponse>(response);
+  listConnectedIssues: async (
+    projectId: string,
+    provider: string,
+    params?: { limit?: number }
+  ): Promise<ProviderIssue[]> => {
+    const searchParams = new URLSearchParams();
+    if (params?.limit) searchParams.append('limit', params.limit.toString());
+    const query = searchParams.toString();
+    const response = await makeRequest(
+      `/api/projects/${projectId}/issue-connections/${provider}/issues${query ? `?${query}` : ''}`
+    );
+    return handleApiResponse<ProviderIssue[]>(response);
   },
 
-  reassign: async (
-    sharedTaskId: string,
-    data: { new_assignee_user_id: string | null }
-  ): Promise<SharedTaskResponse> => {
-    const payload = {
-      new_assignee_user_id: data.new_assignee_user_id,
-    };
-
+  importConnectedIssue: async (
+    projectId: string,
+    provider: string,
+    issueId: string
+  ): Promise<ImportedIssue> => {
     const response = await makeRequest(
-      `/api/shared-tasks/${sharedTaskId}/assign`,
+      `/api/projects/${projectId}/issue-connections/${provider}/issues/import`,
       {
         method: 'POST',
-        body: JSON.stringify(payload),
+        body: JSON.stringify({ issue_id: issueId }),
       }
     );
-
-    return handleApiResponse<SharedTaskResponse>(response);
+    return handleApiResponse<ImportedIssue>(response);
   },
 
-  unshare: async (sharedTaskId: string): Promise<void> => {
-    const response = await makeRequest(`/api/shared-tasks/${sharedTaskId}`, {
+  syncConnectedIssues: async (
+    projectId: string,
+    provider: string
+  ): Promise<ImportedIssue[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/issue-connections/${provider}/issues/sync`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<ImportedIssue[]>(response);
+  },
+
+  listLinearTeams: async (
+    projectId: string,
+    apiKey?: string
+  ): Promise<LinearTeam[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/linear/teams`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ api_key: apiKey || undefined }),
+      }
+    );
+    return handleApiResponse<LinearTeam[]>(response);
+  },
+
+  listShortcutWorkflows: async (
+    projectId: string
+  ): Promise<ShortcutWorkflow[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/shortcut/workflows`
+    );
+    return handleApiResponse<ShortcutWorkflow[]>(response);
+  },
+
+  // Bitbucket Cloud Integration
+  getBitbucketConfig: async (
+    projectId: string
+  ): Promise<BitbucketConfigStatus> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/bitbucket/config`
+    );
+    return handleApiResponse<BitbucketConfigStatus>(response);
+  },
+
+  listBitbucketIssues: async (
+    projectId: string,
+    params?: { states?: string[]; page?: number; pagelen?: number }
+  ): Promise<BitbucketIssuesResponse> => {
+    const searchParams = new URLSearchParams();
+    if (params?.states?.length)
+      searchParams.append('states', params.states.join(','));
+    if (params?.page) searchParams.append('page', params.page.toString());
+    if (params?.pagelen)
+      searchParams.append('pagelen', params.pagelen.toString());
+    const query = searchParams.toString();
+    const response = await makeRequest(
+      `/api/projects/${projectId}/bitbucket/issues${query ? `?${query}` : ''}`
+    );
+    return handleApiResponse<BitbucketIssuesResponse>(response);
+  },
+
+  importBitbucketIssue: async (
+    projectId: string,
+    issueId: number
+  ): Promise<ImportBitbucketIssueResponse> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/bitbucket/issues/import`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ issue_id: issueId }),
+      }
+    );
+    return handleApiResponse<ImportBitbucketIssueResponse>(response);
+  },
+
+  syncBitbucketIssues: async (
+    projectId: string
+  ): Promise<ImportBitbucketIssueResponse[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/bitbucket/issues/sync`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<ImportBitbucketIssueResponse[]>(response);
+  },
+
+  // Vortex Integration
+  getVortexConfig: async (projectId: string): Promise<VortexConfigStatus> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/config`
+    );
+    return handleApiResponse<VortexConfigStatus>(response);
+  },
+
+  listVortexProjects: async (projectId: string): Promise<VortexProject[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/projects`
+    );
+    return handleApiResponse<VortexProject[]>(response);
+  },
+
+  listVortexIssues: async (
+    projectId: string,
+    params?: { status?: string; labels?: string; page?: number; per_page?: number }
+  ): Promise<VortexIssuesResponse> => {
+    const searchParams = new URLSearchParams();
+    if (params?.status) searchParams.append('status', params.status);
+    if (params?.labels) searchParams.append('labels', params.labels);
+    if (params?.page) searchParams.append('page', params.page.toString());
+    if (params?.per_page) searchParams.append('per_page', params.per_page.toString());
+    const query = searchParams.toString();
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/issues${query ? `?${query}` : ''}`
+    );
+    return handleApiResponse<VortexIssuesResponse>(response);
+  },
+
+  importVortexIssue: async (
+    projectId: string,
+    issueId: string
+  ): Promise<ImportedIssue> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/issues/import`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ issue_id: issueId }),
+      }
+    );
+    return handleApiResponse<ImportedIssue>(response);
+  },
+
+  syncVortexIssues: async (projectId: string): Promise<ImportedIssue[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/issues/sync`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<ImportedIssue[]>(response);
+  },
+
+  getVortexSyncConflicts: async (
+    projectId: string,
+    limit?: number
+  ): Promise<IssueSyncConflict[]> => {
+    const query = limit ? `?limit=${limit}` : '';
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/sync-conflicts${query}`
+    );
+    return handleApiResponse<IssueSyncConflict[]>(response);
+  },
+
+  getVortexOAuthStatus: async (projectId: string): Promise<VortexOAuthStatus> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/oauth`
+    );
+    return handleApiResponse<VortexOAuthStatus>(response);
+  },
+
+  startVortexOAuth: async (
+    projectId: string,
+    redirectUri: string
+  ): Promise<StartVortexOAuthResponse> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/oauth/authorize`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ redirect_uri: redirectUri }),
+      }
+    );
+    return handleApiResponse<StartVortexOAuthResponse>(response);
+  },
+
+  disconnectVortexOAuth: async (projectId: string): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/vortex/oauth`,
+      {
+        method: 'DELETE',
+      }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  previewIssueSync: async (
+    projectId: string,
+    provider:
+      | 'github'
+      | 'gitlab'
+      | 'gitea'
+      | 'bitbucket'
+      | 'linear'
+      | 'azure'
+      | 'notion'
+      | 'vortex'
+  ): Promise<IssueSyncPreview> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/${provider}/issues/sync?dry_run=true`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<IssueSyncPreview>(response);
+  },
+
+  getSyncRuns: async (projectId: string, limit = 10): Promise<SyncRun[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/sync-runs?limit=${limit}`
+    );
+    return handleApiResponse<SyncRun[]>(response);
+  },
+
+  getSyncSchedule: async (projectId: string): Promise<IssueSyncSchedule> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/sync-schedule`
+    );
+    return handleApiResponse<IssueSyncSchedule>(response);
+  },
+
+  getTimeline: async (projectId: string): Promise<ProjectTimeline> => {
+    const response = await makeRequest(`/api/projects/${projectId}/timeline`);
+    return handleApiResponse<ProjectTimeline>(response);
+  },
+
+  getBoardAt: async (
+    projectId: string,
+    timestamp: Date
+  ): Promise<BoardSnapshot> => {
+    const params = new URLSearchParams({ timestamp: timestamp.toISOString() });
+    const response = await makeRequest(
+      `/api/projects/${projectId}/board-at?${params}`
+    );
+    return handleApiResponse<BoardSnapshot>(response);
+  },
+
+  getCapacity: async (projectId: string): Promise<ProjectCapacity> => {
+    const response = await makeRequest(`/api/projects/${projectId}/capacity`);
+    return handleApiResponse<ProjectCapacity>(response);
+  },
+
+  getSlaRules: async (projectId: string): Promise<SlaRule[]> => {
+    const response = await makeRequest(`/api/projects/${projectId}/sla-rules`);
+    return handleApiResponse<SlaRule[]>(response);
+  },
+
+  createSlaRule: async (
+    projectId: string,
+    data: CreateSlaRule
+  ): Promise<SlaRule> => {
+    const response = await makeRequest(`/api/projects/${projectId}/sla-rules`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<SlaRule>(response);
+  },
+
+  deleteSlaRule: async (projectId: string, ruleId: string): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/sla-rules/${ruleId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  getLabelProfileRules: async (
+    projectId: string
+  ): Promise<LabelProfileRule[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/label-profile-rules`
+    );
+    return handleApiResponse<LabelProfileRule[]>(response);
+  },
+
+  upsertLabelProfileRule: async (
+    projectId: string,
+    data: UpsertLabelProfileRule
+  ): Promise<LabelProfileRule> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/label-profile-rules`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<LabelProfileRule>(response);
+  },
+
+  deleteLabelProfileRule: async (
+    projectId: string,
+    ruleId: string
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/label-profile-rules/${ruleId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  getPendingStarts: async (projectId: string): Promise<PendingStart[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/pending-starts`
+    );
+    return handleApiResponse<PendingStart[]>(response);
+  },
+
+  approvePendingStart: async (
+    projectId: string,
+    pendingStartId: string
+  ): Promise<Task> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/pending-starts/${pendingStartId}/approve`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<Task>(response);
+  },
+
+  denyPendingStart: async (
+    projectId: string,
+    pendingStartId: string
+  ): Promise<Task> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/pending-starts/${pendingStartId}/deny`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<Task>(response);
+  },
+
+  getTaskDrafts: async (projectId: string): Promise<TaskDraft[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/task-drafts`
+    );
+    return handleApiResponse<TaskDraft[]>(response);
+  },
+
+  triageTaskDrafts: async (
+    projectId: string,
+    data: TriageTaskDrafts
+  ): Promise<TaskWithAttemptStatus[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/task-drafts/triage`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<TaskWithAttemptStatus[]>(response);
+  },
+
+  getTaskGroups: async (
+    projectId: string
+  ): Promise<TaskGroupWithProgress[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/task-groups`
+    );
+    return handleApiResponse<TaskGroupWithProgress[]>(response);
+  },
+
+  getSlaBreaches: async (projectId: string): Promise<TaskSlaBreach[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/sla-breaches`
+    );
+    return handleApiResponse<TaskSlaBreach[]>(response);
+  },
+
+  getTokenBudget: async (projectId: string): Promise<TokenBudget | null> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/token-budget`
+    );
+    return handleApiResponse<TokenBudget | null>(response);
+  },
+
+  setTokenBudget: async (
+    projectId: string,
+    data: SetTokenBudget
+  ): Promise<TokenBudget | null> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/token-budget`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<TokenBudget | null>(response);
+  },
+
+  getMaintenanceJobs: async (projectId: string): Promise<MaintenanceJob[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/maintenance-jobs`
+    );
+    return handleApiResponse<MaintenanceJob[]>(response);
+  },
+
+  createMaintenanceJob: async (
+    projectId: string,
+    data: UpsertMaintenanceJob
+  ): Promise<MaintenanceJob> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/maintenance-jobs`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<MaintenanceJob>(response);
+  },
+
+  updateMaintenanceJob: async (
+    projectId: string,
+    jobId: string,
+    data: UpsertMaintenanceJob
+  ): Promise<MaintenanceJob> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/maintenance-jobs/${jobId}`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<MaintenanceJob>(response);
+  },
+
+  deleteMaintenanceJob: async (
+    projectId: string,
+    jobId: string
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/maintenance-jobs/${jobId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  runMaintenanceJob: async (
+    projectId: string,
+    jobId: string
+  ): Promise<Task> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/maintenance-jobs/${jobId}/run`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<Task>(response);
+  },
+
+  getExecutorWeight: async (
+    projectId: string
+  ): Promise<ProjectExecutorWeight> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/executor-weight`
+    );
+    return handleApiResponse<ProjectExecutorWeight>(response);
+  },
+
+  setExecutorWeight: async (
+    projectId: string,
+    weight: number
+  ): Promise<ProjectExecutorWeight> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/executor-weight`,
+      {
+        method: 'PUT',
+        body: JSON.stringify({ weight }),
+      }
+    );
+    return handleApiResponse<ProjectExecutorWeight>(response);
+  },
+
+  getExecutorRecommendation: async (
+    projectId: string
+  ): Promise<ExecutorRecommendation> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/executor-recommendation`
+    );
+    return handleApiResponse<ExecutorRecommendation>(response);
+  },
+
+  getDiffExclusions: async (
+    projectId: string
+  ): Promise<ProjectDiffExclusion[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/diff-exclusions`
+    );
+    return handleApiResponse<ProjectDiffExclusion[]>(response);
+  },
+
+  createDiffExclusion: async (
+    projectId: string,
+    data: CreateProjectDiffExclusion
+  ): Promise<ProjectDiffExclusion> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/diff-exclusions`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ProjectDiffExclusion>(response);
+  },
+
+  deleteDiffExclusion: async (
+    projectId: string,
+    exclusionId: string
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/diff-exclusions/${exclusionId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+};
+
+// Task Management APIs
+export const tasksApi = {
+  getById: async (taskId: string): Promise<Task> => {
+    const response = await makeRequest(`/api/tasks/${taskId}`);
+    return handleApiResponse<Task>(response);
+  },
+
+  create: async (data: CreateTask): Promise<Task> => {
+    const response = await makeRequest(`/api/tasks`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<Task>(response);
+  },
+
+  getMine: async (): Promise<MyTaskGroup[]> => {
+    const response = await makeRequest(`/api/me/tasks`);
+    return handleApiResponse<MyTaskGroup[]>(response);
+  },
+
+  getMyFeed: async (params?: {
+    before?: number;
+    limit?: number;
+  }): Promise<FeedPage> => {
+    const searchParams = new URLSearchParams();
+    if (params?.before) searchParams.append('before', params.before.toString());
+    if (params?.limit) searchParams.append('limit', params.limit.toString());
+    const query = searchParams.toString();
+    const response = await makeRequest(
+      `/api/me/feed${query ? `?${query}` : ''}`
+    );
+    return handleApiResponse<FeedPage>(response);
+  },
+
+  getOwner: async (taskId: string): Promise<TaskOwner | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/owner`);
+    return handleApiResponse<TaskOwner | null>(response);
+  },
+
+  setAssignee: async (
+    taskId: string,
+    data: SetTaskAssignee
+  ): Promise<TaskOwner> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/owner`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskOwner>(response);
+  },
+
+  getSchedule: async (taskId: string): Promise<TaskSchedule | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/schedule`);
+    return handleApiResponse<TaskSchedule | null>(response);
+  },
+
+  setSchedule: async (
+    taskId: string,
+    data: SetTaskSchedule
+  ): Promise<TaskSchedule> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/schedule`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskSchedule>(response);
+  },
+
+  getLabels: async (taskId: string): Promise<string[]> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/labels`);
+    return handleApiResponse<string[]>(response);
+  },
+
+  setLabels: async (taskId: string, labels: string[]): Promise<string[]> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/labels`, {
+      method: 'PUT',
+      body: JSON.stringify({ labels }),
+    });
+    return handleApiResponse<string[]>(response);
+  },
+
+  getEstimate: async (taskId: string): Promise<TaskEstimate | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/estimate`);
+    return handleApiResponse<TaskEstimate | null>(response);
+  },
+
+  setEstimate: async (
+    taskId: string,
+    data: SetTaskEstimate
+  ): Promise<TaskEstimate | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/estimate`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskEstimate | null>(response);
+  },
+
+  getTokenBudget: async (taskId: string): Promise<TokenBudget | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/token-budget`);
+    return handleApiResponse<TokenBudget | null>(response);
+  },
+
+  setTokenBudget: async (
+    taskId: string,
+    data: SetTokenBudget
+  ): Promise<TokenBudget | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/token-budget`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TokenBudget | null>(response);
+  },
+
+  createAndStart: async (
+    data: CreateAndStartTaskRequest
+  ): Promise<TaskWithAttemptStatus> => {
+    const response = await makeRequest(`/api/tasks/create-and-start`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskWithAttemptStatus>(response);
+  },
+
+  validate: async (
+    data: ValidateTaskRequest
+  ): Promise<TaskValidationReport> => {
+    const response = await makeRequest(`/api/tasks/validate`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskValidationReport>(response);
+  },
+
+  checkDuplicates: async (
+    data: CheckDuplicatesRequest
+  ): Promise<PotentialDuplicate[]> => {
+    const response = await makeRequest(`/api/tasks/check-duplicates`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<PotentialDuplicate[]>(response);
+  },
+
+  quickAdd: async (
+    projectId: string,
+    data: QuickAddRequest
+  ): Promise<QuickAddResponse> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/tasks/quick-add`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<QuickAddResponse>(response);
+  },
+
+  update: async (
+    taskId: string,
+    data: UpdateTask,
+    options?: { forceStatus?: boolean }
+  ): Promise<Task> => {
+    const query = options?.forceStatus ? '?force_status=true' : '';
+    const response = await makeRequest(`/api/tasks/${taskId}${query}`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<Task>(response);
+  },
+
+  delete: async (taskId: string): Promise<void> => {
+    const response = await makeRequest(`/api/tasks/${taskId}`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+
+  share: async (
+    taskId: string,
+    allowSecrets = false
+  ): Promise<ShareTaskResponse> => {
+    const query = allowSecrets ? '?allow_secrets=true' : '';
+    const response = await makeRequest(`/api/tasks/${taskId}/share${query}`, {
+      method: 'POST',
+    });
+    return handleApiResponse<ShareTaskResponse>(response);
+  },
+
+  reassign: async (
+    sharedTaskId: string,
+    data: { new_assignee_user_id: string | null }
+  ): Promise<SharedTaskResponse> => {
+    const payload = {
+      new_assignee_user_id: data.new_assignee_user_id,
+    };
+
+    const response = await makeRequest(
+      `/api/shared-tasks/${sharedTaskId}/assign`,
+      {
+        method: 'POST',
+        body: JSON.stringify(payload),
+      }
+    );
+
+    return handleApiResponse<SharedTaskResponse>(response);
+  },
+
+  unshare: async (sharedTaskId: string): Promise<void> => {
+    const response = await makeRequest(`/api/shared-tasks/${sharedTaskId}`, {
       method: 'DELETE',
     });
     return handleApiResponse<void>(response);
@@ -725,6 +1846,52 @@ export const tasksApi = {
     return handleApiResponse<Task | null>(response);
   },
 
+  listReviews: async (taskId: string): Promise<TaskReview[]> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/reviews`);
+    return handleApiResponse<TaskReview[]>(response);
+  },
+
+  requestReview: async (
+    taskId: string,
+    data: RequestReviewRequest
+  ): Promise<TaskReview[]> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/request-review`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskReview[]>(response);
+  },
+
+  approveReview: async (
+    taskId: string,
+    reviewId: string,
+    data: ReviewResponseRequest
+  ): Promise<ReviewActionResponse> => {
+    const response = await makeRequest(
+      `/api/tasks/${taskId}/reviews/${reviewId}/approve`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ReviewActionResponse>(response);
+  },
+
+  requestChanges: async (
+    taskId: string,
+    reviewId: string,
+    data: ReviewResponseRequest
+  ): Promise<ReviewActionResponse> => {
+    const response = await makeRequest(
+      `/api/tasks/${taskId}/reviews/${reviewId}/request-changes`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ReviewActionResponse>(response);
+  },
+
   reorderQueue: async (taskId: string, newPosition: number): Promise<Task> => {
     const response = await makeRequest(`/api/tasks/${taskId}/reorder-queue`, {
       method: 'POST',
@@ -733,6 +1900,34 @@ export const tasksApi = {
     return handleApiResponse<Task>(response);
   },
 
+  getDependencies: async (taskId: string): Promise<TaskDependency[]> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/dependencies`);
+    return handleApiResponse<TaskDependency[]>(response);
+  },
+
+  addDependency: async (
+    taskId: string,
+    dependsOnTaskId: string
+  ): Promise<TaskDependency> => {
+    const body: CreateTaskDependency = { depends_on_task_id: dependsOnTaskId };
+    const response = await makeRequest(`/api/tasks/${taskId}/dependencies`, {
+      method: 'POST',
+      body: JSON.stringify(body),
+    });
+    return handleApiResponse<TaskDependency>(response);
+  },
+
+  removeDependency: async (
+    taskId: string,
+    dependsOnTaskId: string
+  ): Promise<void> => {
+    const response = await makeRequest(
+      `/api/tasks/${taskId}/dependencies/${dependsOnTaskId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+
   getQueueStatus: async (projectId: string): Promise<QueueProcessingStatus> => {
     const response = await makeRequest(
       `/api/tasks/queue/status?project_id=${projectId}`
@@ -845,42 +2040,221 @@ export const attemptsApi = {
 
   runAgentSetup: async (
     attemptId: string,
-    data: RunAgentSetupRequest
-  ): Promise<RunAgentSetupResponse> => {
+    data: RunAgentSetupRequest
+  ): Promise<RunAgentSetupResponse> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/run-agent-setup`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<RunAgentSetupResponse>(response);
+  },
+
+  openEditor: async (
+    attemptId: string,
+    data: OpenEditorRequest
+  ): Promise<OpenEditorResponse> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/open-editor`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<OpenEditorResponse>(response);
+  },
+
+  getBranchStatus: async (attemptId: string): Promise<RepoBranchStatus[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/branch-status`
+    );
+    return handleApiResponse<RepoBranchStatus[]>(response);
+  },
+
+  getRepos: async (attemptId: string): Promise<RepoWithTargetBranch[]> => {
+    const response = await makeRequest(`/api/task-attempts/${attemptId}/repos`);
+    return handleApiResponse<RepoWithTargetBranch[]>(response);
+  },
+
+  getSecretFindings: async (
+    attemptId: string
+  ): Promise<SecretScanFinding[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/secret-findings`
+    );
+    return handleApiResponse<SecretScanFinding[]>(response);
+  },
+
+  getPathViolations: async (
+    attemptId: string
+  ): Promise<PathRuleViolation[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/path-violations`
+    );
+    return handleApiResponse<PathRuleViolation[]>(response);
+  },
+
+  getProcesses: async (attemptId: string): Promise<ExecutionProcessTree[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/processes`
+    );
+    return handleApiResponse<ExecutionProcessTree[]>(response);
+  },
+
+  getManifest: async (attemptId: string): Promise<WorkspaceManifest> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/manifest`
+    );
+    return handleApiResponse<WorkspaceManifest>(response);
+  },
+
+  /** Link that downloads the attempt's manifest as a JSON file */
+  getManifestDownloadUrl: (attemptId: string): string =>
+    `/api/task-attempts/${attemptId}/manifest/download`,
+
+  /** Link that downloads the attempt's combined, redacted process logs */
+  getLogsDownloadUrl: (
+    attemptId: string,
+    format: 'text' | 'ndjson' = 'text'
+  ): string =>
+    `/api/task-attempts/${attemptId}/logs/download?format=${format}`,
+
+  getSummary: async (attemptId: string): Promise<WorkspaceSummary | null> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/summary`
+    );
+    return handleApiResponse<WorkspaceSummary | null>(response);
+  },
+
+  /** Ask the agent for a commit message and PR description from the diff */
+  generateSummary: async (attemptId: string): Promise<WorkspaceSummary> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/generate-summary`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<WorkspaceSummary>(response);
+  },
+
+  getApprovals: async (attemptId: string): Promise<PendingApprovalInfo[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/approvals`
+    );
+    return handleApiResponse<PendingApprovalInfo[]>(response);
+  },
+
+  approveApproval: async (
+    attemptId: string,
+    approvalId: string
+  ): Promise<ApprovalStatus> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/approvals/${approvalId}/approve`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<ApprovalStatus>(response);
+  },
+
+  denyApproval: async (
+    attemptId: string,
+    approvalId: string,
+    data: DenyApprovalRequest
+  ): Promise<ApprovalStatus> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/approvals/${approvalId}/deny`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ApprovalStatus>(response);
+  },
+
+  getProposedTasks: async (attemptId: string): Promise<ProposedTask[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/proposed-tasks`
+    );
+    return handleApiResponse<ProposedTask[]>(response);
+  },
+
+  acceptProposedTask: async (
+    attemptId: string,
+    proposalId: string,
+    data: AcceptProposedTaskRequest = { title: null, description: null }
+  ): Promise<ProposedTask> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/proposed-tasks/${proposalId}/accept`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ProposedTask>(response);
+  },
+
+  rejectProposedTask: async (
+    attemptId: string,
+    proposalId: string
+  ): Promise<ProposedTask> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/proposed-tasks/${proposalId}/reject`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<ProposedTask>(response);
+  },
+
+  getDiffComments: async (attemptId: string): Promise<DiffComment[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/diff-comments`
+    );
+    return handleApiResponse<DiffComment[]>(response);
+  },
+
+  createDiffComment: async (
+    attemptId: string,
+    data: CreateDiffComment
+  ): Promise<DiffComment> => {
     const response = await makeRequest(
-      `/api/task-attempts/${attemptId}/run-agent-setup`,
+      `/api/task-attempts/${attemptId}/diff-comments`,
       {
         method: 'POST',
         body: JSON.stringify(data),
       }
     );
-    return handleApiResponse<RunAgentSetupResponse>(response);
+    return handleApiResponse<DiffComment>(response);
   },
 
-  openEditor: async (
+  updateDiffComment: async (
     attemptId: string,
-    data: OpenEditorRequest
-  ): Promise<OpenEditorResponse> => {
+    commentId: string,
+    data: UpdateDiffComment
+  ): Promise<DiffComment> => {
     const response = await makeRequest(
-      `/api/task-attempts/${attemptId}/open-editor`,
+      `/api/task-attempts/${attemptId}/diff-comments/${commentId}`,
       {
-        method: 'POST',
+        method: 'PUT',
         body: JSON.stringify(data),
       }
     );
-    return handleApiResponse<OpenEditorResponse>(response);
+    return handleApiResponse<DiffComment>(response);
   },
 
-  getBranchStatus: async (attemptId: string): Promise<RepoBranchStatus[]> => {
+  deleteDiffComment: async (
+    attemptId: string,
+    commentId: string
+  ): Promise<void> => {
     const response = await makeRequest(
-      `/api/task-attempts/${attemptId}/branch-status`
+      `/api/task-attempts/${attemptId}/diff-comments/${commentId}`,
+      { method: 'DELETE' }
     );
-    return handleApiResponse<RepoBranchStatus[]>(response);
+    return handleApiResponse<void>(response);
   },
 
-  getRepos: async (attemptId: string): Promise<RepoWithTargetBranch[]> => {
-    const response = await makeRequest(`/api/task-attempts/${attemptId}/repos`);
-    return handleApiResponse<RepoWithTargetBranch[]>(response);
+  getReviewPrompt: async (attemptId: string): Promise<string | null> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/diff-comments/prompt`
+    );
+    return handleApiResponse<string | null>(response);
   },
 
   merge: async (
@@ -992,6 +2366,96 @@ export const attemptsApi = {
     return handleApiResponseAsResult<string, CreatePrError>(response);
   },
 
+  createGitLabMR: async (
+    attemptId: string,
+    data: CreateGitLabMrRequest
+  ): Promise<Result<GitLabMergeRequest, CreatePrError>> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/gitlab/mr`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponseAsResult<GitLabMergeRequest, CreatePrError>(
+      response
+    );
+  },
+
+  getGitLabMRs: async (attemptId: string): Promise<GitLabMergeRequest[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/gitlab/mr`
+    );
+    return handleApiResponse<GitLabMergeRequest[]>(response);
+  },
+
+  createPRSet: async (
+    attemptId: string,
+    data: CreatePrSetRequest
+  ): Promise<Result<PrSetStatus, CreatePrSetError>> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/pr-set`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponseAsResult<PrSetStatus, CreatePrSetError>(response);
+  },
+
+  getPRSet: async (attemptId: string): Promise<PrSetStatus> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/pr-set`
+    );
+    return handleApiResponse<PrSetStatus>(response);
+  },
+
+  getPullRequests: async (
+    attemptId: string
+  ): Promise<WorkspacePullRequest[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/pull-requests`
+    );
+    return handleApiResponse<WorkspacePullRequest[]>(response);
+  },
+
+  refreshPullRequests: async (
+    attemptId: string
+  ): Promise<WorkspacePullRequest[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/pull-requests/refresh`,
+      {
+        method: 'POST',
+      }
+    );
+    return handleApiResponse<WorkspacePullRequest[]>(response);
+  },
+
+  createBitbucketPR: async (
+    attemptId: string,
+    data: CreateBitbucketPrRequest
+  ): Promise<Result<BitbucketPullRequest, CreatePrError>> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/bitbucket/pr`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponseAsResult<BitbucketPullRequest, CreatePrError>(
+      response
+    );
+  },
+
+  getBitbucketPRs: async (
+    attemptId: string
+  ): Promise<BitbucketPullRequest[]> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/bitbucket/pr`
+    );
+    return handleApiResponse<BitbucketPullRequest[]>(response);
+  },
+
   startDevServer: async (attemptId: string): Promise<void> => {
     const response = await makeRequest(
       `/api/task-attempts/${attemptId}/start-dev-server`,
@@ -1049,6 +2513,25 @@ export const attemptsApi = {
     );
     return handleApiResponse<PrCommentsResponse>(response);
   },
+
+  getDiffSummary: async (attemptId: string): Promise<DiffSizeSummary> => {
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/diff/summary`
+    );
+    return handleApiResponse<DiffSizeSummary>(response);
+  },
+
+  getDiffFile: async (
+    attemptId: string,
+    repoId: string,
+    path: string
+  ): Promise<Diff> => {
+    const params = new URLSearchParams({ repo_id: repoId, path });
+    const response = await makeRequest(
+      `/api/task-attempts/${attemptId}/diff/file?${params.toString()}`
+    );
+    return handleApiResponse<Diff>(response);
+  },
 };
 
 // Execution Process APIs
@@ -1115,6 +2598,28 @@ export const repoApi = {
     return handleApiResponse<GitBranch[]>(response);
   },
 
+  /** Branches vibe-kanban created in the repo for attempts */
+  getManagedBranches: async (
+    repoId: string
+  ): Promise<ManagedBranchStatus[]> => {
+    const response = await makeRequest(
+      `/api/repos/${repoId}/managed-branches`
+    );
+    return handleApiResponse<ManagedBranchStatus[]>(response);
+  },
+
+  /** Point a repo at the new location of its moved checkout */
+  relocate: async (
+    repoId: string,
+    data: RelocateRepoRequest
+  ): Promise<Repo> => {
+    const response = await makeRequest(`/api/repos/${repoId}/relocate`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<Repo>(response);
+  },
+
   init: async (data: {
     parent_path: string;
     folder_name: string;
@@ -1125,6 +2630,63 @@ export const repoApi = {
     });
     return handleApiResponse<Repo>(response);
   },
+
+  getPathRules: async (repoId: string): Promise<RepoPathRule[]> => {
+    const response = await makeRequest(`/api/repos/${repoId}/path-rules`);
+    return handleApiResponse<RepoPathRule[]>(response);
+  },
+
+  createPathRule: async (
+    repoId: string,
+    data: CreateRepoPathRule
+  ): Promise<RepoPathRule> => {
+    const response = await makeRequest(`/api/repos/${repoId}/path-rules`, {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<RepoPathRule>(response);
+  },
+
+  deletePathRule: async (repoId: string, ruleId: string): Promise<void> => {
+    const response = await makeRequest(
+      `/api/repos/${repoId}/path-rules/${ruleId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+};
+
+export const projectGroupsApi = {
+  list: async (): Promise<ProjectGroup[]> => {
+    const response = await makeRequest('/api/project-groups');
+    return handleApiResponse<ProjectGroup[]>(response);
+  },
+
+  create: async (data: CreateProjectGroup): Promise<ProjectGroup> => {
+    const response = await makeRequest('/api/project-groups', {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<ProjectGroup>(response);
+  },
+
+  update: async (
+    groupId: string,
+    data: UpdateProjectGroup
+  ): Promise<ProjectGroup> => {
+    const response = await makeRequest(`/api/project-groups/${groupId}`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<ProjectGroup>(response);
+  },
+
+  delete: async (groupId: string): Promise<void> => {
+    const response = await makeRequest(`/api/project-groups/${groupId}`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
 };
 
 // Config APIs (backwards compatible)
@@ -1586,3 +3148,242 @@ export const queueApi = {
     return handleApiResponse<QueueStatus>(response);
   },
 };
+
+// Admin APIs
+export const adminApi = {
+  getStats: async (): Promise<StorageStats> => {
+    const response = await makeRequest('/api/admin/stats');
+    return handleApiResponse<StorageStats>(response);
+  },
+
+  vacuum: async (): Promise<VacuumResponse> => {
+    const response = await makeRequest('/api/admin/vacuum', {
+      method: 'POST',
+    });
+    return handleApiResponse<VacuumResponse>(response);
+  },
+
+  getEventBusStatus: async (): Promise<EventBusStatus> => {
+    const response = await makeRequest('/api/admin/event-bus');
+    return handleApiResponse<EventBusStatus>(response);
+  },
+
+  getExecutorSlotsStatus: async (): Promise<ExecutorSlotsStatus> => {
+    const response = await makeRequest('/api/admin/executor-slots');
+    return handleApiResponse<ExecutorSlotsStatus>(response);
+  },
+
+  getMissingWorkspaces: async (): Promise<WorkspaceDirectory[]> => {
+    const response = await makeRequest('/api/admin/workspaces/missing');
+    return handleApiResponse<WorkspaceDirectory[]>(response);
+  },
+
+  reconcileWorkspaces: async (
+    data: ReconcileWorkspacesRequest
+  ): Promise<ReconcileWorkspacesResponse> => {
+    const response = await makeRequest('/api/admin/workspaces/reconcile', {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<ReconcileWorkspacesResponse>(response);
+  },
+
+  getRecoveryReport: async (): Promise<RecoveryReport> => {
+    const response = await makeRequest('/api/admin/recovery-report');
+    return handleApiResponse<RecoveryReport>(response);
+  },
+
+  fixRecoveryReport: async (
+    data: RecoveryFix
+  ): Promise<RecoveryFixResponse> => {
+    const response = await makeRequest('/api/admin/recovery-report/fix', {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<RecoveryFixResponse>(response);
+  },
+};
+
+// Kiosk token APIs (read-only wallboard access)
+export const kioskTokensApi = {
+  list: async (): Promise<KioskToken[]> => {
+    const response = await makeRequest('/api/admin/kiosk-tokens');
+    return handleApiResponse<KioskToken[]>(response);
+  },
+
+  create: async (data: CreateKioskToken): Promise<CreatedKioskToken> => {
+    const response = await makeRequest('/api/admin/kiosk-tokens', {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<CreatedKioskToken>(response);
+  },
+
+  revoke: async (id: string): Promise<void> => {
+    const response = await makeRequest(`/api/admin/kiosk-tokens/${id}`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+};
+
+// REST hook subscription APIs
+export const restHooksApi = {
+  list: async (): Promise<RestHookSubscription[]> => {
+    const response = await makeRequest('/api/hooks');
+    return handleApiResponse<RestHookSubscription[]>(response);
+  },
+
+  subscribe: async (
+    data: CreateRestHookSubscription
+  ): Promise<RestHookSubscription> => {
+    const response = await makeRequest('/api/hooks', {
+      method: 'POST',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<RestHookSubscription>(response);
+  },
+
+  unsubscribe: async (id: string): Promise<void> => {
+    const response = await makeRequest(`/api/hooks/${id}`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+};
+
+// Notification inbox APIs
+export const notificationsApi = {
+  list: async (params?: {
+    unread_only?: boolean;
+    limit?: number;
+  }): Promise<NotificationListResponse> => {
+    const query = new URLSearchParams();
+    if (params?.unread_only) query.set('unread_only', 'true');
+    if (params?.limit) query.set('limit', String(params.limit));
+    const queryString = query.toString();
+    const response = await makeRequest(
+      `/api/notifications${queryString ? `?${queryString}` : ''}`
+    );
+    return handleApiResponse<NotificationListResponse>(response);
+  },
+
+  getUnreadCount: async (): Promise<UnreadCountResponse> => {
+    const response = await makeRequest('/api/notifications/unread-count');
+    return handleApiResponse<UnreadCountResponse>(response);
+  },
+
+  markRead: async (notificationId: string): Promise<UnreadCountResponse> => {
+    const response = await makeRequest(
+      `/api/notifications/${notificationId}/read`,
+      { method: 'POST' }
+    );
+    return handleApiResponse<UnreadCountResponse>(response);
+  },
+
+  markAllRead: async (): Promise<UnreadCountResponse> => {
+    const response = await makeRequest('/api/notifications/read-all', {
+      method: 'POST',
+    });
+    return handleApiResponse<UnreadCountResponse>(response);
+  },
+};
+
+// Project instruction profiles
+export const projectInstructionsApi = {
+  list: async (projectId: string): Promise<ProjectInstruction[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/instructions`
+    );
+    return handleApiResponse<ProjectInstruction[]>(response);
+  },
+
+  create: async (
+    projectId: string,
+    data: CreateProjectInstruction
+  ): Promise<ProjectInstruction> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/instructions`,
+      {
+        method: 'POST',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ProjectInstruction>(response);
+  },
+
+  update: async (
+    projectId: string,
+    instructionId: string,
+    data: UpdateProjectInstruction
+  ): Promise<ProjectInstruction> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/instructions/${instructionId}`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(data),
+      }
+    );
+    return handleApiResponse<ProjectInstruction>(response);
+  },
+
+  delete: async (projectId: string, instructionId: string): Promise<void> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/instructions/${instructionId}`,
+      { method: 'DELETE' }
+    );
+    return handleApiResponse<void>(response);
+  },
+
+  listVersions: async (
+    projectId: string,
+    instructionId: string
+  ): Promise<ProjectInstructionVersion[]> => {
+    const response = await makeRequest(
+      `/api/projects/${projectId}/instructions/${instructionId}/versions`
+    );
+    return handleApiResponse<ProjectInstructionVersion[]>(response);
+  },
+
+  getForTask: async (taskId: string): Promise<TaskInstruction | null> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/instruction`);
+    return handleApiResponse<TaskInstruction | null>(response);
+  },
+
+  setForTask: async (
+    taskId: string,
+    data: SetTaskInstruction
+  ): Promise<TaskInstruction> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/instruction`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<TaskInstruction>(response);
+  },
+
+  clearForTask: async (taskId: string): Promise<void> => {
+    const response = await makeRequest(`/api/tasks/${taskId}/instruction`, {
+      method: 'DELETE',
+    });
+    return handleApiResponse<void>(response);
+  },
+};
+
+// Capacity Planning APIs
+export const capacityApi = {
+  getUserCapacity: async (userId: string): Promise<UserCapacity | null> => {
+    const response = await makeRequest(`/api/users/${userId}/capacity`);
+    return handleApiResponse<UserCapacity | null>(response);
+  },
+
+  setUserCapacity: async (
+    userId: string,
+    data: SetUserCapacity
+  ): Promise<UserCapacity> => {
+    const response = await makeRequest(`/api/users/${userId}/capacity`, {
+      method: 'PUT',
+      body: JSON.stringify(data),
+    });
+    return handleApiResponse<UserCapacity>(response);
+  },
+};
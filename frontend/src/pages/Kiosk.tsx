@@ -0,0 +1,32 @@
+import { useEffect } from 'react';
+import { useSearchParams } from 'react-router-dom';
+import { Loader } from '@/components/ui/loader';
+
+/**
+ * Entry point for wallboard displays: `/kiosk?token=vkk_...&project=<id>`.
+ * Kiosk tokens are long-lived and read-only, so there is no refresh token to
+ * keep rotating; the page just stores the token and reloads into the board.
+ */
+export function Kiosk() {
+  const [searchParams] = useSearchParams();
+
+  useEffect(() => {
+    const token = searchParams.get('token');
+    const project = searchParams.get('project');
+    if (!token) {
+      window.location.replace('/login');
+      return;
+    }
+
+    localStorage.setItem('auth_access_token', token);
+    localStorage.removeItem('auth_refresh_token');
+    localStorage.removeItem('auth_user');
+    window.location.replace(project ? `/projects/${project}/tasks` : '/');
+  }, [searchParams]);
+
+  return (
+    <div className="min-h-screen bg-background flex items-center justify-center">
+      <Loader message="Loading..." size={32} />
+    </div>
+  );
+}
@@ -461,6 +461,66 @@ export function GeneralSettings() {
               )}
             </p>
           </div>
+          <div className="flex items-center space-x-2">
+            <Checkbox
+              id="keep-branches-after-cleanup"
+              checked={draft?.keep_branches_after_cleanup ?? false}
+              onCheckedChange={(checked: boolean) =>
+                updateDraft({ keep_branches_after_cleanup: checked })
+              }
+            />
+            <div className="space-y-0.5">
+              <Label
+                htmlFor="keep-branches-after-cleanup"
+                className="cursor-pointer"
+              >
+                {t('settings.general.git.keepBranches.label')}
+              </Label>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.general.git.keepBranches.helper')}
+              </p>
+            </div>
+          </div>
+          <div className="flex items-center space-x-2">
+            <Checkbox
+              id="keep-workspace-after-merge"
+              checked={draft?.keep_workspace_after_merge ?? false}
+              onCheckedChange={(checked: boolean) =>
+                updateDraft({ keep_workspace_after_merge: checked })
+              }
+            />
+            <div className="space-y-0.5">
+              <Label
+                htmlFor="keep-workspace-after-merge"
+                className="cursor-pointer"
+              >
+                {t('settings.general.git.keepWorkspaceAfterMerge.label')}
+              </Label>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.general.git.keepWorkspaceAfterMerge.helper')}
+              </p>
+            </div>
+          </div>
+          <div className="flex items-center space-x-2">
+            <Checkbox
+              id="delete-remote-branch-after-merge"
+              checked={draft?.delete_remote_branch_after_merge ?? false}
+              onCheckedChange={(checked: boolean) =>
+                updateDraft({ delete_remote_branch_after_merge: checked })
+              }
+            />
+            <div className="space-y-0.5">
+              <Label
+                htmlFor="delete-remote-branch-after-merge"
+                className="cursor-pointer"
+              >
+                {t('settings.general.git.deleteRemoteBranchAfterMerge.label')}
+              </Label>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.general.git.deleteRemoteBranchAfterMerge.helper')}
+              </p>
+            </div>
+          </div>
         </CardContent>
       </Card>
 
@@ -662,6 +722,278 @@ export function GeneralSettings() {
         </CardContent>
       </Card>
 
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('settings.general.network.title')}</CardTitle>
+          <CardDescription>
+            {t('settings.general.network.description')}
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="space-y-2">
+            <Label htmlFor="http-proxy">
+              {t('settings.general.network.proxy.label')}
+            </Label>
+            <Input
+              id="http-proxy"
+              type="text"
+              placeholder={t('settings.general.network.proxy.placeholder')}
+              value={draft?.network.http_proxy ?? ''}
+              onChange={(e) =>
+                draft &&
+                updateDraft({
+                  network: {
+                    ...draft.network,
+                    http_proxy: e.target.value.trim() || null,
+                  },
+                })
+              }
+            />
+            <p className="text-sm text-muted-foreground">
+              {t('settings.general.network.proxy.helper')}
+            </p>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="ca-certificates-path">
+              {t('settings.general.network.caCertificates.label')}
+            </Label>
+            <Input
+              id="ca-certificates-path"
+              type="text"
+              placeholder={t(
+                'settings.general.network.caCertificates.placeholder'
+              )}
+              value={draft?.network.ca_certificates_path ?? ''}
+              onChange={(e) =>
+                draft &&
+                updateDraft({
+                  network: {
+                    ...draft.network,
+                    ca_certificates_path: e.target.value.trim() || null,
+                  },
+                })
+              }
+            />
+            <p className="text-sm text-muted-foreground">
+              {t('settings.general.network.caCertificates.helper')}
+            </p>
+          </div>
+        </CardContent>
+      </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('settings.general.autoStart.title')}</CardTitle>
+          <CardDescription>
+            {t('settings.general.autoStart.description')}
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="space-y-2">
+            <Label htmlFor="auto-start-slots">
+              {t('settings.general.autoStart.slots.label')}
+            </Label>
+            <Input
+              id="auto-start-slots"
+              type="number"
+              min={0}
+              value={draft?.auto_start_slots ?? 0}
+              onChange={(e) =>
+                updateDraft({
+                  auto_start_slots: Math.max(
+                    0,
+                    Math.floor(Number(e.target.value) || 0)
+                  ),
+                })
+              }
+            />
+            <p className="text-sm text-muted-foreground">
+              {t('settings.general.autoStart.slots.helper')}
+            </p>
+          </div>
+          <div className="flex items-center space-x-2">
+            <Checkbox
+              id="one-attempt-per-task"
+              checked={draft?.one_attempt_per_task ?? false}
+              onCheckedChange={(checked: boolean) =>
+                updateDraft({ one_attempt_per_task: checked })
+              }
+            />
+            <div className="space-y-0.5">
+              <Label htmlFor="one-attempt-per-task" className="cursor-pointer">
+                {t('settings.general.autoStart.oneAttemptPerTask.label')}
+              </Label>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.general.autoStart.oneAttemptPerTask.helper')}
+              </p>
+            </div>
+          </div>
+        </CardContent>
+      </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('settings.general.diffSizeGuard.title')}</CardTitle>
+          <CardDescription>
+            {t('settings.general.diffSizeGuard.description')}
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="flex items-center space-x-2">
+            <Checkbox
+              id="diff-size-guard-enabled"
+              checked={draft?.diff_size_guard.enabled ?? true}
+              onCheckedChange={(checked: boolean) =>
+                draft &&
+                updateDraft({
+                  diff_size_guard: {
+                    ...draft.diff_size_guard,
+                    enabled: checked,
+                  },
+                })
+              }
+            />
+            <Label htmlFor="diff-size-guard-enabled" className="cursor-pointer">
+              {t('settings.general.diffSizeGuard.enabled')}
+            </Label>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="diff-size-guard-max-files">
+              {t('settings.general.diffSizeGuard.maxFiles.label')}
+            </Label>
+            <Input
+              id="diff-size-guard-max-files"
+              type="number"
+              min={1}
+              value={draft?.diff_size_guard.max_files ?? 300}
+              disabled={!draft?.diff_size_guard.enabled}
+              onChange={(e) =>
+                draft &&
+                updateDraft({
+                  diff_size_guard: {
+                    ...draft.diff_size_guard,
+                    max_files: Math.max(
+                      1,
+                      Math.floor(Number(e.target.value) || 0)
+                    ),
+                  },
+                })
+              }
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="diff-size-guard-max-megabytes">
+              {t('settings.general.diffSizeGuard.maxMegabytes.label')}
+            </Label>
+            <Input
+              id="diff-size-guard-max-megabytes"
+              type="number"
+              min={1}
+              value={Math.round(
+                (draft?.diff_size_guard.max_bytes ?? 5 * 1024 * 1024) /
+                  (1024 * 1024)
+              )}
+              disabled={!draft?.diff_size_guard.enabled}
+              onChange={(e) =>
+                draft &&
+                updateDraft({
+                  diff_size_guard: {
+                    ...draft.diff_size_guard,
+                    max_bytes:
+                      Math.max(1, Math.floor(Number(e.target.value) || 0)) *
+                      1024 *
+                      1024,
+                  },
+                })
+              }
+            />
+            <p className="text-sm text-muted-foreground">
+              {t('settings.general.diffSizeGuard.maxMegabytes.helper')}
+            </p>
+          </div>
+        </CardContent>
+      </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('settings.general.issueSync.title')}</CardTitle>
+          <CardDescription>
+            {t('settings.general.issueSync.description')}
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="flex items-center space-x-2">
+            <Checkbox
+              id="issue-sync-enabled"
+              checked={draft?.issue_sync_schedule.enabled ?? true}
+              onCheckedChange={(checked: boolean) =>
+                draft &&
+                updateDraft({
+                  issue_sync_schedule: {
+                    ...draft.issue_sync_schedule,
+                    enabled: checked,
+                  },
+                })
+              }
+            />
+            <Label htmlFor="issue-sync-enabled" className="cursor-pointer">
+              {t('settings.general.issueSync.enabled')}
+            </Label>
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="issue-sync-interval">
+              {t('settings.general.issueSync.intervalMinutes.label')}
+            </Label>
+            <Input
+              id="issue-sync-interval"
+              type="number"
+              min={1}
+              value={draft?.issue_sync_schedule.interval_minutes ?? 15}
+              disabled={!draft?.issue_sync_schedule.enabled}
+              onChange={(e) =>
+                draft &&
+                updateDraft({
+                  issue_sync_schedule: {
+                    ...draft.issue_sync_schedule,
+                    interval_minutes: Math.max(
+                      1,
+                      Math.floor(Number(e.target.value) || 0)
+                    ),
+                  },
+                })
+              }
+            />
+          </div>
+          <div className="space-y-2">
+            <Label htmlFor="issue-sync-jitter">
+              {t('settings.general.issueSync.jitterSeconds.label')}
+            </Label>
+            <Input
+              id="issue-sync-jitter"
+              type="number"
+              min={0}
+              value={draft?.issue_sync_schedule.jitter_seconds ?? 60}
+              disabled={!draft?.issue_sync_schedule.enabled}
+              onChange={(e) =>
+                draft &&
+                updateDraft({
+                  issue_sync_schedule: {
+                    ...draft.issue_sync_schedule,
+                    jitter_seconds: Math.max(
+                      0,
+                      Math.floor(Number(e.target.value) || 0)
+                    ),
+                  },
+                })
+              }
+            />
+            <p className="text-sm text-muted-foreground">
+              {t('settings.general.issueSync.jitterSeconds.helper')}
+            </p>
+          </div>
+        </CardContent>
+      </Card>
+
       <Card>
         <CardHeader>
           <CardTitle>{t('settings.general.taskTemplates.title')}</CardTitle>
@@ -23,7 +23,7 @@ import { Input } from '@/components/ui/input';
 import { Checkbox } from '@/components/ui/checkbox';
 import { Switch } from '@/components/ui/switch';
 import { Alert, AlertDescription } from '@/components/ui/alert';
-import { Loader2, Plus, Trash2, Github, GitlabIcon, RefreshCw, ExternalLink, Zap } from 'lucide-react';
+import { Loader2, Plus, Trash2, CircleCheckBig, Github, GitlabIcon, GitFork, GitPullRequest, LayoutList, ListChecks, ListTodo, NotebookTabs, RefreshCw, ExternalLink, Flag, Zap } from 'lucide-react';
 import { useProjects } from '@/hooks/useProjects';
 import { useProjectMutations } from '@/hooks/useProjectMutations';
 import { useScriptPlaceholders } from '@/hooks/useScriptPlaceholders';
@@ -31,14 +31,37 @@ import { CopyFilesField } from '@/components/projects/CopyFilesField';
 import { AutoExpandingTextarea } from '@/components/ui/auto-expanding-textarea';
 import { RepoPickerDialog } from '@/components/dialogs/shared/RepoPickerDialog';
 import { projectsApi, GitHubIssue, GitLabIssue, VortexIssue } from '@/lib/api';
+import type {
+  IssueConnection,
+  IssueConnectionSettings,
+  ProviderIssue,
+  ShortcutWorkflow,
+  BitbucketIssue,
+  GiteaIssue,
+  LinearTeam,
+} from 'shared/types';
 import { repoBranchKeys } from '@/hooks/useRepoBranches';
-import type { Project, ProjectRepo, Repo, UpdateProject } from 'shared/types';
+import type {
+  LabelSyncDirection,
+  Project,
+  ProjectRepo,
+  Repo,
+  UpdateProject,
+  VortexOAuthStatus,
+  SyncRun,
+  VortexProject,
+} from 'shared/types';
 
 interface ProjectFormState {
   name: string;
   dev_script: string;
   dev_script_working_dir: string;
   default_agent_working_dir: string;
+  comment_on_linked_issues: boolean;
+  confirm_auto_start: boolean;
+  wait_for_ci: boolean;
+  done_on_merge: boolean;
+  triage_synced_issues: boolean;
 }
 
 interface RepoScriptsFormState {
@@ -46,6 +69,7 @@ interface RepoScriptsFormState {
   parallel_setup_script: boolean;
   cleanup_script: string;
   copy_files: string;
+  default_target_branch: string;
 }
 
 interface GitHubFormState {
@@ -53,20 +77,118 @@ interface GitHubFormState {
   github_token: string;
   github_sync_enabled: boolean;
   github_sync_labels: string;
+  github_sync_pull_requests: boolean;
+  github_label_sync: LabelSyncDirection;
 }
 
 interface GitLabFormState {
+  gitlab_base_url: string;
   gitlab_project_url: string;
   gitlab_token: string;
   gitlab_sync_enabled: boolean;
   gitlab_sync_labels: string;
 }
 
+interface GiteaFormState {
+  gitea_base_url: string;
+  gitea_repo: string;
+  gitea_token: string;
+  gitea_sync_enabled: boolean;
+  gitea_sync_labels: string;
+}
+
+type ConnectionSettings<P extends IssueConnectionSettings['provider']> =
+  Extract<IssueConnectionSettings, { provider: P }>;
+
+// Settings of the connection to `provider` among a project's connections
+function connectionSettings<P extends IssueConnectionSettings['provider']>(
+  connections: IssueConnection[],
+  provider: P
+): ConnectionSettings<P> | null {
+  const settings = connections.find(
+    (connection) => connection.settings.provider === provider
+  )?.settings;
+  return (settings as ConnectionSettings<P> | undefined) ?? null;
+}
+
+interface AzureFormState {
+  organization_url: string;
+  azure_project: string;
+  pat: string;
+  wiql_filter: string;
+}
+
+interface NotionFormState {
+  database_id: string;
+  token: string;
+  status_property: string;
+  description_property: string;
+}
+
+const EMPTY_NOTION_DRAFT: NotionFormState = {
+  database_id: '',
+  token: '',
+  status_property: 'Status',
+  description_property: 'Description',
+};
+
+interface AsanaFormState {
+  project_gid: string;
+  token: string;
+}
+
+const EMPTY_ASANA_DRAFT: AsanaFormState = {
+  project_gid: '',
+  token: '',
+};
+
+interface ClickUpFormState {
+  list_id: string;
+  token: string;
+  tag_filter: string;
+}
+
+const EMPTY_CLICKUP_DRAFT: ClickUpFormState = {
+  list_id: '',
+  token: '',
+  tag_filter: '',
+};
+
+interface ShortcutFormState {
+  token: string;
+  labels: string;
+  review_state_id: string;
+}
+
+// Select value for detecting the review state from each story's workflow
+const SHORTCUT_AUTO_REVIEW_STATE = 'auto';
+
+const EMPTY_SHORTCUT_DRAFT: ShortcutFormState = {
+  token: '',
+  labels: '',
+  review_state_id: SHORTCUT_AUTO_REVIEW_STATE,
+};
+
+interface LinearFormState {
+  api_key: string;
+  team_id: string;
+  sync_labels: string;
+  status_sync_enabled: boolean;
+}
+
+interface BitbucketFormState {
+  bitbucket_workspace: string;
+  bitbucket_repo_slug: string;
+  bitbucket_username: string;
+  bitbucket_app_password: string;
+}
+
 interface VortexFormState {
   vortex_project_id: string;
   vortex_token: string;
   vortex_sync_enabled: boolean;
   vortex_sync_labels: string;
+  vortex_two_way_sync: boolean;
 }
 
 function projectToFormState(project: Project): ProjectFormState {
@@ -75,6 +197,11 @@ function projectToFormState(project: Project): ProjectFormState {
     dev_script: project.dev_script ?? '',
     dev_script_working_dir: project.dev_script_working_dir ?? '',
     default_agent_working_dir: project.default_agent_working_dir ?? '',
+    comment_on_linked_issues: project.comment_on_linked_issues,
+    confirm_auto_start: project.confirm_auto_start,
+    wait_for_ci: project.wait_for_ci,
+    done_on_merge: project.done_on_merge,
+    triage_synced_issues: project.triage_synced_issues,
   };
 }
 
@@ -86,6 +213,7 @@ function projectRepoToScriptsFormState(
     parallel_setup_script: projectRepo?.parallel_setup_script ?? false,
     cleanup_script: projectRepo?.cleanup_script ?? '',
     copy_files: projectRepo?.copy_files ?? '',
+    default_target_branch: projectRepo?.default_target_branch ?? '',
   };
 }
 
@@ -141,6 +269,8 @@ export function ProjectSettings() {
     github_token: '',
     github_sync_enabled: false,
     github_sync_labels: '',
+    github_sync_pull_requests: false,
+    github_label_sync: 'import',
   });
   const [savingGithub, setSavingGithub] = useState(false);
   const [githubSuccess, setGithubSuccess] = useState(false);
@@ -148,11 +278,14 @@ export function ProjectSettings() {
   const [githubIssues, setGithubIssues] = useState<GitHubIssue[]>([]);
   const [loadingIssues, setLoadingIssues] = useState(false);
   const [syncingIssues, setSyncingIssues] = useState(false);
+  const [restoreIssueNumber, setRestoreIssueNumber] = useState('');
+  const [restoringIssue, setRestoringIssue] = useState(false);
   const [showToken, setShowToken] = useState(false);
   const [hasExistingToken, setHasExistingToken] = useState(false);
 
   // GitLab integration state
   const [gitlabDraft, setGitlabDraft] = useState<GitLabFormState>({
+    gitlab_base_url: '',
     gitlab_project_url: '',
     gitlab_token: '',
     gitlab_sync_enabled: false,
@@ -167,11 +300,131 @@ export function ProjectSettings() {
   const [showGitlabToken, setShowGitlabToken] = useState(false);
   const [hasExistingGitlabToken, setHasExistingGitlabToken] = useState(false);
 
+  // Gitea / Forgejo integration state
+  const [giteaDraft, setGiteaDraft] = useState<GiteaFormState>({
+    gitea_base_url: '',
+    gitea_repo: '',
+    gitea_token: '',
+    gitea_sync_enabled: false,
+    gitea_sync_labels: '',
+  });
+  const [savingGitea, setSavingGitea] = useState(false);
+  const [giteaSuccess, setGiteaSuccess] = useState(false);
+  const [giteaError, setGiteaError] = useState<string | null>(null);
+  const [giteaIssues, setGiteaIssues] = useState<GiteaIssue[]>([]);
+  const [loadingGiteaIssues, setLoadingGiteaIssues] = useState(false);
+  const [syncingGiteaIssues, setSyncingGiteaIssues] = useState(false);
+  const [showGiteaToken, setShowGiteaToken] = useState(false);
+  const [hasExistingGiteaToken, setHasExistingGiteaToken] = useState(false);
+
+  // Azure DevOps integration state
+  const [azureDraft, setAzureDraft] = useState<AzureFormState>({
+    organization_url: '',
+    azure_project: '',
+    pat: '',
+    wiql_filter: '',
+  });
+  const [azureConnection, setAzureConnection] =
+    useState<ConnectionSettings<'Azure DevOps'> | null>(null);
+  const [savingAzure, setSavingAzure] = useState(false);
+  const [azureSuccess, setAzureSuccess] = useState(false);
+  const [azureError, setAzureError] = useState<string | null>(null);
+  const [azureWorkItems, setAzureWorkItems] = useState<ProviderIssue[]>([]);
+  const [loadingAzureWorkItems, setLoadingAzureWorkItems] = useState(false);
+  const [syncingAzureWorkItems, setSyncingAzureWorkItems] = useState(false);
+
+  // Notion integration state
+  const [notionDraft, setNotionDraft] =
+    useState<NotionFormState>(EMPTY_NOTION_DRAFT);
+  const [notionConnection, setNotionConnection] =
+    useState<ConnectionSettings<'Notion'> | null>(null);
+  const [savingNotion, setSavingNotion] = useState(false);
+  const [notionSuccess, setNotionSuccess] = useState(false);
+  const [notionError, setNotionError] = useState<string | null>(null);
+  const [notionRows, setNotionRows] = useState<ProviderIssue[]>([]);
+  const [loadingNotionRows, setLoadingNotionRows] = useState(false);
+  const [syncingNotionRows, setSyncingNotionRows] = useState(false);
+
+  // Asana integration state
+  const [asanaDraft, setAsanaDraft] =
+    useState<AsanaFormState>(EMPTY_ASANA_DRAFT);
+  const [asanaConnection, setAsanaConnection] =
+    useState<ConnectionSettings<'Asana'> | null>(null);
+  const [savingAsana, setSavingAsana] = useState(false);
+  const [asanaSuccess, setAsanaSuccess] = useState(false);
+  const [asanaError, setAsanaError] = useState<string | null>(null);
+  const [asanaTasks, setAsanaTasks] = useState<ProviderIssue[]>([]);
+  const [loadingAsanaTasks, setLoadingAsanaTasks] = useState(false);
+  const [syncingAsanaTasks, setSyncingAsanaTasks] = useState(false);
+
+  // ClickUp integration state
+  const [clickupDraft, setClickUpDraft] =
+    useState<ClickUpFormState>(EMPTY_CLICKUP_DRAFT);
+  const [clickupConnection, setClickUpConnection] =
+    useState<ConnectionSettings<'ClickUp'> | null>(null);
+  const [savingClickUp, setSavingClickUp] = useState(false);
+  const [clickupSuccess, setClickUpSuccess] = useState(false);
+  const [clickupError, setClickUpError] = useState<string | null>(null);
+  const [clickupTasks, setClickUpTasks] = useState<ProviderIssue[]>([]);
+  const [loadingClickUpTasks, setLoadingClickUpTasks] = useState(false);
+  const [syncingClickUpTasks, setSyncingClickUpTasks] = useState(false);
+
+  // Shortcut integration state
+  const [shortcutDraft, setShortcutDraft] =
+    useState<ShortcutFormState>(EMPTY_SHORTCUT_DRAFT);
+  const [shortcutConnection, setShortcutConnection] =
+    useState<ConnectionSettings<'Shortcut'> | null>(null);
+  const [shortcutWorkflows, setShortcutWorkflows] = useState<
+    ShortcutWorkflow[]
+  >([]);
+  const [savingShortcut, setSavingShortcut] = useState(false);
+  const [shortcutSuccess, setShortcutSuccess] = useState(false);
+  const [shortcutError, setShortcutError] = useState<string | null>(null);
+  const [shortcutStories, setShortcutStories] = useState<ProviderIssue[]>([]);
+  const [loadingShortcutStories, setLoadingShortcutStories] = useState(false);
+  const [syncingShortcutStories, setSyncingShortcutStories] = useState(false);
+
+  // Linear integration state
+  const [linearDraft, setLinearDraft] = useState<LinearFormState>({
+    api_key: '',
+    team_id: '',
+    sync_labels: '',
+    status_sync_enabled: true,
+  });
+  const [linearConnection, setLinearConnection] =
+    useState<ConnectionSettings<'Linear'> | null>(null);
+  const [linearTeams, setLinearTeams] = useState<LinearTeam[]>([]);
+  const [loadingLinearTeams, setLoadingLinearTeams] = useState(false);
+  const [savingLinear, setSavingLinear] = useState(false);
+  const [linearSuccess, setLinearSuccess] = useState(false);
+  const [linearError, setLinearError] = useState<string | null>(null);
+  const [linearIssues, setLinearIssues] = useState<ProviderIssue[]>([]);
+  const [loadingLinearIssues, setLoadingLinearIssues] = useState(false);
+  const [syncingLinearIssues, setSyncingLinearIssues] = useState(false);
+
+  // Bitbucket Cloud integration state
+  const [bitbucketDraft, setBitbucketDraft] = useState<BitbucketFormState>({
+    bitbucket_workspace: '',
+    bitbucket_repo_slug: '',
+    bitbucket_username: '',
+    bitbucket_app_password: '',
+  });
+  const [savingBitbucket, setSavingBitbucket] = useState(false);
+  const [bitbucketSuccess, setBitbucketSuccess] = useState(false);
+  const [bitbucketError, setBitbucketError] = useState<string | null>(null);
+  const [bitbucketIssues, setBitbucketIssues] = useState<BitbucketIssue[]>([]);
+  const [loadingBitbucketIssues, setLoadingBitbucketIssues] = useState(false);
+  const [syncingBitbucketIssues, setSyncingBitbucketIssues] = useState(false);
+  const [showBitbucketPassword, setShowBitbucketPassword] = useState(false);
+  const [hasExistingBitbucketPassword, setHasExistingBitbucketPassword] =
+    useState(false);
+
   const [vortexDraft, setVortexDraft] = useState<VortexFormState>({
     vortex_project_id: '',
     vortex_token: '',
     vortex_sync_enabled: false,
     vortex_sync_labels: '',
+    vortex_two_way_sync: false,
   });
   const [savingVortex, setSavingVortex] = useState(false);
   const [vortexSuccess, setVortexSuccess] = useState(false);
@@ -181,6 +434,16 @@ export function ProjectSettings() {
   const [syncingVortexIssues, setSyncingVortexIssues] = useState(false);
   const [showVortexToken, setShowVortexToken] = useState(false);
   const [hasExistingVortexToken, setHasExistingVortexToken] = useState(false);
+  const [vortexOAuth, setVortexOAuth] = useState<VortexOAuthStatus | null>(
+    null
+  );
+  const [connectingVortex, setConnectingVortex] = useState(false);
+  const [vortexProjects, setVortexProjects] = useState<VortexProject[] | null>(
+    null
+  );
+  const [loadingVortexProjects, setLoadingVortexProjects] = useState(false);
+  const [syncRuns, setSyncRuns] = useState<SyncRun[]>([]);
+  const [loadingSyncRuns, setLoadingSyncRuns] = useState(false);
 
   // Get OS-appropriate script placeholders
   const placeholders = useScriptPlaceholders();
@@ -353,6 +616,8 @@ export function ProjectSettings() {
         github_token: '',
         github_sync_enabled: false,
         github_sync_labels: '',
+        github_sync_pull_requests: false,
+        github_label_sync: 'import',
       });
       setGithubIssues([]);
       setHasExistingToken(false);
@@ -367,6 +632,8 @@ export function ProjectSettings() {
           github_token: '',
           github_sync_enabled: config.sync_enabled,
           github_sync_labels: config.sync_labels ?? '',
+          github_sync_pull_requests: config.sync_pull_requests,
+          github_label_sync: config.label_sync,
         });
         setHasExistingToken(config.has_token);
       })
@@ -378,6 +645,7 @@ export function ProjectSettings() {
       .getGitLabConfig(selectedProjectId)
       .then((config) => {
         setGitlabDraft({
+          gitlab_base_url: config.base_url ?? '',
           gitlab_project_url: config.project_url ?? '',
           gitlab_token: '',
           gitlab_sync_enabled: config.sync_enabled,
@@ -389,6 +657,115 @@ export function ProjectSettings() {
         setHasExistingGitlabToken(false);
       });
 
+    projectsApi
+      .getGiteaConfig(selectedProjectId)
+      .then((config) => {
+        setGiteaDraft({
+          gitea_base_url: config.base_url ?? '',
+          gitea_repo: config.repo ?? '',
+          gitea_token: '',
+          gitea_sync_enabled: config.sync_enabled,
+          gitea_sync_labels: config.sync_labels ?? '',
+        });
+        setHasExistingGiteaToken(config.has_token);
+      })
+      .catch(() => {
+        setHasExistingGiteaToken(false);
+      });
+
+    setAzureWorkItems([]);
+    setNotionRows([]);
+    setAsanaTasks([]);
+    setClickUpTasks([]);
+    setShortcutStories([]);
+    setShortcutWorkflows([]);
+    setLinearTeams([]);
+    setLinearIssues([]);
+    projectsApi
+      .listIssueConnections(selectedProjectId)
+      .then((connections) => {
+        const azure = connectionSettings(connections, 'Azure DevOps');
+        setAzureDraft({
+          organization_url: azure?.organization_url ?? '',
+          azure_project: azure?.azure_project ?? '',
+          pat: '',
+          wiql_filter: azure?.wiql_filter ?? '',
+        });
+        setAzureConnection(azure);
+
+        const notion = connectionSettings(connections, 'Notion');
+        setNotionDraft({
+          database_id: notion?.database_id ?? '',
+          token: '',
+          status_property: notion?.status_property ?? 'Status',
+          description_property: notion?.description_property ?? 'Description',
+        });
+        setNotionConnection(notion);
+
+        const asana = connectionSettings(connections, 'Asana');
+        setAsanaDraft({
+          project_gid: asana?.project_gid ?? '',
+          token: '',
+        });
+        setAsanaConnection(asana);
+
+        const clickup = connectionSettings(connections, 'ClickUp');
+        setClickUpDraft({
+          list_id: clickup?.list_id ?? '',
+          token: '',
+          tag_filter: clickup?.tag_filter ?? '',
+        });
+        setClickUpConnection(clickup);
+
+        const shortcut = connectionSettings(connections, 'Shortcut');
+        setShortcutDraft({
+          token: '',
+          labels: shortcut?.labels ?? '',
+          review_state_id:
+            shortcut?.review_state_id?.toString() ??
+            SHORTCUT_AUTO_REVIEW_STATE,
+        });
+        setShortcutConnection(shortcut);
+        if (shortcut) {
+          projectsApi
+            .listShortcutWorkflows(selectedProjectId)
+            .then(setShortcutWorkflows)
+            .catch(() => setShortcutWorkflows([]));
+        }
+
+        const linear = connectionSettings(connections, 'Linear');
+        setLinearDraft({
+          api_key: '',
+          team_id: linear?.team_id ?? '',
+          sync_labels: linear?.sync_labels ?? '',
+          status_sync_enabled: linear?.status_sync_enabled ?? true,
+        });
+        setLinearConnection(linear);
+      })
+      .catch(() => {
+        setAzureConnection(null);
+        setNotionConnection(null);
+        setAsanaConnection(null);
+        setClickUpConnection(null);
+        setShortcutConnection(null);
+        setLinearConnection(null);
+      });
+
+    projectsApi
+      .getBitbucketConfig(selectedProjectId)
+      .then((config) => {
+        setBitbucketDraft({
+          bitbucket_workspace: config.workspace ?? '',
+          bitbucket_repo_slug: config.repo_slug ?? '',
+          bitbucket_username: config.username ?? '',
+          bitbucket_app_password: '',
+        });
+        setHasExistingBitbucketPassword(config.has_credentials);
+      })
+      .catch(() => {
+        setHasExistingBitbucketPassword(false);
+      });
+
     projectsApi
       .getVortexConfig(selectedProjectId)
       .then((config) => {
@@ -397,12 +774,24 @@ export function ProjectSettings() {
           vortex_token: '',
           vortex_sync_enabled: config.sync_enabled,
           vortex_sync_labels: config.sync_labels ?? '',
+          vortex_two_way_sync: config.two_way_sync,
         });
         setHasExistingVortexToken(config.has_token);
       })
       .catch(() => {
         setHasExistingVortexToken(false);
       });
+
+    setVortexProjects(null);
+    projectsApi
+      .getVortexOAuthStatus(selectedProjectId)
+      .then(setVortexOAuth)
+      .catch(() => setVortexOAuth(null));
+
+    projectsApi
+      .getSyncRuns(selectedProjectId)
+      .then(setSyncRuns)
+      .catch(() => setSyncRuns([]));
   }, [selectedProjectId]);
 
   useEffect(() => {
@@ -523,19 +912,11 @@ export function ProjectSettings() {
         dev_script_working_dir: draft.dev_script_working_dir.trim() || null,
         default_agent_working_dir:
           draft.default_agent_working_dir.trim() || null,
-        github_repo_url: null,
-        github_token: null,
-        github_sync_enabled: null,
-        github_sync_labels: null,
-        gitlab_project_url: null,
-        gitlab_token: null,
-        gitlab_sync_enabled: null,
-        gitlab_sync_labels: null,
-        vortex_api_url: null,
-        vortex_project_id: null,
-        vortex_token: null,
-        vortex_sync_enabled: null,
-        vortex_sync_labels: null,
+        comment_on_linked_issues: draft.comment_on_linked_issues,
+        confirm_auto_start: draft.confirm_auto_start,
+        wait_for_ci: draft.wait_for_ci,
+        done_on_merge: draft.done_on_merge,
+        triage_synced_issues: draft.triage_synced_issues,
       };
 
       updateProject.mutate({
@@ -564,6 +945,8 @@ export function ProjectSettings() {
           setup_script: scriptsDraft.setup_script.trim() || null,
           cleanup_script: scriptsDraft.cleanup_script.trim() || null,
           copy_files: scriptsDraft.copy_files.trim() || null,
+          default_target_branch:
+            scriptsDraft.default_target_branch.trim() || null,
           parallel_setup_script: scriptsDraft.parallel_setup_script,
         }
       );
@@ -612,6 +995,38 @@ export function ProjectSettings() {
     setGitlabDraft((prev) => ({ ...prev, ...updates }));
   };
 
+  const updateGiteaDraft = (updates: Partial<GiteaFormState>) => {
+    setGiteaDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateAzureDraft = (updates: Partial<AzureFormState>) => {
+    setAzureDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateNotionDraft = (updates: Partial<NotionFormState>) => {
+    setNotionDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateAsanaDraft = (updates: Partial<AsanaFormState>) => {
+    setAsanaDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateClickUpDraft = (updates: Partial<ClickUpFormState>) => {
+    setClickUpDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateShortcutDraft = (updates: Partial<ShortcutFormState>) => {
+    setShortcutDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateLinearDraft = (updates: Partial<LinearFormState>) => {
+    setLinearDraft((prev) => ({ ...prev, ...updates }));
+  };
+
+  const updateBitbucketDraft = (updates: Partial<BitbucketFormState>) => {
+    setBitbucketDraft((prev) => ({ ...prev, ...updates }));
+  };
+
   const updateVortexDraft = (updates: Partial<VortexFormState>) => {
     setVortexDraft((prev) => ({ ...prev, ...updates }));
   };
@@ -624,24 +1039,15 @@ export function ProjectSettings() {
     setGithubSuccess(false);
 
     try {
+      const githubToken = githubDraft.github_token.trim();
       const updateData: UpdateProject = {
-        name: selectedProject.name,
-        dev_script: selectedProject.dev_script ?? null,
-        dev_script_working_dir: selectedProject.dev_script_working_dir ?? null,
-        default_agent_working_dir: selectedProject.default_agent_working_dir ?? null,
         github_repo_url: githubDraft.github_repo_url.trim() || null,
-        github_token: githubDraft.github_token.trim() || null,
+        // Leaving the token field empty keeps the saved token
+        ...(githubToken && { github_token: githubToken }),
         github_sync_enabled: githubDraft.github_sync_enabled,
         github_sync_labels: githubDraft.github_sync_labels.trim() || null,
-        gitlab_project_url: null,
-        gitlab_token: null,
-        gitlab_sync_enabled: null,
-        gitlab_sync_labels: null,
-        vortex_api_url: null,
-        vortex_project_id: null,
-        vortex_token: null,
-        vortex_sync_enabled: null,
-        vortex_sync_labels: null,
+        github_sync_pull_requests: githubDraft.github_sync_pull_requests,
+        github_label_sync: githubDraft.github_label_sync,
       };
 
       await projectsApi.update(selectedProject.id, updateData);
@@ -696,6 +1102,27 @@ export function ProjectSettings() {
     }
   };
 
+  const handleRestoreIssue = async () => {
+    const issueNumber = parseInt(restoreIssueNumber.replace('#', ''), 10);
+    if (!selectedProjectId || Number.isNaN(issueNumber)) return;
+
+    setRestoringIssue(true);
+    setGithubError(null);
+
+    try {
+      await projectsApi.restoreGitHubIssue(selectedProjectId, issueNumber);
+      setRestoreIssueNumber('');
+      setGithubSuccess(true);
+      setTimeout(() => setGithubSuccess(false), 3000);
+    } catch (err) {
+      setGithubError(
+        err instanceof Error ? err.message : t('settings.projects.githubIntegration.messages.restoreError')
+      );
+    } finally {
+      setRestoringIssue(false);
+    }
+  };
+
   const handleSyncIssues = async () => {
     if (!selectedProjectId) return;
 
@@ -726,24 +1153,14 @@ export function ProjectSettings() {
     setGitlabSuccess(false);
 
     try {
+      const gitlabToken = gitlabDraft.gitlab_token.trim();
       const updateData: UpdateProject = {
-        name: selectedProject.name,
-        dev_script: selectedProject.dev_script ?? null,
-        dev_script_working_dir: selectedProject.dev_script_working_dir ?? null,
-        default_agent_working_dir: selectedProject.default_agent_working_dir ?? null,
-        github_repo_url: null,
-        github_token: null,
-        github_sync_enabled: null,
-        github_sync_labels: null,
+        gitlab_base_url: gitlabDraft.gitlab_base_url.trim() || null,
         gitlab_project_url: gitlabDraft.gitlab_project_url.trim() || null,
-        gitlab_token: gitlabDraft.gitlab_token.trim() || null,
+        // Leaving the token field empty keeps the saved token
+        ...(gitlabToken && { gitlab_token: gitlabToken }),
         gitlab_sync_enabled: gitlabDraft.gitlab_sync_enabled,
         gitlab_sync_labels: gitlabDraft.gitlab_sync_labels.trim() || null,
-        vortex_api_url: null,
-        vortex_project_id: null,
-        vortex_token: null,
-        vortex_sync_enabled: null,
-        vortex_sync_labels: null,
       };
 
       await projectsApi.update(selectedProject.id, updateData);
@@ -820,569 +1237,3123 @@ export function ProjectSettings() {
     }
   };
 
-  const handleSaveVortex = async () => {
+  const handleSaveGitea = async () => {
     if (!selectedProject) return;
 
-    setSavingVortex(true);
-    setVortexError(null);
-    setVortexSuccess(false);
+    setSavingGitea(true);
+    setGiteaError(null);
+    setGiteaSuccess(false);
 
     try {
+      const giteaToken = giteaDraft.gitea_token.trim();
       const updateData: UpdateProject = {
-        name: selectedProject.name,
-        dev_script: selectedProject.dev_script ?? null,
-        dev_script_working_dir: selectedProject.dev_script_working_dir ?? null,
-        default_agent_working_dir: selectedProject.default_agent_working_dir ?? null,
-        github_repo_url: null,
-        github_token: null,
-        github_sync_enabled: null,
-        github_sync_labels: null,
-        gitlab_project_url: null,
-        gitlab_token: null,
-        gitlab_sync_enabled: null,
-        gitlab_sync_labels: null,
-        vortex_api_url: null,
-        vortex_project_id: vortexDraft.vortex_project_id.trim() || null,
-        vortex_token: vortexDraft.vortex_token.trim() || null,
-        vortex_sync_enabled: vortexDraft.vortex_sync_enabled,
-        vortex_sync_labels: vortexDraft.vortex_sync_labels.trim() || null,
+        gitea_base_url: giteaDraft.gitea_base_url.trim() || null,
+        gitea_repo: giteaDraft.gitea_repo.trim() || null,
+        // Leaving the token field empty keeps the saved token
+        ...(giteaToken && { gitea_token: giteaToken }),
+        gitea_sync_enabled: giteaDraft.gitea_sync_enabled,
+        gitea_sync_labels: giteaDraft.gitea_sync_labels.trim() || null,
       };
 
       await projectsApi.update(selectedProject.id, updateData);
-      setVortexSuccess(true);
-      if (vortexDraft.vortex_token.trim()) {
-        setHasExistingVortexToken(true);
+      setGiteaSuccess(true);
+      if (giteaDraft.gitea_token.trim()) {
+        setHasExistingGiteaToken(true);
       }
-      setVortexDraft((prev) => ({ ...prev, vortex_token: '' }));
-      setTimeout(() => setVortexSuccess(false), 3000);
+      setGiteaDraft((prev) => ({ ...prev, gitea_token: '' }));
+      setTimeout(() => setGiteaSuccess(false), 3000);
     } catch (err) {
-      setVortexError(
-        err instanceof Error ? err.message : 'Failed to save Vortex settings'
+      setGiteaError(
+        err instanceof Error ? err.message : 'Failed to save Gitea settings'
       );
     } finally {
-      setSavingVortex(false);
+      setSavingGitea(false);
     }
   };
 
-  const handleLoadVortexIssues = async () => {
+  const handleLoadGiteaIssues = async () => {
     if (!selectedProjectId) return;
 
-    setLoadingVortexIssues(true);
-    setVortexError(null);
+    setLoadingGiteaIssues(true);
+    setGiteaError(null);
 
     try {
-      const response = await projectsApi.listVortexIssues(selectedProjectId);
-      if (!response.has_vortex_config) {
-        setVortexError('Please configure Vortex settings first');
-        setVortexIssues([]);
+      const response = await projectsApi.listGiteaIssues(selectedProjectId);
+      if (!response.has_gitea_config) {
+        setGiteaError(t('settings.projects.giteaIntegration.messages.configureFirst'));
+        setGiteaIssues([]);
       } else {
-        setVortexIssues(response.issues);
+        setGiteaIssues(response.issues);
       }
     } catch (err) {
-      setVortexError(
-        err instanceof Error ? err.message : 'Failed to load Vortex issues'
+      setGiteaError(
+        err instanceof Error ? err.message : t('settings.projects.giteaIntegration.messages.loadError')
       );
     } finally {
-      setLoadingVortexIssues(false);
+      setLoadingGiteaIssues(false);
     }
   };
 
-  const handleImportVortexIssue = async (issueId: string) => {
+  const handleImportGiteaIssue = async (issueNumber: number) => {
     if (!selectedProjectId) return;
 
     try {
-      await projectsApi.importVortexIssue(selectedProjectId, issueId);
-      setVortexIssues((prev) => prev.filter((i) => i.id !== issueId));
+      await projectsApi.importGiteaIssue(selectedProjectId, issueNumber);
+      setGiteaIssues((prev) =>
+        prev.filter((i) => Number(i.number) !== issueNumber)
+      );
     } catch (err) {
-      setVortexError(
-        err instanceof Error ? err.message : 'Failed to import Vortex issue'
+      setGiteaError(
+        err instanceof Error ? err.message : t('settings.projects.giteaIntegration.messages.importError')
       );
     }
   };
 
-  const handleSyncVortexIssues = async () => {
+  const handleSyncGiteaIssues = async () => {
     if (!selectedProjectId) return;
 
-    setSyncingVortexIssues(true);
-    setVortexError(null);
+    setSyncingGiteaIssues(true);
+    setGiteaError(null);
 
     try {
-      const imported = await projectsApi.syncVortexIssues(selectedProjectId);
+      const imported = await projectsApi.syncGiteaIssues(selectedProjectId);
       if (imported.length > 0) {
-        setVortexSuccess(true);
-        setTimeout(() => setVortexSuccess(false), 3000);
+        setGiteaSuccess(true);
+        setTimeout(() => setGiteaSuccess(false), 3000);
       }
-      await handleLoadVortexIssues();
+      await handleLoadGiteaIssues();
     } catch (err) {
-      setVortexError(
-        err instanceof Error ? err.message : 'Failed to sync Vortex issues'
+      setGiteaError(
+        err instanceof Error ? err.message : t('settings.projects.giteaIntegration.messages.syncError')
       );
     } finally {
-      setSyncingVortexIssues(false);
+      setSyncingGiteaIssues(false);
     }
   };
 
-  if (projectsLoading) {
-    return (
-      <div className="flex items-center justify-center py-8">
-        <Loader2 className="h-8 w-8 animate-spin" />
-        <span className="ml-2">{t('settings.projects.loading')}</span>
-      </div>
-    );
-  }
+  const handleSaveAzure = async () => {
+    if (!selectedProjectId) return;
 
-  if (projectsError) {
-    return (
-      <div className="py-8">
-        <Alert variant="destructive">
-          <AlertDescription>
-            {projectsError instanceof Error
-              ? projectsError.message
-              : t('settings.projects.loadError')}
-          </AlertDescription>
-        </Alert>
-      </div>
-    );
-  }
+    setSavingAzure(true);
+    setAzureError(null);
+    setAzureSuccess(false);
 
-  return (
-    <div className="space-y-6">
-      {error && (
-        <Alert variant="destructive">
-          <AlertDescription>{error}</AlertDescription>
-        </Alert>
-      )}
+    try {
+      const pat = azureDraft.pat.trim();
+      const connection = await projectsApi.upsertIssueConnection(
+        selectedProjectId,
+        {
+          ...(pat && { token: pat }),
+          settings: {
+            provider: 'Azure DevOps',
+            organization_url: azureDraft.organization_url.trim(),
+            azure_project: azureDraft.azure_project.trim(),
+            wiql_filter: azureDraft.wiql_filter.trim() || null,
+          },
+        }
+      );
+      const azure = connectionSettings([connection], 'Azure DevOps');
+      setAzureConnection(azure);
+      setAzureDraft((prev) => ({
+        ...prev,
+        pat: '',
+        organization_url: azure?.organization_url ?? prev.organization_url,
+      }));
+      setAzureSuccess(true);
+      setTimeout(() => setAzureSuccess(false), 3000);
+    } catch (err) {
+      setAzureError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.azureIntegration.messages.saveError')
+      );
+    } finally {
+      setSavingAzure(false);
+    }
+  };
 
-      {success && (
-        <Alert variant="success">
-          <AlertDescription className="font-medium">
-            {t('settings.projects.save.success')}
-          </AlertDescription>
-        </Alert>
-      )}
+  const handleDisconnectAzure = async () => {
+    if (!selectedProjectId) return;
 
-      <Card>
-        <CardHeader>
-          <CardTitle>{t('settings.projects.title')}</CardTitle>
-          <CardDescription>
-            {t('settings.projects.description')}
-          </CardDescription>
-        </CardHeader>
-        <CardContent className="space-y-4">
-          <div className="space-y-2">
-            <Label htmlFor="project-selector">
-              {t('settings.projects.selector.label')}
-            </Label>
-            <Select
-              value={selectedProjectId}
-              onValueChange={handleProjectSelect}
-            >
-              <SelectTrigger id="project-selector">
-                <SelectValue
-                  placeholder={t('settings.projects.selector.placeholder')}
-                />
-              </SelectTrigger>
-              <SelectContent>
-                {projects && projects.length > 0 ? (
-                  projects.map((project) => (
-                    <SelectItem key={project.id} value={project.id}>
-                      {project.name}
-                    </SelectItem>
-                  ))
-                ) : (
-                  <SelectItem value="no-projects" disabled>
-                    {t('settings.projects.selector.noProjects')}
-                  </SelectItem>
-                )}
-              </SelectContent>
-            </Select>
-            <p className="text-sm text-muted-foreground">
-              {t('settings.projects.selector.helper')}
-            </p>
-          </div>
-        </CardContent>
-      </Card>
+    setAzureError(null);
+    try {
+      await projectsApi.deleteIssueConnection(
+        selectedProjectId,
+        'azure-devops'
+      );
+      setAzureConnection(null);
+      setAzureWorkItems([]);
+      setAzureDraft({
+        organization_url: '',
+        azure_project: '',
+        pat: '',
+        wiql_filter: '',
+      });
+    } catch (err) {
+      setAzureError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.azureIntegration.messages.saveError')
+      );
+    }
+  };
 
-      {selectedProject && draft && (
-        <>
-          <Card>
-            <CardHeader>
-              <CardTitle>{t('settings.projects.general.title')}</CardTitle>
-              <CardDescription>
-                {t('settings.projects.general.description')}
-              </CardDescription>
-            </CardHeader>
-            <CardContent className="space-y-4">
-              <div className="space-y-2">
-                <Label htmlFor="project-name">
-                  {t('settings.projects.general.name.label')}
-                </Label>
-                <Input
-                  id="project-name"
-                  type="text"
-                  value={draft.name}
-                  onChange={(e) => updateDraft({ name: e.target.value })}
-                  placeholder={t('settings.projects.general.name.placeholder')}
-                  required
-                />
-                <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.general.name.helper')}
+  const handleLoadAzureWorkItems = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingAzureWorkItems(true);
+    setAzureError(null);
+
+    try {
+      if (!azureConnection) {
+        setAzureError(
+          t('settings.projects.azureIntegration.messages.configureFirst')
+        );
+        setAzureWorkItems([]);
+      } else {
+        setAzureWorkItems(
+          await projectsApi.listConnectedIssues(
+            selectedProjectId,
+            'azure-devops'
+          )
+        );
+      }
+    } catch (err) {
+      setAzureError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.azureIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingAzureWorkItems(false);
+    }
+  };
+
+  const handleImportAzureWorkItem = async (workItemId: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importConnectedIssue(
+        selectedProjectId,
+        'azure-devops',
+        workItemId
+      );
+      setAzureWorkItems((prev) =>
+        prev.filter((item) => item.id !== workItemId)
+      );
+    } catch (err) {
+      setAzureError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.azureIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSyncAzureWorkItems = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingAzureWorkItems(true);
+    setAzureError(null);
+
+    try {
+      const imported = await projectsApi.syncConnectedIssues(
+        selectedProjectId,
+        'azure-devops'
+      );
+      if (imported.length > 0) {
+        setAzureSuccess(true);
+        setTimeout(() => setAzureSuccess(false), 3000);
+      }
+      await handleLoadAzureWorkItems();
+    } catch (err) {
+      setAzureError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.azureIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingAzureWorkItems(false);
+    }
+  };
+
+  const handleSaveNotion = async () => {
+    if (!selectedProjectId) return;
+
+    setSavingNotion(true);
+    setNotionError(null);
+    setNotionSuccess(false);
+
+    try {
+      const token = notionDraft.token.trim();
+      const connection = await projectsApi.upsertIssueConnection(
+        selectedProjectId,
+        {
+          ...(token && { token }),
+          settings: {
+            provider: 'Notion',
+            database_id: notionDraft.database_id.trim(),
+            status_property: notionDraft.status_property.trim(),
+            description_property: notionDraft.description_property.trim(),
+          },
+        }
+      );
+      const notion = connectionSettings([connection], 'Notion');
+      setNotionConnection(notion);
+      setNotionDraft((prev) => ({
+        ...prev,
+        token: '',
+        database_id: notion?.database_id ?? prev.database_id,
+      }));
+      setNotionSuccess(true);
+      setTimeout(() => setNotionSuccess(false), 3000);
+    } catch (err) {
+      setNotionError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.notionIntegration.messages.saveError')
+      );
+    } finally {
+      setSavingNotion(false);
+    }
+  };
+
+  const handleDisconnectNotion = async () => {
+    if (!selectedProjectId) return;
+
+    setNotionError(null);
+    try {
+      await projectsApi.deleteIssueConnection(selectedProjectId, 'notion');
+      setNotionConnection(null);
+      setNotionRows([]);
+      setNotionDraft(EMPTY_NOTION_DRAFT);
+    } catch (err) {
+      setNotionError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.notionIntegration.messages.saveError')
+      );
+    }
+  };
+
+  const handleLoadNotionRows = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingNotionRows(true);
+    setNotionError(null);
+
+    try {
+      if (!notionConnection) {
+        setNotionError(
+          t('settings.projects.notionIntegration.messages.configureFirst')
+        );
+        setNotionRows([]);
+      } else {
+        setNotionRows(
+          await projectsApi.listConnectedIssues(selectedProjectId, 'notion')
+        );
+      }
+    } catch (err) {
+      setNotionError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.notionIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingNotionRows(false);
+    }
+  };
+
+  const handleImportNotionRow = async (pageId: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importConnectedIssue(
+        selectedProjectId,
+        'notion',
+        pageId
+      );
+      setNotionRows((prev) => prev.filter((row) => row.id !== pageId));
+    } catch (err) {
+      setNotionError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.notionIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSyncNotionRows = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingNotionRows(true);
+    setNotionError(null);
+
+    try {
+      const imported = await projectsApi.syncConnectedIssues(
+        selectedProjectId,
+        'notion'
+      );
+      if (imported.length > 0) {
+        setNotionSuccess(true);
+        setTimeout(() => setNotionSuccess(false), 3000);
+      }
+      await handleLoadNotionRows();
+    } catch (err) {
+      setNotionError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.notionIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingNotionRows(false);
+    }
+  };
+
+  const handleSaveAsana = async () => {
+    if (!selectedProjectId) return;
+
+    setSavingAsana(true);
+    setAsanaError(null);
+    setAsanaSuccess(false);
+
+    try {
+      const token = asanaDraft.token.trim();
+      const connection = await projectsApi.upsertIssueConnection(
+        selectedProjectId,
+        {
+          ...(token && { token }),
+          settings: {
+            provider: 'Asana',
+            project_gid: asanaDraft.project_gid.trim(),
+          },
+        }
+      );
+      const asana = connectionSettings([connection], 'Asana');
+      setAsanaConnection(asana);
+      setAsanaDraft((prev) => ({
+        token: '',
+        project_gid: asana?.project_gid ?? prev.project_gid,
+      }));
+      setAsanaSuccess(true);
+      setTimeout(() => setAsanaSuccess(false), 3000);
+    } catch (err) {
+      setAsanaError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.asanaIntegration.messages.saveError')
+      );
+    } finally {
+      setSavingAsana(false);
+    }
+  };
+
+  const handleDisconnectAsana = async () => {
+    if (!selectedProjectId) return;
+
+    setAsanaError(null);
+    try {
+      await projectsApi.deleteIssueConnection(selectedProjectId, 'asana');
+      setAsanaConnection(null);
+      setAsanaTasks([]);
+      setAsanaDraft(EMPTY_ASANA_DRAFT);
+    } catch (err) {
+      setAsanaError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.asanaIntegration.messages.saveError')
+      );
+    }
+  };
+
+  const handleLoadAsanaTasks = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingAsanaTasks(true);
+    setAsanaError(null);
+
+    try {
+      if (!asanaConnection) {
+        setAsanaError(
+          t('settings.projects.asanaIntegration.messages.configureFirst')
+        );
+        setAsanaTasks([]);
+      } else {
+        setAsanaTasks(
+          await projectsApi.listConnectedIssues(selectedProjectId, 'asana')
+        );
+      }
+    } catch (err) {
+      setAsanaError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.asanaIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingAsanaTasks(false);
+    }
+  };
+
+  const handleImportAsanaTask = async (taskGid: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importConnectedIssue(
+        selectedProjectId,
+        'asana',
+        taskGid
+      );
+      setAsanaTasks((prev) => prev.filter((task) => task.id !== taskGid));
+    } catch (err) {
+      setAsanaError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.asanaIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSyncAsanaTasks = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingAsanaTasks(true);
+    setAsanaError(null);
+
+    try {
+      const imported = await projectsApi.syncConnectedIssues(
+        selectedProjectId,
+        'asana'
+      );
+      if (imported.length > 0) {
+        setAsanaSuccess(true);
+        setTimeout(() => setAsanaSuccess(false), 3000);
+      }
+      await handleLoadAsanaTasks();
+    } catch (err) {
+      setAsanaError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.asanaIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingAsanaTasks(false);
+    }
+  };
+
+  const handleSaveClickUp = async () => {
+    if (!selectedProjectId) return;
+
+    setSavingClickUp(true);
+    setClickUpError(null);
+    setClickUpSuccess(false);
+
+    try {
+      const token = clickupDraft.token.trim();
+      const connection = await projectsApi.upsertIssueConnection(
+        selectedProjectId,
+        {
+          ...(token && { token }),
+          settings: {
+            provider: 'ClickUp',
+            list_id: clickupDraft.list_id.trim(),
+            tag_filter: clickupDraft.tag_filter.trim() || null,
+          },
+        }
+      );
+      const clickup = connectionSettings([connection], 'ClickUp');
+      setClickUpConnection(clickup);
+      setClickUpDraft((prev) => ({
+        token: '',
+        list_id: clickup?.list_id ?? prev.list_id,
+        tag_filter: clickup?.tag_filter ?? '',
+      }));
+      setClickUpSuccess(true);
+      setTimeout(() => setClickUpSuccess(false), 3000);
+    } catch (err) {
+      setClickUpError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.clickupIntegration.messages.saveError')
+      );
+    } finally {
+      setSavingClickUp(false);
+    }
+  };
+
+  const handleDisconnectClickUp = async () => {
+    if (!selectedProjectId) return;
+
+    setClickUpError(null);
+    try {
+      await projectsApi.deleteIssueConnection(selectedProjectId, 'clickup');
+      setClickUpConnection(null);
+      setClickUpTasks([]);
+      setClickUpDraft(EMPTY_CLICKUP_DRAFT);
+    } catch (err) {
+      setClickUpError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.clickupIntegration.messages.saveError')
+      );
+    }
+  };
+
+  const handleLoadClickUpTasks = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingClickUpTasks(true);
+    setClickUpError(null);
+
+    try {
+      if (!clickupConnection) {
+        setClickUpError(
+          t('settings.projects.clickupIntegration.messages.configureFirst')
+        );
+        setClickUpTasks([]);
+      } else {
+        setClickUpTasks(
+          await projectsApi.listConnectedIssues(selectedProjectId, 'clickup')
+        );
+      }
+    } catch (err) {
+      setClickUpError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.clickupIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingClickUpTasks(false);
+    }
+  };
+
+  const handleImportClickUpTask = async (taskId: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importConnectedIssue(
+        selectedProjectId,
+        'clickup',
+        taskId
+      );
+      setClickUpTasks((prev) => prev.filter((task) => task.id !== taskId));
+    } catch (err) {
+      setClickUpError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.clickupIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSyncClickUpTasks = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingClickUpTasks(true);
+    setClickUpError(null);
+
+    try {
+      const imported = await projectsApi.syncConnectedIssues(
+        selectedProjectId,
+        'clickup'
+      );
+      if (imported.length > 0) {
+        setClickUpSuccess(true);
+        setTimeout(() => setClickUpSuccess(false), 3000);
+      }
+      await handleLoadClickUpTasks();
+    } catch (err) {
+      setClickUpError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.clickupIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingClickUpTasks(false);
+    }
+  };
+
+  const handleSaveShortcut = async () => {
+    if (!selectedProjectId) return;
+
+    setSavingShortcut(true);
+    setShortcutError(null);
+    setShortcutSuccess(false);
+
+    try {
+      const token = shortcutDraft.token.trim();
+      const connection = await projectsApi.upsertIssueConnection(
+        selectedProjectId,
+        {
+          ...(token && { token }),
+          settings: {
+            provider: 'Shortcut',
+            labels: shortcutDraft.labels.trim() || null,
+            review_state_id:
+              shortcutDraft.review_state_id === SHORTCUT_AUTO_REVIEW_STATE
+                ? null
+                : Number(shortcutDraft.review_state_id),
+          },
+        }
+      );
+      const shortcut = connectionSettings([connection], 'Shortcut');
+      setShortcutConnection(shortcut);
+      setShortcutDraft((prev) => ({
+        ...prev,
+        token: '',
+        labels: shortcut?.labels ?? '',
+      }));
+      if (shortcutWorkflows.length === 0) {
+        setShortcutWorkflows(
+          await projectsApi.listShortcutWorkflows(selectedProjectId)
+        );
+      }
+      setShortcutSuccess(true);
+      setTimeout(() => setShortcutSuccess(false), 3000);
+    } catch (err) {
+      setShortcutError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.shortcutIntegration.messages.saveError')
+      );
+    } finally {
+      setSavingShortcut(false);
+    }
+  };
+
+  const handleDisconnectShortcut = async () => {
+    if (!selectedProjectId) return;
+
+    setShortcutError(null);
+    try {
+      await projectsApi.deleteIssueConnection(selectedProjectId, 'shortcut');
+      setShortcutConnection(null);
+      setShortcutStories([]);
+      setShortcutWorkflows([]);
+      setShortcutDraft(EMPTY_SHORTCUT_DRAFT);
+    } catch (err) {
+      setShortcutError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.shortcutIntegration.messages.saveError')
+      );
+    }
+  };
+
+  const handleLoadShortcutStories = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingShortcutStories(true);
+    setShortcutError(null);
+
+    try {
+      if (!shortcutConnection) {
+        setShortcutError(
+          t('settings.projects.shortcutIntegration.messages.configureFirst')
+        );
+        setShortcutStories([]);
+      } else {
+        setShortcutStories(
+          await projectsApi.listConnectedIssues(selectedProjectId, 'shortcut')
+        );
+      }
+    } catch (err) {
+      setShortcutError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.shortcutIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingShortcutStories(false);
+    }
+  };
+
+  const handleImportShortcutStory = async (storyId: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importConnectedIssue(
+        selectedProjectId,
+        'shortcut',
+        storyId
+      );
+      setShortcutStories((prev) =>
+        prev.filter((story) => story.id !== storyId)
+      );
+    } catch (err) {
+      setShortcutError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.shortcutIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSyncShortcutStories = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingShortcutStories(true);
+    setShortcutError(null);
+
+    try {
+      const imported = await projectsApi.syncConnectedIssues(
+        selectedProjectId,
+        'shortcut'
+      );
+      if (imported.length > 0) {
+        setShortcutSuccess(true);
+        setTimeout(() => setShortcutSuccess(false), 3000);
+      }
+      await handleLoadShortcutStories();
+    } catch (err) {
+      setShortcutError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.shortcutIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingShortcutStories(false);
+    }
+  };
+
+  const handleLoadLinearTeams = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingLinearTeams(true);
+    setLinearError(null);
+
+    try {
+      const teams = await projectsApi.listLinearTeams(
+        selectedProjectId,
+        linearDraft.api_key.trim()
+      );
+      setLinearTeams(teams);
+    } catch (err) {
+      setLinearError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.linearIntegration.messages.teamsError')
+      );
+    } finally {
+      setLoadingLinearTeams(false);
+    }
+  };
+
+  const handleSaveLinear = async () => {
+    if (!selectedProjectId) return;
+
+    setSavingLinear(true);
+    setLinearError(null);
+    setLinearSuccess(false);
+
+    try {
+      const apiKey = linearDraft.api_key.trim();
+      const connection = await projectsApi.upsertIssueConnection(
+        selectedProjectId,
+        {
+          ...(apiKey && { token: apiKey }),
+          settings: {
+            provider: 'Linear',
+            team_id: linearDraft.team_id,
+            team_key: '',
+            sync_labels: linearDraft.sync_labels.trim() || null,
+            status_sync_enabled: linearDraft.status_sync_enabled,
+          },
+        }
+      );
+      const linear = connectionSettings([connection], 'Linear');
+      setLinearConnection(linear);
+      setLinearDraft((prev) => ({
+        ...prev,
+        api_key: '',
+        team_id: linear?.team_id ?? prev.team_id,
+      }));
+      setLinearSuccess(true);
+      setTimeout(() => setLinearSuccess(false), 3000);
+    } catch (err) {
+      setLinearError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.linearIntegration.messages.saveError')
+      );
+    } finally {
+      setSavingLinear(false);
+    }
+  };
+
+  const handleDisconnectLinear = async () => {
+    if (!selectedProjectId) return;
+
+    setLinearError(null);
+    try {
+      await projectsApi.deleteIssueConnection(selectedProjectId, 'linear');
+      setLinearConnection(null);
+      setLinearTeams([]);
+      setLinearIssues([]);
+      setLinearDraft({
+        api_key: '',
+        team_id: '',
+        sync_labels: '',
+        status_sync_enabled: true,
+      });
+    } catch (err) {
+      setLinearError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.linearIntegration.messages.saveError')
+      );
+    }
+  };
+
+  const handleLoadLinearIssues = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingLinearIssues(true);
+    setLinearError(null);
+
+    try {
+      if (!linearConnection) {
+        setLinearError(
+          t('settings.projects.linearIntegration.messages.configureFirst')
+        );
+        setLinearIssues([]);
+      } else {
+        setLinearIssues(
+          await projectsApi.listConnectedIssues(selectedProjectId, 'linear')
+        );
+      }
+    } catch (err) {
+      setLinearError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.linearIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingLinearIssues(false);
+    }
+  };
+
+  const handleImportLinearIssue = async (issueId: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importConnectedIssue(
+        selectedProjectId,
+        'linear',
+        issueId
+      );
+      setLinearIssues((prev) => prev.filter((i) => i.id !== issueId));
+    } catch (err) {
+      setLinearError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.linearIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSyncLinearIssues = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingLinearIssues(true);
+    setLinearError(null);
+
+    try {
+      const imported = await projectsApi.syncConnectedIssues(
+        selectedProjectId,
+        'linear'
+      );
+      if (imported.length > 0) {
+        setLinearSuccess(true);
+        setTimeout(() => setLinearSuccess(false), 3000);
+      }
+      await handleLoadLinearIssues();
+    } catch (err) {
+      setLinearError(
+        err instanceof Error
+          ? err.message
+          : t('settings.projects.linearIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingLinearIssues(false);
+    }
+  };
+
+  const handleSaveBitbucket = async () => {
+    if (!selectedProject) return;
+
+    setSavingBitbucket(true);
+    setBitbucketError(null);
+    setBitbucketSuccess(false);
+
+    try {
+      const appPassword = bitbucketDraft.bitbucket_app_password.trim();
+      const updateData: UpdateProject = {
+        bitbucket_workspace: bitbucketDraft.bitbucket_workspace.trim() || null,
+        bitbucket_repo_slug: bitbucketDraft.bitbucket_repo_slug.trim() || null,
+        bitbucket_username: bitbucketDraft.bitbucket_username.trim() || null,
+        // Leaving the password field empty keeps the saved app password
+        ...(appPassword && { bitbucket_app_password: appPassword }),
+      };
+
+      await projectsApi.update(selectedProject.id, updateData);
+      setBitbucketSuccess(true);
+      if (appPassword) {
+        setHasExistingBitbucketPassword(true);
+      }
+      setBitbucketDraft((prev) => ({ ...prev, bitbucket_app_password: '' }));
+      setTimeout(() => setBitbucketSuccess(false), 3000);
+    } catch (err) {
+      setBitbucketError(
+        err instanceof Error ? err.message : 'Failed to save Bitbucket settings'
+      );
+    } finally {
+      setSavingBitbucket(false);
+    }
+  };
+
+  const handleLoadBitbucketIssues = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingBitbucketIssues(true);
+    setBitbucketError(null);
+
+    try {
+      const response = await projectsApi.listBitbucketIssues(selectedProjectId);
+      if (!response.has_bitbucket_config) {
+        setBitbucketError(t('settings.projects.bitbucketIntegration.messages.configureFirst'));
+        setBitbucketIssues([]);
+      } else {
+        setBitbucketIssues(response.issues);
+      }
+    } catch (err) {
+      setBitbucketError(
+        err instanceof Error ? err.message : t('settings.projects.bitbucketIntegration.messages.loadError')
+      );
+    } finally {
+      setLoadingBitbucketIssues(false);
+    }
+  };
+
+  const handleSyncBitbucketIssues = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingBitbucketIssues(true);
+    setBitbucketError(null);
+
+    try {
+      const imported = await projectsApi.syncBitbucketIssues(selectedProjectId);
+      if (imported.length > 0) {
+        setBitbucketSuccess(true);
+        setTimeout(() => setBitbucketSuccess(false), 3000);
+      }
+      await handleLoadBitbucketIssues();
+    } catch (err) {
+      setBitbucketError(
+        err instanceof Error ? err.message : t('settings.projects.bitbucketIntegration.messages.syncError')
+      );
+    } finally {
+      setSyncingBitbucketIssues(false);
+    }
+  };
+
+  const handleImportBitbucketIssue = async (issueId: number) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importBitbucketIssue(selectedProjectId, issueId);
+      setBitbucketIssues((prev) =>
+        prev.filter((i) => Number(i.id) !== issueId)
+      );
+    } catch (err) {
+      setBitbucketError(
+        err instanceof Error ? err.message : t('settings.projects.bitbucketIntegration.messages.importError')
+      );
+    }
+  };
+
+  const handleSaveVortex = async () => {
+    if (!selectedProject) return;
+
+    setSavingVortex(true);
+    setVortexError(null);
+    setVortexSuccess(false);
+
+    try {
+      const vortexToken = vortexDraft.vortex_token.trim();
+      const updateData: UpdateProject = {
+        vortex_project_id: vortexDraft.vortex_project_id.trim() || null,
+        // Leaving the token field empty keeps the saved token
+        ...(vortexToken && { vortex_token: vortexToken }),
+        vortex_sync_enabled: vortexDraft.vortex_sync_enabled,
+        vortex_sync_labels: vortexDraft.vortex_sync_labels.trim() || null,
+        vortex_two_way_sync: vortexDraft.vortex_two_way_sync,
+      };
+
+      await projectsApi.update(selectedProject.id, updateData);
+      setVortexSuccess(true);
+      if (vortexDraft.vortex_token.trim()) {
+        setHasExistingVortexToken(true);
+      }
+      setVortexDraft((prev) => ({ ...prev, vortex_token: '' }));
+      setTimeout(() => setVortexSuccess(false), 3000);
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to save Vortex settings'
+      );
+    } finally {
+      setSavingVortex(false);
+    }
+  };
+
+  const handleRefreshSyncRuns = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingSyncRuns(true);
+    try {
+      setSyncRuns(await projectsApi.getSyncRuns(selectedProjectId));
+    } catch {
+      setSyncRuns([]);
+    } finally {
+      setLoadingSyncRuns(false);
+    }
+  };
+
+  const handleLoadVortexProjects = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingVortexProjects(true);
+    setVortexError(null);
+    try {
+      setVortexProjects(
+        await projectsApi.listVortexProjects(selectedProjectId)
+      );
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to load Vortex projects'
+      );
+    } finally {
+      setLoadingVortexProjects(false);
+    }
+  };
+
+  const handleConnectVortex = async () => {
+    if (!selectedProjectId) return;
+
+    setConnectingVortex(true);
+    setVortexError(null);
+    try {
+      const { authorize_url } = await projectsApi.startVortexOAuth(
+        selectedProjectId,
+        `${window.location.origin}/api/vortex/oauth/callback`
+      );
+      const popup = window.open(authorize_url, '_blank');
+      // Poll until the callback has stored the tokens or the window is closed
+      const timer = window.setInterval(async () => {
+        const status = await projectsApi
+          .getVortexOAuthStatus(selectedProjectId)
+          .catch(() => null);
+        if (status?.connected || !popup || popup.closed) {
+          window.clearInterval(timer);
+          if (status) setVortexOAuth(status);
+          setConnectingVortex(false);
+        }
+      }, 2000);
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to connect to Vortex'
+      );
+      setConnectingVortex(false);
+    }
+  };
+
+  const handleDisconnectVortex = async () => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.disconnectVortexOAuth(selectedProjectId);
+      setVortexOAuth((prev) =>
+        prev ? { ...prev, connected: false, expires_at: null } : prev
+      );
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to disconnect Vortex'
+      );
+    }
+  };
+
+  const handleLoadVortexIssues = async () => {
+    if (!selectedProjectId) return;
+
+    setLoadingVortexIssues(true);
+    setVortexError(null);
+
+    try {
+      const response = await projectsApi.listVortexIssues(selectedProjectId);
+      if (!response.has_vortex_config) {
+        setVortexError('Please configure Vortex settings first');
+        setVortexIssues([]);
+      } else {
+        setVortexIssues(response.issues);
+      }
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to load Vortex issues'
+      );
+    } finally {
+      setLoadingVortexIssues(false);
+    }
+  };
+
+  const handleImportVortexIssue = async (issueId: string) => {
+    if (!selectedProjectId) return;
+
+    try {
+      await projectsApi.importVortexIssue(selectedProjectId, issueId);
+      setVortexIssues((prev) => prev.filter((i) => i.id !== issueId));
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to import Vortex issue'
+      );
+    }
+  };
+
+  const handleSyncVortexIssues = async () => {
+    if (!selectedProjectId) return;
+
+    setSyncingVortexIssues(true);
+    setVortexError(null);
+
+    try {
+      const imported = await projectsApi.syncVortexIssues(selectedProjectId);
+      if (imported.length > 0) {
+        setVortexSuccess(true);
+        setTimeout(() => setVortexSuccess(false), 3000);
+      }
+      await handleLoadVortexIssues();
+    } catch (err) {
+      setVortexError(
+        err instanceof Error ? err.message : 'Failed to sync Vortex issues'
+      );
+    } finally {
+      setSyncingVortexIssues(false);
+    }
+  };
+
+  if (projectsLoading) {
+    return (
+      <div className="flex items-center justify-center py-8">
+        <Loader2 className="h-8 w-8 animate-spin" />
+        <span className="ml-2">{t('settings.projects.loading')}</span>
+      </div>
+    );
+  }
+
+  if (projectsError) {
+    return (
+      <div className="py-8">
+        <Alert variant="destructive">
+          <AlertDescription>
+            {projectsError instanceof Error
+              ? projectsError.message
+              : t('settings.projects.loadError')}
+          </AlertDescription>
+        </Alert>
+      </div>
+    );
+  }
+
+  return (
+    <div className="space-y-6">
+      {error && (
+        <Alert variant="destructive">
+          <AlertDescription>{error}</AlertDescription>
+        </Alert>
+      )}
+
+      {success && (
+        <Alert variant="success">
+          <AlertDescription className="font-medium">
+            {t('settings.projects.save.success')}
+          </AlertDescription>
+        </Alert>
+      )}
+
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('settings.projects.title')}</CardTitle>
+          <CardDescription>
+            {t('settings.projects.description')}
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="space-y-4">
+          <div className="space-y-2">
+            <Label htmlFor="project-selector">
+              {t('settings.projects.selector.label')}
+            </Label>
+            <Select
+              value={selectedProjectId}
+              onValueChange={handleProjectSelect}
+            >
+              <SelectTrigger id="project-selector">
+                <SelectValue
+                  placeholder={t('settings.projects.selector.placeholder')}
+                />
+              </SelectTrigger>
+              <SelectContent>
+                {projects && projects.length > 0 ? (
+                  projects.map((project) => (
+                    <SelectItem key={project.id} value={project.id}>
+                      {project.name}
+                    </SelectItem>
+                  ))
+                ) : (
+                  <SelectItem value="no-projects" disabled>
+                    {t('settings.projects.selector.noProjects')}
+                  </SelectItem>
+                )}
+              </SelectContent>
+            </Select>
+            <p className="text-sm text-muted-foreground">
+              {t('settings.projects.selector.helper')}
+            </p>
+          </div>
+        </CardContent>
+      </Card>
+
+      {selectedProject && draft && (
+        <>
+          <Card>
+            <CardHeader>
+              <CardTitle>{t('settings.projects.general.title')}</CardTitle>
+              <CardDescription>
+                {t('settings.projects.general.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              <div className="space-y-2">
+                <Label htmlFor="project-name">
+                  {t('settings.projects.general.name.label')}
+                </Label>
+                <Input
+                  id="project-name"
+                  type="text"
+                  value={draft.name}
+                  onChange={(e) => updateDraft({ name: e.target.value })}
+                  placeholder={t('settings.projects.general.name.placeholder')}
+                  required
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.general.name.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="dev-script">
+                  {t('settings.projects.scripts.dev.label')}
+                </Label>
+                <AutoExpandingTextarea
+                  id="dev-script"
+                  value={draft.dev_script}
+                  onChange={(e) => updateDraft({ dev_script: e.target.value })}
+                  placeholder={placeholders.dev}
+                  maxRows={12}
+                  className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.scripts.dev.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="dev-script-working-dir">
+                  {t('settings.projects.scripts.devWorkingDir.label')}
+                </Label>
+                <Input
+                  id="dev-script-working-dir"
+                  value={draft.dev_script_working_dir}
+                  onChange={(e) =>
+                    updateDraft({ dev_script_working_dir: e.target.value })
+                  }
+                  placeholder={t(
+                    'settings.projects.scripts.devWorkingDir.placeholder'
+                  )}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.scripts.devWorkingDir.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="agent-working-dir">
+                  {t('settings.projects.scripts.agentWorkingDir.label')}
+                </Label>
+                <Input
+                  id="agent-working-dir"
+                  value={draft.default_agent_working_dir}
+                  onChange={(e) =>
+                    updateDraft({ default_agent_working_dir: e.target.value })
+                  }
+                  placeholder={t(
+                    'settings.projects.scripts.agentWorkingDir.placeholder'
+                  )}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.scripts.agentWorkingDir.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center space-x-2">
+                  <Switch
+                    id="comment-on-linked-issues"
+                    checked={draft.comment_on_linked_issues}
+                    onCheckedChange={(checked) =>
+                      updateDraft({ comment_on_linked_issues: checked })
+                    }
+                  />
+                  <Label
+                    htmlFor="comment-on-linked-issues"
+                    className="cursor-pointer"
+                  >
+                    {t('settings.projects.general.linkedIssueComments.label')}
+                  </Label>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.general.linkedIssueComments.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center space-x-2">
+                  <Switch
+                    id="confirm-auto-start"
+                    checked={draft.confirm_auto_start}
+                    onCheckedChange={(checked) =>
+                      updateDraft({ confirm_auto_start: checked })
+                    }
+                  />
+                  <Label
+                    htmlFor="confirm-auto-start"
+                    className="cursor-pointer"
+                  >
+                    {t('settings.projects.general.confirmAutoStart.label')}
+                  </Label>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.general.confirmAutoStart.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center space-x-2">
+                  <Switch
+                    id="wait-for-ci"
+                    checked={draft.wait_for_ci}
+                    onCheckedChange={(checked) =>
+                      updateDraft({ wait_for_ci: checked })
+                    }
+                  />
+                  <Label htmlFor="wait-for-ci" className="cursor-pointer">
+                    {t('settings.projects.general.waitForCi.label')}
+                  </Label>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.general.waitForCi.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center space-x-2">
+                  <Switch
+                    id="done-on-merge"
+                    checked={draft.done_on_merge}
+                    onCheckedChange={(checked) =>
+                      updateDraft({ done_on_merge: checked })
+                    }
+                  />
+                  <Label htmlFor="done-on-merge" className="cursor-pointer">
+                    {t('settings.projects.general.doneOnMerge.label')}
+                  </Label>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.general.doneOnMerge.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center space-x-2">
+                  <Switch
+                    id="triage-synced-issues"
+                    checked={draft.triage_synced_issues}
+                    onCheckedChange={(checked) =>
+                      updateDraft({ triage_synced_issues: checked })
+                    }
+                  />
+                  <Label
+                    htmlFor="triage-synced-issues"
+                    className="cursor-pointer"
+                  >
+                    {t('settings.projects.general.triageSyncedIssues.label')}
+                  </Label>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.general.triageSyncedIssues.helper')}
+                </p>
+              </div>
+
+              {/* Save Button */}
+              <div className="flex items-center justify-between pt-4 border-t">
+                {hasUnsavedProjectChanges ? (
+                  <span className="text-sm text-muted-foreground">
+                    {t('settings.projects.save.unsavedChanges')}
+                  </span>
+                ) : (
+                  <span />
+                )}
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleDiscard}
+                    disabled={saving || !hasUnsavedProjectChanges}
+                  >
+                    {t('settings.projects.save.discard')}
+                  </Button>
+                  <Button
+                    onClick={handleSave}
+                    disabled={saving || !hasUnsavedProjectChanges}
+                  >
+                    {saving ? (
+                      <>
+                        <Loader2 className="h-4 w-4 mr-2 animate-spin" />
+                        {t('settings.projects.save.saving')}
+                      </>
+                    ) : (
+                      t('settings.projects.save.button')
+                    )}
+                  </Button>
+                </div>
+              </div>
+              {error && (
+                <Alert variant="destructive">
+                  <AlertDescription>{error}</AlertDescription>
+                </Alert>
+              )}
+              {success && (
+                <Alert>
+                  <AlertDescription>
+                    {t('settings.projects.save.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+            </CardContent>
+          </Card>
+
+          {/* Repositories Section */}
+          <Card>
+            <CardHeader>
+              <CardTitle>Repositories</CardTitle>
+              <CardDescription>
+                Manage the git repositories in this project
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {repoError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{repoError}</AlertDescription>
+                </Alert>
+              )}
+
+              {loadingRepos ? (
+                <div className="flex items-center justify-center py-4">
+                  <Loader2 className="h-5 w-5 animate-spin" />
+                  <span className="ml-2 text-sm text-muted-foreground">
+                    Loading repositories...
+                  </span>
+                </div>
+              ) : (
+                <div className="space-y-2">
+                  {repositories.map((repo) => (
+                    <div
+                      key={repo.id}
+                      className="flex items-center justify-between p-3 border rounded-md"
+                    >
+                      <div className="min-w-0 flex-1">
+                        <div className="font-medium">{repo.display_name}</div>
+                        <div className="text-sm text-muted-foreground truncate">
+                          {repo.path}
+                        </div>
+                      </div>
+                      <Button
+                        variant="ghost"
+                        size="sm"
+                        onClick={() => handleDeleteRepository(repo.id)}
+                        disabled={deletingRepoId === repo.id}
+                        title="Delete repository"
+                      >
+                        {deletingRepoId === repo.id ? (
+                          <Loader2 className="h-4 w-4 animate-spin" />
+                        ) : (
+                          <Trash2 className="h-4 w-4" />
+                        )}
+                      </Button>
+                    </div>
+                  ))}
+
+                  {repositories.length === 0 && !loadingRepos && (
+                    <div className="text-center py-4 text-sm text-muted-foreground">
+                      No repositories configured
+                    </div>
+                  )}
+
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    onClick={handleAddRepository}
+                    disabled={addingRepo}
+                    className="w-full"
+                  >
+                    {addingRepo ? (
+                      <Loader2 className="h-4 w-4 mr-2 animate-spin" />
+                    ) : (
+                      <Plus className="h-4 w-4 mr-2" />
+                    )}
+                    Add Repository
+                  </Button>
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle>{t('settings.projects.scripts.title')}</CardTitle>
+              <CardDescription>
+                {t('settings.projects.scripts.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {scriptsError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{scriptsError}</AlertDescription>
+                </Alert>
+              )}
+
+              {scriptsSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    Scripts saved successfully
+                  </AlertDescription>
+                </Alert>
+              )}
+
+              {repositories.length === 0 ? (
+                <div className="text-center py-4 text-sm text-muted-foreground">
+                  Add a repository above to configure scripts
+                </div>
+              ) : (
+                <>
+                  {/* Repository Selector for Scripts */}
+                  <div className="space-y-2">
+                    <Label htmlFor="scripts-repo-selector">Repository</Label>
+                    <Select
+                      value={selectedScriptsRepoId ?? ''}
+                      onValueChange={setSelectedScriptsRepoId}
+                    >
+                      <SelectTrigger id="scripts-repo-selector">
+                        <SelectValue placeholder="Select a repository" />
+                      </SelectTrigger>
+                      <SelectContent>
+                        {repositories.map((repo) => (
+                          <SelectItem key={repo.id} value={repo.id}>
+                            {repo.display_name}
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                    <p className="text-sm text-muted-foreground">
+                      Configure scripts for each repository separately
+                    </p>
+                  </div>
+
+                  {loadingProjectRepo ? (
+                    <div className="flex items-center justify-center py-4">
+                      <Loader2 className="h-5 w-5 animate-spin" />
+                      <span className="ml-2 text-sm text-muted-foreground">
+                        Loading scripts...
+                      </span>
+                    </div>
+                  ) : scriptsDraft ? (
+                    <>
+                      <div className="space-y-2">
+                        <Label htmlFor="setup-script">
+                          {t('settings.projects.scripts.setup.label')}
+                        </Label>
+                        <AutoExpandingTextarea
+                          id="setup-script"
+                          value={scriptsDraft.setup_script}
+                          onChange={(e) =>
+                            updateScriptsDraft({ setup_script: e.target.value })
+                          }
+                          placeholder={placeholders.setup}
+                          maxRows={12}
+                          className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
+                        />
+                        <p className="text-sm text-muted-foreground">
+                          {t('settings.projects.scripts.setup.helper')}
+                        </p>
+
+                        <div className="flex items-center space-x-2 pt-2">
+                          <Checkbox
+                            id="parallel-setup-script"
+                            checked={scriptsDraft.parallel_setup_script}
+                            onCheckedChange={(checked) =>
+                              updateScriptsDraft({
+                                parallel_setup_script: checked === true,
+                              })
+                            }
+                            disabled={!scriptsDraft.setup_script.trim()}
+                          />
+                          <Label
+                            htmlFor="parallel-setup-script"
+                            className="text-sm font-normal cursor-pointer"
+                          >
+                            {t('settings.projects.scripts.setup.parallelLabel')}
+                          </Label>
+                        </div>
+                        <p className="text-sm text-muted-foreground pl-6">
+                          {t('settings.projects.scripts.setup.parallelHelper')}
+                        </p>
+                      </div>
+
+                      <div className="space-y-2">
+                        <Label htmlFor="cleanup-script">
+                          {t('settings.projects.scripts.cleanup.label')}
+                        </Label>
+                        <AutoExpandingTextarea
+                          id="cleanup-script"
+                          value={scriptsDraft.cleanup_script}
+                          onChange={(e) =>
+                            updateScriptsDraft({
+                              cleanup_script: e.target.value,
+                            })
+                          }
+                          placeholder={placeholders.cleanup}
+                          maxRows={12}
+                          className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
+                        />
+                        <p className="text-sm text-muted-foreground">
+                          {t('settings.projects.scripts.cleanup.helper')}
+                        </p>
+                      </div>
+
+                      <div className="space-y-2">
+                        <Label>
+                          {t('settings.projects.scripts.copyFiles.label')}
+                        </Label>
+                        <CopyFilesField
+                          value={scriptsDraft.copy_files}
+                          onChange={(value) =>
+                            updateScriptsDraft({ copy_files: value })
+                          }
+                          projectId={selectedProject.id}
+                        />
+                        <p className="text-sm text-muted-foreground">
+                          {t('settings.projects.scripts.copyFiles.helper')}
+                        </p>
+                      </div>
+
+                      <div className="space-y-2">
+                        <Label htmlFor="default-target-branch">
+                          {t('settings.projects.scripts.targetBranch.label')}
+                        </Label>
+                        <Input
+                          id="default-target-branch"
+                          value={scriptsDraft.default_target_branch}
+                          onChange={(e) =>
+                            updateScriptsDraft({
+                              default_target_branch: e.target.value,
+                            })
+                          }
+                          placeholder="main"
+                          className="font-mono"
+                        />
+                        <p className="text-sm text-muted-foreground">
+                          {t('settings.projects.scripts.targetBranch.helper')}
+                        </p>
+                      </div>
+
+                      {/* Scripts Save Buttons */}
+                      <div className="flex items-center justify-between pt-4 border-t">
+                        {hasUnsavedScriptsChanges ? (
+                          <span className="text-sm text-muted-foreground">
+                            {t('settings.projects.save.unsavedChanges')}
+                          </span>
+                        ) : (
+                          <span />
+                        )}
+                        <div className="flex gap-2">
+                          <Button
+                            variant="outline"
+                            onClick={handleDiscardScripts}
+                            disabled={
+                              !hasUnsavedScriptsChanges || savingScripts
+                            }
+                          >
+                            {t('settings.projects.save.discard')}
+                          </Button>
+                          <Button
+                            onClick={handleSaveScripts}
+                            disabled={
+                              !hasUnsavedScriptsChanges || savingScripts
+                            }
+                          >
+                            {savingScripts && (
+                              <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                            )}
+                            Save Scripts
+                          </Button>
+                        </div>
+                      </div>
+                    </>
+                  ) : null}
+                </>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center gap-2">
+                <Github className="h-5 w-5" />
+                {t('settings.projects.githubIntegration.title')}
+              </CardTitle>
+              <CardDescription>
+                {t('settings.projects.githubIntegration.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {githubError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{githubError}</AlertDescription>
+                </Alert>
+              )}
+
+              {githubSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.githubIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+
+              <div className="space-y-2">
+                <Label htmlFor="github-repo-url">
+                  {t('settings.projects.githubIntegration.repoUrl.label')}
+                </Label>
+                <Input
+                  id="github-repo-url"
+                  value={githubDraft.github_repo_url}
+                  onChange={(e) =>
+                    updateGithubDraft({ github_repo_url: e.target.value })
+                  }
+                  placeholder={t('settings.projects.githubIntegration.repoUrl.placeholder')}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.githubIntegration.repoUrl.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="github-token">
+                    {t('settings.projects.githubIntegration.token.label')}
+                  </Label>
+                  {hasExistingToken && !githubDraft.github_token && (
+                    <span className="text-xs text-green-600 dark:text-green-400">
+                      {t('settings.projects.githubIntegration.token.saved')}
+                    </span>
+                  )}
+                </div>
+                <div className="flex gap-2">
+                  <Input
+                    id="github-token"
+                    type={showToken ? 'text' : 'password'}
+                    value={githubDraft.github_token}
+                    onChange={(e) =>
+                      updateGithubDraft({ github_token: e.target.value })
+                    }
+                    placeholder={
+                      hasExistingToken
+                        ? t('settings.projects.githubIntegration.token.placeholderExisting')
+                        : t('settings.projects.githubIntegration.token.placeholder')
+                    }
+                    className="font-mono"
+                  />
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    type="button"
+                    onClick={() => setShowToken(!showToken)}
+                  >
+                    {showToken
+                      ? t('settings.projects.githubIntegration.token.hide')
+                      : t('settings.projects.githubIntegration.token.show')}
+                  </Button>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.githubIntegration.token.helper')}{' '}
+                  <a
+                    href="https://github.com/settings/tokens"
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    className="text-primary hover:underline"
+                  >
+                    {t('settings.projects.githubIntegration.token.settingsLink')}
+                    <ExternalLink className="inline h-3 w-3 ml-1" />
+                  </a>
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="github-sync-labels">
+                  {t('settings.projects.githubIntegration.syncLabels.label')}
+                </Label>
+                <Input
+                  id="github-sync-labels"
+                  value={githubDraft.github_sync_labels}
+                  onChange={(e) =>
+                    updateGithubDraft({ github_sync_labels: e.target.value })
+                  }
+                  placeholder={t('settings.projects.githubIntegration.syncLabels.placeholder')}
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.githubIntegration.syncLabels.helper')}
+                </p>
+              </div>
+
+              <div className="flex items-center space-x-2">
+                <Switch
+                  id="github-sync-enabled"
+                  checked={githubDraft.github_sync_enabled}
+                  onCheckedChange={(checked) =>
+                    updateGithubDraft({ github_sync_enabled: checked })
+                  }
+                />
+                <Label htmlFor="github-sync-enabled" className="cursor-pointer">
+                  {t('settings.projects.githubIntegration.autoSync.label')}
+                </Label>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.githubIntegration.autoSync.helper')}
+              </p>
+
+              <div className="flex items-center space-x-2">
+                <Switch
+                  id="github-sync-pull-requests"
+                  checked={githubDraft.github_sync_pull_requests}
+                  onCheckedChange={(checked) =>
+                    updateGithubDraft({ github_sync_pull_requests: checked })
+                  }
+                />
+                <Label
+                  htmlFor="github-sync-pull-requests"
+                  className="cursor-pointer"
+                >
+                  {t('settings.projects.githubIntegration.syncPullRequests.label')}
+                </Label>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.githubIntegration.syncPullRequests.helper')}
+              </p>
+
+              <div className="space-y-2">
+                <Label htmlFor="github-label-sync">
+                  {t('settings.projects.githubIntegration.labelSync.label')}
+                </Label>
+                <Select
+                  value={githubDraft.github_label_sync}
+                  onValueChange={(value) =>
+                    updateGithubDraft({
+                      github_label_sync: value as LabelSyncDirection,
+                    })
+                  }
+                >
+                  <SelectTrigger id="github-label-sync">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {(['off', 'import', 'export', 'both'] as const).map(
+                      (direction) => (
+                        <SelectItem key={direction} value={direction}>
+                          {t(
+                            `settings.projects.githubIntegration.labelSync.options.${direction}`
+                          )}
+                        </SelectItem>
+                      )
+                    )}
+                  </SelectContent>
+                </Select>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.githubIntegration.labelSync.helper')}
+                </p>
+              </div>
+
+              <div className="flex items-center justify-between pt-4 border-t">
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadIssues}
+                    disabled={loadingIssues || !githubDraft.github_repo_url}
+                  >
+                    {loadingIssues && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.githubIntegration.buttons.loadIssues')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncIssues}
+                    disabled={syncingIssues || !githubDraft.github_repo_url}
+                  >
+                    {syncingIssues ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.githubIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <Button
+                  onClick={handleSaveGithub}
+                  disabled={savingGithub}
+                >
+                  {savingGithub && (
+                    <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                  )}
+                  {t('settings.projects.githubIntegration.buttons.save')}
+                </Button>
+              </div>
+
+              <div className="pt-4 border-t space-y-2">
+                <Label htmlFor="github-restore-issue">
+                  {t('settings.projects.githubIntegration.restore.label')}
+                </Label>
+                <div className="flex gap-2">
+                  <Input
+                    id="github-restore-issue"
+                    value={restoreIssueNumber}
+                    onChange={(e) => setRestoreIssueNumber(e.target.value)}
+                    placeholder={t('settings.projects.githubIntegration.restore.placeholder')}
+                    className="max-w-40"
+                  />
+                  <Button
+                    variant="outline"
+                    onClick={handleRestoreIssue}
+                    disabled={restoringIssue || !restoreIssueNumber.trim() || !githubDraft.github_repo_url}
+                  >
+                    {restoringIssue && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.githubIntegration.buttons.restore')}
+                  </Button>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.githubIntegration.restore.helper')}
+                </p>
+              </div>
+
+              {githubIssues.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.githubIntegration.issues.title')} ({githubIssues.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {githubIssues.map((issue) => (
+                      <div
+                        key={issue.number}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1">
+                          <div className="font-medium truncate">
+                            #{issue.number} {issue.title}
+                          </div>
+                          <div className="flex items-center gap-2 text-sm text-muted-foreground">
+                            <span>{issue.user.login}</span>
+                            {issue.labels.length > 0 && (
+                              <div className="flex gap-1">
+                                {issue.labels.slice(0, 3).map((label) => (
+                                  <span
+                                    key={label.name}
+                                    className="px-1.5 py-0.5 text-xs rounded"
+                                    style={{
+                                      backgroundColor: `#${label.color}20`,
+                                      color: `#${label.color}`,
+                                    }}
+                                  >
+                                    {label.name}
+                                  </span>
+                                ))}
+                              </div>
+                            )}
+                          </div>
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportIssue(issue.number)}
+                        >
+                          {t('settings.projects.githubIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center gap-2">
+                <GitlabIcon className="h-5 w-5" />
+                {t('settings.projects.gitlabIntegration.title')}
+              </CardTitle>
+              <CardDescription>
+                {t('settings.projects.gitlabIntegration.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {gitlabError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{gitlabError}</AlertDescription>
+                </Alert>
+              )}
+
+              {gitlabSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.gitlabIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+
+              <div className="space-y-2">
+                <Label htmlFor="gitlab-base-url">
+                  {t('settings.projects.gitlabIntegration.baseUrl.label')}
+                </Label>
+                <Input
+                  id="gitlab-base-url"
+                  value={gitlabDraft.gitlab_base_url}
+                  onChange={(e) =>
+                    updateGitlabDraft({ gitlab_base_url: e.target.value })
+                  }
+                  placeholder={t('settings.projects.gitlabIntegration.baseUrl.placeholder')}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.gitlabIntegration.baseUrl.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="gitlab-project-url">
+                  {t('settings.projects.gitlabIntegration.projectUrl.label')}
+                </Label>
+                <Input
+                  id="gitlab-project-url"
+                  value={gitlabDraft.gitlab_project_url}
+                  onChange={(e) =>
+                    updateGitlabDraft({ gitlab_project_url: e.target.value })
+                  }
+                  placeholder={t('settings.projects.gitlabIntegration.projectUrl.placeholder')}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.gitlabIntegration.projectUrl.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="gitlab-token">
+                    {t('settings.projects.gitlabIntegration.token.label')}
+                  </Label>
+                  {hasExistingGitlabToken && !gitlabDraft.gitlab_token && (
+                    <span className="text-xs text-green-600 dark:text-green-400">
+                      {t('settings.projects.gitlabIntegration.token.saved')}
+                    </span>
+                  )}
+                </div>
+                <div className="flex gap-2">
+                  <Input
+                    id="gitlab-token"
+                    type={showGitlabToken ? 'text' : 'password'}
+                    value={gitlabDraft.gitlab_token}
+                    onChange={(e) =>
+                      updateGitlabDraft({ gitlab_token: e.target.value })
+                    }
+                    placeholder={
+                      hasExistingGitlabToken
+                        ? t('settings.projects.gitlabIntegration.token.placeholderExisting')
+                        : t('settings.projects.gitlabIntegration.token.placeholder')
+                    }
+                    className="font-mono"
+                  />
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    type="button"
+                    onClick={() => setShowGitlabToken(!showGitlabToken)}
+                  >
+                    {showGitlabToken
+                      ? t('settings.projects.gitlabIntegration.token.hide')
+                      : t('settings.projects.gitlabIntegration.token.show')}
+                  </Button>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.gitlabIntegration.token.helper')}{' '}
+                  <a
+                    href="https://gitlab.com/-/user_settings/personal_access_tokens"
+                    target="_blank"
+                    rel="noopener noreferrer"
+                    className="text-primary hover:underline"
+                  >
+                    {t('settings.projects.gitlabIntegration.token.settingsLink')}
+                    <ExternalLink className="inline h-3 w-3 ml-1" />
+                  </a>
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="gitlab-sync-labels">
+                  {t('settings.projects.gitlabIntegration.syncLabels.label')}
+                </Label>
+                <Input
+                  id="gitlab-sync-labels"
+                  value={gitlabDraft.gitlab_sync_labels}
+                  onChange={(e) =>
+                    updateGitlabDraft({ gitlab_sync_labels: e.target.value })
+                  }
+                  placeholder={t('settings.projects.gitlabIntegration.syncLabels.placeholder')}
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.gitlabIntegration.syncLabels.helper')}
+                </p>
+              </div>
+
+              <div className="flex items-center space-x-2">
+                <Switch
+                  id="gitlab-sync-enabled"
+                  checked={gitlabDraft.gitlab_sync_enabled}
+                  onCheckedChange={(checked) =>
+                    updateGitlabDraft({ gitlab_sync_enabled: checked })
+                  }
+                />
+                <Label htmlFor="gitlab-sync-enabled" className="cursor-pointer">
+                  {t('settings.projects.gitlabIntegration.autoSync.label')}
+                </Label>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.gitlabIntegration.autoSync.helper')}
+              </p>
+
+              <div className="flex items-center justify-between pt-4 border-t">
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadGitlabIssues}
+                    disabled={loadingGitlabIssues || !gitlabDraft.gitlab_project_url}
+                  >
+                    {loadingGitlabIssues && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.gitlabIntegration.buttons.loadIssues')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncGitlabIssues}
+                    disabled={syncingGitlabIssues || !gitlabDraft.gitlab_project_url}
+                  >
+                    {syncingGitlabIssues ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.gitlabIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <Button
+                  onClick={handleSaveGitlab}
+                  disabled={savingGitlab}
+                >
+                  {savingGitlab && (
+                    <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                  )}
+                  {t('settings.projects.gitlabIntegration.buttons.save')}
+                </Button>
+              </div>
+
+              {gitlabIssues.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.gitlabIntegration.issues.title')} ({gitlabIssues.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {gitlabIssues.map((issue) => (
+                      <div
+                        key={issue.iid}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1">
+                          <div className="font-medium truncate">
+                            #{issue.iid} {issue.title}
+                          </div>
+                          <div className="flex items-center gap-2 text-sm text-muted-foreground">
+                            <span>{issue.author.username}</span>
+                            {issue.labels.length > 0 && (
+                              <div className="flex gap-1">
+                                {issue.labels.slice(0, 3).map((label) => (
+                                  <span
+                                    key={label}
+                                    className="px-1.5 py-0.5 text-xs rounded bg-muted"
+                                  >
+                                    {label}
+                                  </span>
+                                ))}
+                              </div>
+                            )}
+                          </div>
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportGitlabIssue(issue.iid)}
+                        >
+                          {t('settings.projects.gitlabIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center gap-2">
+                <GitFork className="h-5 w-5" />
+                {t('settings.projects.giteaIntegration.title')}
+              </CardTitle>
+              <CardDescription>
+                {t('settings.projects.giteaIntegration.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {giteaError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{giteaError}</AlertDescription>
+                </Alert>
+              )}
+
+              {giteaSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.giteaIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+
+              <div className="space-y-2">
+                <Label htmlFor="gitea-base-url">
+                  {t('settings.projects.giteaIntegration.baseUrl.label')}
+                </Label>
+                <Input
+                  id="gitea-base-url"
+                  value={giteaDraft.gitea_base_url}
+                  onChange={(e) =>
+                    updateGiteaDraft({ gitea_base_url: e.target.value })
+                  }
+                  placeholder={t('settings.projects.giteaIntegration.baseUrl.placeholder')}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.giteaIntegration.baseUrl.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="gitea-repo">
+                  {t('settings.projects.giteaIntegration.repo.label')}
+                </Label>
+                <Input
+                  id="gitea-repo"
+                  value={giteaDraft.gitea_repo}
+                  onChange={(e) =>
+                    updateGiteaDraft({ gitea_repo: e.target.value })
+                  }
+                  placeholder={t('settings.projects.giteaIntegration.repo.placeholder')}
+                  className="font-mono"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.giteaIntegration.repo.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="gitea-token">
+                    {t('settings.projects.giteaIntegration.token.label')}
+                  </Label>
+                  {hasExistingGiteaToken && !giteaDraft.gitea_token && (
+                    <span className="text-xs text-green-600 dark:text-green-400">
+                      {t('settings.projects.giteaIntegration.token.saved')}
+                    </span>
+                  )}
+                </div>
+                <div className="flex gap-2">
+                  <Input
+                    id="gitea-token"
+                    type={showGiteaToken ? 'text' : 'password'}
+                    value={giteaDraft.gitea_token}
+                    onChange={(e) =>
+                      updateGiteaDraft({ gitea_token: e.target.value })
+                    }
+                    placeholder={
+                      hasExistingGiteaToken
+                        ? t('settings.projects.giteaIntegration.token.placeholderExisting')
+                        : t('settings.projects.giteaIntegration.token.placeholder')
+                    }
+                    className="font-mono"
+                  />
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    type="button"
+                    onClick={() => setShowGiteaToken(!showGiteaToken)}
+                  >
+                    {showGiteaToken
+                      ? t('settings.projects.giteaIntegration.token.hide')
+                      : t('settings.projects.giteaIntegration.token.show')}
+                  </Button>
+                </div>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.giteaIntegration.token.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="gitea-sync-labels">
+                  {t('settings.projects.giteaIntegration.syncLabels.label')}
+                </Label>
+                <Input
+                  id="gitea-sync-labels"
+                  value={giteaDraft.gitea_sync_labels}
+                  onChange={(e) =>
+                    updateGiteaDraft({ gitea_sync_labels: e.target.value })
+                  }
+                  placeholder={t('settings.projects.giteaIntegration.syncLabels.placeholder')}
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.giteaIntegration.syncLabels.helper')}
                 </p>
               </div>
 
+              <div className="flex items-center space-x-2">
+                <Switch
+                  id="gitea-sync-enabled"
+                  checked={giteaDraft.gitea_sync_enabled}
+                  onCheckedChange={(checked) =>
+                    updateGiteaDraft({ gitea_sync_enabled: checked })
+                  }
+                />
+                <Label htmlFor="gitea-sync-enabled" className="cursor-pointer">
+                  {t('settings.projects.giteaIntegration.autoSync.label')}
+                </Label>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.giteaIntegration.autoSync.helper')}
+              </p>
+
+              <div className="flex items-center justify-between pt-4 border-t">
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadGiteaIssues}
+                    disabled={loadingGiteaIssues || !giteaDraft.gitea_repo}
+                  >
+                    {loadingGiteaIssues && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.giteaIntegration.buttons.loadIssues')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncGiteaIssues}
+                    disabled={syncingGiteaIssues || !giteaDraft.gitea_repo}
+                  >
+                    {syncingGiteaIssues ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.giteaIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <Button
+                  onClick={handleSaveGitea}
+                  disabled={savingGitea}
+                >
+                  {savingGitea && (
+                    <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                  )}
+                  {t('settings.projects.giteaIntegration.buttons.save')}
+                </Button>
+              </div>
+
+              {giteaIssues.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.giteaIntegration.issues.title')} ({giteaIssues.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {giteaIssues.map((issue) => (
+                      <div
+                        key={Number(issue.number)}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1">
+                          <div className="font-medium truncate">
+                            #{Number(issue.number)} {issue.title}
+                          </div>
+                          <div className="flex items-center gap-2 text-sm text-muted-foreground">
+                            <span>{issue.user.login}</span>
+                            {issue.labels.length > 0 && (
+                              <div className="flex gap-1">
+                                {issue.labels.slice(0, 3).map((label) => (
+                                  <span
+                                    key={label.name}
+                                    className="px-1.5 py-0.5 text-xs rounded bg-muted"
+                                  >
+                                    {label.name}
+                                  </span>
+                                ))}
+                              </div>
+                            )}
+                          </div>
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportGiteaIssue(Number(issue.number))}
+                        >
+                          {t('settings.projects.giteaIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center gap-2">
+                <ListChecks className="h-5 w-5" />
+                {t('settings.projects.linearIntegration.title')}
+              </CardTitle>
+              <CardDescription>
+                {t('settings.projects.linearIntegration.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {linearError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{linearError}</AlertDescription>
+                </Alert>
+              )}
+
+              {linearSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.linearIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+
               <div className="space-y-2">
-                <Label htmlFor="dev-script">
-                  {t('settings.projects.scripts.dev.label')}
-                </Label>
-                <AutoExpandingTextarea
-                  id="dev-script"
-                  value={draft.dev_script}
-                  onChange={(e) => updateDraft({ dev_script: e.target.value })}
-                  placeholder={placeholders.dev}
-                  maxRows={12}
-                  className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
-                />
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="linear-api-key">
+                    {t('settings.projects.linearIntegration.apiKey.label')}
+                  </Label>
+                  {linearConnection && !linearDraft.api_key && (
+                    <span className="text-xs text-muted-foreground">
+                      {t('settings.projects.linearIntegration.apiKey.saved')}
+                    </span>
+                  )}
+                </div>
+                <div className="flex gap-2">
+                  <Input
+                    id="linear-api-key"
+                    type="password"
+                    value={linearDraft.api_key}
+                    onChange={(e) =>
+                      updateLinearDraft({ api_key: e.target.value })
+                    }
+                    placeholder={
+                      linearConnection
+                        ? t(
+                            'settings.projects.linearIntegration.apiKey.placeholderExisting'
+                          )
+                        : 'lin_api_xxxxxxxxxxxxxxxxxxxx'
+                    }
+                  />
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadLinearTeams}
+                    disabled={
+                      loadingLinearTeams ||
+                      (!linearConnection && !linearDraft.api_key.trim())
+                    }
+                  >
+                    {loadingLinearTeams && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.linearIntegration.buttons.loadTeams')}
+                  </Button>
+                </div>
                 <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.scripts.dev.helper')}
+                  {t('settings.projects.linearIntegration.apiKey.helper')}
                 </p>
               </div>
 
               <div className="space-y-2">
-                <Label htmlFor="dev-script-working-dir">
-                  {t('settings.projects.scripts.devWorkingDir.label')}
+                <Label htmlFor="linear-team">
+                  {t('settings.projects.linearIntegration.team.label')}
                 </Label>
-                <Input
-                  id="dev-script-working-dir"
-                  value={draft.dev_script_working_dir}
-                  onChange={(e) =>
-                    updateDraft({ dev_script_working_dir: e.target.value })
+                <Select
+                  value={linearDraft.team_id}
+                  onValueChange={(teamId) =>
+                    updateLinearDraft({ team_id: teamId })
                   }
-                  placeholder={t(
-                    'settings.projects.scripts.devWorkingDir.placeholder'
-                  )}
-                  className="font-mono"
-                />
-                <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.scripts.devWorkingDir.helper')}
-                </p>
+                >
+                  <SelectTrigger id="linear-team">
+                    <SelectValue
+                      placeholder={t(
+                        'settings.projects.linearIntegration.team.placeholder'
+                      )}
+                    />
+                  </SelectTrigger>
+                  <SelectContent>
+                    {linearTeams.map((team) => (
+                      <SelectItem key={team.id} value={team.id}>
+                        {team.key} · {team.name}
+                      </SelectItem>
+                    ))}
+                    {linearConnection &&
+                      !linearTeams.some(
+                        (team) => team.id === linearConnection.team_id
+                      ) && (
+                        <SelectItem value={linearConnection.team_id}>
+                          {linearConnection.team_key}
+                        </SelectItem>
+                      )}
+                  </SelectContent>
+                </Select>
               </div>
 
               <div className="space-y-2">
-                <Label htmlFor="agent-working-dir">
-                  {t('settings.projects.scripts.agentWorkingDir.label')}
+                <Label htmlFor="linear-sync-labels">
+                  {t('settings.projects.linearIntegration.syncLabels.label')}
                 </Label>
                 <Input
-                  id="agent-working-dir"
-                  value={draft.default_agent_working_dir}
+                  id="linear-sync-labels"
+                  value={linearDraft.sync_labels}
                   onChange={(e) =>
-                    updateDraft({ default_agent_working_dir: e.target.value })
+                    updateLinearDraft({ sync_labels: e.target.value })
                   }
-                  placeholder={t(
-                    'settings.projects.scripts.agentWorkingDir.placeholder'
-                  )}
-                  className="font-mono"
+                  placeholder="Bug, Feature"
                 />
                 <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.scripts.agentWorkingDir.helper')}
+                  {t('settings.projects.linearIntegration.syncLabels.helper')}
                 </p>
               </div>
 
-              {/* Save Button */}
+              <div className="flex items-center space-x-2">
+                <Switch
+                  id="linear-status-sync"
+                  checked={linearDraft.status_sync_enabled}
+                  onCheckedChange={(checked) =>
+                    updateLinearDraft({ status_sync_enabled: checked })
+                  }
+                />
+                <Label htmlFor="linear-status-sync" className="cursor-pointer">
+                  {t('settings.projects.linearIntegration.statusSync.label')}
+                </Label>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.linearIntegration.statusSync.helper')}
+              </p>
+
               <div className="flex items-center justify-between pt-4 border-t">
-                {hasUnsavedProjectChanges ? (
-                  <span className="text-sm text-muted-foreground">
-                    {t('settings.projects.save.unsavedChanges')}
-                  </span>
-                ) : (
-                  <span />
-                )}
                 <div className="flex gap-2">
                   <Button
                     variant="outline"
-                    onClick={handleDiscard}
-                    disabled={saving || !hasUnsavedProjectChanges}
+                    onClick={handleLoadLinearIssues}
+                    disabled={loadingLinearIssues || !linearConnection}
                   >
-                    {t('settings.projects.save.discard')}
+                    {loadingLinearIssues && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.linearIntegration.buttons.loadIssues')}
                   </Button>
                   <Button
-                    onClick={handleSave}
-                    disabled={saving || !hasUnsavedProjectChanges}
+                    variant="outline"
+                    onClick={handleSyncLinearIssues}
+                    disabled={syncingLinearIssues || !linearConnection}
                   >
-                    {saving ? (
-                      <>
-                        <Loader2 className="h-4 w-4 mr-2 animate-spin" />
-                        {t('settings.projects.save.saving')}
-                      </>
+                    {syncingLinearIssues ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     ) : (
-                      t('settings.projects.save.button')
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.linearIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <div className="flex gap-2">
+                  {linearConnection && (
+                    <Button variant="outline" onClick={handleDisconnectLinear}>
+                      {t(
+                        'settings.projects.linearIntegration.buttons.disconnect'
+                      )}
+                    </Button>
+                  )}
+                  <Button
+                    onClick={handleSaveLinear}
+                    disabled={savingLinear || !linearDraft.team_id}
+                  >
+                    {savingLinear && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     )}
+                    {t('settings.projects.linearIntegration.buttons.save')}
                   </Button>
                 </div>
               </div>
-              {error && (
-                <Alert variant="destructive">
-                  <AlertDescription>{error}</AlertDescription>
-                </Alert>
-              )}
-              {success && (
-                <Alert>
-                  <AlertDescription>
-                    {t('settings.projects.save.success')}
-                  </AlertDescription>
-                </Alert>
+
+              {linearIssues.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.linearIntegration.issues.title')} (
+                    {linearIssues.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {linearIssues.map((issue) => (
+                      <div
+                        key={issue.id}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1">
+                          <div className="font-medium truncate">
+                            {issue.key} {issue.title}
+                          </div>
+                          {issue.labels.length > 0 && (
+                            <div className="flex gap-1">
+                              {issue.labels.slice(0, 3).map((label) => (
+                                <span
+                                  key={label}
+                                  className="px-1.5 py-0.5 text-xs rounded bg-muted"
+                                >
+                                  {label}
+                                </span>
+                              ))}
+                            </div>
+                          )}
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportLinearIssue(issue.id)}
+                        >
+                          {t('settings.projects.linearIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
               )}
             </CardContent>
           </Card>
 
-          {/* Repositories Section */}
           <Card>
             <CardHeader>
-              <CardTitle>Repositories</CardTitle>
+              <CardTitle className="flex items-center gap-2">
+                <LayoutList className="h-5 w-5" />
+                {t('settings.projects.azureIntegration.title')}
+              </CardTitle>
               <CardDescription>
-                Manage the git repositories in this project
+                {t('settings.projects.azureIntegration.description')}
               </CardDescription>
             </CardHeader>
             <CardContent className="space-y-4">
-              {repoError && (
+              {azureError && (
                 <Alert variant="destructive">
-                  <AlertDescription>{repoError}</AlertDescription>
+                  <AlertDescription>{azureError}</AlertDescription>
                 </Alert>
               )}
 
-              {loadingRepos ? (
-                <div className="flex items-center justify-center py-4">
-                  <Loader2 className="h-5 w-5 animate-spin" />
-                  <span className="ml-2 text-sm text-muted-foreground">
-                    Loading repositories...
-                  </span>
-                </div>
-              ) : (
-                <div className="space-y-2">
-                  {repositories.map((repo) => (
-                    <div
-                      key={repo.id}
-                      className="flex items-center justify-between p-3 border rounded-md"
-                    >
-                      <div className="min-w-0 flex-1">
-                        <div className="font-medium">{repo.display_name}</div>
-                        <div className="text-sm text-muted-foreground truncate">
-                          {repo.path}
-                        </div>
-                      </div>
-                      <Button
-                        variant="ghost"
-                        size="sm"
-                        onClick={() => handleDeleteRepository(repo.id)}
-                        disabled={deletingRepoId === repo.id}
-                        title="Delete repository"
-                      >
-                        {deletingRepoId === repo.id ? (
-                          <Loader2 className="h-4 w-4 animate-spin" />
-                        ) : (
-                          <Trash2 className="h-4 w-4" />
-                        )}
-                      </Button>
-                    </div>
-                  ))}
+              {azureSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.azureIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
 
-                  {repositories.length === 0 && !loadingRepos && (
-                    <div className="text-center py-4 text-sm text-muted-foreground">
-                      No repositories configured
-                    </div>
+              <div className="space-y-2">
+                <Label htmlFor="azure-organization-url">
+                  {t('settings.projects.azureIntegration.organizationUrl.label')}
+                </Label>
+                <Input
+                  id="azure-organization-url"
+                  value={azureDraft.organization_url}
+                  onChange={(e) =>
+                    updateAzureDraft({ organization_url: e.target.value })
+                  }
+                  placeholder="https://dev.azure.com/my-org"
+                />
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="azure-project">
+                  {t('settings.projects.azureIntegration.project.label')}
+                </Label>
+                <Input
+                  id="azure-project"
+                  value={azureDraft.azure_project}
+                  onChange={(e) =>
+                    updateAzureDraft({ azure_project: e.target.value })
+                  }
+                  placeholder="Fabrikam"
+                />
+              </div>
+
+              <div className="space-y-2">
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="azure-pat">
+                    {t('settings.projects.azureIntegration.pat.label')}
+                  </Label>
+                  {azureConnection && !azureDraft.pat && (
+                    <span className="text-xs text-muted-foreground">
+                      {t('settings.projects.azureIntegration.pat.saved')}
+                    </span>
                   )}
+                </div>
+                <Input
+                  id="azure-pat"
+                  type="password"
+                  value={azureDraft.pat}
+                  onChange={(e) => updateAzureDraft({ pat: e.target.value })}
+                  placeholder={
+                    azureConnection
+                      ? t(
+                          'settings.projects.azureIntegration.pat.placeholderExisting'
+                        )
+                      : ''
+                  }
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.azureIntegration.pat.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="azure-wiql-filter">
+                  {t('settings.projects.azureIntegration.wiqlFilter.label')}
+                </Label>
+                <Input
+                  id="azure-wiql-filter"
+                  value={azureDraft.wiql_filter}
+                  onChange={(e) =>
+                    updateAzureDraft({ wiql_filter: e.target.value })
+                  }
+                  placeholder="[System.AreaPath] UNDER 'Fabrikam\Web'"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.azureIntegration.wiqlFilter.helper')}
+                </p>
+              </div>
 
+              <div className="flex items-center justify-between pt-4 border-t">
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadAzureWorkItems}
+                    disabled={loadingAzureWorkItems || !azureConnection}
+                  >
+                    {loadingAzureWorkItems && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.azureIntegration.buttons.loadItems')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncAzureWorkItems}
+                    disabled={syncingAzureWorkItems || !azureConnection}
+                  >
+                    {syncingAzureWorkItems ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.azureIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <div className="flex gap-2">
+                  {azureConnection && (
+                    <Button variant="outline" onClick={handleDisconnectAzure}>
+                      {t('settings.projects.azureIntegration.buttons.disconnect')}
+                    </Button>
+                  )}
                   <Button
-                    variant="outline"
-                    size="sm"
-                    onClick={handleAddRepository}
-                    disabled={addingRepo}
-                    className="w-full"
+                    onClick={handleSaveAzure}
+                    disabled={
+                      savingAzure ||
+                      !azureDraft.organization_url.trim() ||
+                      !azureDraft.azure_project.trim()
+                    }
                   >
-                    {addingRepo ? (
-                      <Loader2 className="h-4 w-4 mr-2 animate-spin" />
-                    ) : (
-                      <Plus className="h-4 w-4 mr-2" />
+                    {savingAzure && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     )}
-                    Add Repository
+                    {t('settings.projects.azureIntegration.buttons.save')}
                   </Button>
                 </div>
+              </div>
+
+              {azureWorkItems.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.azureIntegration.items.title')} (
+                    {azureWorkItems.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {azureWorkItems.map((item) => (
+                      <div
+                        key={item.id}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1">
+                          <div className="font-medium truncate">
+                            #{item.key} {item.title}
+                          </div>
+                          {item.labels.length > 0 && (
+                            <div className="flex gap-1">
+                              {item.labels.slice(0, 3).map((tag) => (
+                                <span
+                                  key={tag}
+                                  className="px-1.5 py-0.5 text-xs rounded bg-muted"
+                                >
+                                  {tag}
+                                </span>
+                              ))}
+                            </div>
+                          )}
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportAzureWorkItem(item.id)}
+                        >
+                          {t('settings.projects.azureIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
               )}
             </CardContent>
           </Card>
 
           <Card>
             <CardHeader>
-              <CardTitle>{t('settings.projects.scripts.title')}</CardTitle>
+              <CardTitle className="flex items-center gap-2">
+                <NotebookTabs className="h-5 w-5" />
+                {t('settings.projects.notionIntegration.title')}
+              </CardTitle>
               <CardDescription>
-                {t('settings.projects.scripts.description')}
+                {t('settings.projects.notionIntegration.description')}
               </CardDescription>
             </CardHeader>
             <CardContent className="space-y-4">
-              {scriptsError && (
+              {notionError && (
                 <Alert variant="destructive">
-                  <AlertDescription>{scriptsError}</AlertDescription>
+                  <AlertDescription>{notionError}</AlertDescription>
                 </Alert>
               )}
 
-              {scriptsSuccess && (
+              {notionSuccess && (
                 <Alert variant="success">
                   <AlertDescription className="font-medium">
-                    Scripts saved successfully
+                    {t('settings.projects.notionIntegration.messages.success')}
                   </AlertDescription>
                 </Alert>
               )}
 
-              {repositories.length === 0 ? (
-                <div className="text-center py-4 text-sm text-muted-foreground">
-                  Add a repository above to configure scripts
-                </div>
-              ) : (
-                <>
-                  {/* Repository Selector for Scripts */}
-                  <div className="space-y-2">
-                    <Label htmlFor="scripts-repo-selector">Repository</Label>
-                    <Select
-                      value={selectedScriptsRepoId ?? ''}
-                      onValueChange={setSelectedScriptsRepoId}
-                    >
-                      <SelectTrigger id="scripts-repo-selector">
-                        <SelectValue placeholder="Select a repository" />
-                      </SelectTrigger>
-                      <SelectContent>
-                        {repositories.map((repo) => (
-                          <SelectItem key={repo.id} value={repo.id}>
-                            {repo.display_name}
-                          </SelectItem>
-                        ))}
-                      </SelectContent>
-                    </Select>
-                    <p className="text-sm text-muted-foreground">
-                      Configure scripts for each repository separately
-                    </p>
-                  </div>
-
-                  {loadingProjectRepo ? (
-                    <div className="flex items-center justify-center py-4">
-                      <Loader2 className="h-5 w-5 animate-spin" />
-                      <span className="ml-2 text-sm text-muted-foreground">
-                        Loading scripts...
-                      </span>
-                    </div>
-                  ) : scriptsDraft ? (
-                    <>
-                      <div className="space-y-2">
-                        <Label htmlFor="setup-script">
-                          {t('settings.projects.scripts.setup.label')}
-                        </Label>
-                        <AutoExpandingTextarea
-                          id="setup-script"
-                          value={scriptsDraft.setup_script}
-                          onChange={(e) =>
-                            updateScriptsDraft({ setup_script: e.target.value })
-                          }
-                          placeholder={placeholders.setup}
-                          maxRows={12}
-                          className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
-                        />
-                        <p className="text-sm text-muted-foreground">
-                          {t('settings.projects.scripts.setup.helper')}
-                        </p>
+              <div className="space-y-2">
+                <Label htmlFor="notion-database-id">
+                  {t('settings.projects.notionIntegration.database.label')}
+                </Label>
+                <Input
+                  id="notion-database-id"
+                  value={notionDraft.database_id}
+                  onChange={(e) =>
+                    updateNotionDraft({ database_id: e.target.value })
+                  }
+                  placeholder="https://www.notion.so/acme/Roadmap-0f2b3c4d5e6f4a1b9c8d7e6f5a4b3c2d"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.notionIntegration.database.helper')}
+                </p>
+              </div>
 
-                        <div className="flex items-center space-x-2 pt-2">
-                          <Checkbox
-                            id="parallel-setup-script"
-                            checked={scriptsDraft.parallel_setup_script}
-                            onCheckedChange={(checked) =>
-                              updateScriptsDraft({
-                                parallel_setup_script: checked === true,
-                              })
-                            }
-                            disabled={!scriptsDraft.setup_script.trim()}
-                          />
-                          <Label
-                            htmlFor="parallel-setup-script"
-                            className="text-sm font-normal cursor-pointer"
-                          >
-                            {t('settings.projects.scripts.setup.parallelLabel')}
-                          </Label>
-                        </div>
-                        <p className="text-sm text-muted-foreground pl-6">
-                          {t('settings.projects.scripts.setup.parallelHelper')}
-                        </p>
-                      </div>
+              <div className="space-y-2">
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="notion-token">
+                    {t('settings.projects.notionIntegration.token.label')}
+                  </Label>
+                  {notionConnection && !notionDraft.token && (
+                    <span className="text-xs text-muted-foreground">
+                      {t('settings.projects.notionIntegration.token.saved')}
+                    </span>
+                  )}
+                </div>
+                <Input
+                  id="notion-token"
+                  type="password"
+                  value={notionDraft.token}
+                  onChange={(e) => updateNotionDraft({ token: e.target.value })}
+                  placeholder={
+                    notionConnection
+                      ? t(
+                          'settings.projects.notionIntegration.token.placeholderExisting'
+                        )
+                      : 'secret_...'
+                  }
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.notionIntegration.token.helper')}
+                </p>
+              </div>
 
-                      <div className="space-y-2">
-                        <Label htmlFor="cleanup-script">
-                          {t('settings.projects.scripts.cleanup.label')}
-                        </Label>
-                        <AutoExpandingTextarea
-                          id="cleanup-script"
-                          value={scriptsDraft.cleanup_script}
-                          onChange={(e) =>
-                            updateScriptsDraft({
-                              cleanup_script: e.target.value,
-                            })
-                          }
-                          placeholder={placeholders.cleanup}
-                          maxRows={12}
-                          className="w-full px-3 py-2 border border-input bg-background text-foreground rounded-md focus:outline-none focus:ring-2 focus:ring-ring font-mono"
-                        />
-                        <p className="text-sm text-muted-foreground">
-                          {t('settings.projects.scripts.cleanup.helper')}
-                        </p>
-                      </div>
+              <div className="grid grid-cols-2 gap-4">
+                <div className="space-y-2">
+                  <Label htmlFor="notion-status-property">
+                    {t(
+                      'settings.projects.notionIntegration.statusProperty.label'
+                    )}
+                  </Label>
+                  <Input
+                    id="notion-status-property"
+                    value={notionDraft.status_property}
+                    onChange={(e) =>
+                      updateNotionDraft({ status_property: e.target.value })
+                    }
+                    placeholder="Status"
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="notion-description-property">
+                    {t(
+                      'settings.projects.notionIntegration.descriptionProperty.label'
+                    )}
+                  </Label>
+                  <Input
+                    id="notion-description-property"
+                    value={notionDraft.description_property}
+                    onChange={(e) =>
+                      updateNotionDraft({ description_property: e.target.value })
+                    }
+                    placeholder="Description"
+                  />
+                </div>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.notionIntegration.properties.helper')}
+              </p>
 
-                      <div className="space-y-2">
-                        <Label>
-                          {t('settings.projects.scripts.copyFiles.label')}
-                        </Label>
-                        <CopyFilesField
-                          value={scriptsDraft.copy_files}
-                          onChange={(value) =>
-                            updateScriptsDraft({ copy_files: value })
-                          }
-                          projectId={selectedProject.id}
-                        />
-                        <p className="text-sm text-muted-foreground">
-                          {t('settings.projects.scripts.copyFiles.helper')}
-                        </p>
-                      </div>
+              <div className="flex items-center justify-between pt-4 border-t">
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadNotionRows}
+                    disabled={loadingNotionRows || !notionConnection}
+                  >
+                    {loadingNotionRows && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.notionIntegration.buttons.loadRows')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncNotionRows}
+                    disabled={syncingNotionRows || !notionConnection}
+                  >
+                    {syncingNotionRows ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.notionIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <div className="flex gap-2">
+                  {notionConnection && (
+                    <Button variant="outline" onClick={handleDisconnectNotion}>
+                      {t('settings.projects.notionIntegration.buttons.disconnect')}
+                    </Button>
+                  )}
+                  <Button
+                    onClick={handleSaveNotion}
+                    disabled={savingNotion || !notionDraft.database_id.trim()}
+                  >
+                    {savingNotion && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.notionIntegration.buttons.save')}
+                  </Button>
+                </div>
+              </div>
 
-                      {/* Scripts Save Buttons */}
-                      <div className="flex items-center justify-between pt-4 border-t">
-                        {hasUnsavedScriptsChanges ? (
-                          <span className="text-sm text-muted-foreground">
-                            {t('settings.projects.save.unsavedChanges')}
-                          </span>
-                        ) : (
-                          <span />
-                        )}
-                        <div className="flex gap-2">
-                          <Button
-                            variant="outline"
-                            onClick={handleDiscardScripts}
-                            disabled={
-                              !hasUnsavedScriptsChanges || savingScripts
-                            }
-                          >
-                            {t('settings.projects.save.discard')}
-                          </Button>
-                          <Button
-                            onClick={handleSaveScripts}
-                            disabled={
-                              !hasUnsavedScriptsChanges || savingScripts
-                            }
-                          >
-                            {savingScripts && (
-                              <Loader2 className="mr-2 h-4 w-4 animate-spin" />
-                            )}
-                            Save Scripts
-                          </Button>
+              {notionRows.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.notionIntegration.rows.title')} (
+                    {notionRows.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {notionRows.map((row) => (
+                      <div
+                        key={row.id}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1 font-medium truncate">
+                          {row.title}
                         </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportNotionRow(row.id)}
+                        >
+                          {t('settings.projects.notionIntegration.buttons.import')}
+                        </Button>
                       </div>
-                    </>
-                  ) : null}
-                </>
+                    ))}
+                  </div>
+                </div>
               )}
             </CardContent>
           </Card>
@@ -1390,207 +4361,499 @@ export function ProjectSettings() {
           <Card>
             <CardHeader>
               <CardTitle className="flex items-center gap-2">
-                <Github className="h-5 w-5" />
-                {t('settings.projects.githubIntegration.title')}
+                <CircleCheckBig className="h-5 w-5" />
+                {t('settings.projects.asanaIntegration.title')}
               </CardTitle>
               <CardDescription>
-                {t('settings.projects.githubIntegration.description')}
+                {t('settings.projects.asanaIntegration.description')}
               </CardDescription>
             </CardHeader>
             <CardContent className="space-y-4">
-              {githubError && (
+              {asanaError && (
                 <Alert variant="destructive">
-                  <AlertDescription>{githubError}</AlertDescription>
+                  <AlertDescription>{asanaError}</AlertDescription>
                 </Alert>
               )}
 
-              {githubSuccess && (
+              {asanaSuccess && (
                 <Alert variant="success">
                   <AlertDescription className="font-medium">
-                    {t('settings.projects.githubIntegration.messages.success')}
+                    {t('settings.projects.asanaIntegration.messages.success')}
                   </AlertDescription>
                 </Alert>
               )}
 
               <div className="space-y-2">
-                <Label htmlFor="github-repo-url">
-                  {t('settings.projects.githubIntegration.repoUrl.label')}
+                <Label htmlFor="asana-project-gid">
+                  {t('settings.projects.asanaIntegration.project.label')}
                 </Label>
                 <Input
-                  id="github-repo-url"
-                  value={githubDraft.github_repo_url}
+                  id="asana-project-gid"
+                  value={asanaDraft.project_gid}
                   onChange={(e) =>
-                    updateGithubDraft({ github_repo_url: e.target.value })
+                    updateAsanaDraft({ project_gid: e.target.value })
                   }
-                  placeholder={t('settings.projects.githubIntegration.repoUrl.placeholder')}
-                  className="font-mono"
+                  placeholder="https://app.asana.com/0/1203456789012345/list"
                 />
                 <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.githubIntegration.repoUrl.helper')}
+                  {t('settings.projects.asanaIntegration.project.helper')}
                 </p>
               </div>
 
               <div className="space-y-2">
                 <div className="flex items-center gap-2">
-                  <Label htmlFor="github-token">
-                    {t('settings.projects.githubIntegration.token.label')}
+                  <Label htmlFor="asana-token">
+                    {t('settings.projects.asanaIntegration.token.label')}
                   </Label>
-                  {hasExistingToken && !githubDraft.github_token && (
-                    <span className="text-xs text-green-600 dark:text-green-400">
-                      {t('settings.projects.githubIntegration.token.saved')}
+                  {asanaConnection && !asanaDraft.token && (
+                    <span className="text-xs text-muted-foreground">
+                      {t('settings.projects.asanaIntegration.token.saved')}
                     </span>
                   )}
                 </div>
+                <Input
+                  id="asana-token"
+                  type="password"
+                  value={asanaDraft.token}
+                  onChange={(e) => updateAsanaDraft({ token: e.target.value })}
+                  placeholder={
+                    asanaConnection
+                      ? t(
+                          'settings.projects.asanaIntegration.token.placeholderExisting'
+                        )
+                      : '2/1200000000000000/...'
+                  }
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.asanaIntegration.token.helper')}
+                </p>
+              </div>
+
+              <div className="flex items-center justify-between pt-4 border-t">
                 <div className="flex gap-2">
-                  <Input
-                    id="github-token"
-                    type={showToken ? 'text' : 'password'}
-                    value={githubDraft.github_token}
-                    onChange={(e) =>
-                      updateGithubDraft({ github_token: e.target.value })
-                    }
-                    placeholder={
-                      hasExistingToken
-                        ? t('settings.projects.githubIntegration.token.placeholderExisting')
-                        : t('settings.projects.githubIntegration.token.placeholder')
-                    }
-                    className="font-mono"
-                  />
                   <Button
                     variant="outline"
-                    size="sm"
-                    type="button"
-                    onClick={() => setShowToken(!showToken)}
+                    onClick={handleLoadAsanaTasks}
+                    disabled={loadingAsanaTasks || !asanaConnection}
                   >
-                    {showToken
-                      ? t('settings.projects.githubIntegration.token.hide')
-                      : t('settings.projects.githubIntegration.token.show')}
+                    {loadingAsanaTasks && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.asanaIntegration.buttons.loadTasks')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncAsanaTasks}
+                    disabled={syncingAsanaTasks || !asanaConnection}
+                  >
+                    {syncingAsanaTasks ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.asanaIntegration.buttons.syncNow')}
                   </Button>
                 </div>
-                <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.githubIntegration.token.helper')}{' '}
-                  <a
-                    href="https://github.com/settings/tokens"
-                    target="_blank"
-                    rel="noopener noreferrer"
-                    className="text-primary hover:underline"
+                <div className="flex gap-2">
+                  {asanaConnection && (
+                    <Button variant="outline" onClick={handleDisconnectAsana}>
+                      {t('settings.projects.asanaIntegration.buttons.disconnect')}
+                    </Button>
+                  )}
+                  <Button
+                    onClick={handleSaveAsana}
+                    disabled={savingAsana || !asanaDraft.project_gid.trim()}
                   >
-                    {t('settings.projects.githubIntegration.token.settingsLink')}
-                    <ExternalLink className="inline h-3 w-3 ml-1" />
-                  </a>
+                    {savingAsana && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.asanaIntegration.buttons.save')}
+                  </Button>
+                </div>
+              </div>
+
+              {asanaTasks.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.asanaIntegration.tasks.title')} (
+                    {asanaTasks.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {asanaTasks.map((asanaTask) => (
+                      <div
+                        key={asanaTask.id}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1 font-medium truncate">
+                          {asanaTask.title}
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportAsanaTask(asanaTask.id)}
+                        >
+                          {t('settings.projects.asanaIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center gap-2">
+                <ListTodo className="h-5 w-5" />
+                {t('settings.projects.clickupIntegration.title')}
+              </CardTitle>
+              <CardDescription>
+                {t('settings.projects.clickupIntegration.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {clickupError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{clickupError}</AlertDescription>
+                </Alert>
+              )}
+
+              {clickupSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.clickupIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+
+              <div className="space-y-2">
+                <Label htmlFor="clickup-list-id">
+                  {t('settings.projects.clickupIntegration.list.label')}
+                </Label>
+                <Input
+                  id="clickup-list-id"
+                  value={clickupDraft.list_id}
+                  onChange={(e) =>
+                    updateClickUpDraft({ list_id: e.target.value })
+                  }
+                  placeholder="https://app.clickup.com/9015000000/v/li/901500000001"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.clickupIntegration.list.helper')}
                 </p>
               </div>
 
               <div className="space-y-2">
-                <Label htmlFor="github-sync-labels">
-                  {t('settings.projects.githubIntegration.syncLabels.label')}
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="clickup-token">
+                    {t('settings.projects.clickupIntegration.token.label')}
+                  </Label>
+                  {clickupConnection && !clickupDraft.token && (
+                    <span className="text-xs text-muted-foreground">
+                      {t('settings.projects.clickupIntegration.token.saved')}
+                    </span>
+                  )}
+                </div>
+                <Input
+                  id="clickup-token"
+                  type="password"
+                  value={clickupDraft.token}
+                  onChange={(e) => updateClickUpDraft({ token: e.target.value })}
+                  placeholder={
+                    clickupConnection
+                      ? t(
+                          'settings.projects.clickupIntegration.token.placeholderExisting'
+                        )
+                      : 'pk_...'
+                  }
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.clickupIntegration.token.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="clickup-tag-filter">
+                  {t('settings.projects.clickupIntegration.tags.label')}
                 </Label>
                 <Input
-                  id="github-sync-labels"
-                  value={githubDraft.github_sync_labels}
+                  id="clickup-tag-filter"
+                  value={clickupDraft.tag_filter}
                   onChange={(e) =>
-                    updateGithubDraft({ github_sync_labels: e.target.value })
+                    updateClickUpDraft({ tag_filter: e.target.value })
                   }
-                  placeholder={t('settings.projects.githubIntegration.syncLabels.placeholder')}
+                  placeholder="bug, frontend"
                 />
                 <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.githubIntegration.syncLabels.helper')}
+                  {t('settings.projects.clickupIntegration.tags.helper')}
                 </p>
               </div>
 
-              <div className="flex items-center space-x-2">
-                <Switch
-                  id="github-sync-enabled"
-                  checked={githubDraft.github_sync_enabled}
-                  onCheckedChange={(checked) =>
-                    updateGithubDraft({ github_sync_enabled: checked })
+              <div className="flex items-center justify-between pt-4 border-t">
+                <div className="flex gap-2">
+                  <Button
+                    variant="outline"
+                    onClick={handleLoadClickUpTasks}
+                    disabled={loadingClickUpTasks || !clickupConnection}
+                  >
+                    {loadingClickUpTasks && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.clickupIntegration.buttons.loadTasks')}
+                  </Button>
+                  <Button
+                    variant="outline"
+                    onClick={handleSyncClickUpTasks}
+                    disabled={syncingClickUpTasks || !clickupConnection}
+                  >
+                    {syncingClickUpTasks ? (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    ) : (
+                      <RefreshCw className="mr-2 h-4 w-4" />
+                    )}
+                    {t('settings.projects.clickupIntegration.buttons.syncNow')}
+                  </Button>
+                </div>
+                <div className="flex gap-2">
+                  {clickupConnection && (
+                    <Button variant="outline" onClick={handleDisconnectClickUp}>
+                      {t('settings.projects.clickupIntegration.buttons.disconnect')}
+                    </Button>
+                  )}
+                  <Button
+                    onClick={handleSaveClickUp}
+                    disabled={savingClickUp || !clickupDraft.list_id.trim()}
+                  >
+                    {savingClickUp && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.clickupIntegration.buttons.save')}
+                  </Button>
+                </div>
+              </div>
+
+              {clickupTasks.length > 0 && (
+                <div className="pt-4 border-t">
+                  <Label className="mb-3 block">
+                    {t('settings.projects.clickupIntegration.tasks.title')} (
+                    {clickupTasks.length})
+                  </Label>
+                  <div className="space-y-2 max-h-64 overflow-y-auto">
+                    {clickupTasks.map((clickupTask) => (
+                      <div
+                        key={clickupTask.id}
+                        className="flex items-center justify-between p-3 border rounded-md"
+                      >
+                        <div className="min-w-0 flex-1 font-medium truncate">
+                          {clickupTask.title}
+                        </div>
+                        <Button
+                          variant="outline"
+                          size="sm"
+                          onClick={() => handleImportClickUpTask(clickupTask.id)}
+                        >
+                          {t('settings.projects.clickupIntegration.buttons.import')}
+                        </Button>
+                      </div>
+                    ))}
+                  </div>
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center gap-2">
+                <Flag className="h-5 w-5" />
+                {t('settings.projects.shortcutIntegration.title')}
+              </CardTitle>
+              <CardDescription>
+                {t('settings.projects.shortcutIntegration.description')}
+              </CardDescription>
+            </CardHeader>
+            <CardContent className="space-y-4">
+              {shortcutError && (
+                <Alert variant="destructive">
+                  <AlertDescription>{shortcutError}</AlertDescription>
+                </Alert>
+              )}
+
+              {shortcutSuccess && (
+                <Alert variant="success">
+                  <AlertDescription className="font-medium">
+                    {t('settings.projects.shortcutIntegration.messages.success')}
+                  </AlertDescription>
+                </Alert>
+              )}
+
+              <div className="space-y-2">
+                <div className="flex items-center gap-2">
+                  <Label htmlFor="shortcut-token">
+                    {t('settings.projects.shortcutIntegration.token.label')}
+                  </Label>
+                  {shortcutConnection && !shortcutDraft.token && (
+                    <span className="text-xs text-muted-foreground">
+                      {t('settings.projects.shortcutIntegration.token.saved')}
+                    </span>
+                  )}
+                </div>
+                <Input
+                  id="shortcut-token"
+                  type="password"
+                  value={shortcutDraft.token}
+                  onChange={(e) =>
+                    updateShortcutDraft({ token: e.target.value })
+                  }
+                  placeholder={
+                    shortcutConnection
+                      ? t(
+                          'settings.projects.shortcutIntegration.token.placeholderExisting'
+                        )
+                      : '00000000-0000-0000-0000-000000000000'
                   }
                 />
-                <Label htmlFor="github-sync-enabled" className="cursor-pointer">
-                  {t('settings.projects.githubIntegration.autoSync.label')}
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.shortcutIntegration.token.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="shortcut-labels">
+                  {t('settings.projects.shortcutIntegration.labels.label')}
+                </Label>
+                <Input
+                  id="shortcut-labels"
+                  value={shortcutDraft.labels}
+                  onChange={(e) =>
+                    updateShortcutDraft({ labels: e.target.value })
+                  }
+                  placeholder="agent, backend"
+                />
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.shortcutIntegration.labels.helper')}
+                </p>
+              </div>
+
+              <div className="space-y-2">
+                <Label htmlFor="shortcut-review-state">
+                  {t('settings.projects.shortcutIntegration.reviewState.label')}
                 </Label>
+                <Select
+                  value={shortcutDraft.review_state_id}
+                  onValueChange={(stateId) =>
+                    updateShortcutDraft({ review_state_id: stateId })
+                  }
+                >
+                  <SelectTrigger id="shortcut-review-state">
+                    <SelectValue />
+                  </SelectTrigger>
+                  <SelectContent>
+                    <SelectItem value={SHORTCUT_AUTO_REVIEW_STATE}>
+                      {t(
+                        'settings.projects.shortcutIntegration.reviewState.auto'
+                      )}
+                    </SelectItem>
+                    {shortcutWorkflows.flatMap((workflow) =>
+                      workflow.states
+                        .filter((state) => state.type !== 'done')
+                        .map((state) => (
+                          <SelectItem key={state.id} value={String(state.id)}>
+                            {workflow.name} · {state.name}
+                          </SelectItem>
+                        ))
+                    )}
+                  </SelectContent>
+                </Select>
+                <p className="text-sm text-muted-foreground">
+                  {t('settings.projects.shortcutIntegration.reviewState.helper')}
+                </p>
               </div>
-              <p className="text-sm text-muted-foreground">
-                {t('settings.projects.githubIntegration.autoSync.helper')}
-              </p>
 
               <div className="flex items-center justify-between pt-4 border-t">
                 <div className="flex gap-2">
                   <Button
                     variant="outline"
-                    onClick={handleLoadIssues}
-                    disabled={loadingIssues || !githubDraft.github_repo_url}
+                    onClick={handleLoadShortcutStories}
+                    disabled={loadingShortcutStories || !shortcutConnection}
                   >
-                    {loadingIssues && (
+                    {loadingShortcutStories && (
                       <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     )}
-                    {t('settings.projects.githubIntegration.buttons.loadIssues')}
+                    {t(
+                      'settings.projects.shortcutIntegration.buttons.loadStories'
+                    )}
                   </Button>
                   <Button
                     variant="outline"
-                    onClick={handleSyncIssues}
-                    disabled={syncingIssues || !githubDraft.github_repo_url}
+                    onClick={handleSyncShortcutStories}
+                    disabled={syncingShortcutStories || !shortcutConnection}
                   >
-                    {syncingIssues ? (
+                    {syncingShortcutStories ? (
                       <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     ) : (
                       <RefreshCw className="mr-2 h-4 w-4" />
                     )}
-                    {t('settings.projects.githubIntegration.buttons.syncNow')}
+                    {t('settings.projects.shortcutIntegration.buttons.syncNow')}
                   </Button>
                 </div>
-                <Button
-                  onClick={handleSaveGithub}
-                  disabled={savingGithub}
-                >
-                  {savingGithub && (
-                    <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                <div className="flex gap-2">
+                  {shortcutConnection && (
+                    <Button
+                      variant="outline"
+                      onClick={handleDisconnectShortcut}
+                    >
+                      {t(
+                        'settings.projects.shortcutIntegration.buttons.disconnect'
+                      )}
+                    </Button>
                   )}
-                  {t('settings.projects.githubIntegration.buttons.save')}
-                </Button>
+                  <Button
+                    onClick={handleSaveShortcut}
+                    disabled={
+                      savingShortcut ||
+                      (!shortcutConnection && !shortcutDraft.token.trim())
+                    }
+                  >
+                    {savingShortcut && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    {t('settings.projects.shortcutIntegration.buttons.save')}
+                  </Button>
+                </div>
               </div>
 
-              {githubIssues.length > 0 && (
+              {shortcutStories.length > 0 && (
                 <div className="pt-4 border-t">
                   <Label className="mb-3 block">
-                    {t('settings.projects.githubIntegration.issues.title')} ({githubIssues.length})
+                    {t('settings.projects.shortcutIntegration.stories.title')} (
+                    {shortcutStories.length})
                   </Label>
                   <div className="space-y-2 max-h-64 overflow-y-auto">
-                    {githubIssues.map((issue) => (
+                    {shortcutStories.map((story) => (
                       <div
-                        key={issue.number}
+                        key={story.id}
                         className="flex items-center justify-between p-3 border rounded-md"
                       >
                         <div className="min-w-0 flex-1">
                           <div className="font-medium truncate">
-                            #{issue.number} {issue.title}
+                            {story.title}
                           </div>
-                          <div className="flex items-center gap-2 text-sm text-muted-foreground">
-                            <span>{issue.user.login}</span>
-                            {issue.labels.length > 0 && (
-                              <div className="flex gap-1">
-                                {issue.labels.slice(0, 3).map((label) => (
-                                  <span
-                                    key={label.name}
-                                    className="px-1.5 py-0.5 text-xs rounded"
-                                    style={{
-                                      backgroundColor: `#${label.color}20`,
-                                      color: `#${label.color}`,
-                                    }}
-                                  >
-                                    {label.name}
-                                  </span>
-                                ))}
-                              </div>
-                            )}
+                          <div className="text-xs text-muted-foreground">
+                            sc-{story.key}
+                            {story.labels.length > 0 &&
+                              ` · ${story.labels.join(', ')}`}
                           </div>
                         </div>
                         <Button
                           variant="outline"
                           size="sm"
-                          onClick={() => handleImportIssue(issue.number)}
+                          onClick={() => handleImportShortcutStory(story.id)}
                         >
-                          {t('settings.projects.githubIntegration.buttons.import')}
+                          {t(
+                            'settings.projects.shortcutIntegration.buttons.import'
+                          )}
                         </Button>
                       </div>
                     ))}
@@ -1603,69 +4866,100 @@ export function ProjectSettings() {
           <Card>
             <CardHeader>
               <CardTitle className="flex items-center gap-2">
-                <GitlabIcon className="h-5 w-5" />
-                {t('settings.projects.gitlabIntegration.title')}
+                <GitPullRequest className="h-5 w-5" />
+                {t('settings.projects.bitbucketIntegration.title')}
               </CardTitle>
               <CardDescription>
-                {t('settings.projects.gitlabIntegration.description')}
+                {t('settings.projects.bitbucketIntegration.description')}
               </CardDescription>
             </CardHeader>
             <CardContent className="space-y-4">
-              {gitlabError && (
+              {bitbucketError && (
                 <Alert variant="destructive">
-                  <AlertDescription>{gitlabError}</AlertDescription>
+                  <AlertDescription>{bitbucketError}</AlertDescription>
                 </Alert>
               )}
 
-              {gitlabSuccess && (
+              {bitbucketSuccess && (
                 <Alert variant="success">
                   <AlertDescription className="font-medium">
-                    {t('settings.projects.gitlabIntegration.messages.success')}
+                    {t('settings.projects.bitbucketIntegration.messages.success')}
                   </AlertDescription>
                 </Alert>
               )}
 
+              <div className="grid grid-cols-2 gap-4">
+                <div className="space-y-2">
+                  <Label htmlFor="bitbucket-workspace">
+                    {t('settings.projects.bitbucketIntegration.workspace.label')}
+                  </Label>
+                  <Input
+                    id="bitbucket-workspace"
+                    value={bitbucketDraft.bitbucket_workspace}
+                    onChange={(e) =>
+                      updateBitbucketDraft({ bitbucket_workspace: e.target.value })
+                    }
+                    placeholder={t('settings.projects.bitbucketIntegration.workspace.placeholder')}
+                    className="font-mono"
+                  />
+                </div>
+                <div className="space-y-2">
+                  <Label htmlFor="bitbucket-repo-slug">
+                    {t('settings.projects.bitbucketIntegration.repoSlug.label')}
+                  </Label>
+                  <Input
+                    id="bitbucket-repo-slug"
+                    value={bitbucketDraft.bitbucket_repo_slug}
+                    onChange={(e) =>
+                      updateBitbucketDraft({ bitbucket_repo_slug: e.target.value })
+                    }
+                    placeholder={t('settings.projects.bitbucketIntegration.repoSlug.placeholder')}
+                    className="font-mono"
+                  />
+                </div>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                {t('settings.projects.bitbucketIntegration.repoSlug.helper')}
+              </p>
+
               <div className="space-y-2">
-                <Label htmlFor="gitlab-project-url">
-                  {t('settings.projects.gitlabIntegration.projectUrl.label')}
+                <Label htmlFor="bitbucket-username">
+                  {t('settings.projects.bitbucketIntegration.username.label')}
                 </Label>
                 <Input
-                  id="gitlab-project-url"
-                  value={gitlabDraft.gitlab_project_url}
+                  id="bitbucket-username"
+                  value={bitbucketDraft.bitbucket_username}
                   onChange={(e) =>
-                    updateGitlabDraft({ gitlab_project_url: e.target.value })
+                    updateBitbucketDraft({ bitbucket_username: e.target.value })
                   }
-                  placeholder={t('settings.projects.gitlabIntegration.projectUrl.placeholder')}
-                  className="font-mono"
+                  placeholder={t('settings.projects.bitbucketIntegration.username.placeholder')}
                 />
-                <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.gitlabIntegration.projectUrl.helper')}
-                </p>
               </div>
 
               <div className="space-y-2">
                 <div className="flex items-center gap-2">
-                  <Label htmlFor="gitlab-token">
-                    {t('settings.projects.gitlabIntegration.token.label')}
+                  <Label htmlFor="bitbucket-app-password">
+                    {t('settings.projects.bitbucketIntegration.appPassword.label')}
                   </Label>
-                  {hasExistingGitlabToken && !gitlabDraft.gitlab_token && (
-                    <span className="text-xs text-green-600 dark:text-green-400">
-                      {t('settings.projects.gitlabIntegration.token.saved')}
-                    </span>
-                  )}
+                  {hasExistingBitbucketPassword &&
+                    !bitbucketDraft.bitbucket_app_password && (
+                      <span className="text-xs text-green-600 dark:text-green-400">
+                        {t('settings.projects.bitbucketIntegration.appPassword.saved')}
+                      </span>
+                    )}
                 </div>
                 <div className="flex gap-2">
                   <Input
-                    id="gitlab-token"
-                    type={showGitlabToken ? 'text' : 'password'}
-                    value={gitlabDraft.gitlab_token}
+                    id="bitbucket-app-password"
+                    type={showBitbucketPassword ? 'text' : 'password'}
+                    value={bitbucketDraft.bitbucket_app_password}
                     onChange={(e) =>
-                      updateGitlabDraft({ gitlab_token: e.target.value })
+                      updateBitbucketDraft({ bitbucket_app_password: e.target.value })
                     }
                     placeholder={
-                      hasExistingGitlabToken
-                        ? t('settings.projects.gitlabIntegration.token.placeholderExisting')
-                        : t('settings.projects.gitlabIntegration.token.placeholder')
+                      hasExistingBitbucketPassword
+                        ? t('settings.projects.bitbucketIntegration.appPassword.placeholderExisting')
+                        : t('settings.projects.bitbucketIntegration.appPassword.placeholder')
                     }
                     className="font-mono"
                   />
@@ -1673,133 +4967,97 @@ export function ProjectSettings() {
                     variant="outline"
                     size="sm"
                     type="button"
-                    onClick={() => setShowGitlabToken(!showGitlabToken)}
+                    onClick={() => setShowBitbucketPassword(!showBitbucketPassword)}
                   >
-                    {showGitlabToken
-                      ? t('settings.projects.gitlabIntegration.token.hide')
-                      : t('settings.projects.gitlabIntegration.token.show')}
+                    {showBitbucketPassword
+                      ? t('settings.projects.bitbucketIntegration.appPassword.hide')
+                      : t('settings.projects.bitbucketIntegration.appPassword.show')}
                   </Button>
                 </div>
                 <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.gitlabIntegration.token.helper')}{' '}
+                  {t('settings.projects.bitbucketIntegration.appPassword.helper')}{' '}
                   <a
-                    href="https://gitlab.com/-/user_settings/personal_access_tokens"
+                    href="https://bitbucket.org/account/settings/app-passwords/"
                     target="_blank"
                     rel="noopener noreferrer"
                     className="text-primary hover:underline"
                   >
-                    {t('settings.projects.gitlabIntegration.token.settingsLink')}
+                    {t('settings.projects.bitbucketIntegration.appPassword.settingsLink')}
                     <ExternalLink className="inline h-3 w-3 ml-1" />
                   </a>
                 </p>
               </div>
 
-              <div className="space-y-2">
-                <Label htmlFor="gitlab-sync-labels">
-                  {t('settings.projects.gitlabIntegration.syncLabels.label')}
-                </Label>
-                <Input
-                  id="gitlab-sync-labels"
-                  value={gitlabDraft.gitlab_sync_labels}
-                  onChange={(e) =>
-                    updateGitlabDraft({ gitlab_sync_labels: e.target.value })
-                  }
-                  placeholder={t('settings.projects.gitlabIntegration.syncLabels.placeholder')}
-                />
-                <p className="text-sm text-muted-foreground">
-                  {t('settings.projects.gitlabIntegration.syncLabels.helper')}
-                </p>
-              </div>
-
-              <div className="flex items-center space-x-2">
-                <Switch
-                  id="gitlab-sync-enabled"
-                  checked={gitlabDraft.gitlab_sync_enabled}
-                  onCheckedChange={(checked) =>
-                    updateGitlabDraft({ gitlab_sync_enabled: checked })
-                  }
-                />
-                <Label htmlFor="gitlab-sync-enabled" className="cursor-pointer">
-                  {t('settings.projects.gitlabIntegration.autoSync.label')}
-                </Label>
-              </div>
-              <p className="text-sm text-muted-foreground">
-                {t('settings.projects.gitlabIntegration.autoSync.helper')}
-              </p>
-
               <div className="flex items-center justify-between pt-4 border-t">
                 <div className="flex gap-2">
                   <Button
                     variant="outline"
-                    onClick={handleLoadGitlabIssues}
-                    disabled={loadingGitlabIssues || !gitlabDraft.gitlab_project_url}
+                    onClick={handleLoadBitbucketIssues}
+                    disabled={
+                      loadingBitbucketIssues || !bitbucketDraft.bitbucket_repo_slug
+                    }
                   >
-                    {loadingGitlabIssues && (
+                    {loadingBitbucketIssues && (
                       <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     )}
-                    {t('settings.projects.gitlabIntegration.buttons.loadIssues')}
+                    {t('settings.projects.bitbucketIntegration.buttons.loadIssues')}
                   </Button>
                   <Button
                     variant="outline"
-                    onClick={handleSyncGitlabIssues}
-                    disabled={syncingGitlabIssues || !gitlabDraft.gitlab_project_url}
+                    onClick={handleSyncBitbucketIssues}
+                    disabled={
+                      syncingBitbucketIssues || !bitbucketDraft.bitbucket_repo_slug
+                    }
                   >
-                    {syncingGitlabIssues ? (
+                    {syncingBitbucketIssues ? (
                       <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                     ) : (
                       <RefreshCw className="mr-2 h-4 w-4" />
                     )}
-                    {t('settings.projects.gitlabIntegration.buttons.syncNow')}
+                    {t('settings.projects.bitbucketIntegration.buttons.syncNow')}
                   </Button>
                 </div>
                 <Button
-                  onClick={handleSaveGitlab}
-                  disabled={savingGitlab}
+                  onClick={handleSaveBitbucket}
+                  disabled={savingBitbucket}
                 >
-                  {savingGitlab && (
+                  {savingBitbucket && (
                     <Loader2 className="mr-2 h-4 w-4 animate-spin" />
                   )}
-                  {t('settings.projects.gitlabIntegration.buttons.save')}
+                  {t('settings.projects.bitbucketIntegration.buttons.save')}
                 </Button>
               </div>
 
-              {gitlabIssues.length > 0 && (
+              {bitbucketIssues.length > 0 && (
                 <div className="pt-4 border-t">
                   <Label className="mb-3 block">
-                    {t('settings.projects.gitlabIntegration.issues.title')} ({gitlabIssues.length})
+                    {t('settings.projects.bitbucketIntegration.issues.title')} ({bitbucketIssues.length})
                   </Label>
                   <div className="space-y-2 max-h-64 overflow-y-auto">
-                    {gitlabIssues.map((issue) => (
+                    {bitbucketIssues.map((issue) => (
                       <div
-                        key={issue.iid}
+                        key={Number(issue.id)}
                         className="flex items-center justify-between p-3 border rounded-md"
                       >
                         <div className="min-w-0 flex-1">
                           <div className="font-medium truncate">
-                            #{issue.iid} {issue.title}
+                            #{Number(issue.id)} {issue.title}
                           </div>
                           <div className="flex items-center gap-2 text-sm text-muted-foreground">
-                            <span>{issue.author.username}</span>
-                            {issue.labels.length > 0 && (
-                              <div className="flex gap-1">
-                                {issue.labels.slice(0, 3).map((label) => (
-                                  <span
-                                    key={label}
-                                    className="px-1.5 py-0.5 text-xs rounded bg-muted"
-                                  >
-                                    {label}
-                                  </span>
-                                ))}
-                              </div>
+                            {issue.reporter && <span>{issue.reporter}</span>}
+                            {issue.kind && (
+                              <span className="px-1.5 py-0.5 text-xs rounded bg-muted">
+                                {issue.kind}
+                              </span>
                             )}
                           </div>
                         </div>
                         <Button
                           variant="outline"
                           size="sm"
-                          onClick={() => handleImportGitlabIssue(issue.iid)}
+                          onClick={() => handleImportBitbucketIssue(Number(issue.id))}
                         >
-                          {t('settings.projects.gitlabIntegration.buttons.import')}
+                          {t('settings.projects.bitbucketIntegration.buttons.import')}
                         </Button>
                       </div>
                     ))}
@@ -1838,15 +5096,56 @@ export function ProjectSettings() {
                 <Label htmlFor="vortex-project-id">
                   Vortex Project ID
                 </Label>
-                <Input
-                  id="vortex-project-id"
-                  value={vortexDraft.vortex_project_id}
-                  onChange={(e) =>
-                    updateVortexDraft({ vortex_project_id: e.target.value })
-                  }
-                  placeholder="PROJECT-KEY"
-                  className="font-mono"
-                />
+                <div className="flex gap-2">
+                  {vortexProjects && vortexProjects.length > 0 ? (
+                    <Select
+                      value={vortexDraft.vortex_project_id || undefined}
+                      onValueChange={(value) =>
+                        updateVortexDraft({ vortex_project_id: value })
+                      }
+                    >
+                      <SelectTrigger id="vortex-project-id">
+                        <SelectValue placeholder="Select a Vortex project" />
+                      </SelectTrigger>
+                      <SelectContent>
+                        {vortexProjects.map((vortexProject) => (
+                          <SelectItem
+                            key={vortexProject.id}
+                            value={vortexProject.id}
+                          >
+                            {vortexProject.workspace_name
+                              ? `${vortexProject.workspace_name} / `
+                              : ''}
+                            {vortexProject.name}
+                            {vortexProject.key ? ` (${vortexProject.key})` : ''}
+                          </SelectItem>
+                        ))}
+                      </SelectContent>
+                    </Select>
+                  ) : (
+                    <Input
+                      id="vortex-project-id"
+                      value={vortexDraft.vortex_project_id}
+                      onChange={(e) =>
+                        updateVortexDraft({ vortex_project_id: e.target.value })
+                      }
+                      placeholder="PROJECT-KEY"
+                      className="font-mono"
+                    />
+                  )}
+                  <Button
+                    variant="outline"
+                    size="sm"
+                    type="button"
+                    onClick={handleLoadVortexProjects}
+                    disabled={!hasExistingVortexToken || loadingVortexProjects}
+                  >
+                    {loadingVortexProjects && (
+                      <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                    )}
+                    Browse
+                  </Button>
+                </div>
                 <p className="text-sm text-muted-foreground">
                   The project key or ID in Vortex
                 </p>
@@ -1892,6 +5191,46 @@ export function ProjectSettings() {
                 </p>
               </div>
 
+              {vortexOAuth?.configured && (
+                <div className="space-y-2">
+                  <div className="flex items-center gap-2">
+                    <Label>Vortex Account</Label>
+                    {vortexOAuth.connected && (
+                      <span className="text-xs text-green-600 dark:text-green-400">
+                        Connected
+                      </span>
+                    )}
+                  </div>
+                  {vortexOAuth.connected ? (
+                    <Button
+                      variant="outline"
+                      size="sm"
+                      type="button"
+                      onClick={handleDisconnectVortex}
+                    >
+                      Disconnect
+                    </Button>
+                  ) : (
+                    <Button
+                      variant="outline"
+                      size="sm"
+                      type="button"
+                      onClick={handleConnectVortex}
+                      disabled={connectingVortex}
+                    >
+                      {connectingVortex && (
+                        <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+                      )}
+                      Connect with Vortex
+                    </Button>
+                  )}
+                  <p className="text-sm text-muted-foreground">
+                    Sign in to Vortex instead of pasting an API token. A
+                    connected account takes precedence over the token above.
+                  </p>
+                </div>
+              )}
+
               <div className="space-y-2">
                 <Label htmlFor="vortex-sync-labels">
                   Sync Labels
@@ -1925,6 +5264,24 @@ export function ProjectSettings() {
                 Automatically sync issues when the project loads
               </p>
 
+              <div className="flex items-center space-x-2">
+                <Switch
+                  id="vortex-two-way-sync"
+                  checked={vortexDraft.vortex_two_way_sync}
+                  onCheckedChange={(checked) =>
+                    updateVortexDraft({ vortex_two_way_sync: checked })
+                  }
+                />
+                <Label htmlFor="vortex-two-way-sync" className="cursor-pointer">
+                  Two-Way Title and Description Sync
+                </Label>
+              </div>
+              <p className="text-sm text-muted-foreground">
+                Pull edits to issue titles and descriptions into their tasks,
+                and push task edits back. When both change between syncs, the
+                latest edit wins and the overwritten version is recorded.
+              </p>
+
               <div className="flex items-center justify-between pt-4 border-t">
                 <div className="flex gap-2">
                   <Button
@@ -2012,6 +5369,63 @@ export function ProjectSettings() {
             </CardContent>
           </Card>
 
+          <Card>
+            <CardHeader>
+              <CardTitle className="flex items-center justify-between gap-2">
+                Sync History
+                <Button
+                  variant="outline"
+                  size="sm"
+                  onClick={handleRefreshSyncRuns}
+                  disabled={loadingSyncRuns}
+                >
+                  <RefreshCw
+                    className={`h-4 w-4 ${loadingSyncRuns ? 'animate-spin' : ''}`}
+                  />
+                </Button>
+              </CardTitle>
+              <CardDescription>
+                Recent issue syncs from every provider
+              </CardDescription>
+            </CardHeader>
+            <CardContent>
+              {syncRuns.length === 0 ? (
+                <p className="text-sm text-muted-foreground">
+                  No syncs have run for this project yet
+                </p>
+              ) : (
+                <div className="divide-y">
+                  {syncRuns.map((run) => (
+                    <div key={run.id} className="py-2 text-sm">
+                      <div className="flex items-center justify-between gap-2">
+                        <span className="font-medium">{run.provider}</span>
+                        <span
+                          className={
+                            run.status === 'failed'
+                              ? 'text-destructive'
+                              : 'text-muted-foreground'
+                          }
+                        >
+                          {run.status === 'succeeded'
+                            ? `${run.imported_count} imported`
+                            : run.status}
+                        </span>
+                      </div>
+                      <div className="text-xs text-muted-foreground">
+                        {new Date(run.started_at).toLocaleString()}
+                      </div>
+                      {run.error && (
+                        <div className="text-xs text-destructive break-words">
+                          {run.error}
+                        </div>
+                      )}
+                    </div>
+                  ))}
+                </div>
+              )}
+            </CardContent>
+          </Card>
+
           {hasUnsavedProjectChanges && (
             <div className="sticky bottom-0 z-10 bg-background/80 backdrop-blur-sm border-t py-4">
               <div className="flex items-center justify-between">
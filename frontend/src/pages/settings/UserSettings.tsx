@@ -26,6 +26,7 @@ import type {
   ApiResponse,
 } from 'shared/types';
 import { useLocalAuth } from '@/contexts/LocalAuthContext';
+import { localizedErrorMessage } from '@/lib/api';
 
 // API helpers
 const fetchWithAuth = async <T,>(
@@ -43,7 +44,7 @@ const fetchWithAuth = async <T,>(
   const data = await response.json();
 
   if (!response.ok) {
-    throw new Error(data.error || 'Request failed');
+    throw new Error(localizedErrorMessage(data, 'Request failed'));
   }
 
   return data;
@@ -12,11 +12,72 @@ export type SharedTask = { id: string, organization_id: string, project_id: stri
 
 export type UserData = { user_id: string, first_name: string | null, last_name: string | null, username: string | null, };
 
-export type Project = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, remote_project_id: string | null, github_repo_url: string | null, github_sync_enabled: boolean, github_sync_labels: string | null, github_last_sync_at: string | null, gitlab_project_url: string | null, gitlab_sync_enabled: boolean, gitlab_sync_labels: string | null, gitlab_last_sync_at: string | null, vortex_api_url: string | null, vortex_project_id: string | null, vortex_sync_enabled: boolean, vortex_sync_labels: string | null, vortex_last_sync_at: string | null, created_at: Date, updated_at: Date, };
+export type Project = { id: string, name: string, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, remote_project_id: string | null, github_repo_url: string | null, github_sync_enabled: boolean, github_sync_labels: string | null, 
+/**
+ * Also import open pull requests as review tasks when syncing
+ */
+github_sync_pull_requests: boolean, github_last_sync_at: string | null, 
+/**
+ * Instance URL for a self-hosted GitLab; gitlab.com when unset
+ */
+gitlab_base_url: string | null, gitlab_project_url: string | null, gitlab_sync_enabled: boolean, gitlab_sync_labels: string | null, gitlab_last_sync_at: string | null, vortex_api_url: string | null, vortex_project_id: string | null, vortex_sync_enabled: boolean, vortex_sync_labels: string | null, 
+/**
+ * Keep the titles and descriptions of tasks and their Vortex issues in
+ * step both ways, instead of only pushing status changes to Vortex
+ */
+vortex_two_way_sync: boolean, vortex_last_sync_at: string | null, gitea_base_url: string | null, gitea_repo: string | null, gitea_sync_enabled: boolean, gitea_sync_labels: string | null, gitea_last_sync_at: string | null, bitbucket_workspace: string | null, bitbucket_repo_slug: string | null, bitbucket_username: string | null, 
+/**
+ * Which way labels are synced between tasks and linked GitHub issues
+ */
+github_label_sync: LabelSyncDirection, 
+/**
+ * Comment on the GitHub, GitLab or Vortex issue a task was imported from
+ * when one of its attempts finishes
+ */
+comment_on_linked_issues: boolean, 
+/**
+ * Record a pending start for a person to approve instead of starting an
+ * attempt automatically when a task moves to In Progress
+ */
+confirm_auto_start: boolean, 
+/**
+ * Keep tasks out of Done while CI is pending or failing on their open
+ * pull and merge requests
+ */
+wait_for_ci: boolean, 
+/**
+ * Move tasks to Done when their pull or merge request is merged
+ */
+done_on_merge: boolean, 
+/**
+ * Hold unlabeled issues picked up by sync as drafts, off the board until
+ * someone triages them
+ */
+triage_synced_issues: boolean, created_at: Date, updated_at: Date, };
 
 export type CreateProject = { name: string, repositories: Array<CreateProjectRepo>, };
 
-export type UpdateProject = { name: string | null, dev_script: string | null, dev_script_working_dir: string | null, default_agent_working_dir: string | null, github_repo_url: string | null, github_token: string | null, github_sync_enabled: boolean | null, github_sync_labels: string | null, gitlab_project_url: string | null, gitlab_token: string | null, gitlab_sync_enabled: boolean | null, gitlab_sync_labels: string | null, vortex_api_url: string | null, vortex_project_id: string | null, vortex_token: string | null, vortex_sync_enabled: boolean | null, vortex_sync_labels: string | null, };
+export type CreateProjectFromTemplate = { template_project_id: string, name: string, 
+/**
+ * Repositories for the new project; defaults to the template's repositories
+ */
+repositories?: Array<CreateProjectRepo>, };
+
+export type DuplicateProject = { name: string, 
+/**
+ * Skip tasks that are already done or cancelled
+ */
+open_tasks_only: boolean, 
+/**
+ * Attach the source tasks' images to their copies
+ */
+include_images: boolean, 
+/**
+ * Replacement repository for a source repo id; unlisted repos are reused as-is
+ */
+repo_remap: { [key in string]?: CreateProjectRepo }, };
+
+export type UpdateProject = { name?: string, dev_script?: string | null, dev_script_working_dir?: string | null, default_agent_working_dir?: string | null, github_repo_url?: string | null, github_token?: string | null, github_sync_enabled?: boolean, github_sync_labels?: string | null, github_sync_pull_requests?: boolean, gitlab_base_url?: string | null, gitlab_project_url?: string | null, gitlab_token?: string | null, gitlab_sync_enabled?: boolean, gitlab_sync_labels?: string | null, vortex_api_url?: string | null, vortex_project_id?: string | null, vortex_token?: string | null, vortex_sync_enabled?: boolean, vortex_sync_labels?: string | null, vortex_two_way_sync?: boolean, gitea_base_url?: string | null, gitea_repo?: string | null, gitea_token?: string | null, gitea_sync_enabled?: boolean, gitea_sync_labels?: string | null, bitbucket_workspace?: string | null, bitbucket_repo_slug?: string | null, bitbucket_username?: string | null, bitbucket_app_password?: string | null, github_label_sync?: LabelSyncDirection, comment_on_linked_issues?: boolean, confirm_auto_start?: boolean, wait_for_ci?: boolean, done_on_merge?: boolean, triage_synced_issues?: boolean, };
 
 export type SearchResult = { path: string, is_file: boolean, match_type: SearchMatchType, };
 
@@ -24,11 +85,15 @@ export type SearchMatchType = "FileName" | "DirectoryName" | "FullPath";
 
 export type Repo = { id: string, path: string, name: string, display_name: string, created_at: Date, updated_at: Date, };
 
-export type ProjectRepo = { id: string, project_id: string, repo_id: string, setup_script: string | null, cleanup_script: string | null, copy_files: string | null, parallel_setup_script: boolean, };
+export type ProjectRepo = { id: string, project_id: string, repo_id: string, setup_script: string | null, cleanup_script: string | null, copy_files: string | null, parallel_setup_script: boolean, 
+/**
+ * Target branch for new attempts when the client doesn't specify one
+ */
+default_target_branch: string | null, };
 
 export type CreateProjectRepo = { display_name: string, git_repo_path: string, };
 
-export type UpdateProjectRepo = { setup_script: string | null, cleanup_script: string | null, copy_files: string | null, parallel_setup_script: boolean | null, };
+export type UpdateProjectRepo = { setup_script: string | null, cleanup_script: string | null, copy_files: string | null, parallel_setup_script: boolean | null, default_target_branch: string | null, };
 
 export type WorkspaceRepo = { id: string, workspace_id: string, repo_id: string, target_branch: string, created_at: Date, updated_at: Date, };
 
@@ -56,6 +121,56 @@ export type CreateTask = { project_id: string, title: string, description: strin
 
 export type UpdateTask = { title: string | null, description: string | null, status: TaskStatus | null, execution_mode: ExecutionMode | null, parent_workspace_id: string | null, image_ids: Array<string> | null, };
 
+export type StatusTransition = { from_status: TaskStatus, to_status: TaskStatus, };
+
+export type SetStatusTransitions = { 
+/**
+ * An empty list removes all restrictions
+ */
+transitions: Array<StatusTransition>, };
+
+export type TaskOwner = { task_id: string, created_by_user_id: string | null, assignee_user_id: string | null, updated_at: string, };
+
+export type SetTaskAssignee = { assignee_user_id: string | null, };
+
+export type MyTask = { project_name: string, is_creator: boolean, is_assignee: boolean, has_in_progress_attempt: boolean, last_attempt_failed: boolean, executor: string, latest_workspace_id: string | null, latest_workspace_container_ref: string | null, id: string, project_id: string, title: string, description: string | null, status: TaskStatus, execution_mode: ExecutionMode, queue_position: number | null, parent_workspace_id: string | null, shared_task_id: string | null, created_at: string, updated_at: string, };
+
+export type MyTaskGroup = { status: TaskStatus, tasks: Array<MyTask>, };
+
+export type FeedEventKind = "attempt_started" | "attempt_completed" | "attempt_failed" | "mentioned" | "review_requested" | "queue_started";
+
+export type FeedEvent = { 
+/**
+ * Increases with every event, so it doubles as the pagination cursor
+ */
+id: number, user_id: string, kind: FeedEventKind, title: string, project_id: string | null, task_id: string | null, workspace_id: string | null, created_at: string, };
+
+export type FeedPage = { events: Array<FeedEvent>, 
+/**
+ * Pass as `before` to get the next page; null on the last one
+ */
+next_cursor: number | null, };
+
+export type QuickAddPriority = "urgent" | "high" | "medium" | "low";
+
+export type QuickAdd = { title: string, labels: Array<string>, priority: QuickAddPriority | null, 
+/**
+ * Username of the assignee, without the `@`
+ */
+assignee: string | null, due_date: string | null, execution_mode: ExecutionMode | null, };
+
+export type QuickAddRequest = { 
+/**
+ * e.g. `Fix login bug #bug !high @alice due:fri seq`
+ */
+text: string, };
+
+export type QuickAddResponse = { task: Task, 
+/**
+ * How the line was read
+ */
+parsed: QuickAdd, };
+
 export type DraftFollowUpData = { message: string, variant: string | null, };
 
 export type ScratchPayload = { "type": "DRAFT_TASK", "data": string } | { "type": "DRAFT_FOLLOW_UP", "data": DraftFollowUpData };
@@ -72,7 +187,11 @@ export type Image = { id: string, file_path: string, original_name: string, mime
 
 export type CreateImage = { file_path: string, original_name: string, mime_type: string | null, size_bytes: bigint, hash: string, };
 
-export type Workspace = { id: string, task_id: string, container_ref: string | null, branch: string, agent_working_dir: string | null, setup_completed_at: string | null, created_at: string, updated_at: string, };
+export type Workspace = { id: string, task_id: string, container_ref: string | null, branch: string, agent_working_dir: string | null, setup_completed_at: string | null, 
+/**
+ * Commit that landed the branch, once a merge or merged PR is recorded
+ */
+merge_commit_sha: string | null, created_at: string, updated_at: string, };
 
 export type Session = { id: string, workspace_id: string, executor: string | null, created_at: string, updated_at: string, };
 
@@ -106,6 +225,26 @@ export type CreateApprovalRequest = { tool_name: string, tool_input: JsonValue,
 
 export type ApprovalResponse = { execution_process_id: string, status: ApprovalStatus, };
 
+export type PendingApprovalInfo = { id: string, execution_process_id: string, tool_name: string, tool_input: JsonValue, policy_rule: string | null, created_at: string, timeout_at: string, };
+
+export type DenyApprovalRequest = { reason: string | null, };
+
+export type ProposedTaskStatus = "pending" | "accepted" | "rejected";
+
+export type ProposedTask = { id: string, workspace_id: string, execution_process_id: string | null, title: string, description: string | null, status: ProposedTaskStatus, 
+/**
+ * The child task created when the proposal was accepted
+ */
+task_id: string | null, created_at: string, updated_at: string, };
+
+export type AcceptProposedTaskRequest = { title: string | null, description: string | null, };
+
+export type DiffComment = { id: string, workspace_id: string, repo_id: string, file_path: string, line_number: bigint | null, body: string, author_user_id: string | null, resolved: boolean, created_at: string, updated_at: string, };
+
+export type CreateDiffComment = { repo_id: string, file_path: string, line_number: bigint | null, body: string, };
+
+export type UpdateDiffComment = { body: string | null, resolved: boolean | null, };
+
 export type Diff = { change: DiffChangeKind, oldPath: string | null, newPath: string | null, oldContent: string | null, newContent: string | null, 
 /**
  * True when file contents are intentionally omitted (e.g., too large)
@@ -118,7 +257,13 @@ additions: number | null, deletions: number | null, };
 
 export type DiffChangeKind = "added" | "deleted" | "modified" | "renamed" | "copied" | "permissionChange";
 
-export type ApiResponse<T, E = T> = { success: boolean, data: T | null, error_data: E | null, message: string | null, };
+export type ApiResponse<T, E = T> = { success: boolean, data: T | null, error_data: E | null, message: string | null, error_code: ErrorCode | null, 
+/**
+ * Values interpolated into the message, keyed by name
+ */
+error_params: { [key in string]?: string } | null, };
+
+export type ErrorCode = "BAD_REQUEST" | "UNAUTHORIZED" | "FORBIDDEN" | "NOT_FOUND" | "CONFLICT" | "INTERNAL" | "BAD_GATEWAY" | "SERVICE_UNAVAILABLE" | "REPO_REQUIRED" | "REPO_NOT_FOUND" | "PROJECT_NOT_FOUND" | "TASK_NOT_FOUND" | "QUEUE_NOT_SEQUENTIAL" | "TASK_HAS_RUNNING_PROCESSES" | "INTEGRATION_NOT_CONFIGURED" | "PATH_NOT_FOUND" | "PATH_NOT_DIRECTORY" | "NOT_A_GIT_REPOSITORY" | "USERNAME_TOO_SHORT" | "PASSWORD_TOO_SHORT" | "MESSAGE_QUEUED" | "MERGE_CONFLICTS" | "REBASE_IN_PROGRESS" | "ATTEMPT_ALREADY_RUNNING" | "VALIDATION_FAILED" | "DEPENDENCY_CYCLE";
 
 export type LoginStatus = { "status": "loggedout" } | { "status": "loggedin", profile: ProfileResponse, };
 
@@ -220,9 +365,9 @@ export type AuthTokensResponse = { access_token: string, refresh_token: string,
 
 export type SetupStatusResponse = { setup_required: boolean, user_count: bigint, };
 
-export type UserPublic = { id: string, username: string, email: string | null, role: "admin" | "user", created_at: string, };
+export type UserPublic = { id: string, username: string, email: string | null, role: "admin" | "user" | "viewer", created_at: string, };
 
-export type UserRole = "admin" | "user";
+export type UserRole = "admin" | "user" | "viewer";
 
 export type UpdateUser = { email: string | null, role: string | null, };
 
@@ -232,13 +377,21 @@ export type UpdateUserRequest = { email: string | null, role: string | null, pas
 
 export type UsersListResponse = { users: Array<UserPublic>, };
 
-export type CreateFollowUpAttempt = { prompt: string, variant: string | null, retry_process_id: string | null, force_when_dirty: boolean | null, perform_git_reset: boolean | null, };
+export type CreateFollowUpAttempt = { prompt: string, variant: string | null, retry_process_id: string | null, force_when_dirty: boolean | null, perform_git_reset: boolean | null, 
+/**
+ * Append the workspace's unresolved diff comments and resolve them once sent
+ */
+include_diff_comments: boolean | null, };
 
 export type ChangeTargetBranchRequest = { repo_id: string, new_target_branch: string, };
 
 export type ChangeTargetBranchResponse = { repo_id: string, new_target_branch: string, status: [number, number], };
 
-export type MergeTaskAttemptRequest = { repo_id: string, };
+export type MergeTaskAttemptRequest = { repo_id: string, 
+/**
+ * Revert changes to paths protected by a rejecting rule instead of refusing to merge
+ */
+revert_protected_paths: boolean, };
 
 export type PushTaskAttemptRequest = { repo_id: string, };
 
@@ -254,117 +407,316 @@ export type AssignSharedTaskRequest = { new_assignee_user_id: string | null, };
 
 export type ShareTaskResponse = { shared_task_id: string, };
 
-export type CreateAndStartTaskRequest = { task: CreateTask, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, };
+export type CreateAndStartTaskRequest = { task: CreateTask, 
+/**
+ * Resolved from the project's label rules and history when not given
+ */
+executor_profile_id?: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, labels?: Array<string>, };
+
+export type ValidateTaskRequest = { title: string, description: string | null, };
+
+export type TaskValidationRule = "required_section" | "max_length" | "secret" | "forbidden_pattern";
+
+export type TaskValidationIssue = { rule: TaskValidationRule, level: ValidationLevel, message: string, };
+
+export type TaskValidationReport = { issues: Array<TaskValidationIssue>, };
+
+export type CheckDuplicatesRequest = { project_id: string, title: string, 
+/**
+ * The task being edited, which shouldn't be reported as its own duplicate
+ */
+exclude_task_id: string | null, };
+
+export type PotentialDuplicate = { task_id: string, title: string, status: TaskStatus, 
+/**
+ * Share of title trigrams the tasks have in common, from 0 to 1
+ */
+similarity: number, };
+
+export type CreateGitHubPrRequest = { title: string, body: string | null, target_branch: string | null, draft: boolean | null, repo_id: string, auto_generate_description: boolean, 
+/**
+ * Push even if the secret scan flags the branch's changes
+ */
+allow_secrets: boolean, };
+
+export type CreateGitLabMrRequest = { 
+/**
+ * Falls back to the agent-written title, then the task's title, when omitted
+ */
+title: string | null, 
+/**
+ * Falls back to the agent-written description, then the configured
+ * description template, when omitted
+ */
+body: string | null, target_branch: string | null, repo_id: string, labels: Array<string>, remove_source_branch: boolean, 
+/**
+ * Push even if the secret scan flags the branch's changes
+ */
+allow_secrets: boolean, };
+
+export type GitLabMergeRequest = { id: string, workspace_id: string, repo_id: string, mr_iid: bigint, web_url: string, state: string, target_branch: string, created_at: string, updated_at: string, };
+
+export type CreateBitbucketPrRequest = { title: string, 
+/**
+ * Falls back to a description built from the task when omitted
+ */
+body: string | null, target_branch: string | null, repo_id: string, close_source_branch: boolean, 
+/**
+ * Push even if the secret scan flags the branch's changes
+ */
+allow_secrets: boolean, };
+
+export type BitbucketPullRequest = { id: string, workspace_id: string, repo_id: string, pr_id: bigint, web_url: string, state: string, target_branch: string, created_at: string, updated_at: string, };
+
+export type CreatePrSetRequest = { title: string, 
+/**
+ * Falls back to the agent-written description when omitted
+ */
+body: string | null, draft: boolean | null, 
+/**
+ * Push even if the secret scan flags the branch's changes
+ */
+allow_secrets: boolean, };
+
+export type CiStatus = "none" | "pending" | "success" | "failure";
+
+export type PrSetMember = { repo_id: string, repo_name: string, 
+/**
+ * `owner/repo` on GitHub
+ */
+github_repo: string, pr_number: bigint, pr_url: string, status: MergeStatus, };
+
+export type PrSetState = "none" | "incomplete" | "open" | "partially_merged" | "merged" | "closed";
+
+export type PrSetStatus = { state: PrSetState, ci_status: CiStatus, members: Array<PrSetMember>, 
+/**
+ * Workspace repositories without a pull request
+ */
+missing_repo_ids: Array<string>, };
+
+export type ReviewState = "none" | "review_required" | "approved" | "changes_requested";
+
+export type CiCheck = { name: string, status: CiStatus, 
+/**
+ * Where the check's details are shown
+ */
+url: string | null, };
+
+export type PullRequestProvider = "github" | "gitlab";
 
-export type CreateGitHubPrRequest = { title: string, body: string | null, target_branch: string | null, draft: boolean | null, repo_id: string, auto_generate_description: boolean, };
+export type WorkspacePullRequest = { provider: PullRequestProvider, number: bigint, url: string, state: MergeStatus, review_state: ReviewState, ci_status: CiStatus, checks: Array<CiCheck>, 
+/**
+ * None until review and CI are first polled
+ */
+checked_at: string | null, };
 
 export type ImageResponse = { id: string, file_path: string, original_name: string, mime_type: string | null, size_bytes: bigint, hash: string, created_at: string, updated_at: string, };
 
 export type ImageMetadata = { exists: boolean, file_name: string | null, path: string | null, size_bytes: bigint | null, format: string | null, proxy_url: string | null, };
 
-export type CreateTaskAttemptBody = { task_id: string, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, };
+export type TableStats = { name: string, row_count: bigint, 
+/**
+ * Bytes used by the table and its indexes; `None` when the SQLite build lacks `dbstat`
+ */
+size_bytes: bigint | null, };
+
+export type DatabaseStats = { 
+/**
+ * Size of the main database file (page_count * page_size)
+ */
+file_size_bytes: bigint, 
+/**
+ * Bytes held by free pages that a VACUUM would give back
+ */
+free_bytes: bigint, tables: Array<TableStats>, };
 
-export type WorkspaceRepoInput = { repo_id: string, target_branch: string, };
+export type OrphanedRecords = { kind: string, count: bigint, oldest_created_at: string | null, };
 
-export type RunAgentSetupRequest = { executor_profile_id: ExecutorProfileId, };
+export type DirectoryUsage = { path: string, size_bytes: bigint, file_count: bigint, };
 
-export type RunAgentSetupResponse = Record<string, never>;
+export type WorkspaceDiskUsage = { usage: DirectoryUsage, workspace_count: bigint, 
+/**
+ * Workspace directories on disk that no workspace row points at any more
+ */
+orphaned_count: bigint, orphaned_size_bytes: bigint, oldest_orphan_modified_at: string | null, };
 
-export type GhCliSetupError = "BREW_MISSING" | "SETUP_HELPER_NOT_SUPPORTED" | { "OTHER": { message: string, } };
+export type StorageStats = { database: DatabaseStats, images: DirectoryUsage, workspaces: WorkspaceDiskUsage, orphaned_records: Array<OrphanedRecords>, };
 
-export type RebaseTaskAttemptRequest = { repo_id: string, old_base_branch: string | null, new_base_branch: string | null, };
+export type VacuumResponse = { reclaimed_bytes: bigint, file_size_bytes: bigint, };
 
-export type AbortConflictsRequest = { repo_id: string, };
+export type WorkspaceDirectory = { workspace_id: string, task_id: string, project_id: string, task_title: string, container_ref: string, branch: string, updated_at: string, };
 
-export type GitOperationError = { "type": "merge_conflicts", message: string, op: ConflictOp, } | { "type": "rebase_in_progress" };
+export type ReconcileAction = "repair" | "purge";
 
-export type PushError = { "type": "force_push_required" };
+export type ReconcileWorkspacesRequest = { workspace_ids: Array<string>, action: ReconcileAction, };
 
-export type CreatePrError = { "type": "github_cli_not_installed" } | { "type": "github_cli_not_logged_in" } | { "type": "git_cli_not_logged_in" } | { "type": "git_cli_not_installed" } | { "type": "target_branch_not_found", branch: string, };
+export type SkippedWorkspace = { workspace_id: string, reason: string, };
 
-export type BranchStatus = { commits_behind: number | null, commits_ahead: number | null, has_uncommitted_changes: boolean | null, head_oid: string | null, uncommitted_count: number | null, untracked_count: number | null, target_branch_name: string, remote_commits_behind: number | null, remote_commits_ahead: number | null, merges: Array<Merge>, 
+export type ReconcileWorkspacesResponse = { repaired: Array<string>, purged: Array<string>, skipped: Array<SkippedWorkspace>, };
+
+export type StaleSession = { session_id: string, workspace_id: string, task_id: string, task_title: string, executor: string | null, created_at: string, };
+
+export type InterruptedProcess = { execution_process_id: string, session_id: string, workspace_id: string, task_id: string, task_title: string, run_reason: ExecutionProcessRunReason, started_at: string, };
+
+export type RecoveryReport = { generated_at: string, interrupted_processes: Array<InterruptedProcess>, 
 /**
- * True if a `git rebase` is currently in progress in this worktree
+ * Workspaces whose directory no longer exists on disk
  */
-is_rebase_in_progress: boolean, 
+missing_workspaces: Array<WorkspaceDirectory>, 
 /**
- * Current conflict operation if any
+ * Directories under the workspace base dir with no workspace record
  */
-conflict_op: ConflictOp | null, 
+orphaned_directories: Array<string>, stale_sessions: Array<StaleSession>, };
+
+export type RecoveryFix = { "action": "remove_orphaned_directories", paths: Array<string>, } | { "action": "delete_stale_sessions", session_ids: Array<string>, } | { "action": "dismiss_interrupted", execution_process_ids: Array<string>, };
+
+export type RecoveryFixResponse = { fixed: number, report: RecoveryReport, };
+
+export type TrackedProcess = { pid: number, ppid: number, pgid: number, command: string, };
+
+export type ExecutionProcessTree = { execution_process_id: string, process_group_id: number, processes: Array<TrackedProcess>, };
+
+export type EventTopic = "tasks" | "projects" | "workspaces" | "execution_processes" | "scratch" | "imports" | "records";
+
+export type ConsumerOffset = { consumer: string, offset: number, 
 /**
- * List of files currently in conflicted (unmerged) state
+ * Events published after the committed offset
  */
-conflicted_files: Array<string>, };
+lag: number, };
 
-export type RunScriptError = { "type": "no_script_configured" } | { "type": "process_already_running" };
+export type EventBusStatus = { 
+/**
+ * Oldest offset still in the buffer; zero when the buffer is empty
+ */
+first_offset: number, last_offset: number, consumers: Array<ConsumerOffset>, };
 
-export type DeleteWorktreeError = { "type": "task_not_done" } | { "type": "no_worktree_exists" } | { "type": "has_running_processes" };
+export type ProjectSlotUsage = { project_id: string, weight: number, running: number, waiting: number, };
 
-export type AttachPrResponse = { pr_attached: boolean, pr_url: string | null, pr_number: bigint | null, pr_status: MergeStatus | null, };
+export type ExecutorSlotsStatus = { 
+/**
+ * Zero means no limit
+ */
+capacity: number, running: number, projects: Array<ProjectSlotUsage>, };
 
-export type AttachExistingPrRequest = { repo_id: string, };
+export type NotificationKind = "attempt_completed" | "attempt_failed" | "mention" | "review_requested" | "sync_error" | "issue_comment" | "sla_breach" | "budget_exceeded";
 
-export type PrCommentsResponse = { comments: Array<UnifiedPrComment>, };
+export type Notification = { id: string, user_id: string, kind: NotificationKind, title: string, message: string, project_id: string | null, task_id: string | null, workspace_id: string | null, read_at: string | null, created_at: string, };
 
-export type GetPrCommentsError = { "type": "no_pr_attached" } | { "type": "github_cli_not_installed" } | { "type": "github_cli_not_logged_in" };
+export type NotificationListQuery = { unread_only: boolean, limit: bigint | null, };
 
-export type GetPrCommentsQuery = { repo_id: string, };
+export type NotificationListResponse = { notifications: Array<Notification>, unread_count: bigint, };
 
-export type UnifiedPrComment = { "comment_type": "general", id: string, author: string, author_association: string, body: string, created_at: string, url: string, } | { "comment_type": "review", id: bigint, author: string, author_association: string, body: string, created_at: string, url: string, path: string, line: bigint | null, diff_hunk: string, };
+export type UnreadCountResponse = { unread_count: bigint, };
 
-export type GitHubIssue = { number: bigint, title: string, body: string | null, state: string, html_url: string, user: GitHubUser, labels: Array<GitHubLabel>, created_at: string, updated_at: string, assignees: Array<GitHubUser>, milestone: GitHubMilestone | null, };
+export type TaskReviewStatus = "pending" | "approved" | "changes_requested";
 
-export type GitHubUser = { login: string, avatar_url: string, };
+export type TaskReview = { id: string, task_id: string, reviewer_id: string, requested_by: string | null, status: TaskReviewStatus, comment: string | null, responded_at: string | null, created_at: string, updated_at: string, };
 
-export type GitHubLabel = { name: string, color: string, };
+export type RequestReviewRequest = { reviewer_ids: Array<string>, message: string | null, };
 
-export type GitHubMilestone = { title: string, number: bigint, };
+export type ReviewResponseRequest = { comment: string | null, };
 
-export type ListIssuesParams = { state: string | null, labels: string | null, sort: string | null, direction: string | null, per_page: number | null, page: number | null, };
+export type ReviewActionResponse = { review: TaskReview, task: Task, };
 
-export type GitHubIssuesResponse = { issues: Array<GitHubIssue>, has_github_config: boolean, };
+export type ProjectInstruction = { id: string, project_id: string, name: string, 
+/**
+ * Applied to tasks that don't select an instruction explicitly
+ */
+is_default: boolean, current_version: bigint, content: string, created_at: string, updated_at: string, };
 
-export type ImportIssueRequest = { issue_number: bigint, auto_start: boolean | null, };
+export type ProjectInstructionVersion = { instruction_id: string, version: bigint, content: string, created_at: string, };
 
-export type ImportIssueResponse = { task: Task, issue: GitHubIssue, };
+export type CreateProjectInstruction = { name: string, content: string, is_default: boolean | null, };
 
-export type GitHubConfigStatus = { has_repo_url: boolean, has_token: boolean, repo_url: string | null, sync_enabled: boolean, sync_labels: string | null, };
+export type UpdateProjectInstruction = { name: string | null, content: string | null, is_default: boolean | null, };
 
-export type GitLabIssue = { iid: bigint, title: string, description: string | null, state: string, web_url: string, author: GitLabUser, labels: Array<string>, created_at: string, updated_at: string, assignees: Array<GitLabUser>, milestone: GitLabMilestone | null, };
+export type TaskInstruction = { task_id: string, instruction_id: string, version: bigint | null, };
 
-export type GitLabUser = { username: string, avatar_url: string | null, };
+export type SetTaskInstruction = { instruction_id: string, version: bigint | null, };
 
-export type GitLabMilestone = { title: string, iid: bigint, };
+export type SecretScanOperation = "share" | "create_pr";
 
-export type ListGitLabIssuesParams = { state: string | null, labels: string | null, sort: string | null, order_by: string | null, per_page: number | null, page: number | null, };
+export type SecretScanFinding = { id: string, workspace_id: string, repo_id: string, operation: SecretScanOperation, file_path: string, line_number: bigint, kind: string, 
+/**
+ * Prefix of the matched value; the secret itself is never stored
+ */
+redacted: string, overridden: boolean, created_at: string, };
 
-export type GitLabIssuesResponse = { issues: Array<GitLabIssue>, has_gitlab_config: boolean, };
+export type ProjectGroup = { id: string, name: string, position: bigint, created_at: string, updated_at: string, };
 
-export type ImportGitLabIssueRequest = { issue_iid: bigint, auto_start: boolean | null, };
+export type CreateProjectGroup = { name: string, };
 
-export type ImportGitLabIssueResponse = { task: Task, issue: GitLabIssue, };
+export type UpdateProjectGroup = { name: string | null, position: bigint | null, };
 
-export type GitLabConfigStatus = { has_project_url: boolean, has_token: boolean, project_url: string | null, sync_enabled: boolean, sync_labels: string | null, };
+export type ProjectPlacement = { project_id: string, group_id: string | null, position: bigint, };
 
-export type VortexIssue = { id: string, workspace_id: string | null, project_id: string | null, key: string, title: string, description: string | null, type: string | null, status: string, priority: string | null, severity: string | null, assignee_id: string | null, reporter_id: string | null, due_date: string | null, labels: Array<string>, custom_fields: string | null, customFields: any, componentIds: Array<string>, subtasks: any[], linkedIssues: any[], attachments: Array<VortexAttachment>, watcherIds: Array<string>, github_issue: any, created_at: string, updated_at: string, };
+export type SetProjectPlacement = { group_id: string | null, position: bigint, };
 
-export type VortexUser = { id: string, name: string, email: string, avatar_url: string | null, };
+export type ProjectLayout = { groups: Array<ProjectGroup>, placements: Array<ProjectPlacement>, favorite_project_ids: Array<string>, };
 
-export type VortexAttachment = { id: string, filename: string, downloadUrl: string | null, mimeType: string | null, isImage: boolean, };
+export type ProjectListQuery = { 
+/**
+ * A project group id, `ungrouped`, or `favorites`
+ */
+group: string | null, };
 
-export type VortexComment = { id: string, issue_id: string, user_id: string, content: string, created_at: string, };
+export type RepoPathRule = { id: string, repo_id: string, 
+/**
+ * gitignore-style pattern relative to the repo root
+ */
+pattern: string, kind: RepoPathRuleKind, action: RepoPathRuleAction, created_at: string, };
 
-export type ListVortexIssuesParams = { status: string | null, priority: string | null, labels: string | null, page: number | null, limit: number | null, };
+export type RepoPathRuleKind = "deny" | "allow";
 
-export type VortexIssuesResponse = { issues: Array<VortexIssue>, has_vortex_config: boolean, };
+export type RepoPathRuleAction = "reject" | "flag";
 
-export type ImportVortexIssueRequest = { issue_id: string, };
+export type CreateRepoPathRule = { pattern: string, kind: RepoPathRuleKind, action: RepoPathRuleAction, };
+
+export type PathRuleViolation = { repo_id: string, file_path: string, 
+/**
+ * The deny rule that matched; `None` when the file is outside the allowlist
+ */
+rule_id: string | null, pattern: string | null, action: RepoPathRuleAction, };
+
+/**
+ * Files matching the pattern are left out of a project's diffs, linked-issue
+ * reports and PR descriptions, e.g. fixtures, generated or vendored code
+ */
+export type ProjectDiffExclusion = { id: string, project_id: string, 
+/**
+ * gitignore-style pattern relative to each repo root
+ */
+pattern: string, created_at: string, };
+
+export type CreateProjectDiffExclusion = { pattern: string, };
 
-export type ImportVortexIssueResponse = { task: Task, issue: VortexIssue, };
+export type CreateTaskAttemptBody = { task_id: string, executor_profile_id: ExecutorProfileId, repos: Array<WorkspaceRepoInput>, };
 
-export type VortexConfigStatus = { has_project_id: boolean, has_token: boolean, project_id: string | null, sync_enabled: boolean, sync_labels: string | null, };
+export type WorkspaceRepoInput = { repo_id: string, 
+/**
+ * Defaults to the project repo's default target branch, then the repo's current branch
+ */
+target_branch?: string, };
 
-export type RepoBranchStatus = { repo_id: string, repo_name: string, commits_behind: number | null, commits_ahead: number | null, has_uncommitted_changes: boolean | null, head_oid: string | null, uncommitted_count: number | null, untracked_count: number | null, target_branch_name: string, remote_commits_behind: number | null, remote_commits_ahead: number | null, merges: Array<Merge>, 
+export type RunAgentSetupRequest = { executor_profile_id: ExecutorProfileId, };
+
+export type RunAgentSetupResponse = Record<string, never>;
+
+export type GhCliSetupError = "BREW_MISSING" | "SETUP_HELPER_NOT_SUPPORTED" | { "OTHER": { message: string, } };
+
+export type RebaseTaskAttemptRequest = { repo_id: string, old_base_branch: string | null, new_base_branch: string | null, };
+
+export type AbortConflictsRequest = { repo_id: string, };
+
+export type GitOperationError = { "type": "merge_conflicts", message: string, op: ConflictOp, } | { "type": "rebase_in_progress" };
+
+export type PushError = { "type": "force_push_required" };
+
+export type CreatePrError = { "type": "github_cli_not_installed" } | { "type": "github_cli_not_logged_in" } | { "type": "git_cli_not_logged_in" } | { "type": "git_cli_not_installed" } | { "type": "target_branch_not_found", branch: string, } | { "type": "secrets_detected", findings: Array<SecretScanFinding>, };
+
+export type CreatePrSetError = { repo_id: string, repo_name: string, error: CreatePrError, };
+
+export type BranchStatus = { commits_behind: number | null, commits_ahead: number | null, has_uncommitted_changes: boolean | null, head_oid: string | null, uncommitted_count: number | null, untracked_count: number | null, target_branch_name: string, remote_commits_behind: number | null, remote_commits_ahead: number | null, merges: Array<Merge>, 
 /**
  * True if a `git rebase` is currently in progress in this worktree
  */
@@ -378,35 +730,873 @@ conflict_op: ConflictOp | null,
  */
 conflicted_files: Array<string>, };
 
-export type DirectoryEntry = { name: string, path: string, is_directory: boolean, is_git_repo: boolean, last_modified: bigint | null, };
+export type RunScriptError = { "type": "no_script_configured" } | { "type": "process_already_running" };
 
-export type DirectoryListResponse = { entries: Array<DirectoryEntry>, current_path: string, };
+export type DeleteWorktreeError = { "type": "task_not_done" } | { "type": "no_worktree_exists" } | { "type": "has_running_processes" };
 
-export type Config = { config_version: string, theme: ThemeMode, executor_profile: ExecutorProfileId, disclaimer_acknowledged: boolean, onboarding_acknowledged: boolean, notifications: NotificationConfig, editor: EditorConfig, github: GitHubConfig, analytics_enabled: boolean, workspace_dir: string | null, last_app_version: string | null, show_release_notes: boolean, language: UiLanguage, git_branch_prefix: string, showcases: ShowcaseState, pr_auto_description_enabled: boolean, pr_auto_description_prompt: string | null, };
+export type AttachPrResponse = { pr_attached: boolean, pr_url: string | null, pr_number: bigint | null, pr_status: MergeStatus | null, };
 
-export type NotificationConfig = { sound_enabled: boolean, push_enabled: boolean, sound_file: SoundFile, };
+export type AttachExistingPrRequest = { repo_id: string, };
 
-export enum ThemeMode { LIGHT = "LIGHT", DARK = "DARK", SYSTEM = "SYSTEM" }
+export type PrCommentsResponse = { comments: Array<UnifiedPrComment>, };
 
-export type EditorConfig = { editor_type: EditorType, custom_command: string | null, remote_ssh_host: string | null, remote_ssh_user: string | null, };
+export type GetPrCommentsError = { "type": "no_pr_attached" } | { "type": "github_cli_not_installed" } | { "type": "github_cli_not_logged_in" };
 
-export enum EditorType { VS_CODE = "VS_CODE", CURSOR = "CURSOR", WINDSURF = "WINDSURF", INTELLI_J = "INTELLI_J", ZED = "ZED", XCODE = "XCODE", CUSTOM = "CUSTOM" }
+export type GetPrCommentsQuery = { repo_id: string, };
 
-export type EditorOpenError = { "type": "executable_not_found", executable: string, editor_type: EditorType, } | { "type": "invalid_command", details: string, editor_type: EditorType, } | { "type": "launch_failed", executable: string, details: string, editor_type: EditorType, };
+export type DiffFileQuery = { repo_id: string, 
+/**
+ * Path relative to the repo root, without the repo name prefix of the
+ * diff stream
+ */
+path: string, };
 
-export type GitHubConfig = { pat: string | null, oauth_token: string | null, username: string | null, primary_email: string | null, default_pr_base: string | null, };
+export type DiffSizeSummary = { 
+/**
+ * Whether any repo's diff is past the configured limits, so the diff
+ * stream only carries stats
+ */
+oversized: boolean, files: number, bytes: number, additions: number, deletions: number, directories: Array<DirectoryRollup>, };
 
-export enum SoundFile { ABSTRACT_SOUND1 = "ABSTRACT_SOUND1", ABSTRACT_SOUND2 = "ABSTRACT_SOUND2", ABSTRACT_SOUND3 = "ABSTRACT_SOUND3", ABSTRACT_SOUND4 = "ABSTRACT_SOUND4", COW_MOOING = "COW_MOOING", PHONE_VIBRATION = "PHONE_VIBRATION", ROOSTER = "ROOSTER" }
+export type DirectoryRollup = { 
+/**
+ * Directory of the changed files, prefixed with the repo name like the
+ * paths of the diff stream
+ */
+path: string, files: number, additions: number, deletions: number, };
 
-export type UiLanguage = "BROWSER" | "EN" | "JA" | "ES" | "KO" | "ZH_HANS";
+export type UnifiedPrComment = { "comment_type": "general", id: string, author: string, author_association: string, body: string, created_at: string, url: string, } | { "comment_type": "review", id: bigint, author: string, author_association: string, body: string, created_at: string, url: string, path: string, line: bigint | null, diff_hunk: string, };
 
-export type ShowcaseState = { seen_features: Array<string>, };
+export type GitHubIssue = { number: bigint, title: string, body: string | null, state: string, html_url: string, user: GitHubUser, labels: Array<GitHubLabel>, created_at: string, updated_at: string, assignees: Array<GitHubUser>, milestone: GitHubMilestone | null, };
 
-export type GitBranch = { name: string, is_current: boolean, is_remote: boolean, last_commit_date: Date, };
+export type GitHubUser = { login: string, avatar_url: string, };
 
-export type SharedTaskDetails = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, };
+export type GitHubLabel = { name: string, color: string, };
 
-export type QueuedMessage = { 
+export type GitHubMilestone = { title: string, number: bigint, description: string | null, 
+/**
+ * `open` or `closed`
+ */
+state: string, html_url: string, open_issues: bigint, closed_issues: bigint, due_on: string | null, };
+
+export type ListIssuesParams = { state: string | null, labels: string | null, sort: string | null, direction: string | null, per_page: number | null, page: number | null, };
+
+export type GitHubPullRequest = { number: bigint, title: string, body: string | null, state: string, html_url: string, diff_url: string, user: GitHubUser, labels: Array<GitHubLabel>, draft: boolean, requested_reviewers: Array<GitHubUser>, head: GitHubPullRequestRef, created_at: string, updated_at: string, };
+
+export type GitHubPullRequestRef = { ref: string, sha: string, };
+
+export type GitHubCiStatus = "success" | "pending" | "failure" | "none";
+
+export type GitHubIssueFilter = { 
+/**
+ * Issue must carry every one of these labels
+ */
+labels: Array<string>, 
+/**
+ * Milestone title
+ */
+milestone: string | null, 
+/**
+ * Assignee login
+ */
+assignee: string | null, created_after: string | null, created_before: string | null, };
+
+export type ImportProgress = { import_id: string, project_id: string, 
+/**
+ * Issues selected for import; zero until the provider has been queried
+ */
+total: number, imported: number, 
+/**
+ * Issues that did not match the filters or already have a task
+ */
+skipped: number, failed: number, errors: Array<string>, done: boolean, };
+
+export type GitHubIssuesResponse = { issues: Array<GitHubIssue>, has_github_config: boolean, };
+
+export type ImportIssueRequest = { issue_number: bigint, auto_start: boolean | null, };
+
+export type BulkImportIssuesRequest = { 
+/**
+ * Issues to import. Empty imports every open issue that matches the filter.
+ */
+issue_numbers: Array<bigint>, 
+/**
+ * Issue must carry every one of these labels
+ */
+labels: Array<string>, 
+/**
+ * Milestone title
+ */
+milestone: string | null, 
+/**
+ * Assignee login
+ */
+assignee: string | null, created_after: string | null, created_before: string | null, };
+
+export type GitHubPullRequestsResponse = { pull_requests: Array<GitHubPullRequest>, has_github_config: boolean, };
+
+export type ImportPullRequestRequest = { pull_number: bigint, };
+
+export type ImportPullRequestResponse = { task: Task, pull_request: GitHubPullRequest, ci_status: GitHubCiStatus, };
+
+export type GitHubConfigStatus = { has_repo_url: boolean, has_token: boolean, repo_url: string | null, sync_enabled: boolean, sync_labels: string | null, sync_pull_requests: boolean, label_sync: LabelSyncDirection, };
+
+export type WebhookProvider = "github" | "gitlab" | "gitea" | "slack" | "vortex";
+
+export type ProjectWebhook = { project_id: string, provider: WebhookProvider, created_at: string, updated_at: string, };
+
+export type SetProjectWebhook = { secret: string, };
+
+export type WebhookDeliveryResult = { projects: number, tasks_created: number, tasks_updated: number, };
+
+export type ProjectEmailAddress = { project_id: string, address: string, created_at: string, updated_at: string, };
+
+export type SetProjectEmailAddress = { address: string, };
+
+/**
+ * A Slack account an admin linked to a local user, so approvals and tasks from
+ * Slack are attributed to that user. Slack users without a link act as
+ * themselves only.
+ */
+export type SlackUserLink = { 
+/**
+ * Slack's id for the user, e.g. `U2147483697`
+ */
+slack_user_id: string, user_id: string, created_at: string, updated_at: string, };
+
+export type SetSlackUserLink = { slack_user_id: string, user_id: string, };
+
+export type EmailIntakeResult = { 
+/**
+ * None when no project uses any of the recipient addresses
+ */
+task: Task | null, attachments_stored: number, 
+/**
+ * Attachments that could not be stored, such as non-image files
+ */
+attachments_skipped: Array<string>, };
+
+export type TaskSchedule = { task_id: string, starts_at: string | null, due_at: string | null, updated_at: string, };
+
+export type SetTaskSchedule = { starts_at: string | null, due_at: string | null, };
+
+export type LabelSyncDirection = "off" | "import" | "export" | "both";
+
+export type SetTaskLabels = { labels: Array<string>, };
+
+export type TaskDependency = { task_id: string, depends_on_task_id: string, 
+/**
+ * Current status of the task depended on
+ */
+depends_on_status: TaskStatus, created_at: string, };
+
+export type CreateTaskDependency = { depends_on_task_id: string, };
+
+export type ProjectCalendarFeed = { project_id: string, created_at: string, };
+
+export type RotatedCalendarFeed = { calendar_feed: ProjectCalendarFeed, 
+/**
+ * Only returned here; it can't be looked up again
+ */
+token: string, };
+
+export type ProjectStatusPage = { project_id: string, created_at: string, };
+
+export type RotatedStatusPage = { status_page: ProjectStatusPage, 
+/**
+ * Only returned here; it can't be looked up again
+ */
+token: string, };
+
+export type StatusCount = { status: TaskStatus, count: number, };
+
+export type CompletedTask = { title: string, 
+/**
+ * When the task last changed while in Done
+ */
+completed_at: string, };
+
+/**
+ * What a public status page shows. Only titles of completed tasks are
+ * included; descriptions, ids and people stay private.
+ */
+export type PublicProjectStatus = { project_name: string, 
+/**
+ * Every column, including empty ones
+ */
+columns: Array<StatusCount>, 
+/**
+ * Most recently completed first
+ */
+recently_completed: Array<CompletedTask>, 
+/**
+ * Sequential tasks waiting in To Do for the queue to reach them
+ */
+queue_length: number, generated_at: string, };
+
+export type RestHookEvent = "task_created" | "task_status_changed" | "attempt_finished" | "sla_breached";
+
+export type RestHookSubscription = { id: string, event: RestHookEvent, target_url: string, 
+/**
+ * None subscribes to events from every project
+ */
+project_id: string | null, created_by_user_id: string | null, created_at: string, };
+
+export type CreateRestHookSubscription = { event: RestHookEvent, target_url: string, project_id: string | null, };
+
+export type IssueSyncPreview = { to_import: Array<IssueSyncPreviewItem>, already_linked: Array<IssueSyncPreviewItem>, };
+
+export type ImportedIssue = { task: Task, issue: ProviderIssue, };
+
+export type ProviderIssue = { 
+/**
+ * What the provider's API addresses the issue by
+ */
+id: string, 
+/**
+ * What people call the issue, e.g. `42` or `WEB-7`
+ */
+key: string, title: string, body: string | null, url: string, labels: Array<string>, closed: boolean, 
+/**
+ * Status the imported task starts in, for trackers whose states map onto
+ * task statuses; others start in To Do
+ */
+status: TaskStatus | null, };
+
+export type IssueSyncPreviewItem = { 
+/**
+ * Provider's issue number or key, e.g. `#42` or `PR #7`
+ */
+external_id: string, 
+/**
+ * Title the imported task would get
+ */
+title: string, url: string, 
+/**
+ * Open tasks with similar titles, filled in for issues that would be imported
+ */
+possible_duplicates: Array<PotentialDuplicate>, };
+
+export type SyncRunStatus = "running" | "succeeded" | "failed";
+
+export type SyncRun = { id: string, project_id: string, 
+/**
+ * Provider name as shown to users, e.g. `GitHub`
+ */
+provider: string, status: SyncRunStatus, imported_count: bigint, updated_count: bigint, error: string | null, started_at: string, finished_at: string | null, };
+
+/**
+ * Providers with a per-project `*_sync_enabled` flag
+ */
+export type SyncProvider = "GitHub" | "GitLab" | "Gitea" | "Vortex";
+
+/**
+ * Outcome of the last scheduled sync of one provider of a project
+ */
+export type ScheduledSyncStatus = { provider: SyncProvider, last_run_at: string, last_success_at: string | null, last_error: string | null, 
+/**
+ * Scheduled runs that failed in a row, reset by a successful one
+ */
+consecutive_failures: number, };
+
+export type IssueSyncSchedule = { enabled: boolean, interval_minutes: number, next_run_at: string | null, 
+/**
+ * Whether a sync of the project is running right now
+ */
+syncing: boolean, providers: Array<ScheduledSyncStatus>, };
+
+export type EstimateUnit = "points" | "hours";
+
+export type TaskEstimate = { task_id: string, value: number, unit: EstimateUnit, updated_at: string, };
+
+export type SetTaskEstimate = { value: number | null, unit: EstimateUnit, };
+
+export type UserCapacity = { user_id: string, weekly_hours: number | null, weekly_points: number | null, updated_at: string, };
+
+export type SetUserCapacity = { weekly_hours: number | null, weekly_points: number | null, };
+
+export type EstimatedWork = { hours: number, points: number, tasks: number, };
+
+export type UserCapacityUsage = { user_id: string, username: string, queued: EstimatedWork, weekly_hours: number | null, weekly_points: number | null, 
+/**
+ * Queued work as a share of weekly capacity, taking the fuller of the two
+ * units; above 1.0 the user is overbooked. `None` when no capacity is set.
+ */
+utilization: number | null, };
+
+export type ProjectCapacity = { users: Array<UserCapacityUsage>, 
+/**
+ * Estimated work on tasks nobody is assigned to
+ */
+unassigned: EstimatedWork, };
+
+export type SlaRuleKind = "time_in_status" | "idle";
+
+export type SlaRule = { id: string, project_id: string, status: TaskStatus, kind: SlaRuleKind, threshold_hours: number, created_at: string, };
+
+export type CreateSlaRule = { status: TaskStatus, kind: SlaRuleKind, threshold_hours: number, };
+
+export type LabelProfileRule = { id: string, project_id: string, label: string, executor_profile_id: ExecutorProfileId, created_at: string, };
+
+export type UpsertLabelProfileRule = { label: string, executor_profile_id: ExecutorProfileId, };
+
+export type PendingStart = { id: string, task_id: string, project_id: string, task_title: string, 
+/**
+ * Who moved the task to In Progress, when it was a signed-in user
+ */
+requested_by: string | null, requested_by_username: string | null, created_at: string, };
+
+export type TaskDraft = { task_id: string, project_id: string, task_title: string, 
+/**
+ * Provider the issue was synced from, e.g. `GitHub`
+ */
+source: string, created_at: string, };
+
+export type TriageTaskDrafts = { task_ids: Array<string>, 
+/**
+ * Added to each task's labels, which also pick its executor through the
+ * project's label rules
+ */
+labels: Array<string>, 
+/**
+ * Move the tasks to Cancelled instead of To Do, for issues that won't be
+ * worked on
+ */
+dismiss: boolean, };
+
+export type BudgetAction = "pause" | "abort";
+
+export type TokenBudget = { max_tokens: number, action: BudgetAction, updated_at: string, };
+
+export type SetTokenBudget = { max_tokens: number | null, action: BudgetAction, };
+
+export type MaintenanceJob = { id: string, project_id: string, 
+/**
+ * Title of the tasks created for each run
+ */
+name: string, prompt: string, 
+/**
+ * Five-field cron expression, evaluated in UTC
+ */
+schedule: string, executor_profile_id: ExecutorProfileId, enabled: boolean, last_run_at: string | null, 
+/**
+ * Task created by the most recent run
+ */
+last_task_id: string | null, created_at: string, updated_at: string, };
+
+export type UpsertMaintenanceJob = { name: string, prompt: string, schedule: string, executor_profile_id: ExecutorProfileId, enabled: boolean, };
+
+export type ProjectExecutorWeight = { project_id: string, weight: bigint, };
+
+export type SetProjectExecutorWeight = { weight: bigint, };
+
+export type ProfileStats = { executor_profile_id: ExecutorProfileId, attempts: number, 
+/**
+ * Attempts that were merged or whose task was completed
+ */
+successful_attempts: number, success_rate: number, 
+/**
+ * Follow-ups sent per attempt after the initial run
+ */
+avg_follow_ups: number, 
+/**
+ * Share of agent runs that failed
+ */
+failed_run_rate: number, avg_agent_minutes: number, 
+/**
+ * Success rate smoothed towards 50% for profiles with few attempts
+ */
+score: number, };
+
+export type ExecutorRecommendation = { 
+/**
+ * None until some profile has at least [`MIN_ATTEMPTS`] attempts
+ */
+recommended: ExecutorProfileId | null, reason: string, attempts_considered: number, 
+/**
+ * Best first
+ */
+profiles: Array<ProfileStats>, };
+
+export type RepoHealth = { repo_id: string, 
+/**
+ * First commit of the repository's history, used to recognise it at a new
+ * path
+ */
+root_commit: string | null, remote_url: string | null, broken_reason: string | null, checked_at: string, };
+
+export type RelocateRepoRequest = { 
+/**
+ * Where the repository's checkout lives now
+ */
+path: string, };
+
+export type TaskSlaBreach = { id: string, task_id: string, rule_id: string, breached_at: string, resolved_at: string | null, };
+
+export type KioskToken = { id: string, name: string, created_by_user_id: string | null, last_used_at: string | null, created_at: string, };
+
+export type CreateKioskToken = { name: string, };
+
+export type CreatedKioskToken = { kiosk_token: KioskToken, 
+/**
+ * Only returned here; it can't be looked up again
+ */
+token: string, };
+
+export type WorkspaceManifest = { workspace_id: string, task_id: string, project_id: string, branch: string, repos: Array<ManifestRepo>, executor_profile_id: ExecutorProfileId, 
+/**
+ * The resolved profile configuration, including any command overrides.
+ * Default agent commands are pinned per release, so together with
+ * `app_version` this identifies the agent version.
+ */
+executor_config: JsonValue | null, app_version: string, 
+/**
+ * Names of the variables set on the agent's process; values are left out
+ */
+env_var_names: Array<string>, os: ManifestOs, created_at: string, };
+
+export type ManifestRepo = { repo_id: string, name: string, target_branch: string, 
+/**
+ * Commit the attempt branch pointed at when the attempt started
+ */
+base_commit: string | null, };
+
+export type ManifestOs = { os_type: string, version: string, architecture: string | null, };
+
+export type WorkspaceSummary = { workspace_id: string, 
+/**
+ * Set while an agent run is generating a new summary
+ */
+execution_process_id: string | null, commit_message: string | null, pr_title: string | null, pr_description: string | null, 
+/**
+ * Tokens the attempt's coding agent runs have spent, as far as they were metered
+ */
+tokens_used: number, 
+/**
+ * The budget in force when the attempt went over it
+ */
+token_budget: number | null, budget_exceeded_at: string | null, updated_at: string, };
+
+export type ManagedBranch = { id: string, repo_id: string, 
+/**
+ * Cleared when the attempt is deleted; the branch may outlive it
+ */
+workspace_id: string | null, branch_name: string, 
+/**
+ * Target branch the attempt branched from
+ */
+base_branch: string, deleted_at: string | null, created_at: string, };
+
+export type ManagedBranchStatus = { exists: boolean, id: string, repo_id: string, 
+/**
+ * Cleared when the attempt is deleted; the branch may outlive it
+ */
+workspace_id: string | null, branch_name: string, 
+/**
+ * Target branch the attempt branched from
+ */
+base_branch: string, deleted_at: string | null, created_at: string, };
+
+export type TimelineTask = { id: string, title: string, status: TaskStatus, 
+/**
+ * Task whose attempt spawned this one
+ */
+parent_task_id: string | null, created_at: string, started_at: string | null, finished_at: string | null, planned_start_at: string | null, due_at: string | null, };
+
+export type ProjectTimeline = { tasks: Array<TimelineTask>, dependencies: Array<TimelineDependency>, };
+
+export type TimelineDependency = { task_id: string, depends_on_task_id: string, };
+
+export type BoardSnapshot = { timestamp: string, tasks: Array<BoardSnapshotTask>, };
+
+export type BoardSnapshotTask = { task_id: string, title: string, status: TaskStatus, 
+/**
+ * When the task entered `status`
+ */
+status_since: string, };
+
+export type GitLabIssue = { iid: bigint, title: string, description: string | null, state: string, web_url: string, author: GitLabUser, labels: Array<string>, created_at: string, updated_at: string, assignees: Array<GitLabUser>, milestone: GitLabMilestone | null, };
+
+export type GitLabUser = { username: string, avatar_url: string | null, };
+
+export type GitLabMilestone = { title: string, iid: bigint, };
+
+export type ListGitLabIssuesParams = { state: string | null, labels: string | null, sort: string | null, order_by: string | null, per_page: number | null, page: number | null, };
+
+export type GitLabIssuesResponse = { issues: Array<GitLabIssue>, has_gitlab_config: boolean, };
+
+export type ImportGitLabIssueRequest = { issue_iid: bigint, auto_start: boolean | null, };
+
+export type GitLabConfigStatus = { has_project_url: boolean, has_token: boolean, base_url: string | null, project_url: string | null, sync_enabled: boolean, sync_labels: string | null, };
+
+export type GiteaIssue = { number: bigint, title: string, body: string | null, state: string, html_url: string, user: GiteaUser, labels: Array<GiteaLabel>, created_at: string, updated_at: string, assignees: Array<GiteaUser> | null, milestone: GiteaMilestone | null, };
+
+export type GiteaUser = { login: string, avatar_url: string | null, };
+
+export type GiteaLabel = { name: string, color: string | null, };
+
+export type GiteaMilestone = { id: bigint, title: string, };
+
+export type ListGiteaIssuesParams = { state: string | null, 
+/**
+ * Comma-separated label names
+ */
+labels: string | null, limit: number | null, page: number | null, };
+
+export type GiteaIssuesResponse = { issues: Array<GiteaIssue>, has_gitea_config: boolean, };
+
+export type ImportGiteaIssueRequest = { issue_number: bigint, };
+
+export type GiteaConfigStatus = { has_base_url: boolean, has_repo: boolean, has_token: boolean, base_url: string | null, repo: string | null, sync_enabled: boolean, sync_labels: string | null, };
+
+export type IssueConnection = { project_id: string, 
+/**
+ * Provider name as shown to users, e.g. `Linear`
+ */
+provider: string, settings: IssueConnectionSettings, last_sync_at: string | null, created_at: string, updated_at: string, };
+
+export type IssueConnectionSettings = { "provider": "Linear" } & LinearSettings | { "provider": "Azure DevOps" } & AzureDevOpsSettings | { "provider": "Notion" } & NotionSettings | { "provider": "Asana" } & AsanaSettings | { "provider": "ClickUp" } & ClickUpSettings | { "provider": "Shortcut" } & ShortcutSettings;
+
+export type LinearSettings = { team_id: string, 
+/**
+ * Prefix of the team's issue identifiers, e.g. `ENG`; filled in on save
+ */
+team_key: string, 
+/**
+ * Comma-separated labels; only issues with one of them are imported
+ */
+sync_labels: string | null, 
+/**
+ * Move the linked Linear issue when its task moves to In Progress, In
+ * Review or Done
+ */
+status_sync_enabled: boolean, };
+
+export type AzureDevOpsSettings = { 
+/**
+ * `https://dev.azure.com/{organization}` or a server collection URL
+ */
+organization_url: string, azure_project: string, 
+/**
+ * Extra WIQL condition narrowing which work items are imported
+ */
+wiql_filter: string | null, };
+
+export type NotionSettings = { database_id: string, 
+/**
+ * Name of the property holding each row's status
+ */
+status_property: string, 
+/**
+ * Name of the property holding each row's description
+ */
+description_property: string, };
+
+export type AsanaSettings = { project_gid: string, };
+
+export type ClickUpSettings = { list_id: string, 
+/**
+ * Comma-separated tags; only tasks with one of them are listed and synced
+ */
+tag_filter: string | null, };
+
+export type ShortcutSettings = { 
+/**
+ * Comma-separated labels; stories with any of them are imported
+ */
+labels: string | null, 
+/**
+ * Workflow state stories move to when their task goes to review; when
+ * unset, a state named like "review" in the story's workflow is used
+ */
+review_state_id: number | null, };
+
+export type UpsertIssueConnection = { 
+/**
+ * Required when connecting; omit to keep the stored token
+ */
+token?: string, settings: IssueConnectionSettings, };
+
+export type ImportConnectedIssueRequest = { 
+/**
+ * The tracker's id for the issue, e.g. `ENG-123` for Linear
+ */
+issue_id: string, };
+
+export type ListLinearTeamsRequest = { 
+/**
+ * Key to list teams with; the project's stored key is used when omitted
+ */
+api_key?: string, };
+
+export type LinearTeam = { id: string, key: string, name: string, };
+
+export type ShortcutWorkflowState = { id: number, name: string, 
+/**
+ * `backlog`, `unstarted`, `started` or `done`
+ */
+type: string, };
+
+export type ShortcutWorkflow = { id: number, name: string, states: Array<ShortcutWorkflowState>, };
+
+export type BitbucketIssue = { id: bigint, title: string, description: string | null, 
+/**
+ * `new`, `open`, `resolved`, `on hold`, `invalid`, `duplicate`, `wontfix` or `closed`
+ */
+state: string, kind: string | null, priority: string | null, html_url: string, reporter: string | null, created_on: string, updated_on: string, };
+
+export type ListBitbucketIssuesParams = { 
+/**
+ * Only issues in these states; defaults to the unresolved ones
+ */
+states: Array<string>, pagelen: number | null, page: number | null, };
+
+export type BitbucketIssuesResponse = { issues: Array<BitbucketIssue>, has_bitbucket_config: boolean, };
+
+export type ImportBitbucketIssueRequest = { issue_id: bigint, };
+
+export type ImportBitbucketIssueResponse = { task: Task, issue: BitbucketIssue, };
+
+export type BitbucketConfigStatus = { has_repo: boolean, has_credentials: boolean, workspace: string | null, repo_slug: string | null, username: string | null, };
+
+export type VortexIssue = { id: string, workspace_id: string | null, project_id: string | null, key: string, title: string, description: string | null, type: string | null, status: string, priority: string | null, severity: string | null, assignee_id: string | null, reporter_id: string | null, due_date: string | null, labels: Array<string>, custom_fields: string | null, customFields: any, componentIds: Array<string>, subtasks: any[], linkedIssues: any[], attachments: Array<VortexAttachment>, watcherIds: Array<string>, github_issue: any, created_at: string, updated_at: string, };
+
+export type VortexUser = { id: string, name: string, email: string, avatar_url: string | null, };
+
+export type VortexAttachment = { id: string, filename: string, downloadUrl: string | null, mimeType: string | null, isImage: boolean, };
+
+export type VortexComment = { id: string, issue_id: string, user_id: string, content: string, created_at: string, };
+
+export type VortexProject = { id: string, key: string | null, name: string, workspace_id: string | null, workspace_name: string | null, };
+
+export type ListVortexIssuesParams = { status: string | null, priority: string | null, labels: string | null, page: number | null, limit: number | null, };
+
+export type VortexIssuesResponse = { issues: Array<VortexIssue>, has_vortex_config: boolean, };
+
+export type ImportVortexIssueRequest = { issue_id: string, };
+
+export type VortexConfigStatus = { has_project_id: boolean, has_token: boolean, project_id: string | null, sync_enabled: boolean, sync_labels: string | null, two_way_sync: boolean, };
+
+export type IssueSyncSide = "task" | "issue";
+
+/**
+ * A task and its issue that were both edited between two syncs. The side
+ * edited last was kept; both versions are recorded so the other can be
+ * recovered.
+ */
+export type IssueSyncConflict = { id: string, task_id: string, 
+/**
+ * Provider name as shown to users, e.g. `Vortex`
+ */
+provider: string, external_id: string, winner: IssueSyncSide, task_title: string, 
+/**
+ * The task's description without its import header
+ */
+task_description: string | null, issue_title: string, issue_description: string | null, created_at: string, };
+
+/**
+ * Tasks tracked together, such as the tasks of a GitHub milestone
+ */
+export type TaskGroup = { id: string, project_id: string, 
+/**
+ * Provider name as shown to users, e.g. `GitHub`
+ */
+provider: string, 
+/**
+ * What the provider's API addresses the group by, e.g. the milestone number
+ */
+external_id: string, title: string, description: string | null, url: string, closed: boolean, due_at: string | null, 
+/**
+ * Open issues as the provider counts them, including issues without a task
+ */
+open_issues: number, closed_issues: number, created_at: string, updated_at: string, };
+
+/**
+ * A group with the tasks on the board that belong to it
+ */
+export type TaskGroupWithProgress = { task_ids: Array<string>, 
+/**
+ * How many of the tasks are Done
+ */
+tasks_done: number, id: string, project_id: string, 
+/**
+ * Provider name as shown to users, e.g. `GitHub`
+ */
+provider: string, 
+/**
+ * What the provider's API addresses the group by, e.g. the milestone number
+ */
+external_id: string, title: string, description: string | null, url: string, closed: boolean, due_at: string | null, 
+/**
+ * Open issues as the provider counts them, including issues without a task
+ */
+open_issues: number, closed_issues: number, created_at: string, updated_at: string, };
+
+export type StartVortexOAuthRequest = { 
+/**
+ * Where Vortex sends the user back to; must point at `/api/vortex/oauth/callback`
+ */
+redirect_uri: string, };
+
+export type StartVortexOAuthResponse = { authorize_url: string, };
+
+export type VortexOAuthStatus = { 
+/**
+ * Whether a Vortex OAuth app is set up in settings
+ */
+configured: boolean, connected: boolean, expires_at: string | null, };
+
+export type RepoBranchStatus = { repo_id: string, repo_name: string, commits_behind: number | null, commits_ahead: number | null, has_uncommitted_changes: boolean | null, head_oid: string | null, uncommitted_count: number | null, untracked_count: number | null, target_branch_name: string, remote_commits_behind: number | null, remote_commits_ahead: number | null, merges: Array<Merge>, 
+/**
+ * True if a `git rebase` is currently in progress in this worktree
+ */
+is_rebase_in_progress: boolean, 
+/**
+ * Current conflict operation if any
+ */
+conflict_op: ConflictOp | null, 
+/**
+ * List of files currently in conflicted (unmerged) state
+ */
+conflicted_files: Array<string>, };
+
+export type DirectoryEntry = { name: string, path: string, is_directory: boolean, is_git_repo: boolean, last_modified: bigint | null, };
+
+export type DirectoryListResponse = { entries: Array<DirectoryEntry>, current_path: string, };
+
+export type Config = { config_version: string, theme: ThemeMode, executor_profile: ExecutorProfileId, disclaimer_acknowledged: boolean, onboarding_acknowledged: boolean, notifications: NotificationConfig, editor: EditorConfig, github: GitHubConfig, analytics_enabled: boolean, workspace_dir: string | null, last_app_version: string | null, show_release_notes: boolean, language: UiLanguage, git_branch_prefix: string, showcases: ShowcaseState, pr_auto_description_enabled: boolean, pr_auto_description_prompt: string | null, 
+/**
+ * Description template for GitLab merge requests opened without a body
+ */
+gitlab_mr_description_template: string | null, 
+/**
+ * Mailgun webhook signing key that inbound email deliveries must be signed with
+ */
+email_intake_signing_key: string | null, 
+/**
+ * OAuth app registered with Vortex, so projects can connect without pasting
+ * a long-lived API token
+ */
+vortex_oauth_client_id: string | null, vortex_oauth_client_secret: string | null, task_validation: TaskValidationConfig, approval_policy: ApprovalPolicyConfig, log_redaction: LogRedactionConfig, 
+/**
+ * Ask coding agents to propose follow-up tasks for human review
+ */
+follow_up_task_proposals: boolean, 
+/**
+ * Keep attempt branches when their worktrees are cleaned up, instead of
+ * deleting the ones already merged into their target branch
+ */
+keep_branches_after_cleanup: boolean, 
+/**
+ * Keep the worktrees and branch of a workspace once its changes have
+ * landed, instead of cleaning them up right away
+ */
+keep_workspace_after_merge: boolean, 
+/**
+ * Also delete the workspace branch on the remote once its changes have landed
+ */
+delete_remote_branch_after_merge: boolean, network: NetworkConfig, 
+/**
+ * How many automatically started attempts may run at once, shared across
+ * projects by their weights. Zero means no limit.
+ */
+auto_start_slots: number, 
+/**
+ * Refuse to start an attempt for a task while another attempt for it is
+ * still running
+ */
+one_attempt_per_task: boolean, diff_size_guard: DiffSizeGuardConfig, issue_sync_schedule: IssueSyncScheduleConfig, };
+
+export type NotificationConfig = { sound_enabled: boolean, push_enabled: boolean, sound_file: SoundFile, };
+
+export enum ThemeMode { LIGHT = "LIGHT", DARK = "DARK", SYSTEM = "SYSTEM" }
+
+export type EditorConfig = { editor_type: EditorType, custom_command: string | null, remote_ssh_host: string | null, remote_ssh_user: string | null, };
+
+export enum EditorType { VS_CODE = "VS_CODE", CURSOR = "CURSOR", WINDSURF = "WINDSURF", INTELLI_J = "INTELLI_J", ZED = "ZED", XCODE = "XCODE", CUSTOM = "CUSTOM" }
+
+export type EditorOpenError = { "type": "executable_not_found", executable: string, editor_type: EditorType, } | { "type": "invalid_command", details: string, editor_type: EditorType, } | { "type": "launch_failed", executable: string, details: string, editor_type: EditorType, };
+
+export type GitHubConfig = { pat: string | null, oauth_token: string | null, username: string | null, primary_email: string | null, default_pr_base: string | null, };
+
+export enum SoundFile { ABSTRACT_SOUND1 = "ABSTRACT_SOUND1", ABSTRACT_SOUND2 = "ABSTRACT_SOUND2", ABSTRACT_SOUND3 = "ABSTRACT_SOUND3", ABSTRACT_SOUND4 = "ABSTRACT_SOUND4", COW_MOOING = "COW_MOOING", PHONE_VIBRATION = "PHONE_VIBRATION", ROOSTER = "ROOSTER" }
+
+export type UiLanguage = "BROWSER" | "EN" | "JA" | "ES" | "KO" | "ZH_HANS";
+
+export type ShowcaseState = { seen_features: Array<string>, };
+
+export type TaskValidationConfig = { 
+/**
+ * Markdown headings every description must contain, e.g. "Acceptance Criteria"
+ */
+required_sections: Array<string>, required_sections_level: ValidationLevel, max_description_length: number | null, max_description_length_level: ValidationLevel, 
+/**
+ * Extra regexes treated like credentials, on top of the built-in secret patterns
+ */
+forbidden_patterns: Array<string>, secrets_level: ValidationLevel, };
+
+export type ValidationLevel = "OFF" | "WARN" | "ERROR";
+
+export type ApprovalPolicyConfig = { 
+/**
+ * Only Claude Code can enforce the policy, so other agents won't start
+ * while it's on
+ */
+enabled: boolean, rules: Array<ApprovalRule>, 
+/**
+ * Pause recursive deletes that reach outside the attempt's worktree
+ */
+block_delete_outside_worktree: boolean, };
+
+export type ApprovalRule = { name: string, pattern: string, };
+
+export type LogRedactionConfig = { enabled: boolean, 
+/**
+ * API keys, tokens and other well-known credential formats
+ */
+secrets: boolean, emails: boolean, 
+/**
+ * Hostnames under these domains are masked, e.g. "corp.example.com"
+ */
+internal_domains: Array<string>, 
+/**
+ * Extra regexes; whatever they match is masked
+ */
+patterns: Array<string>, };
+
+export type NetworkConfig = { 
+/**
+ * e.g. "http://proxy.corp.example.com:3128". When unset, `VK_HTTP_PROXY` and
+ * then `HTTPS_PROXY`/`HTTP_PROXY` apply.
+ */
+http_proxy: string | null, 
+/**
+ * PEM file of additional CA certificates to trust, such as the root of a
+ * TLS-inspecting proxy
+ */
+ca_certificates_path: string | null, };
+
+export type DiffSizeGuardConfig = { enabled: boolean, max_files: number, 
+/**
+ * Combined size of the old and new contents of all changed files
+ */
+max_bytes: number, };
+
+/**
+ * Background sync of the issue providers that projects have sync enabled for
+ */
+export type IssueSyncScheduleConfig = { enabled: boolean, interval_minutes: number, 
+/**
+ * Up to this many seconds are added to each interval at random, so
+ * instances started together don't hit the providers at the same moment
+ */
+jitter_seconds: number, };
+
+export type GitBranch = { name: string, is_current: boolean, is_remote: boolean, last_commit_date: Date, };
+
+export type SharedTaskDetails = { id: string, project_id: string, title: string, description: string | null, status: TaskStatus, };
+
+export type QueuedMessage = { 
 /**
  * The session this message is queued for
  */
@@ -422,7 +1612,19 @@ queued_at: string, };
 
 export type QueueStatus = { "status": "empty" } | { "status": "queued", message: QueuedMessage, };
 
-export type QueueProcessingStatus = { is_processing: boolean, current_task_id: string | null, queue_length: number, };
+export type QueueProcessingStatus = { is_processing: boolean, current_task_id: string | null, queue_length: number, 
+/**
+ * When the queue should drain, from the hour estimates of queued tasks
+ */
+estimated_completion_at: string | null, 
+/**
+ * Queued tasks without an hour estimate, left out of the ETA
+ */
+unestimated_tasks: number, 
+/**
+ * Todo tasks held back until their dependencies are done
+ */
+blocked_task_ids: Array<string>, };
 
 export type ConflictOp = "rebase" | "merge" | "cherry_pick" | "revert";
 
@@ -473,7 +1675,7 @@ export type ExecutorConfig = { [key in string]?: { "CLAUDE_CODE": ClaudeCode } |
 
 export type ExecutorConfigs = { executors: { [key in BaseCodingAgent]?: ExecutorConfig }, };
 
-export enum BaseAgentCapability { SESSION_FORK = "SESSION_FORK", SETUP_HELPER = "SETUP_HELPER" }
+export enum BaseAgentCapability { SESSION_FORK = "SESSION_FORK", SETUP_HELPER = "SETUP_HELPER", COMMAND_APPROVAL_POLICY = "COMMAND_APPROVAL_POLICY" }
 
 export type ClaudeCode = { append_prompt: AppendPrompt, claude_code_router?: boolean | null, plan?: boolean | null, approvals?: boolean | null, model?: string | null, dangerously_skip_permissions?: boolean | null, disable_api_key?: boolean | null, base_command_override?: string | null, additional_params?: Array<string> | null, env?: { [key in string]?: string } | null, };
 